@@ -50,7 +50,9 @@ use mz_persist_client::cfg::{PersistParameters, RetryParameters};
 use mz_pgrepr::oid::FIRST_USER_OID;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::role_id::RoleId;
-use mz_repr::{explain::ExprHumanizer, Diff, GlobalId, RelationDesc, ScalarType};
+use mz_repr::{
+    explain::ExprHumanizer, Datum, Diff, GlobalId, RelationDesc, Row, RowArena, ScalarType,
+};
 use mz_secrets::InMemorySecretsController;
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::Expr;
@@ -67,11 +69,13 @@ use mz_sql::names::{
     SchemaId, SchemaSpecifier, PUBLIC_ROLE_NAME,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext, PlanError, SourceSinkClusterConfig as PlanStorageClusterConfig,
-    StatementDesc,
+    CheckConstraint, CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
+    CreateViewPlan, Params, Plan, PlanContext, PlanError,
+    SourceSinkClusterConfig as PlanStorageClusterConfig, StatementDesc, UniqueConstraint,
+    WebhookDesc,
 };
+use mz_sql::session::password::ScramSecret;
 use mz_sql::session::user::{INTROSPECTION_USER, SYSTEM_USER};
 use mz_sql::session::vars::{
     OwnedVarInput, SystemVars, Var, VarError, VarInput, CONFIG_HAS_SYNCED_ONCE,
@@ -1776,6 +1780,14 @@ pub struct Table {
     pub desc: RelationDesc,
     #[serde(skip)]
     pub defaults: Vec<Expr<Aug>>,
+    /// The `CHECK` constraints that rows written to the table must satisfy.
+    pub check_constraints: Vec<CheckConstraint>,
+    /// The `PRIMARY KEY` and `UNIQUE` constraints that rows written to the
+    /// table must satisfy.
+    pub unique_constraints: Vec<UniqueConstraint>,
+    /// The value of each column in rows written before the column was added
+    /// to the table.
+    pub missing_values: Row,
     pub conn_id: Option<ConnectionId>,
     pub depends_on: Vec<GlobalId>,
     pub custom_logical_compaction_window: Option<Duration>,
@@ -1790,6 +1802,28 @@ impl Table {
     pub fn timeline(&self) -> Timeline {
         Timeline::EpochMilliseconds
     }
+
+    /// Verifies that a row with the given `datums`, which is being written to
+    /// the table named `name`, satisfies the table's `CHECK` constraints.
+    ///
+    /// As in PostgreSQL, a constraint whose predicate evaluates to `NULL` is
+    /// satisfied.
+    pub fn check_constraints_met(
+        &self,
+        name: &str,
+        datums: &[Datum],
+        arena: &RowArena,
+    ) -> Result<(), AdapterError> {
+        for check in &self.check_constraints {
+            if check.expr.eval(datums, arena)? == Datum::False {
+                return Err(AdapterError::CheckViolation {
+                    table: name.to_string(),
+                    constraint: check.name.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1802,6 +1836,8 @@ pub enum DataSourceDesc {
     Introspection(IntrospectionType),
     /// Receives data from the source's reclocking/remapping operations.
     Progress,
+    /// Receives data from requests to an HTTP endpoint.
+    Webhook(WebhookDesc),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1824,7 +1860,8 @@ impl Source {
             DataSourceDesc::Ingestion(_) => true,
             DataSourceDesc::Introspection(_)
             | DataSourceDesc::Progress
-            | DataSourceDesc::Source => false,
+            | DataSourceDesc::Source
+            | DataSourceDesc::Webhook(_) => false,
         }
     }
 
@@ -1834,6 +1871,7 @@ impl Source {
             DataSourceDesc::Ingestion(ingestion) => ingestion.desc.connection.name(),
             DataSourceDesc::Progress | DataSourceDesc::Source => "subsource",
             DataSourceDesc::Introspection(_) => "source",
+            DataSourceDesc::Webhook(_) => "webhook",
         }
     }
 
@@ -1872,7 +1910,8 @@ impl Source {
             },
             DataSourceDesc::Introspection(_)
             | DataSourceDesc::Progress
-            | DataSourceDesc::Source => None,
+            | DataSourceDesc::Source
+            | DataSourceDesc::Webhook(_) => None,
         }
     }
 
@@ -1882,7 +1921,8 @@ impl Source {
             DataSourceDesc::Ingestion(ingestion) => ingestion.desc.connection.connection_id(),
             DataSourceDesc::Introspection(_)
             | DataSourceDesc::Progress
-            | DataSourceDesc::Source => None,
+            | DataSourceDesc::Source
+            | DataSourceDesc::Webhook(_) => None,
         }
     }
 }
@@ -1919,7 +1959,7 @@ pub struct Sink {
     // TODO(benesch): this field duplicates information that could be derived
     // from the connection ID. Too hard to fix at the moment.
     pub connection: StorageSinkConnectionState,
-    pub envelope: SinkEnvelope,
+    pub envelope: Option<SinkEnvelope>,
     pub with_snapshot: bool,
     pub depends_on: Vec<GlobalId>,
     pub cluster_id: ClusterId,
@@ -1936,8 +1976,9 @@ impl Sink {
     /// Envelope of the sink.
     pub fn envelope(&self) -> Option<&str> {
         match &self.envelope {
-            SinkEnvelope::Debezium => Some("debezium"),
-            SinkEnvelope::Upsert => Some("upsert"),
+            Some(SinkEnvelope::Debezium) => Some("debezium"),
+            Some(SinkEnvelope::Upsert) => Some("upsert"),
+            None => None,
         }
     }
 
@@ -2077,7 +2118,8 @@ impl CatalogItem {
                 DataSourceDesc::Ingestion(ingestion) => Ok(Some(&ingestion.desc)),
                 DataSourceDesc::Introspection(_)
                 | DataSourceDesc::Progress
-                | DataSourceDesc::Source => Ok(None),
+                | DataSourceDesc::Source
+                | DataSourceDesc::Webhook(_) => Ok(None),
             },
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
@@ -2248,7 +2290,8 @@ impl CatalogItem {
                 DataSourceDesc::Ingestion(ingestion) => Some(ingestion.cluster_id),
                 DataSourceDesc::Introspection(_)
                 | DataSourceDesc::Progress
-                | DataSourceDesc::Source => None,
+                | DataSourceDesc::Source
+                | DataSourceDesc::Webhook(_) => None,
             },
             CatalogItem::Sink(sink) => Some(sink.cluster_id),
             CatalogItem::Table(_)
@@ -2346,6 +2389,14 @@ impl CatalogEntry {
         }
     }
 
+    /// Returns the inner [`Table`] if this entry is a table, else `None`.
+    pub fn table(&self) -> Option<&Table> {
+        match self.item() {
+            CatalogItem::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
     /// Returns the inner [`Source`] if this entry is a source, else `None`.
     pub fn source(&self) -> Option<&Source> {
         match self.item() {
@@ -2410,6 +2461,16 @@ impl CatalogEntry {
         }
     }
 
+    /// Reports whether this catalog entry is a webhook source.
+    pub fn is_webhook_source(&self) -> bool {
+        match &self.item() {
+            CatalogItem::Source(source) => {
+                matches!(&source.data_source, DataSourceDesc::Webhook(_))
+            }
+            _ => false,
+        }
+    }
+
     /// Returns the `GlobalId` of all of this entry's subsources.
     pub fn subsources(&self) -> Vec<GlobalId> {
         match &self.item() {
@@ -2426,7 +2487,8 @@ impl CatalogEntry {
                     .collect(),
                 DataSourceDesc::Introspection(_)
                 | DataSourceDesc::Progress
-                | DataSourceDesc::Source => vec![],
+                | DataSourceDesc::Source
+                | DataSourceDesc::Webhook(_) => vec![],
             },
             CatalogItem::Table(_)
             | CatalogItem::Log(_)
@@ -2896,6 +2958,9 @@ impl Catalog {
                                 create_sql: CREATE_SQL_TODO.to_string(),
                                 desc: table.desc.clone(),
                                 defaults: vec![Expr::null(); table.desc.arity()],
+                                check_constraints: vec![],
+                                unique_constraints: vec![],
+                                missing_values: Row::default(),
                                 conn_id: None,
                                 depends_on: vec![],
                                 custom_logical_compaction_window: table
@@ -3588,6 +3653,9 @@ impl Catalog {
             CatalogType::Bytes => CatalogType::Bytes,
             CatalogType::Char => CatalogType::Char,
             CatalogType::Date => CatalogType::Date,
+            CatalogType::Enum { labels } => CatalogType::Enum {
+                labels: labels.clone(),
+            },
             CatalogType::Float32 => CatalogType::Float32,
             CatalogType::Float64 => CatalogType::Float64,
             CatalogType::Int16 => CatalogType::Int16,
@@ -3614,6 +3682,8 @@ impl Catalog {
             CatalogType::VarChar => CatalogType::VarChar,
             CatalogType::Int2Vector => CatalogType::Int2Vector,
             CatalogType::MzAclItem => CatalogType::MzAclItem,
+            CatalogType::Inet => CatalogType::Inet,
+            CatalogType::Cidr => CatalogType::Cidr,
         };
 
         BuiltinType {
@@ -3952,7 +4022,7 @@ impl Catalog {
                     }))
                 }
             };
-            let oid = c.allocate_oid()?;
+            let oid = c.allocate_item_oid(&catalog_item)?;
 
             // Enqueue any items waiting on this dependency.
             if let Some(dependent_items) = awaiting_id_dependencies.remove(&item.id) {
@@ -4129,11 +4199,15 @@ impl Catalog {
     }
 
     pub fn for_sessionless_user(&self, role_id: RoleId) -> ConnCatalog {
+        Self::for_sessionless_user_state(&self.state, role_id)
+    }
+
+    pub fn for_sessionless_user_state(state: &CatalogState, role_id: RoleId) -> ConnCatalog {
         ConnCatalog {
-            state: Cow::Borrowed(&self.state),
+            state: Cow::Borrowed(state),
             conn_id: SYSTEM_CONN_ID,
             cluster: "default".into(),
-            database: self
+            database: state
                 .resolve_database(DEFAULT_DATABASE_NAME)
                 .ok()
                 .map(|db| db.id()),
@@ -4232,6 +4306,27 @@ impl Catalog {
         self.state.allocate_oid()
     }
 
+    /// Allocates the OID for `item`.
+    ///
+    /// Enum types additionally reserve the following OID for their array type.
+    /// See [`mz_pgrepr::oid::enum_array_oid`].
+    pub fn allocate_item_oid(&mut self, item: &CatalogItem) -> Result<u32, Error> {
+        let oid = self.allocate_oid()?;
+        if let CatalogItem::Type(Type {
+            details:
+                CatalogTypeDetails {
+                    typ: CatalogType::Enum { .. },
+                    ..
+                },
+            ..
+        }) = item
+        {
+            let array_oid = self.allocate_oid()?;
+            assert_eq!(array_oid, mz_pgrepr::oid::enum_array_oid(oid));
+        }
+        Ok(oid)
+    }
+
     /// Get all global timestamps that has been persisted to disk.
     pub async fn get_all_persisted_timestamps(
         &self,
@@ -5036,6 +5131,140 @@ impl Catalog {
                     let to_name = entry.name().clone();
                     update_item(state, builtin_table_updates, id, to_name, source)?;
                 }
+                Op::AlterTable { id, table } => {
+                    let entry = state.get_entry(&id);
+                    let name = entry.name().clone();
+
+                    if entry.id().is_system() {
+                        let schema_name = state
+                            .resolve_full_name(&name, session.map(|session| session.conn_id()))
+                            .schema;
+                        return Err(AdapterError::Catalog(Error::new(
+                            ErrorKind::ReadOnlySystemSchema(schema_name),
+                        )));
+                    }
+                    if !matches!(entry.item(), CatalogItem::Table(_)) {
+                        coord_bail!("ALTER TABLE entry was not a table: {}", entry.item().typ());
+                    }
+
+                    let full_name = state.resolve_full_name(&name, entry.conn_id());
+                    let old_uses = entry.uses().to_vec();
+                    let dependents = entry.used_by().to_vec();
+
+                    let item = CatalogItem::Table(table);
+                    if !item.is_temporary() {
+                        tx.update_item(id, &name.item, &Self::serialize_item(&item))?;
+                    }
+                    state.add_to_audit_log(
+                        oracle_write_ts,
+                        session,
+                        tx,
+                        builtin_table_updates,
+                        audit_events,
+                        EventType::Alter,
+                        ObjectType::Table,
+                        EventDetails::IdFullNameV1(IdFullNameV1 {
+                            id: id.to_string(),
+                            name: Self::full_name_detail(&full_name),
+                        }),
+                    )?;
+
+                    // The new column may introduce new dependencies (e.g., on
+                    // its type), so we can't use `update_item`, which requires
+                    // the dependencies to stay fixed.
+                    builtin_table_updates.extend(state.pack_item_update(id, -1));
+                    for u in item.uses() {
+                        if !old_uses.contains(u) {
+                            state
+                                .entry_by_id
+                                .get_mut(u)
+                                .expect("catalog out of sync")
+                                .used_by
+                                .push(id);
+                        }
+                    }
+                    state
+                        .entry_by_id
+                        .get_mut(&id)
+                        .expect("catalog out of sync")
+                        .item = item;
+                    builtin_table_updates.extend(state.pack_item_update(id, 1));
+
+                    // Views and materialized views that read the table are
+                    // re-planned against its new shape, as their optimized
+                    // expressions refer to the table's columns by position.
+                    // Their own shape must not change: anything that depends
+                    // on them, including running dataflows, relies on it.
+                    // Indexes and sinks capture the table's full shape and
+                    // cannot be adjusted in place.
+                    for dependent_id in dependents {
+                        let dependent = state.get_entry(&dependent_id);
+                        let dependent_err = |reason: String| AdapterError::AlterTableDependent {
+                            table: full_name.to_string(),
+                            dependent_type: dependent.item_type().to_string(),
+                            dependent: state
+                                .resolve_full_name(dependent.name(), dependent.conn_id())
+                                .to_string(),
+                            reason,
+                        };
+                        let (create_sql, desc) = match dependent.item() {
+                            CatalogItem::View(View {
+                                create_sql, desc, ..
+                            })
+                            | CatalogItem::MaterializedView(MaterializedView {
+                                create_sql,
+                                desc,
+                                ..
+                            }) => (create_sql.clone(), desc),
+                            _ => {
+                                return Err(dependent_err(format!(
+                                    "An existing {} cannot be adjusted to include new columns.",
+                                    dependent.item_type()
+                                )))
+                            }
+                        };
+                        let optimized_expr = match Self::parse_item_state(
+                            state,
+                            dependent_id,
+                            create_sql,
+                            Some(&PlanContext::zero()),
+                            false,
+                            None,
+                        ) {
+                            Ok(
+                                CatalogItem::View(View {
+                                    optimized_expr,
+                                    desc: new_desc,
+                                    ..
+                                })
+                                | CatalogItem::MaterializedView(MaterializedView {
+                                    optimized_expr,
+                                    desc: new_desc,
+                                    ..
+                                }),
+                            ) if new_desc.iter().eq(desc.iter()) => optimized_expr,
+                            Ok(_) => {
+                                return Err(dependent_err(format!(
+                                    "The columns of the {} would change.",
+                                    dependent.item_type()
+                                )))
+                            }
+                            Err(e) => return Err(dependent_err(e.to_string())),
+                        };
+                        match &mut state
+                            .entry_by_id
+                            .get_mut(&dependent_id)
+                            .expect("catalog out of sync")
+                            .item
+                        {
+                            CatalogItem::View(view) => view.optimized_expr = optimized_expr,
+                            CatalogItem::MaterializedView(mview) => {
+                                mview.optimized_expr = optimized_expr
+                            }
+                            _ => unreachable!("checked above"),
+                        }
+                    }
+                }
                 Op::CreateDatabase {
                     name,
                     oid,
@@ -6458,7 +6687,27 @@ impl Catalog {
         is_retained_metrics_object: bool,
         custom_logical_compaction_window: Option<Duration>,
     ) -> Result<CatalogItem, AdapterError> {
-        let mut session_catalog = self.for_system_session();
+        Self::parse_item_state(
+            &self.state,
+            id,
+            create_sql,
+            pcx,
+            is_retained_metrics_object,
+            custom_logical_compaction_window,
+        )
+    }
+
+    // Parses the given SQL string into a `CatalogItem`, resolving names
+    // against `state`.
+    fn parse_item_state(
+        state: &CatalogState,
+        id: GlobalId,
+        create_sql: String,
+        pcx: Option<&PlanContext>,
+        is_retained_metrics_object: bool,
+        custom_logical_compaction_window: Option<Duration>,
+    ) -> Result<CatalogItem, AdapterError> {
+        let mut session_catalog = Self::for_sessionless_user_state(state, MZ_SYSTEM_ROLE_ID);
         enable_features_required_for_catalog_open(&mut session_catalog);
 
         let stmt = mz_sql::parse::parse(&create_sql)?.into_element();
@@ -6470,6 +6719,9 @@ impl Catalog {
                 create_sql: table.create_sql,
                 desc: table.desc,
                 defaults: table.defaults,
+                check_constraints: table.check_constraints,
+                unique_constraints: table.unique_constraints,
+                missing_values: table.missing_values,
                 conn_id: None,
                 depends_on,
                 custom_logical_compaction_window,
@@ -6492,7 +6744,7 @@ impl Catalog {
                                 plan::SourceSinkClusterConfig::Existing { id } => id,
                                 plan::SourceSinkClusterConfig::Linked { .. }
                                 | plan::SourceSinkClusterConfig::Undefined => {
-                                    self.state.clusters_by_linked_object_id[&id]
+                                    state.clusters_by_linked_object_id[&id]
                                 }
                             },
                             remap_collection_id: ingestion.progress_subsource,
@@ -6500,6 +6752,9 @@ impl Catalog {
                     }
                     mz_sql::plan::DataSourceDesc::Progress => DataSourceDesc::Progress,
                     mz_sql::plan::DataSourceDesc::Source => DataSourceDesc::Source,
+                    mz_sql::plan::DataSourceDesc::Webhook(webhook) => {
+                        DataSourceDesc::Webhook(webhook)
+                    }
                 },
                 desc: source.desc,
                 timeline,
@@ -6559,7 +6814,7 @@ impl Catalog {
                     plan::SourceSinkClusterConfig::Existing { id } => id,
                     plan::SourceSinkClusterConfig::Linked { .. }
                     | plan::SourceSinkClusterConfig::Undefined => {
-                        self.state.clusters_by_linked_object_id[&id]
+                        state.clusters_by_linked_object_id[&id]
                     }
                 },
             }),
@@ -6857,6 +7112,24 @@ fn enable_features_required_for_catalog_open(session_catalog: &mut ConnCatalog)
             .system_vars_mut()
             .set_enable_format_json(true);
     }
+    if !session_catalog.system_vars().enable_file_sinks() {
+        session_catalog
+            .system_vars_mut()
+            .set_enable_file_sinks(true);
+    }
+    if !session_catalog
+        .system_vars()
+        .enable_unvalidated_webhook_sources()
+    {
+        session_catalog
+            .system_vars_mut()
+            .set_enable_unvalidated_webhook_sources(true);
+    }
+    if !session_catalog.system_vars().enable_table_keys() {
+        session_catalog
+            .system_vars_mut()
+            .set_enable_table_keys(true);
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -6889,6 +7162,12 @@ pub enum Op {
         name: String,
         attributes: RoleAttributes,
     },
+    /// Replaces the definition of the table `id` with `table`, which must
+    /// describe a superset of the table's existing columns.
+    AlterTable {
+        id: GlobalId,
+        table: Table,
+    },
     CreateDatabase {
         name: String,
         oid: u32,
@@ -7202,7 +7481,8 @@ impl ExprHumanizer for ConnCatalog<'_> {
             Record {
                 custom_id: Some(id),
                 ..
-            } => {
+            }
+            | Enum { custom_id: id, .. } => {
                 let item = self.get_item(id);
                 self.minimal_qualification(item.name()).to_string()
             }
@@ -7620,6 +7900,10 @@ impl mz_sql::catalog::CatalogRole for Role {
         self.attributes.create_cluster
     }
 
+    fn password(&self) -> Option<&ScramSecret> {
+        self.attributes.password.as_ref()
+    }
+
     fn membership(&self) -> &BTreeMap<RoleId, RoleId> {
         &self.membership.map
     }
@@ -7785,7 +8069,7 @@ mod tests {
     use mz_ore::now::{NOW_ZERO, SYSTEM_TIME};
     use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
     use mz_repr::role_id::RoleId;
-    use mz_repr::{GlobalId, RelationDesc, RelationType, ScalarType};
+    use mz_repr::{GlobalId, RelationDesc, RelationType, Row, ScalarType};
     use mz_sql::catalog::{CatalogDatabase, PrivilegeMap, SessionCatalog};
     use mz_sql::names;
     use mz_sql::names::{
@@ -8080,6 +8364,9 @@ mod tests {
                             .with_column("a", ScalarType::Int32.nullable(true))
                             .with_key(vec![0]),
                         defaults: vec![Expr::null(); 1],
+                        check_constraints: vec![],
+                        unique_constraints: vec![],
+                        missing_values: Row::default(),
                         conn_id: None,
                         depends_on: vec![],
                         custom_logical_compaction_window: None,
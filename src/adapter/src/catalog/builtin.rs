@@ -629,6 +629,50 @@ pub const TYPE_UUID_ARRAY: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_INET: BuiltinType<NameReference> = BuiltinType {
+    name: "inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Inet,
+        array_id: None,
+    },
+};
+
+pub const TYPE_INET_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_inet",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_INET_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_INET.name,
+        },
+        array_id: None,
+    },
+};
+
+pub const TYPE_CIDR: BuiltinType<NameReference> = BuiltinType {
+    name: "cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Cidr,
+        array_id: None,
+    },
+};
+
+pub const TYPE_CIDR_ARRAY: BuiltinType<NameReference> = BuiltinType {
+    name: "_cidr",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_CIDR_ARRAY_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Array {
+            element_reference: TYPE_CIDR.name,
+        },
+        array_id: None,
+    },
+};
+
 pub const TYPE_JSONB: BuiltinType<NameReference> = BuiltinType {
     name: "jsonb",
     schema: PG_CATALOG_SCHEMA,
@@ -701,6 +745,16 @@ pub const TYPE_ANYRANGE: BuiltinType<NameReference> = BuiltinType {
     },
 };
 
+pub const TYPE_ANYENUM: BuiltinType<NameReference> = BuiltinType {
+    name: "anyenum",
+    schema: PG_CATALOG_SCHEMA,
+    oid: oid::TYPE_ANYENUM_OID,
+    details: CatalogTypeDetails {
+        typ: CatalogType::Pseudo,
+        array_id: None,
+    },
+};
+
 pub const TYPE_CHAR: BuiltinType<NameReference> = BuiltinType {
     name: "char",
     schema: PG_CATALOG_SCHEMA,
@@ -1606,6 +1660,17 @@ pub static MZ_MAP_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
         .with_column("value_id", ScalarType::String.nullable(false)),
     is_retained_metrics_object: false,
 });
+pub static MZ_ENUM_TYPES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
+    name: "mz_enum_types",
+    schema: MZ_CATALOG_SCHEMA,
+    desc: RelationDesc::empty()
+        .with_column("id", ScalarType::String.nullable(false))
+        .with_column(
+            "labels",
+            ScalarType::Array(Box::new(ScalarType::String)).nullable(false),
+        ),
+    is_retained_metrics_object: false,
+});
 pub static MZ_ROLES: Lazy<BuiltinTable> = Lazy::new(|| BuiltinTable {
     name: "mz_roles",
     schema: MZ_CATALOG_SCHEMA,
@@ -2449,6 +2514,7 @@ FROM
             UNION ALL SELECT id, 'b' FROM mz_catalog.mz_base_types
             UNION ALL SELECT id, 'l' FROM mz_catalog.mz_list_types
             UNION ALL SELECT id, 'm' FROM mz_catalog.mz_map_types
+            UNION ALL SELECT id, 'e' FROM mz_catalog.mz_enum_types
             UNION ALL SELECT id, 'p' FROM mz_catalog.mz_pseudo_types
         )
             AS t ON mz_types.id = t.id
@@ -2550,10 +2616,12 @@ pub const PG_ENUM: BuiltinView = BuiltinView {
     schema: PG_CATALOG_SCHEMA,
     sql: "CREATE VIEW pg_catalog.pg_enum AS SELECT
     NULL::pg_catalog.oid AS oid,
-    NULL::pg_catalog.oid AS enumtypid,
-    NULL::pg_catalog.float4 AS enumsortorder,
-    NULL::pg_catalog.text AS enumlabel
-WHERE false",
+    mz_types.oid AS enumtypid,
+    labels.ordinality::pg_catalog.float4 AS enumsortorder,
+    labels.label AS enumlabel
+FROM mz_catalog.mz_enum_types
+    JOIN mz_catalog.mz_types ON mz_types.id = mz_enum_types.id,
+    unnest(mz_enum_types.labels) WITH ORDINALITY AS labels (label, ordinality)",
 };
 
 pub const PG_ATTRDEF: BuiltinView = BuiltinView {
@@ -3525,6 +3593,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_ANYELEMENT),
         Builtin::Type(&TYPE_ANYNONARRAY),
         Builtin::Type(&TYPE_ANYRANGE),
+        Builtin::Type(&TYPE_ANYENUM),
         Builtin::Type(&TYPE_BOOL),
         Builtin::Type(&TYPE_BOOL_ARRAY),
         Builtin::Type(&TYPE_BYTEA),
@@ -3573,6 +3642,10 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Type(&TYPE_TIMESTAMPTZ_ARRAY),
         Builtin::Type(&TYPE_UUID),
         Builtin::Type(&TYPE_UUID_ARRAY),
+        Builtin::Type(&TYPE_INET),
+        Builtin::Type(&TYPE_INET_ARRAY),
+        Builtin::Type(&TYPE_CIDR),
+        Builtin::Type(&TYPE_CIDR_ARRAY),
         Builtin::Type(&TYPE_VARCHAR),
         Builtin::Type(&TYPE_VARCHAR_ARRAY),
         Builtin::Type(&TYPE_INT2_VECTOR),
@@ -3664,6 +3737,7 @@ pub static BUILTINS_STATIC: Lazy<Vec<Builtin<NameReference>>> = Lazy::new(|| {
         Builtin::Table(&MZ_BASE_TYPES),
         Builtin::Table(&MZ_LIST_TYPES),
         Builtin::Table(&MZ_MAP_TYPES),
+        Builtin::Table(&MZ_ENUM_TYPES),
         Builtin::Table(&MZ_ROLES),
         Builtin::Table(&MZ_ROLE_MEMBERS),
         Builtin::Table(&MZ_PSEUDO_TYPES),
@@ -4236,7 +4310,10 @@ mod tests {
                         | ScalarType::RegType
                         | ScalarType::RegClass
                         | ScalarType::Int2Vector
-                        | ScalarType::Range { .. } => {}
+                        | ScalarType::Range { .. }
+                        | ScalarType::Inet
+                        | ScalarType::Cidr
+                        | ScalarType::Enum { .. } => {}
                     }
                 }
             }
@@ -43,11 +43,11 @@ use crate::catalog::builtin::{
     MZ_CLUSTER_LINKS, MZ_CLUSTER_REPLICAS, MZ_CLUSTER_REPLICA_FRONTIERS,
     MZ_CLUSTER_REPLICA_HEARTBEATS, MZ_CLUSTER_REPLICA_METRICS, MZ_CLUSTER_REPLICA_SIZES,
     MZ_CLUSTER_REPLICA_STATUSES, MZ_COLUMNS, MZ_CONNECTIONS, MZ_DATABASES, MZ_EGRESS_IPS,
-    MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS,
-    MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES, MZ_OPERATORS,
-    MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS, MZ_SECRETS,
-    MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
-    MZ_SUBSCRIPTIONS, MZ_TABLES, MZ_TYPES, MZ_VIEWS,
+    MZ_ENUM_TYPES, MZ_FUNCTIONS, MZ_INDEXES, MZ_INDEX_COLUMNS, MZ_KAFKA_CONNECTIONS,
+    MZ_KAFKA_SINKS, MZ_LIST_TYPES, MZ_MAP_TYPES, MZ_MATERIALIZED_VIEWS, MZ_OBJECT_DEPENDENCIES,
+    MZ_OPERATORS, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_ROLES, MZ_ROLE_MEMBERS, MZ_SCHEMAS,
+    MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCES, MZ_SSH_TUNNEL_CONNECTIONS,
+    MZ_STORAGE_USAGE_BY_SHARD, MZ_SUBSCRIPTIONS, MZ_TABLES, MZ_TYPES, MZ_VIEWS,
 };
 use crate::catalog::{
     CatalogItem, CatalogState, Connection, DataSourceDesc, Database, Error, ErrorKind, Func, Index,
@@ -504,6 +504,7 @@ impl CatalogState {
                     mz_storage_client::types::connections::Connection::Postgres { .. } => {
                         "postgres"
                     }
+                    mz_storage_client::types::connections::Connection::MySql { .. } => "mysql",
                     mz_storage_client::types::connections::Connection::Aws(..) => "aws",
                     mz_storage_client::types::connections::Connection::AwsPrivatelink(..) => {
                         "aws-privatelink"
@@ -532,6 +533,7 @@ impl CatalogState {
             }
             mz_storage_client::types::connections::Connection::Csr(_)
             | mz_storage_client::types::connections::Connection::Postgres(_)
+            | mz_storage_client::types::connections::Connection::MySql(_)
             | mz_storage_client::types::connections::Connection::Aws(_)
             | mz_storage_client::types::connections::Connection::AwsPrivatelink(_) => {
                 if let Some(aws_principal_context) = self.aws_principal_context.as_ref() {
@@ -720,6 +722,7 @@ impl CatalogState {
                         diff,
                     });
                 }
+                StorageSinkConnection::File(_) | StorageSinkConnection::Postgres(_) => {}
             };
 
             let envelope = sink.envelope();
@@ -841,6 +844,30 @@ impl CatalogState {
             diff,
         };
 
+        if let CatalogType::Enum { labels } = &typ.details.typ {
+            // Per PostgreSQL, an empty array has no dimensions.
+            let dims = if labels.is_empty() {
+                vec![]
+            } else {
+                vec![ArrayDimension {
+                    lower_bound: 1,
+                    length: labels.len(),
+                }]
+            };
+            let mut row = Row::default();
+            let mut packer = row.packer();
+            packer.push(Datum::String(&id.to_string()));
+            packer
+                .push_array(&dims, labels.iter().map(|l| Datum::String(l)))
+                .expect("labels is 1 dimensional, and its length is used for the array length");
+            let specific_update = BuiltinTableUpdate {
+                id: self.resolve_builtin_table(&MZ_ENUM_TYPES),
+                row,
+                diff,
+            };
+            return vec![generic_update, specific_update];
+        }
+
         let (index_id, update) = match typ.details.typ {
             CatalogType::Array {
                 element_reference: element_id,
@@ -679,6 +679,7 @@ async fn migrate(
                     Statement::CreateConnection(_) => mz_sql_parser::ast::ObjectType::Connection,
                     Statement::CreateSource(_) => mz_sql_parser::ast::ObjectType::Source,
                     Statement::CreateSubsource(_) => mz_sql_parser::ast::ObjectType::Source,
+                    Statement::CreateWebhookSource(_) => mz_sql_parser::ast::ObjectType::Source,
                     Statement::CreateSink(_) => mz_sql_parser::ast::ObjectType::Sink,
                     Statement::CreateView(_) => mz_sql_parser::ast::ObjectType::View,
                     Statement::CreateMaterializedView(_) => {
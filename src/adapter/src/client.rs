@@ -26,11 +26,14 @@ use mz_ore::id_gen::IdAllocator;
 use mz_ore::now::{to_datetime, EpochMillis, NowFn};
 use mz_ore::task::{AbortOnDropHandle, JoinHandleExt};
 use mz_ore::thread::JoinOnDropHandle;
-use mz_repr::{GlobalId, Row, ScalarType};
+use mz_repr::{Diff, GlobalId, Row, ScalarType};
 use mz_sql::ast::{Raw, Statement};
+use mz_sql::session::password::ScramSecret;
 use mz_sql::session::user::{User, INTROSPECTION_USER};
 
-use crate::command::{Canceled, Command, ExecuteResponse, Response, StartupResponse};
+use crate::command::{
+    Canceled, Command, ExecuteResponse, GetWebhookResponse, Response, StartupResponse,
+};
 use crate::error::AdapterError;
 use crate::metrics::Metrics;
 use crate::session::{EndTransactionAction, PreparedStatement, Session, TransactionId};
@@ -140,6 +143,53 @@ impl Client {
         }
     }
 
+    /// Returns the verifier of the password of the role named `role_name`.
+    ///
+    /// Returns `None` if no such role exists or the role has no password.
+    pub async fn get_role_password(&self, role_name: &str) -> Option<ScramSecret> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::GetRolePassword {
+            role_name: role_name.into(),
+            tx,
+        });
+        rx.await.expect("coordinator unexpectedly gone")
+    }
+
+    /// Looks up the webhook source named `database.schema.name`, on behalf of
+    /// `user`.
+    ///
+    /// Returns an error if `user` is not authorized to append to the source.
+    pub async fn get_webhook(
+        &self,
+        database: &str,
+        schema: &str,
+        name: &str,
+        user: User,
+    ) -> Result<GetWebhookResponse, AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::GetWebhook {
+            database: database.into(),
+            schema: schema.into(),
+            name: name.into(),
+            user,
+            tx,
+        });
+        rx.await.expect("coordinator unexpectedly gone")
+    }
+
+    /// Appends `rows` to the webhook source with the given ID.
+    ///
+    /// Returns once the rows are visible to readers.
+    pub async fn append_webhook(
+        &self,
+        id: GlobalId,
+        rows: Vec<(Row, Diff)>,
+    ) -> Result<(), AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::AppendWebhook { id, rows, tx });
+        rx.await.expect("coordinator unexpectedly gone")
+    }
+
     /// Returns the metrics associated with the adapter layer.
     pub fn metrics(&self) -> &Metrics {
         &self.metrics
@@ -504,6 +554,9 @@ impl SessionClient {
                     | Command::VerifyPreparedStatement { .. }
                     | Command::Commit { .. }
                     | Command::CancelRequest { .. }
+                    | Command::GetRolePassword { .. }
+                    | Command::GetWebhook { .. }
+                    | Command::AppendWebhook { .. }
                     | Command::DumpCatalog { .. }
                     | Command::CopyRows { .. }
                     | Command::GetSystemVars { .. }
@@ -25,9 +25,12 @@ use tokio::sync::watch;
 
 use mz_ore::str::StrExt;
 use mz_pgcopy::CopyFormatParams;
-use mz_repr::{GlobalId, Row, ScalarType};
+use mz_repr::{Diff, GlobalId, Row, ScalarType};
+use mz_secrets::SecretsReader;
 use mz_sql::ast::{FetchDirection, ObjectType, Raw, Statement};
-use mz_sql::plan::ExecuteTimeout;
+use mz_sql::plan::{ExecuteTimeout, WebhookDesc};
+use mz_sql::session::password::ScramSecret;
+use mz_sql::session::user::User;
 
 use crate::client::ConnectionId;
 use crate::coord::peek::PeekResponseUnary;
@@ -83,6 +86,25 @@ pub enum Command {
         secret_key: u32,
     },
 
+    GetRolePassword {
+        role_name: String,
+        tx: oneshot::Sender<Option<ScramSecret>>,
+    },
+
+    GetWebhook {
+        database: String,
+        schema: String,
+        name: String,
+        user: User,
+        tx: oneshot::Sender<Result<GetWebhookResponse, AdapterError>>,
+    },
+
+    AppendWebhook {
+        id: GlobalId,
+        rows: Vec<(Row, Diff)>,
+        tx: oneshot::Sender<Result<(), AdapterError>>,
+    },
+
     DumpCatalog {
         session: Session,
         tx: oneshot::Sender<Response<String>>,
@@ -127,7 +149,10 @@ impl Command {
             | Command::GetSystemVars { session, .. }
             | Command::SetSystemVars { session, .. }
             | Command::Terminate { session, .. } => Some(session),
-            Command::CancelRequest { .. } => None,
+            Command::CancelRequest { .. }
+            | Command::GetRolePassword { .. }
+            | Command::GetWebhook { .. }
+            | Command::AppendWebhook { .. } => None,
         }
     }
 
@@ -144,7 +169,10 @@ impl Command {
             | Command::GetSystemVars { session, .. }
             | Command::SetSystemVars { session, .. }
             | Command::Terminate { session, .. } => Some(session),
-            Command::CancelRequest { .. } => None,
+            Command::CancelRequest { .. }
+            | Command::GetRolePassword { .. }
+            | Command::GetWebhook { .. }
+            | Command::AppendWebhook { .. } => None,
         }
     }
 
@@ -162,7 +190,13 @@ impl Command {
             Command::VerifyPreparedStatement { tx, session, .. } => send(tx, session, e),
             Command::Execute { tx, session, .. } => send(tx, session, e),
             Command::Commit { tx, session, .. } => send(tx, session, e),
-            Command::CancelRequest { .. } => {}
+            Command::CancelRequest { .. } | Command::GetRolePassword { .. } => {}
+            Command::GetWebhook { tx, .. } => {
+                let _ = tx.send(Err(e));
+            }
+            Command::AppendWebhook { tx, .. } => {
+                let _ = tx.send(Err(e));
+            }
             Command::DumpCatalog { tx, session, .. } => send(tx, session, e),
             Command::CopyRows { tx, session, .. } => send(tx, session, e),
             Command::GetSystemVars { tx, session, .. } => send(tx, session, e),
@@ -236,6 +270,36 @@ impl fmt::Display for StartupMessage {
     }
 }
 
+/// The response to [`Client::get_webhook`](crate::Client::get_webhook).
+#[derive(Debug)]
+pub struct GetWebhookResponse {
+    /// The ID of the webhook source.
+    pub id: GlobalId,
+    /// Describes the requests that the source accepts.
+    pub desc: WebhookDesc,
+    /// Reads the secret that requests are validated with, if any.
+    pub secrets_reader: Arc<dyn SecretsReader>,
+}
+
+impl GetWebhookResponse {
+    /// Reports whether a request to the webhook source passes validation.
+    ///
+    /// Requests to sources without validation always pass.
+    pub async fn validate_request(
+        &self,
+        headers: &BTreeMap<String, String>,
+        body: &[u8],
+    ) -> Result<bool, anyhow::Error> {
+        match &self.desc.validation {
+            None => Ok(true),
+            Some(validation) => {
+                let key = self.secrets_reader.read(validation.secret_id).await?;
+                Ok(validation.validate(&key, headers, body))
+            }
+        }
+    }
+}
+
 /// The response to [`SessionClient::execute`](crate::SessionClient::execute).
 #[derive(EnumKind, Derivative)]
 #[derivative(Debug)]
@@ -254,7 +318,7 @@ pub enum ExecuteResponse {
     /// The requested cursor was closed.
     ClosedCursor,
     CopyTo {
-        format: mz_sql::plan::CopyFormat,
+        format: CopyFormatParams<'static>,
         resp: Box<ExecuteResponse>,
     },
     CopyFrom {
@@ -429,7 +493,7 @@ impl ExecuteResponse {
         match plan {
             AbortTransaction => vec![TransactionRolledBack],
             AlterOwner | AlterItemRename | AlterNoop | AlterSecret | AlterSink | AlterSource
-            | RotateKeys => {
+            | AlterTableAddColumn | RotateKeys => {
                 vec![AlteredObject]
             }
             AlterIndexSetOptions | AlterIndexResetOptions => {
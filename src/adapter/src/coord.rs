@@ -101,13 +101,14 @@ use mz_ore::thread::JoinHandleExt;
 use mz_ore::tracing::OpenTelemetryContext;
 use mz_ore::{stack, task};
 use mz_persist_client::usage::{ShardsUsage, StorageUsageClient};
+use mz_pgcopy::CopyFormatParams;
 use mz_repr::explain::ExplainFormat;
 use mz_repr::{Datum, GlobalId, RelationType, Row, Timestamp};
 use mz_secrets::SecretsController;
 use mz_sql::ast::{CreateSourceStatement, CreateSubsourceStatement, Raw, Statement};
 use mz_sql::catalog::EnvironmentId;
 use mz_sql::names::Aug;
-use mz_sql::plan::{CopyFormat, Params, QueryWhen};
+use mz_sql::plan::{Params, QueryWhen};
 use mz_storage_client::controller::{
     CollectionDescription, CreateExportToken, DataSource, StorageError,
 };
@@ -124,7 +125,7 @@ use crate::catalog::{
 use crate::client::{Client, ConnectionId, Handle};
 use crate::command::{Canceled, Command, ExecuteResponse};
 use crate::config::SystemParameterFrontend;
-use crate::coord::appends::{Deferred, PendingWriteTxn};
+use crate::coord::appends::{Deferred, PendingWriteTxn, UniqueKeys};
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::peek::PendingPeek;
 use crate::coord::read_policy::ReadCapability;
@@ -192,6 +193,8 @@ pub enum Message<T = mz_repr::Timestamp> {
         T,
         /// Clients waiting on responses from the group commit.
         Vec<CompletedClientTransmitter<ExecuteResponse>>,
+        /// Webhook requests waiting on responses from the group commit.
+        Vec<oneshot::Sender<Result<(), AdapterError>>>,
         /// Optional lock if the group commit contained writes to user tables.
         Option<OwnedMutexGuard<()>>,
     ),
@@ -259,7 +262,7 @@ pub enum RealTimeRecencyContext {
     Peek {
         tx: ClientTransmitter<ExecuteResponse>,
         finishing: RowSetFinishing,
-        copy_to: Option<CopyFormat>,
+        copy_to: Option<CopyFormatParams<'static>>,
         dataflow: DataflowDescription<OptimizedMirRelationExpr>,
         session: Session,
         cluster_id: ClusterId,
@@ -304,7 +307,7 @@ pub struct PeekStageOptimize {
     replan: TransientPlan,
     source: MirRelationExpr,
     finishing: RowSetFinishing,
-    copy_to: Option<CopyFormat>,
+    copy_to: Option<CopyFormatParams<'static>>,
     view_id: GlobalId,
     index_id: GlobalId,
     source_ids: BTreeSet<GlobalId>,
@@ -320,7 +323,7 @@ pub struct PeekStageTimestamp {
     replan: TransientPlan,
     dataflow: DataflowDescription<OptimizedMirRelationExpr>,
     finishing: RowSetFinishing,
-    copy_to: Option<CopyFormat>,
+    copy_to: Option<CopyFormatParams<'static>>,
     view_id: GlobalId,
     index_id: GlobalId,
     source_ids: BTreeSet<GlobalId>,
@@ -337,7 +340,7 @@ pub struct PeekStageTimestamp {
 pub struct PeekStageFinish {
     replan: TransientPlan,
     pub finishing: RowSetFinishing,
-    pub copy_to: Option<CopyFormat>,
+    pub copy_to: Option<CopyFormatParams<'static>>,
     pub dataflow: DataflowDescription<OptimizedMirRelationExpr>,
     pub cluster_id: ClusterId,
     pub when: QueryWhen,
@@ -579,6 +582,9 @@ pub struct Coordinator {
     write_lock_wait_group: VecDeque<Deferred>,
     /// Pending writes waiting for a group commit.
     pending_writes: Vec<PendingWriteTxn>,
+    /// The keys present in user tables with `PRIMARY KEY` or `UNIQUE`
+    /// constraints.
+    unique_keys: UniqueKeys,
     /// For the realtime timeline, an explicit SELECT or INSERT on a table will bump the
     /// table's timestamps, but there are cases where timestamps are not bumped but
     /// we expect the closed timestamps to advance (`AS OF X`, SUBSCRIBing views over
@@ -853,6 +859,8 @@ impl Coordinator {
                 // Subsources use source statuses.
                 DataSourceDesc::Source => (DataSource::Other, source_status_collection_id),
                 DataSourceDesc::Progress => (DataSource::Progress, None),
+                // Webhook sources are written to by the coordinator, like tables.
+                DataSourceDesc::Webhook(_) => (DataSource::Other, None),
                 DataSourceDesc::Introspection(introspection) => {
                     (DataSource::Introspection(*introspection), None)
                 }
@@ -862,6 +870,7 @@ impl Coordinator {
                 data_source,
                 since: None,
                 status_collection_id,
+                missing_values: Row::default(),
             }
         }
 
@@ -876,7 +885,10 @@ impl Coordinator {
                             source_desc(entry.id(), source_status_collection_id, source),
                         )),
                         CatalogItem::Table(table) => {
-                            let collection_desc = table.desc.clone().into();
+                            let collection_desc = CollectionDescription {
+                                missing_values: table.missing_values.clone(),
+                                ..CollectionDescription::from(table.desc.clone())
+                            };
                             Some((entry.id(), collection_desc))
                         }
                         CatalogItem::MaterializedView(mview) => {
@@ -897,7 +909,10 @@ impl Coordinator {
         for entry in &entries {
             match entry.item() {
                 CatalogItem::Table(table) => {
-                    let collection_desc = table.desc.clone().into();
+                    let collection_desc = CollectionDescription {
+                        missing_values: table.missing_values.clone(),
+                        ..CollectionDescription::from(table.desc.clone())
+                    };
                     collections_to_create.push((entry.id(), collection_desc));
                 }
                 // User sources can have dependencies, so do avoid them in the
@@ -1187,7 +1202,7 @@ impl Coordinator {
         // Expose mapping from T-shirt sizes to actual sizes
         builtin_table_updates.extend(self.catalog().state().pack_all_replica_size_updates());
 
-        // Advance all tables to the current timestamp
+        // Advance all tables and webhook sources to the current timestamp
         info!("coordinator init: advancing all tables to current timestamp");
         let WriteTimestamp {
             timestamp: _,
@@ -1195,7 +1210,7 @@ impl Coordinator {
         } = self.get_local_write_ts().await;
         let appends = entries
             .iter()
-            .filter(|entry| entry.is_table())
+            .filter(|entry| entry.is_table() || entry.is_webhook_source())
             .map(|entry| (entry.id(), Vec::new(), advance_to))
             .collect();
         self.controller
@@ -1538,6 +1553,7 @@ pub async fn serve(
                 write_lock: Arc::new(tokio::sync::Mutex::new(())),
                 write_lock_wait_group: VecDeque::new(),
                 pending_writes: Vec::new(),
+                unique_keys: UniqueKeys::default(),
                 advance_timelines_interval,
                 secrets_controller,
                 cloud_resource_controller,
@@ -9,26 +9,28 @@
 
 //! Logic and types for all appends executed by the [`Coordinator`].
 
-use std::collections::BTreeMap;
+use std::collections::{btree_map, BTreeMap, BTreeSet};
+use std::fmt;
 use std::sync::Arc;
 use std::time::Duration;
 
 use derivative::Derivative;
-use tokio::sync::OwnedMutexGuard;
+use itertools::Itertools;
+use tokio::sync::{oneshot, OwnedMutexGuard};
 use tracing::warn;
 
 use mz_ore::task;
 use mz_ore::vec::VecExt;
-use mz_repr::{Diff, GlobalId, Row, Timestamp};
-use mz_sql::plan::Plan;
+use mz_repr::{ColumnName, Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_sql::plan::{Plan, UniqueConstraint};
 use mz_storage_client::client::Update;
 
 use crate::catalog::BuiltinTableUpdate;
 use crate::coord::timeline::WriteTimestamp;
 use crate::coord::{Coordinator, Message, PendingTxn};
-use crate::session::{Session, WriteOp};
+use crate::session::{EndTransactionAction, Session, WriteOp};
 use crate::util::{ClientTransmitter, CompletedClientTransmitter, ResultExt};
-use crate::ExecuteResponse;
+use crate::{AdapterError, ExecuteResponse};
 
 /// An operation that is deferred while waiting for a lock.
 #[derive(Debug)]
@@ -74,6 +76,13 @@ pub(crate) enum PendingWriteTxn {
         updates: Vec<BuiltinTableUpdate>,
         source: BuiltinTableUpdateSource,
     },
+    /// Write of the requests received by a webhook source.
+    Webhook {
+        id: GlobalId,
+        rows: Vec<(Row, Diff)>,
+        /// Notified once the write is visible to readers.
+        tx: oneshot::Sender<Result<(), AdapterError>>,
+    },
 }
 
 impl PendingWriteTxn {
@@ -82,7 +91,7 @@ impl PendingWriteTxn {
             PendingWriteTxn::User {
                 write_lock_guard, ..
             } => std::mem::take(write_lock_guard),
-            PendingWriteTxn::System { .. } => None,
+            PendingWriteTxn::System { .. } | PendingWriteTxn::Webhook { .. } => None,
         }
     }
 
@@ -90,7 +99,7 @@ impl PendingWriteTxn {
     /// asynchronously.
     fn should_block(&self) -> bool {
         match self {
-            PendingWriteTxn::User { .. } => false,
+            PendingWriteTxn::User { .. } | PendingWriteTxn::Webhook { .. } => false,
             PendingWriteTxn::System { source, .. } => match source {
                 BuiltinTableUpdateSource::DDL => true,
                 BuiltinTableUpdateSource::Background => false,
@@ -196,11 +205,12 @@ impl Coordinator {
         } else if self
             .pending_writes
             .iter()
-            .all(|write| matches!(write, PendingWriteTxn::System { .. }))
+            .all(|write| !matches!(write, PendingWriteTxn::User { .. }))
             || self.pending_writes.is_empty()
         {
             // If none of the pending transactions are for user tables, then we don't need the
-            // write lock.
+            // write lock. Webhook sources cannot be written to by transactions, so their writes
+            // don't need it either.
             (None, self.pending_writes.drain(..).collect())
         } else if let Some(guard) = self
             .pending_writes
@@ -224,14 +234,17 @@ impl Coordinator {
             //      commit will then acquire the lock and execute a group commit.
             self.defer_write(Deferred::GroupCommit);
 
-            // Without the write lock we can only apply writes to system tables.
+            // Without the write lock we can only apply writes to system tables and webhook
+            // sources.
             let pending_writes = self
                 .pending_writes
-                .drain_filter_swapping(|w| matches!(w, PendingWriteTxn::System { .. }))
+                .drain_filter_swapping(|w| !matches!(w, PendingWriteTxn::User { .. }))
                 .collect();
             (None, pending_writes)
         };
 
+        self.load_unique_keys(&pending_writes).await;
+
         // The value returned here still might be ahead of `now()` if `now()` has gone backwards at
         // any point during this method or if this was triggered from DDL. We will still commit the
         // write without waiting for `now()` to advance. This is ok because the next batch of writes
@@ -245,6 +258,7 @@ impl Coordinator {
         } = self.get_local_write_ts().await;
         let mut appends: BTreeMap<GlobalId, Vec<(Row, Diff)>> = BTreeMap::new();
         let mut responses = Vec::with_capacity(self.pending_writes.len());
+        let mut webhook_responses = Vec::new();
         let should_block = pending_writes.iter().any(|write| write.should_block());
         for pending_write_txn in pending_writes {
             match pending_write_txn {
@@ -259,16 +273,25 @@ impl Coordinator {
                             action,
                         },
                 } => {
-                    for WriteOp { id, rows } in writes {
-                        // If the table that some write was targeting has been deleted while the
-                        // write was waiting, then the write will be ignored and we respond to the
-                        // client that the write was successful. This is only possible if the write
-                        // and the delete were concurrent. Therefore, we are free to order the
-                        // write before the delete without violating any consistency guarantees.
-                        if self.catalog().try_get_entry(&id).is_some() {
-                            appends.entry(id).or_default().extend(rows);
+                    // A transaction whose writes would violate a unique constraint is rolled
+                    // back in its entirety.
+                    let (response, action) = match self.unique_keys.try_apply(&writes) {
+                        Ok(()) => {
+                            for WriteOp { id, rows } in writes {
+                                // If the table that some write was targeting has been deleted
+                                // while the write was waiting, then the write will be ignored and
+                                // we respond to the client that the write was successful. This is
+                                // only possible if the write and the delete were concurrent.
+                                // Therefore, we are free to order the write before the delete
+                                // without violating any consistency guarantees.
+                                if self.catalog().try_get_entry(&id).is_some() {
+                                    appends.entry(id).or_default().extend(rows);
+                                }
+                            }
+                            (response, action)
                         }
-                    }
+                        Err(e) => (Err(e), EndTransactionAction::Rollback),
+                    };
                     responses.push(CompletedClientTransmitter::new(
                         client_transmitter,
                         response,
@@ -277,6 +300,10 @@ impl Coordinator {
                     ));
                 }
                 PendingWriteTxn::System { updates, .. } => {
+                    for update in &updates {
+                        self.unique_keys
+                            .apply(update.id, [(&update.row, update.diff)]);
+                    }
                     for update in updates {
                         appends
                             .entry(update.id)
@@ -284,14 +311,26 @@ impl Coordinator {
                             .push((update.row, update.diff));
                     }
                 }
+                PendingWriteTxn::Webhook { id, rows, tx } => {
+                    // As with user tables, writes to webhook sources that were dropped while the
+                    // write was waiting are ignored.
+                    if self.catalog().try_get_entry(&id).is_some() {
+                        appends.entry(id).or_default().extend(rows);
+                    }
+                    webhook_responses.push(tx);
+                }
             }
         }
 
         for (_, updates) in &mut appends {
             differential_dataflow::consolidation::consolidate(updates);
         }
-        // Add table advancements for all tables.
-        for table in self.catalog().entries().filter(|entry| entry.is_table()) {
+        // Add table advancements for all tables and webhook sources.
+        for table in self
+            .catalog()
+            .entries()
+            .filter(|entry| entry.is_table() || entry.is_webhook_source())
+        {
             appends.entry(table.id()).or_default();
         }
         let appends = appends
@@ -322,7 +361,7 @@ impl Coordinator {
                 .await
                 .expect("One-shot dropped while waiting synchronously")
                 .unwrap_or_terminate("cannot fail to apply appends");
-            self.group_commit_apply(timestamp, responses, write_lock_guard)
+            self.group_commit_apply(timestamp, responses, webhook_responses, write_lock_guard)
                 .await;
         } else {
             let internal_cmd_tx = self.internal_cmd_tx.clone();
@@ -332,6 +371,7 @@ impl Coordinator {
                     if let Err(e) = internal_cmd_tx.send(Message::GroupCommitApply(
                         timestamp,
                         responses,
+                        webhook_responses,
                         write_lock_guard,
                     )) {
                         warn!("Server closed with non-responded writes, {e}");
@@ -353,17 +393,22 @@ impl Coordinator {
     ///
     /// We also advance all other timelines and update the read holds of non-realtime
     /// timelines.
-    #[tracing::instrument(level = "debug", skip(self, responses))]
+    #[tracing::instrument(level = "debug", skip(self, responses, webhook_responses))]
     pub(crate) async fn group_commit_apply(
         &mut self,
         timestamp: Timestamp,
         responses: Vec<CompletedClientTransmitter<ExecuteResponse>>,
+        webhook_responses: Vec<oneshot::Sender<Result<(), AdapterError>>>,
         _write_lock_guard: Option<OwnedMutexGuard<()>>,
     ) {
         self.apply_local_write(timestamp).await;
         for response in responses {
             response.send();
         }
+        for tx in webhook_responses {
+            // The request may have been cancelled while it was waiting.
+            let _ = tx.send(Ok(()));
+        }
 
         // Advancing timelines will update all timeline read holds, and update the read timestamps
         // of non-realtime timelines. There are no guarantees that we need to provide with the
@@ -377,6 +422,48 @@ impl Coordinator {
             .expect("sending to self.internal_cmd_tx cannot fail");
     }
 
+    /// Starts tracking the keys of the tables with `PRIMARY KEY` or `UNIQUE` constraints that
+    /// `pending_writes` write to, if they are not already tracked.
+    ///
+    /// Keys are loaded from a snapshot of the table at the local read timestamp. This is only
+    /// correct because every write to a user table passes through group commit, so a table's
+    /// keys are loaded before the first write to it since the coordinator started.
+    async fn load_unique_keys(&mut self, pending_writes: &[PendingWriteTxn]) {
+        let ids: BTreeSet<_> = pending_writes
+            .iter()
+            .filter_map(|write| match write {
+                PendingWriteTxn::User { writes, .. } => Some(writes),
+                PendingWriteTxn::System { .. } | PendingWriteTxn::Webhook { .. } => None,
+            })
+            .flatten()
+            .map(|WriteOp { id, .. }| *id)
+            .filter(|id| !self.unique_keys.contains(id))
+            .collect();
+        let read_ts = self.get_local_read_ts();
+        for id in ids {
+            let Some(table) = self
+                .catalog()
+                .try_get_entry(&id)
+                .and_then(|entry| entry.table())
+            else {
+                continue;
+            };
+            if table.unique_constraints.is_empty() {
+                continue;
+            }
+            let desc = table.desc.clone();
+            let constraints = table.unique_constraints.clone();
+            let contents = self
+                .controller
+                .storage
+                .snapshot(id, read_ts)
+                .await
+                .unwrap_or_terminate("cannot fail to fetch snapshot");
+            self.unique_keys
+                .add_table(id, &desc, &constraints, &contents);
+        }
+    }
+
     /// Submit a write to be executed during the next group commit and trigger a group commit.
     pub(crate) fn submit_write(&mut self, pending_write_txn: PendingWriteTxn) {
         self.pending_writes.push(pending_write_txn);
@@ -451,3 +538,166 @@ impl Coordinator {
         })
     }
 }
+
+/// The keys currently present in user tables for each of their `PRIMARY KEY`
+/// and `UNIQUE` constraints, used to reject writes that would violate them.
+///
+/// Only tables declared with such constraints are tracked. A table's keys are
+/// loaded when the table is created, or otherwise by the first group commit
+/// that writes to it, and are kept up to date by group commit, through which
+/// every write to a user table passes.
+#[derive(Debug, Default)]
+pub(crate) struct UniqueKeys {
+    tables: BTreeMap<GlobalId, Vec<UniqueIndex>>,
+}
+
+impl UniqueKeys {
+    /// Starts tracking the keys of the table `id`, whose current contents are
+    /// `contents`.
+    pub(crate) fn add_table(
+        &mut self,
+        id: GlobalId,
+        desc: &RelationDesc,
+        constraints: &[UniqueConstraint],
+        contents: &[(Row, Diff)],
+    ) {
+        if constraints.is_empty() {
+            return;
+        }
+        let indexes = constraints
+            .iter()
+            .map(|constraint| UniqueIndex {
+                constraint: constraint.clone(),
+                column_names: constraint
+                    .columns
+                    .iter()
+                    .map(|i| desc.get_name(*i).clone())
+                    .collect(),
+                keys: BTreeMap::new(),
+            })
+            .collect();
+        self.tables.insert(id, indexes);
+        self.apply(id, contents.iter().map(|(row, diff)| (row, *diff)));
+    }
+
+    /// Reports whether the keys of the table `id` are tracked.
+    pub(crate) fn contains(&self, id: &GlobalId) -> bool {
+        self.tables.contains_key(id)
+    }
+
+    /// Stops tracking the keys of the table `id`.
+    pub(crate) fn remove_table(&mut self, id: &GlobalId) {
+        self.tables.remove(id);
+    }
+
+    /// Records that `updates` were written to the table `id`, without
+    /// verifying that they respect its constraints.
+    pub(crate) fn apply<'a>(
+        &mut self,
+        id: GlobalId,
+        updates: impl IntoIterator<Item = (&'a Row, Diff)>,
+    ) {
+        let Some(indexes) = self.tables.get_mut(&id) else {
+            return;
+        };
+        for (row, diff) in updates {
+            for index in indexes.iter_mut() {
+                if let Some(key) = index.key(row) {
+                    index.update(key, diff);
+                }
+            }
+        }
+    }
+
+    /// Records the writes of a transaction. If the writes do not respect the
+    /// constraints of the tables they write to, instead returns an error
+    /// describing the first violated constraint and records nothing.
+    pub(crate) fn try_apply(&mut self, writes: &[WriteOp]) -> Result<(), AdapterError> {
+        // The net change to the multiplicity of each key, by table and
+        // constraint.
+        let mut deltas: BTreeMap<(GlobalId, usize), BTreeMap<Row, Diff>> = BTreeMap::new();
+        for WriteOp { id, rows } in writes {
+            let Some(indexes) = self.tables.get(id) else {
+                continue;
+            };
+            for (i, index) in indexes.iter().enumerate() {
+                let delta = deltas.entry((*id, i)).or_default();
+                for (row, diff) in rows {
+                    if let Some(key) = index.key(row) {
+                        *delta.entry(key).or_default() += diff;
+                    }
+                }
+            }
+        }
+
+        for ((id, i), delta) in &deltas {
+            let index = &self.tables[id][*i];
+            for (key, diff) in delta {
+                if *diff > 0 && index.keys.get(key).copied().unwrap_or(0) + diff > 1 {
+                    return Err(AdapterError::UniqueViolation {
+                        constraint: index.constraint.name.clone(),
+                        key: format!(
+                            "({})=({})",
+                            index.column_names.iter().join(", "),
+                            key.iter().join(", ")
+                        ),
+                    });
+                }
+            }
+        }
+
+        for ((id, i), delta) in deltas {
+            let index = &mut self.tables.get_mut(&id).expect("known to exist")[i];
+            for (key, diff) in delta {
+                index.update(key, diff);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The keys present in a table for one of its `PRIMARY KEY` or `UNIQUE`
+/// constraints.
+struct UniqueIndex {
+    constraint: UniqueConstraint,
+    /// The names of the constraint's columns.
+    column_names: Vec<ColumnName>,
+    /// The multiplicity of each key in the table.
+    keys: BTreeMap<Row, Diff>,
+}
+
+impl fmt::Debug for UniqueIndex {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UniqueIndex")
+            .field("constraint", &self.constraint)
+            .field("keys", &self.keys.len())
+            .finish()
+    }
+}
+
+impl UniqueIndex {
+    /// Returns the key of `row`, or `None` if the key contains a `NULL` and so
+    /// cannot conflict with any other key.
+    fn key(&self, row: &Row) -> Option<Row> {
+        let datums = row.unpack();
+        let mut key = self.constraint.columns.iter().map(|i| datums[*i]);
+        if !self.constraint.nulls_not_distinct && key.clone().any(|datum| datum.is_null()) {
+            return None;
+        }
+        Some(Row::pack(&mut key))
+    }
+
+    fn update(&mut self, key: Row, diff: Diff) {
+        match self.keys.entry(key) {
+            btree_map::Entry::Occupied(mut entry) => {
+                *entry.get_mut() += diff;
+                if *entry.get() == 0 {
+                    entry.remove();
+                }
+            }
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(diff);
+            }
+        }
+    }
+}
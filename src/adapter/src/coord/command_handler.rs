@@ -26,17 +26,21 @@ use mz_sql::ast::{
     CopyRelation, CopyStatement, InsertSource, Query, Raw, SetExpr, Statement, SubscribeStatement,
 };
 use mz_sql::catalog::{RoleAttributes, SessionCatalog};
+use mz_sql::names::PartialItemName;
 use mz_sql::plan::{
     AbortTransactionPlan, CommitTransactionPlan, CopyRowsPlan, CreateRolePlan, Params, Plan,
     TransactionType,
 };
+use mz_sql::session::user::User;
 use mz_sql::session::vars::{
     EndTransactionAction, OwnedVarInput, SystemVars, Var, MAX_CONNECTIONS,
 };
 
+use crate::catalog::{DataSourceDesc, SYSTEM_CONN_ID};
 use crate::client::ConnectionId;
 use crate::command::{
-    Canceled, Command, ExecuteResponse, Response, StartupMessage, StartupResponse,
+    Canceled, Command, ExecuteResponse, GetWebhookResponse, Response, StartupMessage,
+    StartupResponse,
 };
 use crate::coord::appends::{Deferred, PendingWriteTxn};
 use crate::coord::peek::PendingPeek;
@@ -108,6 +112,28 @@ impl Coordinator {
                 self.handle_cancel(conn_id, secret_key);
             }
 
+            Command::GetRolePassword { role_name, tx } => {
+                let password = self
+                    .catalog()
+                    .try_get_role_by_name(&role_name)
+                    .and_then(|role| role.attributes.password.clone());
+                let _ = tx.send(password);
+            }
+
+            Command::GetWebhook {
+                database,
+                schema,
+                name,
+                user,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_get_webhook(database, schema, name, &user));
+            }
+
+            Command::AppendWebhook { id, rows, tx } => {
+                self.submit_write(PendingWriteTxn::Webhook { id, rows, tx });
+            }
+
             Command::DumpCatalog { session, tx } => {
                 // TODO(benesch/jkosh44): when we have RBAC, dumping the catalog should
                 // require superuser permissions.
@@ -456,6 +482,7 @@ impl Coordinator {
                     // Statements below must by run singly (in Started).
                     Statement::AlterConnection(_)
                     | Statement::AlterIndex(_)
+                    | Statement::AlterTable(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
@@ -476,6 +503,7 @@ impl Coordinator {
                     | Statement::CreateSink(_)
                     | Statement::CreateSource(_)
                     | Statement::CreateSubsource(_)
+                    | Statement::CreateWebhookSource(_)
                     | Statement::CreateTable(_)
                     | Statement::CreateType(_)
                     | Statement::CreateView(_)
@@ -585,6 +613,47 @@ impl Coordinator {
         });
     }
 
+    /// Looks up the webhook source named `database.schema.name`, checking that
+    /// `user` may append to it.
+    fn handle_get_webhook(
+        &self,
+        database: String,
+        schema: String,
+        name: String,
+        user: &User,
+    ) -> Result<GetWebhookResponse, AdapterError> {
+        let partial_name = PartialItemName {
+            database: Some(database.clone()),
+            schema: Some(schema.clone()),
+            item: name.clone(),
+        };
+        let entry = self
+            .catalog()
+            .resolve_entry(None, &vec![], &partial_name, SYSTEM_CONN_ID)
+            .ok();
+        match entry.and_then(|entry| Some((entry.id(), entry.source()?))) {
+            Some((
+                id,
+                catalog::Source {
+                    data_source: DataSourceDesc::Webhook(desc),
+                    ..
+                },
+            )) => {
+                rbac::check_webhook(self.catalog(), user, id)?;
+                Ok(GetWebhookResponse {
+                    id,
+                    desc: desc.clone(),
+                    secrets_reader: Arc::clone(&self.connection_context.secrets_reader),
+                })
+            }
+            _ => Err(AdapterError::UnknownWebhook {
+                database,
+                schema,
+                name,
+            }),
+        }
+    }
+
     /// Instruct the dataflow layer to cancel any ongoing, interactive work for
     /// the named `conn_id`.
     fn handle_cancel(&mut self, conn_id: ConnectionId, secret_key: u32) {
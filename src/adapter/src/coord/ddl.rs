@@ -343,6 +343,9 @@ impl Coordinator {
                 self.drop_sources(sources_to_drop);
             }
             if !tables_to_drop.is_empty() {
+                for id in &tables_to_drop {
+                    self.unique_keys.remove_table(id);
+                }
                 self.drop_sources(tables_to_drop);
             }
             if !storage_sinks_to_drop.is_empty() {
@@ -736,10 +739,11 @@ impl Coordinator {
                 .expect("indexes can only be built on items with descs")
                 .into_owned(),
             connection,
-            envelope: Some(sink.envelope),
+            envelope: sink.envelope,
             as_of,
             status_id,
             from_storage_metadata: (),
+            progress_shard: (),
         };
 
         Ok(self
@@ -988,6 +992,7 @@ impl Coordinator {
                 Op::AlterRole { .. }
                 | Op::AlterSink { .. }
                 | Op::AlterSource { .. }
+                | Op::AlterTable { .. }
                 | Op::DropTimeline(_)
                 | Op::UpdatePrivilege { .. }
                 | Op::GrantRole { .. }
@@ -83,6 +83,7 @@ pub fn auto_run_on_introspection<'a, 's, 'p>(
         | Plan::AlterSource(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterSecret(_)
+        | Plan::AlterTableAddColumn(_)
         | Plan::AlterSystemSet(_)
         | Plan::AlterSystemReset(_)
         | Plan::AlterSystemResetAll(_)
@@ -272,6 +273,7 @@ pub fn user_privilege_hack(
         | Plan::AlterSource(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterSecret(_)
+        | Plan::AlterTableAddColumn(_)
         | Plan::AlterSystemSet(_)
         | Plan::AlterSystemReset(_)
         | Plan::AlterSystemResetAll(_)
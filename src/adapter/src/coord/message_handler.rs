@@ -63,8 +63,13 @@ impl Coordinator {
             Message::GroupCommitInitiate => {
                 self.try_group_commit().await;
             }
-            Message::GroupCommitApply(timestamp, responses, write_lock_guard) => {
-                self.group_commit_apply(timestamp, responses, write_lock_guard)
+            Message::GroupCommitApply(
+                timestamp,
+                responses,
+                webhook_responses,
+                write_lock_guard,
+            ) => {
+                self.group_commit_apply(timestamp, responses, webhook_responses, write_lock_guard)
                     .await;
             }
             Message::AdvanceTimelines => {
@@ -132,6 +137,7 @@ impl Coordinator {
                     // `mz_storage_usage_by_shard` table.
                     persist_location: _,
                     relation_desc: _,
+                    missing_values: _,
                 } = &collection.collection_metadata;
                 [*remap_shard, *status_shard, Some(*data_shard)].into_iter()
             })
@@ -318,6 +318,10 @@ impl Coordinator {
             Plan::AlterSource(plan) => {
                 tx.send(self.sequence_alter_source(&session, plan).await, session);
             }
+            Plan::AlterTableAddColumn(plan) => {
+                self.sequence_alter_table_add_column(tx, session, plan, depends_on)
+                    .await;
+            }
             Plan::AlterSystemSet(plan) => {
                 tx.send(
                     self.sequence_alter_system_set(&session, plan).await,
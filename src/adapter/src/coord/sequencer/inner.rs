@@ -41,7 +41,9 @@ use mz_ore::task;
 use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem};
 use mz_repr::explain::{ExplainFormat, Explainee};
 use mz_repr::role_id::RoleId;
-use mz_repr::{Datum, Diff, GlobalId, RelationDesc, RelationType, Row, RowArena, Timestamp};
+use mz_repr::{
+    Datum, Diff, GlobalId, NotNullViolation, RelationDesc, RelationType, Row, RowArena, Timestamp,
+};
 use mz_sql::ast::{ExplainStage, IndexOptionName, ObjectType};
 use mz_sql::catalog::{
     CatalogCluster, CatalogDatabase, CatalogError, CatalogItemType, CatalogSchema,
@@ -53,14 +55,14 @@ use mz_sql::plan::{
     AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan, AlterItemRenamePlan,
     AlterOptionParameter, AlterOwnerPlan, AlterRolePlan, AlterSecretPlan, AlterSinkPlan,
     AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan, AlterSystemSetPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateConnectionPlan, CreateDatabasePlan,
-    CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan,
-    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, DropObjectsPlan, ExecutePlan, ExplainPlan, GrantPrivilegePlan, GrantRolePlan,
-    IndexOption, InsertPlan, MaterializedView, MutationKind, OptimizerConfig, PeekPlan, Plan,
-    QueryWhen, ReadThenWritePlan, ResetVariablePlan, RevokePrivilegePlan, RevokeRolePlan,
-    SendDiffsPlan, SetVariablePlan, ShowVariablePlan, SourceSinkClusterConfig, SubscribeFrom,
-    SubscribePlan, VariableValue, View,
+    AlterTableAddColumnPlan, CreateClusterPlan, CreateClusterReplicaPlan, CreateConnectionPlan,
+    CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan,
+    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan,
+    CreateTypePlan, CreateViewPlan, DropObjectsPlan, ExecutePlan, ExplainPlan, GrantPrivilegePlan,
+    GrantRolePlan, IndexOption, InsertPlan, MaterializedView, MutationKind, OptimizerConfig,
+    PeekPlan, Plan, QueryWhen, ReadThenWritePlan, ResetVariablePlan, RevokePrivilegePlan,
+    RevokeRolePlan, SendDiffsPlan, SetVariablePlan, ShowVariablePlan, SourceSinkClusterConfig,
+    SubscribeFrom, SubscribePlan, VariableValue, View,
 };
 use mz_sql::session::vars::Var;
 use mz_sql::session::vars::{
@@ -69,7 +71,6 @@ use mz_sql::session::vars::{
 };
 use mz_ssh_util::keys::SshKeyPairSet;
 use mz_storage_client::controller::{CollectionDescription, DataSource, ReadPolicy, StorageError};
-use mz_storage_client::types::sinks::StorageSinkConnectionBuilder;
 use mz_storage_client::types::sources::{IngestionDescription, SourceExport};
 use mz_transform::Optimizer;
 
@@ -179,6 +180,16 @@ impl Coordinator {
                         );
                         DataSourceDesc::Source
                     }
+                    mz_sql::plan::DataSourceDesc::Webhook(webhook) => {
+                        assert!(
+                            matches!(
+                                plan.cluster_config,
+                                mz_sql::plan::SourceSinkClusterConfig::Undefined
+                            ),
+                            "webhook sources must not have a host config defined"
+                        );
+                        DataSourceDesc::Webhook(webhook)
+                    }
                 },
                 desc: plan.source.desc,
                 timeline: plan.timeline,
@@ -198,6 +209,7 @@ impl Coordinator {
         match self.catalog_transact(Some(session), ops).await {
             Ok(()) => {
                 let mut source_ids = Vec::with_capacity(sources.len());
+                let mut webhook_ids = Vec::new();
                 for (source_id, source) in sources {
                     let source_status_collection_id =
                         Some(self.catalog().resolve_builtin_storage_collection(
@@ -242,6 +254,11 @@ impl Coordinator {
                         // Subsources use source statuses.
                         DataSourceDesc::Source => (DataSource::Other, source_status_collection_id),
                         DataSourceDesc::Progress => (DataSource::Progress, None),
+                        // Webhook sources are written to by the coordinator, like tables.
+                        DataSourceDesc::Webhook(_) => {
+                            webhook_ids.push(source_id);
+                            (DataSource::Other, None)
+                        }
                         DataSourceDesc::Introspection(_) => {
                             unreachable!("cannot create sources with introspection data sources")
                         }
@@ -258,6 +275,7 @@ impl Coordinator {
                                 data_source,
                                 since: None,
                                 status_collection_id,
+                                missing_values: Row::default(),
                             },
                         )])
                         .await
@@ -266,12 +284,40 @@ impl Coordinator {
                     source_ids.push(source_id);
                 }
 
+                // Like tables, webhook sources are valid from the current
+                // local write timestamp onward.
+                let since_ts = self.peek_local_write_ts();
+                if !webhook_ids.is_empty() {
+                    let policy = ReadPolicy::ValidFrom(Antichain::from_elem(since_ts));
+                    self.controller.storage.set_read_policy(
+                        webhook_ids.iter().map(|id| (*id, policy.clone())).collect(),
+                    );
+                }
+
                 self.initialize_storage_read_policies(
                     source_ids,
                     Some(DEFAULT_LOGICAL_COMPACTION_WINDOW_TS),
                 )
                 .await;
 
+                if !webhook_ids.is_empty() {
+                    // Advance the new webhook sources to a timestamp higher
+                    // than the current read timestamp so that they are
+                    // immediately readable.
+                    let upper = since_ts.step_forward();
+                    let appends = webhook_ids
+                        .into_iter()
+                        .map(|id| (id, Vec::new(), upper))
+                        .collect();
+                    self.controller
+                        .storage
+                        .append(appends)
+                        .expect("invalid webhook source upper initialization")
+                        .await
+                        .expect("One-shot dropped while waiting synchronously")
+                        .unwrap_or_terminate("cannot fail to append");
+                }
+
                 Ok(ExecuteResponse::CreatedSource)
             }
             Err(AdapterError::Catalog(catalog::Error {
@@ -787,6 +833,9 @@ impl Coordinator {
             create_sql: table.create_sql,
             desc: table.desc,
             defaults: table.defaults,
+            check_constraints: table.check_constraints,
+            unique_constraints: table.unique_constraints,
+            missing_values: table.missing_values,
             conn_id,
             depends_on,
             custom_logical_compaction_window: None,
@@ -805,12 +854,17 @@ impl Coordinator {
                 // Determine the initial validity for the table.
                 let since_ts = self.peek_local_write_ts();
 
-                let collection_desc = table.desc.clone().into();
+                let collection_desc = CollectionDescription {
+                    missing_values: table.missing_values.clone(),
+                    ..CollectionDescription::from(table.desc.clone())
+                };
                 self.controller
                     .storage
                     .create_collections(vec![(table_id, collection_desc)])
                     .await
                     .unwrap_or_terminate("cannot fail to create collections");
+                self.unique_keys
+                    .add_table(table_id, &table.desc, &table.unique_constraints, &[]);
 
                 let policy = ReadPolicy::ValidFrom(Antichain::from_elem(since_ts));
                 self.controller
@@ -932,10 +986,6 @@ impl Coordinator {
             session
         );
 
-        // Knowing that we're only handling kafka sinks here helps us simplify.
-        let StorageSinkConnectionBuilder::Kafka(connection_builder) =
-            sink.connection_builder.clone();
-
         // Then try to create a placeholder catalog item with an unknown
         // connection. If that fails, we're done, though if the client specified
         // `if_not_exists` we'll tell the client we succeeded.
@@ -946,9 +996,7 @@ impl Coordinator {
         let catalog_sink = catalog::Sink {
             create_sql: sink.create_sql,
             from: sink.from,
-            connection: StorageSinkConnectionState::Pending(StorageSinkConnectionBuilder::Kafka(
-                connection_builder,
-            )),
+            connection: StorageSinkConnectionState::Pending(sink.connection_builder.clone()),
             envelope: sink.envelope,
             with_snapshot,
             depends_on,
@@ -1268,6 +1316,7 @@ impl Coordinator {
                             data_source: DataSource::Other,
                             since: Some(as_of.clone()),
                             status_collection_id: None,
+                            missing_values: Row::default(),
                         },
                     )])
                     .await
@@ -1389,13 +1438,14 @@ impl Coordinator {
             },
             depends_on,
         };
+        let item = CatalogItem::Type(typ);
         let id = self.catalog_mut().allocate_user_id().await?;
-        let oid = self.catalog_mut().allocate_oid()?;
+        let oid = self.catalog_mut().allocate_item_oid(&item)?;
         let op = catalog::Op::CreateItem {
             id,
             oid,
             name: plan.name,
-            item: CatalogItem::Type(typ),
+            item,
             owner_id: *session.role_id(),
         };
         match self.catalog_transact(Some(session), vec![op]).await {
@@ -1808,11 +1858,22 @@ impl Coordinator {
         if let EndTransactionAction::Commit = action {
             if let (Some(mut ops), write_lock_guard) = txn.into_ops_and_lock_guard() {
                 if let TransactionOps::Writes(writes) = &mut ops {
-                    for WriteOp { id, .. } in &mut writes.iter() {
+                    for WriteOp { id, rows } in &mut writes.iter() {
                         // Re-verify this id exists.
-                        let _ = self.catalog().try_get_entry(id).ok_or_else(|| {
+                        let entry = self.catalog().try_get_entry(id).ok_or_else(|| {
                             AdapterError::SqlCatalog(CatalogError::UnknownItem(id.to_string()))
                         })?;
+                        // Re-verify that the table was not altered after the
+                        // rows were planned.
+                        if let CatalogItem::Table(table) = entry.item() {
+                            let arity = table.desc.arity();
+                            if rows.iter().any(|(row, _)| row.iter().count() != arity) {
+                                let name = self
+                                    .catalog()
+                                    .resolve_full_name(entry.name(), Some(session.conn_id()));
+                                return Err(AdapterError::ConcurrentTableAlter(name.to_string()));
+                            }
+                        }
                     }
 
                     // `rows` can be empty if, say, a DELETE's WHERE clause had 0 results.
@@ -3116,24 +3177,31 @@ impl Coordinator {
         constants: MirRelationExpr,
     ) -> Result<ExecuteResponse, AdapterError> {
         // Insert can be queued, so we need to re-verify the id exists.
-        let desc = match catalog.try_get_entry(&id) {
-            Some(table) => {
-                table.desc(&catalog.resolve_full_name(table.name(), Some(session.conn_id())))?
-            }
+        let (entry, name) = match catalog.try_get_entry(&id) {
+            Some(table) => (
+                table,
+                catalog.resolve_full_name(table.name(), Some(session.conn_id())),
+            ),
             None => {
                 return Err(AdapterError::SqlCatalog(CatalogError::UnknownItem(
                     id.to_string(),
                 )))
             }
         };
+        let desc = entry.desc(&name)?;
+        let table = entry.table().expect("insert target is a table");
 
         match constants.as_const() {
             Some((rows, ..)) => {
                 let rows = rows.clone()?;
+                let arena = RowArena::new();
+                let mut datum_vec = mz_repr::DatumVec::new();
                 for (row, _) in &rows {
-                    for (i, datum) in row.iter().enumerate() {
-                        desc.constraints_met(i, &datum)?;
+                    let datums = datum_vec.borrow_with(row);
+                    for (i, datum) in datums.iter().enumerate() {
+                        desc.constraints_met(i, datum)?;
                     }
+                    table.check_constraints_met(&name.item, &datums, &arena)?;
                 }
                 let diffs_plan = SendDiffsPlan {
                     id,
@@ -3203,15 +3271,20 @@ impl Coordinator {
         } = plan;
 
         // Read then writes can be queued, so re-verify the id exists.
-        let desc = match self.catalog().try_get_entry(&id) {
-            Some(table) => table
-                .desc(
-                    &self
-                        .catalog()
-                        .resolve_full_name(table.name(), Some(session.conn_id())),
+        let (desc, table, table_name) = match self.catalog().try_get_entry(&id) {
+            Some(entry) => {
+                let name = self
+                    .catalog()
+                    .resolve_full_name(entry.name(), Some(session.conn_id()));
+                (
+                    entry
+                        .desc(&name)
+                        .expect("desc called on table")
+                        .into_owned(),
+                    entry.table().expect("mutation target is a table").clone(),
+                    name.item,
                 )
-                .expect("desc called on table")
-                .into_owned(),
+            }
             None => {
                 tx.send(
                     Err(AdapterError::SqlCatalog(CatalogError::UnknownItem(
@@ -3223,6 +3296,19 @@ impl Coordinator {
             }
         };
 
+        // The table may have been altered while this statement was deferred.
+        if selection.arity() != desc.arity() {
+            let name = self.catalog().resolve_full_name(
+                self.catalog().get_entry(&id).name(),
+                Some(session.conn_id()),
+            );
+            tx.send(
+                Err(AdapterError::ConcurrentTableAlter(name.to_string())),
+                session,
+            );
+            return;
+        }
+
         // Ensure all objects `selection` depends on are valid for
         // `ReadThenWrite` operations, i.e. they do not refer to any objects
         // whose notion of time moves differently than that of user tables.
@@ -3352,9 +3438,15 @@ impl Coordinator {
                                     }
                                     for (row, diff) in &diffs {
                                         if *diff > 0 {
-                                            for (idx, datum) in row.iter().enumerate() {
-                                                desc.constraints_met(idx, &datum)?;
+                                            let datums = datum_vec.borrow_with(row);
+                                            for (idx, datum) in datums.iter().enumerate() {
+                                                desc.constraints_met(idx, datum)?;
                                             }
+                                            table.check_constraints_met(
+                                                &table_name,
+                                                &datums,
+                                                &arena,
+                                            )?;
                                         }
                                     }
                                     Ok(diffs)
@@ -3634,7 +3726,8 @@ impl Coordinator {
             DataSourceDesc::Ingestion(_) => (),
             DataSourceDesc::Introspection(_)
             | DataSourceDesc::Progress
-            | DataSourceDesc::Source => {
+            | DataSourceDesc::Source
+            | DataSourceDesc::Webhook(_) => {
                 coord_bail!("cannot ALTER this type of source");
             }
         }
@@ -3653,6 +3746,106 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Source))
     }
 
+    pub(super) async fn sequence_alter_table_add_column(
+        &mut self,
+        tx: ClientTransmitter<ExecuteResponse>,
+        mut session: Session,
+        plan: AlterTableAddColumnPlan,
+        depends_on: Vec<GlobalId>,
+    ) {
+        // Holding the write lock guarantees that there are no pending writes
+        // to the table, and that none can be committed between checking its
+        // existing contents and altering it.
+        guard_write_critical_section!(self, tx, session, Plan::AlterTableAddColumn(plan));
+
+        let result = self
+            .sequence_alter_table_add_column_inner(&session, plan, depends_on)
+            .await;
+        tx.send(result, session);
+    }
+
+    async fn sequence_alter_table_add_column_inner(
+        &mut self,
+        session: &Session,
+        AlterTableAddColumnPlan { id, table }: AlterTableAddColumnPlan,
+        depends_on: Vec<GlobalId>,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        // The alteration may have been deferred behind other writes, so
+        // re-verify that the table still has the shape it was planned against.
+        let entry = self
+            .catalog()
+            .try_get_entry(&id)
+            .ok_or_else(|| AdapterError::SqlCatalog(CatalogError::UnknownItem(id.to_string())))?;
+        let full_name = self
+            .catalog()
+            .resolve_full_name(entry.name(), Some(session.conn_id()));
+        let old_table = match entry.item() {
+            CatalogItem::Table(old_table)
+                if old_table.desc.arity() + 1 == table.desc.arity()
+                    && old_table
+                        .desc
+                        .iter()
+                        .eq(table.desc.iter().take(old_table.desc.arity())) =>
+            {
+                old_table.clone()
+            }
+            _ => return Err(AdapterError::ConcurrentTableAlter(full_name.to_string())),
+        };
+
+        // Rows written before the alteration are read back with the new
+        // column's missing value, which is its default. A `NOT NULL` column
+        // without a default can therefore only be added to an empty table.
+        let (column_name, column_type) = table.desc.iter().last().expect("table has columns");
+        let missing_is_null = table
+            .missing_values
+            .iter()
+            .nth(table.desc.arity() - 1)
+            .map_or(true, |datum| datum.is_null());
+        if missing_is_null && !column_type.nullable {
+            let mut contents = self
+                .controller
+                .storage
+                .snapshot(id, self.get_local_read_ts())
+                .await?;
+            differential_dataflow::consolidation::consolidate(&mut contents);
+            if !contents.is_empty() {
+                return Err(AdapterError::ConstraintViolation(NotNullViolation(
+                    column_name.clone(),
+                )));
+            }
+        }
+
+        let mut new_depends_on = old_table.depends_on;
+        for dep in depends_on {
+            if dep != id && !new_depends_on.contains(&dep) {
+                new_depends_on.push(dep);
+            }
+        }
+        let desc = table.desc.clone();
+        let missing_values = table.missing_values.clone();
+        let table = catalog::Table {
+            create_sql: table.create_sql,
+            desc: table.desc,
+            defaults: table.defaults,
+            check_constraints: table.check_constraints,
+            unique_constraints: table.unique_constraints,
+            missing_values: table.missing_values,
+            conn_id: old_table.conn_id,
+            depends_on: new_depends_on,
+            custom_logical_compaction_window: old_table.custom_logical_compaction_window,
+            is_retained_metrics_object: old_table.is_retained_metrics_object,
+        };
+        self.catalog_transact(Some(session), vec![Op::AlterTable { id, table }])
+            .await?;
+        self.controller
+            .storage
+            .alter_table_desc(id, desc, missing_values)
+            .await
+            .unwrap_or_terminate("cannot fail to alter table");
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
     fn extract_secret(
         &mut self,
         session: &Session,
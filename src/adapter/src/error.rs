@@ -97,6 +97,18 @@ pub enum AdapterError {
     },
     /// Expression violated a column's constraint
     ConstraintViolation(NotNullViolation),
+    /// A row written to a table violated one of the table's `CHECK` constraints.
+    CheckViolation {
+        table: String,
+        constraint: String,
+    },
+    /// A write to a table would have duplicated a key of one of the table's
+    /// `PRIMARY KEY` or `UNIQUE` constraints.
+    UniqueViolation {
+        constraint: String,
+        /// The conflicting key, formatted as `(<columns>)=(<values>)`.
+        key: String,
+    },
     /// Target cluster has no replicas to service query.
     NoClusterReplicasAvailable(String),
     /// The named operation cannot be run in a transaction.
@@ -169,6 +181,12 @@ pub enum AdapterError {
         cluster_name: String,
         replica_name: String,
     },
+    /// The named webhook source does not exist.
+    UnknownWebhook {
+        database: String,
+        schema: String,
+        name: String,
+    },
     /// The named setting does not exist.
     UnrecognizedConfigurationParam(String),
     /// A generic error occurred.
@@ -204,6 +222,17 @@ pub enum AdapterError {
     ///
     /// The map keys are role names and values are detailed error messages.
     DependentObject(BTreeMap<String, Vec<String>>),
+    /// A table was altered by another session while a statement was using its
+    /// previous shape.
+    ConcurrentTableAlter(String),
+    /// An `ALTER TABLE` could not be applied because an object that depends on
+    /// the table cannot accommodate the change.
+    AlterTableDependent {
+        table: String,
+        dependent_type: String,
+        dependent: String,
+        reason: String,
+    },
 }
 
 impl AdapterError {
@@ -264,6 +293,8 @@ impl AdapterError {
             AdapterError::PlanError(e) => e.detail(),
             AdapterError::VarError(e) => e.detail(),
             AdapterError::ConcurrentRoleDrop(_) => Some("Please disconnect and re-connect with a valid role.".into()),
+            AdapterError::AlterTableDependent { reason, .. } => Some(reason.clone()),
+            AdapterError::UniqueViolation { key, .. } => Some(format!("Key {key} already exists.")),
             AdapterError::Unauthorized(unauthorized) => unauthorized.detail(),
             AdapterError::DependentObject(dependent_objects) => {
                 Some(dependent_objects
@@ -333,6 +364,7 @@ impl AdapterError {
             ),
             AdapterError::PlanError(e) => e.hint(),
             AdapterError::VarError(e) => e.hint(),
+            AdapterError::ConcurrentTableAlter(_) => Some("Retry the transaction.".into()),
             AdapterError::UnallowedOnCluster { .. } => Some(
                 "Use `SET CLUSTER = <cluster-name>` to change your cluster and re-run the query."
                     .into(),
@@ -397,6 +429,17 @@ impl fmt::Display for AdapterError {
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
+            AdapterError::CheckViolation { table, constraint } => write!(
+                f,
+                "new row for relation {} violates check constraint {}",
+                table.quoted(),
+                constraint.quoted()
+            ),
+            AdapterError::UniqueViolation { constraint, .. } => write!(
+                f,
+                "duplicate key value violates unique constraint {}",
+                constraint.quoted()
+            ),
             AdapterError::NoClusterReplicasAvailable(cluster) => {
                 write!(
                     f,
@@ -497,6 +540,14 @@ impl fmt::Display for AdapterError {
                 f,
                 "cluster replica '{cluster_name}.{replica_name}' does not exist"
             ),
+            AdapterError::UnknownWebhook {
+                database,
+                schema,
+                name,
+            } => write!(
+                f,
+                "webhook source '{database}.{schema}.{name}' does not exist"
+            ),
             AdapterError::UnrecognizedConfigurationParam(setting_name) => write!(
                 f,
                 "unrecognized configuration parameter {}",
@@ -517,6 +568,20 @@ impl fmt::Display for AdapterError {
             AdapterError::ConcurrentRoleDrop(role_id) => {
                 write!(f, "role {role_id} was concurrently dropped")
             }
+            AdapterError::ConcurrentTableAlter(name) => {
+                write!(f, "table {} was concurrently altered", name.quoted())
+            }
+            AdapterError::AlterTableDependent {
+                table,
+                dependent_type,
+                dependent,
+                ..
+            } => write!(
+                f,
+                "cannot alter table {} because {dependent_type} {} depends on it",
+                table.quoted(),
+                dependent.quoted()
+            ),
             AdapterError::DependentObject(dependent_objects) => {
                 let role_str = if dependent_objects.keys().count() == 1 {
                     "role"
@@ -97,6 +97,7 @@ where
         StatementKind::CreateSchema => "create_schema",
         StatementKind::CreateSource => "create_source",
         StatementKind::CreateSubsource => "create_subsource",
+        StatementKind::CreateWebhookSource => "create_webhook_source",
         StatementKind::CreateSink => "create_sink",
         StatementKind::CreateView => "create_view",
         StatementKind::CreateMaterializedView => "create_materialized_view",
@@ -109,6 +110,7 @@ where
         StatementKind::CreateSecret => "create_secret",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterIndex => "alter_index",
+        StatementKind::AlterTable => "alter_table",
         StatementKind::AlterRole => "alter_role",
         StatementKind::AlterSecret => "alter_secret",
         StatementKind::AlterSink => "alter_sink",
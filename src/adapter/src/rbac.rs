@@ -25,17 +25,17 @@ use mz_sql::plan::{
     AbortTransactionPlan, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan,
     AlterItemRenamePlan, AlterNoopPlan, AlterOwnerPlan, AlterRolePlan, AlterSecretPlan,
     AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, ClosePlan, CommitTransactionPlan, CopyFromPlan, CopyRowsPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateConnectionPlan, CreateDatabasePlan,
-    CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan,
-    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateSourcePlans, CreateTablePlan,
-    CreateTypePlan, CreateViewPlan, DeallocatePlan, DeclarePlan, DropObjectsPlan, ExecutePlan,
-    ExplainPlan, FetchPlan, GrantPrivilegePlan, GrantRolePlan, InsertPlan, MutationKind, PeekPlan,
-    Plan, PlannedRoleAttributes, PreparePlan, RaisePlan, ReadThenWritePlan, ResetVariablePlan,
-    RevokePrivilegePlan, RevokeRolePlan, RotateKeysPlan, SetVariablePlan, ShowCreatePlan,
-    ShowVariablePlan, SourceSinkClusterConfig, StartTransactionPlan, SubscribePlan,
+    AlterSystemSetPlan, AlterTableAddColumnPlan, ClosePlan, CommitTransactionPlan, CopyFromPlan,
+    CopyRowsPlan, CreateClusterPlan, CreateClusterReplicaPlan, CreateConnectionPlan,
+    CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateRolePlan,
+    CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateSourcePlans,
+    CreateTablePlan, CreateTypePlan, CreateViewPlan, DeallocatePlan, DeclarePlan, DropObjectsPlan,
+    ExecutePlan, ExplainPlan, FetchPlan, GrantPrivilegePlan, GrantRolePlan, InsertPlan,
+    MutationKind, PeekPlan, Plan, PlannedRoleAttributes, PreparePlan, RaisePlan, ReadThenWritePlan,
+    ResetVariablePlan, RevokePrivilegePlan, RevokeRolePlan, RotateKeysPlan, SetVariablePlan,
+    ShowCreatePlan, ShowVariablePlan, SourceSinkClusterConfig, StartTransactionPlan, SubscribePlan,
 };
-use mz_sql::session::user::{INTROSPECTION_USER, SYSTEM_USER};
+use mz_sql::session::user::{User, INTROSPECTION_USER, SYSTEM_USER};
 use mz_sql::session::vars::SystemVars;
 use mz_sql_parser::ast::{ObjectType, QualifiedReplica};
 
@@ -134,6 +134,9 @@ pub fn check_command(catalog: &Catalog, cmd: &Command) -> Result<(), Unauthorize
         | Command::Execute { .. }
         | Command::Commit { .. }
         | Command::CancelRequest { .. }
+        | Command::GetRolePassword { .. }
+        | Command::GetWebhook { .. }
+        | Command::AppendWebhook { .. }
         | Command::CopyRows { .. }
         | Command::GetSystemVars { .. }
         | Command::SetSystemVars { .. }
@@ -141,6 +144,40 @@ pub fn check_command(catalog: &Catalog, cmd: &Command) -> Result<(), Unauthorize
     }
 }
 
+/// Checks if `user` is authorized to append to the webhook source `id`. If not, an error is
+/// returned.
+///
+/// Sources cannot be granted the INSERT privilege, so appending to a webhook source instead
+/// requires membership in the role that owns the source, along with USAGE on its schema.
+pub fn check_webhook(catalog: &Catalog, user: &User, id: GlobalId) -> Result<(), AdapterError> {
+    if !is_rbac_enabled_for_system(catalog.system_config()) || user.is_superuser() {
+        return Ok(());
+    }
+
+    let Some(role) = catalog.try_get_role_by_name(&user.name) else {
+        return Err(AdapterError::UnknownLoginRole(user.name.clone()));
+    };
+    let role_id = role.id;
+    let catalog = catalog.for_sessionless_user(role_id);
+    let role_membership = catalog.collect_role_membership(&role_id);
+
+    let object_id = ObjectId::Item(id);
+    if !check_owner_roles(&object_id, &role_membership, &catalog) {
+        ownership_err(vec![object_id], &catalog)?;
+    }
+
+    let schema_id: ObjectId = catalog.get_item(&id).name().qualifiers.clone().into();
+    let mut role_memberships = BTreeMap::new();
+    role_memberships.insert(role_id, role_membership);
+    check_object_privileges(
+        &catalog,
+        vec![(schema_id, AclMode::USAGE, role_id)],
+        role_memberships,
+    )?;
+
+    Ok(())
+}
+
 /// Checks if a session is authorized to execute a plan. If not, an error is returned.
 pub fn check_plan(
     catalog: &impl SessionCatalog,
@@ -306,6 +343,7 @@ fn generate_required_plan_attribute(plan: &Plan) -> Vec<Attribute> {
         | Plan::AlterSource(_)
         | Plan::AlterItemRename(_)
         | Plan::AlterSecret(_)
+        | Plan::AlterTableAddColumn(_)
         | Plan::AlterSystemSet(_)
         | Plan::AlterSystemReset(_)
         | Plan::AlterSystemResetAll(_)
@@ -483,6 +521,7 @@ fn generate_required_ownership(plan: &Plan) -> Vec<ObjectId> {
         Plan::AlterSource(plan) => vec![ObjectId::Item(plan.id)],
         Plan::AlterItemRename(plan) => vec![ObjectId::Item(plan.id)],
         Plan::AlterSecret(plan) => vec![ObjectId::Item(plan.id)],
+        Plan::AlterTableAddColumn(plan) => vec![ObjectId::Item(plan.id)],
         Plan::RotateKeys(plan) => vec![ObjectId::Item(plan.id)],
         Plan::AlterOwner(plan) => vec![plan.id.clone()],
         Plan::GrantPrivilege(plan) => vec![plan.object_id.clone()],
@@ -928,6 +967,11 @@ fn generate_required_privileges(
             object_type: _,
         })
         | Plan::AlterSecret(AlterSecretPlan { id, secret_as: _ })
+        | Plan::AlterTableAddColumn(AlterTableAddColumnPlan {
+            id,
+            table: _,
+            default: _,
+        })
         | Plan::RotateKeys(RotateKeysPlan { id }) => {
             let item = catalog.get_item(id);
             vec![(
@@ -155,6 +155,8 @@ async fn datadriven() {
                                             create_sql: "TODO".to_string(),
                                             desc: RelationDesc::empty(),
                                             defaults: vec![Expr::null(); 0],
+                                            check_constraints: vec![],
+                                            unique_constraints: vec![],
                                             conn_id: None,
                                             depends_on: vec![],
                                             custom_logical_compaction_window: None,
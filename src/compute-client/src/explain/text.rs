@@ -282,6 +282,9 @@ impl DisplayText<PlanRenderingContext<'_, Plan>> for Plan {
                         if let Some(limit) = &plan.limit {
                             write!(f, " limit={}", limit)?;
                         }
+                        if plan.with_ties {
+                            write!(f, " with_ties")?;
+                        }
                         if plan.must_consolidate {
                             write!(f, " must_consolidate")?;
                         }
@@ -302,6 +305,9 @@ impl DisplayText<PlanRenderingContext<'_, Plan>> for Plan {
                         if &plan.offset > &0 {
                             write!(f, " offset={}", plan.offset)?;
                         }
+                        if plan.with_ties {
+                            write!(f, " with_ties")?;
+                        }
                     }
                 }
                 writeln!(f)?;
@@ -1370,6 +1370,7 @@ This is not expected to cause incorrect results, but could indicate a performanc
                 offset,
                 monotonic,
                 expected_group_size,
+                with_ties,
             } => {
                 let arity = input.arity();
                 let (input, keys) = Self::from_mir_inner(input, arrangements, debug_info)?;
@@ -1382,6 +1383,7 @@ This is not expected to cause incorrect results, but could indicate a performanc
                     arity,
                     *monotonic,
                     *expected_group_size,
+                    *with_ties,
                 );
 
                 // We don't have an MFP here -- install an operator to permute the
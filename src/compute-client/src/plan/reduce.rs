@@ -39,7 +39,8 @@
 //!    of the input, and then compute the reduction again on those results. For example:
 //!    `min[2, 5, 1, 10]` is the same as `min[ min[2, 5], min[1, 10]]`. When we compute hierarchical
 //!    reductions this way, we can maintain the computation in sublinear time with respect to
-//!    the overall input. `min` and `max` are two examples of hierarchical reductions. More formally,
+//!    the overall input. `min` and `max` are two examples of hierarchical reductions, as is merging
+//!    the fixed-size HyperLogLog sketches that back `approx_count_distinct`. More formally,
 //!    hierarchical reductions correspond to instances of semigroups, in that they are associative,
 //!    but in order to benefit from being computed hierarchically, they need to have some reduction
 //!    in data size as well. A function like "concat-everything-to-a-string" wouldn't benefit from
@@ -927,7 +928,8 @@ fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::MinString
         | AggregateFunc::MinDate
         | AggregateFunc::MinTimestamp
-        | AggregateFunc::MinTimestampTz => ReductionType::Hierarchical,
+        | AggregateFunc::MinTimestampTz
+        | AggregateFunc::HllMerge => ReductionType::Hierarchical,
         AggregateFunc::JsonbAgg { .. }
         | AggregateFunc::JsonbObjectAgg { .. }
         | AggregateFunc::ArrayConcat { .. }
@@ -935,9 +937,25 @@ fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::StringAgg { .. }
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::DenseRank { .. }
+        | AggregateFunc::Rank { .. }
+        | AggregateFunc::PercentRank { .. }
+        | AggregateFunc::CumeDist { .. }
         | AggregateFunc::LagLead { .. }
         | AggregateFunc::FirstValue { .. }
-        | AggregateFunc::LastValue { .. } => ReductionType::Basic,
+        | AggregateFunc::LastValue { .. }
+        | AggregateFunc::Ntile { .. }
+        | AggregateFunc::NthValue { .. }
+        | AggregateFunc::WindowAggregate { .. } => ReductionType::Basic,
+        // Ordered-set aggregates can't be hierarchical. A hierarchical reduction
+        // applies the aggregation function to the results of aggregating
+        // subsets of the group, which only works for functions like `min` and
+        // `max` that select a value regardless of the rest of the group.
+        // Percentiles depend on the position of a value within the whole
+        // sorted group, and the mode on the number of occurrences of each
+        // value, neither of which survives reducing a subset to one value.
+        AggregateFunc::PercentileCont { .. }
+        | AggregateFunc::PercentileDisc { .. }
+        | AggregateFunc::Mode { .. } => ReductionType::Basic,
     }
 }
 
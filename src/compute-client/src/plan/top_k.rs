@@ -49,6 +49,7 @@ impl TopKPlan {
     /// * `arity` - The number of columns in the input and output.
     /// * `monotonic` - `true` if the input is monotonic.
     /// * `expected_group_size` - A hint about how many rows will have the same group key.
+    /// * `with_ties` - `true` if rows tying with the last row within `limit` should be revealed.
     pub(crate) fn create_from(
         group_key: Vec<usize>,
         order_key: Vec<ColumnOrder>,
@@ -57,8 +58,9 @@ impl TopKPlan {
         arity: usize,
         monotonic: bool,
         expected_group_size: Option<u64>,
+        with_ties: bool,
     ) -> Self {
-        if monotonic && offset == 0 && limit == Some(1) {
+        if monotonic && offset == 0 && limit == Some(1) && !with_ties {
             TopKPlan::MonotonicTop1(MonotonicTop1Plan {
                 group_key,
                 order_key,
@@ -79,6 +81,7 @@ impl TopKPlan {
                 limit,
                 arity,
                 must_consolidate: false,
+                with_ties,
             })
         } else {
             // A plan for all other inputs
@@ -89,6 +92,7 @@ impl TopKPlan {
                 limit,
                 arity,
                 buckets: bucketing_of_expected_group_size(expected_group_size),
+                with_ties,
             })
         }
     }
@@ -99,7 +103,7 @@ impl TopKPlan {
         match self {
             TopKPlan::Basic(plan) => {
                 if plan.offset == 0 {
-                    *self = if plan.limit == Some(1) {
+                    *self = if plan.limit == Some(1) && !plan.with_ties {
                         TopKPlan::MonotonicTop1(MonotonicTop1Plan {
                             group_key: plan.group_key.clone(),
                             order_key: plan.order_key.clone(),
@@ -112,6 +116,7 @@ impl TopKPlan {
                             limit: plan.limit,
                             arity: plan.arity,
                             must_consolidate,
+                            with_ties: plan.with_ties,
                         })
                     }
                 }
@@ -212,6 +217,9 @@ pub struct MonotonicTopKPlan {
     /// and the operator must first consolidate the inputs to remove
     /// potential negations.
     pub must_consolidate: bool,
+    /// True if records tying with the last record within `limit` should
+    /// be produced as well.
+    pub with_ties: bool,
 }
 
 impl RustType<ProtoMonotonicTopKPlan> for MonotonicTopKPlan {
@@ -222,6 +230,7 @@ impl RustType<ProtoMonotonicTopKPlan> for MonotonicTopKPlan {
             limit: self.limit.into_proto(),
             arity: self.arity.into_proto(),
             must_consolidate: self.must_consolidate.into_proto(),
+            with_ties: self.with_ties.into_proto(),
         }
     }
 
@@ -232,6 +241,7 @@ impl RustType<ProtoMonotonicTopKPlan> for MonotonicTopKPlan {
             limit: proto.limit.into_rust()?,
             arity: proto.arity.into_rust()?,
             must_consolidate: proto.must_consolidate.into_rust()?,
+            with_ties: proto.with_ties.into_rust()?,
         })
     }
 }
@@ -255,6 +265,9 @@ pub struct BasicTopKPlan {
     pub arity: usize,
     /// Bucket sizes for hierarchical stages of TopK.  Should be decreasing.
     pub buckets: Vec<u64>,
+    /// True if records tying with the last record within `limit` should
+    /// be produced as well.
+    pub with_ties: bool,
 }
 
 impl RustType<ProtoBasicTopKPlan> for BasicTopKPlan {
@@ -266,6 +279,7 @@ impl RustType<ProtoBasicTopKPlan> for BasicTopKPlan {
             offset: self.offset.into_proto(),
             arity: self.arity.into_proto(),
             buckets: self.buckets.into_proto(),
+            with_ties: self.with_ties.into_proto(),
         }
     }
 
@@ -277,6 +291,7 @@ impl RustType<ProtoBasicTopKPlan> for BasicTopKPlan {
             offset: proto.offset.into_rust()?,
             arity: proto.arity.into_rust()?,
             buckets: proto.buckets.into_rust()?,
+            with_ties: proto.with_ties.into_rust()?,
         })
     }
 }
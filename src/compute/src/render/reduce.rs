@@ -540,13 +540,16 @@ where
                 &format!("len={}", aggrs.len()),
             )
         }
-        let mut err_output = None;
+        let mut err_output: Option<Collection<S, DataflowError, Diff>> = None;
         let mut to_collect = Vec::new();
         for (index, aggr) in aggrs {
             let (result, errs) =
                 self.build_basic_aggregate(input.clone(), index, &aggr, err_output.is_none());
-            if errs.is_some() {
-                err_output = errs
+            if let Some(errs) = errs {
+                err_output = Some(match err_output {
+                    Some(err_output) => err_output.concat(&errs),
+                    None => errs,
+                });
             }
             to_collect
                 .push(result.as_collection(move |key, val| (key.clone(), (index, val.clone()))));
@@ -572,7 +575,8 @@ where
 
     /// Build the dataflow to compute a single basic aggregation.
     ///
-    /// This method also applies distinctness if required.
+    /// This method also applies distinctness if required, and reports the
+    /// errors of aggregation functions that can fail even when not `validating`.
     fn build_basic_aggregate<S>(
         &self,
         input: Collection<S, (Row, Row), Diff>,
@@ -623,6 +627,27 @@ where
 
         let arranged =
             partial.arrange_named::<RowSpine<_, Row, _, _>>("Arranged ReduceInaccumulable");
+
+        // The output of the reduction can only hold values, so aggregation functions that can
+        // fail are evaluated a second time to report their errors.
+        let eval_errs = func.can_error().then(|| {
+            let func = func.clone();
+            arranged
+                .reduce_abelian::<_, ErrValSpine<_, _, _>>(
+                    "ReduceInaccumulable Eval Errors",
+                    move |_key, source, target| {
+                        let iter = source.iter().flat_map(|(v, w)| {
+                            let count = usize::try_from(*w).unwrap_or(0);
+                            std::iter::repeat(v.iter().next().unwrap()).take(count)
+                        });
+                        if let Err(e) = func.try_eval(iter, &RowArena::new()) {
+                            target.push((e.into(), 1));
+                        }
+                    },
+                )
+                .as_collection(|_, v| v.clone())
+        });
+
         let oks = arranged.reduce_abelian::<_, RowSpine<_, _, _, _>>("ReduceInaccumulable", {
             let mut row_buf = Row::default();
             move |_key, source, target| {
@@ -664,10 +689,16 @@ where
                     }
                 },
             );
-            (oks, Some(errs.as_collection(|_, v| v.clone())))
-        } else {
-            (oks, err_output)
+            err_output = Some(errs.as_collection(|_, v| v.clone()));
+        }
+
+        if let Some(eval_errs) = eval_errs {
+            err_output = Some(match err_output {
+                Some(err_output) => err_output.concat(&eval_errs),
+                None => eval_errs,
+            });
         }
+        (oks, err_output)
     }
 
     fn build_reduce_inaccumulable_distinct<S, R>(
@@ -973,7 +1004,7 @@ where
                     for monoid in accum.iter() {
                         use ReductionMonoid::*;
                         match monoid {
-                            Min(row) | Max(row) => row_packer.extend(row.iter()),
+                            Min(row) | Max(row) | Hll(row) => row_packer.extend(row.iter()),
                         }
                     }
                     output.push((row_buf.clone(), 1));
@@ -1779,13 +1810,15 @@ pub mod monoids {
 
     use mz_expr::AggregateFunc;
     use mz_ore::soft_panic_or_log;
-    use mz_repr::{Datum, Diff, Row};
+    use mz_repr::{Datum, Diff, Row, RowArena};
 
     /// A monoid containing a single-datum row.
     #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Clone, Serialize, Deserialize, Hash)]
     pub enum ReductionMonoid {
         Min(Row),
         Max(Row),
+        /// A row containing a HyperLogLog sketch, or `Datum::Null`.
+        Hll(Row),
     }
 
     impl Multiply<Diff> for ReductionMonoid {
@@ -1834,6 +1867,21 @@ pub mod monoids {
                         lhs.clone_from(rhs);
                     }
                 }
+                (ReductionMonoid::Hll(lhs), ReductionMonoid::Hll(rhs)) => {
+                    // Datum::Null is the identity, not an empty sketch.
+                    let merged = match (lhs.unpack_first(), rhs.unpack_first()) {
+                        (_, Datum::Null) => None,
+                        (Datum::Null, _) => Some(rhs.clone()),
+                        (lhs_val, rhs_val) => {
+                            let arena = RowArena::new();
+                            let merged = AggregateFunc::HllMerge.eval([lhs_val, rhs_val], &arena);
+                            Some(Row::pack_slice(&[merged]))
+                        }
+                    };
+                    if let Some(merged) = merged {
+                        *lhs = merged;
+                    }
+                }
                 (lhs, rhs) => {
                     soft_panic_or_log!(
                         "Mismatched monoid variants in reduction! lhs: {lhs:?} rhs: {rhs:?}"
@@ -1881,6 +1929,7 @@ pub mod monoids {
             | AggregateFunc::MinDate
             | AggregateFunc::MinTimestamp
             | AggregateFunc::MinTimestampTz => Some(ReductionMonoid::Min(row)),
+            AggregateFunc::HllMerge => Some(ReductionMonoid::Hll(row)),
             AggregateFunc::SumInt16
             | AggregateFunc::SumInt32
             | AggregateFunc::SumInt64
@@ -1901,9 +1950,18 @@ pub mod monoids {
             | AggregateFunc::StringAgg { .. }
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::Rank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
-            | AggregateFunc::LastValue { .. } => None,
+            | AggregateFunc::LastValue { .. }
+            | AggregateFunc::Ntile { .. }
+            | AggregateFunc::NthValue { .. }
+            | AggregateFunc::WindowAggregate { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => None,
         }
     }
 }
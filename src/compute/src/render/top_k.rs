@@ -12,6 +12,7 @@
 //! Consult [TopKPlan] documentation for details.
 
 use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::rc::Rc;
 
@@ -77,6 +78,7 @@ where
                     arity,
                     limit,
                     must_consolidate,
+                    with_ties,
                 }) => {
                     // Map the group key along with the row and consolidate if required to do so.
                     let mut datum_vec = mz_repr::DatumVec::new();
@@ -115,7 +117,7 @@ where
                     // 2. Then, we can do inter-timestamp thinning by feeding back negations for
                     //    any records that have been invalidated.
                     let collection = if let Some(limit) = limit {
-                        render_intra_ts_thinning(collection, order_key.clone(), limit)
+                        render_intra_ts_thinning(collection, order_key.clone(), limit, with_ties)
                     } else {
                         collection
                     };
@@ -146,8 +148,9 @@ where
                     // intra-ts thinning. The maximum number of records per timestamp is
                     // (num_workers * limit), which we expect to be a small number and so we render
                     // a single topk stage.
-                    let (result, errs) =
-                        self.build_topk_stage(thinned, order_key, 1u64, 0, limit, arity, false);
+                    let (result, errs) = self.build_topk_stage(
+                        thinned, order_key, 1u64, 0, limit, arity, false, with_ties,
+                    );
                     retractions.set(&collection.concat(&result.negate()));
                     soft_assert_or_log!(
                         errs.is_none(),
@@ -163,9 +166,10 @@ where
                     limit,
                     arity,
                     buckets,
+                    with_ties,
                 }) => {
                     let (oks, errs) = self.build_topk(
-                        ok_input, group_key, order_key, offset, limit, arity, buckets,
+                        ok_input, group_key, order_key, offset, limit, arity, buckets, with_ties,
                     );
                     err_collection = err_collection.concat(&errs);
                     oks
@@ -188,6 +192,7 @@ where
         limit: Option<usize>,
         arity: usize,
         buckets: Vec<u64>,
+        with_ties: bool,
     ) -> (Collection<S, Row, Diff>, Collection<S, DataflowError, Diff>)
     where
         S: Scope<Timestamp = G::Timestamp>,
@@ -218,7 +223,8 @@ where
             for bucket in buckets.into_iter() {
                 // here we do not apply `offset`, but instead restrict ourself with a limit
                 // that includes the offset. We cannot apply `offset` until we perform the
-                // final, complete reduction.
+                // final, complete reduction. Retaining ties in each bucket is sufficient to
+                // retain all ties in the final reduction.
                 let (oks, errs) = self.build_topk_stage(
                     collection,
                     order_key.clone(),
@@ -227,6 +233,7 @@ where
                     Some(offset + limit),
                     arity,
                     validating,
+                    with_ties,
                 );
                 collection = oks;
                 if validating {
@@ -240,7 +247,7 @@ where
         // apply `offset` to the final group, as we have not yet been applying it to the partially
         // formed groups.
         let (oks, errs) = self.build_topk_stage(
-            collection, order_key, 1u64, offset, limit, arity, validating,
+            collection, order_key, 1u64, offset, limit, arity, validating, with_ties,
         );
         collection = oks;
         if validating {
@@ -269,6 +276,7 @@ where
         limit: Option<usize>,
         arity: usize,
         validating: bool,
+        with_ties: bool,
     ) -> (
         Collection<S, ((Row, u64), Row), Diff>,
         Option<Collection<S, DataflowError, Diff>>,
@@ -279,7 +287,7 @@ where
         let input = collection.map(move |((key, hash), row)| ((key, hash % modulus), row));
         let (oks, errs) = if validating {
             let stage = build_topk_negated_stage::<S, Result<Row, Row>>(
-                &input, order_key, offset, limit, arity,
+                &input, order_key, offset, limit, arity, with_ties,
             );
 
             let error_logger = self.error_logger();
@@ -295,7 +303,9 @@ where
             (oks, Some(errs))
         } else {
             (
-                build_topk_negated_stage::<S, Row>(&input, order_key, offset, limit, arity),
+                build_topk_negated_stage::<S, Row>(
+                    &input, order_key, offset, limit, arity, with_ties,
+                ),
                 None,
             )
         };
@@ -380,6 +390,7 @@ fn build_topk_negated_stage<G, R>(
     offset: usize,
     limit: Option<usize>,
     arity: usize,
+    with_ties: bool,
 ) -> Collection<G, ((Row, u64), R), Diff>
 where
     G: Scope,
@@ -443,6 +454,8 @@ where
 
                 // We now need to lay out the data in order of `buffer`, but respecting
                 // the `offset` and `limit` constraints.
+                // The index of the last record produced, to identify ties with it.
+                let mut last = None;
                 for index in indexes.into_iter() {
                     let (row, mut diff) = source[index];
                     if !diff.is_positive() {
@@ -454,18 +467,32 @@ where
                         offset -= to_skip;
                         diff -= Diff::try_from(to_skip).unwrap();
                     }
-                    // We should produce at most `limit` records.
+                    // We should produce at most `limit` records, unless they tie with
+                    // the last record produced.
                     // TODO(benesch): avoid dangerous `as` conversion.
                     #[allow(clippy::as_conversions)]
                     if let Some(limit) = &mut limit {
-                        diff = std::cmp::min(diff, Diff::try_from(*limit).unwrap());
-                        *limit -= diff as usize;
+                        let in_limit = std::cmp::min(diff, Diff::try_from(*limit).unwrap());
+                        *limit -= in_limit as usize;
+                        let is_tie = with_ties
+                            && (in_limit > 0
+                                || last.map_or(false, |last: usize| {
+                                    let last = &buffer[last * width..][..width];
+                                    let this = &buffer[index * width..][..width];
+                                    mz_expr::compare_columns(&order_key, last, this, || {
+                                        Ordering::Equal
+                                    }) == Ordering::Equal
+                                }));
+                        if !is_tie {
+                            diff = in_limit;
+                        }
                     }
                     // Output the indicated number of rows.
                     if diff > 0 {
                         // Emit retractions for the elements actually part of
                         // the set of TopK elements.
                         target.push((R::ok(row.clone()), -diff));
+                        last = Some(index);
                     }
                 }
             }
@@ -477,6 +504,7 @@ fn render_intra_ts_thinning<S>(
     collection: Collection<S, (Row, Row), Diff>,
     order_key: Vec<mz_expr::ColumnOrder>,
     limit: usize,
+    with_ties: bool,
 ) -> Collection<S, (Row, Row), Diff>
 where
     S: Scope,
@@ -510,7 +538,10 @@ where
                             agg_time
                                 .entry((grp_row, record_time))
                                 .or_insert_with(move || {
-                                    topk_agg::TopKBatch::new(limit.try_into().expect("must fit"))
+                                    topk_agg::TopKBatch::new(
+                                        limit.try_into().expect("must fit"),
+                                        with_ties,
+                                    )
                                 });
                         topk.update(monoid, diff);
                     }
@@ -551,14 +582,22 @@ pub mod topk_agg {
         updates: SmallVec<[(T, i64); 16]>,
         clean: usize,
         limit: i64,
+        with_ties: bool,
+    }
+
+    /// Items whose ordering can consider distinct items to be tied.
+    pub trait Ties {
+        /// Returns true if `self` and `other` are tied in the ordering.
+        fn ties_with(&self, other: &Self) -> bool;
     }
 
-    impl<T: Ord> TopKBatch<T> {
-        pub fn new(limit: i64) -> Self {
+    impl<T: Ord + Ties> TopKBatch<T> {
+        pub fn new(limit: i64, with_ties: bool) -> Self {
             Self {
                 updates: SmallVec::new(),
                 clean: 0,
                 limit,
+                with_ties,
             }
         }
 
@@ -587,22 +626,28 @@ pub mod topk_agg {
 
                 // We can now retain only the first K records and throw away everything else
                 let mut limit = self.limit;
-                self.updates.retain(|x| {
-                    if limit > 0 {
-                        limit -= x.1;
-                        true
-                    } else {
-                        false
-                    }
-                });
+                let mut retained = 0;
+                while retained < self.updates.len() && limit > 0 {
+                    limit -= self.updates[retained].1;
+                    retained += 1;
+                }
+                // If requested, we also retain the records tying with the last retained one.
+                if self.with_ties && retained > 0 {
+                    let last = &self.updates[retained - 1].0;
+                    retained += self.updates[retained..]
+                        .iter()
+                        .take_while(|x| last.ties_with(&x.0))
+                        .count();
+                }
+                self.updates.truncate(retained);
                 // By the end of the loop above `limit` will either be:
                 // (a) Positive, in which case all updates were retained;
                 // (b) Zero, in which case we discarded all updates after limit became zero;
                 // (c) Negative, in which case the last record we retained had more copies
-                // than necessary. In this latter case, we need to do one final adjustment
-                // of the diff field of the last record so that the total sum of the diffs
-                // in the batch is K.
-                if limit < 0 {
+                // than necessary. In this latter case, unless ties are retained, we need to
+                // do one final adjustment of the diff field of the last record so that the
+                // total sum of the diffs in the batch is K.
+                if limit < 0 && !self.with_ties {
                     if let Some(item) = self.updates.last_mut() {
                         // We are subtracting the limit *negated*, therefore we are subtracting a value
                         // that is *greater* than or equal to zero, which represents the excess.
@@ -623,7 +668,7 @@ pub mod topk_agg {
         }
     }
 
-    impl<T: Ord> IntoIterator for TopKBatch<T> {
+    impl<T: Ord + Ties> IntoIterator for TopKBatch<T> {
         type Item = (T, i64);
         type IntoIter = smallvec::IntoIter<[(T, i64); 16]>;
 
@@ -755,6 +800,22 @@ pub mod monoids {
         }
     }
 
+    impl super::topk_agg::Ties for Top1MonoidLocal {
+        fn ties_with(&self, other: &Self) -> bool {
+            debug_assert!(Rc::ptr_eq(&self.shared, &other.shared));
+            let Top1MonoidShared {
+                left,
+                right,
+                order_key,
+            } = &mut *self.shared.borrow_mut();
+
+            let left = left.borrow_with(&self.row);
+            let right = right.borrow_with(&other.row);
+            mz_expr::compare_columns(order_key, &left, &right, || Ordering::Equal)
+                == Ordering::Equal
+        }
+    }
+
     impl Semigroup for Top1MonoidLocal {
         fn plus_equals(&mut self, rhs: &Self) {
             let cmp = (*self).cmp(rhs);
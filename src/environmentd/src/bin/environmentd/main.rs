@@ -167,8 +167,8 @@ pub struct Args {
     /// The address on which to listen for untrusted SQL connections.
     ///
     /// Connections on this address are subject to encryption, authentication,
-    /// and authorization as specified by the `--tls-mode` and `--frontegg-auth`
-    /// options.
+    /// and authorization as specified by the `--tls-mode`, `--frontegg-auth`,
+    /// and `--password-auth` options.
     #[clap(
         long,
         env = "SQL_LISTEN_ADDR",
@@ -179,8 +179,8 @@ pub struct Args {
     /// The address on which to listen for untrusted HTTP connections.
     ///
     /// Connections on this address are subject to encryption, authentication,
-    /// and authorization as specified by the `--tls-mode` and `--frontegg-auth`
-    /// options.
+    /// and authorization as specified by the `--tls-mode`, `--frontegg-auth`,
+    /// and `--password-auth` options.
     #[clap(
         long,
         env = "HTTP_LISTEN_ADDR",
@@ -277,6 +277,13 @@ pub struct Args {
     /// The name of the admin role in Frontegg.
     #[clap(long, env = "FRONTEGG_ADMIN_ROLE", requires = "frontegg-tenant")]
     frontegg_admin_role: Option<String>,
+    /// Require users to authenticate with the password of their role, as set
+    /// by `CREATE ROLE ... PASSWORD` or `ALTER ROLE ... PASSWORD`.
+    ///
+    /// Roles without a password cannot log in on the untrusted SQL and HTTP
+    /// addresses.
+    #[clap(long, env = "PASSWORD_AUTH", conflicts_with = "frontegg-tenant")]
+    password_auth: bool,
 
     // === Orchestrator options. ===
     /// The service orchestrator implementation to use.
@@ -783,6 +790,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         internal_http_listen_addr: args.internal_http_listen_addr,
         tls,
         frontegg,
+        password_auth: args.password_auth,
         cors_allowed_origin,
         adapter_stash_url: args.adapter_stash_url,
         controller,
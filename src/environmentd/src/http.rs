@@ -50,6 +50,7 @@ use mz_frontegg_auth::{Authentication as FronteggAuthentication, Error as Fronte
 use mz_ore::cast::u64_to_usize;
 use mz_ore::metrics::MetricsRegistry;
 use mz_ore::tracing::TracingHandle;
+use mz_sql::session::password::ScramSecret;
 use mz_sql::session::user::{ExternalUserMetadata, User, HTTP_DEFAULT_USER, SYSTEM_USER};
 
 use crate::server::{ConnectionHandler, Server};
@@ -62,6 +63,7 @@ mod memory;
 mod probe;
 mod root;
 mod sql;
+mod webhook;
 
 /// Maximum allowed size for a request.
 pub const MAX_REQUEST_SIZE: usize = u64_to_usize(2 * bytesize::MB);
@@ -70,6 +72,9 @@ pub const MAX_REQUEST_SIZE: usize = u64_to_usize(2 * bytesize::MB);
 pub struct HttpConfig {
     pub tls: Option<TlsConfig>,
     pub frontegg: Option<FronteggAuthentication>,
+    /// Whether to authenticate users against the passwords stored in the
+    /// catalog when Frontegg authentication is disabled.
+    pub password_auth: bool,
     pub adapter_client: mz_adapter::Client,
     pub allowed_origin: AllowOrigin,
 }
@@ -89,6 +94,7 @@ pub enum TlsMode {
 #[derive(Clone)]
 pub struct WsState {
     frontegg: Arc<Option<FronteggAuthentication>>,
+    password_auth: bool,
     adapter_client: mz_adapter::Client,
 }
 
@@ -103,6 +109,7 @@ impl HttpServer {
         HttpConfig {
             tls,
             frontegg,
+            password_auth,
             adapter_client,
             allowed_origin,
         }: HttpConfig,
@@ -110,6 +117,7 @@ impl HttpServer {
         let tls_mode = tls.as_ref().map(|tls| tls.mode).unwrap_or(TlsMode::Disable);
         let frontegg = Arc::new(frontegg);
         let base_frontegg = Arc::clone(&frontegg);
+        let base_passwords = password_auth.then(|| adapter_client.clone());
         let (adapter_client_tx, adapter_client_rx) = oneshot::channel();
         adapter_client_tx
             .send(adapter_client.clone())
@@ -119,7 +127,10 @@ impl HttpServer {
             .layer(DefaultBodyLimit::max(MAX_REQUEST_SIZE))
             .layer(middleware::from_fn(move |req, next| {
                 let base_frontegg = Arc::clone(&base_frontegg);
-                async move { http_auth(req, next, tls_mode, &base_frontegg).await }
+                let base_passwords = base_passwords.clone();
+                async move {
+                    http_auth(req, next, tls_mode, &base_frontegg, base_passwords.as_ref()).await
+                }
             }))
             .layer(Extension(adapter_client_rx.shared()))
             .layer(
@@ -135,13 +146,43 @@ impl HttpServer {
                     .expose_headers(Any)
                     .max_age(Duration::from_secs(60) * 60),
             );
+        // Requests to webhook sources must meet the same TLS and
+        // authentication requirements as all other requests, in addition to
+        // any validation that the source performs itself.
+        let webhook_frontegg = Arc::clone(&frontegg);
+        let webhook_passwords = password_auth.then(|| adapter_client.clone());
+        let webhook_router = Router::new()
+            .route(
+                "/api/webhook/:database/:schema/:name",
+                routing::post(webhook::handle_webhook),
+            )
+            .layer(DefaultBodyLimit::max(MAX_REQUEST_SIZE))
+            .layer(middleware::from_fn(move |req, next| {
+                let webhook_frontegg = Arc::clone(&webhook_frontegg);
+                let webhook_passwords = webhook_passwords.clone();
+                async move {
+                    http_auth(
+                        req,
+                        next,
+                        tls_mode,
+                        &webhook_frontegg,
+                        webhook_passwords.as_ref(),
+                    )
+                    .await
+                }
+            }))
+            .with_state(adapter_client.clone());
         let ws_router = Router::new()
             .route("/api/experimental/sql", routing::get(sql::handle_sql_ws))
             .with_state(WsState {
                 frontegg,
+                password_auth,
                 adapter_client,
             });
-        let router = Router::new().merge(base_router).merge(ws_router);
+        let router = Router::new()
+            .merge(base_router)
+            .merge(ws_router)
+            .merge(webhook_router);
         HttpServer { tls, router }
     }
 
@@ -360,6 +401,8 @@ enum AuthError {
     Frontegg(#[from] FronteggError),
     #[error("missing authorization header")]
     MissingHttpAuthentication,
+    #[error("password authentication failed")]
+    InvalidPassword,
     #[error("{0}")]
     MismatchedUser(&'static str),
     #[error("unexpected credentials")]
@@ -389,6 +432,7 @@ async fn http_auth<B>(
     next: Next<B>,
     tls_mode: TlsMode,
     frontegg: &Option<FronteggAuthentication>,
+    passwords: Option<&mz_adapter::Client>,
 ) -> impl IntoResponse {
     // First, extract the username from the certificate, validating that the
     // connection matches the TLS configuration along the way.
@@ -399,11 +443,11 @@ async fn http_auth<B>(
         (TlsMode::Require, ConnProtocol::Http) => return Err(AuthError::HttpsRequired),
         (TlsMode::Require, ConnProtocol::Https { .. }) => None,
     };
-    let creds = match frontegg {
-        // If no Frontegg authentication, we can use the cert's username if
-        // present, otherwise the default HTTP user.
-        None => Credentials::User(cert_user),
-        Some(_) => {
+    let creds = match (frontegg, passwords) {
+        // If no Frontegg or password authentication, we can use the cert's
+        // username if present, otherwise the default HTTP user.
+        (None, None) => Credentials::User(cert_user),
+        _ => {
             if let Some(basic) = req.headers().typed_get::<Authorization<Basic>>() {
                 if let Some(user) = cert_user {
                     if basic.username() != user {
@@ -426,7 +470,7 @@ async fn http_auth<B>(
         }
     };
 
-    let user = auth(frontegg, creds).await?;
+    let user = auth(frontegg, passwords, creds).await?;
 
     // Add the authenticated user as an extension so downstream handlers can
     // inspect it if necessary.
@@ -439,6 +483,7 @@ async fn http_auth<B>(
 async fn init_ws(
     WsState {
         frontegg,
+        password_auth,
         adapter_client,
     }: &WsState,
     ws: &mut WebSocket,
@@ -462,7 +507,7 @@ async fn init_ws(
             }
         }
     };
-    let (creds, options) = if frontegg.is_some() {
+    let (creds, options) = if frontegg.is_some() || *password_auth {
         match ws_auth {
             WebSocketAuth::Basic {
                 user,
@@ -485,7 +530,8 @@ async fn init_ws(
     } else {
         anyhow::bail!("unexpected")
     };
-    let user = auth(frontegg, creds).await?;
+    let passwords = password_auth.then_some(adapter_client);
+    let user = auth(frontegg, passwords, creds).await?;
     let mut client = AuthedClient::new(adapter_client, user).await?;
 
     // Assign any options we got from our WebSocket startup.
@@ -511,6 +557,7 @@ enum Credentials {
 
 async fn auth(
     frontegg: &Option<FronteggAuthentication>,
+    passwords: Option<&mz_adapter::Client>,
     creds: Credentials,
 ) -> Result<AuthedUser, AuthError> {
     // There are three places a username may be specified:
@@ -522,22 +569,40 @@ async fn auth(
     // We verify that if any of these are present, they must match any other
     // that is also present.
 
-    // Then, handle Frontegg authentication if required.
-    let user = match (frontegg, creds) {
-        // If no Frontegg authentication, use the requested user or the default
-        // HTTP user.
-        (None, Credentials::User(user)) => User {
+    // Then, handle Frontegg or password authentication if required.
+    let user = match (frontegg, passwords, creds) {
+        // If no Frontegg or password authentication, use the requested user or
+        // the default HTTP user.
+        (None, None, Credentials::User(user)) => User {
             name: user.unwrap_or_else(|| HTTP_DEFAULT_USER.name.to_string()),
             external_metadata: None,
         },
-        // With frontegg disabled, specifying credentials is an error.
-        (None, _) => return Err(AuthError::UnexpectedCredentials),
+        // If password authentication is enabled, verify the password against
+        // the verifier stored for the role. Unknown roles are checked against
+        // a mock verifier so that they take as long to reject as bad passwords.
+        (None, Some(adapter_client), Credentials::Password { username, password }) => {
+            let secret = adapter_client
+                .get_role_password(&username)
+                .await
+                .unwrap_or_else(|| ScramSecret::mock(&username));
+            if !secret.verify_password(&password) {
+                return Err(AuthError::InvalidPassword);
+            }
+            User {
+                name: username,
+                external_metadata: None,
+            }
+        }
+        (None, Some(_), Credentials::User(_)) => return Err(AuthError::MissingHttpAuthentication),
+        // Otherwise, with frontegg disabled, specifying credentials is an
+        // error.
+        (None, _, _) => return Err(AuthError::UnexpectedCredentials),
         // If we require Frontegg auth, fetch credentials from the HTTP auth
         // header. Basic auth comes with a username/password, where the password
         // is the client+secret pair. Bearer auth is an existing JWT that must
         // be validated. In either case, if a username was specified in the
         // client cert, it must match that of the JWT.
-        (Some(frontegg), creds) => {
+        (Some(frontegg), _, creds) => {
             let (user, token) = match creds {
                 Credentials::Password { username, password } => (
                     Some(username),
@@ -0,0 +1,90 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Helpers for handling requests to webhook sources.
+
+use std::collections::BTreeMap;
+
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use thiserror::Error;
+use tracing::{debug, warn};
+
+use mz_adapter::{AdapterError, Client};
+
+use crate::http::AuthedUser;
+
+pub async fn handle_webhook(
+    State(client): State<Client>,
+    Extension(AuthedUser(user)): Extension<AuthedUser>,
+    Path((database, schema, name)): Path<(String, String, String)>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<(), WebhookError> {
+    let webhook = client.get_webhook(&database, &schema, &name, user).await?;
+
+    // Header names are already lowercase. Headers whose values are not valid
+    // UTF-8 are dropped, as are all but the last value of repeated headers.
+    let headers: BTreeMap<_, _> = headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.as_str().to_string(), value.to_str().ok()?.into())))
+        .collect();
+
+    if !webhook
+        .validate_request(&headers, &body)
+        .await
+        .map_err(|e| WebhookError::Adapter(AdapterError::Unstructured(e)))?
+    {
+        return Err(WebhookError::ValidationFailed);
+    }
+    let row = webhook.desc.decode(&headers, &body).map_err(|e| {
+        debug!(
+            "failed to decode request to webhook source {}: {e}",
+            webhook.id
+        );
+        WebhookError::InvalidBody
+    })?;
+    client.append_webhook(webhook.id, vec![(row, 1)]).await?;
+
+    Ok(())
+}
+
+/// Errors that can occur when handling a request to a webhook source.
+#[derive(Error, Debug)]
+pub enum WebhookError {
+    #[error("request failed validation")]
+    ValidationFailed,
+    /// The details of the error are not returned to the sender, as they may
+    /// contain parts of the body.
+    #[error("invalid request body")]
+    InvalidBody,
+    #[error(transparent)]
+    Adapter(#[from] AdapterError),
+}
+
+impl IntoResponse for WebhookError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            WebhookError::ValidationFailed => StatusCode::UNAUTHORIZED,
+            WebhookError::InvalidBody => StatusCode::BAD_REQUEST,
+            WebhookError::Adapter(AdapterError::UnknownWebhook { .. }) => StatusCode::NOT_FOUND,
+            WebhookError::Adapter(
+                AdapterError::Unauthorized(_) | AdapterError::UnknownLoginRole(_),
+            ) => StatusCode::FORBIDDEN,
+            WebhookError::Adapter(e) => {
+                warn!("failed to append to webhook source: {e}");
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        };
+        (status, self.to_string()).into_response()
+    }
+}
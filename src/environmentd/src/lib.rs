@@ -107,6 +107,7 @@ use mz_ore::now::NowFn;
 use mz_ore::task;
 use mz_ore::tracing::TracingHandle;
 use mz_persist_client::usage::StorageUsageClient;
+use mz_pgwire::Authenticator;
 use mz_secrets::SecretsController;
 use mz_sql::catalog::EnvironmentId;
 use mz_storage_client::types::connections::ConnectionContext;
@@ -146,6 +147,9 @@ pub struct Config {
     pub tls: Option<TlsConfig>,
     /// Frontegg JWT authentication configuration.
     pub frontegg: Option<FronteggAuthentication>,
+    /// Whether to authenticate users against the passwords of their roles.
+    /// Ignored if Frontegg authentication is enabled.
+    pub password_auth: bool,
 
     // === Connection options. ===
     /// Configuration for source and sink connections created by the storage
@@ -401,7 +405,11 @@ pub async fn serve(config: Config) -> Result<Server, anyhow::Error> {
         let sql_server = mz_pgwire::Server::new(mz_pgwire::Config {
             tls: pgwire_tls.clone(),
             adapter_client: adapter_client.clone(),
-            frontegg: config.frontegg.clone(),
+            authenticator: match &config.frontegg {
+                Some(frontegg) => Some(Arc::new(frontegg.clone()) as Arc<dyn Authenticator>),
+                None if config.password_auth => Some(Arc::new(adapter_client.clone())),
+                None => None,
+            },
             metrics: metrics.clone(),
             internal: false,
         });
@@ -422,7 +430,7 @@ pub async fn serve(config: Config) -> Result<Server, anyhow::Error> {
                 pgwire_tls
             }),
             adapter_client: adapter_client.clone(),
-            frontegg: None,
+            authenticator: None,
             metrics,
             internal: true,
         });
@@ -434,6 +442,7 @@ pub async fn serve(config: Config) -> Result<Server, anyhow::Error> {
         let http_server = HttpServer::new(HttpConfig {
             tls: http_tls,
             frontegg: config.frontegg.clone(),
+            password_auth: config.password_auth,
             adapter_client: adapter_client.clone(),
             allowed_origin: config.cors_allowed_origin,
         });
@@ -1736,3 +1736,136 @@ fn test_auth_admin() {
         );
     }
 }
+
+#[test]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+fn test_auth_password() {
+    mz_ore::test::init_logging();
+
+    let ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+
+    let config = util::Config::default()
+        .with_tls(&server_cert, &server_key)
+        .with_password_auth();
+    let server = util::start_server(config).unwrap();
+
+    // Roles are managed via the internal port, which does not require
+    // authentication.
+    let mut system_client = server
+        .pg_config_internal()
+        .user(&SYSTEM_USER.name)
+        .connect(postgres::NoTls)
+        .unwrap();
+    system_client
+        .batch_execute("ALTER ROLE materialize PASSWORD 'hunter2'")
+        .unwrap();
+    system_client
+        .batch_execute("CREATE ROLE nopassword")
+        .unwrap();
+
+    let assert_invalid_password = |user: &'static str| -> Assert<
+        Box<dyn Fn(&tokio_postgres::error::Error)>,
+        Box<dyn Fn(&tokio_postgres::error::DbError)>,
+    > {
+        Assert::DbErr(Box::new(move |err: &tokio_postgres::error::DbError| {
+            assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+            assert_eq!(
+                err.message(),
+                format!("password authentication failed for user \"{user}\"")
+            );
+        }))
+    };
+
+    run_tests(
+        "PasswordAuth",
+        &server,
+        &[
+            // Correct password. The client and server negotiate
+            // SCRAM-SHA-256-PLUS, as the connection uses TLS.
+            TestCase::Pgwire {
+                user: "materialize",
+                password: Some("hunter2"),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: Assert::Success,
+            },
+            // Wrong or missing password.
+            TestCase::Pgwire {
+                user: "materialize",
+                password: Some("hunter3"),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: assert_invalid_password("materialize"),
+            },
+            TestCase::Pgwire {
+                user: "materialize",
+                password: None,
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: assert_invalid_password("materialize"),
+            },
+            // Roles without a password and unknown roles cannot log in.
+            TestCase::Pgwire {
+                user: "nopassword",
+                password: Some(""),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: assert_invalid_password("nopassword"),
+            },
+            TestCase::Pgwire {
+                user: "unknown",
+                password: Some("hunter2"),
+                ssl_mode: SslMode::Require,
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: assert_invalid_password("unknown"),
+            },
+            // HTTP requests must supply the password via basic auth.
+            TestCase::Http {
+                user: "materialize",
+                scheme: Scheme::HTTPS,
+                headers: &make_header(Authorization::basic("materialize", "hunter2")),
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user: "materialize",
+                scheme: Scheme::HTTPS,
+                headers: &make_header(Authorization::basic("materialize", "hunter3")),
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+            TestCase::Http {
+                user: "materialize",
+                scheme: Scheme::HTTPS,
+                headers: &HeaderMap::new(),
+                configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+                assert: Assert::Err(Box::new(|code, message| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                    assert_eq!(message, "unauthorized");
+                })),
+            },
+        ],
+    );
+
+    // Removing the password prevents the role from logging in.
+    system_client
+        .batch_execute("ALTER ROLE materialize PASSWORD NULL")
+        .unwrap();
+    run_tests(
+        "PasswordAuth after PASSWORD NULL",
+        &server,
+        &[TestCase::Pgwire {
+            user: "materialize",
+            password: Some("hunter2"),
+            ssl_mode: SslMode::Require,
+            configure: Box::new(|b| b.set_ca_file(ca.ca_cert_path())),
+            assert: assert_invalid_password("materialize"),
+        }],
+    );
+}
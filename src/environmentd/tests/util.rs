@@ -125,6 +125,7 @@ pub struct Config {
     data_directory: Option<PathBuf>,
     tls: Option<mz_environmentd::TlsConfig>,
     frontegg: Option<FronteggAuthentication>,
+    password_auth: bool,
     unsafe_mode: bool,
     workers: usize,
     now: NowFn,
@@ -144,6 +145,7 @@ impl Default for Config {
             data_directory: None,
             tls: None,
             frontegg: None,
+            password_auth: false,
             unsafe_mode: false,
             workers: 1,
             now: SYSTEM_TIME.clone(),
@@ -188,6 +190,11 @@ impl Config {
         self
     }
 
+    pub fn with_password_auth(mut self) -> Self {
+        self.password_auth = true;
+        self
+    }
+
     pub fn with_now(mut self, now: NowFn) -> Self {
         self.now = now;
         self
@@ -355,6 +362,7 @@ pub fn start_server(config: Config) -> Result<Server, anyhow::Error> {
         internal_http_listen_addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
         tls: config.tls,
         frontegg: config.frontegg,
+        password_auth: config.password_auth,
         unsafe_mode: config.unsafe_mode,
         metrics_registry: metrics_registry.clone(),
         now: config.now,
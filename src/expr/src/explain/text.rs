@@ -591,6 +591,7 @@ impl MirRelationExpr {
                 monotonic,
                 input,
                 expected_group_size,
+                with_ties,
             } => {
                 FmtNode {
                     fmt_root: |f, ctx| {
@@ -609,6 +610,9 @@ impl MirRelationExpr {
                         if offset > &0 {
                             write!(f, " offset={}", offset)?
                         }
+                        if *with_ties {
+                            write!(f, " with_ties")?;
+                        }
                         write!(f, " monotonic={}", monotonic)?;
                         if let Some(expected_group_size) = expected_group_size {
                             write!(f, " exp_group_size={}", expected_group_size)?;
@@ -105,7 +105,7 @@ pub use linear::{
     util::{join_permutations, permutation_for_arrangement},
     MapFilterProject, ProtoMapFilterProject, ProtoMfpPlan, ProtoSafeMfpPlan,
 };
-pub use relation::func::{AggregateFunc, LagLeadType, TableFunc};
+pub use relation::func::{AggregateFunc, LagLeadType, PercentileFractions, TableFunc};
 pub use relation::func::{AnalyzedRegex, CaptureGroupDesc};
 pub use relation::join_input_mapper::JoinInputMapper;
 pub use relation::{
@@ -9,14 +9,15 @@
 
 #![allow(missing_docs)]
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::iter;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 
 use chrono::{DateTime, NaiveDateTime, Utc};
 use dec::OrderedDecimal;
 use itertools::Itertools;
-use num::{CheckedAdd, Integer, Signed};
+use num::{CheckedAdd, CheckedNeg, Integer, Signed};
 use ordered_float::OrderedFloat;
 use proptest::prelude::{Arbitrary, Just};
 use proptest::strategy::{BoxedStrategy, Strategy, Union};
@@ -25,11 +26,13 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 use mz_lowertest::MzReflect;
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy, TryCastFrom};
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::hll::HyperLogLog;
 use mz_repr::adt::interval::Interval;
+use mz_repr::adt::jsonpath::JsonPath;
 use mz_repr::adt::numeric::{self, NumericMaxScale};
 use mz_repr::adt::regex::Regex as ReprRegex;
 use mz_repr::adt::timestamp::CheckedTimestamp;
@@ -37,11 +40,16 @@ use mz_repr::adt::timestamp::TimestampLike;
 use mz_repr::{ColumnName, ColumnType, Datum, Diff, RelationType, Row, RowArena, ScalarType};
 
 use crate::relation::{
-    compare_columns, proto_aggregate_func, proto_aggregate_func::ProtoColumnOrders,
+    compare_columns, proto_aggregate_func, proto_aggregate_func::proto_percentile_fractions,
+    proto_aggregate_func::ProtoColumnOrders, proto_aggregate_func::ProtoPercentileFractions,
     proto_table_func, ColumnOrder, ProtoAggregateFunc, ProtoTableFunc, WindowFrame,
     WindowFrameBound, WindowFrameUnits,
 };
-use crate::scalar::func::{add_timestamp_months, jsonb_stringify};
+use crate::scalar::func::{
+    add_interval, add_numeric, add_timestamp_months, add_timestamplike_interval,
+    build_regex_with_limit, eval_jsonb_path, jsonb_stringify, regexp_match_locations, sub_interval,
+    sub_numeric, suppress_jsonb_path_error,
+};
 use crate::EvalError;
 
 include!(concat!(env!("OUT_DIR"), "/mz_expr.relation.func.rs"));
@@ -572,6 +580,27 @@ where
         })
 }
 
+fn hll_merge<'a, I>(datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let mut sketches = datums
+        .into_iter()
+        .filter(|d| !d.is_null())
+        // Inputs are validated by the SQL layer, so only skip invalid sketches
+        // rather than erroring.
+        .filter_map(|d| HyperLogLog::decode(d.unwrap_bytes()).ok())
+        .peekable();
+    if sketches.peek().is_none() {
+        return Datum::Null;
+    }
+    let mut hll = HyperLogLog::new();
+    for sketch in sketches {
+        hll.merge(&sketch);
+    }
+    Datum::Bytes(temp_storage.push_bytes(hll.encode()))
+}
+
 fn string_agg<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -732,6 +761,145 @@ where
     })
 }
 
+fn percentile_cont<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    fractions: &PercentileFractions,
+    order_by: &[ColumnOrder],
+) -> Result<Datum<'a>, EvalError>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    percentile(
+        datums,
+        temp_storage,
+        fractions,
+        order_by,
+        percentile_cont_value,
+    )
+}
+
+fn percentile_disc<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    fractions: &PercentileFractions,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    percentile(
+        datums,
+        temp_storage,
+        fractions,
+        order_by,
+        |values, fraction| Ok(percentile_disc_value(values, fraction)),
+    )
+    .expect("percentile_disc cannot fail")
+}
+
+fn percentile_cont_value<'a>(values: &[Datum<'a>], fraction: f64) -> Result<Datum<'a>, EvalError> {
+    // Like PostgreSQL, linearly interpolate between the two values surrounding
+    // the position `fraction` of the way through the values.
+    let position = fraction * f64::cast_lossy(values.len() - 1);
+    let floor = position.floor();
+    let lower_idx = u64::try_cast_from(floor).map_or(0, usize::cast_from);
+    let upper_idx = u64::try_cast_from(position.ceil())
+        .map_or(0, usize::cast_from)
+        .min(values.len() - 1);
+    if lower_idx == upper_idx {
+        return Ok(values[lower_idx]);
+    }
+    let weight = position - floor;
+    match (values[lower_idx], values[upper_idx]) {
+        (Datum::Float64(lower), Datum::Float64(upper)) => {
+            Ok(Datum::from(lower.0 + (upper.0 - lower.0) * weight))
+        }
+        (Datum::Interval(lower), Datum::Interval(upper)) => lower
+            .checked_neg()
+            .and_then(|neg_lower| upper.checked_add(&neg_lower))
+            .and_then(|diff| diff.checked_mul(weight))
+            .and_then(|diff| lower.checked_add(&diff))
+            .map(Datum::Interval)
+            .ok_or(EvalError::IntervalOutOfRange),
+        (lower, upper) => unreachable!("percentile_cont on {:?} and {:?}", lower, upper),
+    }
+}
+
+fn percentile_disc_value<'a>(values: &[Datum<'a>], fraction: f64) -> Datum<'a> {
+    // Like PostgreSQL, return the first value whose position in the ordering is
+    // at or past `fraction` of the way through the values.
+    let position = (fraction * f64::cast_lossy(values.len())).ceil();
+    let position = u64::try_cast_from(position).map_or(0, usize::cast_from);
+    values[position.saturating_sub(1).min(values.len() - 1)]
+}
+
+/// Sorts the non-null values of an ordered-set aggregate and computes
+/// `percentile_fn` over them for each of `fractions`. Returns null if there are
+/// no non-null values.
+fn percentile<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    fractions: &PercentileFractions,
+    order_by: &[ColumnOrder],
+    percentile_fn: fn(&[Datum<'a>], f64) -> Result<Datum<'a>, EvalError>,
+) -> Result<Datum<'a>, EvalError>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let values = order_aggregate_datums(datums, order_by)
+        .filter(|d| !d.is_null())
+        .collect_vec();
+    if values.is_empty() {
+        return Ok(Datum::Null);
+    }
+    match fractions {
+        PercentileFractions::Scalar(fraction) => percentile_fn(&values, fraction.0),
+        PercentileFractions::Array(fractions) => {
+            let datums = fractions
+                .iter()
+                .map(|fraction| match fraction {
+                    Some(fraction) => percentile_fn(&values, fraction.0),
+                    None => Ok(Datum::Null),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            // Like PostgreSQL, an empty array of fractions produces the empty
+            // array, which has no dimensions.
+            let dims = if datums.is_empty() {
+                vec![]
+            } else {
+                vec![ArrayDimension {
+                    lower_bound: 1,
+                    length: datums.len(),
+                }]
+            };
+            Ok(temp_storage.make_datum(|packer| {
+                packer.push_array(&dims, datums).unwrap();
+            }))
+        }
+    }
+}
+
+fn mode<'a, I>(datums: I, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Equal values are adjacent once sorted, so the most frequent value is the
+    // one with the longest run. Like PostgreSQL, ties go to the value that
+    // sorts first.
+    let mut mode = Datum::Null;
+    let mut mode_count = 0;
+    let values = order_aggregate_datums(datums, order_by).filter(|d| !d.is_null());
+    for (value, run) in &values.group_by(|d| *d) {
+        let count = run.count();
+        if count > mode_count {
+            mode = value;
+            mode_count = count;
+        }
+    }
+    mode
+}
+
 fn row_number<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -791,6 +959,100 @@ where
     })
 }
 
+/// Sorts the input of a scalar window function according to the ORDER BY expressions, and returns
+/// each original row along with the bounds of its peer group, i.e., the index of its first row and
+/// one past the index of its last row.
+fn order_datums_with_peer_groups<'a, I>(
+    datums: I,
+    order_by: &[ColumnOrder],
+) -> Vec<(Datum<'a>, Range<usize>)>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Keep the row used for ordering around, as it determines the peer groups
+    let datums = order_aggregate_datums_with_rank(datums, order_by)
+        .flat_map(|(d0, row)| d0.unwrap_list().iter().map(move |d1| (d1, row.clone())))
+        .collect_vec();
+
+    let mut result = Vec::with_capacity(datums.len());
+    let mut start = 0;
+    while start < datums.len() {
+        let peers = datums[start..]
+            .iter()
+            .take_while(|(_, row)| *row == datums[start].1)
+            .count();
+        let peer_group = start..start + peers;
+        result.extend(
+            datums[peer_group.clone()]
+                .iter()
+                .map(|(d, _)| (*d, peer_group.clone())),
+        );
+        start += peers;
+    }
+    result
+}
+
+fn rank<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // The rank of a row is the position of the first row of its peer group
+    let datums = order_datums_with_peer_groups(datums, order_by)
+        .into_iter()
+        .map(|(d, peer_group)| {
+            let rank = i64::cast_from(peer_group.start) + 1;
+            temp_storage.make_datum(|packer| {
+                packer.push_list(vec![Datum::Int64(rank), d]);
+            })
+        });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+fn percent_rank<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // The relative rank of a row is (rank - 1) / (partition rows - 1), or 0 for a single row
+    let datums = order_datums_with_peer_groups(datums, order_by);
+    let count = datums.len();
+    let datums = datums.into_iter().map(|(d, peer_group)| {
+        let percent_rank = if count > 1 {
+            f64::cast_lossy(peer_group.start) / f64::cast_lossy(count - 1)
+        } else {
+            0.0
+        };
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![Datum::Float64(percent_rank.into()), d]);
+        })
+    });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+fn cume_dist<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // The cumulative distribution of a row is the fraction of rows that precede it or are its peers
+    let datums = order_datums_with_peer_groups(datums, order_by);
+    let count = datums.len();
+    let datums = datums.into_iter().map(|(d, peer_group)| {
+        let cume_dist = f64::cast_lossy(peer_group.end) / f64::cast_lossy(count);
+        temp_storage.make_datum(|packer| {
+            packer.push_list(vec![Datum::Float64(cume_dist.into()), d]);
+        })
+    });
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
 // The expected input is in the format of [((OriginalRow, EncodedArgs), OrderByExprs...)]
 fn lag_lead<'a, I>(
     datums: I,
@@ -861,6 +1123,233 @@ where
     })
 }
 
+/// Computes the window frame of each row of a partition, as a range of indexes
+/// into the partition.
+///
+/// `order_by_rows` contains the ORDER BY columns of each row of the partition,
+/// sorted according to `order_by`. In RANGE mode, offset bounds are not
+/// described by their offset: they require a single ORDER BY column, and the
+/// ORDER BY columns of each row are followed by the offset of the start bound
+/// (if it is an offset bound) and the offset of the end bound (if it is an
+/// offset bound), which have the type of the ORDER BY column or are intervals.
+fn window_frame_ranges(
+    order_by_rows: &[Row],
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Vec<Range<usize>> {
+    let length = order_by_rows.len();
+
+    // The index of the first row of each peer group, followed by the length of the partition.
+    // The peer group is the group of rows with the same ORDER BY value.
+    let mut group_starts = vec![];
+    // The peer group of each row
+    let mut groups = Vec::with_capacity(length);
+    for (idx, row) in order_by_rows.iter().enumerate() {
+        if idx == 0 || order_by_rows[idx - 1] != *row {
+            group_starts.push(idx);
+        }
+        groups.push(group_starts.len() - 1);
+    }
+    let group_count = group_starts.len();
+    group_starts.push(length);
+
+    // In RANGE mode, the index of the offset of the start and end bounds of each row
+    let range_start_idx = order_by.len();
+    let range_end_idx = match window_frame.start_bound {
+        WindowFrameBound::OffsetPreceding(_) | WindowFrameBound::OffsetFollowing(_) => {
+            range_start_idx + 1
+        }
+        _ => range_start_idx,
+    };
+    // Finds the first row that does not sort before (or, if `include_equal` is false, the first
+    // row that sorts after) the value of the bound of the row at `idx` whose offset is at
+    // `offset_idx`, and that precedes or follows the row according to `preceding`
+    let range_partition_point =
+        |idx: usize, offset_idx: usize, preceding: bool, include_equal: bool| {
+            let order = ColumnOrder {
+                column: 0,
+                ..order_by[0].clone()
+            };
+            let value = order_by_rows[idx].iter().nth(order_by[0].column).unwrap();
+            let offset = order_by_rows[idx].iter().nth(offset_idx).unwrap();
+            // PRECEDING means smaller values in ascending order, and larger values in descending
+            // order
+            let bound = range_bound(value, offset, preceding != order.desc);
+            order_by_rows.partition_point(|row| {
+                let value = row.iter().nth(order_by[0].column).unwrap();
+                let cmp = match bound {
+                    Some(bound) => {
+                        compare_columns(&[order.clone()], &[value], &[bound], || Ordering::Equal)
+                    }
+                    // A bound that is out of range sorts before (if it precedes the row) or
+                    // after (if it follows the row) all non-null values, and nulls keep their
+                    // place at either end of the partition
+                    None => {
+                        let sorts_first = if value.is_null() {
+                            !order.nulls_last
+                        } else {
+                            !preceding
+                        };
+                        if sorts_first {
+                            Ordering::Less
+                        } else {
+                            Ordering::Greater
+                        }
+                    }
+                };
+                cmp == Ordering::Less || (!include_equal && cmp == Ordering::Equal)
+            })
+        };
+
+    (0..length)
+        .map(|idx| {
+            let group = groups[idx];
+            let start = match (&window_frame.units, &window_frame.start_bound) {
+                (_, WindowFrameBound::UnboundedPreceding) => 0,
+                (WindowFrameUnits::Rows, WindowFrameBound::OffsetPreceding(offset)) => {
+                    idx.saturating_sub(usize::cast_from(*offset))
+                }
+                (WindowFrameUnits::Rows, WindowFrameBound::CurrentRow) => idx,
+                (WindowFrameUnits::Rows, WindowFrameBound::OffsetFollowing(offset)) => {
+                    idx.saturating_add(usize::cast_from(*offset))
+                }
+                (WindowFrameUnits::Groups, WindowFrameBound::OffsetPreceding(offset)) => {
+                    group_starts[group.saturating_sub(usize::cast_from(*offset))]
+                }
+                (
+                    WindowFrameUnits::Range | WindowFrameUnits::Groups,
+                    WindowFrameBound::CurrentRow,
+                ) => group_starts[group],
+                (WindowFrameUnits::Groups, WindowFrameBound::OffsetFollowing(offset)) => {
+                    group_starts[std::cmp::min(
+                        group.saturating_add(usize::cast_from(*offset)),
+                        group_count,
+                    )]
+                }
+                (WindowFrameUnits::Range, WindowFrameBound::OffsetPreceding(_)) => {
+                    range_partition_point(idx, range_start_idx, true, true)
+                }
+                (WindowFrameUnits::Range, WindowFrameBound::OffsetFollowing(_)) => {
+                    range_partition_point(idx, range_start_idx, false, true)
+                }
+                // Forbidden during planning
+                (_, WindowFrameBound::UnboundedFollowing) => unreachable!(),
+            };
+            let end = match (&window_frame.units, &window_frame.end_bound) {
+                (_, WindowFrameBound::UnboundedFollowing) => length,
+                (WindowFrameUnits::Rows, WindowFrameBound::OffsetPreceding(offset)) => {
+                    (idx + 1).saturating_sub(usize::cast_from(*offset))
+                }
+                (WindowFrameUnits::Rows, WindowFrameBound::CurrentRow) => idx + 1,
+                (WindowFrameUnits::Rows, WindowFrameBound::OffsetFollowing(offset)) => idx
+                    .saturating_add(usize::cast_from(*offset))
+                    .saturating_add(1),
+                (WindowFrameUnits::Groups, WindowFrameBound::OffsetPreceding(offset)) => {
+                    match (group + 1).checked_sub(usize::cast_from(*offset)) {
+                        Some(end_group) => group_starts[end_group],
+                        None => 0,
+                    }
+                }
+                (
+                    WindowFrameUnits::Range | WindowFrameUnits::Groups,
+                    WindowFrameBound::CurrentRow,
+                ) => group_starts[group + 1],
+                (WindowFrameUnits::Groups, WindowFrameBound::OffsetFollowing(offset)) => {
+                    group_starts[std::cmp::min(
+                        group
+                            .saturating_add(usize::cast_from(*offset))
+                            .saturating_add(1),
+                        group_count,
+                    )]
+                }
+                (WindowFrameUnits::Range, WindowFrameBound::OffsetPreceding(_)) => {
+                    range_partition_point(idx, range_end_idx, true, false)
+                }
+                (WindowFrameUnits::Range, WindowFrameBound::OffsetFollowing(_)) => {
+                    range_partition_point(idx, range_end_idx, false, false)
+                }
+                // Forbidden during planning
+                (_, WindowFrameBound::UnboundedPreceding) => unreachable!(),
+            };
+            let end = std::cmp::min(end, length);
+            // An empty frame is represented by an empty range
+            std::cmp::min(start, end)..end
+        })
+        .collect()
+}
+
+/// Computes the value of a RANGE offset bound, `value - offset` if `sub` is
+/// true and `value + offset` otherwise.
+///
+/// Like PostgreSQL's `in_range` support functions, returns `None` if the value
+/// is out of the range of its type, in which case the bound lies beyond all
+/// values of the type in the direction of the offset. This is also the case
+/// when an infinite offset is added to an infinite value of the opposite sign.
+fn range_bound<'a>(value: Datum<'a>, offset: Datum<'a>, sub: bool) -> Option<Datum<'a>> {
+    match (value, offset) {
+        (Datum::Null, _) => Some(Datum::Null),
+        (Datum::Int16(v), Datum::Int16(o)) => {
+            let bound = if sub {
+                v.checked_sub(o)
+            } else {
+                v.checked_add(o)
+            };
+            bound.map(Datum::from)
+        }
+        (Datum::Int32(v), Datum::Int32(o)) => {
+            let bound = if sub {
+                v.checked_sub(o)
+            } else {
+                v.checked_add(o)
+            };
+            bound.map(Datum::from)
+        }
+        (Datum::Int64(v), Datum::Int64(o)) => {
+            let bound = if sub {
+                v.checked_sub(o)
+            } else {
+                v.checked_add(o)
+            };
+            bound.map(Datum::from)
+        }
+        (Datum::Float32(v), Datum::Float32(o)) => {
+            let (v, o) = (v.into_inner(), o.into_inner());
+            let bound = if sub { v - o } else { v + o };
+            (!bound.is_nan() || v.is_nan()).then(|| Datum::from(bound))
+        }
+        (Datum::Float64(v), Datum::Float64(o)) => {
+            let (v, o) = (v.into_inner(), o.into_inner());
+            let bound = if sub { v - o } else { v + o };
+            (!bound.is_nan() || v.is_nan()).then(|| Datum::from(bound))
+        }
+        (Datum::Numeric(_), Datum::Numeric(_)) => {
+            let bound = if sub {
+                sub_numeric(value, offset)
+            } else {
+                add_numeric(value, offset)
+            };
+            bound.ok()
+        }
+        (Datum::Timestamp(v), Datum::Interval(o)) => {
+            let o = if sub { o.checked_neg()? } else { o };
+            add_timestamplike_interval(v, o).ok()
+        }
+        (Datum::TimestampTz(v), Datum::Interval(o)) => {
+            let o = if sub { o.checked_neg()? } else { o };
+            add_timestamplike_interval(v, o).ok()
+        }
+        (Datum::Interval(_), Datum::Interval(_)) => {
+            let bound = if sub {
+                sub_interval(value, offset)
+            } else {
+                add_interval(value, offset)
+            };
+            bound.ok()
+        }
+        (value, offset) => unreachable!("unsupported RANGE offset {:?} for {:?}", offset, value),
+    }
+}
+
 // The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
 fn first_value<'a, I>(
     datums: I,
@@ -871,83 +1360,38 @@ fn first_value<'a, I>(
 where
     I: IntoIterator<Item = Datum<'a>>,
 {
-    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, InputValue) record
-    let datums = order_aggregate_datums(datums, order_by);
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, InputValue), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the window frames
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
 
     // Decode the input (OriginalRow, InputValue) into separate datums
-    let datums = datums
+    let (datums, order_by_rows): (Vec<_>, Vec<_>) = datums
         .into_iter()
-        .map(|d| {
+        .map(|(d, order_by_row)| {
             let mut iter = d.unwrap_list().iter();
             let original_row = iter.next().unwrap();
             let input_value = iter.next().unwrap();
 
-            (input_value, original_row)
+            ((input_value, original_row), order_by_row)
         })
-        .collect_vec();
-
-    let length = datums.len();
-    let mut result: Vec<(Datum, Datum)> = Vec::with_capacity(length);
-    for (idx, (current_datum, original_row)) in datums.iter().enumerate() {
-        let first_value = match &window_frame.start_bound {
-            // Always return the current value
-            WindowFrameBound::CurrentRow => *current_datum,
-            WindowFrameBound::UnboundedPreceding => {
-                if let WindowFrameBound::OffsetPreceding(end_offset) = &window_frame.end_bound {
-                    let end_offset = usize::cast_from(*end_offset);
-
-                    // If the frame ends before the first row, return null
-                    if idx < end_offset {
-                        Datum::Null
-                    } else {
-                        datums[0].0
-                    }
-                } else {
-                    datums[0].0
-                }
-            }
-            WindowFrameBound::OffsetPreceding(offset) => {
-                let start_offset = usize::cast_from(*offset);
-                let start_idx = idx.saturating_sub(start_offset);
-                if let WindowFrameBound::OffsetPreceding(end_offset) = &window_frame.end_bound {
-                    let end_offset = usize::cast_from(*end_offset);
-
-                    // If the frame is empty or ends before the first row, return null
-                    if start_offset < end_offset || idx < end_offset {
-                        Datum::Null
-                    } else {
-                        datums[start_idx].0
-                    }
-                } else {
-                    datums[start_idx].0
-                }
-            }
-            WindowFrameBound::OffsetFollowing(offset) => {
-                let start_offset = usize::cast_from(*offset);
-                let start_idx = idx.saturating_add(start_offset);
-                if let WindowFrameBound::OffsetFollowing(end_offset) = &window_frame.end_bound {
-                    // If the frame is empty or starts after the last row, return null
-                    if offset > end_offset || start_idx >= length {
-                        Datum::Null
-                    } else {
-                        datums[start_idx].0
-                    }
-                } else {
-                    datums.get(start_idx).map(|d| d.0).unwrap_or(Datum::Null)
-                }
-            }
-            // Forbidden during planning
-            WindowFrameBound::UnboundedFollowing => unreachable!(),
-        };
-
-        result.push((first_value, *original_row));
-    }
+        .unzip();
 
-    let result = result.into_iter().map(|(lag, original_row)| {
-        temp_storage.make_datum(|packer| {
-            packer.push_list(vec![lag, original_row]);
+    let frames = window_frame_ranges(&order_by_rows, order_by, window_frame);
+    let result = datums
+        .iter()
+        .zip(frames)
+        .map(|((_, original_row), frame)| {
+            // An empty frame has no first value
+            let first_value = if frame.is_empty() {
+                Datum::Null
+            } else {
+                datums[frame.start].0
+            };
+            temp_storage.make_datum(|packer| {
+                packer.push_list(vec![first_value, *original_row]);
+            })
         })
-    });
+        .collect_vec();
 
     temp_storage.make_datum(|packer| {
         packer.push_list(result);
@@ -965,110 +1409,236 @@ where
     I: IntoIterator<Item = Datum<'a>>,
 {
     // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, InputValue), OrderByRow) record
-    // The OrderByRow is kept around because it is required to compute the peer groups in RANGE mode
+    // The OrderByRow is kept around because it is required to compute the window frames
     let datums = order_aggregate_datums_with_rank(datums, order_by);
 
-    // Decode the input (OriginalRow, InputValue) into separate datums, while keeping the OrderByRow
-    let datums = datums
+    // Decode the input (OriginalRow, InputValue) into separate datums
+    let (datums, order_by_rows): (Vec<_>, Vec<_>) = datums
         .into_iter()
         .map(|(d, order_by_row)| {
             let mut iter = d.unwrap_list().iter();
             let original_row = iter.next().unwrap();
             let input_value = iter.next().unwrap();
 
-            (input_value, original_row, order_by_row)
+            ((input_value, original_row), order_by_row)
+        })
+        .unzip();
+
+    let frames = window_frame_ranges(&order_by_rows, order_by, window_frame);
+    let result = datums
+        .iter()
+        .zip(frames)
+        .map(|((_, original_row), frame)| {
+            // An empty frame has no last value
+            let last_value = if frame.is_empty() {
+                Datum::Null
+            } else {
+                datums[frame.end - 1].0
+            };
+            temp_storage.make_datum(|packer| {
+                packer.push_list(vec![last_value, *original_row]);
+            })
         })
         .collect_vec();
 
-    let length = datums.len();
-    let mut result: Vec<(Datum, Datum)> = Vec::with_capacity(length);
-    for (idx, (current_datum, original_row, order_by_row)) in datums.iter().enumerate() {
-        let last_value = match &window_frame.end_bound {
-            WindowFrameBound::CurrentRow => match &window_frame.units {
-                // Always return the current value when in ROWS mode
-                WindowFrameUnits::Rows => *current_datum,
-                WindowFrameUnits::Range => {
-                    // When in RANGE mode, return the last value of the peer group
-                    // The peer group is the group of rows with the same ORDER BY value
-                    // Note: Range is only supported for the default window frame (RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW),
-                    // which is why it does not appear in the other branches
-                    datums[idx..]
-                        .iter()
-                        .take_while(|(_, _, row)| row == order_by_row)
-                        .last()
-                        .unwrap()
-                        .0
-                }
-                // GROUPS is not supported, and forbidden during planning
-                WindowFrameUnits::Groups => unreachable!(),
-            },
-            WindowFrameBound::UnboundedFollowing => {
-                if let WindowFrameBound::OffsetFollowing(start_offset) = &window_frame.start_bound {
-                    let start_offset = usize::cast_from(*start_offset);
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
 
-                    // If the frame starts after the last row of the window, return null
-                    if idx + start_offset > length - 1 {
-                        Datum::Null
+// The expected input is in the format of [((OriginalRow, NumBuckets), OrderByExprs...)]
+fn ntile<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let datums = order_aggregate_datums(datums, order_by)
+        .map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let num_buckets = iter.next().unwrap();
+            (original_row, num_buckets)
+        })
+        .collect_vec();
+
+    // Like in PostgreSQL, the number of buckets is taken from the first row of the partition, and
+    // the arguments of all other rows are ignored. A null number of buckets results in nulls. A
+    // non-positive number of buckets is returned as the result of every row, and is rejected with
+    // an error by the check the planner wraps around `ntile`.
+    let num_buckets = datums.first().map_or(Datum::Null, |(_, n)| *n);
+
+    // The first `count % num_buckets` buckets have one more row than the remaining buckets
+    let count = datums.len();
+    let result = datums
+        .iter()
+        .enumerate()
+        .map(|(idx, (original_row, _))| {
+            let bucket = match num_buckets {
+                Datum::Int32(num_buckets) if num_buckets > 0 => {
+                    let num_buckets = usize::cast_from(num_buckets.unsigned_abs());
+                    let small_bucket_size = count / num_buckets;
+                    let large_bucket_size = small_bucket_size + 1;
+                    let large_buckets_end = (count % num_buckets) * large_bucket_size;
+                    let bucket = if idx < large_buckets_end {
+                        idx / large_bucket_size
                     } else {
-                        datums[length - 1].0
-                    }
-                } else {
-                    datums[length - 1].0
+                        count % num_buckets + (idx - large_buckets_end) / small_bucket_size
+                    };
+                    // The bucket is at most the number of buckets, which fits in an i32
+                    Datum::Int32(i32::try_from(bucket + 1).expect("bucket fits in i32"))
                 }
-            }
-            WindowFrameBound::OffsetFollowing(offset) => {
-                let end_offset = usize::cast_from(*offset);
-                let end_idx = idx.saturating_add(end_offset);
-                if let WindowFrameBound::OffsetFollowing(start_offset) = &window_frame.start_bound {
-                    let start_offset = usize::cast_from(*start_offset);
-                    let start_idx = idx.saturating_add(start_offset);
-
-                    // If the frame is empty or starts after the last row of the window, return null
-                    if end_offset < start_offset || start_idx >= length {
-                        Datum::Null
+                num_buckets => num_buckets,
+            };
+            temp_storage.make_datum(|packer| {
+                packer.push_list(vec![bucket, *original_row]);
+            })
+        })
+        .collect_vec();
+
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
+// The expected input is in the format of [((OriginalRow, (InputValue, N)), OrderByExprs...)]
+fn nth_value<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, (InputValue, N)), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the window frames
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    // Decode the input (OriginalRow, (InputValue, N)) into separate datums
+    let (datums, order_by_rows): (Vec<_>, Vec<_>) = datums
+        .into_iter()
+        .map(|(d, order_by_row)| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let mut args = iter.next().unwrap().unwrap_list().iter();
+            let input_value = args.next().unwrap();
+            let n = args.next().unwrap();
+
+            ((input_value, n, original_row), order_by_row)
+        })
+        .unzip();
+
+    let frames = window_frame_ranges(&order_by_rows, order_by, window_frame);
+    let result = datums
+        .iter()
+        .zip(frames)
+        .map(|((_, n, original_row), frame)| {
+            // A null N, or a frame with fewer than N rows, has no nth value. Like in PostgreSQL,
+            // the N of every row is used, so non-positive values of N were already rejected with
+            // an error when the argument was evaluated.
+            let nth_value = match n {
+                Datum::Int32(n) if *n > 0 => {
+                    let idx = frame.start + usize::cast_from(n.unsigned_abs()) - 1;
+                    if idx < frame.end {
+                        datums[idx].0
                     } else {
-                        // Return the last valid element in the window
-                        datums
-                            .get(end_idx)
-                            .map(|d| d.0)
-                            .unwrap_or(datums[length - 1].0)
-                    }
-                } else {
-                    datums
-                        .get(end_idx)
-                        .map(|d| d.0)
-                        .unwrap_or(datums[length - 1].0)
-                }
-            }
-            WindowFrameBound::OffsetPreceding(offset) => {
-                let end_offset = usize::cast_from(*offset);
-                let end_idx = idx.saturating_sub(end_offset);
-                if idx < end_offset {
-                    // If the frame ends before the first row, return null
-                    Datum::Null
-                } else if let WindowFrameBound::OffsetPreceding(start_offset) =
-                    &window_frame.start_bound
-                {
-                    // If the frame is empty, return null
-                    if offset > start_offset {
                         Datum::Null
-                    } else {
-                        datums[end_idx].0
                     }
-                } else {
-                    datums[end_idx].0
                 }
-            }
-            // Forbidden during planning
-            WindowFrameBound::UnboundedPreceding => unreachable!(),
-        };
+                _ => Datum::Null,
+            };
+            temp_storage.make_datum(|packer| {
+                packer.push_list(vec![nth_value, *original_row]);
+            })
+        })
+        .collect_vec();
 
-        result.push((last_value, *original_row));
-    }
+    temp_storage.make_datum(|packer| {
+        packer.push_list(result);
+    })
+}
+
+// The expected input is in the format of [((OriginalRow, InputValue), OrderByExprs...)]
+//
+// This evaluates a whole partition at once, and the dataflow re-evaluates the partition whenever
+// any of its rows changes (`WindowAggregate` is a basic reduction). Within the partition, frames
+// that start at the beginning of the partition are evaluated in a single pass: aggregates that
+// can be applied to their own result only fold in the rows that each frame adds to the previous
+// one, and all other aggregates are only recomputed when the frame changes, which still takes
+// time quadratic in the size of the partition. Frames with other start bounds are always
+// recomputed for every row.
+fn window_aggregate<'a, I>(
+    datums: I,
+    temp_storage: &'a RowArena,
+    wrapped_aggregate: &AggregateFunc,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, InputValue), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the window frames
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    // Decode the input (OriginalRow, InputValue) into separate datums
+    let (datums, order_by_rows): (Vec<_>, Vec<_>) = datums
+        .into_iter()
+        .map(|(d, order_by_row)| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let input_value = iter.next().unwrap();
+
+            ((input_value, original_row), order_by_row)
+        })
+        .unzip();
+
+    let result = if window_frame.start_bound == WindowFrameBound::UnboundedPreceding
+        && window_frame.end_bound == WindowFrameBound::UnboundedFollowing
+    {
+        // The frame is the entire partition, so the aggregate only needs to be computed once
+        let agg = wrapped_aggregate.eval(datums.iter().map(|(d, _)| *d), temp_storage);
+        datums
+            .iter()
+            .map(|(_, original_row)| (agg, *original_row))
+            .collect_vec()
+    } else if window_frame.start_bound == WindowFrameBound::UnboundedPreceding {
+        // The frames only grow from one row to the next, since the end bounds are monotonic
+        let frames = window_frame_ranges(&order_by_rows, order_by, window_frame);
+        let reapplicable = wrapped_aggregate.is_reapplicable();
+        let mut agg = wrapped_aggregate.eval(std::iter::empty(), temp_storage);
+        let mut agg_end = 0;
+        datums
+            .iter()
+            .zip(frames)
+            .map(|((_, original_row), frame)| {
+                if frame.end > agg_end && reapplicable {
+                    let added = datums[agg_end..frame.end].iter().map(|(d, _)| *d);
+                    agg = wrapped_aggregate.eval(std::iter::once(agg).chain(added), temp_storage);
+                    agg_end = frame.end;
+                } else if frame.end != agg_end {
+                    agg = wrapped_aggregate
+                        .eval(datums[frame.clone()].iter().map(|(d, _)| *d), temp_storage);
+                    agg_end = frame.end;
+                }
+                (agg, *original_row)
+            })
+            .collect_vec()
+    } else {
+        let frames = window_frame_ranges(&order_by_rows, order_by, window_frame);
+        datums
+            .iter()
+            .zip(frames)
+            .map(|((_, original_row), frame)| {
+                let agg =
+                    wrapped_aggregate.eval(datums[frame].iter().map(|(d, _)| *d), temp_storage);
+                (agg, *original_row)
+            })
+            .collect_vec()
+    };
 
-    let result = result.into_iter().map(|(lag, original_row)| {
+    let result = result.into_iter().map(|(agg, original_row)| {
         temp_storage.make_datum(|packer| {
-            packer.push_list(vec![lag, original_row]);
+            packer.push_list(vec![agg, original_row]);
         })
     });
 
@@ -1087,6 +1657,81 @@ pub enum LagLeadType {
     Lead,
 }
 
+/// The fractions at which `percentile_cont` and `percentile_disc` are
+/// computed. These must lie between 0 and 1.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect)]
+pub enum PercentileFractions {
+    /// A single fraction, which produces a single value.
+    Scalar(OrderedFloat<f64>),
+    /// An array of fractions, which produces an array with a value for each
+    /// fraction. Null fractions produce null values.
+    Array(Vec<Option<OrderedFloat<f64>>>),
+}
+
+impl PercentileFractions {
+    pub fn scalar(fraction: f64) -> Self {
+        PercentileFractions::Scalar(OrderedFloat(fraction))
+    }
+
+    pub fn array<I>(fractions: I) -> Self
+    where
+        I: IntoIterator<Item = Option<f64>>,
+    {
+        PercentileFractions::Array(fractions.into_iter().map(|f| f.map(OrderedFloat)).collect())
+    }
+}
+
+/// An explicit [`Arbitrary`] implementation, as `OrderedFloat` does not
+/// implement [`Arbitrary`].
+impl Arbitrary for PercentileFractions {
+    type Parameters = ();
+
+    type Strategy = Union<BoxedStrategy<Self>>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::collection::vec;
+        use proptest::option;
+        Union::new(vec![
+            (0.0..=1.0).prop_map(PercentileFractions::scalar).boxed(),
+            vec(option::of(0.0..=1.0), 0..4)
+                .prop_map(PercentileFractions::array)
+                .boxed(),
+        ])
+    }
+}
+
+impl RustType<ProtoPercentileFractions> for PercentileFractions {
+    fn into_proto(&self) -> ProtoPercentileFractions {
+        use proto_percentile_fractions::{Kind, ProtoFraction, ProtoFractionArray};
+        ProtoPercentileFractions {
+            kind: Some(match self {
+                PercentileFractions::Scalar(fraction) => Kind::Scalar(fraction.0),
+                PercentileFractions::Array(fractions) => Kind::Array(ProtoFractionArray {
+                    fractions: fractions
+                        .iter()
+                        .map(|fraction| ProtoFraction {
+                            fraction: fraction.map(|f| f.0),
+                        })
+                        .collect(),
+                }),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoPercentileFractions) -> Result<Self, TryFromProtoError> {
+        use proto_percentile_fractions::Kind;
+        match proto.kind {
+            Some(Kind::Scalar(fraction)) => Ok(PercentileFractions::scalar(fraction)),
+            Some(Kind::Array(array)) => Ok(PercentileFractions::array(
+                array.fractions.into_iter().map(|f| f.fraction),
+            )),
+            None => Err(TryFromProtoError::missing_field(
+                "ProtoPercentileFractions::kind",
+            )),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, MzReflect)]
 pub enum AggregateFunc {
     MaxNumeric,
@@ -1131,6 +1776,11 @@ pub enum AggregateFunc {
     Count,
     Any,
     All,
+    /// Merges `Datum::Bytes` HyperLogLog sketches into a single sketch.
+    ///
+    /// Merging is idempotent, so this can be evaluated hierarchically, and
+    /// the size of the output is bounded regardless of the number of inputs.
+    HllMerge,
     /// Accumulates `Datum::List`s whose first element is a JSON-typed `Datum`s
     /// into a JSON list. The other elements are columns used by `order_by`.
     ///
@@ -1168,6 +1818,15 @@ pub enum AggregateFunc {
     DenseRank {
         order_by: Vec<ColumnOrder>,
     },
+    Rank {
+        order_by: Vec<ColumnOrder>,
+    },
+    PercentRank {
+        order_by: Vec<ColumnOrder>,
+    },
+    CumeDist {
+        order_by: Vec<ColumnOrder>,
+    },
     LagLead {
         order_by: Vec<ColumnOrder>,
         lag_lead: LagLeadType,
@@ -1180,6 +1839,44 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    /// Divides the partition into as equal buckets as possible, the number of
+    /// which is given by the input value of the first row of the partition.
+    Ntile {
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Returns the input value of the nth row of the window frame of each row,
+    /// where n is given by that row.
+    NthValue {
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
+    /// Evaluates `wrapped_aggregate` over the window frame of each row of a
+    /// partition, as in `sum(x) OVER (...)`.
+    WindowAggregate {
+        wrapped_aggregate: Box<AggregateFunc>,
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
+    /// Computes the continuous percentiles at `fractions` of `Datum::List`s
+    /// whose first element is a `Datum::Float64` or `Datum::Interval`,
+    /// interpolating between adjacent values. The other elements are columns
+    /// used by `order_by`.
+    PercentileCont {
+        fractions: PercentileFractions,
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the discrete percentiles at `fractions` of `Datum::List`s
+    /// whose first element is the value to aggregate. The other elements are
+    /// columns used by `order_by`.
+    PercentileDisc {
+        fractions: PercentileFractions,
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the most frequent first element of `Datum::List`s. The other
+    /// elements are columns used by `order_by`.
+    Mode {
+        order_by: Vec<ColumnOrder>,
+    },
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -1244,6 +1941,7 @@ impl Arbitrary for AggregateFunc {
             Just(AggregateFunc::Count).boxed(),
             Just(AggregateFunc::Any).boxed(),
             Just(AggregateFunc::All).boxed(),
+            Just(AggregateFunc::HllMerge).boxed(),
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::JsonbAgg { order_by })
                 .boxed(),
@@ -1265,6 +1963,27 @@ impl Arbitrary for AggregateFunc {
             vec(proptest_any::<ColumnOrder>(), 1..4)
                 .prop_map(|order_by| AggregateFunc::DenseRank { order_by })
                 .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::Rank { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::PercentRank { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::CumeDist { order_by })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::Ntile { order_by })
+                .boxed(),
+            (
+                vec(proptest_any::<ColumnOrder>(), 1..4),
+                proptest_any::<WindowFrame>(),
+            )
+                .prop_map(|(order_by, window_frame)| AggregateFunc::NthValue {
+                    order_by,
+                    window_frame,
+                })
+                .boxed(),
             (
                 vec(proptest_any::<ColumnOrder>(), 1..4),
                 proptest_any::<LagLeadType>(),
@@ -1289,6 +2008,44 @@ impl Arbitrary for AggregateFunc {
                     window_frame,
                 })
                 .boxed(),
+            (
+                proptest::sample::select(vec![
+                    AggregateFunc::SumInt64,
+                    AggregateFunc::MaxString,
+                    AggregateFunc::Count,
+                ]),
+                vec(proptest_any::<ColumnOrder>(), 1..4),
+                proptest_any::<WindowFrame>(),
+            )
+                .prop_map(|(wrapped_aggregate, order_by, window_frame)| {
+                    AggregateFunc::WindowAggregate {
+                        wrapped_aggregate: Box::new(wrapped_aggregate),
+                        order_by,
+                        window_frame,
+                    }
+                })
+                .boxed(),
+            (
+                proptest_any::<PercentileFractions>(),
+                vec(proptest_any::<ColumnOrder>(), 1..4),
+            )
+                .prop_map(|(fractions, order_by)| AggregateFunc::PercentileCont {
+                    fractions,
+                    order_by,
+                })
+                .boxed(),
+            (
+                proptest_any::<PercentileFractions>(),
+                vec(proptest_any::<ColumnOrder>(), 1..4),
+            )
+                .prop_map(|(fractions, order_by)| AggregateFunc::PercentileDisc {
+                    fractions,
+                    order_by,
+                })
+                .boxed(),
+            vec(proptest_any::<ColumnOrder>(), 1..4)
+                .prop_map(|order_by| AggregateFunc::Mode { order_by })
+                .boxed(),
             Just(AggregateFunc::Dummy).boxed(),
         ])
     }
@@ -1353,6 +2110,7 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::Count => Kind::Count(()),
                 AggregateFunc::Any => Kind::Any(()),
                 AggregateFunc::All => Kind::All(()),
+                AggregateFunc::HllMerge => Kind::HllMerge(()),
                 AggregateFunc::JsonbAgg { order_by } => Kind::JsonbAgg(order_by.into_proto()),
                 AggregateFunc::JsonbObjectAgg { order_by } => {
                     Kind::JsonbObjectAgg(order_by.into_proto())
@@ -1362,6 +2120,10 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                 AggregateFunc::StringAgg { order_by } => Kind::StringAgg(order_by.into_proto()),
                 AggregateFunc::RowNumber { order_by } => Kind::RowNumber(order_by.into_proto()),
                 AggregateFunc::DenseRank { order_by } => Kind::DenseRank(order_by.into_proto()),
+                AggregateFunc::Rank { order_by } => Kind::Rank(order_by.into_proto()),
+                AggregateFunc::PercentRank { order_by } => Kind::PercentRank(order_by.into_proto()),
+                AggregateFunc::CumeDist { order_by } => Kind::CumeDist(order_by.into_proto()),
+                AggregateFunc::Ntile { order_by } => Kind::Ntile(order_by.into_proto()),
                 AggregateFunc::LagLead { order_by, lag_lead } => {
                     Kind::LagLead(proto_aggregate_func::ProtoLagLead {
                         order_by: Some(order_by.into_proto()),
@@ -1389,6 +2151,37 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     order_by: Some(order_by.into_proto()),
                     window_frame: Some(window_frame.into_proto()),
                 }),
+                AggregateFunc::NthValue {
+                    order_by,
+                    window_frame,
+                } => Kind::NthValue(proto_aggregate_func::ProtoWindowFrame {
+                    order_by: Some(order_by.into_proto()),
+                    window_frame: Some(window_frame.into_proto()),
+                }),
+                AggregateFunc::WindowAggregate {
+                    wrapped_aggregate,
+                    order_by,
+                    window_frame,
+                } => Kind::WindowAggregate(Box::new(proto_aggregate_func::ProtoWindowAggregate {
+                    wrapped_aggregate: Some(wrapped_aggregate.into_proto()),
+                    order_by: Some(order_by.into_proto()),
+                    window_frame: Some(window_frame.into_proto()),
+                })),
+                AggregateFunc::PercentileCont {
+                    fractions,
+                    order_by,
+                } => Kind::PercentileCont(proto_aggregate_func::ProtoPercentile {
+                    fractions: Some(fractions.into_proto()),
+                    order_by: Some(order_by.into_proto()),
+                }),
+                AggregateFunc::PercentileDisc {
+                    fractions,
+                    order_by,
+                } => Kind::PercentileDisc(proto_aggregate_func::ProtoPercentile {
+                    fractions: Some(fractions.into_proto()),
+                    order_by: Some(order_by.into_proto()),
+                }),
+                AggregateFunc::Mode { order_by } => Kind::Mode(order_by.into_proto()),
                 AggregateFunc::Dummy => Kind::Dummy(()),
             }),
         }
@@ -1442,6 +2235,7 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::Count(()) => AggregateFunc::Count,
             Kind::Any(()) => AggregateFunc::Any,
             Kind::All(()) => AggregateFunc::All,
+            Kind::HllMerge(()) => AggregateFunc::HllMerge,
             Kind::JsonbAgg(order_by) => AggregateFunc::JsonbAgg {
                 order_by: order_by.into_rust()?,
             },
@@ -1463,6 +2257,18 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
             Kind::DenseRank(order_by) => AggregateFunc::DenseRank {
                 order_by: order_by.into_rust()?,
             },
+            Kind::Rank(order_by) => AggregateFunc::Rank {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::PercentRank(order_by) => AggregateFunc::PercentRank {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::CumeDist(order_by) => AggregateFunc::CumeDist {
+                order_by: order_by.into_rust()?,
+            },
+            Kind::Ntile(order_by) => AggregateFunc::Ntile {
+                order_by: order_by.into_rust()?,
+            },
             Kind::LagLead(pll) => AggregateFunc::LagLead {
                 order_by: pll.order_by.into_rust_if_some("ProtoLagLead::order_by")?,
                 lag_lead: match pll.lag_lead {
@@ -1495,6 +2301,40 @@ impl RustType<ProtoAggregateFunc> for AggregateFunc {
                     .window_frame
                     .into_rust_if_some("ProtoWindowFrame::window_frame")?,
             },
+            Kind::NthValue(pfv) => AggregateFunc::NthValue {
+                order_by: pfv
+                    .order_by
+                    .into_rust_if_some("ProtoWindowFrame::order_by")?,
+                window_frame: pfv
+                    .window_frame
+                    .into_rust_if_some("ProtoWindowFrame::window_frame")?,
+            },
+            Kind::WindowAggregate(pwa) => AggregateFunc::WindowAggregate {
+                wrapped_aggregate: pwa
+                    .wrapped_aggregate
+                    .into_rust_if_some("ProtoWindowAggregate::wrapped_aggregate")?,
+                order_by: pwa
+                    .order_by
+                    .into_rust_if_some("ProtoWindowAggregate::order_by")?,
+                window_frame: pwa
+                    .window_frame
+                    .into_rust_if_some("ProtoWindowAggregate::window_frame")?,
+            },
+            Kind::PercentileCont(pp) => AggregateFunc::PercentileCont {
+                fractions: pp
+                    .fractions
+                    .into_rust_if_some("ProtoPercentile::fractions")?,
+                order_by: pp.order_by.into_rust_if_some("ProtoPercentile::order_by")?,
+            },
+            Kind::PercentileDisc(pp) => AggregateFunc::PercentileDisc {
+                fractions: pp
+                    .fractions
+                    .into_rust_if_some("ProtoPercentile::fractions")?,
+                order_by: pp.order_by.into_rust_if_some("ProtoPercentile::order_by")?,
+            },
+            Kind::Mode(order_by) => AggregateFunc::Mode {
+                order_by: order_by.into_rust()?,
+            },
             Kind::Dummy(()) => AggregateFunc::Dummy,
         })
     }
@@ -1548,6 +2388,7 @@ impl AggregateFunc {
             AggregateFunc::Count => count(datums),
             AggregateFunc::Any => any(datums),
             AggregateFunc::All => all(datums),
+            AggregateFunc::HllMerge => hll_merge(datums, temp_storage),
             AggregateFunc::JsonbAgg { order_by } => jsonb_agg(datums, temp_storage, order_by),
             AggregateFunc::JsonbObjectAgg { order_by } => {
                 jsonb_object_agg(datums, temp_storage, order_by)
@@ -1557,6 +2398,9 @@ impl AggregateFunc {
             AggregateFunc::StringAgg { order_by } => string_agg(datums, temp_storage, order_by),
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
+            AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
+            AggregateFunc::PercentRank { order_by } => percent_rank(datums, temp_storage, order_by),
+            AggregateFunc::CumeDist { order_by } => cume_dist(datums, temp_storage, order_by),
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
@@ -1569,10 +2413,63 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::Ntile { order_by } => ntile(datums, temp_storage, order_by),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate,
+                order_by,
+                window_frame,
+            } => window_aggregate(
+                datums,
+                temp_storage,
+                wrapped_aggregate,
+                order_by,
+                window_frame,
+            ),
+            // Errors can only be reported by `AggregateFunc::try_eval`.
+            AggregateFunc::PercentileCont {
+                fractions,
+                order_by,
+            } => percentile_cont(datums, temp_storage, fractions, order_by).unwrap_or(Datum::Null),
+            AggregateFunc::PercentileDisc {
+                fractions,
+                order_by,
+            } => percentile_disc(datums, temp_storage, fractions, order_by),
+            AggregateFunc::Mode { order_by } => mode(datums, order_by),
             AggregateFunc::Dummy => Datum::Dummy,
         }
     }
 
+    /// Like [`AggregateFunc::eval`], but reports the errors that evaluating the
+    /// aggregation function can produce, for which `eval` produces null.
+    ///
+    /// Only functions for which [`AggregateFunc::can_error`] is true can
+    /// produce errors.
+    pub fn try_eval<'a, I>(
+        &self,
+        datums: I,
+        temp_storage: &'a RowArena,
+    ) -> Result<Datum<'a>, EvalError>
+    where
+        I: IntoIterator<Item = Datum<'a>>,
+    {
+        match self {
+            AggregateFunc::PercentileCont {
+                fractions,
+                order_by,
+            } => percentile_cont(datums, temp_storage, fractions, order_by),
+            _ => Ok(self.eval(datums, temp_storage)),
+        }
+    }
+
+    /// Returns whether [`AggregateFunc::try_eval`] can produce an error.
+    pub fn can_error(&self) -> bool {
+        matches!(self, AggregateFunc::PercentileCont { .. })
+    }
+
     /// Returns the output of the aggregation function when applied on an empty
     /// input relation.
     pub fn default(&self) -> Datum<'static> {
@@ -1596,13 +2493,64 @@ impl AggregateFunc {
             AggregateFunc::ListConcat { .. } => Datum::empty_list(),
             AggregateFunc::RowNumber { .. } => Datum::empty_list(),
             AggregateFunc::DenseRank { .. } => Datum::empty_list(),
+            AggregateFunc::Rank { .. } => Datum::empty_list(),
+            AggregateFunc::PercentRank { .. } => Datum::empty_list(),
+            AggregateFunc::CumeDist { .. } => Datum::empty_list(),
             AggregateFunc::LagLead { .. } => Datum::empty_list(),
             AggregateFunc::FirstValue { .. } => Datum::empty_list(),
             AggregateFunc::LastValue { .. } => Datum::empty_list(),
+            AggregateFunc::Ntile { .. } => Datum::empty_list(),
+            AggregateFunc::NthValue { .. } => Datum::empty_list(),
+            AggregateFunc::WindowAggregate { .. } => Datum::empty_list(),
             _ => Datum::Null,
         }
     }
 
+    /// Whether applying the function to its own result followed by more
+    /// inputs produces the same result as applying it to all of the inputs at
+    /// once. Such functions can be computed incrementally over a growing set of
+    /// inputs.
+    fn is_reapplicable(&self) -> bool {
+        matches!(
+            self,
+            AggregateFunc::MaxNumeric
+                | AggregateFunc::MaxInt16
+                | AggregateFunc::MaxInt32
+                | AggregateFunc::MaxInt64
+                | AggregateFunc::MaxUInt16
+                | AggregateFunc::MaxUInt32
+                | AggregateFunc::MaxUInt64
+                | AggregateFunc::MaxMzTimestamp
+                | AggregateFunc::MaxFloat32
+                | AggregateFunc::MaxFloat64
+                | AggregateFunc::MaxBool
+                | AggregateFunc::MaxString
+                | AggregateFunc::MaxDate
+                | AggregateFunc::MaxTimestamp
+                | AggregateFunc::MaxTimestampTz
+                | AggregateFunc::MinNumeric
+                | AggregateFunc::MinInt16
+                | AggregateFunc::MinInt32
+                | AggregateFunc::MinInt64
+                | AggregateFunc::MinUInt16
+                | AggregateFunc::MinUInt32
+                | AggregateFunc::MinUInt64
+                | AggregateFunc::MinMzTimestamp
+                | AggregateFunc::MinFloat32
+                | AggregateFunc::MinFloat64
+                | AggregateFunc::MinBool
+                | AggregateFunc::MinString
+                | AggregateFunc::MinDate
+                | AggregateFunc::MinTimestamp
+                | AggregateFunc::MinTimestampTz
+                | AggregateFunc::SumFloat32
+                | AggregateFunc::SumFloat64
+                | AggregateFunc::SumNumeric
+                | AggregateFunc::Any
+                | AggregateFunc::All
+        )
+    }
+
     /// The output column type for the result of an aggregation.
     ///
     /// The output column type also contains nullability information, which
@@ -1677,6 +2625,36 @@ impl AggregateFunc {
                 },
                 _ => unreachable!(),
             },
+            AggregateFunc::Rank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. } => {
+                let (column_name, rank_type) = match self {
+                    AggregateFunc::Rank { .. } => ("?rank?", ScalarType::Int64),
+                    AggregateFunc::PercentRank { .. } => ("?percent_rank?", ScalarType::Float64),
+                    _ => ("?cume_dist?", ScalarType::Float64),
+                };
+                match input_type.scalar_type {
+                    ScalarType::Record { ref fields, .. } => ScalarType::List {
+                        element_type: Box::new(ScalarType::Record {
+                            fields: vec![
+                                (ColumnName::from(column_name), rank_type.nullable(false)),
+                                (ColumnName::from("?record?"), {
+                                    let inner = match &fields[0].1.scalar_type {
+                                        ScalarType::List { element_type, .. } => {
+                                            element_type.clone()
+                                        }
+                                        _ => unreachable!(),
+                                    };
+                                    inner.nullable(false)
+                                }),
+                            ],
+                            custom_id: None,
+                        }),
+                        custom_id: None,
+                    },
+                    _ => unreachable!(),
+                }
+            }
             AggregateFunc::LagLead { lag_lead, .. } => {
                 // The input type for Lag is a ((OriginalRow, EncodedArgs), OrderByExprs...)
                 let fields = input_type.scalar_type.unwrap_record_element_type();
@@ -1745,6 +2723,90 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::Ntile { .. } => {
+                // The input type for Ntile is ((OriginalRow, NumBuckets), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (
+                                ColumnName::from("?ntile?"),
+                                ScalarType::Int32.nullable(true),
+                            ),
+                            (ColumnName::from("?record?"), original_row_type),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
+            AggregateFunc::NthValue { .. } => {
+                // The input type for NthValue is ((OriginalRow, (InputValue, N)), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let value_type = fields[0].unwrap_record_element_type()[1]
+                    .unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(true);
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (ColumnName::from("?nth_value?"), value_type),
+                            (ColumnName::from("?record?"), original_row_type),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate, ..
+            } => {
+                // The input type for a window aggregate is ((OriginalRow, InputValue), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let input_value_type = fields[0].unwrap_record_element_type()[1]
+                    .clone()
+                    .nullable(true);
+                let value_type = wrapped_aggregate.output_type(input_value_type);
+
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Record {
+                        fields: vec![
+                            (ColumnName::from("?window_agg?"), value_type),
+                            (ColumnName::from("?record?"), original_row_type),
+                        ],
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
+            AggregateFunc::PercentileCont { fractions, .. }
+            | AggregateFunc::PercentileDisc { fractions, .. } => {
+                let value_type = match input_type.scalar_type {
+                    // The input is wrapped in a Record with the ORDER BY
+                    // expressions, so extract it out.
+                    ScalarType::Record { ref fields, .. } => fields[0].1.scalar_type.clone(),
+                    _ => unreachable!(),
+                };
+                match fractions {
+                    PercentileFractions::Scalar(_) => value_type,
+                    PercentileFractions::Array(_) => ScalarType::Array(Box::new(value_type)),
+                }
+            }
+            AggregateFunc::Mode { .. } => match input_type.scalar_type {
+                ScalarType::Record { ref fields, .. } => fields[0].1.scalar_type.clone(),
+                _ => unreachable!(),
+            },
             // Note AggregateFunc::MaxString, MinString rely on returning input
             // type as output type to support the proper return type for
             // character input.
@@ -1764,6 +2826,11 @@ impl AggregateFunc {
                 },
                 _ => unreachable!(),
             },
+            // The Record wrapping the input is never null, but the values
+            // within it may all be null, which produces null.
+            AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. } => true,
             _ => input_type.nullable,
         };
         scalar_type.nullable(nullable)
@@ -1814,6 +2881,7 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
+            | AggregateFunc::HllMerge
             | AggregateFunc::StringAgg { .. } => true,
             // Count is never null
             AggregateFunc::Count => false,
@@ -1879,6 +2947,71 @@ fn regexp_extract(a: Datum, r: &AnalyzedRegex) -> Option<(Row, Diff)> {
     Some((Row::pack(datums), 1))
 }
 
+/// Yields one row per match of `regex` in `a`, or only for the first match
+/// unless `global` is set. Each row holds an array of the text matched by the
+/// regex's capture groups, or of the text of the entire match if the regex has
+/// no capture groups.
+fn regexp_matches<'a>(
+    a: Datum<'a>,
+    regex: &'a Regex,
+    global: bool,
+) -> impl Iterator<Item = (Row, Diff)> + 'a {
+    let haystack = a.unwrap_str();
+    let limit = if global { usize::MAX } else { 1 };
+    regexp_match_locations(regex, haystack, 0)
+        .take(limit)
+        .map(move |locations| {
+            let groups = if locations.len() == 1 {
+                0..1
+            } else {
+                1..locations.len()
+            };
+            let datums: Vec<_> = groups
+                .map(|i| Datum::from(locations.get(i).map(|(start, end)| &haystack[start..end])))
+                .collect();
+            let dims = ArrayDimension {
+                lower_bound: 1,
+                length: datums.len(),
+            };
+            let mut row = Row::default();
+            row.packer().push_array(&[dims], datums).unwrap();
+            (row, 1)
+        })
+}
+
+/// Yields one row for each item that `path` selects from a jsonb value.
+///
+/// The arguments in `datums` are the target, and optionally the path's
+/// variables and whether to suppress errors.
+fn jsonb_path_query<'a>(
+    path: &'a JsonPath,
+    datums: &[Datum<'a>],
+) -> Result<impl Iterator<Item = (Row, Diff)> + 'a, EvalError> {
+    let vars = datums.get(1).copied();
+    let silent = datums.get(2).map_or(false, |d| d.unwrap_bool());
+    let items = suppress_jsonb_path_error(path.query(datums[0], vars), silent)?;
+    Ok(items
+        .unwrap_or_default()
+        .into_iter()
+        .map(|item| (Row::pack_slice(&[item]), 1)))
+}
+
+/// Like [`jsonb_path_query`], but parses the path from the second of `datums`.
+///
+/// See [`eval_jsonb_path`] for a description of `datums`.
+fn jsonb_path_query_dynamic(
+    datums: &[Datum],
+) -> Result<impl Iterator<Item = (Row, Diff)>, EvalError> {
+    let rows = eval_jsonb_path(datums, |path, target, vars| {
+        Ok(path
+            .query(target, vars)?
+            .into_iter()
+            .map(|item| (Row::pack_slice(&[item]), 1))
+            .collect::<Vec<_>>())
+    })?;
+    Ok(rows.unwrap_or_default().into_iter())
+}
+
 fn generate_series<N>(
     start: N,
     stop: N,
@@ -2049,6 +3182,7 @@ impl fmt::Display for AggregateFunc {
             AggregateFunc::Count => f.write_str("count"),
             AggregateFunc::Any => f.write_str("any"),
             AggregateFunc::All => f.write_str("all"),
+            AggregateFunc::HllMerge => f.write_str("hll_merge"),
             AggregateFunc::JsonbAgg { .. } => f.write_str("jsonb_agg"),
             AggregateFunc::JsonbObjectAgg { .. } => f.write_str("jsonb_object_agg"),
             AggregateFunc::ArrayConcat { .. } => f.write_str("array_agg"),
@@ -2056,6 +3190,9 @@ impl fmt::Display for AggregateFunc {
             AggregateFunc::StringAgg { .. } => f.write_str("string_agg"),
             AggregateFunc::RowNumber { .. } => f.write_str("row_number"),
             AggregateFunc::DenseRank { .. } => f.write_str("dense_rank"),
+            AggregateFunc::Rank { .. } => f.write_str("rank"),
+            AggregateFunc::PercentRank { .. } => f.write_str("percent_rank"),
+            AggregateFunc::CumeDist { .. } => f.write_str("cume_dist"),
             AggregateFunc::LagLead {
                 lag_lead: LagLeadType::Lag,
                 ..
@@ -2066,6 +3203,14 @@ impl fmt::Display for AggregateFunc {
             } => f.write_str("lead"),
             AggregateFunc::FirstValue { .. } => f.write_str("first_value"),
             AggregateFunc::LastValue { .. } => f.write_str("last_value"),
+            AggregateFunc::Ntile { .. } => f.write_str("ntile"),
+            AggregateFunc::NthValue { .. } => f.write_str("nth_value"),
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate, ..
+            } => write!(f, "{}", wrapped_aggregate),
+            AggregateFunc::PercentileCont { .. } => f.write_str("percentile_cont"),
+            AggregateFunc::PercentileDisc { .. } => f.write_str("percentile_disc"),
+            AggregateFunc::Mode { .. } => f.write_str("mode"),
             AggregateFunc::Dummy => f.write_str("dummy"),
         }
     }
@@ -2218,12 +3363,33 @@ pub enum TableFunc {
         width: usize,
     },
     GenerateSubscriptsArray,
+    /// Yields an array of the capture groups of each match of `regex` in its
+    /// input, or of only the first match unless `global` is set.
+    RegexpMatches {
+        #[proptest(strategy = "mz_repr::adt::regex::any_regex()")]
+        regex: ReprRegex,
+        global: bool,
+    },
+    /// Like [`TableFunc::RegexpMatches`], but compiles the regex for each row
+    /// from its second input and optional third input, the flags.
+    RegexpMatchesDynamic,
+    /// Yields the items that `path` selects from a jsonb value, its first
+    /// input. The optional second and third inputs are the path's variables
+    /// and whether to suppress errors.
+    JsonbPathQuery {
+        #[proptest(strategy = "mz_repr::adt::jsonpath::any_jsonpath()")]
+        #[mzreflect(ignore)]
+        path: JsonPath,
+    },
+    /// Like [`TableFunc::JsonbPathQuery`], but parses the path for each row
+    /// from its second input.
+    JsonbPathQueryDynamic,
 }
 
 impl RustType<ProtoTableFunc> for TableFunc {
     fn into_proto(&self) -> ProtoTableFunc {
         use proto_table_func::Kind;
-        use proto_table_func::ProtoWrap;
+        use proto_table_func::{ProtoRegexpMatches, ProtoWrap};
 
         ProtoTableFunc {
             kind: Some(match self {
@@ -2244,6 +3410,15 @@ impl RustType<ProtoTableFunc> for TableFunc {
                     width: width.into_proto(),
                 }),
                 TableFunc::GenerateSubscriptsArray => Kind::GenerateSubscriptsArray(()),
+                TableFunc::RegexpMatches { regex, global } => {
+                    Kind::RegexpMatches(ProtoRegexpMatches {
+                        regex: Some(regex.into_proto()),
+                        global: *global,
+                    })
+                }
+                TableFunc::RegexpMatchesDynamic => Kind::RegexpMatchesDynamic(()),
+                TableFunc::JsonbPathQuery { path } => Kind::JsonbPathQuery(path.into_proto()),
+                TableFunc::JsonbPathQueryDynamic => Kind::JsonbPathQueryDynamic(()),
             }),
         }
     }
@@ -2277,6 +3452,15 @@ impl RustType<ProtoTableFunc> for TableFunc {
                 types: x.types.into_rust()?,
             },
             Kind::GenerateSubscriptsArray(()) => TableFunc::GenerateSubscriptsArray,
+            Kind::RegexpMatches(x) => TableFunc::RegexpMatches {
+                regex: x.regex.into_rust_if_some("ProtoRegexpMatches::regex")?,
+                global: x.global,
+            },
+            Kind::RegexpMatchesDynamic(()) => TableFunc::RegexpMatchesDynamic,
+            Kind::JsonbPathQuery(path) => TableFunc::JsonbPathQuery {
+                path: path.into_rust()?,
+            },
+            Kind::JsonbPathQueryDynamic(()) => TableFunc::JsonbPathQueryDynamic,
         })
     }
 }
@@ -2349,6 +3533,19 @@ impl TableFunc {
             TableFunc::UnnestArray { .. } => Ok(Box::new(unnest_array(datums[0]))),
             TableFunc::UnnestList { .. } => Ok(Box::new(unnest_list(datums[0]))),
             TableFunc::Wrap { width, .. } => Ok(Box::new(wrap(datums, *width))),
+            TableFunc::RegexpMatches { regex, global } => {
+                Ok(Box::new(regexp_matches(datums[0], regex, *global)))
+            }
+            TableFunc::RegexpMatchesDynamic => {
+                let flags = datums.get(2).map(|flags| flags.unwrap_str()).unwrap_or("");
+                let (regex, limit) = build_regex_with_limit(datums[1].unwrap_str(), flags)?;
+                // The rows borrow the regex, so must be produced before it is
+                // dropped.
+                let rows: Vec<_> = regexp_matches(datums[0], &regex, limit == 0).collect();
+                Ok(Box::new(rows.into_iter()))
+            }
+            TableFunc::JsonbPathQuery { path } => Ok(Box::new(jsonb_path_query(path, datums)?)),
+            TableFunc::JsonbPathQueryDynamic => Ok(Box::new(jsonb_path_query_dynamic(datums)?)),
         }
     }
 
@@ -2445,6 +3642,17 @@ impl TableFunc {
                 let keys = vec![];
                 (column_types, keys)
             }
+            TableFunc::RegexpMatches { .. } | TableFunc::RegexpMatchesDynamic => {
+                let column_types =
+                    vec![ScalarType::Array(Box::new(ScalarType::String)).nullable(false)];
+                let keys = vec![];
+                (column_types, keys)
+            }
+            TableFunc::JsonbPathQuery { .. } | TableFunc::JsonbPathQueryDynamic => {
+                let column_types = vec![ScalarType::Jsonb.nullable(false)];
+                let keys = vec![];
+                (column_types, keys)
+            }
         };
 
         if !keys.is_empty() {
@@ -2470,6 +3678,10 @@ impl TableFunc {
             TableFunc::UnnestArray { .. } => 1,
             TableFunc::UnnestList { .. } => 1,
             TableFunc::Wrap { width, .. } => *width,
+            TableFunc::RegexpMatches { .. } => 1,
+            TableFunc::RegexpMatchesDynamic => 1,
+            TableFunc::JsonbPathQuery { .. } => 1,
+            TableFunc::JsonbPathQueryDynamic => 1,
         }
     }
 
@@ -2487,7 +3699,11 @@ impl TableFunc {
             | TableFunc::CsvExtract(_)
             | TableFunc::Repeat
             | TableFunc::UnnestArray { .. }
-            | TableFunc::UnnestList { .. } => true,
+            | TableFunc::UnnestList { .. }
+            | TableFunc::RegexpMatches { .. }
+            | TableFunc::RegexpMatchesDynamic
+            | TableFunc::JsonbPathQuery { .. }
+            | TableFunc::JsonbPathQueryDynamic => true,
             TableFunc::Wrap { .. } => false,
         }
     }
@@ -2511,6 +3727,10 @@ impl TableFunc {
             TableFunc::UnnestArray { .. } => true,
             TableFunc::UnnestList { .. } => true,
             TableFunc::Wrap { .. } => true,
+            TableFunc::RegexpMatches { .. } => true,
+            TableFunc::RegexpMatchesDynamic => true,
+            TableFunc::JsonbPathQuery { .. } => true,
+            TableFunc::JsonbPathQueryDynamic => true,
         }
     }
 }
@@ -2532,6 +3752,15 @@ impl fmt::Display for TableFunc {
             TableFunc::UnnestArray { .. } => f.write_str("unnest_array"),
             TableFunc::UnnestList { .. } => f.write_str("unnest_list"),
             TableFunc::Wrap { width, .. } => write!(f, "wrap{}", width),
+            TableFunc::RegexpMatches { regex, global } => {
+                let flags = if *global { "g" } else { "" };
+                write!(f, "regexp_matches({:?}, _, {:?})", regex.as_str(), flags)
+            }
+            TableFunc::RegexpMatchesDynamic => f.write_str("regexp_matches"),
+            TableFunc::JsonbPathQuery { path } => {
+                write!(f, "jsonb_path_query(_, {:?})", path.as_str())
+            }
+            TableFunc::JsonbPathQueryDynamic => f.write_str("jsonb_path_query"),
         }
     }
 }
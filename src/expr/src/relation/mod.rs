@@ -26,7 +26,8 @@ use mz_ore::id_gen::IdGen;
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::str::Indent;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
-use mz_repr::adt::numeric::NumericMaxScale;
+use mz_repr::adt::interval::Interval;
+use mz_repr::adt::numeric::{Numeric, NumericMaxScale};
 use mz_repr::explain::text::text_string_at;
 use mz_repr::explain::{DummyHumanizer, ExplainConfig, ExprHumanizer, PlanRenderingContext};
 use mz_repr::{ColumnName, ColumnType, Datum, Diff, GlobalId, RelationType, Row, ScalarType};
@@ -34,11 +35,11 @@ use mz_repr::{ColumnName, ColumnType, Datum, Diff, GlobalId, RelationType, Row,
 use crate::visit::{Visit, VisitChildren};
 use crate::Id::Local;
 use crate::{
-    func as scalar_func, EvalError, FilterCharacteristics, Id, LocalId, MirScalarExpr, UnaryFunc,
-    VariadicFunc,
+    func as scalar_func, BinaryFunc, EvalError, FilterCharacteristics, Id, LocalId, MirScalarExpr,
+    UnaryFunc, VariadicFunc,
 };
 
-use self::func::{AggregateFunc, LagLeadType, TableFunc};
+use self::func::{AggregateFunc, LagLeadType, PercentileFractions, TableFunc};
 
 pub mod canonicalize;
 pub mod func;
@@ -245,6 +246,10 @@ pub enum MirRelationExpr {
         /// User-supplied hint: how many rows will have the same group key.
         #[serde(default)]
         expected_group_size: Option<u64>,
+        /// True iff rows that tie with the last row within `limit`, according
+        /// to `order_key`, should be retained as well.
+        #[serde(default)]
+        with_ties: bool,
     },
     /// Return a dataflow where the row counts are negated
     ///
@@ -792,12 +797,16 @@ impl MirRelationExpr {
                 result
             }
             TopK {
-                group_key, limit, ..
+                group_key,
+                limit,
+                with_ties,
+                ..
             } => {
                 // If `limit` is `Some(1)` then the group key will become
-                // a unique key, as there will be only one record with that key.
+                // a unique key, as there will be only one record with that key,
+                // unless ties are retained.
                 let mut result = input_keys.next().unwrap().clone();
-                if limit == &Some(1) {
+                if limit == &Some(1) && !with_ties {
                     result.push(group_key.clone())
                 }
                 result
@@ -1258,6 +1267,7 @@ impl MirRelationExpr {
         limit: Option<usize>,
         offset: usize,
         expected_group_size: Option<u64>,
+        with_ties: bool,
     ) -> Self {
         MirRelationExpr::TopK {
             input: Box::new(self),
@@ -1267,6 +1277,7 @@ impl MirRelationExpr {
             offset,
             expected_group_size,
             monotonic: false,
+            with_ties,
         }
     }
 
@@ -2136,6 +2147,73 @@ impl AggregateExpr {
         self.func.output_type(self.expr.typ(column_types))
     }
 
+    /// Returns an expression that computes whether the window frame of the only row of a
+    /// partition includes that row. The input of the window function is expected to be a
+    /// ((OriginalRow, InputValue), OrderByExprs...) record.
+    fn window_frame_includes_current_row(
+        &self,
+        input_type: &[ColumnType],
+        order_by: &[ColumnOrder],
+        window_frame: &WindowFrame,
+    ) -> MirScalarExpr {
+        use WindowFrameBound::*;
+        let is_offset =
+            |bound: &WindowFrameBound| matches!(bound, OffsetPreceding(_) | OffsetFollowing(_));
+        if window_frame.units != WindowFrameUnits::Range
+            || !(is_offset(&window_frame.start_bound) || is_offset(&window_frame.end_bound))
+        {
+            return MirScalarExpr::literal_ok(
+                Datum::from(window_frame.includes_current_row()),
+                ScalarType::Bool,
+            );
+        }
+
+        // RANGE offsets are appended after the ORDER BY expressions. The row is in its own frame
+        // unless its frame starts at a following value or ends at a preceding value, which are
+        // different from its own value unless the offset is zero. This assumes that adding a
+        // non-zero offset to the value changes it, which does not hold for floating point and
+        // numeric values that are too large for the offset to affect them.
+        let field = |idx| {
+            self.expr
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(idx)))
+        };
+        let field_types = self.expr.typ(input_type).scalar_type;
+        let field_types = field_types.unwrap_record_element_type();
+        let is_zero = |idx: usize| {
+            let zero = match field_types[idx] {
+                ScalarType::Int16 => Datum::Int16(0),
+                ScalarType::Int32 => Datum::Int32(0),
+                ScalarType::Int64 => Datum::Int64(0),
+                ScalarType::Float32 => Datum::from(0f32),
+                ScalarType::Float64 => Datum::from(0f64),
+                ScalarType::Numeric { .. } => Datum::from(Numeric::zero()),
+                ScalarType::Interval => Datum::Interval(Interval::default()),
+                typ => unreachable!("unsupported RANGE offset type {:?}", typ),
+            };
+            field(idx).call_binary(
+                MirScalarExpr::literal_ok(zero, field_types[idx].clone()),
+                BinaryFunc::Eq,
+            )
+        };
+        let value = field(1 + order_by[0].column);
+        let mut offset_idx = 1 + order_by.len();
+        let mut cond = MirScalarExpr::literal_true();
+        match window_frame.start_bound {
+            OffsetPreceding(_) => offset_idx += 1,
+            OffsetFollowing(_) => {
+                cond = cond.and(is_zero(offset_idx));
+                offset_idx += 1;
+            }
+            _ => (),
+        }
+        if let OffsetPreceding(_) = window_frame.end_bound {
+            cond = cond.and(is_zero(offset_idx));
+        }
+        // A null ORDER BY value is only in the frame of its peers, which includes itself.
+        value.call_is_null().or(cond)
+    }
+
     /// Returns whether the expression has a constant result.
     pub fn is_constant(&self) -> bool {
         match self.func {
@@ -2169,6 +2247,7 @@ impl AggregateExpr {
             | AggregateFunc::MinTimestampTz
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::HllMerge
             | AggregateFunc::Dummy => self.expr.is_literal(),
             AggregateFunc::Count => self.expr.is_literal_null(),
             _ => self.expr.is_literal_err(),
@@ -2342,6 +2421,62 @@ impl AggregateExpr {
                 }
             }
 
+            // Rank, PercentRank and CumeDist take a list of records and output a list containing
+            // exactly 1 element
+            AggregateFunc::Rank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. } => {
+                let list = self
+                    .expr
+                    .clone()
+                    // extract the list within the record
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // extract the expression within the list
+                let record = MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListIndex,
+                    exprs: vec![
+                        list,
+                        MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
+                    ],
+                };
+
+                // The only row of the partition is ranked first, and all rows are its peers
+                let (column_name, value) = match &self.func {
+                    AggregateFunc::Rank { .. } => (
+                        "?rank?",
+                        MirScalarExpr::literal_ok(Datum::Int64(1), ScalarType::Int64),
+                    ),
+                    AggregateFunc::PercentRank { .. } => (
+                        "?percent_rank?",
+                        MirScalarExpr::literal_ok(Datum::from(0.0f64), ScalarType::Float64),
+                    ),
+                    _ => (
+                        "?cume_dist?",
+                        MirScalarExpr::literal_ok(Datum::from(1.0f64), ScalarType::Float64),
+                    ),
+                };
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: self
+                            .typ(input_type)
+                            .scalar_type
+                            .unwrap_list_element_type()
+                            .clone(),
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from(column_name),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, record],
+                    }],
+                }
+            }
+
             // The input type for LagLead is a ((OriginalRow, (InputValue, Offset, Default)), OrderByExprs...)
             AggregateFunc::LagLead { lag_lead, .. } => {
                 let tuple = self
@@ -2409,7 +2544,10 @@ impl AggregateExpr {
             }
 
             // The input type for FirstValue is a ((OriginalRow, InputValue), OrderByExprs...)
-            AggregateFunc::FirstValue { window_frame, .. } => {
+            AggregateFunc::FirstValue {
+                order_by,
+                window_frame,
+            } => {
                 let tuple = self
                     .expr
                     .clone()
@@ -2432,11 +2570,9 @@ impl AggregateExpr {
                 let expr = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
 
                 // If the window frame includes the current (single) row, return its value, null otherwise
-                let value = if window_frame.includes_current_row() {
-                    expr
-                } else {
-                    MirScalarExpr::literal_null(first_value_return_type)
-                };
+                let value = self
+                    .window_frame_includes_current_row(input_type, order_by, window_frame)
+                    .if_then_else(expr, MirScalarExpr::literal_null(first_value_return_type));
 
                 MirScalarExpr::CallVariadic {
                     func: VariadicFunc::ListCreate {
@@ -2455,7 +2591,10 @@ impl AggregateExpr {
             }
 
             // The input type for LastValue is a ((OriginalRow, InputValue), OrderByExprs...)
-            AggregateFunc::LastValue { window_frame, .. } => {
+            AggregateFunc::LastValue {
+                order_by,
+                window_frame,
+            } => {
                 let tuple = self
                     .expr
                     .clone()
@@ -2478,11 +2617,9 @@ impl AggregateExpr {
                 let expr = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
 
                 // If the window frame includes the current (single) row, return its value, null otherwise
-                let value = if window_frame.includes_current_row() {
-                    expr
-                } else {
-                    MirScalarExpr::literal_null(last_value_return_type)
-                };
+                let value = self
+                    .window_frame_includes_current_row(input_type, order_by, window_frame)
+                    .if_then_else(expr, MirScalarExpr::literal_null(last_value_return_type));
 
                 MirScalarExpr::CallVariadic {
                     func: VariadicFunc::ListCreate {
@@ -2500,6 +2637,206 @@ impl AggregateExpr {
                 }
             }
 
+            // The input type for Ntile is a ((OriginalRow, NumBuckets), OrderByExprs...)
+            AggregateFunc::Ntile { .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the number of buckets
+                let num_buckets = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                // The only row of the partition is in the first bucket, unless the number of
+                // buckets is null or not positive
+                let value = num_buckets
+                    .call_binary(
+                        MirScalarExpr::literal_ok(Datum::Int32(0), ScalarType::Int32),
+                        BinaryFunc::Gt,
+                    )
+                    .if_then_else(
+                        MirScalarExpr::literal_ok(Datum::Int32(1), ScalarType::Int32),
+                        MirScalarExpr::literal_null(ScalarType::Int32),
+                    );
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: return_type,
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from("?ntile?"),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, original_row],
+                    }],
+                }
+            }
+
+            // The input type for NthValue is a ((OriginalRow, (InputValue, N)), OrderByExprs...)
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let nth_value_return_type = return_type.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the encoded args
+                let encoded_args =
+                    tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+                let expr = encoded_args
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                let n = encoded_args.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                // The current (single) row is the nth value only if it is in its window frame and
+                // n is 1
+                let value = self
+                    .window_frame_includes_current_row(input_type, order_by, window_frame)
+                    .and(n.call_binary(
+                        MirScalarExpr::literal_ok(Datum::Int32(1), ScalarType::Int32),
+                        BinaryFunc::Eq,
+                    ))
+                    .if_then_else(expr, MirScalarExpr::literal_null(nth_value_return_type));
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: return_type,
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from("?nth_value?"),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, original_row],
+                    }],
+                }
+            }
+
+            // The input type for a window aggregate is a ((OriginalRow, InputValue), OrderByExprs...)
+            AggregateFunc::WindowAggregate {
+                wrapped_aggregate,
+                order_by,
+                window_frame,
+            } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the input value
+                let expr = tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                // If the window frame includes the current (single) row, aggregate its value,
+                // otherwise aggregate the empty frame
+                let agg_return_type = return_type.unwrap_record_element_type()[0].clone();
+                let value = self
+                    .window_frame_includes_current_row(input_type, order_by, window_frame)
+                    .if_then_else(
+                        AggregateExpr {
+                            func: (**wrapped_aggregate).clone(),
+                            expr,
+                            distinct: false,
+                        }
+                        .on_unique(input_type),
+                        MirScalarExpr::literal_ok(wrapped_aggregate.default(), agg_return_type),
+                    );
+
+                MirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ListCreate {
+                        elem_type: return_type,
+                    },
+                    exprs: vec![MirScalarExpr::CallVariadic {
+                        func: VariadicFunc::RecordCreate {
+                            field_names: vec![
+                                ColumnName::from("?window_agg?"),
+                                ColumnName::from("?record?"),
+                            ],
+                        },
+                        exprs: vec![value, original_row],
+                    }],
+                }
+            }
+
+            // PercentileCont, PercentileDisc and Mode take a single level of records and output
+            // the value in the record, once for each fraction if there is an array of fractions
+            AggregateFunc::PercentileCont { fractions, .. }
+            | AggregateFunc::PercentileDisc { fractions, .. } => {
+                let value = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+                match fractions {
+                    PercentileFractions::Scalar(_) => value,
+                    PercentileFractions::Array(fractions) => {
+                        let return_type = self.typ(input_type).scalar_type;
+                        let elem_type = return_type.unwrap_array_element_type().clone();
+                        let exprs = fractions
+                            .iter()
+                            .map(|fraction| match fraction {
+                                Some(_) => value.clone(),
+                                None => MirScalarExpr::literal_null(elem_type.clone()),
+                            })
+                            .collect();
+                        // A null value is skipped, so there is nothing to aggregate.
+                        value.call_is_null().if_then_else(
+                            MirScalarExpr::literal_null(return_type),
+                            MirScalarExpr::CallVariadic {
+                                func: VariadicFunc::ArrayCreate { elem_type },
+                                exprs,
+                            },
+                        )
+                    }
+                }
+            }
+            AggregateFunc::Mode { .. } => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
+
             // All other variants should return the argument to the aggregation.
             AggregateFunc::MaxNumeric
             | AggregateFunc::MaxInt16
@@ -2536,6 +2873,7 @@ impl AggregateExpr {
             | AggregateFunc::SumNumeric
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::HllMerge
             | AggregateFunc::Dummy => self.expr.clone(),
         }
     }
@@ -2997,10 +3335,16 @@ pub enum WindowFrameUnits {
     /// Each row is treated as the unit of work for bounds
     Rows,
     /// Each peer group is treated as the unit of work for bounds,
-    /// and offset-based bounds use the value of the ORDER BY expression
+    /// and offset-based bounds use the value of the ORDER BY expression.
+    ///
+    /// The offset in `OffsetPreceding` and `OffsetFollowing` bounds is not used in this mode.
+    /// Instead, the planner appends the offset of each offset bound (e.g.
+    /// `INTERVAL '5 minutes'`) after the ORDER BY expressions of the window function, first
+    /// the start bound's and then the end bound's. Like in PostgreSQL, a bound whose value is
+    /// out of the range of its type lies beyond all values in the direction of its offset.
     Range,
-    /// Each peer group is treated as the unit of work for bounds.
-    /// Groups is currently not supported, and it is rejected during planning.
+    /// Each peer group is treated as the unit of work for bounds,
+    /// and offsets count peer groups
     Groups,
 }
 
@@ -43,7 +43,8 @@ use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
 use mz_repr::adt::datetime::Timezone;
 use mz_repr::adt::interval::Interval;
-use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::jsonb::{JsonbEdit, JsonbRef};
+use mz_repr::adt::jsonpath::{JsonPath, JsonPathError};
 use mz_repr::adt::numeric::{self, DecimalLike, Numeric, NumericMaxScale};
 use mz_repr::adt::range::{self, Range, RangeBound, RangeOps};
 use mz_repr::adt::regex::any_regex;
@@ -326,7 +327,7 @@ fn add_float64<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     }
 }
 
-fn add_timestamplike_interval<'a, T>(
+pub(crate) fn add_timestamplike_interval<'a, T>(
     a: CheckedTimestamp<T>,
     b: Interval,
 ) -> Result<Datum<'a>, EvalError>
@@ -550,7 +551,7 @@ pub fn add_timestamp_months<T: TimestampLike>(
     Ok(CheckedTimestamp::from_timestamplike(new_dt)?)
 }
 
-fn add_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+pub(crate) fn add_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let mut cx = numeric::cx_datum();
     let mut a = a.unwrap_numeric().0;
     cx.add(&mut a, &b.unwrap_numeric().0);
@@ -561,7 +562,7 @@ fn add_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     }
 }
 
-fn add_interval<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+pub(crate) fn add_interval<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     a.unwrap_interval()
         .checked_add(&b.unwrap_interval())
         .ok_or(EvalError::IntervalOutOfRange)
@@ -804,7 +805,7 @@ fn sub_float64<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     }
 }
 
-fn sub_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+pub(crate) fn sub_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let mut cx = numeric::cx_datum();
     let mut a = a.unwrap_numeric().0;
     cx.sub(&mut a, &b.unwrap_numeric().0);
@@ -831,7 +832,7 @@ fn sub_time<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::from(a.unwrap_time() - b.unwrap_time())
 }
 
-fn sub_interval<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+pub(crate) fn sub_interval<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     b.unwrap_interval()
         .checked_neg()
         .and_then(|b| b.checked_add(&a.unwrap_interval()))
@@ -1249,6 +1250,46 @@ fn uuid_generate_v5<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
     Datum::Uuid(res)
 }
 
+fn set_masklen<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let inet = a.unwrap_inet();
+    let len = b.unwrap_int32();
+    // A length of -1 requests the maximum length for the address family.
+    let prefix_len = if len == -1 {
+        inet.max_prefix_len()
+    } else {
+        u8::try_from(len).map_err(|_| EvalError::InvalidMaskLength(len))?
+    };
+    let inet = inet
+        .with_prefix_len(prefix_len)
+        .map_err(|_| EvalError::InvalidMaskLength(len))?;
+    Ok(Datum::Inet(inet))
+}
+
+fn set_masklen_cidr<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
+    let inet = set_masklen(a, b)?.unwrap_inet();
+    Ok(Datum::Inet(inet.network()))
+}
+
+fn inet_contains<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_inet().contains(&b.unwrap_inet()))
+}
+
+fn inet_contains_or_equals<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_inet().contains_or_equals(&b.unwrap_inet()))
+}
+
+fn inet_contained_by<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(b.unwrap_inet().contains(&a.unwrap_inet()))
+}
+
+fn inet_contained_by_or_equals<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(b.unwrap_inet().contains_or_equals(&a.unwrap_inet()))
+}
+
+fn inet_overlaps<'a>(a: Datum<'a>, b: Datum<'a>) -> Datum<'a> {
+    Datum::from(a.unwrap_inet().overlaps(&b.unwrap_inet()))
+}
+
 fn power_numeric<'a>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError> {
     let mut a = a.unwrap_numeric().0;
     let b = b.unwrap_numeric().0;
@@ -1662,6 +1703,55 @@ fn jsonb_delete_string<'a>(a: Datum<'a>, b: Datum<'a>, temp_storage: &'a RowAren
     }
 }
 
+/// Returns the elements of a `text[]` path into a jsonb value.
+fn jsonb_edit_path(path: Datum) -> Vec<Option<&str>> {
+    path.unwrap_array()
+        .elements()
+        .iter()
+        .map(|elem| match elem {
+            Datum::Null => None,
+            elem => Some(elem.unwrap_str()),
+        })
+        .collect()
+}
+
+fn jsonb_edit<'a>(
+    target: Datum<'a>,
+    path: &[Option<&str>],
+    edit: JsonbEdit,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let target = JsonbRef::from_datum(target);
+    Ok(temp_storage.try_make_datum(|packer| target.pack_edited(packer, path, edit))?)
+}
+
+fn jsonb_delete_path<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    jsonb_edit(a, &jsonb_edit_path(b), JsonbEdit::Delete, temp_storage)
+}
+
+/// Returns the values of an enum type from `a` through `b`, inclusive, in
+/// declaration order. The range is empty if `a` sorts after `b`.
+fn enum_range<'a>(
+    a: Datum<'a>,
+    b: Datum<'a>,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let (lower, upper) = (a.unwrap_uint32(), b.unwrap_uint32());
+    if lower > upper {
+        return Ok(Datum::empty_array());
+    }
+    let dims = [ArrayDimension {
+        lower_bound: 1,
+        length: usize::cast_from(upper - lower) + 1,
+    }];
+    Ok(temp_storage
+        .try_make_datum(|packer| packer.push_array(&dims, (lower..=upper).map(Datum::UInt32)))?)
+}
+
 fn date_part_interval<'a, D>(a: Datum<'a>, b: Datum<'a>) -> Result<Datum<'a>, EvalError>
 where
     D: DecimalLike + Into<Datum<'static>>,
@@ -1960,6 +2050,7 @@ pub enum BinaryFunc {
     JsonbContainsJsonb,
     JsonbDeleteInt64,
     JsonbDeleteString,
+    JsonbDeletePath,
     MapContainsKey,
     MapGetValue,
     MapGetValues,
@@ -2007,6 +2098,14 @@ pub enum BinaryFunc {
     RangeIntersection,
     RangeDifference,
     UuidGenerateV5,
+    EnumRange,
+    SetMasklen,
+    SetMasklenCidr,
+    InetContains,
+    InetContainsOrEquals,
+    InetContainedBy,
+    InetContainedByOrEquals,
+    InetOverlaps,
 }
 
 impl BinaryFunc {
@@ -2268,6 +2367,7 @@ impl BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => Ok(eager!(jsonb_contains_jsonb)),
             BinaryFunc::JsonbDeleteInt64 => Ok(eager!(jsonb_delete_int64, temp_storage)),
             BinaryFunc::JsonbDeleteString => Ok(eager!(jsonb_delete_string, temp_storage)),
+            BinaryFunc::JsonbDeletePath => eager!(jsonb_delete_path, temp_storage),
             BinaryFunc::MapContainsKey => Ok(eager!(map_contains_key)),
             BinaryFunc::MapGetValue => Ok(eager!(map_get_value)),
             BinaryFunc::MapGetValues => Ok(eager!(map_get_values, temp_storage)),
@@ -2330,6 +2430,14 @@ impl BinaryFunc {
             BinaryFunc::RangeIntersection => eager!(range_intersection, temp_storage),
             BinaryFunc::RangeDifference => eager!(range_difference, temp_storage),
             BinaryFunc::UuidGenerateV5 => Ok(eager!(uuid_generate_v5)),
+            BinaryFunc::EnumRange => eager!(enum_range, temp_storage),
+            BinaryFunc::SetMasklen => eager!(set_masklen),
+            BinaryFunc::SetMasklenCidr => eager!(set_masklen_cidr),
+            BinaryFunc::InetContains => Ok(eager!(inet_contains)),
+            BinaryFunc::InetContainsOrEquals => Ok(eager!(inet_contains_or_equals)),
+            BinaryFunc::InetContainedBy => Ok(eager!(inet_contained_by)),
+            BinaryFunc::InetContainedByOrEquals => Ok(eager!(inet_contained_by_or_equals)),
+            BinaryFunc::InetOverlaps => Ok(eager!(inet_overlaps)),
         }
     }
 
@@ -2447,6 +2555,8 @@ impl BinaryFunc {
             | JsonbDeleteInt64
             | JsonbDeleteString => ScalarType::Jsonb.nullable(true),
 
+            JsonbDeletePath => ScalarType::Jsonb.nullable(in_nullable),
+
             JsonbContainsString | JsonbContainsJsonb | MapContainsKey | MapContainsAllKeys
             | MapContainsAnyKeys | MapContainsMap => ScalarType::Bool.nullable(in_nullable),
 
@@ -2486,6 +2596,16 @@ impl BinaryFunc {
             GetByte => ScalarType::Int32.nullable(in_nullable),
 
             UuidGenerateV5 => ScalarType::Uuid.nullable(in_nullable),
+            EnumRange => ScalarType::Array(Box::new(input1_type.scalar_type.clone()))
+                .nullable(in_nullable),
+
+            SetMasklen => ScalarType::Inet.nullable(in_nullable),
+            SetMasklenCidr => ScalarType::Cidr.nullable(in_nullable),
+            InetContains
+            | InetContainsOrEquals
+            | InetContainedBy
+            | InetContainedByOrEquals
+            | InetOverlaps => ScalarType::Bool.nullable(in_nullable),
 
             RangeContainsElem { .. }
             | RangeContainsRange { .. }
@@ -2696,7 +2816,16 @@ impl BinaryFunc {
             | RangeUnion
             | RangeIntersection
             | RangeDifference
-            | UuidGenerateV5 => false,
+            | UuidGenerateV5
+            | EnumRange
+            | SetMasklen
+            | SetMasklenCidr
+            | InetContains
+            | InetContainsOrEquals
+            | InetContainedBy
+            | InetContainedByOrEquals
+            | InetOverlaps
+            | JsonbDeletePath => false,
             // can produce nulls inside the resulting array for missing keys, but always produces an outer array
             MapGetValues => false,
 
@@ -2823,6 +2952,7 @@ impl BinaryFunc {
             | JsonbContainsString
             | JsonbDeleteInt64
             | JsonbDeleteString
+            | JsonbDeletePath
             | MapContainsKey
             | MapGetValue
             | MapGetValues
@@ -2850,7 +2980,12 @@ impl BinaryFunc {
             | RangeAdjacent
             | RangeUnion
             | RangeIntersection
-            | RangeDifference => true,
+            | RangeDifference
+            | InetContains
+            | InetContainsOrEquals
+            | InetContainedBy
+            | InetContainedByOrEquals
+            | InetOverlaps => true,
             ToCharTimestamp
             | ToCharTimestampTz
             | DateBinTimestamp
@@ -2896,6 +3031,9 @@ impl BinaryFunc {
             | ListRemove
             | LikeEscape
             | UuidGenerateV5
+            | EnumRange
+            | SetMasklen
+            | SetMasklenCidr
             | GetByte => false,
         }
     }
@@ -3075,6 +3213,7 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb | BinaryFunc::MapContainsMap => f.write_str("@>"),
             BinaryFunc::JsonbDeleteInt64 => f.write_str("-"),
             BinaryFunc::JsonbDeleteString => f.write_str("-"),
+            BinaryFunc::JsonbDeletePath => f.write_str("#-"),
             BinaryFunc::MapGetValue | BinaryFunc::MapGetValues => f.write_str("->"),
             BinaryFunc::MapContainsAllKeys => f.write_str("?&"),
             BinaryFunc::MapContainsAnyKeys => f.write_str("?|"),
@@ -3123,6 +3262,13 @@ impl fmt::Display for BinaryFunc {
             BinaryFunc::RangeIntersection => f.write_str("*"),
             BinaryFunc::RangeDifference => f.write_str("-"),
             BinaryFunc::UuidGenerateV5 => f.write_str("uuid_generate_v5"),
+            BinaryFunc::EnumRange => f.write_str("enum_range"),
+            BinaryFunc::SetMasklen | BinaryFunc::SetMasklenCidr => f.write_str("set_masklen"),
+            BinaryFunc::InetContains => f.write_str(">>"),
+            BinaryFunc::InetContainsOrEquals => f.write_str(">>="),
+            BinaryFunc::InetContainedBy => f.write_str("<<"),
+            BinaryFunc::InetContainedByOrEquals => f.write_str("<<="),
+            BinaryFunc::InetOverlaps => f.write_str("&&"),
         }
     }
 }
@@ -3285,6 +3431,7 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::JsonbContainsJsonb).boxed(),
             Just(BinaryFunc::JsonbDeleteInt64).boxed(),
             Just(BinaryFunc::JsonbDeleteString).boxed(),
+            Just(BinaryFunc::JsonbDeletePath).boxed(),
             Just(BinaryFunc::MapContainsKey).boxed(),
             Just(BinaryFunc::MapGetValue).boxed(),
             Just(BinaryFunc::MapGetValues).boxed(),
@@ -3336,6 +3483,14 @@ impl Arbitrary for BinaryFunc {
             Just(BinaryFunc::RangeUnion).boxed(),
             Just(BinaryFunc::RangeIntersection).boxed(),
             Just(BinaryFunc::RangeDifference).boxed(),
+            Just(BinaryFunc::EnumRange).boxed(),
+            Just(BinaryFunc::SetMasklen).boxed(),
+            Just(BinaryFunc::SetMasklenCidr).boxed(),
+            Just(BinaryFunc::InetContains).boxed(),
+            Just(BinaryFunc::InetContainsOrEquals).boxed(),
+            Just(BinaryFunc::InetContainedBy).boxed(),
+            Just(BinaryFunc::InetContainedByOrEquals).boxed(),
+            Just(BinaryFunc::InetOverlaps).boxed(),
         ])
     }
 }
@@ -3477,6 +3632,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::JsonbContainsJsonb => JsonbContainsJsonb(()),
             BinaryFunc::JsonbDeleteInt64 => JsonbDeleteInt64(()),
             BinaryFunc::JsonbDeleteString => JsonbDeleteString(()),
+            BinaryFunc::JsonbDeletePath => JsonbDeletePath(()),
             BinaryFunc::MapContainsKey => MapContainsKey(()),
             BinaryFunc::MapGetValue => MapGetValue(()),
             BinaryFunc::MapGetValues => MapGetValues(()),
@@ -3529,6 +3685,14 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
             BinaryFunc::RangeIntersection => RangeIntersection(()),
             BinaryFunc::RangeDifference => RangeDifference(()),
             BinaryFunc::UuidGenerateV5 => UuidGenerateV5(()),
+            BinaryFunc::EnumRange => EnumRange(()),
+            BinaryFunc::SetMasklen => SetMasklen(()),
+            BinaryFunc::SetMasklenCidr => SetMasklenCidr(()),
+            BinaryFunc::InetContains => InetContains(()),
+            BinaryFunc::InetContainsOrEquals => InetContainsOrEquals(()),
+            BinaryFunc::InetContainedBy => InetContainedBy(()),
+            BinaryFunc::InetContainedByOrEquals => InetContainedByOrEquals(()),
+            BinaryFunc::InetOverlaps => InetOverlaps(()),
         };
         ProtoBinaryFunc { kind: Some(kind) }
     }
@@ -3674,6 +3838,7 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 JsonbContainsJsonb(()) => Ok(BinaryFunc::JsonbContainsJsonb),
                 JsonbDeleteInt64(()) => Ok(BinaryFunc::JsonbDeleteInt64),
                 JsonbDeleteString(()) => Ok(BinaryFunc::JsonbDeleteString),
+                JsonbDeletePath(()) => Ok(BinaryFunc::JsonbDeletePath),
                 MapContainsKey(()) => Ok(BinaryFunc::MapContainsKey),
                 MapGetValue(()) => Ok(BinaryFunc::MapGetValue),
                 MapGetValues(()) => Ok(BinaryFunc::MapGetValues),
@@ -3728,6 +3893,14 @@ impl RustType<ProtoBinaryFunc> for BinaryFunc {
                 RangeIntersection(()) => Ok(BinaryFunc::RangeIntersection),
                 RangeDifference(()) => Ok(BinaryFunc::RangeDifference),
                 UuidGenerateV5(()) => Ok(BinaryFunc::UuidGenerateV5),
+                EnumRange(()) => Ok(BinaryFunc::EnumRange),
+                SetMasklen(()) => Ok(BinaryFunc::SetMasklen),
+                SetMasklenCidr(()) => Ok(BinaryFunc::SetMasklenCidr),
+                InetContains(()) => Ok(BinaryFunc::InetContains),
+                InetContainsOrEquals(()) => Ok(BinaryFunc::InetContainsOrEquals),
+                InetContainedBy(()) => Ok(BinaryFunc::InetContainedBy),
+                InetContainedByOrEquals(()) => Ok(BinaryFunc::InetContainedByOrEquals),
+                InetOverlaps(()) => Ok(BinaryFunc::InetOverlaps),
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoBinaryFunc::kind"))
@@ -4076,6 +4249,14 @@ derive_unary!(
     CastMapToString,
     CastInt2VectorToString,
     CastRangeToString,
+    CastEnumToString,
+    CastStringToEnum,
+    CastInetToString,
+    CastCidrToString,
+    CastStringToInet,
+    CastStringToCidr,
+    CastCidrToInet,
+    CastInetToCidr,
     CeilFloat32,
     CeilFloat64,
     CeilNumeric,
@@ -4165,7 +4346,21 @@ derive_unary!(
     RangeUpperInf,
     MzAclItemGrantor,
     MzAclItemGrantee,
-    MzAclItemPrivileges
+    MzAclItemPrivileges,
+    Host,
+    Masklen,
+    Network,
+    CheckNtileArgument,
+    CheckNthValueArgument,
+    HllSketchValue,
+    HllValidate,
+    HllEstimate,
+    RegexpReplace,
+    RegexpSplitToArray,
+    RegexpCount,
+    JsonbPathExists,
+    JsonbPathMatch,
+    JsonbPathQueryFirst
 );
 
 impl UnaryFunc {
@@ -4469,6 +4664,14 @@ impl Arbitrary for UnaryFunc {
             CastMapToString::arbitrary().prop_map_into().boxed(),
             CastInt2VectorToString::arbitrary().prop_map_into().boxed(),
             CastRangeToString::arbitrary().prop_map_into().boxed(),
+            CastEnumToString::arbitrary().prop_map_into().boxed(),
+            CastStringToEnum::arbitrary().prop_map_into().boxed(),
+            CastInetToString::arbitrary().prop_map_into().boxed(),
+            CastCidrToString::arbitrary().prop_map_into().boxed(),
+            CastStringToInet::arbitrary().prop_map_into().boxed(),
+            CastStringToCidr::arbitrary().prop_map_into().boxed(),
+            CastCidrToInet::arbitrary().prop_map_into().boxed(),
+            CastInetToCidr::arbitrary().prop_map_into().boxed(),
             CeilFloat32::arbitrary().prop_map_into().boxed(),
             CeilFloat64::arbitrary().prop_map_into().boxed(),
             CeilNumeric::arbitrary().prop_map_into().boxed(),
@@ -4564,6 +4767,32 @@ impl Arbitrary for UnaryFunc {
             MzAclItemGrantor::arbitrary().prop_map_into().boxed(),
             MzAclItemGrantee::arbitrary().prop_map_into().boxed(),
             MzAclItemPrivileges::arbitrary().prop_map_into().boxed(),
+            Host::arbitrary().prop_map_into().boxed(),
+            Masklen::arbitrary().prop_map_into().boxed(),
+            Network::arbitrary().prop_map_into().boxed(),
+            CheckNtileArgument::arbitrary().prop_map_into().boxed(),
+            CheckNthValueArgument::arbitrary().prop_map_into().boxed(),
+            HllSketchValue::arbitrary().prop_map_into().boxed(),
+            HllValidate::arbitrary().prop_map_into().boxed(),
+            HllEstimate::arbitrary().prop_map_into().boxed(),
+            (any_regex(), any::<String>(), any::<usize>())
+                .prop_map(|(regex, replacement, limit)| {
+                    UnaryFunc::RegexpReplace(RegexpReplace {
+                        regex,
+                        replacement,
+                        limit,
+                    })
+                })
+                .boxed(),
+            any_regex()
+                .prop_map(|regex| UnaryFunc::RegexpSplitToArray(RegexpSplitToArray(regex)))
+                .boxed(),
+            (any_regex(), any::<i32>())
+                .prop_map(|(regex, start)| UnaryFunc::RegexpCount(RegexpCount { regex, start }))
+                .boxed(),
+            JsonbPathExists::arbitrary().prop_map_into().boxed(),
+            JsonbPathMatch::arbitrary().prop_map_into().boxed(),
+            JsonbPathQueryFirst::arbitrary().prop_map_into().boxed(),
         ])
     }
 }
@@ -4812,6 +5041,14 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::CastMapToString(func) => CastMapToString(func.ty.into_proto()),
             UnaryFunc::CastInt2VectorToString(_) => CastInt2VectorToString(()),
             UnaryFunc::CastRangeToString(func) => CastRangeToString(func.ty.into_proto()),
+            UnaryFunc::CastEnumToString(func) => CastEnumToString(func.ty.into_proto()),
+            UnaryFunc::CastStringToEnum(func) => CastStringToEnum(func.return_ty.into_proto()),
+            UnaryFunc::CastInetToString(_) => CastInetToString(()),
+            UnaryFunc::CastCidrToString(_) => CastCidrToString(()),
+            UnaryFunc::CastStringToInet(_) => CastStringToInet(()),
+            UnaryFunc::CastStringToCidr(_) => CastStringToCidr(()),
+            UnaryFunc::CastCidrToInet(_) => CastCidrToInet(()),
+            UnaryFunc::CastInetToCidr(_) => CastInetToCidr(()),
             UnaryFunc::CeilFloat32(_) => CeilFloat32(()),
             UnaryFunc::CeilFloat64(_) => CeilFloat64(()),
             UnaryFunc::CeilNumeric(_) => CeilNumeric(()),
@@ -4914,6 +5151,27 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
             UnaryFunc::MzAclItemGrantor(_) => MzAclItemGrantor(()),
             UnaryFunc::MzAclItemGrantee(_) => MzAclItemGrantee(()),
             UnaryFunc::MzAclItemPrivileges(_) => MzAclItemPrivileges(()),
+            UnaryFunc::Host(_) => Host(()),
+            UnaryFunc::Masklen(_) => Masklen(()),
+            UnaryFunc::Network(_) => Network(()),
+            UnaryFunc::CheckNtileArgument(_) => CheckNtileArgument(()),
+            UnaryFunc::CheckNthValueArgument(_) => CheckNthValueArgument(()),
+            UnaryFunc::HllSketchValue(_) => HllSketchValue(()),
+            UnaryFunc::HllValidate(_) => HllValidate(()),
+            UnaryFunc::HllEstimate(_) => HllEstimate(()),
+            UnaryFunc::RegexpReplace(func) => RegexpReplace(ProtoRegexpReplace {
+                regex: Some(func.regex.into_proto()),
+                replacement: func.replacement.clone(),
+                limit: func.limit.into_proto(),
+            }),
+            UnaryFunc::RegexpSplitToArray(regex) => RegexpSplitToArray(regex.0.into_proto()),
+            UnaryFunc::RegexpCount(func) => RegexpCount(ProtoRegexpCount {
+                regex: Some(func.regex.into_proto()),
+                start: func.start,
+            }),
+            UnaryFunc::JsonbPathExists(func) => JsonbPathExists(func.0.into_proto()),
+            UnaryFunc::JsonbPathMatch(func) => JsonbPathMatch(func.0.into_proto()),
+            UnaryFunc::JsonbPathQueryFirst(func) => JsonbPathQueryFirst(func.0.into_proto()),
         };
         ProtoUnaryFunc { kind: Some(kind) }
     }
@@ -5218,6 +5476,20 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                     ty: ty.into_rust()?,
                 }
                 .into()),
+                CastEnumToString(ty) => Ok(impls::CastEnumToString {
+                    ty: ty.into_rust()?,
+                }
+                .into()),
+                CastStringToEnum(ty) => Ok(impls::CastStringToEnum {
+                    return_ty: ty.into_rust()?,
+                }
+                .into()),
+                CastInetToString(()) => Ok(impls::CastInetToString.into()),
+                CastCidrToString(()) => Ok(impls::CastCidrToString.into()),
+                CastStringToInet(()) => Ok(impls::CastStringToInet.into()),
+                CastStringToCidr(()) => Ok(impls::CastStringToCidr.into()),
+                CastCidrToInet(()) => Ok(impls::CastCidrToInet.into()),
+                CastInetToCidr(()) => Ok(impls::CastInetToCidr.into()),
                 CeilFloat32(_) => Ok(impls::CeilFloat32.into()),
                 CeilFloat64(_) => Ok(impls::CeilFloat64.into()),
                 CeilNumeric(_) => Ok(impls::CeilNumeric.into()),
@@ -5334,6 +5606,33 @@ impl RustType<ProtoUnaryFunc> for UnaryFunc {
                 MzAclItemGrantor(_) => Ok(impls::MzAclItemGrantor.into()),
                 MzAclItemGrantee(_) => Ok(impls::MzAclItemGrantee.into()),
                 MzAclItemPrivileges(_) => Ok(impls::MzAclItemPrivileges.into()),
+                Host(_) => Ok(impls::Host.into()),
+                Masklen(_) => Ok(impls::Masklen.into()),
+                Network(_) => Ok(impls::Network.into()),
+                CheckNtileArgument(_) => Ok(impls::CheckNtileArgument.into()),
+                CheckNthValueArgument(_) => Ok(impls::CheckNthValueArgument.into()),
+                HllSketchValue(_) => Ok(impls::HllSketchValue.into()),
+                HllValidate(_) => Ok(impls::HllValidate.into()),
+                HllEstimate(_) => Ok(impls::HllEstimate.into()),
+                RegexpReplace(func) => Ok(impls::RegexpReplace {
+                    regex: func.regex.into_rust_if_some("ProtoRegexpReplace::regex")?,
+                    replacement: func.replacement,
+                    limit: func.limit.into_rust()?,
+                }
+                .into()),
+                RegexpSplitToArray(regex) => {
+                    Ok(impls::RegexpSplitToArray(regex.into_rust()?).into())
+                }
+                RegexpCount(func) => Ok(impls::RegexpCount {
+                    regex: func.regex.into_rust_if_some("ProtoRegexpCount::regex")?,
+                    start: func.start,
+                }
+                .into()),
+                JsonbPathExists(func) => Ok(impls::JsonbPathExists(func.into_rust()?).into()),
+                JsonbPathMatch(func) => Ok(impls::JsonbPathMatch(func.into_rust()?).into()),
+                JsonbPathQueryFirst(func) => {
+                    Ok(impls::JsonbPathQueryFirst(func.into_rust()?).into())
+                }
             }
         } else {
             Err(TryFromProtoError::missing_field("ProtoUnaryFunc::kind"))
@@ -5626,19 +5925,225 @@ fn regexp_match_static<'a>(
 }
 
 pub fn build_regex(needle: &str, flags: &str) -> Result<regex::Regex, EvalError> {
-    let mut regex = RegexBuilder::new(needle);
+    let mut case_insensitive = false;
     for f in flags.chars() {
         match f {
-            'i' => {
-                regex.case_insensitive(true);
+            'i' => case_insensitive = true,
+            'c' => case_insensitive = false,
+            _ => return Err(EvalError::InvalidRegexFlag(f)),
+        }
+    }
+    // Case insensitivity is spelled in the pattern, rather than configured on
+    // the builder, so that it survives serialization of the compiled regex,
+    // which only retains the pattern.
+    let regex = if case_insensitive {
+        RegexBuilder::new(&format!("(?i:{})", needle)).build()
+    } else {
+        RegexBuilder::new(needle).build()
+    };
+    Ok(regex?)
+}
+
+/// Like [`build_regex`], but additionally accepts the `g` flag, which requests
+/// that a function apply to every match of the regex rather than only the
+/// first. Returns the regex and the maximum number of matches to apply to, with
+/// `0` meaning all of them.
+pub fn build_regex_with_limit(
+    needle: &str,
+    flags: &str,
+) -> Result<(regex::Regex, usize), EvalError> {
+    let limit = if flags.contains('g') { 0 } else { 1 };
+    let regex = build_regex(needle, &flags.replace('g', ""))?;
+    Ok((regex, limit))
+}
+
+/// Returns the successive matches of `regex` in `haystack`, starting at byte
+/// offset `start`, as the locations of their capture groups.
+///
+/// Matches are found as PostgreSQL finds them: each search resumes at the end
+/// of the previous match, or one character past it if the match was empty.
+/// Unlike [`regex::Regex::find_iter`], this admits an empty match immediately
+/// following a nonempty one.
+pub fn regexp_match_locations<'a>(
+    regex: &'a regex::Regex,
+    haystack: &'a str,
+    start: usize,
+) -> impl Iterator<Item = regex::CaptureLocations> + 'a {
+    let mut next = Some(start);
+    iter::from_fn(move || {
+        let start = next?;
+        let mut locations = regex.capture_locations();
+        let mtch = regex.captures_read_at(&mut locations, haystack, start)?;
+        next = if mtch.end() > mtch.start() {
+            Some(mtch.end())
+        } else {
+            haystack[mtch.end()..]
+                .chars()
+                .next()
+                .map(|c| mtch.end() + c.len_utf8())
+        };
+        Some(locations)
+    })
+}
+
+fn regexp_replace_dynamic<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let source = datums[0].unwrap_str();
+    let needle = datums[1].unwrap_str();
+    let replacement = datums[2].unwrap_str();
+    let flags = match datums.get(3) {
+        Some(d) => d.unwrap_str(),
+        None => "",
+    };
+    let (needle, limit) = build_regex_with_limit(needle, flags)?;
+    let replaced = regexp_replace_static(source, &needle, replacement, limit);
+    Ok(Datum::String(temp_storage.push_string(replaced)))
+}
+
+/// Replaces the first `limit` matches of `needle` in `source`, or all of them
+/// if `limit` is `0`, with `replacement`.
+///
+/// As in PostgreSQL, `\n` in `replacement` stands for the text matched by the
+/// `n`th capture group, `\&` for the text of the entire match, and `\\` for a
+/// literal backslash.
+pub fn regexp_replace_static(
+    source: &str,
+    needle: &regex::Regex,
+    replacement: &str,
+    limit: usize,
+) -> String {
+    let limit = match limit {
+        0 => usize::MAX,
+        limit => limit,
+    };
+    let mut out = String::with_capacity(source.len());
+    let mut last_end = 0;
+    for locations in regexp_match_locations(needle, source, 0).take(limit) {
+        let (start, end) = locations.get(0).expect("group 0 always participates");
+        out.push_str(&source[last_end..start]);
+        let mut chars = replacement.chars();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                out.push(c);
+                continue;
             }
-            'c' => {
-                regex.case_insensitive(false);
+            let group = match chars.next() {
+                Some(d @ '1'..='9') => d.to_digit(10).map(usize::cast_from),
+                Some('&') => Some(0),
+                Some('\\') => {
+                    out.push('\\');
+                    None
+                }
+                Some(other) => {
+                    out.push('\\');
+                    out.push(other);
+                    None
+                }
+                None => {
+                    out.push('\\');
+                    None
+                }
+            };
+            if let Some((start, end)) = group.and_then(|group| locations.get(group)) {
+                out.push_str(&source[start..end]);
             }
-            _ => return Err(EvalError::InvalidRegexFlag(f)),
         }
+        last_end = end;
+    }
+    out.push_str(&source[last_end..]);
+    out
+}
+
+fn regexp_split_to_array_dynamic<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let haystack = datums[0].unwrap_str();
+    let needle = datums[1].unwrap_str();
+    let flags = match datums.get(2) {
+        Some(d) => d.unwrap_str(),
+        None => "",
+    };
+    let needle = build_regex(needle, flags)?;
+    regexp_split_to_array_static(haystack, &needle, temp_storage)
+}
+
+pub fn regexp_split_to_array_static<'a>(
+    haystack: &'a str,
+    needle: &regex::Regex,
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let parts = regexp_split(haystack, needle);
+    temp_storage.try_make_datum(|packer| {
+        packer.push_array(
+            &[ArrayDimension {
+                lower_bound: 1,
+                length: parts.len(),
+            }],
+            parts.into_iter().map(Datum::String),
+        )
+    })
+}
+
+/// Splits `haystack` around the matches of `needle`.
+///
+/// Like PostgreSQL, this ignores empty matches at the start or end of
+/// `haystack` and immediately after a previous match, so that, e.g., splitting
+/// on the empty pattern produces the individual characters of `haystack`.
+fn regexp_split<'a>(haystack: &'a str, needle: &regex::Regex) -> Vec<&'a str> {
+    let mut parts = vec![];
+    let mut part_start = 0;
+    let mut prev_match_end = 0;
+    for locations in regexp_match_locations(needle, haystack, 0) {
+        let (start, end) = locations.get(0).expect("group 0 always participates");
+        if start < haystack.len() && end > prev_match_end {
+            parts.push(&haystack[part_start..start]);
+            part_start = end;
+        }
+        prev_match_end = end;
     }
-    Ok(regex.build()?)
+    parts.push(&haystack[part_start..]);
+    parts
+}
+
+fn regexp_count_dynamic<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
+    let haystack = datums[0].unwrap_str();
+    let needle = datums[1].unwrap_str();
+    let start = match datums.get(2) {
+        Some(d) => d.unwrap_int32(),
+        None => 1,
+    };
+    let flags = match datums.get(3) {
+        Some(d) => d.unwrap_str(),
+        None => "",
+    };
+    let needle = build_regex(needle, flags)?;
+    regexp_count_static(haystack, &needle, start).map(Datum::Int32)
+}
+
+/// Counts the matches of `needle` in `haystack`, beginning the search at the
+/// 1-based character position `start`.
+pub fn regexp_count_static(
+    haystack: &str,
+    needle: &regex::Regex,
+    start: i32,
+) -> Result<i32, EvalError> {
+    let offset = match usize::try_from(start) {
+        Ok(start) if start > 0 => haystack
+            .char_indices()
+            .nth(start - 1)
+            .map_or(haystack.len(), |(i, _)| i),
+        _ => {
+            return Err(EvalError::InvalidParameterValue(format!(
+                "invalid value for parameter \"start\": {}",
+                start
+            )))
+        }
+    };
+    let count = regexp_match_locations(needle, haystack, offset).count();
+    i32::try_from(count).map_err(|_| EvalError::Int32OutOfRange)
 }
 
 pub fn hmac_string<'a>(
@@ -5765,6 +6270,122 @@ fn jsonb_build_object<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> D
     }
 }
 
+/// Evaluates a `jsonb_path_*` function, whose arguments are the target, the
+/// path, and optionally the path's variables and whether to suppress errors.
+///
+/// The path is parsed on every call. Calls whose arguments other than the
+/// target are constant are instead reduced to a [`UnaryFunc`] that holds the
+/// parsed path, see [`JsonbPath`].
+///
+/// Returns `None` if evaluating the path raised an error that was suppressed.
+pub(crate) fn eval_jsonb_path<T>(
+    datums: &[Datum],
+    f: impl FnOnce(&JsonPath, Datum, Option<Datum>) -> Result<T, JsonPathError>,
+) -> Result<Option<T>, EvalError> {
+    let path: JsonPath = datums[1].unwrap_str().parse()?;
+    let vars = datums.get(2).copied();
+    let silent = datums.get(3).map_or(false, |d| d.unwrap_bool());
+    suppress_jsonb_path_error(f(&path, datums[0], vars), silent)
+}
+
+/// Returns `None` in place of an error raised while evaluating a jsonpath if
+/// `silent` is set and the error is one that may be suppressed.
+pub(crate) fn suppress_jsonb_path_error<T>(
+    result: Result<T, JsonPathError>,
+    silent: bool,
+) -> Result<Option<T>, EvalError> {
+    match result {
+        Ok(result) => Ok(Some(result)),
+        Err(e) if silent && e.is_suppressible() => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn jsonb_set<'a>(datums: &[Datum<'a>], temp_storage: &'a RowArena) -> Result<Datum<'a>, EvalError> {
+    let edit = JsonbEdit::Set {
+        value: datums[2],
+        create_missing: datums.get(3).map_or(true, |d| d.unwrap_bool()),
+    };
+    jsonb_edit(datums[0], &jsonb_edit_path(datums[1]), edit, temp_storage)
+}
+
+/// Like [`jsonb_set`], but with a `null_value_treatment` argument that
+/// determines the result if the new value is SQL `NULL`.
+fn jsonb_set_lax<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    if datums[0].is_null() || datums[1].is_null() || datums.get(3) == Some(&Datum::Null) {
+        return Ok(Datum::Null);
+    }
+    let treatment = match datums.get(4) {
+        None => "use_json_null",
+        Some(Datum::Null) => return Err(invalid_null_value_treatment()),
+        Some(treatment) => treatment.unwrap_str(),
+    };
+    if !datums[2].is_null() {
+        return jsonb_set(datums, temp_storage);
+    }
+    let path = jsonb_edit_path(datums[1]);
+    match treatment {
+        "raise_exception" => Err(EvalError::JsonbNullValue),
+        "use_json_null" => {
+            let edit = JsonbEdit::Set {
+                value: Datum::JsonNull,
+                create_missing: datums.get(3).map_or(true, |d| d.unwrap_bool()),
+            };
+            jsonb_edit(datums[0], &path, edit, temp_storage)
+        }
+        "delete_key" => jsonb_edit(datums[0], &path, JsonbEdit::Delete, temp_storage),
+        "return_target" => Ok(datums[0]),
+        _ => Err(invalid_null_value_treatment()),
+    }
+}
+
+fn invalid_null_value_treatment() -> EvalError {
+    EvalError::InvalidParameterValue(
+        "null_value_treatment must be \"delete_key\", \"return_target\", \"use_json_null\", \
+         or \"raise_exception\""
+            .into(),
+    )
+}
+
+fn jsonb_insert<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let edit = JsonbEdit::Insert {
+        value: datums[2],
+        after: datums.get(3).map_or(false, |d| d.unwrap_bool()),
+    };
+    jsonb_edit(datums[0], &jsonb_edit_path(datums[1]), edit, temp_storage)
+}
+
+fn jsonb_path_exists<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
+    let exists = eval_jsonb_path(datums, |path, target, vars| path.exists(target, vars))?;
+    Ok(exists.map_or(Datum::Null, Datum::from))
+}
+
+fn jsonb_path_match<'a>(datums: &[Datum<'a>]) -> Result<Datum<'a>, EvalError> {
+    let matches = eval_jsonb_path(datums, |path, target, vars| path.matches(target, vars))?;
+    Ok(matches.flatten().map_or(Datum::Null, Datum::from))
+}
+
+fn jsonb_path_query_first<'a>(
+    datums: &[Datum<'a>],
+    temp_storage: &'a RowArena,
+) -> Result<Datum<'a>, EvalError> {
+    let first = eval_jsonb_path(datums, |path, target, vars| {
+        // The selected items may borrow from the path, so they must be copied
+        // out before it is dropped.
+        let items = path.query(target, vars)?;
+        Ok(items
+            .first()
+            .map(|item| temp_storage.make_datum(|packer| packer.push(*item))))
+    })?;
+    Ok(first.flatten().unwrap_or(Datum::Null))
+}
+
 /// Constructs a new multidimensional array out of an arbitrary number of
 /// lower-dimensional arrays.
 ///
@@ -5906,6 +6527,8 @@ where
         }
         Jsonb => Ok(strconv::format_jsonb(buf, JsonbRef::from_datum(d))),
         Uuid => Ok(strconv::format_uuid(buf, d.unwrap_uuid())),
+        Inet => Ok(strconv::format_inet(buf, d.unwrap_inet())),
+        Cidr => Ok(strconv::format_cidr(buf, d.unwrap_inet())),
         Record { fields, .. } => {
             let mut fields = fields.iter();
             strconv::format_record(buf, &d.unwrap_list(), |buf, d| {
@@ -5952,6 +6575,10 @@ where
             None => Ok::<_, EvalError>(buf.write_null()),
         }),
         MzAclItem => Ok(strconv::format_mz_acl_item(buf, d.unwrap_mz_acl_item())),
+        Enum { labels, .. } => Ok(strconv::format_string(
+            buf,
+            &labels[usize::cast_from(d.unwrap_uint32())],
+        )),
     }
 }
 
@@ -6641,6 +7268,15 @@ pub enum VariadicFunc {
     },
     MakeMzAclItem,
     Translate,
+    RegexpReplace,
+    RegexpSplitToArray,
+    RegexpCount,
+    JsonbPathExists,
+    JsonbPathMatch,
+    JsonbPathQueryFirst,
+    JsonbSet,
+    JsonbSetLax,
+    JsonbInsert,
 }
 
 impl VariadicFunc {
@@ -6707,6 +7343,17 @@ impl VariadicFunc {
             VariadicFunc::Or => or(datums, temp_storage, exprs),
             VariadicFunc::RangeCreate { .. } => eager!(create_range, temp_storage),
             VariadicFunc::MakeMzAclItem => eager!(make_mz_acl_item),
+            VariadicFunc::RegexpReplace => eager!(regexp_replace_dynamic, temp_storage),
+            VariadicFunc::RegexpSplitToArray => {
+                eager!(regexp_split_to_array_dynamic, temp_storage)
+            }
+            VariadicFunc::RegexpCount => eager!(regexp_count_dynamic),
+            VariadicFunc::JsonbPathExists => eager!(jsonb_path_exists),
+            VariadicFunc::JsonbPathMatch => eager!(jsonb_path_match),
+            VariadicFunc::JsonbPathQueryFirst => eager!(jsonb_path_query_first, temp_storage),
+            VariadicFunc::JsonbSet => eager!(jsonb_set, temp_storage),
+            VariadicFunc::JsonbSetLax => eager!(jsonb_set_lax, temp_storage),
+            VariadicFunc::JsonbInsert => eager!(jsonb_insert, temp_storage),
         }
     }
 
@@ -6741,7 +7388,16 @@ impl VariadicFunc {
             | VariadicFunc::DateBinTimestamp
             | VariadicFunc::DateBinTimestampTz
             | VariadicFunc::RangeCreate { .. }
-            | VariadicFunc::MakeMzAclItem => false,
+            | VariadicFunc::MakeMzAclItem
+            | VariadicFunc::RegexpReplace
+            | VariadicFunc::RegexpSplitToArray
+            | VariadicFunc::RegexpCount
+            | VariadicFunc::JsonbPathExists
+            | VariadicFunc::JsonbPathMatch
+            | VariadicFunc::JsonbPathQueryFirst
+            | VariadicFunc::JsonbSet
+            | VariadicFunc::JsonbSetLax
+            | VariadicFunc::JsonbInsert => false,
         }
     }
 
@@ -6816,6 +7472,14 @@ impl VariadicFunc {
             }
             .nullable(false),
             MakeMzAclItem => ScalarType::MzAclItem.nullable(true),
+            RegexpReplace => ScalarType::String.nullable(in_nullable),
+            RegexpSplitToArray => {
+                ScalarType::Array(Box::new(ScalarType::String)).nullable(in_nullable)
+            }
+            RegexpCount => ScalarType::Int32.nullable(in_nullable),
+            JsonbPathExists | JsonbPathMatch => ScalarType::Bool.nullable(true),
+            JsonbPathQueryFirst => ScalarType::Jsonb.nullable(true),
+            JsonbSet | JsonbSetLax | JsonbInsert => ScalarType::Jsonb.nullable(in_nullable),
         }
     }
 
@@ -6839,6 +7503,7 @@ impl VariadicFunc {
                 | VariadicFunc::ArrayToString { .. }
                 | VariadicFunc::ErrorIfNull
                 | VariadicFunc::RangeCreate { .. }
+                | VariadicFunc::JsonbSetLax
         )
     }
 
@@ -6871,14 +7536,23 @@ impl VariadicFunc {
             | RangeCreate { .. }
             | And
             | Or
-            | MakeMzAclItem => false,
+            | MakeMzAclItem
+            | RegexpReplace
+            | RegexpSplitToArray
+            | RegexpCount
+            | JsonbSet
+            | JsonbSetLax
+            | JsonbInsert => false,
             Coalesce
             | Greatest
             | Least
             | MakeTimestamp
             | ArrayIndex { .. }
             | ListIndex
-            | RegexpMatch => true,
+            | RegexpMatch
+            | JsonbPathExists
+            | JsonbPathMatch
+            | JsonbPathQueryFirst => true,
         }
     }
 
@@ -6965,6 +7639,15 @@ impl fmt::Display for VariadicFunc {
                 _ => unreachable!(),
             }),
             VariadicFunc::MakeMzAclItem => f.write_str("make_mz_aclitem"),
+            VariadicFunc::RegexpReplace => f.write_str("regexp_replace"),
+            VariadicFunc::RegexpSplitToArray => f.write_str("regexp_split_to_array"),
+            VariadicFunc::RegexpCount => f.write_str("regexp_count"),
+            VariadicFunc::JsonbPathExists => f.write_str("jsonb_path_exists"),
+            VariadicFunc::JsonbPathMatch => f.write_str("jsonb_path_match"),
+            VariadicFunc::JsonbPathQueryFirst => f.write_str("jsonb_path_query_first"),
+            VariadicFunc::JsonbSet => f.write_str("jsonb_set"),
+            VariadicFunc::JsonbSetLax => f.write_str("jsonb_set_lax"),
+            VariadicFunc::JsonbInsert => f.write_str("jsonb_insert"),
         }
     }
 }
@@ -6992,6 +7675,12 @@ impl Arbitrary for VariadicFunc {
             Just(VariadicFunc::Replace).boxed(),
             Just(VariadicFunc::JsonbBuildArray).boxed(),
             Just(VariadicFunc::JsonbBuildObject).boxed(),
+            Just(VariadicFunc::JsonbPathExists).boxed(),
+            Just(VariadicFunc::JsonbPathMatch).boxed(),
+            Just(VariadicFunc::JsonbPathQueryFirst).boxed(),
+            Just(VariadicFunc::JsonbSet).boxed(),
+            Just(VariadicFunc::JsonbSetLax).boxed(),
+            Just(VariadicFunc::JsonbInsert).boxed(),
             Just(VariadicFunc::MakeMzAclItem).boxed(),
             ScalarType::arbitrary()
                 .prop_map(|elem_type| VariadicFunc::ArrayCreate { elem_type })
@@ -7012,6 +7701,9 @@ impl Arbitrary for VariadicFunc {
             Just(VariadicFunc::ListSliceLinear).boxed(),
             Just(VariadicFunc::SplitPart).boxed(),
             Just(VariadicFunc::RegexpMatch).boxed(),
+            Just(VariadicFunc::RegexpReplace).boxed(),
+            Just(VariadicFunc::RegexpSplitToArray).boxed(),
+            Just(VariadicFunc::RegexpCount).boxed(),
             Just(VariadicFunc::HmacString).boxed(),
             Just(VariadicFunc::HmacBytes).boxed(),
             Just(VariadicFunc::ErrorIfNull).boxed(),
@@ -7062,6 +7754,15 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
             VariadicFunc::Or => Or(()),
             VariadicFunc::RangeCreate { elem_type } => RangeCreate(elem_type.into_proto()),
             VariadicFunc::MakeMzAclItem => MakeMzAclItem(()),
+            VariadicFunc::RegexpReplace => RegexpReplace(()),
+            VariadicFunc::RegexpSplitToArray => RegexpSplitToArray(()),
+            VariadicFunc::RegexpCount => RegexpCount(()),
+            VariadicFunc::JsonbPathExists => JsonbPathExists(()),
+            VariadicFunc::JsonbPathMatch => JsonbPathMatch(()),
+            VariadicFunc::JsonbPathQueryFirst => JsonbPathQueryFirst(()),
+            VariadicFunc::JsonbSet => JsonbSet(()),
+            VariadicFunc::JsonbSetLax => JsonbSetLax(()),
+            VariadicFunc::JsonbInsert => JsonbInsert(()),
         };
         ProtoVariadicFunc { kind: Some(kind) }
     }
@@ -7112,6 +7813,15 @@ impl RustType<ProtoVariadicFunc> for VariadicFunc {
                     elem_type: elem_type.into_rust()?,
                 }),
                 MakeMzAclItem(()) => Ok(VariadicFunc::MakeMzAclItem),
+                RegexpReplace(()) => Ok(VariadicFunc::RegexpReplace),
+                RegexpSplitToArray(()) => Ok(VariadicFunc::RegexpSplitToArray),
+                RegexpCount(()) => Ok(VariadicFunc::RegexpCount),
+                JsonbPathExists(()) => Ok(VariadicFunc::JsonbPathExists),
+                JsonbPathMatch(()) => Ok(VariadicFunc::JsonbPathMatch),
+                JsonbPathQueryFirst(()) => Ok(VariadicFunc::JsonbPathQueryFirst),
+                JsonbSet(()) => Ok(VariadicFunc::JsonbSet),
+                JsonbSetLax(()) => Ok(VariadicFunc::JsonbSetLax),
+                JsonbInsert(()) => Ok(VariadicFunc::JsonbInsert),
             }
         } else {
             Err(TryFromProtoError::missing_field(
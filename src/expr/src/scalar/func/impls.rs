@@ -13,8 +13,10 @@ mod byte;
 mod char;
 mod date;
 mod datum;
+mod enums;
 mod float32;
 mod float64;
+mod inet;
 mod int16;
 mod int2vector;
 mod int32;
@@ -47,8 +49,10 @@ pub use boolean::*;
 pub use byte::*;
 pub use date::*;
 pub use datum::*;
+pub use enums::*;
 pub use float32::*;
 pub use float64::*;
+pub use inet::*;
 pub use int16::*;
 pub use int2vector::*;
 pub use int32::*;
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use mz_repr::adt::hll::HyperLogLog;
 use mz_repr::strconv;
 
 use crate::EvalError;
@@ -35,3 +36,19 @@ sqlfunc!(
         i32::try_from(a.len()).or(Err(EvalError::Int32OutOfRange))
     }
 );
+
+fn decode_hll(a: &[u8]) -> Result<HyperLogLog, EvalError> {
+    HyperLogLog::decode(a).map_err(|e| EvalError::InvalidParameterValue(e.to_string()))
+}
+
+sqlfunc!(
+    fn hll_validate<'a>(a: &'a [u8]) -> Result<Vec<u8>, EvalError> {
+        Ok(decode_hll(a)?.encode())
+    }
+);
+
+sqlfunc!(
+    fn hll_estimate<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
+        i64::try_from(decode_hll(a)?.estimate()).or(Err(EvalError::Int64OutOfRange))
+    }
+);
@@ -7,6 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+use mz_repr::adt::hll::{hash_datum, HyperLogLog};
 use mz_repr::{Datum, DatumList};
 
 use crate::EvalError;
@@ -52,3 +53,14 @@ sqlfunc!(
         i32::try_from(sz).or(Err(EvalError::Int32OutOfRange))
     }
 );
+
+sqlfunc!(
+    fn hll_sketch_value<'a>(a: Datum<'a>) -> Option<Vec<u8>> {
+        if a.is_null() {
+            return None;
+        }
+        let mut hll = HyperLogLog::new();
+        hll.insert_hash(hash_datum(a));
+        Some(hll.encode())
+    }
+);
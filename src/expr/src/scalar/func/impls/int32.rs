@@ -211,3 +211,31 @@ sqlfunc!(
         }
     }
 );
+
+sqlfunc!(
+    #[sqlname = "ntile_argument"]
+    #[preserves_uniqueness = true]
+    fn check_ntile_argument(a: i32) -> Result<i32, EvalError> {
+        if a <= 0 {
+            Err(EvalError::InvalidParameterValue(
+                "argument of ntile must be greater than zero".into(),
+            ))
+        } else {
+            Ok(a)
+        }
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "nth_value_argument"]
+    #[preserves_uniqueness = true]
+    fn check_nth_value_argument(a: i32) -> Result<i32, EvalError> {
+        if a <= 0 {
+            Err(EvalError::InvalidParameterValue(
+                "argument of nth_value must be greater than zero".into(),
+            ))
+        } else {
+            Ok(a)
+        }
+    }
+);
@@ -13,13 +13,17 @@ use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 
 use mz_lowertest::MzReflect;
+use mz_ore::str::StrExt;
+use mz_proto::{RustType, TryFromProtoError};
 use mz_repr::adt::jsonb::{Jsonb, JsonbRef};
+use mz_repr::adt::jsonpath::{any_jsonpath, JsonPath, JsonPathError};
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
-use mz_repr::{strconv, ColumnType, Datum, Row, RowPacker, ScalarType};
+use mz_repr::{strconv, ColumnType, Datum, Row, RowArena, RowPacker, ScalarType};
 
 use crate::scalar::func::impls::numeric::*;
-use crate::scalar::func::EagerUnaryFunc;
-use crate::EvalError;
+use crate::scalar::func::{suppress_jsonb_path_error, EagerUnaryFunc, LazyUnaryFunc};
+use crate::scalar::proto_unary_func::ProtoJsonbPath;
+use crate::{EvalError, MirScalarExpr};
 
 sqlfunc!(
     #[sqlname = "jsonb_to_text"]
@@ -234,3 +238,214 @@ sqlfunc!(
         buf
     }
 );
+
+/// The constant arguments of a `jsonb_path_*` function that follow its target,
+/// with the path parsed ahead of time.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct JsonbPath {
+    #[proptest(strategy = "any_jsonpath()")]
+    #[mzreflect(ignore)]
+    pub path: JsonPath,
+    /// A row containing the jsonb object that provides the path's variables.
+    #[mzreflect(ignore)]
+    pub vars: Option<Row>,
+    /// Whether to return `NULL` in place of the suppressible errors.
+    pub silent: bool,
+}
+
+impl JsonbPath {
+    fn eval<'a, T>(
+        &'a self,
+        target: Datum<'a>,
+        f: impl FnOnce(&'a JsonPath, Datum<'a>, Option<Datum<'a>>) -> Result<T, JsonPathError>,
+    ) -> Result<Option<T>, EvalError> {
+        let vars = self.vars.as_ref().map(|vars| vars.unpack_first());
+        suppress_jsonb_path_error(f(&self.path, target, vars), self.silent)
+    }
+}
+
+impl fmt::Display for JsonbPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path.as_str().quoted())?;
+        if let Some(vars) = &self.vars {
+            write!(f, ", {}", JsonbRef::from_datum(vars.unpack_first()))?;
+        }
+        if self.silent {
+            f.write_str(", silent")?;
+        }
+        Ok(())
+    }
+}
+
+impl RustType<ProtoJsonbPath> for JsonbPath {
+    fn into_proto(&self) -> ProtoJsonbPath {
+        ProtoJsonbPath {
+            path: self.path.into_proto(),
+            vars: self.vars.into_proto(),
+            silent: self.silent,
+        }
+    }
+
+    fn from_proto(proto: ProtoJsonbPath) -> Result<Self, TryFromProtoError> {
+        Ok(JsonbPath {
+            path: proto.path.into_rust()?,
+            vars: proto.vars.into_rust()?,
+            silent: proto.silent,
+        })
+    }
+}
+
+/// Like [`VariadicFunc::JsonbPathExists`](crate::VariadicFunc::JsonbPathExists),
+/// but with a constant path and variables.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct JsonbPathExists(pub JsonbPath);
+
+impl LazyUnaryFunc for JsonbPathExists {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let target = a.eval(datums, temp_storage)?;
+        if target.is_null() {
+            return Ok(Datum::Null);
+        }
+        let exists = self
+            .0
+            .eval(target, |path, target, vars| path.exists(target, vars))?;
+        Ok(exists.map_or(Datum::Null, Datum::from))
+    }
+
+    fn output_type(&self, _input_type: ColumnType) -> ColumnType {
+        ScalarType::Bool.nullable(true)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        true
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+}
+
+impl fmt::Display for JsonbPathExists {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "jsonb_path_exists[{}]", self.0)
+    }
+}
+
+/// Like [`VariadicFunc::JsonbPathMatch`](crate::VariadicFunc::JsonbPathMatch),
+/// but with a constant path and variables.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct JsonbPathMatch(pub JsonbPath);
+
+impl LazyUnaryFunc for JsonbPathMatch {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let target = a.eval(datums, temp_storage)?;
+        if target.is_null() {
+            return Ok(Datum::Null);
+        }
+        let matches = self
+            .0
+            .eval(target, |path, target, vars| path.matches(target, vars))?;
+        Ok(matches.flatten().map_or(Datum::Null, Datum::from))
+    }
+
+    fn output_type(&self, _input_type: ColumnType) -> ColumnType {
+        ScalarType::Bool.nullable(true)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        true
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+}
+
+impl fmt::Display for JsonbPathMatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "jsonb_path_match[{}]", self.0)
+    }
+}
+
+/// Like [`VariadicFunc::JsonbPathQueryFirst`](crate::VariadicFunc::JsonbPathQueryFirst),
+/// but with a constant path and variables.
+#[derive(
+    Arbitrary, Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect,
+)]
+pub struct JsonbPathQueryFirst(pub JsonbPath);
+
+impl LazyUnaryFunc for JsonbPathQueryFirst {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let target = a.eval(datums, temp_storage)?;
+        if target.is_null() {
+            return Ok(Datum::Null);
+        }
+        let first = self.0.eval(target, |path, target, vars| {
+            Ok(path.query(target, vars)?.first().copied())
+        })?;
+        Ok(first.flatten().unwrap_or(Datum::Null))
+    }
+
+    fn output_type(&self, _input_type: ColumnType) -> ColumnType {
+        ScalarType::Jsonb.nullable(true)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        true
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+}
+
+impl fmt::Display for JsonbPathQueryFirst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "jsonb_path_query_first[{}]", self.0)
+    }
+}
@@ -23,6 +23,7 @@ use mz_ore::str::StrExt;
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char::{format_str_trim, Char};
 use mz_repr::adt::date::Date;
+use mz_repr::adt::inet::{Cidr, Inet};
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::jsonb::Jsonb;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
@@ -32,7 +33,10 @@ use mz_repr::adt::timestamp::CheckedTimestamp;
 use mz_repr::adt::varchar::{VarChar, VarCharMaxLength};
 use mz_repr::{strconv, ColumnType, Datum, Row, RowArena, ScalarType};
 
-use crate::scalar::func::{array_create_scalar, EagerUnaryFunc, LazyUnaryFunc};
+use crate::scalar::func::{
+    array_create_scalar, regexp_count_static, regexp_replace_static, regexp_split_to_array_static,
+    EagerUnaryFunc, LazyUnaryFunc,
+};
 use crate::{like_pattern, EvalError, MirScalarExpr, UnaryFunc};
 
 sqlfunc!(
@@ -235,6 +239,23 @@ sqlfunc!(
     }
 );
 
+sqlfunc!(
+    #[sqlname = "text_to_inet"]
+    #[preserves_uniqueness = false]
+    fn cast_string_to_inet<'a>(a: &'a str) -> Result<Inet, EvalError> {
+        strconv::parse_inet(a).err_into()
+    }
+);
+
+sqlfunc!(
+    #[sqlname = "text_to_cidr"]
+    #[preserves_uniqueness = false]
+    #[inverse = to_unary!(super::CastCidrToString)]
+    fn cast_string_to_cidr<'a>(a: &'a str) -> Result<Cidr, EvalError> {
+        strconv::parse_cidr(a).map(Cidr).err_into()
+    }
+);
+
 #[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
 pub struct CastStringToArray {
     // Target array's type.
@@ -888,6 +909,115 @@ impl fmt::Display for RegexpMatch {
     }
 }
 
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct RegexpReplace {
+    pub regex: Regex,
+    pub replacement: String,
+    /// The maximum number of matches to replace, or `0` to replace all of
+    /// them.
+    pub limit: usize,
+}
+
+impl<'a> EagerUnaryFunc<'a> for RegexpReplace {
+    type Input = &'a str;
+    type Output = String;
+
+    fn call(&self, source: &'a str) -> String {
+        regexp_replace_static(source, &self.regex, &self.replacement, self.limit)
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::String.nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for RegexpReplace {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "regexp_replace[{}, {}, limit={}]",
+            self.regex.as_str(),
+            self.replacement.quoted(),
+            self.limit
+        )
+    }
+}
+
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct RegexpSplitToArray(pub Regex);
+
+impl LazyUnaryFunc for RegexpSplitToArray {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        a: &'a MirScalarExpr,
+    ) -> Result<Datum<'a>, EvalError> {
+        let haystack = a.eval(datums, temp_storage)?;
+        if haystack.is_null() {
+            return Ok(Datum::Null);
+        }
+        regexp_split_to_array_static(haystack.unwrap_str(), &self.0, temp_storage)
+    }
+
+    fn output_type(&self, input_type: ColumnType) -> ColumnType {
+        ScalarType::Array(Box::new(ScalarType::String)).nullable(input_type.nullable)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        true
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn preserves_uniqueness(&self) -> bool {
+        false
+    }
+
+    fn inverse(&self) -> Option<crate::UnaryFunc> {
+        None
+    }
+}
+
+impl fmt::Display for RegexpSplitToArray {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "regexp_split_to_array[{}]", self.0.as_str())
+    }
+}
+
+#[derive(Ord, PartialOrd, Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Hash, MzReflect)]
+pub struct RegexpCount {
+    pub regex: Regex,
+    /// The 1-based character position at which to begin searching.
+    pub start: i32,
+}
+
+impl<'a> EagerUnaryFunc<'a> for RegexpCount {
+    type Input = &'a str;
+    type Output = Result<i32, EvalError>;
+
+    fn call(&self, haystack: &'a str) -> Result<i32, EvalError> {
+        regexp_count_static(haystack, &self.regex, self.start)
+    }
+
+    fn output_type(&self, input: ColumnType) -> ColumnType {
+        ScalarType::Int32.nullable(input.nullable)
+    }
+}
+
+impl fmt::Display for RegexpCount {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "regexp_count[{}, start={}]",
+            self.regex.as_str(),
+            self.start
+        )
+    }
+}
+
 sqlfunc!(
     #[sqlname = "mz_panic"]
     fn panic<'a>(a: &'a str) -> String {
@@ -29,6 +29,8 @@ use mz_pgrepr::TypeFromOidError;
 use mz_proto::{ProtoType, RustType, TryFromProtoError};
 use mz_repr::adt::array::InvalidArrayError;
 use mz_repr::adt::datetime::DateTimeUnits;
+use mz_repr::adt::jsonb::JsonbEditError;
+use mz_repr::adt::jsonpath::{JsonPath, JsonPathError};
 use mz_repr::adt::range::InvalidRangeError;
 use mz_repr::adt::regex::Regex;
 use mz_repr::arb_datum;
@@ -1147,6 +1149,106 @@ impl MirScalarExpr {
                                     e.typ(column_types).scalar_type,
                                 ),
                             };
+                        } else if *func == VariadicFunc::RegexpReplace
+                            && exprs[1..].iter().all(|e| e.is_literal())
+                        {
+                            let needle = exprs[1].as_literal_str().unwrap();
+                            let replacement = exprs[2].as_literal_str().unwrap().to_owned();
+                            let flags = match exprs.len() {
+                                4 => exprs[3].as_literal_str().unwrap(),
+                                _ => "",
+                            };
+                            *e = match func::build_regex_with_limit(needle, flags) {
+                                Ok((regex, limit)) => mem::take(exprs).into_first().call_unary(
+                                    UnaryFunc::RegexpReplace(func::RegexpReplace {
+                                        regex: Regex(regex),
+                                        replacement,
+                                        limit,
+                                    }),
+                                ),
+                                Err(err) => MirScalarExpr::literal(
+                                    Err(err),
+                                    e.typ(column_types).scalar_type,
+                                ),
+                            };
+                        } else if *func == VariadicFunc::RegexpSplitToArray
+                            && exprs[1..].iter().all(|e| e.is_literal())
+                        {
+                            let needle = exprs[1].as_literal_str().unwrap();
+                            let flags = match exprs.len() {
+                                3 => exprs[2].as_literal_str().unwrap(),
+                                _ => "",
+                            };
+                            *e = match func::build_regex(needle, flags) {
+                                Ok(regex) => mem::take(exprs).into_first().call_unary(
+                                    UnaryFunc::RegexpSplitToArray(func::RegexpSplitToArray(Regex(
+                                        regex,
+                                    ))),
+                                ),
+                                Err(err) => MirScalarExpr::literal(
+                                    Err(err),
+                                    e.typ(column_types).scalar_type,
+                                ),
+                            };
+                        } else if *func == VariadicFunc::RegexpCount
+                            && exprs[1..].iter().all(|e| e.is_literal())
+                        {
+                            let needle = exprs[1].as_literal_str().unwrap();
+                            let start = match exprs.get(2).and_then(|e| e.as_literal()) {
+                                Some(Ok(start)) => start.unwrap_int32(),
+                                _ => 1,
+                            };
+                            let flags = match exprs.len() {
+                                4 => exprs[3].as_literal_str().unwrap(),
+                                _ => "",
+                            };
+                            *e = match func::build_regex(needle, flags) {
+                                Ok(regex) => mem::take(exprs).into_first().call_unary(
+                                    UnaryFunc::RegexpCount(func::RegexpCount {
+                                        regex: Regex(regex),
+                                        start,
+                                    }),
+                                ),
+                                Err(err) => MirScalarExpr::literal(
+                                    Err(err),
+                                    e.typ(column_types).scalar_type,
+                                ),
+                            };
+                        } else if matches!(
+                            func,
+                            VariadicFunc::JsonbPathExists
+                                | VariadicFunc::JsonbPathMatch
+                                | VariadicFunc::JsonbPathQueryFirst
+                        ) && exprs[1..].iter().all(|e| e.is_literal())
+                        {
+                            let path = exprs[1].as_literal_str().unwrap();
+                            let vars = exprs.get(2).map(|vars| {
+                                Row::pack_slice(&[vars.as_literal().unwrap().unwrap()])
+                            });
+                            let silent = exprs.get(3).map_or(false, |silent| {
+                                silent.as_literal().unwrap().unwrap().unwrap_bool()
+                            });
+                            *e = match path.parse::<JsonPath>() {
+                                Ok(path) => {
+                                    let path = func::JsonbPath { path, vars, silent };
+                                    let func = match func {
+                                        VariadicFunc::JsonbPathExists => {
+                                            UnaryFunc::JsonbPathExists(func::JsonbPathExists(path))
+                                        }
+                                        VariadicFunc::JsonbPathMatch => {
+                                            UnaryFunc::JsonbPathMatch(func::JsonbPathMatch(path))
+                                        }
+                                        _ => UnaryFunc::JsonbPathQueryFirst(
+                                            func::JsonbPathQueryFirst(path),
+                                        ),
+                                    };
+                                    mem::take(exprs).into_first().call_unary(func)
+                                }
+                                Err(err) => MirScalarExpr::literal(
+                                    Err(err.into()),
+                                    e.typ(column_types).scalar_type,
+                                ),
+                            };
                         } else if *func == VariadicFunc::ListIndex && is_list_create_call(&exprs[0])
                         {
                             // We are looking for ListIndex(ListCreate, literal), and eliminate
@@ -2225,6 +2327,11 @@ pub enum EvalError {
     InvalidRange(InvalidRangeError),
     InvalidRoleId(String),
     InvalidPrivileges(String),
+    JsonPath(String),
+    JsonbEdit(String),
+    JsonbNullValue,
+    InvalidEnumLabel(String),
+    InvalidMaskLength(i32),
 }
 
 impl fmt::Display for EvalError {
@@ -2378,6 +2485,13 @@ impl fmt::Display for EvalError {
             EvalError::InvalidRange(e) => e.fmt(f),
             EvalError::InvalidRoleId(msg) => write!(f, "{msg}"),
             EvalError::InvalidPrivileges(msg) => write!(f, "{msg}"),
+            EvalError::JsonPath(msg) => write!(f, "{msg}"),
+            EvalError::JsonbEdit(msg) => write!(f, "{msg}"),
+            EvalError::JsonbNullValue => write!(f, "JSON value must not be null"),
+            EvalError::InvalidEnumLabel(label) => {
+                write!(f, "invalid input value for enum: \"{}\"", label)
+            }
+            EvalError::InvalidMaskLength(len) => write!(f, "invalid mask length: {}", len),
         }
     }
 }
@@ -2395,6 +2509,10 @@ impl EvalError {
                 "Arrays of {} and {} dimensions are not compatible for concatenation.",
                 a_dims, b_dims
             )),
+            EvalError::JsonbNullValue => Some(
+                "Exception was raised because null_value_treatment is \"raise_exception\"."
+                    .to_string(),
+            ),
             _ => None,
         }
     }
@@ -2413,6 +2531,11 @@ impl EvalError {
                 converted to mz_timestamp."
                     .into(),
             ),
+            EvalError::JsonbNullValue => Some(
+                "To avoid, either change the null_value_treatment argument or ensure that an \
+                SQL NULL is not passed."
+                    .into(),
+            ),
             _ => None,
         }
     }
@@ -2444,6 +2567,18 @@ impl From<regex::Error> for EvalError {
     }
 }
 
+impl From<JsonPathError> for EvalError {
+    fn from(e: JsonPathError) -> EvalError {
+        EvalError::JsonPath(e.to_string())
+    }
+}
+
+impl From<JsonbEditError> for EvalError {
+    fn from(e: JsonbEditError) -> EvalError {
+        EvalError::JsonbEdit(e.to_string())
+    }
+}
+
 impl From<TypeFromOidError> for EvalError {
     fn from(e: TypeFromOidError) -> EvalError {
         EvalError::TypeFromOid(e.to_string())
@@ -2581,6 +2716,11 @@ impl RustType<ProtoEvalError> for EvalError {
             EvalError::InvalidRange(error) => InvalidRange(error.into_proto()),
             EvalError::InvalidRoleId(v) => InvalidRoleId(v.clone()),
             EvalError::InvalidPrivileges(v) => InvalidPrivileges(v.clone()),
+            EvalError::JsonPath(v) => JsonPath(v.clone()),
+            EvalError::JsonbEdit(v) => JsonbEdit(v.clone()),
+            EvalError::JsonbNullValue => JsonbNullValue(()),
+            EvalError::InvalidEnumLabel(v) => InvalidEnumLabel(v.clone()),
+            EvalError::InvalidMaskLength(v) => InvalidMaskLength(*v),
         };
         ProtoEvalError { kind: Some(kind) }
     }
@@ -2679,6 +2819,11 @@ impl RustType<ProtoEvalError> for EvalError {
                 InvalidRange(e) => Ok(EvalError::InvalidRange(e.into_rust()?)),
                 InvalidRoleId(v) => Ok(EvalError::InvalidRoleId(v)),
                 InvalidPrivileges(v) => Ok(EvalError::InvalidPrivileges(v)),
+                JsonPath(v) => Ok(EvalError::JsonPath(v)),
+                JsonbEdit(v) => Ok(EvalError::JsonbEdit(v)),
+                JsonbNullValue(()) => Ok(EvalError::JsonbNullValue),
+                InvalidEnumLabel(v) => Ok(EvalError::InvalidEnumLabel(v)),
+                InvalidMaskLength(v) => Ok(EvalError::InvalidMaskLength(v)),
             },
             None => Err(TryFromProtoError::missing_field("ProtoEvalError::kind")),
         }
@@ -0,0 +1,371 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Encoding of updates as Apache Arrow columns.
+//!
+//! Each column of the relation becomes a top-level arrow column, followed by
+//! an `mz_timestamp` and an `mz_diff` column that describe the update. Types
+//! with a natural arrow counterpart (integers, floats, dates, times,
+//! timestamps, byte strings and text) are encoded as that counterpart.
+//! Numerics, intervals, UUIDs, network addresses, ranges and enums are
+//! encoded as their text representation, and JSON, lists, arrays, maps and
+//! records as JSON text.
+
+use arrow2::array::{
+    Array, MutableArray, MutableBinaryArray, MutableBooleanArray, MutablePrimitiveArray,
+    MutableUtf8Array,
+};
+use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema, TimeUnit};
+use chrono::{NaiveDateTime, Timelike};
+
+use mz_ore::cast::CastFrom;
+use mz_repr::adt::inet::Cidr;
+use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::{ColumnType, Datum, Diff, RelationDesc, Row, ScalarType, Timestamp};
+
+use crate::encode::{column_names_and_types, TypedDatum};
+use crate::json::ToJson;
+
+/// The name of the column that holds the timestamp of each update.
+pub const TIMESTAMP_COLUMN: &str = "mz_timestamp";
+/// The name of the column that holds the diff of each update.
+pub const DIFF_COLUMN: &str = "mz_diff";
+
+/// Encodes batches of updates to a relation as arrow chunks.
+#[derive(Debug)]
+pub struct ArrowEncoder {
+    columns: Vec<ColumnType>,
+    schema: Schema,
+}
+
+impl ArrowEncoder {
+    /// Creates an encoder for updates to relations described by `desc`.
+    pub fn new(desc: RelationDesc) -> Self {
+        let columns = column_names_and_types(desc);
+        let mut fields: Vec<_> = columns
+            .iter()
+            .map(|(name, typ)| {
+                Field::new(
+                    name.as_str(),
+                    arrow_data_type(&typ.scalar_type),
+                    typ.nullable,
+                )
+            })
+            .collect();
+        fields.push(Field::new(TIMESTAMP_COLUMN, DataType::UInt64, false));
+        fields.push(Field::new(DIFF_COLUMN, DataType::Int64, false));
+        ArrowEncoder {
+            columns: columns.into_iter().map(|(_name, typ)| typ).collect(),
+            schema: Schema::from(fields),
+        }
+    }
+
+    /// Returns the arrow schema of the chunks produced by this encoder.
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    /// Encodes the given updates as a single chunk.
+    pub fn encode<'a, I>(&self, updates: I) -> Chunk<Box<dyn Array>>
+    where
+        I: IntoIterator<Item = (&'a Row, Timestamp, Diff)>,
+    {
+        let mut builders: Vec<_> = self
+            .columns
+            .iter()
+            .map(|typ| ColumnBuilder::new(&typ.scalar_type))
+            .collect();
+        let mut timestamps = MutablePrimitiveArray::<u64>::new();
+        let mut diffs = MutablePrimitiveArray::<i64>::new();
+        for (row, time, diff) in updates {
+            for ((datum, typ), builder) in row.iter().zip(&self.columns).zip(&mut builders) {
+                builder.push(datum, typ);
+            }
+            timestamps.push(Some(u64::from(time)));
+            diffs.push(Some(diff));
+        }
+        let mut arrays: Vec<_> = builders.into_iter().map(ColumnBuilder::finish).collect();
+        arrays.push(timestamps.as_box());
+        arrays.push(diffs.as_box());
+        Chunk::new(arrays)
+    }
+}
+
+/// Returns the arrow type used to represent values of `typ`.
+fn arrow_data_type(typ: &ScalarType) -> DataType {
+    match typ {
+        ScalarType::Bool => DataType::Boolean,
+        ScalarType::PgLegacyChar => DataType::UInt8,
+        ScalarType::Int16 => DataType::Int16,
+        ScalarType::Int32 => DataType::Int32,
+        ScalarType::Int64 => DataType::Int64,
+        ScalarType::UInt16 => DataType::UInt16,
+        ScalarType::UInt32
+        | ScalarType::Oid
+        | ScalarType::RegClass
+        | ScalarType::RegProc
+        | ScalarType::RegType => DataType::UInt32,
+        ScalarType::UInt64 | ScalarType::MzTimestamp => DataType::UInt64,
+        ScalarType::Float32 => DataType::Float32,
+        ScalarType::Float64 => DataType::Float64,
+        ScalarType::Date => DataType::Date32,
+        ScalarType::Time => DataType::Time64(TimeUnit::Microsecond),
+        ScalarType::Timestamp => DataType::Timestamp(TimeUnit::Microsecond, None),
+        ScalarType::TimestampTz => {
+            DataType::Timestamp(TimeUnit::Microsecond, Some("+00:00".into()))
+        }
+        ScalarType::Bytes => DataType::Binary,
+        ScalarType::Numeric { .. }
+        | ScalarType::Interval
+        | ScalarType::String
+        | ScalarType::Char { .. }
+        | ScalarType::VarChar { .. }
+        | ScalarType::Jsonb
+        | ScalarType::Uuid
+        | ScalarType::Array(_)
+        | ScalarType::Int2Vector
+        | ScalarType::List { .. }
+        | ScalarType::Record { .. }
+        | ScalarType::Map { .. }
+        | ScalarType::Range { .. }
+        | ScalarType::MzAclItem
+        | ScalarType::Inet
+        | ScalarType::Cidr
+        | ScalarType::Enum { .. } => DataType::Utf8,
+    }
+}
+
+/// An in-progress arrow column.
+enum ColumnBuilder {
+    Boolean(MutableBooleanArray),
+    UInt8(MutablePrimitiveArray<u8>),
+    Int16(MutablePrimitiveArray<i16>),
+    Int32(MutablePrimitiveArray<i32>),
+    Int64(MutablePrimitiveArray<i64>),
+    UInt16(MutablePrimitiveArray<u16>),
+    UInt32(MutablePrimitiveArray<u32>),
+    UInt64(MutablePrimitiveArray<u64>),
+    Float32(MutablePrimitiveArray<f32>),
+    Float64(MutablePrimitiveArray<f64>),
+    Binary(MutableBinaryArray<i32>),
+    Utf8(MutableUtf8Array<i32>),
+}
+
+impl ColumnBuilder {
+    fn new(typ: &ScalarType) -> Self {
+        let data_type = arrow_data_type(typ);
+        match data_type {
+            DataType::Boolean => ColumnBuilder::Boolean(MutableBooleanArray::new()),
+            DataType::UInt8 => ColumnBuilder::UInt8(MutablePrimitiveArray::from(data_type)),
+            DataType::Int16 => ColumnBuilder::Int16(MutablePrimitiveArray::from(data_type)),
+            DataType::Int32 | DataType::Date32 => {
+                ColumnBuilder::Int32(MutablePrimitiveArray::from(data_type))
+            }
+            DataType::Int64 | DataType::Time64(_) | DataType::Timestamp(..) => {
+                ColumnBuilder::Int64(MutablePrimitiveArray::from(data_type))
+            }
+            DataType::UInt16 => ColumnBuilder::UInt16(MutablePrimitiveArray::from(data_type)),
+            DataType::UInt32 => ColumnBuilder::UInt32(MutablePrimitiveArray::from(data_type)),
+            DataType::UInt64 => ColumnBuilder::UInt64(MutablePrimitiveArray::from(data_type)),
+            DataType::Float32 => ColumnBuilder::Float32(MutablePrimitiveArray::from(data_type)),
+            DataType::Float64 => ColumnBuilder::Float64(MutablePrimitiveArray::from(data_type)),
+            DataType::Binary => ColumnBuilder::Binary(MutableBinaryArray::new()),
+            DataType::Utf8 => ColumnBuilder::Utf8(MutableUtf8Array::new()),
+            data_type => unreachable!("unexpected arrow type {:?}", data_type),
+        }
+    }
+
+    fn push(&mut self, datum: Datum, typ: &ColumnType) {
+        if datum.is_null() {
+            match self {
+                ColumnBuilder::Boolean(array) => array.push_null(),
+                ColumnBuilder::UInt8(array) => array.push_null(),
+                ColumnBuilder::Int16(array) => array.push_null(),
+                ColumnBuilder::Int32(array) => array.push_null(),
+                ColumnBuilder::Int64(array) => array.push_null(),
+                ColumnBuilder::UInt16(array) => array.push_null(),
+                ColumnBuilder::UInt32(array) => array.push_null(),
+                ColumnBuilder::UInt64(array) => array.push_null(),
+                ColumnBuilder::Float32(array) => array.push_null(),
+                ColumnBuilder::Float64(array) => array.push_null(),
+                ColumnBuilder::Binary(array) => array.push_null(),
+                ColumnBuilder::Utf8(array) => array.push_null(),
+            }
+            return;
+        }
+        match self {
+            ColumnBuilder::Boolean(array) => array.push(Some(datum.unwrap_bool())),
+            ColumnBuilder::UInt8(array) => array.push(Some(datum.unwrap_uint8())),
+            ColumnBuilder::Int16(array) => array.push(Some(datum.unwrap_int16())),
+            ColumnBuilder::Int32(array) => {
+                let value = match typ.scalar_type {
+                    ScalarType::Date => datum.unwrap_date().unix_epoch_days(),
+                    _ => datum.unwrap_int32(),
+                };
+                array.push(Some(value))
+            }
+            ColumnBuilder::Int64(array) => {
+                let value = match typ.scalar_type {
+                    ScalarType::Time => {
+                        let time = datum.unwrap_time();
+                        i64::from(time.num_seconds_from_midnight()) * 1_000_000
+                            + i64::from(time.nanosecond()) / 1_000
+                    }
+                    ScalarType::Timestamp => timestamp_micros(datum.unwrap_timestamp().to_naive()),
+                    ScalarType::TimestampTz => {
+                        timestamp_micros(datum.unwrap_timestamptz().to_naive())
+                    }
+                    _ => datum.unwrap_int64(),
+                };
+                array.push(Some(value))
+            }
+            ColumnBuilder::UInt16(array) => array.push(Some(datum.unwrap_uint16())),
+            ColumnBuilder::UInt32(array) => array.push(Some(datum.unwrap_uint32())),
+            ColumnBuilder::UInt64(array) => {
+                let value = match typ.scalar_type {
+                    ScalarType::MzTimestamp => u64::from(datum.unwrap_mz_timestamp()),
+                    _ => datum.unwrap_uint64(),
+                };
+                array.push(Some(value))
+            }
+            ColumnBuilder::Float32(array) => array.push(Some(datum.unwrap_float32())),
+            ColumnBuilder::Float64(array) => array.push(Some(datum.unwrap_float64())),
+            ColumnBuilder::Binary(array) => array.push(Some(datum.unwrap_bytes())),
+            ColumnBuilder::Utf8(array) => array.push(Some(text(datum, typ))),
+        }
+    }
+
+    fn finish(self) -> Box<dyn Array> {
+        match self {
+            ColumnBuilder::Boolean(mut array) => array.as_box(),
+            ColumnBuilder::UInt8(mut array) => array.as_box(),
+            ColumnBuilder::Int16(mut array) => array.as_box(),
+            ColumnBuilder::Int32(mut array) => array.as_box(),
+            ColumnBuilder::Int64(mut array) => array.as_box(),
+            ColumnBuilder::UInt16(mut array) => array.as_box(),
+            ColumnBuilder::UInt32(mut array) => array.as_box(),
+            ColumnBuilder::UInt64(mut array) => array.as_box(),
+            ColumnBuilder::Float32(mut array) => array.as_box(),
+            ColumnBuilder::Float64(mut array) => array.as_box(),
+            ColumnBuilder::Binary(mut array) => array.as_box(),
+            ColumnBuilder::Utf8(mut array) => array.as_box(),
+        }
+    }
+}
+
+/// Returns the text representation of a datum stored in a `Utf8` column.
+fn text(datum: Datum, typ: &ColumnType) -> String {
+    match &typ.scalar_type {
+        ScalarType::String | ScalarType::VarChar { .. } => datum.unwrap_str().to_owned(),
+        ScalarType::Char { length } => {
+            mz_repr::adt::char::format_str_pad(datum.unwrap_str(), *length)
+        }
+        ScalarType::Numeric { .. } => datum.unwrap_numeric().0.to_standard_notation_string(),
+        ScalarType::Interval => datum.unwrap_interval().to_string(),
+        ScalarType::Uuid => datum.unwrap_uuid().to_string(),
+        ScalarType::Range { .. } => datum.unwrap_range().to_string(),
+        ScalarType::MzAclItem => datum.unwrap_mz_acl_item().to_string(),
+        ScalarType::Inet => datum.unwrap_inet().to_string(),
+        ScalarType::Cidr => Cidr(datum.unwrap_inet()).to_string(),
+        ScalarType::Enum { labels, .. } => labels[usize::cast_from(datum.unwrap_uint32())].clone(),
+        ScalarType::Jsonb => JsonbRef::from_datum(datum).to_string(),
+        _ => TypedDatum::new(datum, typ).json().to_string(),
+    }
+}
+
+fn timestamp_micros(ts: NaiveDateTime) -> i64 {
+    ts.timestamp() * 1_000_000 + i64::from(ts.timestamp_subsec_micros())
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::array::{PrimitiveArray, Utf8Array};
+    use mz_repr::adt::date::Date;
+
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_encode() {
+        let desc = RelationDesc::empty()
+            .with_column("a", ScalarType::Int32.nullable(false))
+            .with_column("b", ScalarType::String.nullable(true))
+            .with_column("c", ScalarType::Date.nullable(false))
+            .with_column(
+                "d",
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Int64),
+                    custom_id: None,
+                }
+                .nullable(false),
+            );
+        let encoder = ArrowEncoder::new(desc);
+
+        let names: Vec<_> = encoder
+            .schema()
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c", "d", "mz_timestamp", "mz_diff"]);
+
+        let mut row1 = Row::default();
+        row1.packer().extend([
+            Datum::Int32(1),
+            Datum::String("one"),
+            Datum::Date(Date::from_unix_epoch(1).unwrap()),
+        ]);
+        row1.packer()
+            .push_list([Datum::Int64(1), Datum::Null, Datum::Int64(3)]);
+        let mut row2 = Row::default();
+        row2.packer().extend([
+            Datum::Int32(2),
+            Datum::Null,
+            Datum::Date(Date::from_unix_epoch(-1).unwrap()),
+        ]);
+        row2.packer().push_list(Vec::<Datum>::new());
+
+        let chunk = encoder.encode([
+            (&row1, Timestamp::from(5), 1),
+            (&row2, Timestamp::from(7), -1),
+        ]);
+        assert_eq!(chunk.len(), 2);
+        let arrays = chunk.arrays();
+
+        let a = arrays[0]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        assert_eq!(a.values().as_slice(), &[1, 2]);
+        let b = arrays[1].as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![Some("one"), None]);
+        let c = arrays[2]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()
+            .unwrap();
+        assert_eq!(c.data_type(), &DataType::Date32);
+        assert_eq!(c.values().as_slice(), &[1, -1]);
+        let d = arrays[3].as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+        assert_eq!(
+            d.iter().collect::<Vec<_>>(),
+            vec![Some("[1,null,3]"), Some("[]")]
+        );
+        let t = arrays[4]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<u64>>()
+            .unwrap();
+        assert_eq!(t.values().as_slice(), &[5, 7]);
+        let diff = arrays[5]
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        assert_eq!(diff.values().as_slice(), &[1, -1]);
+    }
+}
@@ -19,6 +19,7 @@ use serde_json::json;
 use mz_avro::types::{DecimalValue, ToAvro, Value};
 use mz_avro::Schema;
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::inet::Cidr;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{self, NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::{ColumnName, ColumnType, Datum, RelationDesc, Row, ScalarType};
@@ -404,6 +405,11 @@ impl<'a> mz_avro::types::ToAvro for TypedDatum<'a> {
                 ScalarType::MzTimestamp => Value::String(datum.unwrap_mz_timestamp().to_string()),
                 ScalarType::Range { .. } => Value::String(datum.unwrap_range().to_string()),
                 ScalarType::MzAclItem => Value::String(datum.unwrap_mz_acl_item().to_string()),
+                ScalarType::Inet => Value::String(datum.unwrap_inet().to_string()),
+                ScalarType::Cidr => Value::String(Cidr(datum.unwrap_inet()).to_string()),
+                ScalarType::Enum { labels, .. } => {
+                    Value::String(labels[usize::cast_from(datum.unwrap_uint32())].clone())
+                }
             };
             if typ.nullable {
                 val = Value::Union {
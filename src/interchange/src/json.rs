@@ -10,18 +10,38 @@
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
+use anyhow::anyhow;
 use serde_json::{json, Map};
 
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
 use mz_repr::adt::char;
-use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::adt::inet::Cidr;
+use mz_repr::adt::jsonb::{Jsonb, JsonbRef};
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
-use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, ScalarType};
+use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, Row, ScalarType};
 
 use crate::encode::{column_names_and_types, Encode, TypedDatum};
 use crate::envelopes;
 
 const AVRO_NAMESPACE: &str = "com.materialize.sink";
 
+/// Decodes a JSON document into a row containing a single `jsonb` datum.
+pub fn decode_json(bytes: &[u8]) -> Result<Row, anyhow::Error> {
+    let jsonb = Jsonb::from_slice(bytes).map_err(|e| {
+        anyhow!(
+            "Failed to decode JSON: {}",
+            // See if we can output the string that failed to be converted to JSON.
+            match std::str::from_utf8(bytes) {
+                Ok(str) => str.to_string(),
+                // Otherwise produce the nominally helpful error.
+                Err(_) => e.display_with_causes().to_string(),
+            }
+        )
+    })?;
+    Ok(jsonb.into_row())
+}
+
 // Manages encoding of JSON-encoded bytes
 pub struct JsonEncoder {
     key_columns: Option<Vec<(ColumnName, ColumnType)>>,
@@ -217,6 +237,11 @@ impl ToJson for TypedDatum<'_> {
                     json!(datum.unwrap_range().to_string())
                 }
                 ScalarType::MzAclItem => json!(datum.unwrap_mz_acl_item().to_string()),
+                ScalarType::Inet => json!(datum.unwrap_inet().to_string()),
+                ScalarType::Cidr => json!(Cidr(datum.unwrap_inet()).to_string()),
+                ScalarType::Enum { labels, .. } => {
+                    json!(labels[usize::cast_from(datum.unwrap_uint32())])
+                }
             }
         }
     }
@@ -334,6 +359,9 @@ fn build_row_schema_field(
         // https://debezium.io/documentation/reference/stable/connectors/postgresql.html
         ScalarType::Range { .. } => json!("string"),
         ScalarType::MzAclItem => json!("string"),
+        ScalarType::Inet | ScalarType::Cidr => json!("string"),
+        // Like Debezium, represent enum values by their labels.
+        ScalarType::Enum { .. } => json!("string"),
     };
     if typ.nullable {
         field_type = json!(["null", field_type]);
@@ -77,9 +77,11 @@
 
 #![warn(missing_debug_implementations)]
 
+pub mod arrow;
 pub mod avro;
 pub mod confluent;
 pub mod encode;
 pub mod envelopes;
 pub mod json;
 pub mod protobuf;
+pub mod text;
@@ -0,0 +1,763 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Encoding of rows as Protobuf messages.
+//!
+//! Each row is encoded as a message with one field per column, numbered in
+//! column order. The generated `.proto` file declares exactly one top-level
+//! message and nests every other message inside of it, so that the file can be
+//! consumed by Protobuf sources and so that the Confluent message index of
+//! every encoded message is `[0]`.
+//!
+//! Protobuf cannot represent every SQL `NULL`. Nullable scalar columns are
+//! `optional` fields and nullable record columns are message fields, both of
+//! which are simply omitted when `NULL`. But a `NULL` list or map is encoded as
+//! an empty list or map, and a `NULL` list element or map value is encoded as
+//! the default value of its type.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use byteorder::{NetworkEndian, WriteBytesExt};
+use chrono::{NaiveDateTime, Timelike};
+use itertools::Itertools;
+use prost::encoding::{encode_key, encode_varint, WireType};
+
+use mz_avro::schema::Name;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
+use mz_repr::adt::inet::Cidr;
+use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::{ColumnName, ColumnType, Datum, GlobalId, RelationDesc, Row, ScalarType};
+
+use crate::encode::{column_names_and_types, Encode};
+use crate::envelopes::{self, ENVELOPE_CUSTOM_NAMES};
+
+/// The name of the top-level message in key schemas.
+const KEY_MESSAGE_NAME: &str = "row";
+/// The name of the top-level message in value schemas.
+const VALUE_MESSAGE_NAME: &str = "envelope";
+
+fn encode_protobuf_header(buf: &mut Vec<u8>, schema_id: i32) {
+    // The first byte is a magic byte (0) that indicates the Confluent
+    // serialization format version, and the next four bytes are a
+    // 32-bit schema ID. Protobuf messages are additionally prefixed with
+    // the path to the message type within the schema, and the path `[0]`
+    // of the first top-level message is encoded as a single zero byte.
+    //
+    // https://docs.confluent.io/current/schema-registry/docs/serializer-formatter.html#wire-format
+    buf.write_u8(0).expect("writing to vec cannot fail");
+    buf.write_i32::<NetworkEndian>(schema_id)
+        .expect("writing to vec cannot fail");
+    buf.write_u8(0).expect("writing to vec cannot fail");
+}
+
+struct MessageInfo {
+    columns: Vec<(ColumnName, ColumnType)>,
+    schema: String,
+}
+
+impl MessageInfo {
+    fn new(
+        columns: Vec<(ColumnName, ColumnType)>,
+        name: &str,
+        custom_names: &BTreeMap<GlobalId, String>,
+    ) -> MessageInfo {
+        let schema = build_schema(&columns, name, custom_names);
+        MessageInfo { columns, schema }
+    }
+}
+
+/// Generates key and value Protobuf schemas.
+pub struct ProtobufSchemaGenerator {
+    value_info: MessageInfo,
+    key_info: Option<MessageInfo>,
+}
+
+impl ProtobufSchemaGenerator {
+    pub fn new(key_desc: Option<RelationDesc>, value_desc: RelationDesc, debezium: bool) -> Self {
+        let mut value_columns = column_names_and_types(value_desc);
+        if debezium {
+            value_columns = envelopes::dbz_envelope(value_columns);
+        }
+        let value_info =
+            MessageInfo::new(value_columns, VALUE_MESSAGE_NAME, &ENVELOPE_CUSTOM_NAMES);
+        let key_info = key_desc.map(|key_desc| {
+            let columns = column_names_and_types(key_desc);
+            MessageInfo::new(columns, KEY_MESSAGE_NAME, &BTreeMap::new())
+        });
+        ProtobufSchemaGenerator {
+            value_info,
+            key_info,
+        }
+    }
+
+    /// Returns the text of the `.proto` file that describes values.
+    pub fn value_schema(&self) -> &str {
+        &self.value_info.schema
+    }
+
+    pub fn value_columns(&self) -> &[(ColumnName, ColumnType)] {
+        &self.value_info.columns
+    }
+
+    /// Returns the text of the `.proto` file that describes keys, if the sink
+    /// has a key.
+    pub fn key_schema(&self) -> Option<&str> {
+        self.key_info.as_ref().map(|info| info.schema.as_str())
+    }
+
+    pub fn key_columns(&self) -> Option<&[(ColumnName, ColumnType)]> {
+        self.key_info.as_ref().map(|info| info.columns.as_slice())
+    }
+}
+
+impl fmt::Debug for ProtobufSchemaGenerator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProtobufSchemaGenerator")
+            .field("value_schema", &self.value_info.schema)
+            .finish()
+    }
+}
+
+/// Manages encoding of Protobuf-encoded bytes.
+pub struct ProtobufEncoder {
+    schema_generator: ProtobufSchemaGenerator,
+    key_schema_id: Option<i32>,
+    value_schema_id: i32,
+}
+
+impl fmt::Debug for ProtobufEncoder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ProtobufEncoder")
+            .field("value_schema", &self.schema_generator.value_info.schema)
+            .finish()
+    }
+}
+
+impl ProtobufEncoder {
+    pub fn new(
+        schema_generator: ProtobufSchemaGenerator,
+        key_schema_id: Option<i32>,
+        value_schema_id: i32,
+    ) -> Self {
+        ProtobufEncoder {
+            schema_generator,
+            key_schema_id,
+            value_schema_id,
+        }
+    }
+}
+
+impl Encode for ProtobufEncoder {
+    fn get_format_name(&self) -> &str {
+        "protobuf"
+    }
+
+    fn encode_key_unchecked(&self, row: Row) -> Vec<u8> {
+        let columns = self.schema_generator.key_columns().unwrap();
+        encode_message_unchecked(self.key_schema_id.unwrap(), row, columns)
+    }
+
+    fn encode_value_unchecked(&self, row: Row) -> Vec<u8> {
+        let columns = self.schema_generator.value_columns();
+        encode_message_unchecked(self.value_schema_id, row, columns)
+    }
+}
+
+fn encode_message_unchecked(
+    schema_id: i32,
+    row: Row,
+    columns: &[(ColumnName, ColumnType)],
+) -> Vec<u8> {
+    let mut buf = vec![];
+    encode_protobuf_header(&mut buf, schema_id);
+    encode_fields(&mut buf, row.iter(), columns);
+    buf
+}
+
+/// Encodes a sequence of `Datum` as the fields of a Protobuf message, using
+/// the supplied column types.
+fn encode_fields<'a, I>(buf: &mut Vec<u8>, datums: I, names_types: &[(ColumnName, ColumnType)])
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    for (i, ((_name, typ), datum)) in names_types.iter().zip_eq(datums).enumerate() {
+        encode_field(buf, field_number(i), datum, &typ.scalar_type);
+    }
+}
+
+/// Returns the field number of the `i`th column, skipping over the field
+/// numbers that Protobuf reserves for its own use.
+fn field_number(i: usize) -> u32 {
+    let number = u32::try_from(i + 1).expect("column count fits in a u32");
+    if number < 19_000 {
+        number
+    } else {
+        number + 1_000
+    }
+}
+
+/// The Protobuf scalar value types to which SQL types are mapped.
+#[derive(Clone, Copy, Debug)]
+enum ProtoScalar {
+    Bool,
+    Int32,
+    Int64,
+    Uint32,
+    Uint64,
+    Float,
+    Double,
+    String,
+    Bytes,
+}
+
+impl ProtoScalar {
+    /// Returns the Protobuf type that represents `ty`, or `None` if `ty` is
+    /// represented by a message, repeated field, or map field.
+    fn for_type(ty: &ScalarType) -> Option<ProtoScalar> {
+        match ty {
+            ScalarType::Bool => Some(ProtoScalar::Bool),
+            // Dates are encoded as days since the Unix epoch, times as
+            // microseconds since midnight, and timestamps as microseconds
+            // since the Unix epoch.
+            ScalarType::Int16 | ScalarType::Int32 | ScalarType::Date => Some(ProtoScalar::Int32),
+            ScalarType::Int64
+            | ScalarType::Time
+            | ScalarType::Timestamp
+            | ScalarType::TimestampTz => Some(ProtoScalar::Int64),
+            ScalarType::PgLegacyChar
+            | ScalarType::UInt16
+            | ScalarType::UInt32
+            | ScalarType::Oid
+            | ScalarType::RegClass
+            | ScalarType::RegProc
+            | ScalarType::RegType => Some(ProtoScalar::Uint32),
+            ScalarType::UInt64 => Some(ProtoScalar::Uint64),
+            ScalarType::Float32 => Some(ProtoScalar::Float),
+            ScalarType::Float64 => Some(ProtoScalar::Double),
+            ScalarType::Bytes => Some(ProtoScalar::Bytes),
+            // Protobuf has no decimal type, so numerics are encoded in
+            // standard notation to avoid any loss of precision.
+            ScalarType::Numeric { .. }
+            | ScalarType::Interval
+            | ScalarType::String
+            | ScalarType::Char { .. }
+            | ScalarType::VarChar { .. }
+            | ScalarType::Jsonb
+            | ScalarType::Uuid
+            | ScalarType::MzTimestamp
+            | ScalarType::Range { .. }
+            | ScalarType::MzAclItem
+            | ScalarType::Inet
+            | ScalarType::Cidr
+            | ScalarType::Enum { .. } => Some(ProtoScalar::String),
+            ScalarType::Array(_)
+            | ScalarType::Int2Vector
+            | ScalarType::List { .. }
+            | ScalarType::Map { .. }
+            | ScalarType::Record { .. } => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            ProtoScalar::Bool => "bool",
+            ProtoScalar::Int32 => "int32",
+            ProtoScalar::Int64 => "int64",
+            ProtoScalar::Uint32 => "uint32",
+            ProtoScalar::Uint64 => "uint64",
+            ProtoScalar::Float => "float",
+            ProtoScalar::Double => "double",
+            ProtoScalar::String => "string",
+            ProtoScalar::Bytes => "bytes",
+        }
+    }
+
+    fn wire_type(&self) -> WireType {
+        match self {
+            ProtoScalar::Bool
+            | ProtoScalar::Int32
+            | ProtoScalar::Int64
+            | ProtoScalar::Uint32
+            | ProtoScalar::Uint64 => WireType::Varint,
+            ProtoScalar::Float => WireType::ThirtyTwoBit,
+            ProtoScalar::Double => WireType::SixtyFourBit,
+            ProtoScalar::String | ProtoScalar::Bytes => WireType::LengthDelimited,
+        }
+    }
+}
+
+/// Reports whether `ty` is encoded as a repeated field or a map field, neither
+/// of which can be directly nested inside one another.
+fn is_collection(ty: &ScalarType) -> bool {
+    matches!(
+        ty,
+        ScalarType::Array(_)
+            | ScalarType::Int2Vector
+            | ScalarType::List { .. }
+            | ScalarType::Map { .. }
+    )
+}
+
+/// Builds the text of a `.proto` file that declares a single top-level message
+/// named `name` whose fields correspond to `columns`.
+fn build_schema(
+    columns: &[(ColumnName, ColumnType)],
+    name: &str,
+    custom_names: &BTreeMap<GlobalId, String>,
+) -> String {
+    let mut builder = SchemaBuilder {
+        message_name: name,
+        custom_names,
+        seen_types: BTreeMap::new(),
+        nested_messages: vec![],
+        record_index: 0,
+        collection_index: 0,
+    };
+    let types: Vec<_> = columns
+        .iter()
+        .map(|(_, typ)| builder.field_type(typ))
+        .collect();
+    // Field names share a namespace with the names of nested messages.
+    let reserved = builder
+        .nested_messages
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect();
+    let fields = build_fields(columns, types, reserved);
+
+    let mut schema = String::from("syntax = \"proto3\";\n\n");
+    schema += &format!("message {name} {{\n");
+    for (nested_name, nested_fields) in &builder.nested_messages {
+        schema += &format!("  message {nested_name} {{\n");
+        for field in nested_fields {
+            schema += &format!("    {field}\n");
+        }
+        schema += "  }\n\n";
+    }
+    for field in fields {
+        schema += &format!("  {field}\n");
+    }
+    schema += "}\n";
+    schema
+}
+
+/// Builds the declarations of the fields that correspond to `columns`, whose
+/// types have already been determined.
+fn build_fields(
+    columns: &[(ColumnName, ColumnType)],
+    types: Vec<String>,
+    mut seen_names: BTreeSet<String>,
+) -> Vec<String> {
+    columns
+        .iter()
+        .zip_eq(types)
+        .enumerate()
+        .map(|(i, ((name, _), typ))| {
+            let stem = Name::make_valid(name.as_str());
+            let mut name = stem.clone();
+            let mut suffix = 1;
+            while seen_names.contains(&name) {
+                name = format!("{stem}{suffix}");
+                suffix += 1;
+            }
+            seen_names.insert(name.clone());
+            format!("{typ} {name} = {};", field_number(i))
+        })
+        .collect()
+}
+
+/// Accumulates the nested messages of a `.proto` file.
+struct SchemaBuilder<'a> {
+    message_name: &'a str,
+    custom_names: &'a BTreeMap<GlobalId, String>,
+    /// The names of the nested messages that represent each type.
+    seen_types: BTreeMap<ScalarType, String>,
+    /// The names and field declarations of the nested messages, in the order
+    /// in which they were declared.
+    nested_messages: Vec<(String, Vec<String>)>,
+    record_index: usize,
+    collection_index: usize,
+}
+
+impl SchemaBuilder<'_> {
+    /// Returns the type of the field that represents a column of type `typ`,
+    /// including its label.
+    fn field_type(&mut self, typ: &ColumnType) -> String {
+        match &typ.scalar_type {
+            ty @ (ScalarType::Array(_) | ScalarType::Int2Vector | ScalarType::List { .. }) => {
+                let element_type = self.element_type(ty.unwrap_collection_element_type());
+                format!("repeated {element_type}")
+            }
+            ScalarType::Map { value_type, .. } => {
+                format!("map<string, {}>", self.element_type(value_type))
+            }
+            ty => match ProtoScalar::for_type(ty) {
+                Some(scalar) if typ.nullable => format!("optional {}", scalar.name()),
+                Some(scalar) => scalar.name().into(),
+                None => self.message_type(ty),
+            },
+        }
+    }
+
+    /// Returns the type of the elements of a repeated field or the values of
+    /// a map field that represent a list or map whose elements are of type
+    /// `ty`.
+    fn element_type(&mut self, ty: &ScalarType) -> String {
+        match ProtoScalar::for_type(ty) {
+            Some(scalar) => scalar.name().into(),
+            None => self.message_type(ty),
+        }
+    }
+
+    /// Returns the fully qualified name of the nested message that represents
+    /// `ty`, declaring the message if necessary.
+    ///
+    /// Records are represented by a message with one field per record field.
+    /// Lists and maps are represented by a message with a single field named
+    /// `value`, as repeated and map fields cannot be nested directly.
+    fn message_type(&mut self, ty: &ScalarType) -> String {
+        let name = match self.seen_types.get(ty) {
+            Some(name) => name.clone(),
+            None => {
+                let name = match ty {
+                    ScalarType::Record { custom_id, .. } => {
+                        match custom_id.as_ref().and_then(|id| self.custom_names.get(id)) {
+                            Some(name) => Name::make_valid(name),
+                            None => {
+                                self.record_index += 1;
+                                format!("record{}", self.record_index - 1)
+                            }
+                        }
+                    }
+                    _ => {
+                        self.collection_index += 1;
+                        format!("collection{}", self.collection_index - 1)
+                    }
+                };
+                self.seen_types.insert(ty.clone(), name.clone());
+                let fields = match ty {
+                    ScalarType::Record { fields, .. } => {
+                        let types = fields.iter().map(|(_, typ)| self.field_type(typ)).collect();
+                        build_fields(fields, types, BTreeSet::new())
+                    }
+                    _ => {
+                        let typ = self.field_type(&ty.clone().nullable(true));
+                        vec![format!("{typ} value = 1;")]
+                    }
+                };
+                self.nested_messages.push((name.clone(), fields));
+                name
+            }
+        };
+        format!(".{}.{}", self.message_name, name)
+    }
+}
+
+/// Encodes `datum` as the field numbered `tag` of a message.
+fn encode_field(buf: &mut Vec<u8>, tag: u32, datum: Datum, ty: &ScalarType) {
+    if datum.is_null() {
+        // Absent fields decode as `NULL` or as an empty collection.
+        return;
+    }
+    match ty {
+        ScalarType::Array(_) | ScalarType::Int2Vector | ScalarType::List { .. } => {
+            let elements = match ty {
+                ScalarType::List { .. } => datum.unwrap_list(),
+                _ => datum.unwrap_array().elements(),
+            };
+            let element_type = ty.unwrap_collection_element_type();
+            for element in elements.iter() {
+                encode_element(buf, tag, element, element_type);
+            }
+        }
+        ScalarType::Map { value_type, .. } => {
+            for (key, value) in datum.unwrap_map().iter() {
+                encode_message(buf, tag, |buf| {
+                    encode_bytes(buf, 1, key.as_bytes());
+                    encode_element(buf, 2, value, value_type);
+                });
+            }
+        }
+        _ => encode_value(buf, tag, datum, ty),
+    }
+}
+
+/// Encodes `datum` as an element of a repeated field or as the value of a map
+/// field, neither of which can be absent.
+fn encode_element(buf: &mut Vec<u8>, tag: u32, datum: Datum, ty: &ScalarType) {
+    if is_collection(ty) {
+        encode_message(buf, tag, |buf| encode_field(buf, 1, datum, ty));
+    } else if datum.is_null() {
+        let wire_type = ProtoScalar::for_type(ty)
+            .map_or(WireType::LengthDelimited, |scalar| scalar.wire_type());
+        encode_key(tag, wire_type, buf);
+        match wire_type {
+            WireType::ThirtyTwoBit => buf.extend([0; 4]),
+            WireType::SixtyFourBit => buf.extend([0; 8]),
+            _ => encode_varint(0, buf),
+        }
+    } else {
+        encode_value(buf, tag, datum, ty);
+    }
+}
+
+/// Encodes a non-null `datum` whose type is not a list or map.
+fn encode_value(buf: &mut Vec<u8>, tag: u32, datum: Datum, ty: &ScalarType) {
+    match ty {
+        ScalarType::Bool => encode_uint(buf, tag, u64::from(datum.unwrap_bool())),
+        ScalarType::PgLegacyChar => encode_uint(buf, tag, u64::from(datum.unwrap_uint8())),
+        ScalarType::Int16 => encode_int(buf, tag, i64::from(datum.unwrap_int16())),
+        ScalarType::Int32 => encode_int(buf, tag, i64::from(datum.unwrap_int32())),
+        ScalarType::Int64 => encode_int(buf, tag, datum.unwrap_int64()),
+        ScalarType::UInt16 => encode_uint(buf, tag, u64::from(datum.unwrap_uint16())),
+        ScalarType::UInt32
+        | ScalarType::Oid
+        | ScalarType::RegClass
+        | ScalarType::RegProc
+        | ScalarType::RegType => encode_uint(buf, tag, u64::from(datum.unwrap_uint32())),
+        ScalarType::UInt64 => encode_uint(buf, tag, datum.unwrap_uint64()),
+        ScalarType::Float32 => {
+            encode_key(tag, WireType::ThirtyTwoBit, buf);
+            buf.extend(datum.unwrap_float32().to_le_bytes());
+        }
+        ScalarType::Float64 => {
+            encode_key(tag, WireType::SixtyFourBit, buf);
+            buf.extend(datum.unwrap_float64().to_le_bytes());
+        }
+        ScalarType::Numeric { .. } => {
+            let s = datum.unwrap_numeric().0.to_standard_notation_string();
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Date => encode_int(buf, tag, i64::from(datum.unwrap_date().unix_epoch_days())),
+        ScalarType::Time => {
+            let time = datum.unwrap_time();
+            let micros = i64::from(time.num_seconds_from_midnight()) * 1_000_000
+                + i64::from(time.nanosecond()) / 1_000;
+            encode_int(buf, tag, micros);
+        }
+        ScalarType::Timestamp => {
+            let ts = datum.unwrap_timestamp().to_naive();
+            encode_int(buf, tag, timestamp_micros(ts));
+        }
+        ScalarType::TimestampTz => {
+            let ts = datum.unwrap_timestamptz().to_naive();
+            encode_int(buf, tag, timestamp_micros(ts));
+        }
+        ScalarType::Interval => {
+            encode_bytes(buf, tag, datum.unwrap_interval().to_string().as_bytes())
+        }
+        ScalarType::Bytes => encode_bytes(buf, tag, datum.unwrap_bytes()),
+        ScalarType::String | ScalarType::VarChar { .. } => {
+            encode_bytes(buf, tag, datum.unwrap_str().as_bytes())
+        }
+        ScalarType::Char { length } => {
+            let s = mz_repr::adt::char::format_str_pad(datum.unwrap_str(), *length);
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Jsonb => {
+            let s = JsonbRef::from_datum(datum).to_string();
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Uuid => encode_bytes(buf, tag, datum.unwrap_uuid().to_string().as_bytes()),
+        ScalarType::Record { fields, .. } => encode_message(buf, tag, |buf| {
+            encode_fields(buf, datum.unwrap_list().iter(), fields)
+        }),
+        ScalarType::MzTimestamp => {
+            let s = datum.unwrap_mz_timestamp().to_string();
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Range { .. } => {
+            encode_bytes(buf, tag, datum.unwrap_range().to_string().as_bytes())
+        }
+        ScalarType::MzAclItem => {
+            let s = datum.unwrap_mz_acl_item().to_string();
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Inet => {
+            let s = datum.unwrap_inet().to_string();
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Cidr => {
+            let s = Cidr(datum.unwrap_inet()).to_string();
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Enum { labels, .. } => {
+            let s = &labels[usize::cast_from(datum.unwrap_uint32())];
+            encode_bytes(buf, tag, s.as_bytes());
+        }
+        ScalarType::Array(_)
+        | ScalarType::Int2Vector
+        | ScalarType::List { .. }
+        | ScalarType::Map { .. } => unreachable!("collections are encoded by encode_field"),
+    }
+}
+
+fn timestamp_micros(ts: NaiveDateTime) -> i64 {
+    ts.timestamp() * 1_000_000 + i64::from(ts.timestamp_subsec_micros())
+}
+
+fn encode_int(buf: &mut Vec<u8>, tag: u32, value: i64) {
+    // Negative `int32` and `int64` values are both encoded as ten-byte
+    // varints.
+    encode_uint(buf, tag, u64::reinterpret_cast(value));
+}
+
+fn encode_uint(buf: &mut Vec<u8>, tag: u32, value: u64) {
+    encode_key(tag, WireType::Varint, buf);
+    encode_varint(value, buf);
+}
+
+fn encode_bytes(buf: &mut Vec<u8>, tag: u32, bytes: &[u8]) {
+    encode_key(tag, WireType::LengthDelimited, buf);
+    encode_varint(u64::cast_from(bytes.len()), buf);
+    buf.extend(bytes);
+}
+
+fn encode_message<F>(buf: &mut Vec<u8>, tag: u32, f: F)
+where
+    F: FnOnce(&mut Vec<u8>),
+{
+    let mut message = vec![];
+    f(&mut message);
+    encode_bytes(buf, tag, &message);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use protobuf_native::compiler::{SourceTreeDescriptorDatabase, VirtualSourceTree};
+    use protobuf_native::MessageLite;
+
+    use crate::protobuf::{DecodedDescriptors, Decoder};
+
+    use super::*;
+
+    /// Compiles a `.proto` file into an encoded file descriptor set.
+    fn compile(schema: &str) -> Vec<u8> {
+        let path = Path::new("schema.proto");
+        let mut source_tree = VirtualSourceTree::new();
+        source_tree
+            .as_mut()
+            .add_file(path, schema.as_bytes().to_vec());
+        let mut db = SourceTreeDescriptorDatabase::new(source_tree.as_mut());
+        let fds = db
+            .as_mut()
+            .build_file_descriptor_set(&[path])
+            .unwrap_or_else(|e| panic!("invalid schema: {e}\n{schema}"));
+        fds.serialize().unwrap()
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_debezium_schema() {
+        let desc = RelationDesc::empty()
+            .with_column("a", ScalarType::Int32.nullable(false))
+            .with_column("b", ScalarType::String.nullable(true))
+            .with_column(
+                "c",
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Int32),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .with_column(
+                "d",
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::List {
+                        element_type: Box::new(ScalarType::Int32),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .with_column(
+                "e",
+                ScalarType::Map {
+                    value_type: Box::new(ScalarType::String),
+                    custom_id: None,
+                }
+                .nullable(true),
+            );
+        let schema_generator = ProtobufSchemaGenerator::new(None, desc, true);
+        let schema = schema_generator.value_schema();
+        assert_eq!(
+            schema,
+            r#"syntax = "proto3";
+
+message envelope {
+  message collection0 {
+    repeated int32 value = 1;
+  }
+
+  message row {
+    int32 a = 1;
+    optional string b = 2;
+    repeated int32 c = 3;
+    repeated .envelope.collection0 d = 4;
+    map<string, string> e = 5;
+  }
+
+  .envelope.row before = 1;
+  .envelope.row after = 2;
+}
+"#
+        );
+        compile(schema);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_roundtrip() {
+        let desc = RelationDesc::empty()
+            .with_column("a", ScalarType::Int32.nullable(false))
+            .with_column("a", ScalarType::String.nullable(true))
+            .with_column(
+                "list",
+                ScalarType::List {
+                    element_type: Box::new(ScalarType::Int64),
+                    custom_id: None,
+                }
+                .nullable(false),
+            )
+            .with_column(
+                "record",
+                ScalarType::Record {
+                    fields: vec![
+                        ("x".into(), ScalarType::Float64.nullable(false)),
+                        ("y".into(), ScalarType::Bytes.nullable(false)),
+                    ],
+                    custom_id: None,
+                }
+                .nullable(true),
+            );
+        let schema_generator = ProtobufSchemaGenerator::new(None, desc, false);
+        let descriptors = DecodedDescriptors::from_bytes(
+            &compile(schema_generator.value_schema()),
+            VALUE_MESSAGE_NAME.into(),
+        )
+        .unwrap();
+        let mut decoder = Decoder::new(descriptors, true).unwrap();
+        let encoder = ProtobufEncoder::new(schema_generator, None, 42);
+
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.push(Datum::Int32(-1));
+        packer.push(Datum::String("hello"));
+        packer.push_list([Datum::Int64(i64::MIN), Datum::Int64(0), Datum::Int64(1)]);
+        packer.push_list([Datum::Float64(1.5.into()), Datum::Bytes(b"\x00\xff")]);
+
+        let bytes = encoder.encode_value_unchecked(row.clone());
+        assert_eq!(&bytes[..6], &[0, 0, 0, 0, 42, 0]);
+        assert_eq!(decoder.decode(&bytes).unwrap(), Some(row));
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Decoding of unstructured text and bytes.
+
+use anyhow::anyhow;
+
+use mz_repr::{Datum, Row};
+
+/// Decodes UTF-8 text into a row containing a single `text` datum.
+pub fn decode_text(bytes: &[u8]) -> Result<Row, anyhow::Error> {
+    let s = std::str::from_utf8(bytes).map_err(|_| anyhow!("Failed to decode UTF-8"))?;
+    Ok(Row::pack_slice(&[Datum::String(s)]))
+}
+
+/// Decodes arbitrary bytes into a row containing a single `bytea` datum.
+pub fn decode_bytes(bytes: &[u8]) -> Row {
+    Row::pack_slice(&[Datum::Bytes(bytes)])
+}
@@ -0,0 +1,265 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Descriptions of MySQL objects.
+
+use std::collections::BTreeSet;
+
+use anyhow::bail;
+use proptest::prelude::{any, Arbitrary};
+use proptest::strategy::{BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use mz_proto::{RustType, TryFromProtoError};
+
+include!(concat!(env!("OUT_DIR"), "/mz_mysql_util.desc.rs"));
+
+/// Describes a table in a MySQL database.
+///
+/// MySQL does not assign stable identifiers to tables, so tables are
+/// identified by their schema (i.e. database) and name.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlTableDesc {
+    /// The name of the schema (database) that the table belongs to.
+    pub schema_name: String,
+    /// The name of the table.
+    pub name: String,
+    /// The description of each column, in order of their position in the table.
+    pub columns: Vec<MySqlColumnDesc>,
+    /// Applicable keys for this table (i.e. primary key and unique
+    /// constraints).
+    pub keys: BTreeSet<MySqlKeyDesc>,
+}
+
+impl MySqlTableDesc {
+    /// Determines if two `MySqlTableDesc` are compatible with one another in
+    /// a way that Materialize can handle.
+    ///
+    /// Unlike PostgreSQL, the MySQL binlog identifies columns only by their
+    /// position, so `self`'s columns must exactly match `other`'s columns
+    /// except that `self`'s nullable columns may have become `NOT NULL`.
+    /// Additionally, all of `self`'s keys must still be present in `other`.
+    pub fn determine_compatibility(&self, other: &MySqlTableDesc) -> Result<(), anyhow::Error> {
+        if self == other {
+            return Ok(());
+        }
+
+        let MySqlTableDesc {
+            schema_name: other_schema_name,
+            name: other_name,
+            columns: other_cols,
+            keys: other_keys,
+        } = other;
+
+        if self.columns.len() == other_cols.len()
+            && self
+                .columns
+                .iter()
+                .zip(other_cols.iter())
+                .all(|(s, o)| s.is_compatible(o))
+            && &self.name == other_name
+            && &self.schema_name == other_schema_name
+            // Our keys are all still present in exactly the same shape.
+            && self.keys.difference(other_keys).next().is_none()
+        {
+            Ok(())
+        } else {
+            warn!(
+                "Error validating table in MySQL. Expected: {:?} Actual: {:?}",
+                &self, other
+            );
+            bail!(
+                "source table {}.{} has been altered",
+                self.schema_name,
+                self.name
+            )
+        }
+    }
+}
+
+impl RustType<ProtoMySqlTableDesc> for MySqlTableDesc {
+    fn into_proto(&self) -> ProtoMySqlTableDesc {
+        ProtoMySqlTableDesc {
+            schema_name: self.schema_name.clone(),
+            name: self.name.clone(),
+            columns: self.columns.iter().map(|c| c.into_proto()).collect(),
+            keys: self.keys.iter().map(MySqlKeyDesc::into_proto).collect(),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlTableDesc) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlTableDesc {
+            schema_name: proto.schema_name,
+            name: proto.name,
+            columns: proto
+                .columns
+                .into_iter()
+                .map(MySqlColumnDesc::from_proto)
+                .collect::<Result<_, _>>()?,
+            keys: proto
+                .keys
+                .into_iter()
+                .map(MySqlKeyDesc::from_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Arbitrary for MySqlTableDesc {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<String>(),
+            any::<Vec<MySqlColumnDesc>>(),
+            any::<BTreeSet<MySqlKeyDesc>>(),
+        )
+            .prop_map(|(schema_name, name, columns, keys)| MySqlTableDesc {
+                schema_name,
+                name,
+                columns,
+                keys,
+            })
+            .boxed()
+    }
+}
+
+/// Describes a column in a [`MySqlTableDesc`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlColumnDesc {
+    /// The name of the column.
+    pub name: String,
+    /// The name of the column's type without any modifiers, e.g. `int`.
+    pub data_type: String,
+    /// The full definition of the column's type, e.g. `int(10) unsigned`.
+    pub column_type: String,
+    /// The precision of numeric columns.
+    pub precision: Option<u32>,
+    /// The scale of numeric columns.
+    pub scale: Option<u32>,
+    /// True if the column lacks a `NOT NULL` constraint.
+    pub nullable: bool,
+}
+
+impl MySqlColumnDesc {
+    /// Reports whether the column's type is declared `unsigned`.
+    pub fn is_unsigned(&self) -> bool {
+        self.column_type.split_whitespace().any(|w| w == "unsigned")
+    }
+
+    /// Determines if data a relation with a structure of `other` can be treated
+    /// the same as `self`.
+    fn is_compatible(&self, other: &MySqlColumnDesc) -> bool {
+        self.name == other.name
+            && self.data_type == other.data_type
+            && self.column_type == other.column_type
+            && self.precision == other.precision
+            && self.scale == other.scale
+            && (self.nullable || self.nullable == other.nullable)
+    }
+}
+
+impl RustType<ProtoMySqlColumnDesc> for MySqlColumnDesc {
+    fn into_proto(&self) -> ProtoMySqlColumnDesc {
+        ProtoMySqlColumnDesc {
+            name: self.name.clone(),
+            data_type: self.data_type.clone(),
+            column_type: self.column_type.clone(),
+            precision: self.precision,
+            scale: self.scale,
+            nullable: self.nullable,
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlColumnDesc) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlColumnDesc {
+            name: proto.name,
+            data_type: proto.data_type,
+            column_type: proto.column_type,
+            precision: proto.precision,
+            scale: proto.scale,
+            nullable: proto.nullable,
+        })
+    }
+}
+
+impl Arbitrary for MySqlColumnDesc {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<String>(),
+            any::<String>(),
+            any::<Option<u32>>(),
+            any::<Option<u32>>(),
+            any::<bool>(),
+        )
+            .prop_map(
+                |(name, data_type, column_type, precision, scale, nullable)| MySqlColumnDesc {
+                    name,
+                    data_type,
+                    column_type,
+                    precision,
+                    scale,
+                    nullable,
+                },
+            )
+            .boxed()
+    }
+}
+
+/// Describes a key in a [`MySqlTableDesc`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, PartialOrd, Ord)]
+pub struct MySqlKeyDesc {
+    /// The name of the index backing the key. Primary keys are always named
+    /// `PRIMARY`.
+    pub name: String,
+    /// Whether or not this key is the primary key.
+    pub is_primary: bool,
+    /// The names of the columns comprising the key, in key order.
+    pub columns: Vec<String>,
+}
+
+impl RustType<ProtoMySqlKeyDesc> for MySqlKeyDesc {
+    fn into_proto(&self) -> ProtoMySqlKeyDesc {
+        ProtoMySqlKeyDesc {
+            name: self.name.clone(),
+            is_primary: self.is_primary,
+            columns: self.columns.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlKeyDesc) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlKeyDesc {
+            name: proto.name,
+            is_primary: proto.is_primary,
+            columns: proto.columns,
+        })
+    }
+}
+
+impl Arbitrary for MySqlKeyDesc {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), any::<bool>(), any::<Vec<String>>())
+            .prop_map(|(name, is_primary, columns)| MySqlKeyDesc {
+                name,
+                is_primary,
+                columns,
+            })
+            .boxed()
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! MySQL global transaction identifier (GTID) sets.
+//!
+//! See <https://dev.mysql.com/doc/refman/8.0/en/replication-gtids-concepts.html>.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail};
+use mysql_async::{GnoInterval, Sid};
+use uuid::Uuid;
+
+/// A set of GTIDs, e.g. the value of `@@gtid_executed`.
+///
+/// Each GTID is a pair of a source server UUID and a transaction number. The
+/// set is stored as a map from server UUID to a sorted list of disjoint,
+/// inclusive ranges of transaction numbers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GtidSet(BTreeMap<Uuid, Vec<(u64, u64)>>);
+
+impl GtidSet {
+    /// Adds the inclusive range of transaction numbers `start..=end` that
+    /// originated on the server identified by `uuid` to the set.
+    pub fn insert_range(&mut self, uuid: Uuid, start: u64, end: u64) {
+        assert!(start <= end, "invalid GTID range {start}-{end}");
+        let ranges = self.0.entry(uuid).or_default();
+        ranges.push((start, end));
+        ranges.sort();
+        let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges.drain(..) {
+            match merged.last_mut() {
+                Some((_, last_end)) if start <= last_end.saturating_add(1) => {
+                    *last_end = std::cmp::max(*last_end, end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        *ranges = merged;
+    }
+
+    /// Returns the highest transaction number that originated on the server
+    /// identified by `uuid`, or zero if no such transaction exists.
+    ///
+    /// Returns an error if the transactions from the server do not form a
+    /// single contiguous range starting at 1, in which case the transactions
+    /// cannot be summarized by a single number.
+    pub fn max_transaction_id(&self, uuid: &Uuid) -> Result<u64, anyhow::Error> {
+        match self.0.get(uuid).map(|ranges| ranges.as_slice()) {
+            None | Some([]) => Ok(0),
+            Some([(1, end)]) => Ok(*end),
+            Some(_) => bail!(
+                "GTID set for server {} is not contiguous: {}",
+                uuid,
+                self.uuid_ranges(uuid)
+            ),
+        }
+    }
+
+    /// Returns a new set containing all of this set's GTIDs except those
+    /// that originated on the server identified by `uuid`, which are replaced
+    /// by transaction numbers `1..=max_transaction_id`.
+    ///
+    /// Returns an error if any of the transaction numbers `1..=max_transaction_id`
+    /// are missing from this set. Requesting a binlog stream with such a set
+    /// would silently skip the missing transactions, should they ever be
+    /// executed.
+    pub fn with_transaction_id(
+        &self,
+        uuid: Uuid,
+        max_transaction_id: u64,
+    ) -> Result<GtidSet, anyhow::Error> {
+        if max_transaction_id > 0 {
+            match self.0.get(&uuid).and_then(|ranges| ranges.first()) {
+                Some((1, end)) if *end >= max_transaction_id => (),
+                _ => bail!(
+                    "GTID set for server {} is missing transactions before {}: {}",
+                    uuid,
+                    max_transaction_id,
+                    self.uuid_ranges(&uuid)
+                ),
+            }
+        }
+        let mut set = self.clone();
+        set.0.remove(&uuid);
+        if max_transaction_id > 0 {
+            set.insert_range(uuid, 1, max_transaction_id);
+        }
+        Ok(set)
+    }
+
+    /// Converts the set into the form expected by a `COM_BINLOG_DUMP_GTID`
+    /// request.
+    pub fn to_sids(&self) -> Vec<Sid<'static>> {
+        self.0
+            .iter()
+            .map(|(uuid, ranges)| {
+                let mut sid = Sid::new(*uuid.as_bytes());
+                for (start, end) in ranges {
+                    // Intervals on the wire are half open.
+                    sid = sid.with_interval(GnoInterval::new(*start, *end + 1));
+                }
+                sid
+            })
+            .collect()
+    }
+
+    fn uuid_ranges(&self, uuid: &Uuid) -> String {
+        let mut s = uuid.to_string();
+        for (start, end) in self.0.get(uuid).into_iter().flatten() {
+            if start == end {
+                s += &format!(":{start}");
+            } else {
+                s += &format!(":{start}-{end}");
+            }
+        }
+        s
+    }
+}
+
+impl FromStr for GtidSet {
+    type Err = anyhow::Error;
+
+    /// Parses a GTID set in the format produced by MySQL, e.g.
+    /// `3E11FA47-71CA-11E1-9E33-C80AA9429562:1-5:11,...`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut set = GtidSet::default();
+        for part in s.split(',') {
+            let part = part.trim();
+            if part.is_empty() {
+                continue;
+            }
+            let mut fields = part.split(':');
+            let uuid = fields.next().expect("split returns at least one field");
+            let uuid = Uuid::parse_str(uuid.trim())
+                .map_err(|e| anyhow!("invalid GTID set {}: {}", s, e))?;
+            let mut saw_range = false;
+            for range in fields {
+                saw_range = true;
+                let parse = |n: &str| {
+                    n.trim()
+                        .parse::<u64>()
+                        .map_err(|e| anyhow!("invalid GTID set {}: {}", s, e))
+                };
+                let (start, end) = match range.split_once('-') {
+                    Some((start, end)) => (parse(start)?, parse(end)?),
+                    None => {
+                        let n = parse(range)?;
+                        (n, n)
+                    }
+                };
+                if start == 0 || start > end {
+                    bail!("invalid GTID set {}: invalid range {}", s, range);
+                }
+                set.insert_range(uuid, start, end);
+            }
+            if !saw_range {
+                bail!(
+                    "invalid GTID set {}: missing transaction ids for {}",
+                    s,
+                    uuid
+                );
+            }
+        }
+        Ok(set)
+    }
+}
+
+impl fmt::Display for GtidSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for uuid in self.0.keys() {
+            if !first {
+                f.write_str(",")?;
+            }
+            first = false;
+            f.write_str(&self.uuid_ranges(uuid))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const A: &str = "3e11fa47-71ca-11e1-9e33-c80aa9429562";
+    const B: &str = "b3a8b2a4-5b1c-11ee-8c99-0242ac120002";
+
+    #[test]
+    fn test_parse_and_display() {
+        let set: GtidSet = format!("{}:1-5:7,\n{}:3", A.to_uppercase(), B)
+            .parse()
+            .unwrap();
+        assert_eq!(set.to_string(), format!("{A}:1-5:7,{B}:3"));
+
+        let set: GtidSet = format!("{A}:1-3:4-6:6-9").parse().unwrap();
+        assert_eq!(set.to_string(), format!("{A}:1-9"));
+
+        let set: GtidSet = "".parse().unwrap();
+        assert_eq!(set, GtidSet::default());
+
+        for bad in [
+            A.to_string(),
+            format!("{A}:0-3"),
+            format!("{A}:5-3"),
+            format!("{A}:x"),
+            "nope:1-3".to_string(),
+        ] {
+            assert!(bad.parse::<GtidSet>().is_err(), "{bad} parsed");
+        }
+    }
+
+    #[test]
+    fn test_max_transaction_id() {
+        let a = Uuid::parse_str(A).unwrap();
+        let b = Uuid::parse_str(B).unwrap();
+
+        let set: GtidSet = format!("{A}:1-42,{B}:5-6").parse().unwrap();
+        assert_eq!(set.max_transaction_id(&a).unwrap(), 42);
+        assert!(set.max_transaction_id(&b).is_err());
+        assert_eq!(GtidSet::default().max_transaction_id(&a).unwrap(), 0);
+
+        let rewound = set.with_transaction_id(a, 10).unwrap();
+        assert_eq!(rewound.to_string(), format!("{A}:1-10,{B}:5-6"));
+        assert_eq!(
+            set.with_transaction_id(a, 0).unwrap().to_string(),
+            format!("{B}:5-6")
+        );
+
+        // Gaps are never filled in, as the missing transactions would then be
+        // skipped if they were executed later.
+        let gappy: GtidSet = format!("{A}:1-5:7-9:20").parse().unwrap();
+        assert!(gappy.max_transaction_id(&a).is_err());
+        assert_eq!(
+            gappy.with_transaction_id(a, 5).unwrap().to_string(),
+            format!("{A}:1-5")
+        );
+        assert!(gappy.with_transaction_id(a, 6).is_err());
+        assert!(gappy.with_transaction_id(a, 20).is_err());
+        assert!(set.with_transaction_id(b, 6).is_err());
+    }
+}
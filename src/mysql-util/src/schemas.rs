@@ -0,0 +1,163 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::anyhow;
+use mysql_async::prelude::Queryable;
+
+use crate::desc::{MySqlColumnDesc, MySqlKeyDesc, MySqlTableDesc};
+use crate::{MySqlConn, MySqlError};
+
+/// Schemas that hold MySQL's own metadata, which are never replicated.
+const SYSTEM_SCHEMAS: &str = "('mysql', 'information_schema', 'performance_schema', 'sys')";
+
+/// Returns the value of the global system variable `name`.
+pub async fn query_sys_var(conn: &mut MySqlConn, name: &str) -> Result<String, MySqlError> {
+    let value: Option<String> = conn.query_first(format!("SELECT @@{}", name)).await?;
+    value.ok_or_else(|| MySqlError::Generic(anyhow!("system variable {} is not set", name)))
+}
+
+/// Verifies that the server is configured to write the row-based,
+/// GTID-annotated binlog that replication requires.
+///
+/// # Errors
+///
+/// Returns an error listing every misconfigured system variable.
+pub async fn ensure_replication_settings(conn: &mut MySqlConn) -> Result<(), MySqlError> {
+    let mut errors = vec![];
+    for (name, expected) in [
+        ("gtid_mode", "ON"),
+        ("enforce_gtid_consistency", "ON"),
+        ("binlog_format", "ROW"),
+        ("binlog_row_image", "FULL"),
+    ] {
+        let value = query_sys_var(conn, name).await?;
+        if !value.eq_ignore_ascii_case(expected) {
+            errors.push(format!("{} is {} but must be {}", name, value, expected));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(MySqlError::Generic(anyhow!(
+            "MySQL server is not configured for replication: {}",
+            errors.join("; ")
+        )))
+    }
+}
+
+/// Returns the names of all schemas in the upstream MySQL server, excluding
+/// the system schemas.
+pub async fn schema_names(conn: &mut MySqlConn) -> Result<BTreeSet<String>, MySqlError> {
+    let names: Vec<String> = conn
+        .query(format!(
+            "SELECT schema_name
+            FROM information_schema.schemata
+            WHERE schema_name NOT IN {SYSTEM_SCHEMAS}"
+        ))
+        .await?;
+    Ok(names.into_iter().collect())
+}
+
+/// Fetches the descriptions of all base tables in the upstream MySQL server,
+/// excluding the system schemas.
+///
+/// If `schemas` is `Some`, only tables in the named schemas are returned.
+/// Tables are returned ordered by schema and name.
+pub async fn schema_info(
+    conn: &mut MySqlConn,
+    schemas: Option<&BTreeSet<String>>,
+) -> Result<Vec<MySqlTableDesc>, MySqlError> {
+    let in_scope = |schema: &str| schemas.map_or(true, |s| s.contains(schema));
+
+    let tables: Vec<(String, String)> = conn
+        .query(format!(
+            "SELECT table_schema, table_name
+            FROM information_schema.tables
+            WHERE table_type = 'BASE TABLE' AND table_schema NOT IN {SYSTEM_SCHEMAS}
+            ORDER BY table_schema, table_name"
+        ))
+        .await?;
+
+    let mut table_infos: BTreeMap<(String, String), MySqlTableDesc> = tables
+        .into_iter()
+        .filter(|(schema, _)| in_scope(schema))
+        .map(|(schema_name, name)| {
+            let desc = MySqlTableDesc {
+                schema_name: schema_name.clone(),
+                name: name.clone(),
+                columns: vec![],
+                keys: BTreeSet::new(),
+            };
+            ((schema_name, name), desc)
+        })
+        .collect();
+
+    let columns: Vec<(
+        String,
+        String,
+        String,
+        String,
+        String,
+        Option<u64>,
+        Option<u64>,
+        String,
+    )> = conn
+        .query(format!(
+            "SELECT
+                table_schema, table_name, column_name, data_type, column_type,
+                numeric_precision, numeric_scale, is_nullable
+            FROM information_schema.columns
+            WHERE table_schema NOT IN {SYSTEM_SCHEMAS}
+            ORDER BY table_schema, table_name, ordinal_position"
+        ))
+        .await?;
+
+    for (schema, table, name, data_type, column_type, precision, scale, is_nullable) in columns {
+        let Some(desc) = table_infos.get_mut(&(schema, table)) else {
+            // A view, or a table outside of the requested schemas.
+            continue;
+        };
+        let narrow = |n: Option<u64>| n.map(|n| u32::try_from(n).unwrap_or(u32::MAX));
+        desc.columns.push(MySqlColumnDesc {
+            name,
+            data_type: data_type.to_lowercase(),
+            column_type: column_type.to_lowercase(),
+            precision: narrow(precision),
+            scale: narrow(scale),
+            nullable: is_nullable == "YES",
+        });
+    }
+
+    let key_columns: Vec<(String, String, String, String)> = conn
+        .query(format!(
+            "SELECT table_schema, table_name, index_name, column_name
+            FROM information_schema.statistics
+            WHERE non_unique = 0 AND table_schema NOT IN {SYSTEM_SCHEMAS}
+            ORDER BY table_schema, table_name, index_name, seq_in_index"
+        ))
+        .await?;
+
+    let mut keys: BTreeMap<(String, String, String), Vec<String>> = BTreeMap::new();
+    for (schema, table, index, column) in key_columns {
+        keys.entry((schema, table, index)).or_default().push(column);
+    }
+    for ((schema, table, index), columns) in keys {
+        if let Some(desc) = table_infos.get_mut(&(schema, table)) {
+            desc.keys.insert(MySqlKeyDesc {
+                is_primary: index == "PRIMARY",
+                name: index,
+                columns,
+            });
+        }
+    }
+
+    Ok(table_infos.into_values().collect())
+}
@@ -0,0 +1,151 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::ops::{Deref, DerefMut};
+
+use mysql_async::{BinlogRequest, BinlogStream, Conn, Opts, OptsBuilder};
+
+use mz_repr::GlobalId;
+use mz_ssh_util::tunnel::{SshTunnelConfig, SshTunnelHandle};
+
+use crate::MySqlError;
+
+/// Configures an optional tunnel for use when connecting to a MySQL
+/// database.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TunnelConfig {
+    /// Establish a direct TCP connection to the database host.
+    Direct,
+    /// Establish a TCP connection to the database via an SSH tunnel.
+    /// This means first establishing an SSH connection to a bastion host,
+    /// and then opening a separate connection from that host to the database.
+    Ssh(SshTunnelConfig),
+    /// Establish a TCP connection to the database via an AWS PrivateLink
+    /// service.
+    AwsPrivatelink {
+        /// The ID of the AWS PrivateLink service.
+        connection_id: GlobalId,
+    },
+}
+
+/// Configuration for MySQL connections.
+///
+/// This wraps [`mysql_async::Opts`] to allow the configuration of a
+/// tunnel via a [`TunnelConfig`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Config {
+    inner: Opts,
+    tunnel: TunnelConfig,
+}
+
+impl Config {
+    pub fn new(inner: Opts, tunnel: TunnelConfig) -> Self {
+        Self { inner, tunnel }
+    }
+
+    /// Connects to the configured MySQL database.
+    pub async fn connect(&self) -> Result<MySqlConn, MySqlError> {
+        let (opts, tunnel) = match &self.tunnel {
+            TunnelConfig::Direct => (self.inner.clone(), None),
+            TunnelConfig::Ssh(tunnel) => {
+                let tunnel = tunnel
+                    .connect(self.inner.ip_or_hostname(), self.inner.tcp_port())
+                    .await?;
+                let addr = tunnel.local_addr();
+                // The server's certificate names the database host, not the
+                // local end of the tunnel.
+                let ssl_opts = self
+                    .inner
+                    .ssl_opts()
+                    .cloned()
+                    .map(|ssl_opts| ssl_opts.with_danger_skip_domain_validation(true));
+                let opts = OptsBuilder::from_opts(self.inner.clone())
+                    .ip_or_hostname(addr.ip().to_string())
+                    .tcp_port(addr.port())
+                    .ssl_opts(ssl_opts);
+                (opts.into(), Some(tunnel))
+            }
+            TunnelConfig::AwsPrivatelink { connection_id } => {
+                let privatelink_host = mz_cloud_resources::vpc_endpoint_name(*connection_id);
+                let opts =
+                    OptsBuilder::from_opts(self.inner.clone()).ip_or_hostname(privatelink_host);
+                (opts.into(), None)
+            }
+        };
+        let conn = Conn::new(opts).await?;
+        Ok(MySqlConn {
+            conn,
+            _tunnel: tunnel,
+        })
+    }
+
+    pub fn get_user(&self) -> Option<&str> {
+        self.inner.user()
+    }
+}
+
+/// A connection to a MySQL database.
+///
+/// Keeps any SSH tunnel the connection was established over alive for the
+/// duration of the connection.
+#[derive(Debug)]
+pub struct MySqlConn {
+    conn: Conn,
+    _tunnel: Option<SshTunnelHandle>,
+}
+
+impl MySqlConn {
+    /// Converts this connection into a binlog stream, as if it were a
+    /// replica of the server.
+    pub async fn get_binlog_stream(
+        self,
+        request: BinlogRequest<'_>,
+    ) -> Result<MySqlBinlogStream, MySqlError> {
+        let stream = self.conn.get_binlog_stream(request).await?;
+        Ok(MySqlBinlogStream {
+            stream,
+            _tunnel: self._tunnel,
+        })
+    }
+}
+
+impl Deref for MySqlConn {
+    type Target = Conn;
+
+    fn deref(&self) -> &Conn {
+        &self.conn
+    }
+}
+
+impl DerefMut for MySqlConn {
+    fn deref_mut(&mut self) -> &mut Conn {
+        &mut self.conn
+    }
+}
+
+/// A binlog stream, keeping alive any SSH tunnel it was established over.
+#[derive(Debug)]
+pub struct MySqlBinlogStream {
+    stream: BinlogStream,
+    _tunnel: Option<SshTunnelHandle>,
+}
+
+impl Deref for MySqlBinlogStream {
+    type Target = BinlogStream;
+
+    fn deref(&self) -> &BinlogStream {
+        &self.stream
+    }
+}
+
+impl DerefMut for MySqlBinlogStream {
+    fn deref_mut(&mut self) -> &mut BinlogStream {
+        &mut self.stream
+    }
+}
@@ -14,10 +14,16 @@ use bytes::BytesMut;
 use csv::ByteRecord;
 use csv::ReaderBuilder;
 
-use mz_repr::{Datum, RelationType, Row, RowArena};
+use mz_repr::{Datum, RelationDesc, RelationType, Row, RowArena};
 
 static END_OF_COPY_MARKER: &[u8] = b"\\.";
 
+/// The 11-byte signature that begins every binary COPY file.
+pub static BINARY_SIGNATURE: &[u8] = b"PGCOPY\n\xFF\r\n\0";
+
+/// The 16-bit field count that marks the end of a binary COPY file.
+const BINARY_TRAILER: i16 = -1;
+
 pub fn encode_copy_row_binary(
     row: Row,
     typ: &RelationType,
@@ -63,16 +69,17 @@ pub fn encode_copy_row_binary(
 }
 
 pub fn encode_copy_row_text(
+    CopyTextFormatParams { null, delimiter }: &CopyTextFormatParams,
     row: Row,
     typ: &RelationType,
     out: &mut Vec<u8>,
 ) -> Result<(), io::Error> {
-    let delim = b'\t';
-    let null = b"\\N";
+    let null = null.as_bytes();
+    let delimiter = delimiter.as_bytes();
     let mut buf = BytesMut::new();
     for (idx, field) in mz_pgrepr::values_from_row(row, typ).into_iter().enumerate() {
         if idx > 0 {
-            out.push(delim);
+            out.extend(delimiter);
         }
         match field {
             None => out.extend(null),
@@ -85,6 +92,10 @@ pub fn encode_copy_row_text(
                         b'\n' => out.extend(b"\\n"),
                         b'\r' => out.extend(b"\\r"),
                         b'\t' => out.extend(b"\\t"),
+                        b if delimiter.contains(b) => {
+                            out.push(b'\\');
+                            out.push(*b);
+                        }
                         _ => out.push(*b),
                     }
                 }
@@ -95,6 +106,122 @@ pub fn encode_copy_row_text(
     Ok(())
 }
 
+pub fn encode_copy_row_csv(
+    params: &CopyCsvFormatParams,
+    row: Row,
+    typ: &RelationType,
+    out: &mut Vec<u8>,
+) -> Result<(), io::Error> {
+    let values = mz_pgrepr::values_from_row(row, typ);
+    let single_column = values.len() == 1;
+    let mut buf = BytesMut::new();
+    for (idx, field) in values.into_iter().enumerate() {
+        if idx > 0 {
+            out.push(params.delimiter);
+        }
+        match field {
+            None => out.extend(params.null.as_bytes()),
+            Some(field) => {
+                buf.clear();
+                field.encode_text(&mut buf);
+                encode_csv_field(params, &buf, single_column, out);
+            }
+        }
+    }
+    out.push(b'\n');
+    Ok(())
+}
+
+/// Writes `value` as a single CSV field, quoting it if it contains any
+/// characters that would otherwise be ambiguous to a reader.
+fn encode_csv_field(
+    CopyCsvFormatParams {
+        delimiter,
+        quote,
+        escape,
+        null,
+        header: _,
+    }: &CopyCsvFormatParams,
+    value: &[u8],
+    single_column: bool,
+    out: &mut Vec<u8>,
+) {
+    // Values that match the NULL string must be quoted so that they are not
+    // read back as NULL, as must a lone end of copy marker.
+    let needs_quote = value == null.as_bytes()
+        || (single_column && value == END_OF_COPY_MARKER)
+        || value
+            .iter()
+            .any(|b| *b == *delimiter || *b == *quote || *b == b'\n' || *b == b'\r');
+    if !needs_quote {
+        out.extend(value);
+        return;
+    }
+    out.push(*quote);
+    for b in value {
+        if *b == *quote || *b == *escape {
+            out.push(*escape);
+        }
+        out.push(*b);
+    }
+    out.push(*quote);
+}
+
+/// Encodes the data that precedes the first row of a COPY TO operation, if
+/// any.
+pub fn encode_copy_format_header(
+    params: &CopyFormatParams,
+    desc: &RelationDesc,
+    out: &mut Vec<u8>,
+) -> Result<(), io::Error> {
+    match params {
+        CopyFormatParams::Text(_) => Ok(()),
+        CopyFormatParams::Csv(params) => {
+            if params.header {
+                let single_column = desc.arity() == 1;
+                for (idx, name) in desc.iter_names().enumerate() {
+                    if idx > 0 {
+                        out.push(params.delimiter);
+                    }
+                    encode_csv_field(params, name.as_str().as_bytes(), single_column, out);
+                }
+                out.push(b'\n');
+            }
+            Ok(())
+        }
+        CopyFormatParams::Binary => {
+            // 11-byte signature.
+            out.extend(BINARY_SIGNATURE);
+            // 32-bit flags field.
+            out.extend([0, 0, 0, 0]);
+            // 32-bit header extension length field.
+            out.extend([0, 0, 0, 0]);
+            Ok(())
+        }
+    }
+}
+
+/// Encodes `row` according to `params`.
+pub fn encode_copy_format(
+    params: &CopyFormatParams,
+    row: Row,
+    typ: &RelationType,
+    out: &mut Vec<u8>,
+) -> Result<(), io::Error> {
+    match params {
+        CopyFormatParams::Text(params) => encode_copy_row_text(params, row, typ, out),
+        CopyFormatParams::Csv(params) => encode_copy_row_csv(params, row, typ, out),
+        CopyFormatParams::Binary => encode_copy_row_binary(row, typ, out),
+    }
+}
+
+/// Encodes the data that follows the last row of a COPY TO operation, if any.
+pub fn encode_copy_format_trailer(params: &CopyFormatParams, out: &mut Vec<u8>) {
+    if let CopyFormatParams::Binary = params {
+        out.extend(BINARY_TRAILER.to_be_bytes());
+    }
+}
+
 pub struct CopyTextFormatParser<'a> {
     data: &'a [u8],
     position: usize,
@@ -370,10 +497,22 @@ impl<'a> RawIterator<'a> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum CopyFormatParams<'a> {
     Text(CopyTextFormatParams<'a>),
     Csv(CopyCsvFormatParams<'a>),
+    Binary,
+}
+
+impl CopyFormatParams<'_> {
+    /// Returns the pgwire format in which the client must send COPY data for
+    /// these parameters.
+    pub fn wire_format(&self) -> mz_pgrepr::Format {
+        match self {
+            CopyFormatParams::Text(_) | CopyFormatParams::Csv(_) => mz_pgrepr::Format::Text,
+            CopyFormatParams::Binary => mz_pgrepr::Format::Binary,
+        }
+    }
 }
 
 pub fn decode_copy_format<'a>(
@@ -384,10 +523,11 @@ pub fn decode_copy_format<'a>(
     match params {
         CopyFormatParams::Text(params) => decode_copy_format_text(data, column_types, params),
         CopyFormatParams::Csv(params) => decode_copy_format_csv(data, column_types, params),
+        CopyFormatParams::Binary => decode_copy_format_binary(data, column_types),
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CopyTextFormatParams<'a> {
     pub null: Cow<'a, str>,
     pub delimiter: Cow<'a, str>,
@@ -429,7 +569,7 @@ pub fn decode_copy_format_text(
     Ok(rows)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CopyCsvFormatParams<'a> {
     pub delimiter: u8,
     pub quote: u8,
@@ -511,8 +651,147 @@ pub fn decode_copy_format_csv(
     Ok(rows)
 }
 
+/// A cursor over the bytes of a binary COPY file.
+struct CopyBinaryFormatParser<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> CopyBinaryFormatParser<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn is_eof(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn consume_bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.position.checked_add(n)?;
+        let bytes = self.data.get(self.position..end)?;
+        self.position = end;
+        Some(bytes)
+    }
+
+    fn consume_i16(&mut self) -> Option<i16> {
+        let bytes = self.consume_bytes(2)?;
+        Some(i16::from_be_bytes(
+            bytes.try_into().expect("known to be 2 bytes"),
+        ))
+    }
+
+    fn consume_i32(&mut self) -> Option<i32> {
+        let bytes = self.consume_bytes(4)?;
+        Some(i32::from_be_bytes(
+            bytes.try_into().expect("known to be 4 bytes"),
+        ))
+    }
+
+    /// Consumes the file header, validating the signature and flags and
+    /// skipping over any header extension area.
+    fn expect_header(&mut self) -> Result<(), io::Error> {
+        if self.consume_bytes(BINARY_SIGNATURE.len()) != Some(BINARY_SIGNATURE) {
+            return Err(invalid_data("COPY file signature not recognized"));
+        }
+        let flags = self
+            .consume_i32()
+            .ok_or_else(|| invalid_data("invalid COPY file header (missing flags)"))?;
+        // Bit 16 indicates that OIDs are included in the data, which we
+        // never support.
+        if flags & (1 << 16) != 0 {
+            return Err(invalid_data("invalid COPY file header (WITH OIDS)"));
+        }
+        // Bits 0-15 are reserved for flags that readers must understand;
+        // bits 17-31 may be ignored.
+        if flags & 0xFFFF != 0 {
+            return Err(invalid_data(
+                "unrecognized critical flags in COPY file header",
+            ));
+        }
+        let extension_len = self
+            .consume_i32()
+            .ok_or_else(|| invalid_data("invalid COPY file header (missing length)"))?;
+        let extension_len = usize::try_from(extension_len)
+            .map_err(|_| invalid_data("invalid COPY file header (missing length)"))?;
+        if self.consume_bytes(extension_len).is_none() {
+            return Err(invalid_data("invalid COPY file header (wrong length)"));
+        }
+        Ok(())
+    }
+
+    /// Consumes the raw bytes of the next field, returning `None` if the
+    /// field is NULL.
+    fn consume_raw_value(&mut self) -> Result<Option<&'a [u8]>, io::Error> {
+        let len = self
+            .consume_i32()
+            .ok_or_else(|| invalid_data("unexpected EOF in COPY data"))?;
+        if len == -1 {
+            return Ok(None);
+        }
+        let len = usize::try_from(len).map_err(|_| invalid_data("invalid field size"))?;
+        self.consume_bytes(len)
+            .map(Some)
+            .ok_or_else(|| invalid_data("unexpected EOF in COPY data"))
+    }
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Decodes a complete PostgreSQL binary COPY file, including its header and
+/// trailer, into rows.
+pub fn decode_copy_format_binary(
+    data: &[u8],
+    column_types: &[mz_pgrepr::Type],
+) -> Result<Vec<Row>, io::Error> {
+    let mut rows = Vec::new();
+
+    let mut parser = CopyBinaryFormatParser::new(data);
+    parser.expect_header()?;
+    loop {
+        let field_count = parser
+            .consume_i16()
+            .ok_or_else(|| invalid_data("unexpected EOF in COPY data"))?;
+        if field_count == BINARY_TRAILER {
+            break;
+        }
+        if usize::try_from(field_count).ok() != Some(column_types.len()) {
+            let msg = format!(
+                "row field count is {}, expected {}",
+                field_count,
+                column_types.len()
+            );
+            return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+        }
+
+        let mut row = Vec::new();
+        let buf = RowArena::new();
+        for typ in column_types {
+            match parser.consume_raw_value()? {
+                Some(raw_value) => match mz_pgrepr::Value::decode_binary(typ, raw_value) {
+                    Ok(value) => row.push(value.into_datum(&buf, typ)),
+                    Err(err) => {
+                        let msg = format!("unable to decode column: {}", err);
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, msg));
+                    }
+                },
+                None => row.push(Datum::Null),
+            }
+        }
+        rows.push(Row::pack(row));
+    }
+    if !parser.is_eof() {
+        return Err(invalid_data("received copy data after EOF marker"));
+    }
+
+    Ok(rows)
+}
+
 #[cfg(test)]
 mod tests {
+    use mz_repr::ScalarType;
+
     use super::*;
 
     #[test]
@@ -676,4 +955,235 @@ mod tests {
             assert!(parser.is_eof());
         }
     }
+
+    /// Wraps binary-encoded tuples in the header and trailer of a binary COPY
+    /// file.
+    fn binary_copy_file(tuples: &[u8]) -> Vec<u8> {
+        let mut data = BINARY_SIGNATURE.to_vec();
+        data.extend([0, 0, 0, 0]);
+        data.extend([0, 0, 0, 0]);
+        data.extend(tuples);
+        data.extend(BINARY_TRAILER.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_copy_format_binary_round_trip() {
+        for scalar_type in ScalarType::enumerate() {
+            let typ = RelationType::new(vec![scalar_type.clone().nullable(true)]);
+            let column_types = [mz_pgrepr::Type::from(scalar_type)];
+
+            let mut expected = scalar_type
+                .interesting_datums()
+                .map(|datum| Row::pack_slice(&[datum]))
+                .collect::<Vec<_>>();
+            expected.push(Row::pack_slice(&[Datum::Null]));
+
+            let mut tuples = Vec::new();
+            for row in &expected {
+                encode_copy_row_binary(row.clone(), &typ, &mut tuples)
+                    .unwrap_or_else(|e| panic!("encoding {:?} failed: {}", scalar_type, e));
+            }
+
+            let rows = decode_copy_format_binary(&binary_copy_file(&tuples), &column_types)
+                .unwrap_or_else(|e| panic!("decoding {:?} failed: {}", scalar_type, e));
+            assert_eq!(rows.len(), expected.len(), "type: {:?}", scalar_type);
+            for (row, expected) in rows.iter().zip(&expected) {
+                assert_eq!(
+                    row.unpack_first(),
+                    expected.unpack_first(),
+                    "type: {:?}",
+                    scalar_type
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_copy_format_binary_multiple_columns() {
+        let typ = RelationType::new(vec![
+            ScalarType::Int32.nullable(true),
+            ScalarType::String.nullable(true),
+        ]);
+        let column_types = typ
+            .column_types
+            .iter()
+            .map(|c| mz_pgrepr::Type::from(&c.scalar_type))
+            .collect::<Vec<_>>();
+        let expected = vec![
+            Row::pack_slice(&[Datum::Int32(1), Datum::String("one")]),
+            Row::pack_slice(&[Datum::Null, Datum::String("\\t\n")]),
+            Row::pack_slice(&[Datum::Int32(3), Datum::Null]),
+        ];
+        let mut tuples = Vec::new();
+        for row in &expected {
+            encode_copy_row_binary(row.clone(), &typ, &mut tuples).expect("encoding succeeds");
+        }
+        let rows = decode_copy_format_binary(&binary_copy_file(&tuples), &column_types)
+            .expect("decoding succeeds");
+        assert_eq!(rows, expected);
+    }
+
+    #[test]
+    fn test_copy_format_binary_errors() {
+        let column_types = [mz_pgrepr::Type::Int4];
+        let mut tuple = Vec::new();
+        encode_copy_row_binary(
+            Row::pack_slice(&[Datum::Int32(42)]),
+            &RelationType::new(vec![ScalarType::Int32.nullable(false)]),
+            &mut tuple,
+        )
+        .expect("encoding succeeds");
+        let mut truncated = binary_copy_file(&tuple);
+        truncated.truncate(truncated.len() - 4);
+
+        struct TestCase {
+            data: Vec<u8>,
+            expect: &'static str,
+        }
+        let tests = vec![
+            TestCase {
+                data: b"1\t2\n".to_vec(),
+                expect: "COPY file signature not recognized",
+            },
+            TestCase {
+                data: BINARY_SIGNATURE.to_vec(),
+                expect: "invalid COPY file header (missing flags)",
+            },
+            TestCase {
+                data: [BINARY_SIGNATURE, &[0, 1, 0, 0]].concat(),
+                expect: "invalid COPY file header (WITH OIDS)",
+            },
+            TestCase {
+                data: [BINARY_SIGNATURE, &[0, 0, 0, 1]].concat(),
+                expect: "unrecognized critical flags in COPY file header",
+            },
+            TestCase {
+                data: [BINARY_SIGNATURE, &[0, 0, 0, 0], &[0, 0, 0, 8]].concat(),
+                expect: "invalid COPY file header (wrong length)",
+            },
+            TestCase {
+                data: truncated,
+                expect: "unexpected EOF in COPY data",
+            },
+            TestCase {
+                data: binary_copy_file(&[0, 2]),
+                expect: "row field count is 2, expected 1",
+            },
+            TestCase {
+                data: [binary_copy_file(&tuple), b"extra".to_vec()].concat(),
+                expect: "received copy data after EOF marker",
+            },
+        ];
+
+        for test in tests {
+            let err = decode_copy_format_binary(&test.data, &column_types)
+                .expect_err("decoding should fail");
+            assert_eq!(err.to_string(), test.expect);
+        }
+    }
+
+    #[test]
+    fn test_copy_format_csv_round_trip() {
+        let typ = RelationType::new(vec![
+            ScalarType::Int32.nullable(true),
+            ScalarType::String.nullable(true),
+        ]);
+        let column_types = typ
+            .column_types
+            .iter()
+            .map(|c| mz_pgrepr::Type::from(&c.scalar_type))
+            .collect::<Vec<_>>();
+        let rows = vec![
+            Row::pack_slice(&[Datum::Int32(1), Datum::String("a,b")]),
+            Row::pack_slice(&[Datum::Int32(2), Datum::String("say \"hi\"")]),
+            Row::pack_slice(&[Datum::Int32(4), Datum::Null]),
+            Row::pack_slice(&[Datum::Null, Datum::String("multi\nline")]),
+            Row::pack_slice(&[Datum::Int32(6), Datum::String("back\\slash")]),
+        ];
+
+        struct TestCase {
+            quote: u8,
+            escape: u8,
+            null: &'static str,
+            expect: &'static str,
+        }
+        let tests = vec![
+            TestCase {
+                quote: b'"',
+                escape: b'"',
+                null: "",
+                expect: "1,\"a,b\"\n\
+                         2,\"say \"\"hi\"\"\"\n\
+                         4,\n\
+                         ,\"multi\nline\"\n\
+                         6,back\\slash\n",
+            },
+            TestCase {
+                quote: b'\'',
+                escape: b'\\',
+                null: "NULL",
+                expect: "1,'a,b'\n\
+                         2,say \"hi\"\n\
+                         4,NULL\n\
+                         NULL,'multi\nline'\n\
+                         6,back\\slash\n",
+            },
+        ];
+
+        for test in tests {
+            let params = CopyCsvFormatParams {
+                delimiter: b',',
+                quote: test.quote,
+                escape: test.escape,
+                header: false,
+                null: Cow::from(test.null),
+            };
+            let mut out = Vec::new();
+            for row in &rows {
+                encode_copy_row_csv(&params, row.clone(), &typ, &mut out)
+                    .expect("encoding succeeds");
+            }
+            assert_eq!(std::str::from_utf8(&out).unwrap(), test.expect);
+
+            let decoded =
+                decode_copy_format_csv(&out, &column_types, params).expect("decoding succeeds");
+            assert_eq!(decoded, rows);
+        }
+    }
+
+    #[test]
+    fn test_copy_format_csv_quotes_null_string() {
+        let typ = RelationType::new(vec![ScalarType::String.nullable(true)]);
+        let params = CopyCsvFormatParams {
+            delimiter: b',',
+            quote: b'"',
+            escape: b'"',
+            header: false,
+            null: Cow::from(""),
+        };
+        let mut out = Vec::new();
+        for datum in [Datum::String(""), Datum::Null, Datum::String("\\.")] {
+            encode_copy_row_csv(&params, Row::pack_slice(&[datum]), &typ, &mut out)
+                .expect("encoding succeeds");
+        }
+        assert_eq!(out, b"\"\"\n\n\"\\.\"\n");
+    }
+
+    #[test]
+    fn test_copy_format_csv_header() {
+        let desc = RelationDesc::empty()
+            .with_column("a", ScalarType::Int32.nullable(true))
+            .with_column("b,c", ScalarType::String.nullable(true));
+        let params = CopyFormatParams::Csv(CopyCsvFormatParams {
+            delimiter: b',',
+            quote: b'"',
+            escape: b'"',
+            header: true,
+            null: Cow::from(""),
+        });
+        let mut out = Vec::new();
+        encode_copy_format_header(&params, &desc, &mut out).expect("encoding succeeds");
+        assert_eq!(out, b"a,\"b,c\"\n");
+    }
 }
@@ -27,12 +27,16 @@ pub const TYPE_BYTEA_ARRAY_OID: u32 = 1001;
 pub const TYPE_BYTEA_OID: u32 = 17;
 pub const TYPE_CHAR_ARRAY_OID: u32 = 1002;
 pub const TYPE_CHAR_OID: u32 = 18;
+pub const TYPE_CIDR_ARRAY_OID: u32 = 651;
+pub const TYPE_CIDR_OID: u32 = 650;
 pub const TYPE_DATE_ARRAY_OID: u32 = 1182;
 pub const TYPE_DATE_OID: u32 = 1082;
 pub const TYPE_FLOAT4_ARRAY_OID: u32 = 1021;
 pub const TYPE_FLOAT4_OID: u32 = 700;
 pub const TYPE_FLOAT8_ARRAY_OID: u32 = 1022;
 pub const TYPE_FLOAT8_OID: u32 = 701;
+pub const TYPE_INET_ARRAY_OID: u32 = 1041;
+pub const TYPE_INET_OID: u32 = 869;
 pub const TYPE_INT2_ARRAY_OID: u32 = 1005;
 pub const TYPE_INT2_OID: u32 = 21;
 pub const TYPE_INT2_VECTOR_ARRAY_OID: u32 = 1006;
@@ -74,6 +78,7 @@ pub const TYPE_INT4RANGE_OID: u32 = 3904;
 pub const TYPE_INT4RANGE_ARRAY_OID: u32 = 3905;
 pub const TYPE_ANYRANGE_OID: u32 = 3831;
 pub const TYPE_ANYCOMPATIBLERANGE_OID: u32 = 5080;
+pub const TYPE_ANYENUM_OID: u32 = 3500;
 pub const TYPE_INT8RANGE_OID: u32 = 3926;
 pub const TYPE_INT8RANGE_ARRAY_OID: u32 = 3927;
 pub const TYPE_DATERANGE_OID: u32 = 3912;
@@ -289,3 +294,29 @@ pub const FUNC_MZ_ACL_ITEM_GRANTOR_OID: u32 = 16_571;
 pub const FUNC_MZ_ACL_ITEM_GRANTEE_OID: u32 = 16_572;
 pub const FUNC_MZ_ACL_ITEM_PRIVILEGES_OID: u32 = 16_573;
 pub const FUNC_IS_RBAC_ENABLED_OID: u32 = 16_574;
+pub const FUNC_APPROX_COUNT_DISTINCT_OID: u32 = 16_575;
+pub const FUNC_HLL_SKETCH_OID: u32 = 16_576;
+pub const FUNC_HLL_MERGE_OID: u32 = 16_577;
+pub const FUNC_HLL_ESTIMATE_OID: u32 = 16_578;
+pub const FUNC_REGEXP_COUNT_OID: u32 = 16_579;
+pub const FUNC_REGEXP_COUNT_START_OID: u32 = 16_580;
+pub const FUNC_REGEXP_COUNT_START_FLAGS_OID: u32 = 16_581;
+pub const FUNC_JSONB_PATH_EXISTS_OID: u32 = 16_582;
+pub const FUNC_JSONB_PATH_EXISTS_VARS_OID: u32 = 16_583;
+pub const FUNC_JSONB_PATH_MATCH_OID: u32 = 16_584;
+pub const FUNC_JSONB_PATH_MATCH_VARS_OID: u32 = 16_585;
+pub const FUNC_JSONB_PATH_QUERY_OID: u32 = 16_586;
+pub const FUNC_JSONB_PATH_QUERY_VARS_OID: u32 = 16_587;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_OID: u32 = 16_588;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_VARS_OID: u32 = 16_589;
+pub const FUNC_JSONB_SET_OID: u32 = 16_590;
+pub const FUNC_JSONB_INSERT_OID: u32 = 16_591;
+pub const FUNC_JSONB_SET_LAX_OID: u32 = 16_592;
+pub const FUNC_JSONB_SET_LAX_CREATE_OID: u32 = 16_593;
+pub const FUNC_JSONB_SET_LAX_CREATE_TREATMENT_OID: u32 = 16_594;
+pub const FUNC_JSONB_PATH_EXISTS_VARS_SILENT_OID: u32 = 16_595;
+pub const FUNC_JSONB_PATH_MATCH_VARS_SILENT_OID: u32 = 16_596;
+pub const FUNC_JSONB_PATH_QUERY_VARS_SILENT_OID: u32 = 16_597;
+pub const FUNC_JSONB_PATH_QUERY_FIRST_VARS_SILENT_OID: u32 = 16_598;
+pub const OP_JSONB_PATH_EXISTS_OID: u32 = 16_599;
+pub const OP_JSONB_PATH_MATCH_OID: u32 = 16_600;
@@ -10,3 +10,13 @@
 #![allow(missing_docs)]
 
 pub use mz_pgrepr_consts::oid::*;
+
+/// Returns the OID of the array type of the user-defined enum type with OID
+/// `enum_oid`.
+///
+/// Enum types are allocated two consecutive OIDs when they are created or
+/// loaded: the first for the enum type itself and the second for its array
+/// type.
+pub const fn enum_array_oid(enum_oid: u32) -> u32 {
+    enum_oid + 1
+}
@@ -153,6 +153,17 @@ pub enum Type {
     },
     /// A list of privileges granted to a role.
     MzAclItem,
+    /// An IPv4 or IPv6 host address, and optionally its subnet.
+    Inet,
+    /// An IPv4 or IPv6 network.
+    Cidr,
+    /// A user-defined enum type.
+    Enum {
+        /// The OID of the enum type.
+        oid: u32,
+        /// The labels of the enum, in declaration order.
+        labels: Vec<String>,
+    },
 }
 
 /// An unpacked [`typmod`](Type::typmod) for a [`Type`].
@@ -534,6 +545,8 @@ impl Type {
             postgres_types::Type::TIMESTAMP => Type::Timestamp { precision: None },
             postgres_types::Type::TIMESTAMPTZ => Type::TimestampTz { precision: None },
             postgres_types::Type::UUID => Type::Uuid,
+            postgres_types::Type::INET => Type::Inet,
+            postgres_types::Type::CIDR => Type::Cidr,
             postgres_types::Type::REGCLASS => Type::RegClass,
             postgres_types::Type::REGPROC => Type::RegProc,
             postgres_types::Type::REGTYPE => Type::RegType,
@@ -571,6 +584,8 @@ impl Type {
                 Type::Array(Box::new(Type::TimestampTz { precision: None }))
             }
             postgres_types::Type::UUID_ARRAY => Type::Array(Box::new(Type::Uuid)),
+            postgres_types::Type::INET_ARRAY => Type::Array(Box::new(Type::Inet)),
+            postgres_types::Type::CIDR_ARRAY => Type::Array(Box::new(Type::Cidr)),
             postgres_types::Type::VARCHAR_ARRAY => {
                 Type::Array(Box::new(Type::VarChar { max_length: None }))
             }
@@ -708,6 +723,11 @@ impl Type {
                     _ => unreachable!(),
                 },
                 Type::MzAclItem => &MZ_ACL_ITEM_ARRAY,
+                Type::Inet => &postgres_types::Type::INET_ARRAY,
+                Type::Cidr => &postgres_types::Type::CIDR_ARRAY,
+                // Enum array types have no static type; their OID is reported
+                // by `Type::oid`.
+                Type::Enum { .. } => &postgres_types::Type::ANYARRAY,
             },
             Type::Bool => &postgres_types::Type::BOOL,
             Type::Bytea => &postgres_types::Type::BYTEA,
@@ -752,6 +772,9 @@ impl Type {
                 t => unreachable!("{t:?} is not a range element type"),
             },
             Type::MzAclItem => &MZ_ACL_ITEM,
+            Type::Inet => &postgres_types::Type::INET,
+            Type::Cidr => &postgres_types::Type::CIDR,
+            Type::Enum { .. } => &postgres_types::Type::ANYENUM,
         }
     }
 
@@ -807,7 +830,16 @@ impl Type {
     ///
     /// [OID]: https://www.postgresql.org/docs/current/datatype-oid.html
     pub fn oid(&self) -> u32 {
-        self.inner().oid()
+        match self {
+            // Each enum type has its own OID, which is assigned when the type
+            // is created.
+            Type::Enum { oid, .. } => *oid,
+            Type::Array(element) => match &**element {
+                Type::Enum { oid, .. } => oid::enum_array_oid(*oid),
+                _ => self.inner().oid(),
+            },
+            _ => self.inner().oid(),
+        }
     }
 
     /// Returns the constraint on the type, if any.
@@ -872,7 +904,10 @@ impl Type {
             | Type::MzTimestamp
             | Type::VarChar { max_length: None }
             | Type::Range { .. }
-            | Type::MzAclItem => None,
+            | Type::MzAclItem
+            | Type::Inet
+            | Type::Cidr
+            | Type::Enum { .. } => None,
         }
     }
 
@@ -920,6 +955,9 @@ impl Type {
                 .expect("must fit"),
             Type::Range { .. } => -1,
             Type::MzAclItem => MzAclItem::binary_size().try_into().expect("must fit"),
+            Type::Inet => -1,
+            Type::Cidr => -1,
+            Type::Enum { .. } => 4,
         }
     }
 
@@ -1042,6 +1080,9 @@ impl TryFrom<&Type> for ScalarType {
                 element_type: Box::new(TryFrom::try_from(&**element_type)?),
             }),
             Type::MzAclItem => Ok(ScalarType::MzAclItem),
+            Type::Inet => Ok(ScalarType::Inet),
+            Type::Cidr => Ok(ScalarType::Cidr),
+            Type::Enum { .. } => Err(TypeConversionError::UnsupportedType(typ.clone())),
         }
     }
 }
@@ -1194,6 +1235,14 @@ impl From<&ScalarType> for Type {
                 element_type: Box::new(From::from(&**element_type)),
             },
             ScalarType::MzAclItem => Type::MzAclItem,
+            ScalarType::Inet => Type::Inet,
+            ScalarType::Cidr => Type::Cidr,
+            ScalarType::Enum {
+                labels, custom_oid, ..
+            } => Type::Enum {
+                oid: *custom_oid,
+                labels: labels.clone(),
+            },
         }
     }
 }
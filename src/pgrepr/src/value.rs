@@ -17,7 +17,7 @@ use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use postgres_types::{FromSql, IsNull, ToSql, Type as PgType};
 use uuid::Uuid;
 
-use mz_ore::cast::ReinterpretCast;
+use mz_ore::cast::{CastFrom, ReinterpretCast};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
 use mz_repr::adt::date::Date;
@@ -29,8 +29,9 @@ use mz_repr::strconv::{self, Nestable};
 use mz_repr::{Datum, RelationType, Row, RowArena, ScalarType};
 
 use crate::types::{UINT2, UINT4, UINT8};
-use crate::{Format, Interval, Jsonb, Numeric, Type, UInt2, UInt4, UInt8};
+use crate::{Format, Inet, Interval, Jsonb, Numeric, Type, UInt2, UInt4, UInt8};
 
+pub mod inet;
 pub mod interval;
 pub mod jsonb;
 pub mod numeric;
@@ -110,6 +111,12 @@ pub enum Value {
     Range(Range<Box<Value>>),
     /// A list of privileges granted to a role.
     MzAclItem(MzAclItem),
+    /// An IPv4 or IPv6 host address, and optionally its subnet.
+    Inet(Inet),
+    /// An IPv4 or IPv6 network.
+    Cidr(Inet),
+    /// A label of a user-defined enum type.
+    Enum(String),
 }
 
 impl Value {
@@ -138,6 +145,11 @@ impl Value {
             (Datum::Numeric(d), ScalarType::Numeric { .. }) => Some(Value::Numeric(Numeric(d))),
             (Datum::MzTimestamp(t), ScalarType::MzTimestamp) => Some(Value::MzTimestamp(t)),
             (Datum::MzAclItem(mai), ScalarType::MzAclItem) => Some(Value::MzAclItem(mai)),
+            (Datum::Inet(inet), ScalarType::Inet) => Some(Value::Inet(Inet(inet))),
+            (Datum::Inet(cidr), ScalarType::Cidr) => Some(Value::Cidr(Inet(cidr))),
+            (Datum::UInt32(i), ScalarType::Enum { labels, .. }) => {
+                Some(Value::Enum(labels[usize::cast_from(i)].clone()))
+            }
             (Datum::Date(d), ScalarType::Date) => Some(Value::Date(d)),
             (Datum::Time(t), ScalarType::Time) => Some(Value::Time(t)),
             (Datum::Timestamp(ts), ScalarType::Timestamp) => Some(Value::Timestamp(ts)),
@@ -301,6 +313,19 @@ impl Value {
                 buf.make_datum(|packer| packer.push_range(range).unwrap())
             }
             Value::MzAclItem(mz_acl_item) => Datum::MzAclItem(mz_acl_item),
+            Value::Inet(inet) | Value::Cidr(inet) => Datum::Inet(inet.0),
+            Value::Enum(label) => {
+                let labels = match typ {
+                    Type::Enum { labels, .. } => labels,
+                    _ => panic!("Value::Enum should have type Type::Enum. Found {:?}", typ),
+                };
+                // Value::decode only produces labels that belong to the type.
+                let i = labels
+                    .iter()
+                    .position(|l| *l == label)
+                    .expect("enum label must belong to its type");
+                Datum::UInt32(u32::try_from(i).expect("enum label count fits in u32"))
+            }
         }
     }
 
@@ -382,6 +407,9 @@ impl Value {
             })
             .expect("provided closure never fails"),
             Value::MzAclItem(mz_acl_item) => strconv::format_mz_acl_item(buf, *mz_acl_item),
+            Value::Inet(inet) => strconv::format_inet(buf, inet.0),
+            Value::Cidr(cidr) => strconv::format_cidr(buf, cidr.0),
+            Value::Enum(label) => strconv::format_string(buf, label),
         }
     }
 
@@ -513,6 +541,10 @@ impl Value {
                 buf.extend_from_slice(&mz_acl_item.encode_binary());
                 Ok(postgres_types::IsNull::No)
             }
+            // PostgreSQL uses the label's text as the binary encoding of enums.
+            Value::Enum(label) => label.to_sql(&PgType::TEXT, buf),
+            Value::Inet(inet) => inet.to_sql(&PgType::INET, buf),
+            Value::Cidr(cidr) => cidr.to_sql(&PgType::CIDR, buf),
         }
         .expect("encode_binary should never trigger a to_sql failure");
         if let IsNull::Yes = is_null {
@@ -609,6 +641,9 @@ impl Value {
                 Value::decode_text(element_type, elem_text.as_bytes()).map(Box::new)
             })?),
             Type::MzAclItem => Value::MzAclItem(strconv::parse_mz_acl_item(s)?),
+            Type::Inet => Value::Inet(Inet(strconv::parse_inet(s)?)),
+            Type::Cidr => Value::Cidr(Inet(strconv::parse_cidr(s)?)),
+            Type::Enum { labels, .. } => Value::Enum(decode_enum_label(labels, s)?),
         })
     }
 
@@ -671,10 +706,24 @@ impl Value {
                 let mz_acl_item = MzAclItem::decode_binary(raw)?;
                 Ok(Value::MzAclItem(mz_acl_item))
             }
+            Type::Inet => Inet::from_sql(ty.inner(), raw).map(Value::Inet),
+            Type::Cidr => Inet::from_sql(ty.inner(), raw).map(Value::Cidr),
+            Type::Enum { labels, .. } => {
+                let s = String::from_sql(&PgType::TEXT, raw)?;
+                Ok(Value::Enum(decode_enum_label(labels, &s)?))
+            }
         }
     }
 }
 
+fn decode_enum_label(labels: &[String], s: &str) -> Result<String, Box<dyn Error + Sync + Send>> {
+    if labels.iter().any(|l| l == s) {
+        Ok(s.to_owned())
+    } else {
+        Err(format!("invalid input value for enum: \"{}\"", s).into())
+    }
+}
+
 fn encode_element(buf: &mut BytesMut, elem: Option<&Value>, ty: &Type) -> Result<(), io::Error> {
     match elem {
         None => buf.put_i32(-1),
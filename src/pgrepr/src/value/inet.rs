@@ -0,0 +1,99 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::error::Error;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use byteorder::ReadBytesExt;
+use bytes::{BufMut, BytesMut};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use mz_repr::adt::inet::Inet as ReprInet;
+
+/// PostgreSQL's on-the-wire identifiers for the address families, which are
+/// `AF_INET` and `AF_INET + 1` on the server.
+const PGSQL_AF_INET: u8 = 2;
+const PGSQL_AF_INET6: u8 = 3;
+
+/// A wrapper for the `repr` crate's [`Inet`](mz_repr::adt::inet::Inet) type
+/// that can be serialized to and deserialized from the PostgreSQL binary
+/// format of the `inet` and `cidr` types.
+#[derive(Debug, Clone)]
+pub struct Inet(pub ReprInet);
+
+impl fmt::Display for Inet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ToSql for Inet {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + 'static + Send + Sync>> {
+        // Postgres represents network addresses as the address family, the
+        // prefix length, a flag that is set for `cidr` values, the number of
+        // bytes in the address and finally the address itself.
+        //
+        // Postgres implementation: https://github.com/postgres/postgres/blob/REL_15_0/src/backend/utils/adt/network.c#L258
+        match self.0.addr() {
+            IpAddr::V4(addr) => {
+                out.put_u8(PGSQL_AF_INET);
+                out.put_u8(self.0.prefix_len());
+                out.put_u8(u8::from(*ty == Type::CIDR));
+                out.put_u8(4);
+                out.put_slice(&addr.octets());
+            }
+            IpAddr::V6(addr) => {
+                out.put_u8(PGSQL_AF_INET6);
+                out.put_u8(self.0.prefix_len());
+                out.put_u8(u8::from(*ty == Type::CIDR));
+                out.put_u8(16);
+                out.put_slice(&addr.octets());
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INET | Type::CIDR)
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Inet {
+    fn from_sql(ty: &Type, mut raw: &'a [u8]) -> Result<Inet, Box<dyn Error + Sync + Send>> {
+        let family = raw.read_u8()?;
+        let prefix_len = raw.read_u8()?;
+        // The cidr flag is ignored, like Postgres does.
+        let _is_cidr = raw.read_u8()?;
+        let len = raw.read_u8()?;
+        let addr = match (family, len, raw) {
+            (PGSQL_AF_INET, 4, &[a, b, c, d]) => IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+            (PGSQL_AF_INET6, 16, raw) if raw.len() == 16 => {
+                let octets: [u8; 16] = raw.try_into()?;
+                IpAddr::V6(Ipv6Addr::from(octets))
+            }
+            _ => return Err(format!("invalid external \"{}\" value", ty.name()).into()),
+        };
+        let inet = ReprInet::new(addr, prefix_len)?;
+        if *ty == Type::CIDR && !inet.is_network() {
+            return Err("invalid external \"cidr\" value".into());
+        }
+        Ok(Inet(inet))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::INET | Type::CIDR)
+    }
+}
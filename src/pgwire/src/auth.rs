@@ -0,0 +1,116 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Pluggable verification of the credentials that pgwire clients present.
+
+use std::fmt;
+
+use async_trait::async_trait;
+use futures::future::{BoxFuture, FutureExt};
+use tokio::sync::mpsc::UnboundedSender;
+
+use mz_frontegg_auth::{Authentication as FronteggAuthentication, Claims};
+use mz_sql::session::password::ScramSecret;
+use mz_sql::session::user::ExternalUserMetadata;
+
+/// The message exchange by which a client proves knowledge of its password.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordExchange {
+    /// The client sends its password in clear.
+    ///
+    /// The password is verified with [`Authenticator::verify_password`].
+    Cleartext,
+    /// The client and server run a SCRAM-SHA-256 SASL exchange.
+    ///
+    /// The client is verified against the secret returned by
+    /// [`Authenticator::scram_secret`].
+    Scram,
+}
+
+/// Verifies the credentials that pgwire clients present at startup.
+#[async_trait]
+pub trait Authenticator: fmt::Debug + Send + Sync {
+    /// Returns the message exchange that clients must complete.
+    fn password_exchange(&self) -> PasswordExchange;
+
+    /// Verifies the cleartext `password` of the user named `user`.
+    ///
+    /// Updates to the user's metadata are sent to `external_metadata_tx`. On
+    /// success, returns a future that resolves when the credential expires, at
+    /// which point the connection is terminated.
+    ///
+    /// Called only if [`Authenticator::password_exchange`] returns
+    /// [`PasswordExchange::Cleartext`].
+    async fn verify_password(
+        &self,
+        user: &str,
+        password: &str,
+        external_metadata_tx: UnboundedSender<ExternalUserMetadata>,
+    ) -> Result<BoxFuture<'static, ()>, anyhow::Error> {
+        let _ = (user, password, external_metadata_tx);
+        anyhow::bail!("cleartext passwords are not supported")
+    }
+
+    /// Returns the SCRAM secret of the user named `user`, or `None` if no
+    /// such user exists or the user has no password.
+    ///
+    /// Called only if [`Authenticator::password_exchange`] returns
+    /// [`PasswordExchange::Scram`].
+    async fn scram_secret(&self, user: &str) -> Option<ScramSecret> {
+        let _ = user;
+        None
+    }
+}
+
+/// Verifies Frontegg API tokens, which clients present as cleartext
+/// passwords.
+#[async_trait]
+impl Authenticator for FronteggAuthentication {
+    fn password_exchange(&self) -> PasswordExchange {
+        PasswordExchange::Cleartext
+    }
+
+    async fn verify_password(
+        &self,
+        user: &str,
+        password: &str,
+        external_metadata_tx: UnboundedSender<ExternalUserMetadata>,
+    ) -> Result<BoxFuture<'static, ()>, anyhow::Error> {
+        let admin_role = self.admin_role().to_string();
+        let token = self.exchange_password_for_token(password).await?;
+        let is_expired =
+            self.continuously_validate_access_token(token, user.to_string(), move |claims| {
+                let external_metadata = convert_claims_to_external_metadata(claims, &admin_role);
+                // Ignore error if client has hung up.
+                let _ = external_metadata_tx.send(external_metadata);
+            })?;
+        Ok(is_expired.boxed())
+    }
+}
+
+/// Verifies users against the SCRAM secrets of the passwords stored for their
+/// roles in the catalog.
+#[async_trait]
+impl Authenticator for mz_adapter::Client {
+    fn password_exchange(&self) -> PasswordExchange {
+        PasswordExchange::Scram
+    }
+
+    async fn scram_secret(&self, user: &str) -> Option<ScramSecret> {
+        self.get_role_password(user).await
+    }
+}
+
+fn convert_claims_to_external_metadata(claims: Claims, admin_role: &str) -> ExternalUserMetadata {
+    ExternalUserMetadata {
+        user_id: claims.best_user_id(),
+        group_id: claims.tenant_id,
+        admin: claims.admin(admin_role),
+    }
+}
@@ -238,6 +238,9 @@ impl Encoder<BackendMessage> for Codec {
         let byte = match &msg {
             BackendMessage::AuthenticationOk => b'R',
             BackendMessage::AuthenticationCleartextPassword => b'R',
+            BackendMessage::AuthenticationSasl { .. } => b'R',
+            BackendMessage::AuthenticationSaslContinue(_) => b'R',
+            BackendMessage::AuthenticationSaslFinal(_) => b'R',
             BackendMessage::RowDescription(_) => b'T',
             BackendMessage::DataRow(_) => b'D',
             BackendMessage::CommandComplete { .. } => b'C',
@@ -295,6 +298,21 @@ impl Encoder<BackendMessage> for Codec {
             BackendMessage::AuthenticationCleartextPassword => {
                 dst.put_u32(3);
             }
+            BackendMessage::AuthenticationSasl { mechanisms } => {
+                dst.put_u32(10);
+                for mechanism in mechanisms {
+                    dst.put_string(mechanism);
+                }
+                dst.put_u8(b'\0');
+            }
+            BackendMessage::AuthenticationSaslContinue(data) => {
+                dst.put_u32(11);
+                dst.put_slice(&data);
+            }
+            BackendMessage::AuthenticationSaslFinal(data) => {
+                dst.put_u32(12);
+                dst.put_slice(&data);
+            }
             BackendMessage::RowDescription(fields) => {
                 dst.put_length_i16(fields.len())?;
                 for f in &fields {
@@ -551,7 +569,7 @@ impl Decoder for Codec {
                         b'X' => decode_terminate(buf)?,
 
                         // Authentication.
-                        b'p' => decode_password(buf)?,
+                        b'p' => decode_raw_authentication(buf, frame_len)?,
 
                         // Copy from flow.
                         b'f' => decode_copy_fail(buf)?,
@@ -580,12 +598,51 @@ fn decode_terminate(mut _buf: Cursor) -> Result<FrontendMessage, io::Error> {
     Ok(FrontendMessage::Terminate)
 }
 
-fn decode_password(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
+fn decode_raw_authentication(
+    mut buf: Cursor,
+    frame_len: usize,
+) -> Result<FrontendMessage, io::Error> {
+    let mut data = Vec::with_capacity(frame_len);
+    for _ in 0..frame_len {
+        data.push(buf.read_byte()?);
+    }
+    Ok(FrontendMessage::RawAuthentication(data))
+}
+
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] as a
+/// password message.
+pub fn decode_password(data: &[u8]) -> Result<FrontendMessage, io::Error> {
+    let mut buf = Cursor::new(data);
     Ok(FrontendMessage::Password {
         password: buf.read_cstr()?.to_owned(),
     })
 }
 
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] as a SASL
+/// initial response message.
+pub fn decode_sasl_initial_response(data: &[u8]) -> Result<FrontendMessage, io::Error> {
+    let mut buf = Cursor::new(data);
+    let mechanism = buf.read_cstr()?.to_owned();
+    let len = buf.read_i32()?;
+    // A length of -1 indicates that there is no initial response.
+    let initial_response = if len == -1 {
+        vec![]
+    } else {
+        let len = usize::try_from(len).map_err(|_| input_err("invalid SASL response length"))?;
+        buf.read_bytes(len)?.to_vec()
+    };
+    Ok(FrontendMessage::SaslInitialResponse {
+        mechanism,
+        initial_response,
+    })
+}
+
+/// Decodes the contents of a [`FrontendMessage::RawAuthentication`] as a SASL
+/// response message.
+pub fn decode_sasl_response(data: &[u8]) -> Result<FrontendMessage, io::Error> {
+    Ok(FrontendMessage::SaslResponse(data.to_vec()))
+}
+
 fn decode_query(mut buf: Cursor) -> Result<FrontendMessage, io::Error> {
     Ok(FrontendMessage::Query {
         sql: buf.read_cstr()?.to_string(),
@@ -765,6 +822,16 @@ impl<'a> Cursor<'a> {
         }
     }
 
+    /// Returns the next `n` bytes, advancing the cursor by `n` bytes.
+    fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], io::Error> {
+        if self.buf.len() < n {
+            return Err(input_err("not enough buffer for the requested bytes"));
+        }
+        let val = &self.buf[..n];
+        self.advance(n);
+        Ok(val)
+    }
+
     /// Reads the next 16-bit signed integer, advancing the cursor by two
     /// bytes.
     fn read_i16(&mut self) -> Result<i16, io::Error> {
@@ -88,12 +88,15 @@
 
 #![warn(clippy::as_conversions)]
 
+mod auth;
 mod codec;
 mod message;
 mod metrics;
 mod protocol;
+mod scram;
 mod server;
 
+pub use auth::{Authenticator, PasswordExchange};
 pub use codec::MAX_REQUEST_SIZE;
 pub use message::Severity;
 pub use metrics::MetricsConfig;
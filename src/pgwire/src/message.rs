@@ -186,9 +186,26 @@ pub enum FrontendMessage {
 
     CopyFail(String),
 
+    /// A password, SASL initial response, or SASL response message.
+    ///
+    /// These messages share a type byte, so they can only be told apart by
+    /// the authentication exchange that is in progress. The codec decodes
+    /// them into this variant, and the exchange then decodes the contents
+    /// into one of the typed variants below.
+    RawAuthentication(Vec<u8>),
+
     Password {
         password: String,
     },
+
+    SaslInitialResponse {
+        /// The name of the SASL mechanism that the client selected.
+        mechanism: String,
+        /// The mechanism-specific initial response.
+        initial_response: Vec<u8>,
+    },
+
+    SaslResponse(Vec<u8>),
 }
 
 impl FrontendMessage {
@@ -208,7 +225,10 @@ impl FrontendMessage {
             FrontendMessage::CopyData(_) => "copy_data",
             FrontendMessage::CopyDone => "copy_done",
             FrontendMessage::CopyFail(_) => "copy_fail",
+            FrontendMessage::RawAuthentication(_) => "raw_authentication",
             FrontendMessage::Password { .. } => "password",
+            FrontendMessage::SaslInitialResponse { .. } => "sasl_initial_response",
+            FrontendMessage::SaslResponse(_) => "sasl_response",
         }
     }
 }
@@ -220,6 +240,13 @@ impl FrontendMessage {
 pub enum BackendMessage {
     AuthenticationOk,
     AuthenticationCleartextPassword,
+    AuthenticationSasl {
+        /// The SASL mechanisms that the server supports, in order of
+        /// preference.
+        mechanisms: Vec<&'static str>,
+    },
+    AuthenticationSaslContinue(Vec<u8>),
+    AuthenticationSaslFinal(Vec<u8>),
     CommandComplete {
         tag: String,
     },
@@ -338,6 +365,8 @@ impl ErrorResponse {
             AdapterError::SourceOrSinkSizeRequired { .. } => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::InvalidTableMutationSelection => SqlState::INVALID_TRANSACTION_STATE,
             AdapterError::ConstraintViolation(NotNullViolation(_)) => SqlState::NOT_NULL_VIOLATION,
+            AdapterError::CheckViolation { .. } => SqlState::CHECK_VIOLATION,
+            AdapterError::UniqueViolation { .. } => SqlState::UNIQUE_VIOLATION,
             AdapterError::NoClusterReplicasAvailable(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::OperationProhibitsTransaction(_) => SqlState::ACTIVE_SQL_TRANSACTION,
             AdapterError::OperationRequiresTransaction(_) => SqlState::NO_ACTIVE_SQL_TRANSACTION,
@@ -368,6 +397,7 @@ impl ErrorResponse {
             AdapterError::UnknownPreparedStatement(_) => SqlState::UNDEFINED_PSTATEMENT,
             AdapterError::UnknownLoginRole(_) => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
             AdapterError::UnknownClusterReplica { .. } => SqlState::UNDEFINED_OBJECT,
+            AdapterError::UnknownWebhook { .. } => SqlState::UNDEFINED_OBJECT,
             AdapterError::UnmaterializableFunction(_) => SqlState::FEATURE_NOT_SUPPORTED,
             AdapterError::UnrecognizedConfigurationParam(_) => SqlState::UNDEFINED_OBJECT,
             AdapterError::UnstableDependency { .. } => SqlState::FEATURE_NOT_SUPPORTED,
@@ -385,6 +415,8 @@ impl ErrorResponse {
             }
             AdapterError::ConcurrentRoleDrop(_) => SqlState::UNDEFINED_OBJECT,
             AdapterError::DependentObject(_) => SqlState::DEPENDENT_OBJECTS_STILL_EXIST,
+            AdapterError::ConcurrentTableAlter(_) => SqlState::T_R_SERIALIZATION_FAILURE,
+            AdapterError::AlterTableDependent { .. } => SqlState::DEPENDENT_OBJECTS_STILL_EXIST,
             AdapterError::VarError(e) => match e {
                 VarError::ConstrainedParameter { .. } => SqlState::INVALID_PARAMETER_VALUE,
                 VarError::FixedValueParameter(_) => SqlState::INVALID_PARAMETER_VALUE,
@@ -27,23 +27,25 @@ use mz_adapter::session::{
     EndTransactionAction, InProgressRows, Portal, PortalState, RowBatchStream, TransactionStatus,
 };
 use mz_adapter::{AdapterNotice, ExecuteResponse, PeekResponseUnary, RowsFuture};
-use mz_frontegg_auth::{Authentication as FronteggAuthentication, Claims};
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
 use mz_ore::str::StrExt;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::GlobalId;
-use mz_repr::{Datum, RelationDesc, RelationType, Row, RowArena, ScalarType};
+use mz_repr::{Datum, RelationDesc, Row, RowArena, ScalarType};
 use mz_sql::ast::display::AstDisplay;
 use mz_sql::ast::{FetchDirection, Ident, Raw, Statement};
-use mz_sql::plan::{CopyFormat, ExecuteTimeout, StatementDesc};
-use mz_sql::session::user::{ExternalUserMetadata, User, INTERNAL_USER_NAMES};
+use mz_sql::plan::{ExecuteTimeout, StatementDesc};
+use mz_sql::session::password::ScramSecret;
+use mz_sql::session::user::{User, INTERNAL_USER_NAMES};
 use mz_sql::session::vars::VarInput;
 
-use crate::codec::FramedConn;
+use crate::auth::{Authenticator, PasswordExchange};
+use crate::codec::{self, FramedConn};
 use crate::message::{
     self, BackendMessage, ErrorResponse, FrontendMessage, Severity, VERSIONS, VERSION_3,
 };
+use crate::scram::{self, ScramError, ScramExchange};
 use crate::server::{Conn, TlsMode};
 
 /// Reports whether the given stream begins with a pgwire handshake.
@@ -78,8 +80,9 @@ pub struct RunParams<'a, A> {
     pub version: i32,
     /// The parameters that the client provided in the startup message.
     pub params: BTreeMap<String, String>,
-    /// Frontegg authentication.
-    pub frontegg: Option<&'a FronteggAuthentication>,
+    /// The authenticator for client credentials, if password authentication
+    /// is enabled.
+    pub authenticator: Option<&'a dyn Authenticator>,
     /// Whether this is an internal server that permits access to restricted
     /// system resources.
     pub internal: bool,
@@ -102,7 +105,7 @@ pub async fn run<'a, A>(
         conn,
         version,
         mut params,
-        frontegg,
+        authenticator,
         internal,
     }: RunParams<'a, A>,
 ) -> Result<(), io::Error>
@@ -164,51 +167,56 @@ where
         external_metadata: None,
     });
 
-    let is_expired = if let Some(frontegg) = frontegg {
-        conn.send(BackendMessage::AuthenticationCleartextPassword)
-            .await?;
-        conn.flush().await?;
-        let password = match conn.recv().await? {
-            Some(FrontendMessage::Password { password }) => password,
-            _ => {
-                return conn
-                    .send(ErrorResponse::fatal(
-                        SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
-                        "expected Password message",
-                    ))
+    let is_expired = match authenticator {
+        Some(authenticator) => match authenticator.password_exchange() {
+            PasswordExchange::Cleartext => {
+                conn.send(BackendMessage::AuthenticationCleartextPassword)
+                    .await?;
+                conn.flush().await?;
+                let password = match recv_authentication(conn, codec::decode_password).await? {
+                    Some(FrontendMessage::Password { password }) => password,
+                    _ => {
+                        return conn
+                            .send(ErrorResponse::fatal(
+                                SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+                                "expected Password message",
+                            ))
+                            .await
+                    }
+                };
+                let external_metadata_tx = session.retain_external_metadata_transmitter();
+                match authenticator
+                    .verify_password(&user, &password, external_metadata_tx)
                     .await
+                {
+                    Ok(is_expired) => {
+                        // Make sure to apply the initial metadata.
+                        session.apply_external_metadata_updates();
+                        is_expired.left_future()
+                    }
+                    Err(e) => {
+                        warn!("PGwire connection failed authentication: {}", e);
+                        return conn
+                            .send(ErrorResponse::fatal(
+                                SqlState::INVALID_PASSWORD,
+                                "invalid password",
+                            ))
+                            .await;
+                    }
+                }
             }
-        };
-        let admin_role = frontegg.admin_role();
-        let external_metadata_rx = session.retain_external_metadata_transmitter();
-        match frontegg
-            .exchange_password_for_token(&password)
-            .await
-            .and_then(|token| {
-                frontegg.continuously_validate_access_token(token, user.clone(), move |claims| {
-                    let external_metadata = convert_claims_to_external_metadata(claims, admin_role);
-                    // Ignore error if client has hung up.
-                    let _ = external_metadata_rx.send(external_metadata);
-                })
-            }) {
-            Ok(is_expired) => {
-                // Make sure to apply the initial claims.
-                session.apply_external_metadata_updates();
-                is_expired.left_future()
-            }
-            Err(e) => {
-                warn!("PGwire connection failed authentication: {}", e);
-                return conn
-                    .send(ErrorResponse::fatal(
-                        SqlState::INVALID_PASSWORD,
-                        "invalid password",
-                    ))
-                    .await;
+            PasswordExchange::Scram => {
+                let secret = authenticator.scram_secret(&user).await;
+                if let Err(err) = authenticate_scram(conn, &user, secret).await? {
+                    return conn.send(err).await;
+                }
+                // Passwords are verified only once, so is_expired never
+                // resolves.
+                pending().right_future()
             }
-        }
-    } else {
-        // No frontegg check, so is_expired never resolves.
-        pending().right_future()
+        },
+        // No authentication, so is_expired never resolves.
+        None => pending().right_future(),
     };
 
     for (name, value) in params {
@@ -299,14 +307,6 @@ where
     }
 }
 
-fn convert_claims_to_external_metadata(claims: Claims, admin_role: &str) -> ExternalUserMetadata {
-    ExternalUserMetadata {
-        user_id: claims.best_user_id(),
-        group_id: claims.tenant_id,
-        admin: claims.admin(admin_role),
-    }
-}
-
 /// Returns (name, value) session settings pairs from an options value.
 ///
 /// From Postgres, see pg_split_opts in postinit.c and process_postgres_switches
@@ -388,6 +388,104 @@ fn split_options(value: &str) -> Vec<String> {
     strs
 }
 
+/// Receives an authentication message from the client and decodes it with
+/// `decode`.
+///
+/// Returns `None` if the client sends any other kind of message, or an
+/// authentication message that `decode` rejects.
+async fn recv_authentication<A>(
+    conn: &mut FramedConn<A>,
+    decode: fn(&[u8]) -> Result<FrontendMessage, io::Error>,
+) -> Result<Option<FrontendMessage>, io::Error>
+where
+    A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin,
+{
+    match conn.recv().await? {
+        Some(FrontendMessage::RawAuthentication(data)) => Ok(decode(&data).ok()),
+        _ => Ok(None),
+    }
+}
+
+/// Runs a SCRAM-SHA-256 exchange with the client, verifying that it knows the
+/// password from which `secret` was derived.
+///
+/// If the client is connected over TLS, the server additionally offers
+/// SCRAM-SHA-256-PLUS, which binds the exchange to the server's certificate.
+/// Returns the error to report to the client if authentication fails.
+async fn authenticate_scram<A>(
+    conn: &mut FramedConn<A>,
+    user: &str,
+    secret: Option<ScramSecret>,
+) -> Result<Result<(), ErrorResponse>, io::Error>
+where
+    A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin,
+{
+    let scram_error = |e: ScramError| {
+        warn!("PGwire connection failed SCRAM authentication: {}", e);
+        match e {
+            ScramError::InvalidProof => ErrorResponse::fatal(
+                SqlState::INVALID_PASSWORD,
+                format!("password authentication failed for user {}", user.quoted()),
+            ),
+            ScramError::ChannelBindingMismatch => {
+                ErrorResponse::fatal(SqlState::INVALID_AUTHORIZATION_SPECIFICATION, e.to_string())
+            }
+            ScramError::MalformedMessage(_) | ScramError::UnsupportedMechanism(_) => {
+                ErrorResponse::fatal(SqlState::PROTOCOL_VIOLATION, e.to_string())
+            }
+        }
+    };
+    let unexpected_message = || {
+        ErrorResponse::fatal(
+            SqlState::PROTOCOL_VIOLATION,
+            "expected SASL response message",
+        )
+    };
+
+    let channel_binding = match conn.inner() {
+        Conn::Ssl(stream) => stream
+            .ssl()
+            .certificate()
+            .and_then(scram::tls_server_end_point),
+        Conn::Unencrypted(_) => None,
+    };
+    let exchange = ScramExchange::new(user, secret, channel_binding);
+    conn.send(BackendMessage::AuthenticationSasl {
+        mechanisms: exchange.mechanisms(),
+    })
+    .await?;
+    conn.flush().await?;
+
+    let (mechanism, initial_response) =
+        match recv_authentication(conn, codec::decode_sasl_initial_response).await? {
+            Some(FrontendMessage::SaslInitialResponse {
+                mechanism,
+                initial_response,
+            }) => (mechanism, initial_response),
+            _ => return Ok(Err(unexpected_message())),
+        };
+    let res = exchange.handle_client_first(&mechanism, &initial_response);
+    let (exchange, server_first) = match res {
+        Ok(res) => res,
+        Err(e) => return Ok(Err(scram_error(e))),
+    };
+    conn.send(BackendMessage::AuthenticationSaslContinue(server_first))
+        .await?;
+    conn.flush().await?;
+
+    let client_final = match recv_authentication(conn, codec::decode_sasl_response).await? {
+        Some(FrontendMessage::SaslResponse(data)) => data,
+        _ => return Ok(Err(unexpected_message())),
+    };
+    let server_final = match exchange.handle_client_final(&client_final) {
+        Ok(server_final) => server_final,
+        Err(e) => return Ok(Err(scram_error(e))),
+    };
+    conn.send(BackendMessage::AuthenticationSaslFinal(server_final))
+        .await?;
+    Ok(Ok(()))
+}
+
 #[derive(Debug)]
 enum State {
     Ready,
@@ -528,7 +626,10 @@ where
             Some(FrontendMessage::CopyData(_))
             | Some(FrontendMessage::CopyDone)
             | Some(FrontendMessage::CopyFail(_))
-            | Some(FrontendMessage::Password { .. }) => State::Drain,
+            | Some(FrontendMessage::RawAuthentication(_))
+            | Some(FrontendMessage::Password { .. })
+            | Some(FrontendMessage::SaslInitialResponse { .. })
+            | Some(FrontendMessage::SaslResponse(_)) => State::Drain,
             None => State::Done,
         };
 
@@ -1613,26 +1714,11 @@ where
     #[tracing::instrument(level = "debug", skip(self))]
     async fn copy_rows(
         &mut self,
-        format: CopyFormat,
+        format: CopyFormatParams<'_>,
         row_desc: RelationDesc,
         mut stream: RowBatchStream,
     ) -> Result<State, io::Error> {
-        let (encode_fn, encode_format): (
-            fn(Row, &RelationType, &mut Vec<u8>) -> Result<(), std::io::Error>,
-            mz_pgrepr::Format,
-        ) = match format {
-            CopyFormat::Text => (mz_pgcopy::encode_copy_row_text, mz_pgrepr::Format::Text),
-            CopyFormat::Binary => (mz_pgcopy::encode_copy_row_binary, mz_pgrepr::Format::Binary),
-            _ => {
-                return self
-                    .error(ErrorResponse::error(
-                        SqlState::FEATURE_NOT_SUPPORTED,
-                        format!("COPY TO format {:?} not supported", format),
-                    ))
-                    .await
-            }
-        };
-
+        let encode_format = format.wire_format();
         let typ = row_desc.typ();
         let column_formats = iter::repeat(encode_format)
             .take(typ.column_types.len())
@@ -1648,14 +1734,11 @@ where
         // common vec that we can extend one time now and then fill up with the encode
         // functions.
         let mut out = Vec::new();
-
-        if let CopyFormat::Binary = format {
-            // 11-byte signature.
-            out.extend(b"PGCOPY\n\xFF\r\n\0");
-            // 32-bit flags field.
-            out.extend([0, 0, 0, 0]);
-            // 32-bit header extension length field.
-            out.extend([0, 0, 0, 0]);
+        mz_pgcopy::encode_copy_format_header(&format, &row_desc, &mut out)?;
+        // Textual headers, on the other hand, are sent as their own CopyData.
+        if !matches!(format, CopyFormatParams::Binary) && !out.is_empty() {
+            self.send(BackendMessage::CopyData(mem::take(&mut out)))
+                .await?;
         }
 
         let mut count = 0;
@@ -1687,7 +1770,7 @@ where
                     Some(PeekResponseUnary::Rows(rows)) => {
                         count += rows.len();
                         for row in rows {
-                            encode_fn(row, typ, &mut out)?;
+                            mz_pgcopy::encode_copy_format(&format, row, typ, &mut out)?;
                             self.send(BackendMessage::CopyData(mem::take(&mut out)))
                                 .await?;
                         }
@@ -1702,10 +1785,10 @@ where
 
             self.conn.flush().await?;
         }
-        // Send required trailers.
-        if let CopyFormat::Binary = format {
-            let trailer: i16 = -1;
-            out.extend(trailer.to_be_bytes());
+        // Send required trailers, as well as any header that was not sent
+        // along with a row.
+        mz_pgcopy::encode_copy_format_trailer(&format, &mut out);
+        if !out.is_empty() {
             self.send(BackendMessage::CopyData(mem::take(&mut out)))
                 .await?;
         }
@@ -1726,9 +1809,10 @@ where
         row_desc: RelationDesc,
     ) -> Result<State, io::Error> {
         let typ = row_desc.typ();
-        let column_formats = vec![mz_pgrepr::Format::Text; typ.column_types.len()];
+        let format = params.wire_format();
+        let column_formats = vec![format; typ.column_types.len()];
         self.send(BackendMessage::CopyInResponse {
-            overall_format: mz_pgrepr::Format::Text,
+            overall_format: format,
             column_formats,
         })
         .await?;
@@ -0,0 +1,429 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Server side of the SCRAM-SHA-256 SASL mechanism.
+//!
+//! # Useful references
+//!
+//!   * [RFC 5802: Salted Challenge Response Authentication Mechanism](https://www.rfc-editor.org/rfc/rfc5802)
+//!   * [RFC 7677: SCRAM-SHA-256 and SCRAM-SHA-256-PLUS](https://www.rfc-editor.org/rfc/rfc7677)
+//!   * [RFC 5929: Channel Bindings for TLS](https://www.rfc-editor.org/rfc/rfc5929)
+//!   * [PostgreSQL SASL authentication](https://www.postgresql.org/docs/current/sasl-authentication.html)
+
+use std::error::Error;
+use std::fmt;
+use std::str;
+
+use hmac::{Hmac, Mac};
+use openssl::hash::MessageDigest;
+use openssl::nid::Nid;
+use openssl::x509::X509Ref;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+
+use mz_sql::session::password::ScramSecret;
+
+/// The name of the SCRAM-SHA-256 SASL mechanism.
+pub const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+/// The name of the SCRAM-SHA-256 SASL mechanism with channel binding.
+pub const SCRAM_SHA_256_PLUS: &str = "SCRAM-SHA-256-PLUS";
+
+/// The only channel binding type we support.
+const TLS_SERVER_END_POINT: &str = "tls-server-end-point";
+
+/// The number of random bytes in the server's nonce.
+const NONCE_LENGTH: usize = 18;
+
+/// An error that occurs during a SCRAM exchange.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScramError {
+    /// The client sent a message that does not conform to the protocol.
+    MalformedMessage(&'static str),
+    /// The client requested a mechanism that the server did not offer.
+    UnsupportedMechanism(String),
+    /// The client's channel binding did not match the server's.
+    ChannelBindingMismatch,
+    /// The client did not prove knowledge of the password.
+    InvalidProof,
+}
+
+impl fmt::Display for ScramError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScramError::MalformedMessage(detail) => {
+                write!(f, "malformed SCRAM message: {}", detail)
+            }
+            ScramError::UnsupportedMechanism(mechanism) => write!(
+                f,
+                "client selected an invalid SASL authentication mechanism: {}",
+                mechanism
+            ),
+            ScramError::ChannelBindingMismatch => f.write_str("SCRAM channel binding check failed"),
+            ScramError::InvalidProof => f.write_str("invalid SCRAM proof"),
+        }
+    }
+}
+
+impl Error for ScramError {}
+
+/// Computes the `tls-server-end-point` channel binding data for a server
+/// certificate, per RFC 5929 section 4.1.
+pub fn tls_server_end_point(cert: &X509Ref) -> Option<Vec<u8>> {
+    let digest = cert
+        .signature_algorithm()
+        .object()
+        .nid()
+        .signature_algorithms()
+        .and_then(|algs| match algs.digest {
+            // MD5 and SHA-1 are upgraded to SHA-256.
+            Nid::MD5 | Nid::SHA1 => Some(MessageDigest::sha256()),
+            nid => MessageDigest::from_nid(nid),
+        })?;
+    cert.digest(digest).ok().map(|d| d.to_vec())
+}
+
+/// The first step of a server-side SCRAM exchange.
+#[derive(Debug)]
+pub struct ScramExchange {
+    secret: ScramSecret,
+    channel_binding: Option<Vec<u8>>,
+}
+
+impl ScramExchange {
+    /// Starts an exchange that verifies the client against `secret`.
+    ///
+    /// If `secret` is `None`, because `user` does not exist, the exchange
+    /// proceeds as normal against a mock secret but always fails at its final
+    /// step. If `channel_binding` is present, the server offers
+    /// the `SCRAM-SHA-256-PLUS` mechanism with `tls-server-end-point` channel
+    /// binding.
+    pub fn new(
+        user: &str,
+        secret: Option<ScramSecret>,
+        channel_binding: Option<Vec<u8>>,
+    ) -> ScramExchange {
+        ScramExchange {
+            secret: secret.unwrap_or_else(|| ScramSecret::mock(user)),
+            channel_binding,
+        }
+    }
+
+    /// Returns the SASL mechanisms that the server offers, in order of
+    /// preference.
+    pub fn mechanisms(&self) -> Vec<&'static str> {
+        if self.channel_binding.is_some() {
+            vec![SCRAM_SHA_256_PLUS, SCRAM_SHA_256]
+        } else {
+            vec![SCRAM_SHA_256]
+        }
+    }
+
+    /// Processes the client-first-message, returning the server-first-message
+    /// and the state required to process the client-final-message.
+    pub fn handle_client_first(
+        self,
+        mechanism: &str,
+        message: &[u8],
+    ) -> Result<(ScramContinuation, Vec<u8>), ScramError> {
+        let plus = match mechanism {
+            SCRAM_SHA_256 => false,
+            SCRAM_SHA_256_PLUS if self.channel_binding.is_some() => true,
+            _ => return Err(ScramError::UnsupportedMechanism(mechanism.into())),
+        };
+        let message =
+            str::from_utf8(message).map_err(|_| ScramError::MalformedMessage("invalid UTF-8"))?;
+
+        // gs2-header = gs2-cbind-flag "," [ authzid ] ","
+        let (cbind_flag, rest) = message
+            .split_once(',')
+            .ok_or(ScramError::MalformedMessage("missing channel binding flag"))?;
+        let (authzid, client_first_bare) = rest.split_once(',').ok_or(
+            ScramError::MalformedMessage("missing authorization identity"),
+        )?;
+        let gs2_header = &message[..message.len() - client_first_bare.len()];
+
+        let cbind_data = match cbind_flag {
+            // The client does not support channel binding.
+            "n" if !plus => vec![],
+            // The client supports channel binding but believes that the
+            // server does not. If we offered it, this indicates that the
+            // mechanism list was tampered with.
+            "y" if !plus && self.channel_binding.is_none() => vec![],
+            "y" if !plus => return Err(ScramError::ChannelBindingMismatch),
+            flag => match flag.strip_prefix("p=") {
+                Some(TLS_SERVER_END_POINT) if plus => self
+                    .channel_binding
+                    .clone()
+                    .expect("channel binding offered"),
+                Some(_) if plus => {
+                    return Err(ScramError::MalformedMessage(
+                        "unsupported channel binding type",
+                    ))
+                }
+                _ => {
+                    return Err(ScramError::MalformedMessage(
+                        "channel binding flag does not match mechanism",
+                    ))
+                }
+            },
+        };
+        if !authzid.is_empty() {
+            return Err(ScramError::MalformedMessage(
+                "authorization identities are not supported",
+            ));
+        }
+
+        // client-first-message-bare = [reserved-mext ","] username "," nonce
+        // ["," extensions]
+        //
+        // Like PostgreSQL, we ignore the username in favor of the one in the
+        // startup message.
+        let mut attrs = client_first_bare.split(',');
+        match attrs.next() {
+            Some(attr) if attr.starts_with("n=") => (),
+            Some(attr) if attr.starts_with("m=") => {
+                return Err(ScramError::MalformedMessage(
+                    "mandatory extensions are not supported",
+                ))
+            }
+            _ => return Err(ScramError::MalformedMessage("missing username")),
+        }
+        let client_nonce = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("r="))
+            .filter(|nonce| !nonce.is_empty() && is_printable(nonce))
+            .ok_or(ScramError::MalformedMessage("missing nonce"))?;
+
+        let mut server_nonce = [0; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut server_nonce);
+        let nonce = format!("{}{}", client_nonce, base64::encode(server_nonce));
+        let server_first = format!(
+            "r={},s={},i={}",
+            nonce,
+            base64::encode(self.secret.salt()),
+            self.secret.iterations()
+        );
+
+        let mut cbind_input = gs2_header.as_bytes().to_vec();
+        cbind_input.extend(cbind_data);
+        let continuation = ScramContinuation {
+            secret: self.secret,
+            cbind_input,
+            nonce,
+            auth_message_prefix: format!("{},{}", client_first_bare, server_first),
+        };
+        Ok((continuation, server_first.into_bytes()))
+    }
+}
+
+/// The second step of a server-side SCRAM exchange.
+#[derive(Debug)]
+pub struct ScramContinuation {
+    secret: ScramSecret,
+    /// The expected decoded value of the client's `c` attribute.
+    cbind_input: Vec<u8>,
+    /// The combined client and server nonce.
+    nonce: String,
+    /// The client-first-message-bare and server-first-message, joined by a
+    /// comma.
+    auth_message_prefix: String,
+}
+
+impl ScramContinuation {
+    /// Processes the client-final-message, returning the server-final-message
+    /// if the client proved knowledge of the password.
+    pub fn handle_client_final(self, message: &[u8]) -> Result<Vec<u8>, ScramError> {
+        let message =
+            str::from_utf8(message).map_err(|_| ScramError::MalformedMessage("invalid UTF-8"))?;
+
+        // client-final-message = client-final-message-without-proof "," proof
+        let (without_proof, proof) = message
+            .rsplit_once(',')
+            .ok_or(ScramError::MalformedMessage("missing proof"))?;
+        let proof = proof
+            .strip_prefix("p=")
+            .and_then(|proof| base64::decode(proof).ok())
+            .ok_or(ScramError::MalformedMessage("malformed proof"))?;
+
+        let mut attrs = without_proof.split(',');
+        let cbind_input = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("c="))
+            .and_then(|cbind| base64::decode(cbind).ok())
+            .ok_or(ScramError::MalformedMessage("malformed channel binding"))?;
+        if !constant_time_eq(&cbind_input, &self.cbind_input) {
+            return Err(ScramError::ChannelBindingMismatch);
+        }
+        let nonce = attrs
+            .next()
+            .and_then(|attr| attr.strip_prefix("r="))
+            .ok_or(ScramError::MalformedMessage("missing nonce"))?;
+        if nonce != self.nonce {
+            return Err(ScramError::MalformedMessage("nonce does not match"));
+        }
+
+        let auth_message = format!("{},{}", self.auth_message_prefix, without_proof);
+        let client_signature = hmac(self.secret.stored_key(), auth_message.as_bytes());
+        if proof.len() != client_signature.len() {
+            return Err(ScramError::InvalidProof);
+        }
+        let client_key: Vec<u8> = proof
+            .iter()
+            .zip(client_signature)
+            .map(|(p, s)| p ^ s)
+            .collect();
+        let stored_key = Sha256::digest(client_key);
+        if !constant_time_eq(&stored_key, self.secret.stored_key()) {
+            return Err(ScramError::InvalidProof);
+        }
+
+        let server_signature = hmac(self.secret.server_key(), auth_message.as_bytes());
+        Ok(format!("v={}", base64::encode(server_signature)).into_bytes())
+    }
+}
+
+/// Compares two byte slices in constant time with respect to their contents.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && openssl::memcmp::eq(a, b)
+}
+
+fn hmac(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts all key sizes");
+    mac.update(message);
+    mac.finalize().into_bytes().into()
+}
+
+/// Reports whether `s` consists of printable ASCII characters other than
+/// `,`, as the SCRAM `printable` production requires.
+fn is_printable(s: &str) -> bool {
+    s.bytes().all(|b| (0x21..=0x7e).contains(&b) && b != b',')
+}
+
+#[cfg(test)]
+mod tests {
+    use postgres_protocol::authentication::sasl::{ChannelBinding, ScramSha256};
+
+    use super::*;
+
+    /// Runs a complete exchange between a `postgres-protocol` client and the
+    /// server implementation.
+    fn exchange(
+        secret: Option<ScramSecret>,
+        password: &str,
+        mechanism: &str,
+        client_channel_binding: ChannelBinding,
+        server_channel_binding: Option<Vec<u8>>,
+    ) -> Result<(), ScramError> {
+        let mut client = ScramSha256::new(password.as_bytes(), client_channel_binding);
+        let server = ScramExchange::new("alice", secret, server_channel_binding);
+        let (server, server_first) = server.handle_client_first(mechanism, client.message())?;
+        client
+            .update(&server_first)
+            .expect("client accepts server-first");
+        let server_final = server.handle_client_final(client.message())?;
+        client
+            .finish(&server_final)
+            .expect("client accepts server signature");
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_scram_exchange() {
+        let secret = ScramSecret::new("hunter2");
+        let cbind = vec![1, 2, 3, 4];
+
+        // Successful exchanges, with and without channel binding.
+        exchange(
+            Some(secret.clone()),
+            "hunter2",
+            SCRAM_SHA_256,
+            ChannelBinding::unsupported(),
+            None,
+        )
+        .unwrap();
+        exchange(
+            Some(secret.clone()),
+            "hunter2",
+            SCRAM_SHA_256,
+            ChannelBinding::unrequested(),
+            None,
+        )
+        .unwrap();
+        exchange(
+            Some(secret.clone()),
+            "hunter2",
+            SCRAM_SHA_256_PLUS,
+            ChannelBinding::tls_server_end_point(cbind.clone()),
+            Some(cbind.clone()),
+        )
+        .unwrap();
+
+        // Wrong password.
+        assert_eq!(
+            exchange(
+                Some(secret.clone()),
+                "hunter3",
+                SCRAM_SHA_256,
+                ChannelBinding::unsupported(),
+                None,
+            ),
+            Err(ScramError::InvalidProof)
+        );
+
+        // Unknown user.
+        assert_eq!(
+            exchange(
+                None,
+                "hunter2",
+                SCRAM_SHA_256,
+                ChannelBinding::unsupported(),
+                None,
+            ),
+            Err(ScramError::InvalidProof)
+        );
+
+        // Channel binding data that doesn't match the server's certificate.
+        assert_eq!(
+            exchange(
+                Some(secret.clone()),
+                "hunter2",
+                SCRAM_SHA_256_PLUS,
+                ChannelBinding::tls_server_end_point(vec![4, 3, 2, 1]),
+                Some(cbind.clone()),
+            ),
+            Err(ScramError::ChannelBindingMismatch)
+        );
+
+        // A client that believes the server does not support channel binding,
+        // when it does.
+        assert_eq!(
+            exchange(
+                Some(secret.clone()),
+                "hunter2",
+                SCRAM_SHA_256,
+                ChannelBinding::unrequested(),
+                Some(cbind),
+            ),
+            Err(ScramError::ChannelBindingMismatch)
+        );
+
+        // Channel binding without TLS.
+        assert_eq!(
+            exchange(
+                Some(secret),
+                "hunter2",
+                SCRAM_SHA_256_PLUS,
+                ChannelBinding::tls_server_end_point(vec![1, 2, 3, 4]),
+                None,
+            ),
+            Err(ScramError::UnsupportedMechanism(SCRAM_SHA_256_PLUS.into()))
+        );
+    }
+}
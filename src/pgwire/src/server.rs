@@ -10,6 +10,7 @@
 use std::fmt;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use async_trait::async_trait;
@@ -19,9 +20,9 @@ use tokio::io::{self, AsyncRead, AsyncWrite, AsyncWriteExt, Interest, ReadBuf, R
 use tokio_openssl::SslStream;
 use tracing::trace;
 
-use mz_frontegg_auth::Authentication as FronteggAuthentication;
 use mz_ore::netio::AsyncReady;
 
+use crate::auth::Authenticator;
 use crate::codec::{self, FramedConn, ACCEPT_SSL_ENCRYPTION, REJECT_ENCRYPTION};
 use crate::message::FrontendStartupMessage;
 use crate::metrics::{Metrics, MetricsConfig};
@@ -37,12 +38,12 @@ pub struct Config {
     /// If not present, then TLS is not enabled, and clients requests to
     /// negotiate TLS will be rejected.
     pub tls: Option<TlsConfig>,
-    /// The Frontegg authentication configuration.
+    /// The authenticator for client credentials.
     ///
-    /// If present, Frontegg authentication is enabled, and users may present
-    /// a valid Frontegg API token as a password to authenticate. Otherwise,
-    /// password authentication is disabled.
-    pub frontegg: Option<FronteggAuthentication>,
+    /// If present, password authentication is enabled, and users must present
+    /// credentials that the authenticator accepts. Otherwise, password
+    /// authentication is disabled.
+    pub authenticator: Option<Arc<dyn Authenticator>>,
     /// The registry entries that the pgwire server uses to report metrics.
     pub metrics: MetricsConfig,
     /// Whether this is an internal server that permits access to restricted
@@ -72,7 +73,7 @@ pub enum TlsMode {
 pub struct Server {
     tls: Option<TlsConfig>,
     adapter_client: mz_adapter::Client,
-    frontegg: Option<FronteggAuthentication>,
+    authenticator: Option<Arc<dyn Authenticator>>,
     metrics: Metrics,
     internal: bool,
 }
@@ -83,7 +84,7 @@ impl Server {
         Server {
             tls: config.tls,
             adapter_client: config.adapter_client,
-            frontegg: config.frontegg,
+            authenticator: config.authenticator,
             metrics: Metrics::new(config.metrics, config.internal),
             internal: config.internal,
         }
@@ -98,7 +99,7 @@ impl Server {
         A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin + fmt::Debug + 'static,
     {
         let adapter_client = self.adapter_client.new_conn();
-        let frontegg = self.frontegg.clone();
+        let authenticator = self.authenticator.clone();
         let tls = self.tls.clone();
         let internal = self.internal;
         let metrics = self.metrics.clone();
@@ -129,7 +130,7 @@ impl Server {
                             conn: &mut conn,
                             version,
                             params,
-                            frontegg: frontegg.as_ref(),
+                            authenticator: authenticator.as_deref(),
                             internal,
                         })
                         .await?;
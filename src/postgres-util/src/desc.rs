@@ -306,3 +306,59 @@ impl Arbitrary for PostgresKeyDesc {
             .boxed()
     }
 }
+
+/// Describes an enum type in a PostgreSQL database.
+///
+/// <https://www.postgresql.org/docs/current/catalog-pg-enum.html>
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresEnumDesc {
+    /// The OID of the enum type.
+    pub oid: Oid,
+    /// The name of the schema that the enum type belongs to.
+    pub namespace: String,
+    /// The name of the enum type.
+    pub name: String,
+    /// The labels of the enum type, in sort order.
+    pub labels: Vec<String>,
+}
+
+impl RustType<ProtoPostgresEnumDesc> for PostgresEnumDesc {
+    fn into_proto(&self) -> ProtoPostgresEnumDesc {
+        ProtoPostgresEnumDesc {
+            oid: self.oid,
+            namespace: self.namespace.clone(),
+            name: self.name.clone(),
+            labels: self.labels.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresEnumDesc) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresEnumDesc {
+            oid: proto.oid,
+            namespace: proto.namespace,
+            name: proto.name,
+            labels: proto.labels,
+        })
+    }
+}
+
+impl Arbitrary for PostgresEnumDesc {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u32>(),
+            any::<String>(),
+            any::<String>(),
+            any::<Vec<String>>(),
+        )
+            .prop_map(|(oid, namespace, name, labels)| PostgresEnumDesc {
+                oid,
+                namespace,
+                name,
+                labels,
+            })
+            .boxed()
+    }
+}
@@ -9,7 +9,9 @@
 
 use tokio_postgres::types::Oid;
 
-use crate::desc::{PostgresColumnDesc, PostgresKeyDesc, PostgresSchemaDesc, PostgresTableDesc};
+use crate::desc::{
+    PostgresColumnDesc, PostgresEnumDesc, PostgresKeyDesc, PostgresSchemaDesc, PostgresTableDesc,
+};
 use crate::{Config, PostgresError};
 
 pub async fn get_schemas(config: &Config) -> Result<Vec<PostgresSchemaDesc>, PostgresError> {
@@ -28,6 +30,42 @@ pub async fn get_schemas(config: &Config) -> Result<Vec<PostgresSchemaDesc>, Pos
         .collect::<Vec<_>>())
 }
 
+/// Fetches the descriptions of the enum types among the types identified by
+/// `oids`. OIDs that do not identify an enum type are ignored.
+pub async fn enum_types(
+    config: &Config,
+    oids: &[Oid],
+) -> Result<Vec<PostgresEnumDesc>, PostgresError> {
+    let client = config.connect("postgres_enum_types").await?;
+
+    let rows = client
+        .query(
+            "SELECT
+                t.oid, n.nspname, t.typname,
+                array_agg(e.enumlabel::text ORDER BY e.enumsortorder) AS labels
+            FROM
+                pg_catalog.pg_type AS t
+                JOIN pg_catalog.pg_namespace AS n ON t.typnamespace = n.oid
+                JOIN pg_catalog.pg_enum AS e ON e.enumtypid = t.oid
+            WHERE t.typtype = 'e' AND t.oid = ANY ($1)
+            GROUP BY t.oid, n.nspname, t.typname",
+            &[&oids],
+        )
+        .await?;
+
+    // Enum types without any labels do not appear in `pg_enum`, and so are not
+    // returned by the query above; they are of no use as column types anyway.
+    Ok(rows
+        .into_iter()
+        .map(|row| PostgresEnumDesc {
+            oid: row.get("oid"),
+            namespace: row.get("nspname"),
+            name: row.get("typname"),
+            labels: row.get("labels"),
+        })
+        .collect())
+}
+
 /// Fetches table schema information from an upstream Postgres source for
 /// tables that are part of a publication, given a connection string and the
 /// publication name.
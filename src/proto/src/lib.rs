@@ -125,6 +125,9 @@ pub enum TryFromProtoError {
     GlobError(globset::Error),
     /// Failed to parse a serialized URL
     InvalidUrl(url::ParseError),
+    /// Indicates that a field in the `Proto$T` holds a value that is not valid
+    /// for `$T`.
+    InvalidFieldError(String),
 }
 
 impl TryFromProtoError {
@@ -213,6 +216,7 @@ impl std::fmt::Display for TryFromProtoError {
             InvalidUri(error) => error.fmt(f),
             GlobError(error) => error.fmt(f),
             InvalidUrl(error) => error.fmt(f),
+            InvalidFieldError(error) => error.fmt(f),
         }
     }
 }
@@ -244,6 +248,7 @@ impl std::error::Error for TryFromProtoError {
             InvalidUri(error) => Some(error),
             GlobError(error) => Some(error),
             InvalidUrl(error) => Some(error),
+            InvalidFieldError(_) => None,
         }
     }
 }
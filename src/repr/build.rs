@@ -94,6 +94,7 @@ fn main() {
                 "repr/src/adt/char.proto",
                 "repr/src/adt/date.proto",
                 "repr/src/adt/datetime.proto",
+                "repr/src/adt/inet.proto",
                 "repr/src/adt/interval.proto",
                 "repr/src/adt/mz_acl_item.proto",
                 "repr/src/adt/numeric.proto",
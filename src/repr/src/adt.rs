@@ -21,8 +21,11 @@ pub mod array;
 pub mod char;
 pub mod date;
 pub mod datetime;
+pub mod hll;
+pub mod inet;
 pub mod interval;
 pub mod jsonb;
+pub mod jsonpath;
 pub mod mz_acl_item;
 pub mod numeric;
 pub mod range;
@@ -0,0 +1,565 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! HyperLogLog sketches for approximate distinct counting.
+//!
+//! A sketch is exchanged with users as an opaque `bytea` value. Its encoding
+//! is canonical: two sketches that observed the same set of hashes always
+//! encode to identical bytes, no matter the order in which the hashes were
+//! inserted or merged. Dataflow rendering relies on this property when it
+//! retracts intermediate results of hierarchical reductions.
+//!
+//! The encoding is a one byte format tag, followed by a one byte precision, a
+//! one byte [`HASH_VERSION`], and the registers. Sketches with few non-zero
+//! registers use the sparse format, which stores a big-endian `u16` register
+//! index and a `u8` rank for each non-zero register, in increasing order of
+//! index. All other sketches use the dense format, which stores one byte per
+//! register.
+//!
+//! Sketches are only meaningful to merge if their hashes were computed the
+//! same way, so the values inserted into a sketch are hashed with
+//! [`hash_datum`], whose output only changes along with the hash version.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt;
+use std::hash::Hasher;
+
+use chrono::Timelike;
+use mz_ore::cast::{CastFrom, CastLossy, TryCastFrom};
+
+use crate::adt::numeric;
+use crate::Datum;
+
+/// The number of hash bits used to select a register.
+///
+/// With `2^12` registers, the relative standard error of an estimate is about
+/// `1.04 / sqrt(2^12) ≈ 1.6%`.
+pub const PRECISION: u8 = 12;
+
+const NUM_REGISTERS: usize = 1 << PRECISION;
+
+/// The largest rank that can be observed with 64-bit hashes.
+const MAX_RANK: u8 = 64 - PRECISION + 1;
+
+/// The maximum number of non-zero registers stored in the sparse format. Past
+/// this point the dense format is smaller.
+const MAX_SPARSE_REGISTERS: usize = NUM_REGISTERS / 4;
+
+/// The length of the format tag, precision and hash version that precede the
+/// registers in the encoding of a sketch.
+const HEADER_LEN: usize = 3;
+
+const SPARSE_FORMAT: u8 = 1;
+const DENSE_FORMAT: u8 = 2;
+
+/// The version of the datum encoding hashed by [`hash_datum`].
+///
+/// Any change to the hashes that [`hash_datum`] computes must increment this
+/// version. It is part of the encoding of sketches, which refuses to decode
+/// sketches of other versions rather than silently merging incompatible
+/// hashes.
+pub const HASH_VERSION: u8 = 1;
+
+/// A HyperLogLog sketch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Registers,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Registers {
+    /// The non-zero registers, by index.
+    Sparse(BTreeMap<u16, u8>),
+    /// Every register, by index.
+    Dense(Vec<u8>),
+}
+
+impl Default for HyperLogLog {
+    fn default() -> HyperLogLog {
+        HyperLogLog::new()
+    }
+}
+
+impl HyperLogLog {
+    /// Constructs an empty sketch.
+    pub fn new() -> HyperLogLog {
+        HyperLogLog {
+            registers: Registers::Sparse(BTreeMap::new()),
+        }
+    }
+
+    /// Records an observation of a value with the given hash.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = u16::try_from(hash >> (64 - PRECISION)).expect("index has PRECISION bits");
+        // The sentinel bit bounds the rank when the remaining bits are zero.
+        let remaining = (hash << PRECISION) | (1 << (PRECISION - 1));
+        let rank = u8::try_from(remaining.leading_zeros() + 1).expect("rank at most MAX_RANK");
+        self.set_register(index, rank);
+    }
+
+    /// Folds the observations of `other` into this sketch.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        match &other.registers {
+            Registers::Sparse(registers) => {
+                for (index, rank) in registers {
+                    self.set_register(*index, *rank);
+                }
+            }
+            Registers::Dense(registers) => {
+                self.densify();
+                let Registers::Dense(ours) = &mut self.registers else {
+                    unreachable!("sketch was just densified")
+                };
+                for (ours, theirs) in ours.iter_mut().zip(registers) {
+                    *ours = std::cmp::max(*ours, *theirs);
+                }
+            }
+        }
+    }
+
+    /// Estimates the number of distinct hashes observed by this sketch.
+    pub fn estimate(&self) -> u64 {
+        let (zeros, sum) = match &self.registers {
+            Registers::Sparse(registers) => {
+                let zeros = NUM_REGISTERS - registers.len();
+                let sum: f64 = registers.values().map(|rank| rank_weight(*rank)).sum();
+                (zeros, sum + f64::cast_lossy(zeros))
+            }
+            Registers::Dense(registers) => {
+                let zeros = registers.iter().filter(|rank| **rank == 0).count();
+                let sum: f64 = registers.iter().map(|rank| rank_weight(*rank)).sum();
+                (zeros, sum)
+            }
+        };
+        let m = f64::cast_lossy(NUM_REGISTERS);
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let mut estimate = alpha * m * m / sum;
+        // The raw estimate is biased for small cardinalities, where linear
+        // counting over the empty registers is more accurate.
+        if estimate <= 2.5 * m && zeros > 0 {
+            estimate = m * (m / f64::cast_lossy(zeros)).ln();
+        }
+        u64::try_cast_from(estimate.round()).unwrap_or(u64::MAX)
+    }
+
+    /// Encodes the sketch in its canonical binary format.
+    pub fn encode(&self) -> Vec<u8> {
+        let non_zero = match &self.registers {
+            Registers::Sparse(registers) => registers.len(),
+            Registers::Dense(registers) => registers.iter().filter(|rank| **rank != 0).count(),
+        };
+        if non_zero <= MAX_SPARSE_REGISTERS {
+            let mut buf = Vec::with_capacity(HEADER_LEN + 3 * non_zero);
+            buf.extend([SPARSE_FORMAT, PRECISION, HASH_VERSION]);
+            for (index, rank) in self.non_zero_registers() {
+                buf.extend(index.to_be_bytes());
+                buf.push(rank);
+            }
+            buf
+        } else {
+            let mut buf = Vec::with_capacity(HEADER_LEN + NUM_REGISTERS);
+            buf.extend([DENSE_FORMAT, PRECISION, HASH_VERSION]);
+            match &self.registers {
+                Registers::Sparse(registers) => {
+                    buf.resize(HEADER_LEN + NUM_REGISTERS, 0);
+                    for (index, rank) in registers {
+                        buf[HEADER_LEN + usize::from(*index)] = *rank;
+                    }
+                }
+                Registers::Dense(registers) => buf.extend(registers),
+            }
+            buf
+        }
+    }
+
+    /// Decodes a sketch from the binary format produced by
+    /// [`HyperLogLog::encode`].
+    pub fn decode(buf: &[u8]) -> Result<HyperLogLog, InvalidHyperLogLogError> {
+        let (format, precision, hash_version, payload) = match buf {
+            [format, precision, hash_version, payload @ ..] => {
+                (*format, *precision, *hash_version, payload)
+            }
+            _ => return Err(InvalidHyperLogLogError),
+        };
+        if precision != PRECISION || hash_version != HASH_VERSION {
+            return Err(InvalidHyperLogLogError);
+        }
+        let registers = match format {
+            SPARSE_FORMAT => {
+                if payload.len() % 3 != 0 {
+                    return Err(InvalidHyperLogLogError);
+                }
+                let mut registers = BTreeMap::new();
+                let mut prev_index = None;
+                for entry in payload.chunks_exact(3) {
+                    let index = u16::from_be_bytes([entry[0], entry[1]]);
+                    let rank = entry[2];
+                    if usize::from(index) >= NUM_REGISTERS
+                        || rank == 0
+                        || rank > MAX_RANK
+                        || prev_index >= Some(index)
+                    {
+                        return Err(InvalidHyperLogLogError);
+                    }
+                    prev_index = Some(index);
+                    registers.insert(index, rank);
+                }
+                Registers::Sparse(registers)
+            }
+            DENSE_FORMAT => {
+                if payload.len() != NUM_REGISTERS || payload.iter().any(|rank| *rank > MAX_RANK) {
+                    return Err(InvalidHyperLogLogError);
+                }
+                Registers::Dense(payload.to_vec())
+            }
+            _ => return Err(InvalidHyperLogLogError),
+        };
+        Ok(HyperLogLog { registers })
+    }
+
+    fn set_register(&mut self, index: u16, rank: u8) {
+        match &mut self.registers {
+            Registers::Sparse(registers) => {
+                let entry = registers.entry(index).or_insert(0);
+                *entry = std::cmp::max(*entry, rank);
+                if registers.len() > MAX_SPARSE_REGISTERS {
+                    self.densify();
+                }
+            }
+            Registers::Dense(registers) => {
+                let entry = &mut registers[usize::from(index)];
+                *entry = std::cmp::max(*entry, rank);
+            }
+        }
+    }
+
+    fn densify(&mut self) {
+        if let Registers::Sparse(sparse) = &self.registers {
+            let mut dense = vec![0; NUM_REGISTERS];
+            for (index, rank) in sparse {
+                dense[usize::from(*index)] = *rank;
+            }
+            self.registers = Registers::Dense(dense);
+        }
+    }
+
+    fn non_zero_registers(&self) -> Box<dyn Iterator<Item = (u16, u8)> + '_> {
+        match &self.registers {
+            Registers::Sparse(registers) => {
+                Box::new(registers.iter().map(|(index, rank)| (*index, *rank)))
+            }
+            Registers::Dense(registers) => Box::new(
+                (0..)
+                    .zip(registers.iter())
+                    .filter(|(_, rank)| **rank != 0)
+                    .map(|(index, rank)| (index, *rank)),
+            ),
+        }
+    }
+}
+
+/// Returns `2^-rank`.
+fn rank_weight(rank: u8) -> f64 {
+    2f64.powi(-i32::from(rank))
+}
+
+/// Computes the hash of a non-null datum that is inserted into a
+/// [`HyperLogLog`].
+///
+/// The datum is hashed through a canonical byte encoding, rather than with its
+/// derived `Hash` implementation, which depends on the layout of `Datum` and
+/// may change between releases. The encoding only depends on the value of the
+/// datum, so that values that compare equal hash equally: integers of all
+/// widths are encoded as the same 128-bit integer, floats as the bits of the
+/// equivalent `f64` with a single zero and a single NaN, and numerics as the
+/// text of their reduced value. Each value is preceded by a tag for its kind
+/// of value, and values of variable length by their length, so that the
+/// encodings of different values never coincide.
+///
+/// The encoding is versioned by [`HASH_VERSION`].
+pub fn hash_datum(datum: Datum) -> u64 {
+    let mut hasher = HllHasher::default();
+    write_datum(&mut hasher, datum);
+    hasher.finish()
+}
+
+fn write_datum(hasher: &mut HllHasher, datum: Datum) {
+    fn write_len(hasher: &mut HllHasher, len: usize) {
+        hasher.write(&u64::cast_from(len).to_be_bytes());
+    }
+    fn write_bytes(hasher: &mut HllHasher, bytes: &[u8]) {
+        write_len(hasher, bytes.len());
+        hasher.write(bytes);
+    }
+    fn write_int(hasher: &mut HllHasher, i: i128) {
+        hasher.write(&[2]);
+        hasher.write(&i.to_be_bytes());
+    }
+    fn write_float(hasher: &mut HllHasher, f: f64) {
+        let f = if f.is_nan() {
+            f64::NAN
+        } else if f == 0.0 {
+            0.0
+        } else {
+            f
+        };
+        hasher.write(&[3]);
+        hasher.write(&f.to_bits().to_be_bytes());
+    }
+    fn write_timestamp(hasher: &mut HllHasher, tag: u8, secs: i64, nanos: u32) {
+        hasher.write(&[tag]);
+        hasher.write(&secs.to_be_bytes());
+        hasher.write(&nanos.to_be_bytes());
+    }
+
+    match datum {
+        Datum::False => hasher.write(&[1, 0]),
+        Datum::True => hasher.write(&[1, 1]),
+        Datum::Int16(i) => write_int(hasher, i.into()),
+        Datum::Int32(i) => write_int(hasher, i.into()),
+        Datum::Int64(i) => write_int(hasher, i.into()),
+        Datum::UInt8(i) => write_int(hasher, i.into()),
+        Datum::UInt16(i) => write_int(hasher, i.into()),
+        Datum::UInt32(i) => write_int(hasher, i.into()),
+        Datum::UInt64(i) => write_int(hasher, i.into()),
+        Datum::Float32(f) => write_float(hasher, f.into_inner().into()),
+        Datum::Float64(f) => write_float(hasher, f.into_inner()),
+        Datum::Numeric(n) => {
+            let mut n = n.0;
+            numeric::cx_datum().reduce(&mut n);
+            let text = if n.is_zero() {
+                "0".to_string()
+            } else {
+                n.to_standard_notation_string()
+            };
+            hasher.write(&[4]);
+            write_bytes(hasher, text.as_bytes());
+        }
+        Datum::Date(d) => {
+            hasher.write(&[5]);
+            hasher.write(&d.unix_epoch_days().to_be_bytes());
+        }
+        Datum::Time(t) => write_timestamp(
+            hasher,
+            6,
+            t.num_seconds_from_midnight().into(),
+            t.nanosecond(),
+        ),
+        Datum::Timestamp(ts) => {
+            write_timestamp(hasher, 7, ts.timestamp(), ts.timestamp_subsec_nanos())
+        }
+        Datum::TimestampTz(ts) => {
+            write_timestamp(hasher, 8, ts.timestamp(), ts.timestamp_subsec_nanos())
+        }
+        Datum::Interval(i) => {
+            hasher.write(&[9]);
+            hasher.write(&i.months.to_be_bytes());
+            hasher.write(&i.days.to_be_bytes());
+            hasher.write(&i.micros.to_be_bytes());
+        }
+        Datum::Bytes(b) => {
+            hasher.write(&[10]);
+            write_bytes(hasher, b);
+        }
+        Datum::String(s) => {
+            hasher.write(&[11]);
+            write_bytes(hasher, s.as_bytes());
+        }
+        Datum::Uuid(u) => {
+            hasher.write(&[12]);
+            hasher.write(u.as_bytes());
+        }
+        Datum::MzTimestamp(ts) => {
+            hasher.write(&[13]);
+            hasher.write(&u64::from(ts).to_be_bytes());
+        }
+        Datum::Array(a) => {
+            hasher.write(&[14]);
+            write_len(hasher, a.dims().ndims().into());
+            for dim in a.dims() {
+                write_len(hasher, dim.lower_bound);
+                write_len(hasher, dim.length);
+            }
+            for elem in a.elements().iter() {
+                write_datum(hasher, elem);
+            }
+        }
+        Datum::List(l) => {
+            hasher.write(&[15]);
+            write_len(hasher, l.iter().count());
+            for elem in l.iter() {
+                write_datum(hasher, elem);
+            }
+        }
+        Datum::Map(m) => {
+            hasher.write(&[16]);
+            write_len(hasher, m.iter().count());
+            for (key, value) in m.iter() {
+                write_bytes(hasher, key.as_bytes());
+                write_datum(hasher, value);
+            }
+        }
+        Datum::Range(r) => {
+            hasher.write(&[17]);
+            match r.inner {
+                None => hasher.write(&[0]),
+                Some(inner) => {
+                    hasher.write(&[1]);
+                    for (inclusive, bound) in [
+                        (inner.lower.inclusive, inner.lower.bound),
+                        (inner.upper.inclusive, inner.upper.bound),
+                    ] {
+                        hasher.write(&[u8::from(inclusive)]);
+                        match bound {
+                            None => hasher.write(&[0]),
+                            Some(bound) => {
+                                hasher.write(&[1]);
+                                write_datum(hasher, bound.datum());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Datum::MzAclItem(item) => {
+            hasher.write(&[18]);
+            write_bytes(hasher, &item.encode_binary());
+        }
+        Datum::Inet(inet) => {
+            hasher.write(&[19]);
+            write_bytes(hasher, &inet.encode_binary());
+        }
+        Datum::JsonNull => hasher.write(&[20]),
+        Datum::Dummy => hasher.write(&[21]),
+        Datum::Null => hasher.write(&[22]),
+    }
+}
+
+/// A [`Hasher`] whose output is stable across processes and releases.
+///
+/// It is FNV-1a followed by the MurmurHash3 finalizer, which distributes the
+/// short inputs that are typical of datums across all 64 bits of the output.
+#[derive(Debug, Clone)]
+struct HllHasher(u64);
+
+impl Default for HllHasher {
+    fn default() -> HllHasher {
+        HllHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for HllHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= u64::cast_from(*b);
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut h = self.0;
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xff51afd7ed558ccd);
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xc4ceb9fe1a85ec53);
+        h ^= h >> 33;
+        h
+    }
+}
+
+/// The error returned when decoding a malformed [`HyperLogLog`] sketch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidHyperLogLogError;
+
+impl fmt::Display for InvalidHyperLogLogError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("invalid HyperLogLog sketch")
+    }
+}
+
+impl Error for InvalidHyperLogLogError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sketch(values: impl IntoIterator<Item = u64>) -> HyperLogLog {
+        let mut hll = HyperLogLog::new();
+        for v in values {
+            hll.insert_hash(hash_datum(Datum::UInt64(v)));
+        }
+        hll
+    }
+
+    #[test]
+    fn test_hash_datum_is_canonical() {
+        assert_eq!(hash_datum(Datum::Int16(7)), hash_datum(Datum::Int64(7)));
+        assert_eq!(hash_datum(Datum::Int32(-7)), hash_datum(Datum::Int64(-7)));
+        assert_eq!(hash_datum(Datum::UInt32(7)), hash_datum(Datum::Int32(7)));
+        assert_ne!(hash_datum(Datum::Int32(7)), hash_datum(Datum::Int32(8)));
+        assert_eq!(
+            hash_datum(Datum::from(1.5f32)),
+            hash_datum(Datum::from(1.5f64))
+        );
+        assert_eq!(
+            hash_datum(Datum::from(-0.0f64)),
+            hash_datum(Datum::from(0.0f64))
+        );
+        assert_eq!(
+            hash_datum(Datum::from(numeric::Numeric::from(100))),
+            hash_datum(Datum::from(numeric::cx_datum().parse("1.00E+2").unwrap()))
+        );
+        assert_ne!(
+            hash_datum(Datum::String("a")),
+            hash_datum(Datum::Bytes(b"a"))
+        );
+    }
+
+    #[test]
+    fn test_estimate() {
+        assert_eq!(HyperLogLog::new().estimate(), 0);
+        assert_eq!(sketch([1, 2, 3, 3, 2, 1]).estimate(), 3);
+        for n in [100, 1_000, 10_000, 100_000] {
+            let estimate = f64::cast_lossy(sketch(0..n).estimate());
+            let error = (estimate - f64::cast_lossy(n)).abs() / f64::cast_lossy(n);
+            assert!(error < 0.05, "n={n} estimate={estimate}");
+        }
+    }
+
+    #[test]
+    fn test_merge_is_canonical() {
+        for (a, b) in [(0..10, 5..20), (0..500, 250..5_000), (0..5_000, 0..10)] {
+            let mut merged = sketch(a.clone());
+            merged.merge(&sketch(b.clone()));
+            let mut reversed = sketch(b.clone());
+            reversed.merge(&sketch(a.clone()));
+            let direct = sketch(a.chain(b));
+            assert_eq!(merged.encode(), direct.encode());
+            assert_eq!(reversed.encode(), direct.encode());
+        }
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        for n in [0, 10, 2_000, 50_000] {
+            let hll = sketch(0..n);
+            let decoded = HyperLogLog::decode(&hll.encode()).unwrap();
+            assert_eq!(decoded.encode(), hll.encode());
+            assert_eq!(decoded.estimate(), hll.estimate());
+        }
+        let v = HASH_VERSION;
+        assert!(HyperLogLog::decode(&[]).is_err());
+        assert!(HyperLogLog::decode(&[SPARSE_FORMAT, PRECISION + 1, v]).is_err());
+        assert!(HyperLogLog::decode(&[SPARSE_FORMAT, PRECISION, v + 1]).is_err());
+        assert!(HyperLogLog::decode(&[SPARSE_FORMAT, PRECISION, v, 0, 1]).is_err());
+        assert!(HyperLogLog::decode(&[SPARSE_FORMAT, PRECISION, v, 0, 2, 1, 0, 1, 1]).is_err());
+        assert!(HyperLogLog::decode(&[DENSE_FORMAT, PRECISION, v, 0]).is_err());
+    }
+}
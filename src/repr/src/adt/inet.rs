@@ -0,0 +1,405 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Network address data types.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error};
+use columnation::{CloneRegion, Columnation};
+use mz_ore::str::StrExt;
+use mz_proto::{RustType, TryFromProtoError};
+use proptest::prelude::{any, Arbitrary, BoxedStrategy, Strategy};
+use serde::{Deserialize, Serialize};
+
+include!(concat!(env!("OUT_DIR"), "/mz_repr.adt.inet.rs"));
+
+/// An IPv4 or IPv6 host address together with the length of its network
+/// prefix.
+///
+/// This is the representation of both the `inet` and `cidr` types. A `cidr`
+/// value is an `Inet` with no bits set to the right of its netmask; see
+/// [`Inet::is_network`].
+///
+/// Values are ordered like PostgreSQL orders them: IPv4 addresses sort before
+/// IPv6 addresses, then values are compared by the bits of the network prefix
+/// they share, then by prefix length, and finally by the full address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Inet {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl Inet {
+    /// Constructs a new `Inet` from an address and a prefix length, which must
+    /// not exceed the number of bits in the address.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Inet, Error> {
+        if prefix_len > max_prefix_len(addr) {
+            bail!("invalid mask length: {}", prefix_len);
+        }
+        Ok(Inet { addr, prefix_len })
+    }
+
+    /// Constructs an `Inet` that refers to the single host `addr`.
+    pub fn host(addr: IpAddr) -> Inet {
+        Inet {
+            addr,
+            prefix_len: max_prefix_len(addr),
+        }
+    }
+
+    /// Returns the address, including any bits to the right of the netmask.
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the length of the network prefix, in bits.
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns the number of bits in the address, i.e. 32 for IPv4 and 128 for
+    /// IPv6.
+    pub fn max_prefix_len(&self) -> u8 {
+        max_prefix_len(self.addr)
+    }
+
+    /// Reports whether the address has no bits set to the right of the
+    /// netmask, i.e. whether this value is a valid `cidr`.
+    pub fn is_network(&self) -> bool {
+        self.bits() & !mask(self.prefix_len) == 0
+    }
+
+    /// Returns the network part of this value, i.e. the address with all bits
+    /// to the right of the netmask cleared.
+    pub fn network(&self) -> Inet {
+        Inet {
+            addr: self.with_bits(self.bits() & mask(self.prefix_len)),
+            prefix_len: self.prefix_len,
+        }
+    }
+
+    /// Returns this value with its prefix length replaced by `prefix_len`.
+    pub fn with_prefix_len(&self, prefix_len: u8) -> Result<Inet, Error> {
+        Inet::new(self.addr, prefix_len)
+    }
+
+    /// Reports whether the network of `self` strictly contains `other`.
+    pub fn contains(&self, other: &Inet) -> bool {
+        self.prefix_len < other.prefix_len && self.contains_or_equals(other)
+    }
+
+    /// Reports whether the network of `self` contains or equals `other`.
+    pub fn contains_or_equals(&self, other: &Inet) -> bool {
+        self.is_same_family(other)
+            && self.prefix_len <= other.prefix_len
+            && self.shares_prefix(other, self.prefix_len)
+    }
+
+    /// Reports whether either network contains or equals the other.
+    pub fn overlaps(&self, other: &Inet) -> bool {
+        self.is_same_family(other)
+            && self.shares_prefix(other, self.prefix_len.min(other.prefix_len))
+    }
+
+    /// Encodes this value as [`Inet::binary_size`] bytes.
+    pub fn encode_binary(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(Self::binary_size());
+        match self.addr {
+            IpAddr::V4(addr) => {
+                res.push(4);
+                res.push(self.prefix_len);
+                res.extend_from_slice(&addr.octets());
+                res.extend_from_slice(&[0; 12]);
+            }
+            IpAddr::V6(addr) => {
+                res.push(6);
+                res.push(self.prefix_len);
+                res.extend_from_slice(&addr.octets());
+            }
+        }
+        res
+    }
+
+    /// Decodes a value produced by [`Inet::encode_binary`].
+    pub fn decode_binary(raw: &[u8]) -> Result<Inet, Error> {
+        if raw.len() != Inet::binary_size() {
+            bail!(
+                "invalid binary size, expecting {}, found {}",
+                Inet::binary_size(),
+                raw.len()
+            );
+        }
+        let octets: [u8; 16] = raw[2..].try_into()?;
+        let addr = match raw[0] {
+            4 => IpAddr::V4(Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3])),
+            6 => IpAddr::V6(Ipv6Addr::from(octets)),
+            family => bail!("invalid address family {}", family),
+        };
+        Inet::new(addr, raw[1])
+    }
+
+    pub const fn binary_size() -> usize {
+        // Family, prefix length and the widest address.
+        1 + 1 + 16
+    }
+
+    fn is_same_family(&self, other: &Inet) -> bool {
+        self.addr.is_ipv4() == other.addr.is_ipv4()
+    }
+
+    /// Reports whether the first `len` bits of the addresses are equal.
+    fn shares_prefix(&self, other: &Inet, len: u8) -> bool {
+        self.bits() & mask(len) == other.bits() & mask(len)
+    }
+
+    /// Returns the bits of the address, left-aligned so that the same prefix
+    /// length masks IPv4 and IPv6 addresses alike.
+    fn bits(&self) -> u128 {
+        match self.addr {
+            IpAddr::V4(addr) => u128::from(u32::from(addr)) << 96,
+            IpAddr::V6(addr) => u128::from(addr),
+        }
+    }
+
+    /// The inverse of [`Inet::bits`] for addresses of this value's family.
+    fn with_bits(&self, bits: u128) -> IpAddr {
+        match self.addr {
+            IpAddr::V4(_) => {
+                let bits = u32::try_from(bits >> 96).expect("shifted into range");
+                IpAddr::V4(Ipv4Addr::from(bits))
+            }
+            IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+        }
+    }
+}
+
+/// A Rust type representing a `cidr` value, i.e. an [`Inet`] with no bits set
+/// to the right of its netmask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cidr(pub Inet);
+
+fn max_prefix_len(addr: IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+/// Returns a mask of the leftmost `len` bits of a left-aligned address.
+fn mask(len: u8) -> u128 {
+    match len {
+        0 => 0,
+        len => u128::MAX << (128 - u32::from(len)),
+    }
+}
+
+impl Ord for Inet {
+    fn cmp(&self, other: &Inet) -> Ordering {
+        let common_len = self.prefix_len.min(other.prefix_len);
+        self.addr
+            .is_ipv6()
+            .cmp(&other.addr.is_ipv6())
+            .then_with(|| (self.bits() & mask(common_len)).cmp(&(other.bits() & mask(common_len))))
+            .then_with(|| self.prefix_len.cmp(&other.prefix_len))
+            .then_with(|| self.bits().cmp(&other.bits()))
+    }
+}
+
+impl PartialOrd for Inet {
+    fn partial_cmp(&self, other: &Inet) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for Inet {
+    type Err = Error;
+
+    /// Parses an address with an optional `/prefix_len` suffix. Addresses
+    /// without a suffix refer to a single host.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, prefix_len) = match s.split_once('/') {
+            Some((addr, prefix_len)) => (addr, Some(prefix_len)),
+            None => (s, None),
+        };
+        let addr: IpAddr = addr
+            .parse()
+            .map_err(|_| anyhow!("invalid address {}", addr.quoted()))?;
+        match prefix_len {
+            Some(prefix_len) => {
+                let prefix_len = prefix_len
+                    .parse()
+                    .map_err(|_| anyhow!("invalid mask length {}", prefix_len.quoted()))?;
+                Inet::new(addr, prefix_len)
+            }
+            None => Ok(Inet::host(addr)),
+        }
+    }
+}
+
+impl fmt::Display for Inet {
+    /// Formats the value like PostgreSQL formats `inet` values, which omits
+    /// the prefix length of single hosts.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.addr)?;
+        if self.prefix_len != self.max_prefix_len() {
+            write!(f, "/{}", self.prefix_len)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Cidr {
+    /// Formats the value like PostgreSQL formats `cidr` values, which always
+    /// includes the prefix length.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.0.addr, self.0.prefix_len)
+    }
+}
+
+impl RustType<ProtoInet> for Inet {
+    fn into_proto(&self) -> ProtoInet {
+        let addr = match self.addr {
+            IpAddr::V4(addr) => addr.octets().to_vec(),
+            IpAddr::V6(addr) => addr.octets().to_vec(),
+        };
+        ProtoInet {
+            addr,
+            prefix_len: u32::from(self.prefix_len),
+        }
+    }
+
+    fn from_proto(proto: ProtoInet) -> Result<Self, TryFromProtoError> {
+        let addr = if let Ok(octets) = <[u8; 4]>::try_from(&proto.addr[..]) {
+            IpAddr::V4(Ipv4Addr::from(octets))
+        } else if let Ok(octets) = <[u8; 16]>::try_from(&proto.addr[..]) {
+            IpAddr::V6(Ipv6Addr::from(octets))
+        } else {
+            return Err(TryFromProtoError::InvalidFieldError(format!(
+                "invalid address length {}",
+                proto.addr.len()
+            )));
+        };
+        let prefix_len = u8::try_from(proto.prefix_len)?;
+        Inet::new(addr, prefix_len).map_err(|e| TryFromProtoError::InvalidFieldError(e.to_string()))
+    }
+}
+
+impl Columnation for Inet {
+    type InnerRegion = CloneRegion<Inet>;
+}
+
+impl Arbitrary for Inet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Inet>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<IpAddr>()
+            .prop_flat_map(|addr| {
+                (0..=max_prefix_len(addr)).prop_map(move |prefix_len| Inet { addr, prefix_len })
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn inet(s: &str) -> Inet {
+        s.parse().unwrap()
+    }
+
+    #[mz_ore::test]
+    fn test_parse_and_display() {
+        for s in [
+            "192.168.1.5",
+            "192.168.1.5/24",
+            "10.0.0.0/8",
+            "::1",
+            "2001:db8::/32",
+        ] {
+            assert_eq!(inet(s).to_string(), s);
+        }
+        assert_eq!(inet("192.168.1.5/32").to_string(), "192.168.1.5");
+        assert!("192.168.1.5/33".parse::<Inet>().is_err());
+        assert!("192.168.1/24".parse::<Inet>().is_err());
+        assert!("::1/129".parse::<Inet>().is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_network() {
+        assert!(!inet("192.168.1.5/24").is_network());
+        assert!(inet("192.168.1.0/24").is_network());
+        assert!(inet("0.0.0.0/0").is_network());
+        assert_eq!(inet("192.168.1.5/24").network(), inet("192.168.1.0/24"));
+        assert_eq!(inet("2001:db8::1/32").network(), inet("2001:db8::/32"));
+    }
+
+    #[mz_ore::test]
+    fn test_containment() {
+        let net = inet("192.168.1.0/24");
+        assert!(net.contains(&inet("192.168.1.5")));
+        assert!(!net.contains(&net));
+        assert!(net.contains_or_equals(&net));
+        assert!(!net.contains(&inet("192.168.2.5")));
+        assert!(!net.contains(&inet("::ffff:192.168.1.5")));
+        assert!(net.overlaps(&inet("192.168.0.0/16")));
+        assert!(!net.overlaps(&inet("192.168.2.0/24")));
+    }
+
+    #[mz_ore::test]
+    fn test_ordering() {
+        // Matches the order PostgreSQL produces for the same values.
+        let sorted = [
+            "0.0.0.0/0",
+            "10.0.0.0/8",
+            "10.1.2.3/8",
+            "10.0.0.0",
+            "192.168.1.0/24",
+            "192.168.1.5/24",
+            "192.168.1.5",
+            "::/0",
+            "::1",
+        ];
+        for pair in sorted.windows(2) {
+            assert!(inet(pair[0]) < inet(pair[1]), "{} < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_inet_binary_size() {
+        assert_eq!(18, Inet::binary_size());
+        assert!(Inet::decode_binary(&[4, 33, 10, 0, 0, 0]).is_err());
+    }
+
+    proptest! {
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn inet_binary_roundtrip(expect in any::<Inet>()) {
+            let actual = Inet::decode_binary(&expect.encode_binary());
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+
+        #[mz_ore::test]
+        #[cfg_attr(miri, ignore)] // too slow
+        fn inet_protobuf_roundtrip(expect in any::<Inet>()) {
+            let actual = protobuf_roundtrip::<_, ProtoInet>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+}
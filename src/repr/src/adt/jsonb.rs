@@ -212,6 +212,29 @@ impl<'a> JsonbRef<'a> {
         serde_json::to_value(JsonbDatum(self.datum))
             .expect("conversion to serde_json::Value known to be valid")
     }
+
+    /// Packs into `packer` a copy of this JSON value with `edit` applied to the
+    /// item at `path`.
+    ///
+    /// Each element of `path` is an object key or, for arrays, an element
+    /// index, where negative indices count back from the end of the array. If
+    /// an intermediate item on the path does not exist, the copy is unchanged.
+    ///
+    /// If an error is returned, the state of `packer` is unspecified.
+    pub fn pack_edited(
+        &self,
+        packer: &mut RowPacker,
+        path: &[Option<&str>],
+        edit: JsonbEdit,
+    ) -> Result<(), JsonbEditError> {
+        if !matches!(self.datum, Datum::List(_) | Datum::Map(_)) {
+            return Err(match edit {
+                JsonbEdit::Delete => JsonbEditError::DeleteInScalar,
+                JsonbEdit::Set { .. } | JsonbEdit::Insert { .. } => JsonbEditError::SetInScalar,
+            });
+        }
+        pack_edited(packer, self.datum, path, 0, edit)
+    }
 }
 
 impl fmt::Display for JsonbRef<'_> {
@@ -226,6 +249,196 @@ impl fmt::Display for JsonbRef<'_> {
     }
 }
 
+/// An edit to the item at a path within a JSON value.
+///
+/// See [`JsonbRef::pack_edited`].
+#[derive(Debug, Clone, Copy)]
+pub enum JsonbEdit<'a> {
+    /// Replaces the item with `value`. If `create_missing` is set and the
+    /// item's object key or array index does not exist, `value` is added to
+    /// the object or array instead; a missing array index adds `value` to the
+    /// start or end of the array.
+    Set {
+        value: Datum<'a>,
+        create_missing: bool,
+    },
+    /// Inserts `value` into the array before the item, or after it if `after`
+    /// is set, or adds `value` to the object if the item's key does not exist.
+    Insert { value: Datum<'a>, after: bool },
+    /// Removes the item.
+    Delete,
+}
+
+impl JsonbEdit<'_> {
+    fn creates_missing(&self) -> bool {
+        match self {
+            JsonbEdit::Set { create_missing, .. } => *create_missing,
+            JsonbEdit::Insert { .. } => true,
+            JsonbEdit::Delete => false,
+        }
+    }
+}
+
+/// An error while editing a JSON value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonbEditError {
+    /// The value to set or insert into is a scalar.
+    SetInScalar,
+    /// The value to delete from is a scalar.
+    DeleteInScalar,
+    /// The path element at the given 1-based position is null.
+    NullPathElement(usize),
+    /// The path element at the given 1-based position indexes an array but is
+    /// not an integer.
+    NonIntegerPathElement(usize, String),
+    /// An insertion's path refers to an object key that already exists.
+    KeyExists,
+}
+
+impl fmt::Display for JsonbEditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonbEditError::SetInScalar => f.write_str("cannot set path in scalar"),
+            JsonbEditError::DeleteInScalar => f.write_str("cannot delete path in scalar"),
+            JsonbEditError::NullPathElement(pos) => {
+                write!(f, "path element at position {} is null", pos)
+            }
+            JsonbEditError::NonIntegerPathElement(pos, elem) => write!(
+                f,
+                "path element at position {} is not an integer: \"{}\"",
+                pos, elem
+            ),
+            JsonbEditError::KeyExists => f.write_str("cannot replace existing key"),
+        }
+    }
+}
+
+impl std::error::Error for JsonbEditError {}
+
+/// Packs a copy of `datum`, which is found at `path[..level]`, with `edit`
+/// applied to the item at `path`.
+fn pack_edited(
+    packer: &mut RowPacker,
+    datum: Datum,
+    path: &[Option<&str>],
+    level: usize,
+    edit: JsonbEdit,
+) -> Result<(), JsonbEditError> {
+    let key = match path.get(level) {
+        None => {
+            packer.push(datum);
+            return Ok(());
+        }
+        Some(Some(key)) => *key,
+        // Path elements are reported to users by 1-based position.
+        Some(None) => return Err(JsonbEditError::NullPathElement(level + 1)),
+    };
+    let last = level + 1 == path.len();
+    match datum {
+        Datum::Map(dict) => packer.push_dict_with(|packer| {
+            let mut done = false;
+            for (k, v) in dict.iter() {
+                if !done && last && edit.creates_missing() && k > key {
+                    // Keys are sorted, so the item's key does not exist.
+                    add_value(packer, key, edit);
+                    done = true;
+                }
+                if k != key {
+                    packer.push(Datum::String(k));
+                    packer.push(v);
+                    continue;
+                }
+                done = true;
+                if !last {
+                    packer.push(Datum::String(k));
+                    pack_edited(packer, v, path, level + 1, edit)?;
+                    continue;
+                }
+                match edit {
+                    JsonbEdit::Set { value, .. } => {
+                        packer.push(Datum::String(k));
+                        packer.push(value);
+                    }
+                    JsonbEdit::Insert { .. } => return Err(JsonbEditError::KeyExists),
+                    JsonbEdit::Delete => (),
+                }
+            }
+            if !done && last && edit.creates_missing() {
+                add_value(packer, key, edit);
+            }
+            Ok(())
+        }),
+        Datum::List(list) => {
+            let index = match key.parse::<i32>() {
+                Ok(index) => i64::from(index),
+                Err(_) => {
+                    return Err(JsonbEditError::NonIntegerPathElement(
+                        level + 1,
+                        key.to_owned(),
+                    ))
+                }
+            };
+            let len = i64::try_from(list.iter().count()).expect("list length fits in i64");
+            // An index before the start of the array is `None`.
+            let index = match index {
+                i if i >= 0 => Some(i),
+                i if -i <= len => Some(len + i),
+                _ => None,
+            };
+            let creates = last && edit.creates_missing();
+            packer.push_list_with(|packer| {
+                if creates && (len == 0 || index.is_none()) {
+                    packer.push(edit_value(edit));
+                }
+                for (i, elem) in (0..).zip(list.iter()) {
+                    if Some(i) != index {
+                        packer.push(elem);
+                    } else if !last {
+                        pack_edited(packer, elem, path, level + 1, edit)?;
+                    } else {
+                        match edit {
+                            JsonbEdit::Set { value, .. } => packer.push(value),
+                            JsonbEdit::Insert {
+                                value,
+                                after: false,
+                            } => {
+                                packer.push(value);
+                                packer.push(elem);
+                            }
+                            JsonbEdit::Insert { value, after: true } => {
+                                packer.push(elem);
+                                packer.push(value);
+                            }
+                            JsonbEdit::Delete => (),
+                        }
+                    }
+                }
+                if creates && len > 0 && index.map_or(false, |i| i >= len) {
+                    packer.push(edit_value(edit));
+                }
+                Ok(())
+            })
+        }
+        // Scalars within the value are left unchanged.
+        _ => {
+            packer.push(datum);
+            Ok(())
+        }
+    }
+}
+
+fn add_value(packer: &mut RowPacker, key: &str, edit: JsonbEdit) {
+    packer.push(Datum::String(key));
+    packer.push(edit_value(edit));
+}
+
+fn edit_value(edit: JsonbEdit) -> Datum {
+    match edit {
+        JsonbEdit::Set { value, .. } | JsonbEdit::Insert { value, .. } => value,
+        JsonbEdit::Delete => unreachable!("deletions do not add values"),
+    }
+}
+
 /// A JSON deserializer that decodes directly into an existing [`RowPacker`].
 #[derive(Debug)]
 pub struct JsonbPacker<'a, 'row> {
@@ -0,0 +1,1606 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The SQL/JSON path language.
+//!
+//! A [`JsonPath`] is parsed from the syntax described by the SQL standard and
+//! implemented by PostgreSQL, e.g. `strict $.events[*] ? (@.level > 2).name`,
+//! and evaluated against the JSON [`Datum`]s of a
+//! [`Jsonb`](crate::adt::jsonb::Jsonb) value.
+//!
+//! Evaluating a path produces a sequence of JSON items. A path is evaluated in
+//! either lax mode, the default, or strict mode. In lax mode, arrays are
+//! implicitly unwrapped or wrapped to suit the accessor applied to them, and
+//! structural errors, like a reference to a missing key, select no items
+//! rather than raising an error.
+//!
+//! The `keyvalue()` and `datetime()` item methods are not supported.
+
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use dec::Rounding;
+use proptest::prelude::Strategy;
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use mz_proto::{RustType, TryFromProtoError};
+
+use crate::adt::numeric::{self, Numeric};
+use crate::Datum;
+
+/// A parsed SQL/JSON path expression.
+///
+/// A path remembers the text it was parsed from, which is how it is compared,
+/// hashed, and serialized.
+#[derive(Debug, Clone)]
+pub struct JsonPath {
+    source: String,
+    strict: bool,
+    expr: Expr,
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    /// `$`, the item the path is evaluated against.
+    Root,
+    /// `@`, the item being tested by a filter.
+    Current,
+    /// `last`, the last index of the array being subscripted.
+    Last,
+    /// `$name`, a variable supplied alongside the path.
+    Variable(String),
+    Null,
+    Bool(bool),
+    Number(Numeric),
+    String(String),
+    Accessor(Box<Expr>, Accessor),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Compare(CompareOp, Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    IsUnknown(Box<Expr>),
+    Exists(Box<Expr>),
+    LikeRegex(Box<Expr>, Regex),
+    StartsWith(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    /// Reports whether the expression is a predicate, which evaluates to
+    /// true, false, or unknown, rather than to a sequence of items.
+    fn is_predicate(&self) -> bool {
+        matches!(
+            self,
+            Expr::Compare(..)
+                | Expr::And(..)
+                | Expr::Or(..)
+                | Expr::Not(..)
+                | Expr::IsUnknown(..)
+                | Expr::Exists(..)
+                | Expr::LikeRegex(..)
+                | Expr::StartsWith(..)
+        )
+    }
+
+    /// Reports whether the expression is an accessor chain that includes a
+    /// `.**` accessor.
+    fn follows_descendants(&self) -> bool {
+        match self {
+            Expr::Accessor(_, Accessor::Descendants { .. }) => true,
+            Expr::Accessor(base, _) => base.follows_descendants(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Accessor {
+    /// `.key`
+    Member(String),
+    /// `.*`
+    MemberWildcard,
+    /// `[*]`
+    ElementWildcard,
+    /// `[i, j to k, ...]`
+    Elements(Vec<(Expr, Option<Expr>)>),
+    /// `.**{first to last}`, where [`LAST_LEVEL`] stands for `last`.
+    Descendants {
+        first: u32,
+        last: u32,
+    },
+    /// `? (predicate)`
+    Filter(Box<Expr>),
+    Method(Method),
+}
+
+const LAST_LEVEL: u32 = u32::MAX;
+
+#[derive(Debug, Clone, Copy)]
+enum Method {
+    Type,
+    Size,
+    Double,
+    Ceiling,
+    Floor,
+    Abs,
+}
+
+impl Method {
+    fn name(&self) -> &'static str {
+        match self {
+            Method::Type => "type",
+            Method::Size => "size",
+            Method::Double => "double",
+            Method::Ceiling => "ceiling",
+            Method::Floor => "floor",
+            Method::Abs => "abs",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum UnaryOp {
+    Plus,
+    Minus,
+}
+
+impl fmt::Display for UnaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UnaryOp::Plus => f.write_str("+"),
+            UnaryOp::Minus => f.write_str("-"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinaryOp::Add => f.write_str("+"),
+            BinaryOp::Sub => f.write_str("-"),
+            BinaryOp::Mul => f.write_str("*"),
+            BinaryOp::Div => f.write_str("/"),
+            BinaryOp::Mod => f.write_str("%"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    Lte,
+    Gt,
+    Gte,
+}
+
+/// The result of a predicate, which is unknown if its operands could not be
+/// evaluated or compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tri {
+    True,
+    False,
+    Unknown,
+}
+
+impl From<bool> for Tri {
+    fn from(b: bool) -> Tri {
+        if b {
+            Tri::True
+        } else {
+            Tri::False
+        }
+    }
+}
+
+/// An error that occurs while parsing or evaluating a [`JsonPath`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsonPathError {
+    /// The path is not a valid SQL/JSON path expression.
+    Syntax(String),
+    /// The path refers to a variable that was not supplied.
+    UndefinedVariable(String),
+    /// The variables supplied to the path are not a JSON object.
+    VarsNotObject,
+    /// The path could not be evaluated against an item, e.g. because an
+    /// accessor was applied to an item of the wrong type in strict mode or
+    /// because of an arithmetic error.
+    Item(String),
+}
+
+impl JsonPathError {
+    /// Reports whether the error is one that the `silent` argument of the
+    /// jsonpath functions, and the `@?` and `@@` operators, suppress.
+    pub fn is_suppressible(&self) -> bool {
+        matches!(self, JsonPathError::Item(_))
+    }
+}
+
+impl fmt::Display for JsonPathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JsonPathError::Syntax(msg) | JsonPathError::Item(msg) => f.write_str(msg),
+            JsonPathError::UndefinedVariable(name) => {
+                write!(f, "could not find jsonpath variable \"{}\"", name)
+            }
+            JsonPathError::VarsNotObject => f.write_str("\"vars\" argument is not an object"),
+        }
+    }
+}
+
+impl Error for JsonPathError {}
+
+fn item_error(msg: impl Into<String>) -> JsonPathError {
+    JsonPathError::Item(msg.into())
+}
+
+impl FromStr for JsonPath {
+    type Err = JsonPathError;
+
+    fn from_str(s: &str) -> Result<JsonPath, JsonPathError> {
+        let tokens = lex(s)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            filter_depth: 0,
+            subscript_depth: 0,
+        };
+        let (strict, expr) = parser.parse_path()?;
+        Ok(JsonPath {
+            source: s.to_owned(),
+            strict,
+            expr,
+        })
+    }
+}
+
+impl PartialEq for JsonPath {
+    fn eq(&self, other: &JsonPath) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for JsonPath {}
+
+impl PartialOrd for JsonPath {
+    fn partial_cmp(&self, other: &JsonPath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonPath {
+    fn cmp(&self, other: &JsonPath) -> Ordering {
+        self.source.cmp(&other.source)
+    }
+}
+
+impl Hash for JsonPath {
+    fn hash<H: Hasher>(&self, hasher: &mut H) {
+        self.source.hash(hasher)
+    }
+}
+
+impl Serialize for JsonPath {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.source)
+    }
+}
+
+impl<'de> Deserialize<'de> for JsonPath {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<JsonPath, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        source.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl RustType<String> for JsonPath {
+    fn into_proto(&self) -> String {
+        self.source.clone()
+    }
+
+    fn from_proto(proto: String) -> Result<Self, TryFromProtoError> {
+        proto
+            .parse()
+            .map_err(|e: JsonPathError| TryFromProtoError::InvalidFieldError(e.to_string()))
+    }
+}
+
+/// Generates one of a fixed set of paths that exercise the different parts of
+/// the path language.
+pub fn any_jsonpath() -> impl Strategy<Value = JsonPath> {
+    proptest::sample::select(vec![
+        "$",
+        "$.a[last]",
+        "strict $.**.b",
+        "$.a[*] ? (@ > $x)",
+        "$.c.d like_regex \"X\" flag \"i\"",
+        "exists($.a ? (@ == 2))",
+    ])
+    .prop_map(|source| source.parse().expect("valid jsonpath"))
+}
+
+impl JsonPath {
+    /// Returns the text that the path was parsed from.
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Returns the items that the path selects from `target`.
+    ///
+    /// If supplied, `vars` must be a JSON object whose fields provide the
+    /// values of the variables that the path refers to.
+    pub fn query<'a>(
+        &'a self,
+        target: Datum<'a>,
+        vars: Option<Datum<'a>>,
+    ) -> Result<Vec<Datum<'a>>, JsonPathError> {
+        if let Some(vars) = vars {
+            if !matches!(vars, Datum::Map(_)) {
+                return Err(JsonPathError::VarsNotObject);
+            }
+        }
+        let evaluator = Evaluator {
+            root: target,
+            vars,
+            strict: self.strict,
+        };
+        let scope = Scope {
+            current: target,
+            last: None,
+        };
+        evaluator.eval(&self.expr, scope)
+    }
+
+    /// Reports whether the path selects any items from `target`.
+    pub fn exists<'a>(
+        &'a self,
+        target: Datum<'a>,
+        vars: Option<Datum<'a>>,
+    ) -> Result<bool, JsonPathError> {
+        Ok(!self.query(target, vars)?.is_empty())
+    }
+
+    /// Returns the result of a path that is a predicate, i.e. one that selects
+    /// a single boolean from `target`, or `None` if the predicate is unknown.
+    pub fn matches<'a>(
+        &'a self,
+        target: Datum<'a>,
+        vars: Option<Datum<'a>>,
+    ) -> Result<Option<bool>, JsonPathError> {
+        match self.query(target, vars)?.as_slice() {
+            [Datum::True] => Ok(Some(true)),
+            [Datum::False] => Ok(Some(false)),
+            [Datum::JsonNull] => Ok(None),
+            _ => Err(item_error("single boolean result is expected")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Variable(String),
+    String(String),
+    Number(String),
+    Punct(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Token::Ident(s) | Token::Number(s) => f.write_str(s),
+            Token::Variable(s) => write!(f, "${}", s),
+            Token::String(s) => write!(f, "\"{}\"", s),
+            Token::Punct(s) => f.write_str(s),
+        }
+    }
+}
+
+const PUNCTUATION: &[&str] = &[
+    "==", "!=", "<>", "<=", ">=", "&&", "||", "<", ">", "!", "+", "-", "*", "/", "%", "(", ")",
+    "[", "]", "{", "}", ",", ".", "?", "$", "@",
+];
+
+fn syntax_error(near: Option<&Token>) -> JsonPathError {
+    JsonPathError::Syntax(match near {
+        Some(token) => format!("syntax error at or near \"{}\" of jsonpath input", token),
+        None => "syntax error at end of jsonpath input".into(),
+    })
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_alphabetic() || c == '_'
+}
+
+fn is_ident_continue(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn lex(s: &str) -> Result<Vec<Token>, JsonPathError> {
+    let mut tokens = vec![];
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c == '"' {
+            let (string, remainder) = lex_string(&rest[1..])?;
+            tokens.push(Token::String(string));
+            rest = remainder;
+        } else if c.is_ascii_digit() {
+            let len = number_len(rest);
+            tokens.push(Token::Number(rest[..len].into()));
+            rest = &rest[len..];
+        } else if is_ident_start(c) {
+            let len = ident_len(rest);
+            tokens.push(Token::Ident(rest[..len].into()));
+            rest = &rest[len..];
+        } else if c == '$' && rest[1..].starts_with('"') {
+            let (name, remainder) = lex_string(&rest[2..])?;
+            tokens.push(Token::Variable(name));
+            rest = remainder;
+        } else if c == '$' && rest[1..].starts_with(is_ident_start) {
+            let len = ident_len(&rest[1..]);
+            tokens.push(Token::Variable(rest[1..=len].into()));
+            rest = &rest[1 + len..];
+        } else {
+            let punct = PUNCTUATION
+                .iter()
+                .find(|p| rest.starts_with(**p))
+                .ok_or_else(|| syntax_error(Some(&Token::Ident(c.to_string()))))?;
+            tokens.push(Token::Punct(punct));
+            rest = &rest[punct.len()..];
+        }
+    }
+    Ok(tokens)
+}
+
+fn ident_len(s: &str) -> usize {
+    s.find(|c| !is_ident_continue(c)).unwrap_or(s.len())
+}
+
+fn number_len(s: &str) -> usize {
+    let digits_len = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let mut len = digits_len(s);
+    if s[len..].starts_with('.') && s[len + 1..].starts_with(|c: char| c.is_ascii_digit()) {
+        len += 1 + digits_len(&s[len + 1..]);
+    }
+    if s[len..].starts_with(|c| c == 'e' || c == 'E') {
+        let mut exponent = len + 1;
+        if s[exponent..].starts_with(|c| c == '+' || c == '-') {
+            exponent += 1;
+        }
+        let exponent_len = digits_len(&s[exponent..]);
+        if exponent_len > 0 {
+            len = exponent + exponent_len;
+        }
+    }
+    len
+}
+
+/// Lexes a double-quoted string, whose opening quote has already been
+/// consumed, returning its value and the input that follows it.
+fn lex_string(s: &str) -> Result<(String, &str), JsonPathError> {
+    let unterminated =
+        || JsonPathError::Syntax("unterminated quoted string in jsonpath input".into());
+    let mut out = String::new();
+    let mut chars = s.char_indices();
+    loop {
+        let (i, c) = chars.next().ok_or_else(unterminated)?;
+        match c {
+            '"' => return Ok((out, &s[i + 1..])),
+            '\\' => match chars.next().ok_or_else(unterminated)?.1 {
+                'b' => out.push('\u{8}'),
+                'f' => out.push('\u{c}'),
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                'v' => out.push('\u{b}'),
+                'u' => {
+                    let invalid = || {
+                        JsonPathError::Syntax(
+                            "invalid Unicode escape sequence in jsonpath input".into(),
+                        )
+                    };
+                    let mut code = 0;
+                    for _ in 0..4 {
+                        let (_, digit) = chars.next().ok_or_else(invalid)?;
+                        code = code * 16 + digit.to_digit(16).ok_or_else(invalid)?;
+                    }
+                    out.push(char::from_u32(code).ok_or_else(invalid)?);
+                }
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// The number of filters enclosing the current position, within which `@`
+    /// is permitted.
+    filter_depth: usize,
+    /// The number of array subscripts enclosing the current position, within
+    /// which `last` is permitted.
+    subscript_depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_nth(&self, n: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + n)
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn error(&self) -> JsonPathError {
+        syntax_error(self.peek())
+    }
+
+    fn consume_punct(&mut self, punct: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Punct(p)) if *p == punct) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<(), JsonPathError> {
+        if self.consume_punct(punct) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Ident(i)) if i == keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), JsonPathError> {
+        if self.consume_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn expect_string(&mut self) -> Result<String, JsonPathError> {
+        match self.peek() {
+            Some(Token::String(s)) => {
+                let s = s.clone();
+                self.pos += 1;
+                Ok(s)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    fn parse_path(&mut self) -> Result<(bool, Expr), JsonPathError> {
+        let strict = if self.consume_keyword("strict") {
+            true
+        } else {
+            self.consume_keyword("lax");
+            false
+        };
+        let expr = self.parse_or()?;
+        if self.peek().is_some() {
+            return Err(self.error());
+        }
+        Ok((strict, expr))
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_and()?;
+        while self.consume_punct("||") {
+            let right = self.parse_and()?;
+            expr = Expr::Or(
+                Box::new(self.require_predicate(expr)?),
+                Box::new(self.require_predicate(right)?),
+            );
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_not()?;
+        while self.consume_punct("&&") {
+            let right = self.parse_not()?;
+            expr = Expr::And(
+                Box::new(self.require_predicate(expr)?),
+                Box::new(self.require_predicate(right)?),
+            );
+        }
+        Ok(expr)
+    }
+
+    fn parse_not(&mut self) -> Result<Expr, JsonPathError> {
+        if self.consume_punct("!") {
+            let operand = match self.peek() {
+                Some(Token::Punct("(")) => self.parse_primary()?,
+                Some(Token::Ident(i)) if i == "exists" => self.parse_predicate()?,
+                _ => return Err(self.error()),
+            };
+            Ok(Expr::Not(Box::new(self.require_predicate(operand)?)))
+        } else {
+            self.parse_predicate()
+        }
+    }
+
+    fn parse_predicate(&mut self) -> Result<Expr, JsonPathError> {
+        if self.consume_keyword("exists") {
+            self.expect_punct("(")?;
+            let operand = self.parse_or()?;
+            self.expect_punct(")")?;
+            let operand = self.require_expression(operand)?;
+            return Ok(Expr::Exists(Box::new(operand)));
+        }
+        let left = self.parse_additive()?;
+        if left.is_predicate() {
+            if self.consume_keyword("is") {
+                self.expect_keyword("unknown")?;
+                return Ok(Expr::IsUnknown(Box::new(left)));
+            }
+            return Ok(left);
+        }
+        let op = match self.peek() {
+            Some(Token::Punct("==")) => Some(CompareOp::Eq),
+            Some(Token::Punct("!=" | "<>")) => Some(CompareOp::NotEq),
+            Some(Token::Punct("<")) => Some(CompareOp::Lt),
+            Some(Token::Punct("<=")) => Some(CompareOp::Lte),
+            Some(Token::Punct(">")) => Some(CompareOp::Gt),
+            Some(Token::Punct(">=")) => Some(CompareOp::Gte),
+            _ => None,
+        };
+        if let Some(op) = op {
+            self.pos += 1;
+            let right = self.parse_additive()?;
+            let right = self.require_expression(right)?;
+            return Ok(Expr::Compare(op, Box::new(left), Box::new(right)));
+        }
+        if self.consume_keyword("like_regex") {
+            let pattern = self.expect_string()?;
+            let flags = if self.consume_keyword("flag") {
+                self.expect_string()?
+            } else {
+                String::new()
+            };
+            let regex = build_like_regex(&pattern, &flags)?;
+            return Ok(Expr::LikeRegex(Box::new(left), regex));
+        }
+        if self.consume_keyword("starts") {
+            self.expect_keyword("with")?;
+            let prefix = match self.next_token() {
+                Some(Token::String(s)) => Expr::String(s),
+                Some(Token::Variable(name)) => Expr::Variable(name),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.error());
+                }
+            };
+            return Ok(Expr::StartsWith(Box::new(left), Box::new(prefix)));
+        }
+        Ok(left)
+    }
+
+    fn parse_additive(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = if self.consume_punct("+") {
+                BinaryOp::Add
+            } else if self.consume_punct("-") {
+                BinaryOp::Sub
+            } else {
+                return Ok(expr);
+            };
+            let right = self.parse_multiplicative()?;
+            expr = Expr::Binary(
+                op,
+                Box::new(self.require_expression(expr)?),
+                Box::new(self.require_expression(right)?),
+            );
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = if self.consume_punct("*") {
+                BinaryOp::Mul
+            } else if self.consume_punct("/") {
+                BinaryOp::Div
+            } else if self.consume_punct("%") {
+                BinaryOp::Mod
+            } else {
+                return Ok(expr);
+            };
+            let right = self.parse_unary()?;
+            expr = Expr::Binary(
+                op,
+                Box::new(self.require_expression(expr)?),
+                Box::new(self.require_expression(right)?),
+            );
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, JsonPathError> {
+        let op = if self.consume_punct("+") {
+            UnaryOp::Plus
+        } else if self.consume_punct("-") {
+            UnaryOp::Minus
+        } else {
+            return self.parse_accessors();
+        };
+        let operand = self.parse_unary()?;
+        Ok(Expr::Unary(op, Box::new(self.require_expression(operand)?)))
+    }
+
+    fn parse_accessors(&mut self) -> Result<Expr, JsonPathError> {
+        let mut expr = self.parse_primary()?;
+        loop {
+            let accessor = if self.consume_punct(".") {
+                self.parse_dot_accessor()?
+            } else if self.consume_punct("[") {
+                self.parse_subscripts()?
+            } else if self.consume_punct("?") {
+                self.expect_punct("(")?;
+                self.filter_depth += 1;
+                let predicate = self.parse_or()?;
+                self.filter_depth -= 1;
+                self.expect_punct(")")?;
+                Accessor::Filter(Box::new(self.require_predicate(predicate)?))
+            } else {
+                return Ok(expr);
+            };
+            expr = Expr::Accessor(Box::new(self.require_expression(expr)?), accessor);
+        }
+    }
+
+    fn parse_dot_accessor(&mut self) -> Result<Accessor, JsonPathError> {
+        match self.next_token() {
+            Some(Token::Punct("*")) => {
+                if !self.consume_punct("*") {
+                    return Ok(Accessor::MemberWildcard);
+                }
+                let (first, last) = if self.consume_punct("{") {
+                    let first = self.parse_level()?;
+                    let last = if self.consume_keyword("to") {
+                        self.parse_level()?
+                    } else {
+                        first
+                    };
+                    self.expect_punct("}")?;
+                    (first, last)
+                } else {
+                    (0, LAST_LEVEL)
+                };
+                Ok(Accessor::Descendants { first, last })
+            }
+            Some(Token::Ident(name)) => {
+                if matches!(self.peek(), Some(Token::Punct("("))) {
+                    let method = match name.as_str() {
+                        "type" => Method::Type,
+                        "size" => Method::Size,
+                        "double" => Method::Double,
+                        "ceiling" => Method::Ceiling,
+                        "floor" => Method::Floor,
+                        "abs" => Method::Abs,
+                        "keyvalue" | "datetime" => {
+                            return Err(JsonPathError::Syntax(format!(
+                                "jsonpath item method .{}() is not supported",
+                                name
+                            )))
+                        }
+                        _ => {
+                            self.pos -= 1;
+                            return Err(self.error());
+                        }
+                    };
+                    self.expect_punct("(")?;
+                    self.expect_punct(")")?;
+                    Ok(Accessor::Method(method))
+                } else {
+                    Ok(Accessor::Member(name))
+                }
+            }
+            Some(Token::String(name)) => Ok(Accessor::Member(name)),
+            _ => {
+                self.pos -= 1;
+                Err(self.error())
+            }
+        }
+    }
+
+    fn parse_level(&mut self) -> Result<u32, JsonPathError> {
+        if self.consume_keyword("last") {
+            return Ok(LAST_LEVEL);
+        }
+        match self.peek() {
+            Some(Token::Number(n)) => match n.parse::<u32>() {
+                Ok(level) if level < LAST_LEVEL => {
+                    self.pos += 1;
+                    Ok(level)
+                }
+                _ => Err(self.error()),
+            },
+            _ => Err(self.error()),
+        }
+    }
+
+    /// Parses the subscripts of an array accessor, whose opening bracket has
+    /// already been consumed.
+    fn parse_subscripts(&mut self) -> Result<Accessor, JsonPathError> {
+        if matches!(self.peek(), Some(Token::Punct("*")))
+            && matches!(self.peek_nth(1), Some(Token::Punct("]")))
+        {
+            self.pos += 2;
+            return Ok(Accessor::ElementWildcard);
+        }
+        self.subscript_depth += 1;
+        let mut subscripts = vec![];
+        loop {
+            let from = self.parse_or()?;
+            let from = self.require_expression(from)?;
+            let to = if self.consume_keyword("to") {
+                let to = self.parse_or()?;
+                Some(self.require_expression(to)?)
+            } else {
+                None
+            };
+            subscripts.push((from, to));
+            if !self.consume_punct(",") {
+                break;
+            }
+        }
+        self.subscript_depth -= 1;
+        self.expect_punct("]")?;
+        Ok(Accessor::Elements(subscripts))
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, JsonPathError> {
+        let token = match self.next_token() {
+            Some(token) => token,
+            None => return Err(syntax_error(None)),
+        };
+        match token {
+            Token::Punct("$") => Ok(Expr::Root),
+            Token::Punct("@") => {
+                if self.filter_depth == 0 {
+                    return Err(JsonPathError::Syntax(
+                        "@ is not allowed in root expressions".into(),
+                    ));
+                }
+                Ok(Expr::Current)
+            }
+            Token::Punct("(") => {
+                let expr = self.parse_or()?;
+                self.expect_punct(")")?;
+                Ok(expr)
+            }
+            Token::Variable(name) => Ok(Expr::Variable(name)),
+            Token::String(s) => Ok(Expr::String(s)),
+            Token::Number(n) => {
+                let mut cx = numeric::cx_datum();
+                let mut n = cx
+                    .parse(n.as_str())
+                    .map_err(|_| JsonPathError::Syntax(format!("invalid numeric literal {}", n)))?;
+                if cx.status().overflow() || !n.is_finite() {
+                    return Err(JsonPathError::Syntax("numeric literal out of range".into()));
+                }
+                numeric::munge_numeric(&mut n)
+                    .map_err(|_| JsonPathError::Syntax("numeric literal out of range".into()))?;
+                Ok(Expr::Number(n))
+            }
+            Token::Ident(ident) => match ident.as_str() {
+                "null" => Ok(Expr::Null),
+                "true" => Ok(Expr::Bool(true)),
+                "false" => Ok(Expr::Bool(false)),
+                "last" => {
+                    if self.subscript_depth == 0 {
+                        return Err(JsonPathError::Syntax(
+                            "LAST is allowed only in array subscripts".into(),
+                        ));
+                    }
+                    Ok(Expr::Last)
+                }
+                _ => {
+                    self.pos -= 1;
+                    Err(self.error())
+                }
+            },
+            Token::Punct(_) => {
+                self.pos -= 1;
+                Err(self.error())
+            }
+        }
+    }
+
+    fn require_predicate(&self, expr: Expr) -> Result<Expr, JsonPathError> {
+        if expr.is_predicate() {
+            Ok(expr)
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn require_expression(&self, expr: Expr) -> Result<Expr, JsonPathError> {
+        if expr.is_predicate() {
+            Err(self.error())
+        } else {
+            Ok(expr)
+        }
+    }
+}
+
+fn build_like_regex(pattern: &str, flags: &str) -> Result<Regex, JsonPathError> {
+    // The `q` flag requests that the pattern be matched literally.
+    let pattern = if flags.contains('q') {
+        regex::escape(pattern)
+    } else {
+        pattern.to_owned()
+    };
+    let mut builder = RegexBuilder::new(&pattern);
+    for flag in flags.chars() {
+        match flag {
+            'i' => {
+                builder.case_insensitive(true);
+            }
+            's' => {
+                builder.dot_matches_new_line(true);
+            }
+            'm' => {
+                builder.multi_line(true);
+            }
+            'x' => {
+                builder.ignore_whitespace(true);
+            }
+            'q' => (),
+            _ => {
+                return Err(JsonPathError::Syntax(format!(
+                    "unrecognized flag character \"{}\" in LIKE_REGEX predicate",
+                    flag
+                )))
+            }
+        }
+    }
+    builder
+        .build()
+        .map_err(|e| JsonPathError::Syntax(format!("invalid regular expression: {}", e)))
+}
+
+/// The item against which `@` and `last` are evaluated.
+#[derive(Debug, Clone, Copy)]
+struct Scope<'a> {
+    current: Datum<'a>,
+    last: Option<i64>,
+}
+
+struct Evaluator<'a> {
+    root: Datum<'a>,
+    vars: Option<Datum<'a>>,
+    strict: bool,
+}
+
+impl<'a> Evaluator<'a> {
+    fn eval(&self, expr: &'a Expr, scope: Scope<'a>) -> Result<Vec<Datum<'a>>, JsonPathError> {
+        match expr {
+            Expr::Root => Ok(vec![self.root]),
+            Expr::Current => Ok(vec![scope.current]),
+            Expr::Last => match scope.last {
+                Some(last) => Ok(vec![Datum::from(Numeric::from(last))]),
+                None => Err(item_error(
+                    "evaluating jsonpath LAST outside of array subscript",
+                )),
+            },
+            Expr::Variable(name) => {
+                let value = self.vars.and_then(|vars| {
+                    vars.unwrap_map()
+                        .iter()
+                        .find(|(key, _)| *key == name.as_str())
+                        .map(|(_, value)| value)
+                });
+                match value {
+                    Some(value) => Ok(vec![value]),
+                    None => Err(JsonPathError::UndefinedVariable(name.clone())),
+                }
+            }
+            Expr::Null => Ok(vec![Datum::JsonNull]),
+            Expr::Bool(b) => Ok(vec![Datum::from(*b)]),
+            Expr::Number(n) => Ok(vec![Datum::from(*n)]),
+            Expr::String(s) => Ok(vec![Datum::String(s)]),
+            Expr::Accessor(base, accessor) => {
+                // As in PostgreSQL, structural errors are ignored, even in
+                // strict mode, by the accessors that follow a `.**`.
+                let lenient = !self.strict || base.follows_descendants();
+                let mut out = vec![];
+                for item in self.eval(base, scope)? {
+                    self.apply(accessor, item, scope, true, lenient, &mut out)?;
+                }
+                Ok(out)
+            }
+            Expr::Unary(op, operand) => self
+                .eval_unwrapped(operand, scope)?
+                .into_iter()
+                .map(|item| match (op, item) {
+                    (UnaryOp::Plus, Datum::Numeric(_)) => Ok(item),
+                    (UnaryOp::Minus, Datum::Numeric(n)) => {
+                        let mut n = n.0;
+                        numeric::cx_datum().neg(&mut n);
+                        numeric::munge_numeric(&mut n).expect("negation cannot overflow");
+                        Ok(Datum::from(n))
+                    }
+                    _ => Err(item_error(format!(
+                        "operand of unary jsonpath operator {} is not a numeric value",
+                        op
+                    ))),
+                })
+                .collect(),
+            Expr::Binary(op, left, right) => {
+                let left = match self.eval_unwrapped(left, scope)?.as_slice() {
+                    [Datum::Numeric(n)] => n.0,
+                    _ => {
+                        return Err(item_error(format!(
+                            "left operand of jsonpath operator {} is not a single numeric value",
+                            op
+                        )))
+                    }
+                };
+                let right = match self.eval_unwrapped(right, scope)?.as_slice() {
+                    [Datum::Numeric(n)] => n.0,
+                    _ => {
+                        return Err(item_error(format!(
+                            "right operand of jsonpath operator {} is not a single numeric value",
+                            op
+                        )))
+                    }
+                };
+                Ok(vec![Datum::from(arithmetic(*op, left, right)?)])
+            }
+            _ => Ok(vec![match self.eval_predicate(expr, scope)? {
+                Tri::True => Datum::True,
+                Tri::False => Datum::False,
+                Tri::Unknown => Datum::JsonNull,
+            }]),
+        }
+    }
+
+    /// Like [`Evaluator::eval`], but in lax mode replaces each array in the
+    /// result with its elements.
+    fn eval_unwrapped(
+        &self,
+        expr: &'a Expr,
+        scope: Scope<'a>,
+    ) -> Result<Vec<Datum<'a>>, JsonPathError> {
+        let items = self.eval(expr, scope)?;
+        if self.strict {
+            return Ok(items);
+        }
+        let mut out = vec![];
+        for item in items {
+            match item {
+                Datum::List(list) => out.extend(list.iter()),
+                _ => out.push(item),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Applies `accessor` to `item`, appending the selected items to `out`.
+    ///
+    /// In lax mode, accessors that do not apply to arrays are instead applied
+    /// to each element of an array `item`, provided that `unwrap` is set.
+    /// Structural errors, like a reference to a missing key, select no items
+    /// if `lenient` is set.
+    fn apply(
+        &self,
+        accessor: &'a Accessor,
+        item: Datum<'a>,
+        scope: Scope<'a>,
+        unwrap: bool,
+        lenient: bool,
+        out: &mut Vec<Datum<'a>>,
+    ) -> Result<(), JsonPathError> {
+        let unwraps = !matches!(
+            accessor,
+            Accessor::ElementWildcard
+                | Accessor::Elements(_)
+                | Accessor::Descendants { .. }
+                | Accessor::Method(Method::Type | Method::Size)
+        );
+        if let Datum::List(list) = item {
+            if unwraps && unwrap && !self.strict {
+                for elem in list.iter() {
+                    self.apply(accessor, elem, scope, false, lenient, out)?;
+                }
+                return Ok(());
+            }
+        }
+        match accessor {
+            Accessor::Member(key) => match item {
+                Datum::Map(map) => match map.iter().find(|(k, _)| *k == key.as_str()) {
+                    Some((_, value)) => out.push(value),
+                    None if !lenient => {
+                        return Err(item_error(format!(
+                            "JSON object does not contain key \"{}\"",
+                            key
+                        )))
+                    }
+                    None => (),
+                },
+                _ if !lenient => {
+                    return Err(item_error(
+                        "jsonpath member accessor can only be applied to an object",
+                    ))
+                }
+                _ => (),
+            },
+            Accessor::MemberWildcard => match item {
+                Datum::Map(map) => out.extend(map.iter().map(|(_, value)| value)),
+                _ if !lenient => {
+                    return Err(item_error(
+                        "jsonpath wildcard member accessor can only be applied to an object",
+                    ))
+                }
+                _ => (),
+            },
+            Accessor::ElementWildcard => match item {
+                Datum::List(list) => out.extend(list.iter()),
+                _ if !self.strict => out.push(item),
+                _ if !lenient => {
+                    return Err(item_error(
+                        "jsonpath wildcard array accessor can only be applied to an array",
+                    ))
+                }
+                _ => (),
+            },
+            Accessor::Elements(subscripts) => {
+                let elems: Vec<_> = match item {
+                    Datum::List(list) => list.iter().collect(),
+                    _ if !self.strict => vec![item],
+                    _ if !lenient => {
+                        return Err(item_error(
+                            "jsonpath array accessor can only be applied to an array",
+                        ))
+                    }
+                    _ => return Ok(()),
+                };
+                let len = i64::try_from(elems.len()).expect("array length fits in i64");
+                let scope = Scope {
+                    current: scope.current,
+                    last: Some(len - 1),
+                };
+                for (from, to) in subscripts {
+                    let from = self.eval_subscript(from, scope)?;
+                    let to = match to {
+                        Some(to) => self.eval_subscript(to, scope)?,
+                        None => from,
+                    };
+                    if !lenient && (from < 0 || from > to || to >= len) {
+                        return Err(item_error("jsonpath array subscript is out of bounds"));
+                    }
+                    for i in from.max(0)..=to.min(len - 1) {
+                        out.push(elems[usize::try_from(i).expect("known to be non-negative")]);
+                    }
+                }
+            }
+            Accessor::Descendants { first, last } => {
+                descendants(item, 0, *first, *last, out);
+            }
+            Accessor::Filter(predicate) => {
+                let scope = Scope {
+                    current: item,
+                    last: scope.last,
+                };
+                if self.eval_predicate(predicate, scope)? == Tri::True {
+                    out.push(item);
+                }
+            }
+            Accessor::Method(method) => out.push(self.apply_method(*method, item)?),
+        }
+        Ok(())
+    }
+
+    fn apply_method(&self, method: Method, item: Datum<'a>) -> Result<Datum<'a>, JsonPathError> {
+        match (method, item) {
+            (Method::Type, _) => Ok(Datum::String(type_name(item))),
+            (Method::Size, Datum::List(list)) => {
+                let size = i64::try_from(list.iter().count()).expect("array length fits in i64");
+                Ok(Datum::from(Numeric::from(size)))
+            }
+            (Method::Size, _) if !self.strict => Ok(Datum::from(Numeric::from(1))),
+            (Method::Size, _) => Err(item_error(
+                "jsonpath item method .size() can only be applied to an array",
+            )),
+            (Method::Abs, Datum::Numeric(n)) => {
+                let mut n = n.0;
+                numeric::cx_datum().abs(&mut n);
+                Ok(Datum::from(n))
+            }
+            (Method::Floor | Method::Ceiling, Datum::Numeric(n)) => {
+                let mut n = n.0;
+                if n.exponent() < 0 {
+                    let mut cx = numeric::cx_datum();
+                    cx.set_rounding(match method {
+                        Method::Floor => Rounding::Floor,
+                        _ => Rounding::Ceiling,
+                    });
+                    cx.round(&mut n);
+                    numeric::munge_numeric(&mut n).expect("rounding cannot overflow");
+                }
+                Ok(Datum::from(n))
+            }
+            (Method::Double, Datum::Numeric(n)) => {
+                let f = n.0.to_string().parse::<f64>().unwrap_or(f64::INFINITY);
+                if !f.is_finite() {
+                    return Err(item_error(
+                        "numeric argument of jsonpath item method .double() is out of range for \
+                         type double precision",
+                    ));
+                }
+                Ok(Datum::from(float_to_numeric(f)?))
+            }
+            (Method::Double, Datum::String(s)) => match s.trim().parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(Datum::from(float_to_numeric(f)?)),
+                _ => Err(item_error(
+                    "string argument of jsonpath item method .double() is not a valid \
+                     representation of a double precision number",
+                )),
+            },
+            (Method::Double, _) => Err(item_error(
+                "jsonpath item method .double() can only be applied to a string or numeric value",
+            )),
+            (Method::Abs | Method::Floor | Method::Ceiling, _) => Err(item_error(format!(
+                "jsonpath item method .{}() can only be applied to a numeric value",
+                method.name()
+            ))),
+        }
+    }
+
+    /// Evaluates an array subscript, which must be a single number, to an
+    /// index.
+    fn eval_subscript(&self, expr: &'a Expr, scope: Scope<'a>) -> Result<i64, JsonPathError> {
+        match self.eval(expr, scope)?.as_slice() {
+            [Datum::Numeric(n)] => {
+                let mut n = n.0;
+                let mut cx = numeric::cx_datum();
+                cx.set_rounding(Rounding::Down);
+                cx.round(&mut n);
+                cx.try_into_i32(n)
+                    .map(i64::from)
+                    .map_err(|_| item_error("jsonpath array subscript is out of integer range"))
+            }
+            _ => Err(item_error(
+                "jsonpath array subscript is not a single numeric value",
+            )),
+        }
+    }
+
+    fn eval_predicate(&self, expr: &'a Expr, scope: Scope<'a>) -> Result<Tri, JsonPathError> {
+        match expr {
+            Expr::And(left, right) => {
+                let left = self.eval_predicate(left, scope)?;
+                if left == Tri::False {
+                    return Ok(Tri::False);
+                }
+                match self.eval_predicate(right, scope)? {
+                    Tri::True => Ok(left),
+                    right => Ok(right),
+                }
+            }
+            Expr::Or(left, right) => {
+                let left = self.eval_predicate(left, scope)?;
+                if left == Tri::True {
+                    return Ok(Tri::True);
+                }
+                match self.eval_predicate(right, scope)? {
+                    Tri::False => Ok(left),
+                    right => Ok(right),
+                }
+            }
+            Expr::Not(operand) => Ok(match self.eval_predicate(operand, scope)? {
+                Tri::True => Tri::False,
+                Tri::False => Tri::True,
+                Tri::Unknown => Tri::Unknown,
+            }),
+            Expr::IsUnknown(operand) => Ok(Tri::from(
+                self.eval_predicate(operand, scope)? == Tri::Unknown,
+            )),
+            Expr::Exists(operand) => match self.eval(operand, scope) {
+                Ok(items) => Ok(Tri::from(!items.is_empty())),
+                Err(e) if e.is_suppressible() => Ok(Tri::Unknown),
+                Err(e) => Err(e),
+            },
+            Expr::Compare(op, left, right) => {
+                self.eval_comparison(left, Some(right), scope, |l, r| {
+                    compare(*op, l, r.expect("comparison has two operands"))
+                })
+            }
+            Expr::LikeRegex(operand, regex) => {
+                self.eval_comparison(operand, None, scope, |item, _| match item {
+                    Datum::String(s) => Tri::from(regex.is_match(s)),
+                    _ => Tri::Unknown,
+                })
+            }
+            Expr::StartsWith(operand, prefix) => {
+                let prefix = match self.eval(prefix, scope)?.as_slice() {
+                    [Datum::String(prefix)] => *prefix,
+                    _ => return Ok(Tri::Unknown),
+                };
+                self.eval_comparison(operand, None, scope, |item, _| match item {
+                    Datum::String(s) => Tri::from(s.starts_with(prefix)),
+                    _ => Tri::Unknown,
+                })
+            }
+            _ => unreachable!("parser admits only predicates here"),
+        }
+    }
+
+    /// Evaluates a predicate over each item, or pair of items, selected by
+    /// its operands.
+    ///
+    /// In lax mode, the predicate is true if it holds for any items, even if
+    /// it is unknown for others. In strict mode, the predicate is unknown if
+    /// it is unknown for any items.
+    fn eval_comparison<F>(
+        &self,
+        left: &'a Expr,
+        right: Option<&'a Expr>,
+        scope: Scope<'a>,
+        f: F,
+    ) -> Result<Tri, JsonPathError>
+    where
+        F: Fn(Datum<'a>, Option<Datum<'a>>) -> Tri,
+    {
+        let operand = |expr| match self.eval_unwrapped(expr, scope) {
+            Ok(items) => Ok(Some(items)),
+            Err(e) if e.is_suppressible() => Ok(None),
+            Err(e) => Err(e),
+        };
+        let left = match operand(left)? {
+            Some(items) => items,
+            None => return Ok(Tri::Unknown),
+        };
+        let right = match right {
+            Some(right) => match operand(right)? {
+                Some(items) => items.into_iter().map(Some).collect(),
+                None => return Ok(Tri::Unknown),
+            },
+            None => vec![None],
+        };
+        let mut found = false;
+        let mut unknown = false;
+        for l in &left {
+            for r in &right {
+                match f(*l, *r) {
+                    Tri::True if !self.strict => return Ok(Tri::True),
+                    Tri::True => found = true,
+                    Tri::Unknown if self.strict => return Ok(Tri::Unknown),
+                    Tri::Unknown => unknown = true,
+                    Tri::False => (),
+                }
+            }
+        }
+        if found {
+            Ok(Tri::True)
+        } else if unknown {
+            Ok(Tri::Unknown)
+        } else {
+            Ok(Tri::False)
+        }
+    }
+}
+
+/// Appends `item` and its descendants, down to `last` levels below `item`, to
+/// `out` in depth-first order, omitting those fewer than `first` levels below
+/// `item`.
+fn descendants<'a>(item: Datum<'a>, level: u32, first: u32, last: u32, out: &mut Vec<Datum<'a>>) {
+    let is_container = matches!(item, Datum::List(_) | Datum::Map(_));
+    // As in PostgreSQL, `.**{last}` selects the leaves of the item.
+    let leaves_only = first == LAST_LEVEL && last == LAST_LEVEL;
+    if (level >= first && level <= last) || (leaves_only && !is_container) {
+        out.push(item);
+    }
+    if level < last {
+        let children: Vec<_> = match item {
+            Datum::List(list) => list.iter().collect(),
+            Datum::Map(map) => map.iter().map(|(_, value)| value).collect(),
+            _ => vec![],
+        };
+        for child in children {
+            descendants(child, level + 1, first, last, out);
+        }
+    }
+}
+
+fn type_name(item: Datum) -> &'static str {
+    match item {
+        Datum::JsonNull => "null",
+        Datum::True | Datum::False => "boolean",
+        Datum::Numeric(_) => "number",
+        Datum::String(_) => "string",
+        Datum::List(_) => "array",
+        Datum::Map(_) => "object",
+        _ => unreachable!("{:?} is not a JSON datum", item),
+    }
+}
+
+fn compare(op: CompareOp, left: Datum, right: Datum) -> Tri {
+    let ordering = match (left, right) {
+        (Datum::JsonNull, Datum::JsonNull) => Ordering::Equal,
+        // Nulls are not equal to, nor ordered with respect to, other items.
+        (Datum::JsonNull, _) | (_, Datum::JsonNull) => {
+            return Tri::from(matches!(op, CompareOp::NotEq));
+        }
+        (Datum::True | Datum::False, Datum::True | Datum::False) => {
+            (left == Datum::True).cmp(&(right == Datum::True))
+        }
+        (Datum::Numeric(l), Datum::Numeric(r)) => l.cmp(&r),
+        (Datum::String(l), Datum::String(r)) => l.cmp(r),
+        // Arrays, objects, and items of different types are not comparable.
+        _ => return Tri::Unknown,
+    };
+    Tri::from(match op {
+        CompareOp::Eq => ordering == Ordering::Equal,
+        CompareOp::NotEq => ordering != Ordering::Equal,
+        CompareOp::Lt => ordering == Ordering::Less,
+        CompareOp::Lte => ordering != Ordering::Greater,
+        CompareOp::Gt => ordering == Ordering::Greater,
+        CompareOp::Gte => ordering != Ordering::Less,
+    })
+}
+
+fn arithmetic(op: BinaryOp, mut left: Numeric, right: Numeric) -> Result<Numeric, JsonPathError> {
+    let mut cx = numeric::cx_datum();
+    match op {
+        BinaryOp::Add => cx.add(&mut left, &right),
+        BinaryOp::Sub => cx.sub(&mut left, &right),
+        BinaryOp::Mul => cx.mul(&mut left, &right),
+        BinaryOp::Div | BinaryOp::Mod if right.is_zero() => {
+            return Err(item_error("division by zero"))
+        }
+        BinaryOp::Div => cx.div(&mut left, &right),
+        BinaryOp::Mod => cx.rem(&mut left, &right),
+    }
+    if cx.status().overflow() || !left.is_finite() {
+        return Err(item_error("value out of range for type numeric"));
+    }
+    if cx.status().subnormal() {
+        return Err(item_error("value underflows numeric format"));
+    }
+    numeric::munge_numeric(&mut left)
+        .map_err(|_| item_error("value out of range for type numeric"))?;
+    Ok(left)
+}
+
+/// Converts a finite double to a numeric, or errors if its magnitude is too
+/// large or too small for a numeric to represent.
+fn float_to_numeric(f: f64) -> Result<Numeric, JsonPathError> {
+    let out_of_range = || item_error("value out of range for type numeric");
+    // Nonzero doubles smaller than the smallest nonzero numeric would round to
+    // zero, or have too many digits after the decimal point to round at all.
+    if f != 0.0 && f.abs() < 10f64.powi(-i32::from(numeric::NUMERIC_DATUM_MAX_PRECISION)) {
+        return Err(out_of_range());
+    }
+    let mut n = Numeric::from(f);
+    numeric::munge_numeric(&mut n).map_err(|_| out_of_range())?;
+    Ok(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_proto::protobuf_roundtrip;
+    use proptest::prelude::*;
+
+    use crate::adt::jsonb::{Jsonb, JsonbRef};
+
+    use super::*;
+
+    fn query(target: &str, path: &str) -> Result<Vec<String>, JsonPathError> {
+        let target: Jsonb = target.parse().unwrap();
+        let path: JsonPath = path.parse()?;
+        let items = path.query(target.as_ref().into_datum(), None)?;
+        Ok(items
+            .into_iter()
+            .map(|d| JsonbRef::from_datum(d).to_string())
+            .collect())
+    }
+
+    #[test]
+    fn test_query() {
+        let doc = r#"{"a": [1, 2, {"b": 3}], "c": {"d": "x"}, "e": null}"#;
+        let cases: &[(&str, &[&str])] = &[
+            ("$", &[r#"{"a":[1,2,{"b":3}],"c":{"d":"x"},"e":null}"#]),
+            ("$.a[0]", &["1"]),
+            ("$.a[last]", &[r#"{"b":3}"#]),
+            ("$.a[0 to 1]", &["1", "2"]),
+            ("$.a[*].b", &["3"]),
+            ("$.c.*", &[r#""x""#]),
+            ("$.**.b", &["3", "3"]),
+            ("strict $.**.b", &["3"]),
+            ("$.missing", &[]),
+            ("$.a ? (@ > 1)", &["2"]),
+            ("$.a[*] ? (@.type() == \"number\")", &["1", "2"]),
+            ("$.a.size()", &["3"]),
+            ("$.c.d like_regex \"X\" flag \"i\"", &["true"]),
+            ("$.e == null", &["true"]),
+            ("$.c.d starts with \"y\"", &["false"]),
+            ("exists($.a ? (@ == 2))", &["true"]),
+            ("-$.a[0] * 3", &["-3"]),
+        ];
+        for (path, expected) in cases {
+            assert_eq!(query(doc, path).unwrap(), *expected, "path {}", path);
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        let doc = r#"{"a": [1, 2]}"#;
+        assert_eq!(query(doc, "lax $.b").unwrap(), Vec::<String>::new());
+        assert_eq!(query(doc, "lax $.a.c").unwrap(), Vec::<String>::new());
+        assert_eq!(
+            query(doc, "strict $.b").unwrap_err().to_string(),
+            r#"JSON object does not contain key "b""#
+        );
+        assert_eq!(
+            query(doc, "strict $.a[5]").unwrap_err().to_string(),
+            "jsonpath array subscript is out of bounds"
+        );
+        assert!(query(doc, "strict $.b").unwrap_err().is_suppressible());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for path in ["", "$.", "$ ? (@ >", "@.a", "$.a[last]last", "$.keyvalue()"] {
+            let err = path.parse::<JsonPath>().unwrap_err();
+            assert!(matches!(err, JsonPathError::Syntax(_)), "path {}", path);
+            assert!(!err.is_suppressible());
+        }
+        assert!(matches!(
+            "last".parse::<JsonPath>(),
+            Err(JsonPathError::Syntax(_))
+        ));
+    }
+    proptest! {
+        #[test]
+        fn jsonpath_protobuf_roundtrip(expect in any_jsonpath()) {
+            let actual = protobuf_roundtrip::<_, String>(&expect);
+            assert!(actual.is_ok());
+            assert_eq!(actual.unwrap(), expect);
+        }
+    }
+}
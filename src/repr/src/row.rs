@@ -33,6 +33,7 @@ use crate::adt::array::{
     Array, ArrayDimension, ArrayDimensions, InvalidArrayError, MAX_ARRAY_DIMENSIONS,
 };
 use crate::adt::date::Date;
+use crate::adt::inet::Inet;
 use crate::adt::interval::Interval;
 use crate::adt::mz_acl_item::MzAclItem;
 use crate::adt::numeric;
@@ -406,6 +407,7 @@ enum Tag {
     MzTimestamp,
     Range,
     MzAclItem,
+    Inet,
 }
 
 // --------------------------------------------------------------------------------
@@ -667,6 +669,12 @@ unsafe fn read_datum<'a>(data: &'a [u8], offset: &mut usize) -> Datum<'a> {
                 .expect("invalid mz_aclitem");
             Datum::MzAclItem(mz_acl_item)
         }
+        Tag::Inet => {
+            const N: usize = Inet::binary_size();
+            let inet =
+                Inet::decode_binary(&read_byte_array::<N>(data, offset)).expect("invalid inet");
+            Datum::Inet(inet)
+        }
     }
 }
 
@@ -907,6 +915,10 @@ where
             data.push(Tag::MzAclItem.into());
             data.extend_from_slice(&mz_acl_item.encode_binary());
         }
+        Datum::Inet(inet) => {
+            data.push(Tag::Inet.into());
+            data.extend_from_slice(&inet.encode_binary());
+        }
     }
 }
 
@@ -1007,6 +1019,7 @@ pub fn datum_size(datum: &Datum) -> usize {
             }
         }
         Datum::MzAclItem(_) => 1 + MzAclItem::binary_size(),
+        Datum::Inet(_) => 1 + Inet::binary_size(),
     }
 }
 
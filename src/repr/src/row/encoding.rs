@@ -96,8 +96,10 @@ impl ColumnType {
             (true, Int64) => f.call::<Option<i64>>(),
             (false, UInt16) => f.call::<u16>(),
             (true, UInt16) => f.call::<Option<u16>>(),
-            (false, UInt32 | Oid | RegClass | RegProc | RegType) => f.call::<u32>(),
-            (true, UInt32 | Oid | RegClass | RegProc | RegType) => f.call::<Option<u32>>(),
+            (false, UInt32 | Oid | RegClass | RegProc | RegType | Enum { .. }) => f.call::<u32>(),
+            (true, UInt32 | Oid | RegClass | RegProc | RegType | Enum { .. }) => {
+                f.call::<Option<u32>>()
+            }
             (false, UInt64) => f.call::<u64>(),
             (true, UInt64) => f.call::<Option<u64>>(),
             (false, Float32) => f.call::<f32>(),
@@ -130,7 +132,9 @@ impl ColumnType {
                 | Int2Vector
                 | MzTimestamp
                 | Range { .. }
-                | MzAclItem,
+                | MzAclItem
+                | Inet
+                | Cidr,
             ) => {
                 if *nullable {
                     f.call::<NullableProtoDatumToPersist>()
@@ -644,6 +648,7 @@ impl<'a> From<Datum<'a>> for ProtoDatum {
                 }),
             })),
             Datum::MzAclItem(x) => DatumType::MzAclItem(x.into_proto()),
+            Datum::Inet(x) => DatumType::Inet(x.into_proto()),
         };
         ProtoDatum {
             datum_type: Some(datum_type),
@@ -792,6 +797,7 @@ impl RowPacker<'_> {
                 }
             }
             Some(DatumType::MzAclItem(x)) => self.push(Datum::MzAclItem(x.clone().into_rust()?)),
+            Some(DatumType::Inet(x)) => self.push(Datum::Inet(x.clone().into_rust()?)),
             None => return Err("unknown datum type".into()),
         };
         Ok(())
@@ -28,11 +28,13 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use mz_lowertest::MzReflect;
+use mz_ore::cast::CastFrom;
 use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 
 use crate::adt::array::{Array, ArrayDimension};
 use crate::adt::char::{Char, CharLength};
 use crate::adt::date::Date;
+use crate::adt::inet::{Cidr, Inet};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::{AclMode, MzAclItem};
@@ -130,6 +132,8 @@ pub enum Datum<'a> {
     Range(Range<DatumNested<'a>>),
     /// A list of privileges granted to a user.
     MzAclItem(MzAclItem),
+    /// An IPv4 or IPv6 address with a network prefix length.
+    Inet(Inet),
     /// A placeholder value.
     ///
     /// Dummy values are never meant to be observed. Many operations on `Datum`
@@ -758,6 +762,19 @@ impl<'a> Datum<'a> {
         }
     }
 
+    /// Unwraps the network address value within this datum.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the datum is not [`Datum::Inet`].
+    #[track_caller]
+    pub fn unwrap_inet(&self) -> Inet {
+        match self {
+            Datum::Inet(inet) => *inet,
+            _ => panic!("Datum::unwrap_inet called on {:?}", self),
+        }
+    }
+
     /// Reports whether this datum is an instance of the specified column type.
     pub fn is_instance_of(self, column_type: &ColumnType) -> bool {
         fn is_instance_of_scalar(datum: Datum, scalar_type: &ScalarType) -> bool {
@@ -801,6 +818,9 @@ impl<'a> Datum<'a> {
                     (Datum::UInt32(_), ScalarType::RegProc) => true,
                     (Datum::UInt32(_), ScalarType::RegType) => true,
                     (Datum::UInt32(_), ScalarType::UInt32) => true,
+                    (Datum::UInt32(i), ScalarType::Enum { labels, .. }) => {
+                        usize::cast_from(*i) < labels.len()
+                    }
                     (Datum::UInt32(_), _) => false,
                     (Datum::UInt64(_), ScalarType::UInt64) => true,
                     (Datum::UInt64(_), _) => false,
@@ -875,6 +895,9 @@ impl<'a> Datum<'a> {
                     (Datum::Range(_), _) => false,
                     (Datum::MzAclItem(_), ScalarType::MzAclItem) => true,
                     (Datum::MzAclItem(_), _) => false,
+                    (Datum::Inet(_), ScalarType::Inet) => true,
+                    (Datum::Inet(inet), ScalarType::Cidr) => inet.is_network(),
+                    (Datum::Inet(_), _) => false,
                 }
             }
         }
@@ -1157,6 +1180,7 @@ impl fmt::Display for Datum<'_> {
             Datum::Dummy => f.write_str("dummy"),
             Datum::Range(i) => write!(f, "{}", i),
             Datum::MzAclItem(mz_acl_item) => write!(f, "{mz_acl_item}"),
+            Datum::Inet(inet) => write!(f, "{inet}"),
         }
     }
 }
@@ -1294,6 +1318,24 @@ pub enum ScalarType {
     },
     /// The type of [`Datum::MzAclItem`]
     MzAclItem,
+    /// An IPv4 or IPv6 host address with an optional network prefix length,
+    /// represented by [`Datum::Inet`].
+    Inet,
+    /// An IPv4 or IPv6 network, represented by a [`Datum::Inet`] with no bits
+    /// set to the right of its netmask.
+    Cidr,
+    /// A user-defined enumerated type, backed by a [`Datum::UInt32`] that
+    /// holds the index of the value's label in `labels`.
+    ///
+    /// Because labels are stored by index, values sort in the order in which
+    /// the labels were declared.
+    Enum {
+        /// The labels of the type, in declaration order.
+        labels: Vec<String>,
+        custom_id: GlobalId,
+        /// The OID of the type, which is reported to pgwire clients.
+        custom_oid: u32,
+    },
 }
 
 impl RustType<ProtoRecordField> for (ColumnName, ColumnType) {
@@ -1380,6 +1422,17 @@ impl RustType<ProtoScalarType> for ScalarType {
                     element_type: Some(element_type.into_proto()),
                 })),
                 ScalarType::MzAclItem => MzAclItem(()),
+                ScalarType::Inet => Inet(()),
+                ScalarType::Cidr => Cidr(()),
+                ScalarType::Enum {
+                    labels,
+                    custom_id,
+                    custom_oid,
+                } => Enum(ProtoEnum {
+                    labels: labels.clone(),
+                    custom_id: Some(custom_id.into_proto()),
+                    custom_oid: *custom_oid,
+                }),
             }),
         }
     }
@@ -1460,6 +1513,13 @@ impl RustType<ProtoScalarType> for ScalarType {
                 ),
             }),
             MzAclItem(()) => Ok(ScalarType::MzAclItem),
+            Inet(()) => Ok(ScalarType::Inet),
+            Cidr(()) => Ok(ScalarType::Cidr),
+            Enum(x) => Ok(ScalarType::Enum {
+                labels: x.labels,
+                custom_id: x.custom_id.into_rust_if_some("ProtoEnum::custom_id")?,
+                custom_oid: x.custom_oid,
+            }),
         }
     }
 }
@@ -1571,6 +1631,7 @@ impl_datum_type_copy!(NaiveTime, Time);
 impl_datum_type_copy!(CheckedTimestamp<NaiveDateTime>, Timestamp);
 impl_datum_type_copy!(CheckedTimestamp<DateTime<Utc>>, TimestampTz);
 impl_datum_type_copy!(Uuid, Uuid);
+impl_datum_type_copy!(Inet, Inet);
 impl_datum_type_copy!('a, &'a str, String);
 impl_datum_type_copy!('a, &'a [u8], Bytes);
 impl_datum_type_copy!(crate::Timestamp, MzTimestamp);
@@ -1805,6 +1866,29 @@ impl<'a, E> DatumType<'a, E> for Oid {
     }
 }
 
+impl AsColumnType for Cidr {
+    fn as_column_type() -> ColumnType {
+        ScalarType::Cidr.nullable(false)
+    }
+}
+
+impl<'a, E> DatumType<'a, E> for Cidr {
+    fn nullable() -> bool {
+        false
+    }
+
+    fn try_from_result(res: Result<Datum<'a>, E>) -> Result<Self, Result<Datum<'a>, E>> {
+        match res {
+            Ok(Datum::Inet(a)) => Ok(Cidr(a)),
+            _ => Err(res),
+        }
+    }
+
+    fn into_result(self, _temp_storage: &'a RowArena) -> Result<Datum<'a>, E> {
+        Ok(Datum::Inet(self.0))
+    }
+}
+
 impl AsColumnType for RegClass {
     fn as_column_type() -> ColumnType {
         ScalarType::RegClass.nullable(false)
@@ -2258,6 +2342,18 @@ impl<'a> ScalarType {
         }
     }
 
+    /// Returns the labels of a [`ScalarType::Enum`], in declaration order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called on anything other than a [`ScalarType::Enum`].
+    pub fn unwrap_enum_labels(&self) -> &[String] {
+        match self {
+            ScalarType::Enum { labels, .. } => labels,
+            _ => panic!("ScalarType::unwrap_enum_labels called on {:?}", self),
+        }
+    }
+
     /// Returns a "near match" of `self`, which are types that are implicitly
     /// castable from `self` and offer a means to leverage Materialize's type
     /// system to achieve more reasonable approaches to unifying types.
@@ -2329,6 +2425,7 @@ impl<'a> ScalarType {
                         .map(|(_, t)| t)
                         .any(|t| t.scalar_type.is_custom_type())
             }
+            Enum { .. } => true,
             _ => false,
         }
     }
@@ -2402,6 +2499,18 @@ impl<'a> ScalarType {
                                 && a.1.scalar_type.eq_inner(&b.1.scalar_type, structure_only)
                         })
             }
+            (
+                Enum {
+                    labels: labels_a,
+                    custom_id: id_a,
+                    ..
+                },
+                Enum {
+                    labels: labels_b,
+                    custom_id: id_b,
+                    ..
+                },
+            ) => id_a == id_b || (structure_only && labels_a == labels_b),
             (s, o) => ScalarBaseType::from(s) == ScalarBaseType::from(o),
         }
     }
@@ -2629,6 +2738,8 @@ impl<'a> ScalarType {
             ])
         });
         static RANGE: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
+        static ENUM: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[Datum::UInt32(0)]));
+        static EMPTY: Lazy<Row> = Lazy::new(|| Row::pack_slice(&[]));
         static MZACLITEM: Lazy<Row> = Lazy::new(|| {
             Row::pack_slice(&[
                 Datum::MzAclItem(MzAclItem {
@@ -2663,6 +2774,25 @@ impl<'a> ScalarType {
                 }),
             ])
         });
+        static INET: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet("0.0.0.0/0".parse().unwrap()),
+                Datum::Inet("192.168.1.5/24".parse().unwrap()),
+                Datum::Inet("255.255.255.255".parse().unwrap()),
+                Datum::Inet("::/0".parse().unwrap()),
+                Datum::Inet("2001:db8::1/64".parse().unwrap()),
+                Datum::Inet("ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap()),
+            ])
+        });
+        static CIDR: Lazy<Row> = Lazy::new(|| {
+            Row::pack_slice(&[
+                Datum::Inet("0.0.0.0/0".parse().unwrap()),
+                Datum::Inet("192.168.1.0/24".parse().unwrap()),
+                Datum::Inet("255.255.255.255/32".parse().unwrap()),
+                Datum::Inet("::/0".parse().unwrap()),
+                Datum::Inet("2001:db8::/64".parse().unwrap()),
+            ])
+        });
 
         match self {
             ScalarType::Bool => (*BOOL).iter(),
@@ -2699,6 +2829,10 @@ impl<'a> ScalarType {
             ScalarType::MzTimestamp => (*MZTIMESTAMP).iter(),
             ScalarType::Range { .. } => (*RANGE).iter(),
             ScalarType::MzAclItem { .. } => (*MZACLITEM).iter(),
+            ScalarType::Inet => (*INET).iter(),
+            ScalarType::Cidr => (*CIDR).iter(),
+            ScalarType::Enum { labels, .. } if labels.is_empty() => (*EMPTY).iter(),
+            ScalarType::Enum { .. } => (*ENUM).iter(),
         }
     }
 
@@ -2743,6 +2877,8 @@ impl<'a> ScalarType {
             ScalarType::Int2Vector,
             ScalarType::MzTimestamp,
             ScalarType::MzAclItem,
+            ScalarType::Inet,
+            ScalarType::Cidr,
             // TODO: Fill in some variants of these.
             /*
             ScalarType::Array(_),
@@ -2805,6 +2941,19 @@ impl Arbitrary for ScalarType {
             Just(ScalarType::RegType).boxed(),
             Just(ScalarType::RegClass).boxed(),
             Just(ScalarType::Int2Vector).boxed(),
+            Just(ScalarType::Inet).boxed(),
+            Just(ScalarType::Cidr).boxed(),
+            (
+                prop::collection::vec(any::<String>(), 0..4),
+                any::<GlobalId>(),
+                any::<u32>(),
+            )
+                .prop_map(|(labels, custom_id, custom_oid)| ScalarType::Enum {
+                    labels,
+                    custom_id,
+                    custom_oid,
+                })
+                .boxed(),
         ]);
 
         leaf.prop_recursive(
@@ -3277,6 +3426,25 @@ fn verify_base_eq_record_nullability() {
     assert!(!s1.base_eq(&s3));
 }
 
+#[test]
+fn verify_base_eq_enum() {
+    let labels = vec!["a".to_string(), "b".to_string()];
+    let s1 = ScalarType::Enum {
+        labels: labels.clone(),
+        custom_id: GlobalId::User(1),
+        custom_oid: 20_000,
+    };
+    let s2 = ScalarType::Enum {
+        labels,
+        custom_id: GlobalId::User(2),
+        custom_oid: 20_001,
+    };
+    assert!(s1.base_eq(&s1));
+    assert!(!s1.base_eq(&s2));
+    assert!(s1.structural_eq(&s2));
+    assert!(!s1.base_eq(&ScalarType::UInt32));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,6 +52,7 @@ use mz_proto::{RustType, TryFromProtoError};
 use crate::adt::array::ArrayDimension;
 use crate::adt::date::Date;
 use crate::adt::datetime::{self, DateTimeField, ParsedDateTime};
+use crate::adt::inet::{Cidr, Inet};
 use crate::adt::interval::Interval;
 use crate::adt::jsonb::{Jsonb, JsonbRef};
 use crate::adt::mz_acl_item::MzAclItem;
@@ -1456,6 +1457,46 @@ pub fn parse_mz_acl_item(s: &str) -> Result<MzAclItem, ParseError> {
         .map_err(|e| ParseError::invalid_input_syntax("mz_aclitem", s).with_details(e))
 }
 
+/// Parses an `inet` value from `s`.
+pub fn parse_inet(s: &str) -> Result<Inet, ParseError> {
+    s.trim()
+        .parse()
+        .map_err(|e| ParseError::invalid_input_syntax("inet", s).with_details(e))
+}
+
+/// Writes an `inet` value to `buf`, omitting the prefix length of single
+/// hosts.
+pub fn format_inet<F>(buf: &mut F, inet: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{inet}");
+    Nestable::Yes
+}
+
+/// Parses a `cidr` value from `s`, which must not have any bits set to the
+/// right of its netmask.
+pub fn parse_cidr(s: &str) -> Result<Inet, ParseError> {
+    let cidr = s
+        .trim()
+        .parse::<Inet>()
+        .map_err(|e| ParseError::invalid_input_syntax("cidr", s).with_details(e))?;
+    if !cidr.is_network() {
+        return Err(ParseError::invalid_input_syntax("cidr", s)
+            .with_details("Value has bits set to right of mask."));
+    }
+    Ok(cidr)
+}
+
+/// Writes a `cidr` value to `buf`, which always includes the prefix length.
+pub fn format_cidr<F>(buf: &mut F, cidr: Inet) -> Nestable
+where
+    F: FormatBuffer,
+{
+    write!(buf, "{}", Cidr(cidr));
+    Nestable::Yes
+}
+
 pub trait ElementEscaper {
     fn needs_escaping(elem: &[u8]) -> bool;
     fn escape_char(c: u8) -> u8;
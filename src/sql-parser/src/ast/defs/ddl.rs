@@ -726,6 +726,56 @@ impl<T: AstInfo> AstDisplay for PostgresConnectionOption<T> {
 }
 impl_display_t!(PostgresConnectionOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MySqlConnectionOptionName {
+    AwsPrivatelink,
+    Host,
+    Password,
+    Port,
+    SshTunnel,
+    SslCertificate,
+    SslCertificateAuthority,
+    SslKey,
+    SslMode,
+    User,
+}
+
+impl AstDisplay for MySqlConnectionOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MySqlConnectionOptionName::AwsPrivatelink => "AWS PRIVATELINK",
+            MySqlConnectionOptionName::Host => "HOST",
+            MySqlConnectionOptionName::Password => "PASSWORD",
+            MySqlConnectionOptionName::Port => "PORT",
+            MySqlConnectionOptionName::SshTunnel => "SSH TUNNEL",
+            MySqlConnectionOptionName::SslCertificate => "SSL CERTIFICATE",
+            MySqlConnectionOptionName::SslCertificateAuthority => "SSL CERTIFICATE AUTHORITY",
+            MySqlConnectionOptionName::SslKey => "SSL KEY",
+            MySqlConnectionOptionName::SslMode => "SSL MODE",
+            MySqlConnectionOptionName::User => "USER",
+        })
+    }
+}
+impl_display!(MySqlConnectionOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `CREATE CONNECTION ... MYSQL`.
+pub struct MySqlConnectionOption<T: AstInfo> {
+    pub name: MySqlConnectionOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MySqlConnectionOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(MySqlConnectionOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum AwsConnectionOptionName {
     AccessKeyId,
@@ -855,6 +905,9 @@ pub enum CreateConnection<T: AstInfo> {
     Postgres {
         with_options: Vec<PostgresConnectionOption<T>>,
     },
+    MySql {
+        with_options: Vec<MySqlConnectionOption<T>>,
+    },
     Ssh {
         with_options: Vec<SshConnectionOption<T>>,
     },
@@ -878,6 +931,11 @@ impl<T: AstInfo> AstDisplay for CreateConnection<T> {
                 f.write_node(&display::comma_separated(with_options));
                 f.write_str(")");
             }
+            Self::MySql { with_options } => {
+                f.write_str("MYSQL (");
+                f.write_node(&display::comma_separated(with_options));
+                f.write_str(")");
+            }
             Self::Aws { with_options } => {
                 f.write_str("AWS (");
                 f.write_node(&display::comma_separated(with_options));
@@ -989,6 +1047,8 @@ pub struct KafkaSourceConnection<T: AstInfo> {
 pub enum PgConfigOptionName {
     /// Hex encoded string of binary serialization of `dataflow_types::PostgresSourceDetails`
     Details,
+    /// Enum types to use for upstream columns of enum types with the same name
+    EnumTypes,
     /// The name of the publication to sync
     Publication,
     /// Columns whose types you want to unconditionally format as text
@@ -999,6 +1059,7 @@ impl AstDisplay for PgConfigOptionName {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         f.write_str(match self {
             PgConfigOptionName::Details => "DETAILS",
+            PgConfigOptionName::EnumTypes => "ENUM TYPES",
             PgConfigOptionName::Publication => "PUBLICATION",
             PgConfigOptionName::TextColumns => "TEXT COLUMNS",
         })
@@ -1024,6 +1085,150 @@ impl<T: AstInfo> AstDisplay for PgConfigOption<T> {
 }
 impl_display_t!(PgConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PgSinkConfigOptionName {
+    /// The name of the table to write to
+    Table,
+}
+
+impl AstDisplay for PgSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PgSinkConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(PgSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in an `INTO POSTGRES CONNECTION ...` statement.
+pub struct PgSinkConfigOption<T: AstInfo> {
+    pub name: PgSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for PgSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(PgSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FileSinkConfigOptionName {
+    /// The S3 or local URL under which to write files
+    Url,
+}
+
+impl AstDisplay for FileSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            FileSinkConfigOptionName::Url => "URL",
+        })
+    }
+}
+impl_display!(FileSinkConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in an `INTO FILE CONNECTION ...` statement.
+pub struct FileSinkConfigOption<T: AstInfo> {
+    pub name: FileSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for FileSinkConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(FileSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MySqlConfigOptionName {
+    /// Hex encoded string of binary serialization of `dataflow_types::MySqlSourceDetails`
+    Details,
+    /// Columns whose types you want to unconditionally format as text
+    TextColumns,
+}
+
+impl AstDisplay for MySqlConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MySqlConfigOptionName::Details => "DETAILS",
+            MySqlConfigOptionName::TextColumns => "TEXT COLUMNS",
+        })
+    }
+}
+impl_display!(MySqlConfigOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `FROM MYSQL CONNECTION ...` statement.
+pub struct MySqlConfigOption<T: AstInfo> {
+    pub name: MySqlConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for MySqlConfigOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(MySqlConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WebhookValidationOptionName {
+    /// The hash function to use when computing the signature.
+    Algorithm,
+    /// How the signature is encoded in the request header.
+    Encoding,
+    /// The name of the request header that carries the signature.
+    Header,
+    /// The secret key to sign request bodies with.
+    Secret,
+}
+
+impl AstDisplay for WebhookValidationOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            WebhookValidationOptionName::Algorithm => "ALGORITHM",
+            WebhookValidationOptionName::Encoding => "ENCODING",
+            WebhookValidationOptionName::Header => "HEADER",
+            WebhookValidationOptionName::Secret => "SECRET",
+        })
+    }
+}
+impl_display!(WebhookValidationOptionName);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// An option in a `VALIDATE USING HMAC (...)` clause.
+pub struct WebhookValidationOption<T: AstInfo> {
+    pub name: WebhookValidationOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for WebhookValidationOption<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(v) = &self.value {
+            f.write_str(" = ");
+            f.write_node(v);
+        }
+    }
+}
+impl_display_t!(WebhookValidationOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateSourceConnection<T: AstInfo> {
     Kafka(KafkaSourceConnection<T>),
@@ -1032,6 +1237,11 @@ pub enum CreateSourceConnection<T: AstInfo> {
         connection: T::ItemName,
         options: Vec<PgConfigOption<T>>,
     },
+    MySql {
+        /// The MySQL connection.
+        connection: T::ItemName,
+        options: Vec<MySqlConfigOption<T>>,
+    },
     LoadGenerator {
         generator: LoadGenerator,
         options: Vec<LoadGeneratorOption<T>>,
@@ -1065,6 +1275,18 @@ impl<T: AstInfo> AstDisplay for CreateSourceConnection<T> {
                     f.write_str(")");
                 }
             }
+            CreateSourceConnection::MySql {
+                connection,
+                options,
+            } => {
+                f.write_str("MYSQL CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
             CreateSourceConnection::LoadGenerator { generator, options } => {
                 f.write_str("LOAD GENERATOR ");
                 f.write_node(generator);
@@ -1147,6 +1369,17 @@ pub enum CreateSinkConnection<T: AstInfo> {
         connection: KafkaConnection<T>,
         key: Option<KafkaSinkKey>,
     },
+    File {
+        /// The AWS connection, if writing to S3.
+        connection: Option<T::ItemName>,
+        options: Vec<FileSinkConfigOption<T>>,
+    },
+    Postgres {
+        /// The postgres connection.
+        connection: T::ItemName,
+        options: Vec<PgSinkConfigOption<T>>,
+        key: Option<KafkaSinkKey>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
@@ -1159,6 +1392,37 @@ impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
                     f.write_node(key);
                 }
             }
+            CreateSinkConnection::File {
+                connection,
+                options,
+            } => {
+                f.write_str("FILE");
+                if let Some(connection) = connection {
+                    f.write_str(" CONNECTION ");
+                    f.write_node(connection);
+                }
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_node(key);
+                }
+            }
         }
     }
 }
@@ -1339,6 +1603,7 @@ pub enum CreateSourceOptionName {
     Timeline,
     TimestampInterval,
     Disk,
+    RetainKeysFor,
 }
 
 impl AstDisplay for CreateSourceOptionName {
@@ -1349,6 +1614,7 @@ impl AstDisplay for CreateSourceOptionName {
             CreateSourceOptionName::Timeline => "TIMELINE",
             CreateSourceOptionName::TimestampInterval => "TIMESTAMP INTERVAL",
             CreateSourceOptionName::Disk => "DISK",
+            CreateSourceOptionName::RetainKeysFor => "RETAIN KEYS FOR",
         })
     }
 }
@@ -129,6 +129,14 @@ pub enum Expr<T: AstInfo> {
         l_expr: Box<Expr<T>>,
         r_expr: Box<Expr<T>>,
     },
+    /// `GROUPING(<expr>, ...)`
+    ///
+    /// While GROUPING has the same syntax as a function call, it refers to the
+    /// grouping sets of the enclosing query and is not evaluated as a function
+    /// within Postgres.
+    Grouping {
+        exprs: Vec<Expr<T>>,
+    },
     /// Nested expression e.g. `(foo > bar)` or `(1)`
     Nested(Box<Expr<T>>),
     /// A row constructor like `ROW(<expr>...)` or `(<expr>, <expr>...)`.
@@ -339,6 +347,7 @@ impl<T: AstInfo> AstDisplay for Expr<T> {
                         | Expr::Collate { .. }
                         | Expr::HomogenizingFunction { .. }
                         | Expr::NullIf { .. }
+                        | Expr::Grouping { .. }
                 );
                 if needs_wrap {
                     f.write_str('(');
@@ -366,6 +375,11 @@ impl<T: AstInfo> AstDisplay for Expr<T> {
                 f.write_node(&display::comma_separated(&[l_expr, r_expr]));
                 f.write_str(")");
             }
+            Expr::Grouping { exprs } => {
+                f.write_str("GROUPING(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
             Expr::Nested(ast) => {
                 f.write_str("(");
                 f.write_node(&ast);
@@ -693,7 +707,7 @@ impl_display_t!(SubscriptPosition);
 pub struct WindowSpec<T: AstInfo> {
     pub partition_by: Vec<Expr<T>>,
     pub order_by: Vec<OrderByExpr<T>>,
-    pub window_frame: Option<WindowFrame>,
+    pub window_frame: Option<WindowFrame<T>>,
 }
 
 impl<T: AstInfo> AstDisplay for WindowSpec<T> {
@@ -735,13 +749,13 @@ impl_display_t!(WindowSpec);
 /// Note: The parser does not validate the specified bounds; the caller should
 /// reject invalid bounds like `ROWS UNBOUNDED FOLLOWING` before execution.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct WindowFrame {
+pub struct WindowFrame<T: AstInfo> {
     pub units: WindowFrameUnits,
-    pub start_bound: WindowFrameBound,
+    pub start_bound: WindowFrameBound<T>,
     /// The right bound of the `BETWEEN .. AND` clause. The end bound of `None`
     /// indicates the shorthand form (e.g. `ROWS 1 PRECEDING`), which must
     /// behave the same as `end_bound = WindowFrameBound::CurrentRow`.
-    pub end_bound: Option<WindowFrameBound>,
+    pub end_bound: Option<WindowFrameBound<T>>,
     // TBD: EXCLUDE
 }
 
@@ -765,33 +779,33 @@ impl_display!(WindowFrameUnits);
 
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum WindowFrameBound {
+pub enum WindowFrameBound<T: AstInfo> {
     /// `CURRENT ROW`
     CurrentRow,
-    /// `<N> PRECEDING` or `UNBOUNDED PRECEDING`
-    Preceding(Option<u64>),
-    /// `<N> FOLLOWING` or `UNBOUNDED FOLLOWING`.
-    Following(Option<u64>),
+    /// `<offset> PRECEDING` or `UNBOUNDED PRECEDING`
+    Preceding(Option<Box<Expr<T>>>),
+    /// `<offset> FOLLOWING` or `UNBOUNDED FOLLOWING`.
+    Following(Option<Box<Expr<T>>>),
 }
 
-impl AstDisplay for WindowFrameBound {
+impl<T: AstInfo> AstDisplay for WindowFrameBound<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             WindowFrameBound::CurrentRow => f.write_str("CURRENT ROW"),
             WindowFrameBound::Preceding(None) => f.write_str("UNBOUNDED PRECEDING"),
             WindowFrameBound::Following(None) => f.write_str("UNBOUNDED FOLLOWING"),
             WindowFrameBound::Preceding(Some(n)) => {
-                f.write_str(n);
+                f.write_node(n);
                 f.write_str(" PRECEDING");
             }
             WindowFrameBound::Following(Some(n)) => {
-                f.write_str(n);
+                f.write_node(n);
                 f.write_str(" FOLLOWING");
             }
         }
     }
 }
-impl_display!(WindowFrameBound);
+impl_display_t!(WindowFrameBound);
 
 /// A function call
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -814,6 +828,11 @@ impl<T: AstInfo> AstDisplay for Function<T> {
         }
         f.write_node(&self.args);
         f.write_str(")");
+        if let FunctionArgs::WithinGroup { order_by, .. } = &self.args {
+            f.write_str(" WITHIN GROUP (ORDER BY ");
+            f.write_node(&display::comma_separated(order_by));
+            f.write_str(")");
+        }
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
             f.write_node(&filter);
@@ -838,6 +857,16 @@ pub enum FunctionArgs<T: AstInfo> {
         args: Vec<Expr<T>>,
         order_by: Vec<OrderByExpr<T>>,
     },
+    /// The direct arguments of an ordered-set aggregate, along with the
+    /// aggregated arguments given in its `WITHIN GROUP (ORDER BY ...)` clause,
+    /// as in `percentile_cont(0.5) WITHIN GROUP (ORDER BY x)`.
+    ///
+    /// Only the direct arguments are printed by this node; the `WITHIN GROUP`
+    /// clause is printed by the enclosing [`Function`].
+    WithinGroup {
+        args: Vec<Expr<T>>,
+        order_by: Vec<OrderByExpr<T>>,
+    },
 }
 
 impl<T: AstInfo> FunctionArgs<T> {
@@ -860,6 +889,9 @@ impl<T: AstInfo> AstDisplay for FunctionArgs<T> {
                     f.write_node(&display::comma_separated(order_by));
                 }
             }
+            FunctionArgs::WithinGroup { args, order_by: _ } => {
+                f.write_node(&display::comma_separated(args));
+            }
         }
     }
 }
@@ -226,7 +226,7 @@ pub struct Select<T: AstInfo> {
     /// WHERE
     pub selection: Option<Expr<T>>,
     /// GROUP BY
-    pub group_by: Vec<Expr<T>>,
+    pub group_by: Vec<GroupByExpr<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
     /// OPTION
@@ -286,6 +286,52 @@ impl<T: AstInfo> Select<T> {
     }
 }
 
+/// An item in a `GROUP BY` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GroupByExpr<T: AstInfo> {
+    /// A single grouping expression, e.g. `GROUP BY a`.
+    Expr(Expr<T>),
+    /// A parenthesized list of grouping expressions, e.g. `(a, b)` or `()`.
+    /// Outside of `GROUPING SETS`, `ROLLUP` and `CUBE`, only the empty list is
+    /// represented this way.
+    List(Vec<Expr<T>>),
+    /// `ROLLUP (<expr or list>, ...)`
+    Rollup(Vec<GroupByExpr<T>>),
+    /// `CUBE (<expr or list>, ...)`
+    Cube(Vec<GroupByExpr<T>>),
+    /// `GROUPING SETS (<group by expr>, ...)`
+    GroupingSets(Vec<GroupByExpr<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for GroupByExpr<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            GroupByExpr::Expr(expr) => f.write_node(expr),
+            GroupByExpr::List(exprs) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+            GroupByExpr::Rollup(exprs) => {
+                f.write_str("ROLLUP (");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+            GroupByExpr::Cube(exprs) => {
+                f.write_str("CUBE (");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+            GroupByExpr::GroupingSets(exprs) => {
+                f.write_str("GROUPING SETS (");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(GroupByExpr);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Distinct<T: AstInfo> {
     EntireRow,
@@ -309,11 +355,14 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
 /// A block of common table expressions (CTEs).
 ///
 /// The block can either be entirely "simple" (traditional SQL `WITH` block),
-/// or "mutually recursive", which introduce their bindings before the block
-/// and may result in mutually recursive definitions.
+/// "recursive" (standard SQL `WITH RECURSIVE` block), whose bindings may
+/// refer to themselves, or "mutually recursive", which introduce their
+/// bindings before the block and may result in mutually recursive
+/// definitions.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum CteBlock<T: AstInfo> {
     Simple(Vec<Cte<T>>),
+    Recursive(Vec<Cte<T>>),
     MutuallyRecursive(Vec<CteMutRec<T>>),
 }
 
@@ -325,7 +374,7 @@ impl<T: AstInfo> CteBlock<T> {
     /// True if there are no bindings in the block.
     pub fn is_empty(&self) -> bool {
         match self {
-            CteBlock::Simple(list) => list.is_empty(),
+            CteBlock::Simple(list) | CteBlock::Recursive(list) => list.is_empty(),
             CteBlock::MutuallyRecursive(list) => list.is_empty(),
         }
     }
@@ -333,7 +382,7 @@ impl<T: AstInfo> CteBlock<T> {
     pub fn bound_identifiers(&self) -> impl Iterator<Item = &Ident> {
         let mut names = Vec::new();
         match self {
-            CteBlock::Simple(list) => {
+            CteBlock::Simple(list) | CteBlock::Recursive(list) => {
                 for cte in list.iter() {
                     names.push(&cte.alias.name);
                 }
@@ -356,6 +405,10 @@ impl<T: AstInfo> AstDisplay for CteBlock<T> {
                     f.write_str("WITH ");
                     f.write_node(&display::comma_separated(list));
                 }
+                CteBlock::Recursive(list) => {
+                    f.write_str("WITH RECURSIVE ");
+                    f.write_node(&display::comma_separated(list));
+                }
                 CteBlock::MutuallyRecursive(list) => {
                     f.write_str("WITH MUTUALLY RECURSIVE ");
                     f.write_node(&display::comma_separated(list));
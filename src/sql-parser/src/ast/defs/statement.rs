@@ -32,7 +32,7 @@ use crate::ast::{
     CreateSourceFormat, CreateSourceOption, CreateSourceOptionName, DeferredItemName, Envelope,
     Expr, Format, Ident, KeyConstraint, Query, SelectItem, SourceIncludeMetadata, SubscribeOutput,
     TableAlias, TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName,
-    UnresolvedObjectName, UnresolvedSchemaName, Value,
+    UnresolvedObjectName, UnresolvedSchemaName, Value, WebhookValidationOption,
 };
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
@@ -50,6 +50,7 @@ pub enum Statement<T: AstInfo> {
     CreateSchema(CreateSchemaStatement),
     CreateSource(CreateSourceStatement<T>),
     CreateSubsource(CreateSubsourceStatement<T>),
+    CreateWebhookSource(CreateWebhookSourceStatement<T>),
     CreateSink(CreateSinkStatement<T>),
     CreateView(CreateViewStatement<T>),
     CreateMaterializedView(CreateMaterializedViewStatement<T>),
@@ -63,6 +64,7 @@ pub enum Statement<T: AstInfo> {
     AlterOwner(AlterOwnerStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterIndex(AlterIndexStatement<T>),
+    AlterTable(AlterTableStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSink(AlterSinkStatement<T>),
     AlterSource(AlterSourceStatement<T>),
@@ -108,6 +110,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateSchema(stmt) => f.write_node(stmt),
             Statement::CreateSource(stmt) => f.write_node(stmt),
             Statement::CreateSubsource(stmt) => f.write_node(stmt),
+            Statement::CreateWebhookSource(stmt) => f.write_node(stmt),
             Statement::CreateSink(stmt) => f.write_node(stmt),
             Statement::CreateView(stmt) => f.write_node(stmt),
             Statement::CreateMaterializedView(stmt) => f.write_node(stmt),
@@ -121,6 +124,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterOwner(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
+            Statement::AlterTable(stmt) => f.write_node(stmt),
             Statement::AlterSecret(stmt) => f.write_node(stmt),
             Statement::AlterSink(stmt) => f.write_node(stmt),
             Statement::AlterSource(stmt) => f.write_node(stmt),
@@ -738,6 +742,38 @@ impl<T: AstInfo> AstDisplay for CreateSubsourceStatement<T> {
 }
 impl_display_t!(CreateSubsourceStatement);
 
+/// `CREATE SOURCE .. FROM WEBHOOK`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateWebhookSourceStatement<T: AstInfo> {
+    pub name: UnresolvedItemName,
+    pub if_not_exists: bool,
+    pub body_format: Format<T>,
+    pub include_headers: bool,
+    /// The options of the `VALIDATE USING HMAC (..)` clause, if present.
+    pub validate_using: Option<Vec<WebhookValidationOption<T>>>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateWebhookSourceStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE SOURCE ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        f.write_str(" FROM WEBHOOK BODY FORMAT ");
+        f.write_node(&self.body_format);
+        if self.include_headers {
+            f.write_str(" INCLUDE HEADERS");
+        }
+        if let Some(options) = &self.validate_using {
+            f.write_str(" VALIDATE USING HMAC (");
+            f.write_node(&display::comma_separated(options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(CreateWebhookSourceStatement);
+
 /// An option in a `CREATE SINK` statement.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CreateSinkOptionName {
@@ -1075,6 +1111,8 @@ pub enum RoleAttribute {
     CreateRole,
     /// The `NOCREATEROLE` option.
     NoCreateRole,
+    /// The `PASSWORD` option, or `PASSWORD NULL` if the value is `None`.
+    Password(Option<String>),
     // The following are not supported, but included to give helpful error messages.
     Login,
     NoLogin,
@@ -1097,6 +1135,18 @@ impl AstDisplay for RoleAttribute {
             RoleAttribute::NoCreateDB => f.write_str("NOCREATEDB"),
             RoleAttribute::CreateRole => f.write_str("CREATEROLE"),
             RoleAttribute::NoCreateRole => f.write_str("NOCREATEROLE"),
+            RoleAttribute::Password(None) => f.write_str("PASSWORD NULL"),
+            RoleAttribute::Password(Some(password)) => {
+                // Passwords are redacted for human consumption, e.g. in logs
+                // and error messages, like they are in `pg_authid`.
+                if f.stable() {
+                    f.write_str("PASSWORD '");
+                    f.write_node(&display::escape_single_quote_string(password));
+                    f.write_str("'");
+                } else {
+                    f.write_str("PASSWORD '********'");
+                }
+            }
         }
     }
 }
@@ -1138,6 +1188,19 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
         f.write_node(&self.name);
         f.write_str(" AS ");
         match &self.as_type {
+            CreateTypeAs::Enum { labels } => {
+                f.write_str(&self.as_type);
+                f.write_str("(");
+                for (i, label) in labels.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ");
+                    }
+                    f.write_str("'");
+                    f.write_node(&display::escape_single_quote_string(label));
+                    f.write_str("'");
+                }
+                f.write_str(")");
+            }
             CreateTypeAs::List { options } => {
                 f.write_str(&self.as_type);
                 f.write_str("( ");
@@ -1322,6 +1385,9 @@ impl<T: AstInfo> AstDisplay for ReplicaOption<T> {
 /// `CREATE TYPE .. AS <TYPE>`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum CreateTypeAs<T: AstInfo> {
+    Enum {
+        labels: Vec<String>,
+    },
     List {
         options: Vec<CreateTypeListOption<T>>,
     },
@@ -1336,6 +1402,7 @@ pub enum CreateTypeAs<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for CreateTypeAs<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
+            CreateTypeAs::Enum { .. } => f.write_str("ENUM "),
             CreateTypeAs::List { .. } => f.write_str("LIST "),
             CreateTypeAs::Map { .. } => f.write_str("MAP "),
             CreateTypeAs::Record { .. } => f.write_str("RECORD "),
@@ -1492,6 +1559,58 @@ impl<T: AstInfo> AstDisplay for AlterIndexStatement<T> {
 
 impl_display_t!(AlterIndexStatement);
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AlterTableAction<T: AstInfo> {
+    /// `ADD [COLUMN] [IF NOT EXISTS] <column_def>`
+    AddColumn {
+        if_not_exists: bool,
+        column: ColumnDef<T>,
+    },
+    /// `DROP [COLUMN] [IF EXISTS] <name>`
+    DropColumn { if_exists: bool, name: Ident },
+}
+
+/// `ALTER TABLE ... {ADD, DROP} COLUMN`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableStatement<T: AstInfo> {
+    pub table_name: UnresolvedItemName,
+    pub if_exists: bool,
+    pub action: AlterTableAction<T>,
+}
+
+impl<T: AstInfo> AstDisplay for AlterTableStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.table_name);
+        f.write_str(" ");
+
+        match &self.action {
+            AlterTableAction::AddColumn {
+                if_not_exists,
+                column,
+            } => {
+                f.write_str("ADD COLUMN ");
+                if *if_not_exists {
+                    f.write_str("IF NOT EXISTS ");
+                }
+                f.write_node(column);
+            }
+            AlterTableAction::DropColumn { if_exists, name } => {
+                f.write_str("DROP COLUMN ");
+                if *if_exists {
+                    f.write_str("IF EXISTS ");
+                }
+                f.write_node(name);
+            }
+        }
+    }
+}
+
+impl_display_t!(AlterTableStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum AlterSinkAction<T: AstInfo> {
     SetOptions(Vec<CreateSinkOption<T>>),
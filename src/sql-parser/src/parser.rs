@@ -113,6 +113,23 @@ pub fn parse_data_type(sql: &str) -> Result<RawDataType, ParserError> {
     }
 }
 
+/// Parses a SQL string containing a single, possibly qualified and possibly
+/// quoted, item name.
+pub fn parse_item_name(sql: &str) -> Result<UnresolvedItemName, ParserError> {
+    let tokens = lexer::lex(sql)?;
+    let mut parser = Parser::new(sql, tokens);
+    let name = parser.parse_item_name()?;
+    if parser.next_token().is_some() {
+        parser_err!(
+            parser,
+            parser.peek_prev_pos(),
+            "extra token after item name"
+        )
+    } else {
+        Ok(name)
+    }
+}
+
 /// Parses a string containing a comma-separated list of identifiers and
 /// returns their underlying string values.
 ///
@@ -411,6 +428,9 @@ impl<'a> Parser<'a> {
             }
             Token::Keyword(LEAST) => self.parse_homogenizing_function(HomogenizingFunction::Least),
             Token::Keyword(NULLIF) => self.parse_nullif_expr(),
+            Token::Keyword(GROUPING) if self.peek_token() == Some(Token::LParen) => {
+                self.parse_grouping_expr()
+            }
             Token::Keyword(EXISTS) => self.parse_exists_expr(),
             Token::Keyword(EXTRACT) => self.parse_extract_expr(),
             Token::Keyword(INTERVAL) => Ok(Expr::Value(self.parse_interval_value()?)),
@@ -606,7 +626,7 @@ impl<'a> Parser<'a> {
             self.parse_at_most_one_keyword(&[ALL, DISTINCT], &format!("function: {}", name))?,
             Some(DISTINCT),
         );
-        let args = self.parse_optional_args(true)?;
+        let mut args = self.parse_optional_args(true)?;
 
         if distinct && matches!(args, FunctionArgs::Star) {
             return Err(self.error(
@@ -615,6 +635,35 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        let pos = self.peek_pos();
+        if self.parse_keywords(&[WITHIN, GROUP]) {
+            let direct_args = match args {
+                FunctionArgs::Star => {
+                    return Err(self.error(pos, "cannot use * with WITHIN GROUP".to_string()));
+                }
+                FunctionArgs::Args { order_by, .. } if !order_by.is_empty() => {
+                    return Err(self.error(
+                        pos,
+                        "cannot use multiple ORDER BY clauses with WITHIN GROUP".to_string(),
+                    ));
+                }
+                FunctionArgs::Args { .. } if distinct => {
+                    return Err(
+                        self.error(pos, "cannot use DISTINCT with WITHIN GROUP".to_string())
+                    );
+                }
+                FunctionArgs::Args { args, .. } | FunctionArgs::WithinGroup { args, .. } => args,
+            };
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            args = FunctionArgs::WithinGroup {
+                args: direct_args,
+                order_by,
+            };
+        }
+
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -664,7 +713,7 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    fn parse_window_frame(&mut self) -> Result<WindowFrame, ParserError> {
+    fn parse_window_frame(&mut self) -> Result<WindowFrame<Raw>, ParserError> {
         let units = match self.expect_one_of_keywords(&[ROWS, RANGE, GROUPS])? {
             ROWS => WindowFrameUnits::Rows,
             RANGE => WindowFrameUnits::Range,
@@ -686,20 +735,20 @@ impl<'a> Parser<'a> {
         })
     }
 
-    /// Parse `CURRENT ROW` or `{ <positive number> | UNBOUNDED } { PRECEDING | FOLLOWING }`
-    fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound, ParserError> {
+    /// Parse `CURRENT ROW` or `{ <offset expression> | UNBOUNDED } { PRECEDING | FOLLOWING }`
+    fn parse_window_frame_bound(&mut self) -> Result<WindowFrameBound<Raw>, ParserError> {
         if self.parse_keywords(&[CURRENT, ROW]) {
             Ok(WindowFrameBound::CurrentRow)
         } else {
-            let rows = if self.parse_keyword(UNBOUNDED) {
+            let offset = if self.parse_keyword(UNBOUNDED) {
                 None
             } else {
-                Some(self.parse_literal_uint()?)
+                Some(Box::new(self.parse_expr()?))
             };
             if self.parse_keyword(PRECEDING) {
-                Ok(WindowFrameBound::Preceding(rows))
+                Ok(WindowFrameBound::Preceding(offset))
             } else if self.parse_keyword(FOLLOWING) {
-                Ok(WindowFrameBound::Following(rows))
+                Ok(WindowFrameBound::Following(offset))
             } else {
                 self.expected(self.peek_pos(), "PRECEDING or FOLLOWING", self.peek_token())
             }
@@ -776,6 +825,13 @@ impl<'a> Parser<'a> {
         Ok(Expr::NullIf { l_expr, r_expr })
     }
 
+    fn parse_grouping_expr(&mut self) -> Result<Expr<Raw>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let exprs = self.parse_comma_separated(Parser::parse_expr)?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::Grouping { exprs })
+    }
+
     fn parse_extract_expr(&mut self) -> Result<Expr<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let field = match self.next_token() {
@@ -1983,7 +2039,7 @@ impl<'a> Parser<'a> {
             _ => unreachable!(),
         };
         let connection = match self
-            .expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, POSTGRES, SSH])?
+            .expect_one_of_keywords(&[AWS, KAFKA, CONFLUENT, MYSQL, POSTGRES, SSH])?
         {
             AWS => {
                 if self.parse_keyword(PRIVATELINK) {
@@ -2027,6 +2083,14 @@ impl<'a> Parser<'a> {
                     self.parse_comma_separated(Parser::parse_postgres_connection_option)?;
                 CreateConnection::Postgres { with_options }
             }
+            MYSQL => {
+                if expect_paren {
+                    self.expect_token(&Token::LParen)?;
+                }
+                let with_options =
+                    self.parse_comma_separated(Parser::parse_mysql_connection_option)?;
+                CreateConnection::MySql { with_options }
+            }
             SSH => {
                 self.expect_keyword(TUNNEL)?;
                 if expect_paren {
@@ -2335,6 +2399,48 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_mysql_connection_option(&mut self) -> Result<MySqlConnectionOption<Raw>, ParserError> {
+        let name = match self
+            .expect_one_of_keywords(&[AWS, HOST, PASSWORD, PORT, SSH, SSL, USER, USERNAME])?
+        {
+            AWS => {
+                self.expect_keyword(PRIVATELINK)?;
+                return Ok(MySqlConnectionOption {
+                    name: MySqlConnectionOptionName::AwsPrivatelink,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            HOST => MySqlConnectionOptionName::Host,
+            PASSWORD => MySqlConnectionOptionName::Password,
+            PORT => MySqlConnectionOptionName::Port,
+            SSH => {
+                self.expect_keyword(TUNNEL)?;
+                return Ok(MySqlConnectionOption {
+                    name: MySqlConnectionOptionName::SshTunnel,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            SSL => match self.expect_one_of_keywords(&[CERTIFICATE, MODE, KEY])? {
+                CERTIFICATE => {
+                    if self.parse_keyword(AUTHORITY) {
+                        MySqlConnectionOptionName::SslCertificateAuthority
+                    } else {
+                        MySqlConnectionOptionName::SslCertificate
+                    }
+                }
+                KEY => MySqlConnectionOptionName::SslKey,
+                MODE => MySqlConnectionOptionName::SslMode,
+                _ => unreachable!(),
+            },
+            USER | USERNAME => MySqlConnectionOptionName::User,
+            _ => unreachable!(),
+        };
+        Ok(MySqlConnectionOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_aws_connection_option(&mut self) -> Result<AwsConnectionOption<Raw>, ParserError> {
         let name =
             match self.expect_one_of_keywords(&[ACCESS, ENDPOINT, REGION, ROLE, SECRET, TOKEN])? {
@@ -2446,6 +2552,25 @@ impl<'a> Parser<'a> {
         let (col_names, key_constraint) = self.parse_source_columns()?;
         let in_cluster = self.parse_optional_in_cluster()?;
         self.expect_keyword(FROM)?;
+
+        if self.peek_keyword(WEBHOOK) {
+            if !col_names.is_empty() || key_constraint.is_some() {
+                return parser_err!(
+                    self,
+                    self.peek_pos(),
+                    "CREATE SOURCE ... FROM WEBHOOK does not support column names or key constraints"
+                );
+            }
+            if in_cluster.is_some() {
+                return parser_err!(
+                    self,
+                    self.peek_pos(),
+                    "CREATE SOURCE ... FROM WEBHOOK does not support IN CLUSTER"
+                );
+            }
+            return self.parse_create_webhook_source(name, if_not_exists);
+        }
+
         let connection = self.parse_create_source_connection()?;
         let format = match self.parse_one_of_keywords(&[KEY, FORMAT]) {
             Some(KEY) => {
@@ -2529,6 +2654,58 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses the remainder of a `CREATE SOURCE .. FROM WEBHOOK` statement,
+    /// starting at the `WEBHOOK` keyword.
+    fn parse_create_webhook_source(
+        &mut self,
+        name: UnresolvedItemName,
+        if_not_exists: bool,
+    ) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keywords(&[WEBHOOK, BODY, FORMAT])?;
+        let body_format = self.parse_format()?;
+        let include_headers = self.parse_keywords(&[INCLUDE, HEADERS]);
+
+        let validate_using = if self.parse_keywords(&[VALIDATE, USING, HMAC]) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_webhook_validation_option)?;
+            self.expect_token(&Token::RParen)?;
+            Some(options)
+        } else {
+            None
+        };
+
+        Ok(Statement::CreateWebhookSource(
+            CreateWebhookSourceStatement {
+                name,
+                if_not_exists,
+                body_format,
+                include_headers,
+                validate_using,
+            },
+        ))
+    }
+
+    fn parse_webhook_validation_option(
+        &mut self,
+    ) -> Result<WebhookValidationOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[ALGORITHM, ENCODING, HEADER, SECRET])? {
+            ALGORITHM => WebhookValidationOptionName::Algorithm,
+            ENCODING => WebhookValidationOptionName::Encoding,
+            HEADER => WebhookValidationOptionName::Header,
+            SECRET => {
+                return Ok(WebhookValidationOption {
+                    name: WebhookValidationOptionName::Secret,
+                    value: Some(self.parse_object_option_value()?),
+                });
+            }
+            _ => unreachable!(),
+        };
+        Ok(WebhookValidationOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     /// Parses the column section of a CREATE SOURCE statement which can be
     /// empty or a comma-separated list of column identifiers and a single key
     /// constraint, e.g.
@@ -2572,7 +2749,9 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_source_option_name(&mut self) -> Result<CreateSourceOptionName, ParserError> {
-        let name = match self.expect_one_of_keywords(&[IGNORE, SIZE, TIMELINE, TIMESTAMP, DISK])? {
+        let name = match self
+            .expect_one_of_keywords(&[IGNORE, SIZE, TIMELINE, TIMESTAMP, DISK, RETAIN])?
+        {
             IGNORE => {
                 self.expect_keyword(KEYS)?;
                 CreateSourceOptionName::IgnoreKeys
@@ -2584,6 +2763,10 @@ impl<'a> Parser<'a> {
                 CreateSourceOptionName::TimestampInterval
             }
             DISK => CreateSourceOptionName::Disk,
+            RETAIN => {
+                self.expect_keywords(&[KEYS, FOR])?;
+                CreateSourceOptionName::RetainKeysFor
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -2660,7 +2843,7 @@ impl<'a> Parser<'a> {
     fn parse_create_source_connection(
         &mut self,
     ) -> Result<CreateSourceConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, POSTGRES, LOAD, TEST])? {
+        match self.expect_one_of_keywords(&[KAFKA, MYSQL, POSTGRES, LOAD, TEST])? {
             POSTGRES => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_raw_name()?;
@@ -2678,6 +2861,23 @@ impl<'a> Parser<'a> {
                     options,
                 })
             }
+            MYSQL => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_mysql_config_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
+
+                Ok(CreateSourceConnection::MySql {
+                    connection,
+                    options,
+                })
+            }
             KAFKA => {
                 self.expect_keyword(CONNECTION)?;
                 let connection = self.parse_kafka_connection_reference()?;
@@ -2728,8 +2928,29 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_pg_connection_option(&mut self) -> Result<PgConfigOption<Raw>, ParserError> {
-        let name = match self.expect_one_of_keywords(&[DETAILS, PUBLICATION, TEXT])? {
+        let name = match self.expect_one_of_keywords(&[DETAILS, ENUM, PUBLICATION, TEXT])? {
             DETAILS => PgConfigOptionName::Details,
+            ENUM => {
+                self.expect_keyword(TYPES)?;
+
+                let _ = self.consume_token(&Token::Eq);
+
+                let value = self
+                    .parse_option_sequence(Parser::parse_data_type)?
+                    .map(|inner| {
+                        WithOptionValue::Sequence(
+                            inner
+                                .into_iter()
+                                .map(WithOptionValue::DataType)
+                                .collect_vec(),
+                        )
+                    });
+
+                return Ok(PgConfigOption {
+                    name: PgConfigOptionName::EnumTypes,
+                    value,
+                });
+            }
             PUBLICATION => PgConfigOptionName::Publication,
             TEXT => {
                 self.expect_keyword(COLUMNS)?;
@@ -2760,6 +2981,38 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_mysql_config_option(&mut self) -> Result<MySqlConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[DETAILS, TEXT])? {
+            DETAILS => MySqlConfigOptionName::Details,
+            TEXT => {
+                self.expect_keyword(COLUMNS)?;
+
+                let _ = self.consume_token(&Token::Eq);
+
+                let value = self
+                    .parse_option_sequence(Parser::parse_item_name)?
+                    .map(|inner| {
+                        WithOptionValue::Sequence(
+                            inner
+                                .into_iter()
+                                .map(WithOptionValue::UnresolvedItemName)
+                                .collect_vec(),
+                        )
+                    });
+
+                return Ok(MySqlConfigOption {
+                    name: MySqlConfigOptionName::TextColumns,
+                    value,
+                });
+            }
+            _ => unreachable!(),
+        };
+        Ok(MySqlConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_load_generator_option(&mut self) -> Result<LoadGeneratorOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[SCALE, TICK, MAX])? {
             SCALE => {
@@ -2785,33 +3038,94 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        self.expect_keyword(KAFKA)?;
-        self.expect_keyword(CONNECTION)?;
+        match self.expect_one_of_keywords(&[FILE, KAFKA, POSTGRES])? {
+            FILE => {
+                let connection = if self.parse_keyword(CONNECTION) {
+                    Some(self.parse_raw_name()?)
+                } else {
+                    None
+                };
 
-        let connection = self.parse_kafka_connection_reference()?;
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_file_sink_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
+                } else {
+                    vec![]
+                };
 
-        // one token of lookahead:
-        // * `KEY (` means we're parsing a list of columns for the key
-        // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
-        let key =
-            if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
-                let _ = self.expect_keyword(KEY);
-                let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+                Ok(CreateSinkConnection::File {
+                    connection,
+                    options,
+                })
+            }
+            KAFKA => {
+                self.expect_keyword(CONNECTION)?;
 
-                let not_enforced = if self.peek_keywords(&[NOT, ENFORCED]) {
-                    let _ = self.expect_keywords(&[NOT, ENFORCED])?;
-                    true
+                let connection = self.parse_kafka_connection_reference()?;
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Kafka { connection, key })
+            }
+            POSTGRES => {
+                self.expect_keyword(CONNECTION)?;
+                let connection = self.parse_raw_name()?;
+
+                let options = if self.consume_token(&Token::LParen) {
+                    let options = self.parse_comma_separated(Parser::parse_pg_sink_option)?;
+                    self.expect_token(&Token::RParen)?;
+                    options
                 } else {
-                    false
+                    vec![]
                 };
-                Some(KafkaSinkKey {
-                    key_columns,
-                    not_enforced,
+
+                let key = self.parse_sink_key()?;
+                Ok(CreateSinkConnection::Postgres {
+                    connection,
+                    options,
+                    key,
                 })
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_sink_key(&mut self) -> Result<Option<KafkaSinkKey>, ParserError> {
+        // one token of lookahead:
+        // * `KEY (` means we're parsing a list of columns for the key
+        // * `KEY FORMAT` means there is no key, we'll parse a KeyValueFormat later
+        if self.peek_keyword(KEY) && self.peek_nth_token(1) != Some(Token::Keyword(FORMAT)) {
+            let _ = self.expect_keyword(KEY);
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = if self.peek_keywords(&[NOT, ENFORCED]) {
+                let _ = self.expect_keywords(&[NOT, ENFORCED])?;
+                true
             } else {
-                None
+                false
             };
-        Ok(CreateSinkConnection::Kafka { connection, key })
+            Ok(Some(KafkaSinkKey {
+                key_columns,
+                not_enforced,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn parse_pg_sink_option(&mut self) -> Result<PgSinkConfigOption<Raw>, ParserError> {
+        self.expect_keyword(TABLE)?;
+        Ok(PgSinkConfigOption {
+            name: PgSinkConfigOptionName::Table,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_file_sink_option(&mut self) -> Result<FileSinkConfigOption<Raw>, ParserError> {
+        self.expect_keyword(URL)?;
+        Ok(FileSinkConfigOption {
+            name: FileSinkConfigOptionName::Url,
+            value: self.parse_optional_option_value()?,
+        })
     }
 
     fn parse_create_view(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -2980,11 +3294,11 @@ impl<'a> Parser<'a> {
         self.expect_keyword(ROLE)?;
         let name = self.parse_identifier()?;
         let _ = self.parse_keyword(WITH);
-        let options = self.parse_role_attributes();
+        let options = self.parse_role_attributes()?;
         Ok(Statement::CreateRole(CreateRoleStatement { name, options }))
     }
 
-    fn parse_role_attributes(&mut self) -> Vec<RoleAttribute> {
+    fn parse_role_attributes(&mut self) -> Result<Vec<RoleAttribute>, ParserError> {
         let mut options = vec![];
         loop {
             match self.parse_one_of_keywords(&[
@@ -3000,6 +3314,7 @@ impl<'a> Parser<'a> {
                 NOCREATEDB,
                 CREATEROLE,
                 NOCREATEROLE,
+                PASSWORD,
             ]) {
                 None => break,
                 Some(SUPERUSER) => options.push(RoleAttribute::SuperUser),
@@ -3014,10 +3329,18 @@ impl<'a> Parser<'a> {
                 Some(NOCREATEDB) => options.push(RoleAttribute::NoCreateDB),
                 Some(CREATEROLE) => options.push(RoleAttribute::CreateRole),
                 Some(NOCREATEROLE) => options.push(RoleAttribute::NoCreateRole),
+                Some(PASSWORD) => {
+                    let password = if self.parse_keyword(NULL) {
+                        None
+                    } else {
+                        Some(self.parse_literal_string()?)
+                    };
+                    options.push(RoleAttribute::Password(password));
+                }
                 Some(_) => unreachable!(),
             }
         }
-        options
+        Ok(options)
     }
 
     fn parse_create_secret(&mut self) -> Result<Statement<Raw>, ParserError> {
@@ -3038,7 +3361,21 @@ impl<'a> Parser<'a> {
         let name = self.parse_item_name()?;
         self.expect_keyword(AS)?;
 
-        match self.parse_one_of_keywords(&[LIST, MAP]) {
+        match self.parse_one_of_keywords(&[ENUM, LIST, MAP]) {
+            Some(ENUM) => {
+                self.expect_token(&Token::LParen)?;
+                let labels = if self.consume_token(&Token::RParen) {
+                    vec![]
+                } else {
+                    let labels = self.parse_comma_separated(Parser::parse_literal_string)?;
+                    self.expect_token(&Token::RParen)?;
+                    labels
+                };
+                Ok(Statement::CreateType(CreateTypeStatement {
+                    name,
+                    as_type: CreateTypeAs::Enum { labels },
+                }))
+            }
             Some(LIST) => {
                 self.expect_token(&Token::LParen)?;
                 let options = self.parse_comma_separated(Parser::parse_create_type_list_option)?;
@@ -3403,26 +3740,7 @@ impl<'a> Parser<'a> {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Some(column_name) = self.consume_identifier() {
-                let data_type = self.parse_data_type()?;
-                let collation = if self.parse_keyword(COLLATE) {
-                    Some(self.parse_item_name()?)
-                } else {
-                    None
-                };
-                let mut options = vec![];
-                loop {
-                    match self.peek_token() {
-                        None | Some(Token::Comma) | Some(Token::RParen) => break,
-                        _ => options.push(self.parse_column_option_def()?),
-                    }
-                }
-
-                columns.push(ColumnDef {
-                    name: column_name,
-                    data_type,
-                    collation,
-                    options,
-                });
+                columns.push(self.parse_column_def(column_name)?);
             } else {
                 return self.expected(
                     self.peek_pos(),
@@ -3446,6 +3764,30 @@ impl<'a> Parser<'a> {
         Ok((columns, constraints))
     }
 
+    /// Parses the remainder of a column definition, after its name.
+    fn parse_column_def(&mut self, name: Ident) -> Result<ColumnDef<Raw>, ParserError> {
+        let data_type = self.parse_data_type()?;
+        let collation = if self.parse_keyword(COLLATE) {
+            Some(self.parse_item_name()?)
+        } else {
+            None
+        };
+        let mut options = vec![];
+        loop {
+            match self.peek_token() {
+                None | Some(Token::Comma) | Some(Token::RParen) | Some(Token::Semicolon) => break,
+                _ => options.push(self.parse_column_option_def()?),
+            }
+        }
+
+        Ok(ColumnDef {
+            name,
+            data_type,
+            collation,
+            options,
+        })
+    }
+
     fn parse_column_option_def(&mut self) -> Result<ColumnOptionDef<Raw>, ParserError> {
         let name = if self.parse_keyword(CONSTRAINT) {
             Some(self.parse_identifier()?)
@@ -3637,7 +3979,8 @@ impl<'a> Parser<'a> {
             ObjectType::Index => self.parse_alter_index(),
             ObjectType::Secret => self.parse_alter_secret(),
             ObjectType::Connection => self.parse_alter_connection(),
-            ObjectType::View | ObjectType::MaterializedView | ObjectType::Table => {
+            ObjectType::Table => self.parse_alter_table(),
+            ObjectType::View | ObjectType::MaterializedView => {
                 let if_exists = self.parse_if_exists()?;
                 let name = self.parse_item_name()?;
                 let action = self.expect_one_of_keywords(&[RENAME, OWNER])?;
@@ -3732,6 +4075,65 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_alter_table(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let object_type = ObjectType::Table;
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_item_name()?;
+
+        Ok(
+            match self.expect_one_of_keywords(&[ADD, DROP, RENAME, OWNER])? {
+                ADD => {
+                    let _ = self.parse_keyword(COLUMN);
+                    let if_not_exists = self.parse_if_not_exists()?;
+                    let column_name = self.parse_identifier()?;
+                    let column = self.parse_column_def(column_name)?;
+                    Statement::AlterTable(AlterTableStatement {
+                        table_name: name,
+                        if_exists,
+                        action: AlterTableAction::AddColumn {
+                            if_not_exists,
+                            column,
+                        },
+                    })
+                }
+                DROP => {
+                    let _ = self.parse_keyword(COLUMN);
+                    let column_if_exists = self.parse_if_exists()?;
+                    let column_name = self.parse_identifier()?;
+                    Statement::AlterTable(AlterTableStatement {
+                        table_name: name,
+                        if_exists,
+                        action: AlterTableAction::DropColumn {
+                            if_exists: column_if_exists,
+                            name: column_name,
+                        },
+                    })
+                }
+                RENAME => {
+                    self.expect_keyword(TO)?;
+                    let to_item_name = self.parse_identifier()?;
+                    Statement::AlterObjectRename(AlterObjectRenameStatement {
+                        object_type,
+                        if_exists,
+                        name,
+                        to_item_name,
+                    })
+                }
+                OWNER => {
+                    self.expect_keyword(TO)?;
+                    let new_owner = self.parse_identifier()?;
+                    Statement::AlterOwner(AlterOwnerStatement {
+                        object_type,
+                        if_exists,
+                        name: UnresolvedObjectName::Item(name),
+                        new_owner,
+                    })
+                }
+                _ => unreachable!(),
+            },
+        )
+    }
+
     fn parse_alter_source(&mut self) -> Result<Statement<Raw>, ParserError> {
         let if_exists = self.parse_if_exists()?;
         let name = self.parse_item_name()?;
@@ -4007,7 +4409,7 @@ impl<'a> Parser<'a> {
     fn parse_alter_role(&mut self) -> Result<Statement<Raw>, ParserError> {
         let name = self.parse_identifier()?;
         let _ = self.parse_keyword(WITH);
-        let options = self.parse_role_attributes();
+        let options = self.parse_role_attributes()?;
         Ok(Statement::AlterRole(AlterRoleStatement { name, options }))
     }
 
@@ -4631,8 +5033,9 @@ impl<'a> Parser<'a> {
                     CteBlock::MutuallyRecursive(
                         parser.parse_comma_separated(Parser::parse_cte_mut_rec)?,
                     )
+                } else if parser.parse_keyword(RECURSIVE) {
+                    CteBlock::Recursive(parser.parse_comma_separated(Parser::parse_cte)?)
                 } else {
-                    // TODO: optional RECURSIVE
                     CteBlock::Simple(parser.parse_comma_separated(Parser::parse_cte)?)
                 }
             } else {
@@ -4933,7 +5336,7 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[GROUP, BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated(Parser::parse_group_by_expr)?
         } else {
             vec![]
         };
@@ -4964,6 +5367,55 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses an item of a `GROUP BY` clause, which is either an expression,
+    /// the empty grouping set `()`, or a `ROLLUP`, `CUBE` or `GROUPING SETS`
+    /// clause.
+    fn parse_group_by_expr(&mut self) -> Result<GroupByExpr<Raw>, ParserError> {
+        if self.peek_token() == Some(Token::LParen) && self.peek_nth_token(1) == Some(Token::RParen)
+        {
+            self.expect_token(&Token::LParen)?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByExpr::List(vec![]))
+        } else if self.peek_keyword(ROLLUP) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.expect_keyword(ROLLUP)?;
+            Ok(GroupByExpr::Rollup(self.parse_grouping_elements()?))
+        } else if self.peek_keyword(CUBE) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.expect_keyword(CUBE)?;
+            Ok(GroupByExpr::Cube(self.parse_grouping_elements()?))
+        } else if self.parse_keywords(&[GROUPING, SETS]) {
+            self.expect_token(&Token::LParen)?;
+            let sets =
+                self.parse_comma_separated(|parser| match parser.parse_group_by_expr()? {
+                    GroupByExpr::Expr(expr) => Ok(Self::grouping_element(expr)),
+                    expr => Ok(expr),
+                })?;
+            self.expect_token(&Token::RParen)?;
+            Ok(GroupByExpr::GroupingSets(sets))
+        } else {
+            Ok(GroupByExpr::Expr(self.parse_expr()?))
+        }
+    }
+
+    /// Parses the parenthesized elements of a `ROLLUP` or `CUBE` clause, each
+    /// of which is an expression or a non-empty list of expressions.
+    fn parse_grouping_elements(&mut self) -> Result<Vec<GroupByExpr<Raw>>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let elements =
+            self.parse_comma_separated(|parser| Ok(Self::grouping_element(parser.parse_expr()?)))?;
+        self.expect_token(&Token::RParen)?;
+        Ok(elements)
+    }
+
+    /// Within `GROUPING SETS`, `ROLLUP` and `CUBE`, parenthesized expressions
+    /// denote lists of grouping expressions rather than row constructors.
+    fn grouping_element(expr: Expr<Raw>) -> GroupByExpr<Raw> {
+        match expr {
+            Expr::Row { exprs } => GroupByExpr::List(exprs),
+            Expr::Nested(expr) => GroupByExpr::List(vec![*expr]),
+            expr => GroupByExpr::Expr(expr),
+        }
+    }
+
     fn parse_select_option(&mut self) -> Result<SelectOption<Raw>, ParserError> {
         self.expect_keywords(&[EXPECTED, GROUP, SIZE])?;
         let name = SelectOptionName::ExpectedGroupSize;
@@ -131,7 +131,8 @@ fn datadriven() {
                     return "expected exactly one statement\n".to_string();
                 }
                 let stmt = s.into_element();
-                let parsed = match parser::parse_statements(&stmt.to_string()) {
+                // Reparse the stable form, as the simple form redacts secrets.
+                let parsed = match parser::parse_statements(&stmt.to_ast_string_stable()) {
                     Ok(parsed) => parsed.into_element(),
                     Err(err) => return format!("reparse failed: {}\n", err),
                 };
@@ -19,8 +19,8 @@ use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
     AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
     CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateTableStatement,
-    CreateViewStatement, Expr, Ident, Query, Raw, RawItemName, Statement, UnresolvedItemName,
-    ViewDefinition,
+    CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query, Raw, RawItemName,
+    Statement, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -35,6 +35,7 @@ pub fn create_stmt_rename(create_stmt: &mut Statement<Raw>, to_item_name: String
         }
         Statement::CreateSink(CreateSinkStatement { name, .. })
         | Statement::CreateSource(CreateSourceStatement { name, .. })
+        | Statement::CreateWebhookSource(CreateWebhookSourceStatement { name, .. })
         | Statement::CreateView(CreateViewStatement {
             definition: ViewDefinition { name, .. },
             ..
@@ -103,6 +104,7 @@ pub fn create_stmt_rename_refs(
             rewrite_query(from_name, to_item_name, query)?;
         }
         Statement::CreateSource(_)
+        | Statement::CreateWebhookSource(_)
         | Statement::CreateTable(_)
         | Statement::CreateSecret(_)
         | Statement::CreateConnection(_) => {}
@@ -48,6 +48,7 @@ use crate::normalize;
 use crate::plan::statement::ddl::PlannedRoleAttributes;
 use crate::plan::statement::StatementDesc;
 use crate::plan::PlanError;
+use crate::session::password::ScramSecret;
 use crate::session::vars::SystemVars;
 
 /// A catalog keeps track of SQL objects and session state available to the
@@ -383,6 +384,10 @@ pub struct RoleAttributes {
     pub create_db: bool,
     /// Indicates whether the role is allowed to create clusters.
     pub create_cluster: bool,
+    /// The verifier of the role's password, if the role has a password.
+    // Roles that were serialized before passwords existed have no password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<ScramSecret>,
     // Force use of constructor.
     _private: (),
 }
@@ -395,6 +400,7 @@ impl RoleAttributes {
             create_role: false,
             create_db: false,
             create_cluster: false,
+            password: None,
             _private: (),
         }
     }
@@ -434,6 +440,7 @@ impl From<PlannedRoleAttributes> for RoleAttributes {
             create_role,
             create_db,
             create_cluster,
+            password,
         }: PlannedRoleAttributes,
     ) -> RoleAttributes {
         let default_attributes = RoleAttributes::new();
@@ -442,6 +449,7 @@ impl From<PlannedRoleAttributes> for RoleAttributes {
             create_role: create_role.unwrap_or(default_attributes.create_role),
             create_db: create_db.unwrap_or(default_attributes.create_db),
             create_cluster: create_cluster.unwrap_or(default_attributes.create_cluster),
+            password: password.unwrap_or(default_attributes.password),
             _private: (),
         }
     }
@@ -456,6 +464,7 @@ impl From<(&dyn CatalogRole, PlannedRoleAttributes)> for RoleAttributes {
                 create_role,
                 create_db,
                 create_cluster,
+                password,
             },
         ): (&dyn CatalogRole, PlannedRoleAttributes),
     ) -> RoleAttributes {
@@ -464,6 +473,7 @@ impl From<(&dyn CatalogRole, PlannedRoleAttributes)> for RoleAttributes {
             create_role: create_role.unwrap_or_else(|| role.create_role()),
             create_db: create_db.unwrap_or_else(|| role.create_db()),
             create_cluster: create_cluster.unwrap_or_else(|| role.create_cluster()),
+            password: password.unwrap_or_else(|| role.password().cloned()),
             _private: (),
         }
     }
@@ -489,6 +499,10 @@ pub trait CatalogRole {
     /// Indicates whether the role has the cluster creation attribute.
     fn create_cluster(&self) -> bool;
 
+    /// Returns the verifier of the role's password, if the role has a
+    /// password.
+    fn password(&self) -> Option<&ScramSecret>;
+
     /// Returns all role IDs that this role is an immediate a member of, and the grantor of that
     /// membership.
     ///
@@ -718,6 +732,9 @@ pub enum CatalogType<T: TypeReference> {
     Bytes,
     Char,
     Date,
+    Enum {
+        labels: Vec<String>,
+    },
     Float32,
     Float64,
     Int16,
@@ -757,6 +774,8 @@ pub enum CatalogType<T: TypeReference> {
     VarChar,
     Int2Vector,
     MzAclItem,
+    Inet,
+    Cidr,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
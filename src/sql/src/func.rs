@@ -17,9 +17,11 @@ use std::fmt;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 
-use mz_expr::func;
+use mz_expr::{func, EvalError};
 use mz_ore::collections::CollectionExt;
 use mz_pgrepr::oid;
+use mz_repr::adt::jsonpath::JsonPath;
+use mz_repr::adt::regex::Regex;
 use mz_repr::{ColumnName, ColumnType, Datum, RelationType, Row, ScalarBaseType, ScalarType};
 
 use crate::ast::{SelectStatement, Statement};
@@ -106,6 +108,8 @@ impl TypeCategory {
             ScalarType::Map { .. } => Self::Pseudo,
             ScalarType::MzTimestamp => Self::Numeric,
             ScalarType::Range { .. } => Self::Range,
+            ScalarType::Inet | ScalarType::Cidr => Self::NetworkAddress,
+            ScalarType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -122,7 +126,8 @@ impl TypeCategory {
             | ParamType::NonVecAny
             | ParamType::MapAny
             | ParamType::MapAnyCompatible
-            | ParamType::RecordAny => Self::Pseudo,
+            | ParamType::RecordAny
+            | ParamType::EnumAny => Self::Pseudo,
             ParamType::RangeAnyCompatible | ParamType::RangeAny => Self::Range,
             ParamType::Plain(t) => Self::from_type(t),
         }
@@ -170,6 +175,8 @@ impl TypeCategory {
             CatalogType::Map { .. } | CatalogType::Pseudo => Self::Pseudo,
             CatalogType::MzTimestamp => Self::String,
             CatalogType::Range { .. } => Self::Range,
+            CatalogType::Inet | CatalogType::Cidr => Self::NetworkAddress,
+            CatalogType::Enum { .. } => Self::Enum,
         }
     }
 
@@ -188,13 +195,13 @@ impl TypeCategory {
             | Self::Enum
             | Self::Geometric
             | Self::List
-            | Self::NetworkAddress
             | Self::Pseudo
             | Self::Range
             | Self::Unknown
             | Self::UserDefined => None,
             Self::Boolean => Some(ScalarType::Bool),
             Self::DateTime => Some(ScalarType::TimestampTz),
+            Self::NetworkAddress => Some(ScalarType::Inet),
             Self::Numeric => Some(ScalarType::Float64),
             Self::String => Some(ScalarType::String),
             Self::Timespan => Some(ScalarType::Interval),
@@ -450,6 +457,66 @@ fn experimental_sql_impl_table_func(
     sql_impl_table_func_inner(sql, Some(feature))
 }
 
+/// Plans a call to `regexp_matches`. If its pattern and flags are literals,
+/// the regex is compiled during planning; otherwise it is compiled for each
+/// row.
+fn plan_regexp_matches(mut exprs: Vec<HirScalarExpr>) -> Result<TableFuncPlan, PlanError> {
+    let needle = exprs[1].clone().into_literal_string();
+    let flags = match exprs.get(2) {
+        Some(flags) => flags.clone().into_literal_string(),
+        None => Some(String::new()),
+    };
+    let func = match (needle, flags) {
+        (Some(needle), Some(flags)) => {
+            let (regex, limit) = func::build_regex_with_limit(&needle, &flags)?;
+            exprs.truncate(1);
+            TableFunc::RegexpMatches {
+                regex: Regex(regex),
+                global: limit == 0,
+            }
+        }
+        _ => TableFunc::RegexpMatchesDynamic,
+    };
+    Ok(TableFuncPlan {
+        expr: HirRelationExpr::CallTable { func, exprs },
+        column_names: vec!["regexp_matches".into()],
+    })
+}
+
+/// Plans a call to `jsonb_path_query`. If its path is a literal, the path is
+/// parsed during planning; otherwise it is parsed for each row.
+fn plan_jsonb_path_query(mut exprs: Vec<HirScalarExpr>) -> Result<TableFuncPlan, PlanError> {
+    let func = match exprs[1].clone().into_literal_string() {
+        Some(path) => {
+            let path = path.parse::<JsonPath>().map_err(EvalError::from)?;
+            exprs.remove(1);
+            TableFunc::JsonbPathQuery { path }
+        }
+        None => TableFunc::JsonbPathQueryDynamic,
+    };
+    Ok(TableFuncPlan {
+        expr: HirRelationExpr::CallTable { func, exprs },
+        column_names: vec!["jsonb_path_query".into()],
+    })
+}
+
+/// Plans the `@?` and `@@` operators, which behave like the corresponding
+/// `jsonb_path_*` function with no variables and with errors suppressed.
+fn jsonb_path_operator(
+    func: VariadicFunc,
+    target: HirScalarExpr,
+    path: HirScalarExpr,
+) -> HirScalarExpr {
+    let vars = HirScalarExpr::CallVariadic {
+        func: VariadicFunc::JsonbBuildObject,
+        exprs: vec![],
+    };
+    HirScalarExpr::CallVariadic {
+        func,
+        exprs: vec![target, path, vars, HirScalarExpr::literal_true()],
+    }
+}
+
 /// Describes a single function's implementation.
 pub struct FuncImpl<R> {
     pub oid: u32,
@@ -689,6 +756,9 @@ pub enum ParamType {
     /// this type into generating non-existent range types (e.g. ranges of
     /// floats) that will panic.
     RangeAnyCompatible,
+    /// An pseudotype permitting any enum type, requiring other "Any"-type
+    /// parameters to be of the same type.
+    EnumAny,
 }
 
 impl ParamType {
@@ -703,6 +773,7 @@ impl ParamType {
             ListAny | ListAnyCompatible => matches!(t, List { .. }),
             MapAny | MapAnyCompatible => matches!(t, Map { .. }),
             RangeAny | RangeAnyCompatible => matches!(t, Range { .. }),
+            EnumAny => matches!(t, Enum { .. }),
             NonVecAny => !t.is_vec(),
             Plain(to) => typeconv::can_cast(ecx, CastContext::Implicit, t, to),
             RecordAny => matches!(t, Record { .. }),
@@ -756,7 +827,8 @@ impl ParamType {
             // `PolymorphicCompatClass::StructuralEq`.
             | RecordAny
             | RangeAny
-            | RangeAnyCompatible => true,
+            | RangeAnyCompatible
+            | EnumAny => true,
             Any | Plain(_)  => false,
         }
     }
@@ -785,6 +857,7 @@ impl ParamType {
             ParamType::RecordAny => "record",
             ParamType::RangeAny => "anyrange",
             ParamType::RangeAnyCompatible => "anycompatiblerange",
+            ParamType::EnumAny => "anyenum",
         }
     }
 }
@@ -815,7 +888,7 @@ impl From<ScalarBaseType> for ParamType {
     fn from(s: ScalarBaseType) -> ParamType {
         use ScalarBaseType::*;
         let s = match s {
-            Array | List | Map | Record | Range => {
+            Array | List | Map | Record | Range | Enum => {
                 panic!("use polymorphic parameters rather than {:?}", s);
             }
             Bool => ScalarType::Bool,
@@ -847,6 +920,8 @@ impl From<ScalarBaseType> for ParamType {
             Int2Vector => ScalarType::Int2Vector,
             MzTimestamp => ScalarType::MzTimestamp,
             MzAclItem => ScalarType::MzAclItem,
+            Inet => ScalarType::Inet,
+            Cidr => ScalarType::Cidr,
         };
         ParamType::Plain(s)
     }
@@ -1256,7 +1331,7 @@ impl TryFrom<&ParamType> for PolymorphicCompatClass {
         use ParamType::*;
 
         Ok(match param {
-            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny => {
+            AnyElement | ArrayAny | ListAny | MapAny | NonVecAny | RangeAny | EnumAny => {
                 PolymorphicCompatClass::Any
             }
             ArrayAnyCompatible | AnyCompatible | RangeAnyCompatible => {
@@ -1329,7 +1404,7 @@ impl PolymorphicSolution {
 
         self.seen.push(match param {
             // These represent the keys of their respective compatibility classes.
-            AnyElement | AnyCompatible | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny => seen,
+            AnyElement | AnyCompatible | ListAnyCompatible |  MapAnyCompatible | NonVecAny | RecordAny | EnumAny => seen,
             MapAny => seen.map(|array| array.unwrap_map_value_type().clone()),
             ListAny => seen.map(|array| array.unwrap_list_element_type().clone()),
             ArrayAny | ArrayAnyCompatible => seen.map(|array| array.unwrap_array_element_type().clone()),
@@ -1449,9 +1524,8 @@ impl PolymorphicSolution {
         );
 
         match param {
-            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny => {
-                self.key.clone()
-            }
+            AnyElement | AnyCompatible | ListAnyCompatible | MapAnyCompatible | NonVecAny
+            | EnumAny => self.key.clone(),
             ArrayAny | ArrayAnyCompatible => self
                 .key
                 .as_ref()
@@ -1503,7 +1577,7 @@ fn coerce_args_to_types(
                 }
                 _ => cexpr.type_as_any(ecx)?,
             },
-            p @ (ArrayAny | ListAny | MapAny | RangeAny) => {
+            p @ (ArrayAny | ListAny | MapAny | RangeAny | EnumAny) => {
                 let target = polymorphic_solution
                     .target_for_param_type(p)
                     .ok_or_else(|| {
@@ -1852,6 +1926,63 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String) => BinaryFunc::DigestString => Bytes, 44154;
             params!(Bytes, String) => BinaryFunc::DigestBytes => Bytes, 44155;
         },
+        // The enum functions only depend on the type of their arguments, so
+        // they are planned as literals wherever possible.
+        "enum_first" => Scalar {
+            params!(EnumAny) => Operation::unary(|ecx, e| {
+                let ty = ecx.scalar_type(&e);
+                if ty.unwrap_enum_labels().is_empty() {
+                    sql_bail!("enum {} contains no values", ecx.humanize_scalar_type(&ty));
+                }
+                Ok(HirScalarExpr::literal(Datum::UInt32(0), ty))
+            }) => EnumAny, 3528;
+        },
+        "enum_last" => Scalar {
+            params!(EnumAny) => Operation::unary(|ecx, e| {
+                let ty = ecx.scalar_type(&e);
+                let n = ty.unwrap_enum_labels().len();
+                if n == 0 {
+                    sql_bail!("enum {} contains no values", ecx.humanize_scalar_type(&ty));
+                }
+                let last = u32::try_from(n - 1).expect("enum label count fits in u32");
+                Ok(HirScalarExpr::literal(Datum::UInt32(last), ty))
+            }) => EnumAny, 3529;
+        },
+        "enum_range" => Scalar {
+            params!(EnumAny) => Operation::unary(|ecx, e| {
+                let ty = ecx.scalar_type(&e);
+                let n = u32::try_from(ty.unwrap_enum_labels().len())
+                    .expect("enum label count fits in u32");
+                Ok(HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::ArrayCreate { elem_type: ty.clone() },
+                    exprs: (0..n)
+                        .map(|i| HirScalarExpr::literal(Datum::UInt32(i), ty.clone()))
+                        .collect(),
+                })
+            }) => ArrayAny, 3531;
+            params!(EnumAny, EnumAny) => Operation::binary(|ecx, lower, upper| {
+                let ty = ecx.scalar_type(&lower);
+                let n = u32::try_from(ty.unwrap_enum_labels().len())
+                    .expect("enum label count fits in u32");
+                if n == 0 {
+                    return Ok(HirScalarExpr::CallVariadic {
+                        func: VariadicFunc::ArrayCreate { elem_type: ty },
+                        exprs: vec![],
+                    });
+                }
+                // Per PostgreSQL, a NULL bound means the range extends to the
+                // first or last label of the enum.
+                let lower = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![lower, HirScalarExpr::literal(Datum::UInt32(0), ty.clone())],
+                };
+                let upper = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::Coalesce,
+                    exprs: vec![upper, HirScalarExpr::literal(Datum::UInt32(n - 1), ty)],
+                };
+                Ok(lower.call_binary(upper, BinaryFunc::EnumRange))
+            }) => ArrayAny, 3530;
+        },
         "exp" => Scalar {
             params!(Float64) => UnaryFunc::Exp(func::Exp) => Float64, 1347;
             params!(Numeric) => UnaryFunc::ExpNumeric(func::ExpNumeric) => Numeric, 1732;
@@ -1876,6 +2007,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String, String, String) => VariadicFunc::HmacString => Bytes, 44156;
             params!(Bytes, Bytes, String) => VariadicFunc::HmacBytes => Bytes, 44157;
         },
+        "host" => Scalar {
+            params!(Inet) => UnaryFunc::Host(func::Host) => String, 699;
+        },
         "int4range" => Scalar {
             params!(Int32, Int32) => Operation::variadic(|_ecx, mut exprs| {
                 exprs.push(HirScalarExpr::literal(Datum::String("[)"), ScalarType::String));
@@ -1935,9 +2069,37 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => Jsonb, 3273;
         },
+        "jsonb_insert" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => VariadicFunc::JsonbInsert => Jsonb, oid::FUNC_JSONB_INSERT_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbInsert => Jsonb, 3579;
+        },
+        "jsonb_path_exists" => Scalar {
+            params!(Jsonb, String) => VariadicFunc::JsonbPathExists => Bool, oid::FUNC_JSONB_PATH_EXISTS_OID;
+            params!(Jsonb, String, Jsonb) => VariadicFunc::JsonbPathExists => Bool, oid::FUNC_JSONB_PATH_EXISTS_VARS_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPathExists => Bool, oid::FUNC_JSONB_PATH_EXISTS_VARS_SILENT_OID;
+        },
+        "jsonb_path_match" => Scalar {
+            params!(Jsonb, String) => VariadicFunc::JsonbPathMatch => Bool, oid::FUNC_JSONB_PATH_MATCH_OID;
+            params!(Jsonb, String, Jsonb) => VariadicFunc::JsonbPathMatch => Bool, oid::FUNC_JSONB_PATH_MATCH_VARS_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPathMatch => Bool, oid::FUNC_JSONB_PATH_MATCH_VARS_SILENT_OID;
+        },
+        "jsonb_path_query_first" => Scalar {
+            params!(Jsonb, String) => VariadicFunc::JsonbPathQueryFirst => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_OID;
+            params!(Jsonb, String, Jsonb) => VariadicFunc::JsonbPathQueryFirst => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_VARS_OID;
+            params!(Jsonb, String, Jsonb, Bool) => VariadicFunc::JsonbPathQueryFirst => Jsonb, oid::FUNC_JSONB_PATH_QUERY_FIRST_VARS_SILENT_OID;
+        },
         "jsonb_pretty" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbPretty(func::JsonbPretty) => String, 3306;
         },
+        "jsonb_set" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => VariadicFunc::JsonbSet => Jsonb, oid::FUNC_JSONB_SET_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbSet => Jsonb, 3305;
+        },
+        "jsonb_set_lax" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb) => VariadicFunc::JsonbSetLax => Jsonb, oid::FUNC_JSONB_SET_LAX_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool) => VariadicFunc::JsonbSetLax => Jsonb, oid::FUNC_JSONB_SET_LAX_CREATE_OID;
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String)), Jsonb, Bool, String) => VariadicFunc::JsonbSetLax => Jsonb, oid::FUNC_JSONB_SET_LAX_CREATE_TREATMENT_OID;
+        },
         "jsonb_strip_nulls" => Scalar {
             params!(Jsonb) => UnaryFunc::JsonbStripNulls(func::JsonbStripNulls) => Jsonb, 3262;
         },
@@ -1999,6 +2161,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "make_timestamp" => Scalar {
             params!(Int64, Int64, Int64, Int64, Int64, Float64) => VariadicFunc::MakeTimestamp => Timestamp, 3461;
         },
+        "masklen" => Scalar {
+            params!(Inet) => UnaryFunc::Masklen(func::Masklen) => Int32, 697;
+        },
         "md5" => Scalar {
             params!(String) => Operation::unary(move |_ecx, input| {
                 let algorithm = HirScalarExpr::literal(Datum::String("md5"), ScalarType::String);
@@ -2020,6 +2185,9 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(UInt32, UInt32) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt32, oid::FUNC_MOD_UINT32_OID;
             params!(UInt64, UInt64) => Operation::nullary(|_ecx| catalog_name_only!("mod")) => UInt64, oid::FUNC_MOD_UINT64_OID;
         },
+        "network" => Scalar {
+            params!(Inet) => UnaryFunc::Network(func::Network) => Cidr, 683;
+        },
         "now" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp => TimestampTz, 1299;
         },
@@ -2228,10 +2396,23 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "repeat" => Scalar {
             params!(String, Int32) => BinaryFunc::RepeatString => String, 1622;
         },
+        "regexp_count" => Scalar {
+            params!(String, String) => VariadicFunc::RegexpCount => Int32, oid::FUNC_REGEXP_COUNT_OID;
+            params!(String, String, Int32) => VariadicFunc::RegexpCount => Int32, oid::FUNC_REGEXP_COUNT_START_OID;
+            params!(String, String, Int32, String) => VariadicFunc::RegexpCount => Int32, oid::FUNC_REGEXP_COUNT_START_FLAGS_OID;
+        },
         "regexp_match" => Scalar {
             params!(String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3396;
             params!(String, String, String) => VariadicFunc::RegexpMatch => ScalarType::Array(Box::new(ScalarType::String)), 3397;
         },
+        "regexp_replace" => Scalar {
+            params!(String, String, String) => VariadicFunc::RegexpReplace => String, 2284;
+            params!(String, String, String, String) => VariadicFunc::RegexpReplace => String, 2285;
+        },
+        "regexp_split_to_array" => Scalar {
+            params!(String, String) => VariadicFunc::RegexpSplitToArray => ScalarType::Array(Box::new(ScalarType::String)), 2767;
+            params!(String, String, String) => VariadicFunc::RegexpSplitToArray => ScalarType::Array(Box::new(ScalarType::String)), 2768;
+        },
         "replace" => Scalar {
             params!(String, String, String) => VariadicFunc::Replace => String, 2087;
         },
@@ -2248,6 +2429,10 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             params!(String) => UnaryFunc::TrimTrailingWhitespace(func::TrimTrailingWhitespace) => String, 882;
             params!(String, String) => BinaryFunc::TrimTrailing => String, 876;
         },
+        "set_masklen" => Scalar {
+            params!(Inet, Int32) => BinaryFunc::SetMasklen => Inet, 605;
+            params!(Cidr, Int32) => BinaryFunc::SetMasklenCidr => Cidr, 635;
+        },
         "sha224" => Scalar {
             params!(Bytes) => digest("sha224") => Bytes, 3419;
         },
@@ -2570,6 +2755,45 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 Ok((e, AggregateFunc::JsonbObjectAgg { order_by }))
             }) => Jsonb, 3270;
         },
+        "mode" => Aggregate {
+            params!(AnyElement) => Operation::unary_ordered(|_ecx, e, order_by| {
+                Ok((e, AggregateFunc::Mode { order_by }))
+            }) => AnyElement, 3984;
+        },
+        "percentile_cont" => Aggregate {
+            params!(Float64, Float64) => Operation::binary_ordered(|ecx, fraction, value, order_by| {
+                plan_percentile(ecx, fraction, value, order_by, |fractions, order_by| {
+                    AggregateFunc::PercentileCont { fractions, order_by }
+                })
+            }) => Float64, 3974;
+            params!(Float64, Interval) => Operation::binary_ordered(|ecx, fraction, value, order_by| {
+                plan_percentile(ecx, fraction, value, order_by, |fractions, order_by| {
+                    AggregateFunc::PercentileCont { fractions, order_by }
+                })
+            }) => Interval, 3976;
+            params!(ScalarType::Array(Box::new(ScalarType::Float64)), Float64) => Operation::binary_ordered(|ecx, fractions, value, order_by| {
+                plan_percentile(ecx, fractions, value, order_by, |fractions, order_by| {
+                    AggregateFunc::PercentileCont { fractions, order_by }
+                })
+            }) => ScalarType::Array(Box::new(ScalarType::Float64)), 3980;
+            params!(ScalarType::Array(Box::new(ScalarType::Float64)), Interval) => Operation::binary_ordered(|ecx, fractions, value, order_by| {
+                plan_percentile(ecx, fractions, value, order_by, |fractions, order_by| {
+                    AggregateFunc::PercentileCont { fractions, order_by }
+                })
+            }) => ScalarType::Array(Box::new(ScalarType::Interval)), 3982;
+        },
+        "percentile_disc" => Aggregate {
+            params!(Float64, AnyElement) => Operation::binary_ordered(|ecx, fraction, value, order_by| {
+                plan_percentile(ecx, fraction, value, order_by, |fractions, order_by| {
+                    AggregateFunc::PercentileDisc { fractions, order_by }
+                })
+            }) => AnyElement, 3972;
+            params!(ScalarType::Array(Box::new(ScalarType::Float64)), AnyElement) => Operation::binary_ordered(|ecx, fractions, value, order_by| {
+                plan_percentile(ecx, fractions, value, order_by, |fractions, order_by| {
+                    AggregateFunc::PercentileDisc { fractions, order_by }
+                })
+            }) => ArrayAny, 3978;
+        },
         "string_agg" => Aggregate {
             params!(String, String) => Operation::binary_ordered(|_ecx, value, sep, order_by| {
                 let e = HirScalarExpr::CallVariadic {
@@ -2605,9 +2829,26 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "row_number" => ScalarWindow {
             params!() => ScalarWindowFunc::RowNumber => Int64, 3100;
         },
+        "rank" => ScalarWindow {
+            params!() => ScalarWindowFunc::Rank => Int64, 3101;
+        },
         "dense_rank" => ScalarWindow {
             params!() => ScalarWindowFunc::DenseRank => Int64, 3102;
         },
+        "percent_rank" => ScalarWindow {
+            params!() => ScalarWindowFunc::PercentRank => Float64, 3103;
+        },
+        "cume_dist" => ScalarWindow {
+            params!() => ScalarWindowFunc::CumeDist => Float64, 3104;
+        },
+        "ntile" => ValueWindow {
+            // Only the number of buckets of the first row of a partition is used, so non-constant
+            // numbers of buckets are checked on the result of `ntile` rather than on every row.
+            params!(Int32) => Operation::unary(|_ecx, num_buckets| {
+                validate_window_function_count_arg("ntile", &num_buckets)?;
+                Ok((num_buckets, ValueWindowFunc::Ntile))
+            }) => Int32, 3105;
+        },
         "lag" => ValueWindow {
             // All args are encoded into a single record to be handled later
             params!(Any) => Operation::unary(|ecx, e| {
@@ -2678,6 +2919,24 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "last_value" => ValueWindow {
             params!(Any) => ValueWindowFunc::LastValue => Any, 3113;
         },
+        "nth_value" => ValueWindow {
+            // All args are encoded into a single record to be handled later
+            params!(Any, Int32) => Operation::binary(|_ecx, e, n| {
+                // The N of every row is used, so non-constant values are checked on every row
+                let n = if validate_window_function_count_arg("nth_value", &n)? {
+                    n
+                } else {
+                    n.call_unary(UnaryFunc::CheckNthValueArgument(func::CheckNthValueArgument))
+                };
+                let e = HirScalarExpr::CallVariadic {
+                    func: VariadicFunc::RecordCreate {
+                        field_names: vec![ColumnName::from("expr"), ColumnName::from("n")]
+                    },
+                    exprs: vec![e, n],
+                };
+                Ok((e, ValueWindowFunc::NthValue))
+            }) => Any, 3114;
+        },
 
         // Table functions.
         "generate_series" => Table {
@@ -2751,6 +3010,25 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
             }) => ReturnType::set_of(Int32.into()), 1192;
         },
 
+        "regexp_matches" => Table {
+            params!(String, String) => Operation::variadic(move |_ecx, exprs| {
+                plan_regexp_matches(exprs)
+            }) => ReturnType::set_of(ScalarType::Array(Box::new(ScalarType::String)).into()), 2763;
+            params!(String, String, String) => Operation::variadic(move |_ecx, exprs| {
+                plan_regexp_matches(exprs)
+            }) => ReturnType::set_of(ScalarType::Array(Box::new(ScalarType::String)).into()), 2764;
+        },
+        "regexp_split_to_table" => Table {
+            params!(String, String) => sql_impl_table_func("
+                SELECT unnest AS regexp_split_to_table
+                FROM pg_catalog.unnest(pg_catalog.regexp_split_to_array($1, $2))
+            ") => ReturnType::set_of(String.into()), 2765;
+            params!(String, String, String) => sql_impl_table_func("
+                SELECT unnest AS regexp_split_to_table
+                FROM pg_catalog.unnest(pg_catalog.regexp_split_to_array($1, $2, $3))
+            ") => ReturnType::set_of(String.into()), 2766;
+        },
+
         "jsonb_array_elements" => Table {
             params!(Jsonb) => Operation::unary(move |_ecx, jsonb| {
                 Ok(TableFuncPlan {
@@ -2806,6 +3084,17 @@ pub static PG_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
                 })
             }) => ReturnType::set_of(String.into()), 3931;
         },
+        "jsonb_path_query" => Table {
+            params!(Jsonb, String) => Operation::variadic(move |_ecx, exprs| {
+                plan_jsonb_path_query(exprs)
+            }) => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_OID;
+            params!(Jsonb, String, Jsonb) => Operation::variadic(move |_ecx, exprs| {
+                plan_jsonb_path_query(exprs)
+            }) => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_VARS_OID;
+            params!(Jsonb, String, Jsonb, Bool) => Operation::variadic(move |_ecx, exprs| {
+                plan_jsonb_path_query(exprs)
+            }) => ReturnType::set_of(Jsonb.into()), oid::FUNC_JSONB_PATH_QUERY_VARS_SILENT_OID;
+        },
         // Note that these implementations' input to `generate_series` is
         // contrived to match Flink's expected values. There are other,
         // equally valid windows we could generate.
@@ -2870,6 +3159,9 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
     use ParamType::*;
     use ScalarType::*;
     builtins! {
+        "approx_count_distinct" => Aggregate {
+            params!(Any) => Operation::nullary(|_ecx| catalog_name_only!("approx_count_distinct")) => Int64, oid::FUNC_APPROX_COUNT_DISTINCT_OID;
+        },
         "csv_extract" => Table {
             params!(Int64, String) => Operation::binary(move |_ecx, ncols, input| {
                 let ncols = match ncols.into_literal_int64() {
@@ -2894,6 +3186,22 @@ pub static MZ_CATALOG_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|
         "current_timestamp" => Scalar {
             params!() => UnmaterializableFunc::CurrentTimestamp => TimestampTz, oid::FUNC_CURRENT_TIMESTAMP_OID;
         },
+        "hll_estimate" => Scalar {
+            params!(Bytes) => UnaryFunc::HllEstimate(func::HllEstimate) => Int64, oid::FUNC_HLL_ESTIMATE_OID;
+        },
+        "hll_merge" => Aggregate {
+            params!(Bytes) => Operation::unary(|_ecx, e| {
+                // Reject malformed sketches before they reach the aggregate.
+                let e = e.call_unary(UnaryFunc::HllValidate(func::HllValidate));
+                Ok((e, AggregateFunc::HllMerge))
+            }) => Bytes, oid::FUNC_HLL_MERGE_OID;
+        },
+        "hll_sketch" => Aggregate {
+            params!(Any) => Operation::unary(|_ecx, e| {
+                let e = e.call_unary(UnaryFunc::HllSketchValue(func::HllSketchValue));
+                Ok((e, AggregateFunc::HllMerge))
+            }) => Bytes, oid::FUNC_HLL_SKETCH_OID;
+        },
         "list_agg" => Aggregate {
             params!(Any) => Operation::unary_ordered(|ecx, e, order_by| {
                 if let ScalarType::Char {.. }  = ecx.scalar_type(&e) {
@@ -3107,6 +3415,77 @@ pub static MZ_INTERNAL_BUILTINS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(
     }
 });
 
+/// Rejects constant, non-positive counts passed to `ntile` and `nth_value`,
+/// matching PostgreSQL. Returns whether the count is constant; callers check
+/// non-constant counts when they are evaluated.
+fn validate_window_function_count_arg(
+    name: &str,
+    count: &HirScalarExpr,
+) -> Result<bool, PlanError> {
+    match count.clone().simplify_to_literal() {
+        Some(row) => {
+            if let Datum::Int32(n) = row.unpack_first() {
+                if n <= 0 {
+                    sql_bail!("argument of {} must be greater than zero", name);
+                }
+            }
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Plans `percentile_cont` or `percentile_disc`, whose fraction argument must
+/// be a constant fraction or array of fractions.
+fn plan_percentile(
+    ecx: &ExprContext,
+    fraction: HirScalarExpr,
+    mut value: HirScalarExpr,
+    order_by: Vec<ColumnOrder>,
+    func: fn(mz_expr::PercentileFractions, Vec<ColumnOrder>) -> AggregateFunc,
+) -> Result<(HirScalarExpr, AggregateFunc), PlanError> {
+    let validate = |fraction: f64| {
+        if !(0.0..=1.0).contains(&fraction) {
+            sql_bail!("percentile value {} is not between 0 and 1", fraction);
+        }
+        Ok(fraction)
+    };
+    let fraction_type = ecx.scalar_type(&fraction);
+    let row = match fraction.simplify_to_literal() {
+        Some(row) => row,
+        None => bail_unsupported!("non-constant percentile fractions"),
+    };
+    let fractions = match row.unpack_first() {
+        Datum::Float64(fraction) => {
+            mz_expr::PercentileFractions::scalar(validate(fraction.into_inner())?)
+        }
+        Datum::Array(array) => {
+            if array.dims().len() > 1 {
+                bail_unsupported!("multidimensional arrays of percentile fractions");
+            }
+            let fractions = array
+                .elements()
+                .iter()
+                .map(|fraction| match fraction {
+                    Datum::Null => Ok(None),
+                    fraction => validate(fraction.unwrap_float64()).map(Some),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            mz_expr::PercentileFractions::array(fractions)
+        }
+        // A null fraction produces null, as does aggregating only nulls.
+        Datum::Null => {
+            value = HirScalarExpr::literal_null(ecx.scalar_type(&value));
+            match fraction_type {
+                ScalarType::Array(_) => mz_expr::PercentileFractions::array(vec![]),
+                _ => mz_expr::PercentileFractions::scalar(0.0),
+            }
+        }
+        d => unreachable!("unexpected percentile fraction {:?}", d),
+    };
+    Ok((value, func(fractions, order_by)))
+}
+
 fn digest(algorithm: &'static str) -> Operation<HirScalarExpr> {
     Operation::unary(move |_ecx, input| {
         let algorithm = HirScalarExpr::literal(Datum::String(algorithm), ScalarType::String);
@@ -3306,6 +3685,10 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(UInt32, UInt32) => BitShiftLeftUInt32 => UInt32, oid::FUNC_SHIFT_LEFT_UINT32;
             params!(UInt64, UInt32) => BitShiftLeftUInt64 => UInt64, oid::FUNC_SHIFT_LEFT_UINT64;
             params!(RangeAny, RangeAny) => RangeBefore => Bool, 3893;
+            params!(Inet, Inet) => InetContainedBy => Bool, 931;
+        },
+        "<<=" => Scalar {
+            params!(Inet, Inet) => InetContainedByOrEquals => Bool, 932;
         },
         ">>" => Scalar {
             params!(Int16, Int32) => BitShiftRightInt16 => Int16, 1879;
@@ -3315,6 +3698,10 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(UInt32, UInt32) => BitShiftRightUInt32 => UInt32, oid::FUNC_SHIFT_RIGHT_UINT32;
             params!(UInt64, UInt32) => BitShiftRightUInt64 => UInt64, oid::FUNC_SHIFT_RIGHT_UINT64;
             params!(RangeAny, RangeAny) => RangeAfter => Bool, 3894;
+            params!(Inet, Inet) => InetContains => Bool, 933;
+        },
+        ">>=" => Scalar {
+            params!(Inet, Inet) => InetContainsOrEquals => Bool, 934;
         },
 
         // ILIKE
@@ -3469,6 +3856,9 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
         "#>>" => Scalar {
             params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String))) => JsonbGetPath { stringify: true } => String, 3206;
         },
+        "#-" => Scalar {
+            params!(Jsonb, ScalarType::Array(Box::new(ScalarType::String))) => JsonbDeletePath => Jsonb, 3287;
+        },
         "@>" => Scalar {
             params!(Jsonb, Jsonb) => JsonbContainsJsonb => Bool, 3246;
             params!(Jsonb, String) => Operation::binary(|_ecx, lhs, rhs| {
@@ -3522,6 +3912,16 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Jsonb, String) => JsonbContainsString => Bool, 3247;
             params!(MapAny, String) => MapContainsKey => Bool, oid::OP_CONTAINS_KEY_MAP_OID;
         },
+        "@?" => Scalar {
+            params!(Jsonb, String) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(jsonb_path_operator(VariadicFunc::JsonbPathExists, lhs, rhs))
+            }) => Bool, oid::OP_JSONB_PATH_EXISTS_OID;
+        },
+        "@@" => Scalar {
+            params!(Jsonb, String) => Operation::binary(|_ecx, lhs, rhs| {
+                Ok(jsonb_path_operator(VariadicFunc::JsonbPathMatch, lhs, rhs))
+            }) => Bool, oid::OP_JSONB_PATH_MATCH_OID;
+        },
         "?&" => Scalar {
             params!(MapAny, ScalarType::Array(Box::new(ScalarType::String))) => MapContainsAllKeys => Bool, oid::OP_CONTAINS_ALL_KEYS_MAP_OID;
         },
@@ -3530,6 +3930,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
         },
         "&&" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverlaps => Bool, 3888;
+            params!(Inet, Inet) => BinaryFunc::InetOverlaps => Bool, 3552;
         },
         "&<" => Scalar {
             params!(RangeAny, RangeAny) => BinaryFunc::RangeOverleft => Bool, 3895;
@@ -3559,6 +3960,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Lt => Bool, 2062;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Lt => Bool, 1322;
             params!(Uuid, Uuid) => BinaryFunc::Lt => Bool, 2974;
+            params!(Inet, Inet) => BinaryFunc::Lt => Bool, 1203;
             params!(Interval, Interval) => BinaryFunc::Lt => Bool, 1332;
             params!(Bytes, Bytes) => BinaryFunc::Lt => Bool, 1957;
             params!(String, String) => BinaryFunc::Lt => Bool, 664;
@@ -3569,6 +3971,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lt => Bool, 2990;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lt =>Bool, oid::FUNC_MZ_TIMESTAMP_LT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lt => Bool, 3884;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lt => Bool, 3518;
         },
         "<=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Lte => Bool, 1755;
@@ -3587,6 +3990,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Lte => Bool, 2063;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Lte => Bool, 1323;
             params!(Uuid, Uuid) => BinaryFunc::Lte => Bool, 2976;
+            params!(Inet, Inet) => BinaryFunc::Lte => Bool, 1204;
             params!(Interval, Interval) => BinaryFunc::Lte => Bool, 1333;
             params!(Bytes, Bytes) => BinaryFunc::Lte => Bool, 1958;
             params!(String, String) => BinaryFunc::Lte => Bool, 665;
@@ -3597,6 +4001,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Lte => Bool, 2992;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Lte =>Bool, oid::FUNC_MZ_TIMESTAMP_LTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Lte => Bool, 3885;
+            params!(EnumAny, EnumAny) => BinaryFunc::Lte => Bool, 3520;
         },
         ">" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gt => Bool, 1756;
@@ -3615,6 +4020,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Gt => Bool, 2064;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Gt => Bool, 1324;
             params!(Uuid, Uuid) => BinaryFunc::Gt => Bool, 2975;
+            params!(Inet, Inet) => BinaryFunc::Gt => Bool, 1205;
             params!(Interval, Interval) => BinaryFunc::Gt => Bool, 1334;
             params!(Bytes, Bytes) => BinaryFunc::Gt => Bool, 1959;
             params!(String, String) => BinaryFunc::Gt => Bool, 666;
@@ -3625,6 +4031,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gt => Bool, 2991;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gt =>Bool, oid::FUNC_MZ_TIMESTAMP_GT_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gt => Bool, 3887;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gt => Bool, 3519;
         },
         ">=" => Scalar {
             params!(Numeric, Numeric) => BinaryFunc::Gte => Bool, 1757;
@@ -3643,6 +4050,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Gte => Bool, 2065;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Gte => Bool, 1325;
             params!(Uuid, Uuid) => BinaryFunc::Gte => Bool, 2977;
+            params!(Inet, Inet) => BinaryFunc::Gte => Bool, 1206;
             params!(Interval, Interval) => BinaryFunc::Gte => Bool, 1335;
             params!(Bytes, Bytes) => BinaryFunc::Gte => Bool, 1960;
             params!(String, String) => BinaryFunc::Gte => Bool, 667;
@@ -3653,6 +4061,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Gte => Bool, 2993;
             params!(MzTimestamp, MzTimestamp)=>BinaryFunc::Gte =>Bool, oid::FUNC_MZ_TIMESTAMP_GTE_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Gte => Bool, 3886;
+            params!(EnumAny, EnumAny) => BinaryFunc::Gte => Bool, 3521;
         },
         // Warning!
         // - If you are writing functions here that do not simply use
@@ -3680,6 +4089,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::Eq => Bool, 2060;
             params!(TimestampTz, TimestampTz) => BinaryFunc::Eq => Bool, 1320;
             params!(Uuid, Uuid) => BinaryFunc::Eq => Bool, 2972;
+            params!(Inet, Inet) => BinaryFunc::Eq => Bool, 1201;
             params!(Interval, Interval) => BinaryFunc::Eq => Bool, 1330;
             params!(Bytes, Bytes) => BinaryFunc::Eq => Bool, 1955;
             params!(String, String) => BinaryFunc::Eq => Bool, 98;
@@ -3691,6 +4101,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::Eq => Bool, 2988;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_TIMESTAMP_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::Eq => Bool, 3882;
+            params!(EnumAny, EnumAny) => BinaryFunc::Eq => Bool, 3516;
             params!(MzAclItem, MzAclItem) => BinaryFunc::Eq => Bool, oid::FUNC_MZ_ACL_ITEM_EQ_MZ_ACL_ITEM_OID;
         },
         "<>" => Scalar {
@@ -3710,6 +4121,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(Timestamp, Timestamp) => BinaryFunc::NotEq => Bool, 2061;
             params!(TimestampTz, TimestampTz) => BinaryFunc::NotEq => Bool, 1321;
             params!(Uuid, Uuid) => BinaryFunc::NotEq => Bool, 2973;
+            params!(Inet, Inet) => BinaryFunc::NotEq => Bool, 1202;
             params!(Interval, Interval) => BinaryFunc::NotEq => Bool, 1331;
             params!(Bytes, Bytes) => BinaryFunc::NotEq => Bool, 1956;
             params!(String, String) => BinaryFunc::NotEq => Bool, 531;
@@ -3720,6 +4132,7 @@ pub static OP_IMPLS: Lazy<BTreeMap<&'static str, Func>> = Lazy::new(|| {
             params!(RecordAny, RecordAny) => BinaryFunc::NotEq => Bool, 2989;
             params!(MzTimestamp, MzTimestamp) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_TIMESTAMP_NOT_EQ_MZ_TIMESTAMP_OID;
             params!(RangeAny, RangeAny) => BinaryFunc::NotEq => Bool, 3883;
+            params!(EnumAny, EnumAny) => BinaryFunc::NotEq => Bool, 3517;
             params!(MzAclItem, MzAclItem) => BinaryFunc::NotEq => Bool, oid::FUNC_MZ_ACL_ITEM_NOT_EQ_MZ_ACL_ITEM_OID;
         }
     }
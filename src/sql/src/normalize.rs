@@ -24,9 +24,9 @@ use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
     CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateTypeStatement, CreateViewStatement, CteBlock, Function,
-    FunctionArgs, Ident, IfExistsBehavior, Op, Query, Statement, TableFactor, TableFunction,
-    UnresolvedItemName, UnresolvedSchemaName, Value, ViewDefinition,
+    CreateTableStatement, CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement,
+    CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior, Op, Query, Statement, TableFactor,
+    TableFunction, UnresolvedItemName, UnresolvedSchemaName, Value, ViewDefinition,
 };
 
 use crate::names::{Aug, FullItemName, PartialItemName, PartialSchemaName, RawDatabaseSpecifier};
@@ -209,7 +209,7 @@ pub fn create_statement(
         fn visit_query_mut(&mut self, query: &'ast mut Query<Aug>) {
             let n = self.ctes.len();
             match &query.ctes {
-                CteBlock::Simple(ctes) => {
+                CteBlock::Simple(ctes) | CteBlock::Recursive(ctes) => {
                     for cte in ctes.iter() {
                         self.ctes.push(cte.alias.name.clone());
                     }
@@ -232,7 +232,8 @@ pub fn create_statement(
 
             match &mut func.args {
                 FunctionArgs::Star => (),
-                FunctionArgs::Args { args, order_by } => {
+                FunctionArgs::Args { args, order_by }
+                | FunctionArgs::WithinGroup { args, order_by } => {
                     for arg in args {
                         self.visit_expr_mut(arg);
                     }
@@ -254,7 +255,8 @@ pub fn create_statement(
 
             match &mut func.args {
                 FunctionArgs::Star => (),
-                FunctionArgs::Args { args, order_by } => {
+                FunctionArgs::Args { args, order_by }
+                | FunctionArgs::WithinGroup { args, order_by } => {
                     for arg in args {
                         self.visit_expr_mut(arg);
                     }
@@ -327,6 +329,17 @@ pub fn create_statement(
             *if_not_exists = false;
         }
 
+        Statement::CreateWebhookSource(CreateWebhookSourceStatement {
+            name,
+            if_not_exists,
+            body_format: _,
+            include_headers: _,
+            validate_using: _,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
+
         Statement::CreateTable(CreateTableStatement {
             name,
             columns,
@@ -66,6 +66,10 @@ pub use self::expr::{
     AggregateExpr, Hir, HirRelationExpr, HirScalarExpr, JoinKind, WindowExprType,
 };
 pub use crate::plan::statement::ddl::PlannedRoleAttributes;
+pub use crate::plan::webhook::{
+    WebhookBodyFormat, WebhookDesc, WebhookHmacAlgorithm, WebhookSignatureEncoding,
+    WebhookValidation, WEBHOOK_BODY_COLUMN, WEBHOOK_HEADERS_COLUMN,
+};
 
 pub(crate) mod error;
 pub(crate) mod explain;
@@ -79,6 +83,7 @@ pub(crate) mod statement;
 pub(crate) mod transform_ast;
 pub(crate) mod transform_expr;
 pub(crate) mod typeconv;
+pub(crate) mod webhook;
 pub(crate) mod with_options;
 
 /// Instructions for executing a SQL query.
@@ -125,6 +130,7 @@ pub enum Plan {
     AlterSource(AlterSourcePlan),
     AlterItemRename(AlterItemRenamePlan),
     AlterSecret(AlterSecretPlan),
+    AlterTableAddColumn(AlterTableAddColumnPlan),
     AlterSystemSet(AlterSystemSetPlan),
     AlterSystemReset(AlterSystemResetPlan),
     AlterSystemResetAll(AlterSystemResetAllPlan),
@@ -170,6 +176,7 @@ impl Plan {
                 vec![PlanKind::AlterNoop, PlanKind::AlterSystemResetAll]
             }
             StatementKind::AlterSystemSet => vec![PlanKind::AlterNoop, PlanKind::AlterSystemSet],
+            StatementKind::AlterTable => vec![PlanKind::AlterNoop, PlanKind::AlterTableAddColumn],
             StatementKind::AlterOwner => vec![PlanKind::AlterNoop, PlanKind::AlterOwner],
             StatementKind::Close => vec![PlanKind::Close],
             StatementKind::Commit => vec![PlanKind::CommitTransaction],
@@ -184,9 +191,9 @@ impl Plan {
             StatementKind::CreateSchema => vec![PlanKind::CreateSchema],
             StatementKind::CreateSecret => vec![PlanKind::CreateSecret],
             StatementKind::CreateSink => vec![PlanKind::CreateSink],
-            StatementKind::CreateSource | StatementKind::CreateSubsource => {
-                vec![PlanKind::CreateSource]
-            }
+            StatementKind::CreateSource
+            | StatementKind::CreateSubsource
+            | StatementKind::CreateWebhookSource => vec![PlanKind::CreateSource],
             StatementKind::CreateTable => vec![PlanKind::CreateTable],
             StatementKind::CreateType => vec![PlanKind::CreateType],
             StatementKind::CreateView => vec![PlanKind::CreateView],
@@ -297,6 +304,7 @@ impl Plan {
             Plan::AlterSource(_) => "alter source",
             Plan::AlterItemRename(_) => "rename item",
             Plan::AlterSecret(_) => "alter secret",
+            Plan::AlterTableAddColumn(_) => "alter table",
             Plan::AlterSystemSet(_) => "alter system",
             Plan::AlterSystemReset(_) => "alter system",
             Plan::AlterSystemResetAll(_) => "alter system",
@@ -609,7 +617,7 @@ pub struct PeekPlan {
     pub source: MirRelationExpr,
     pub when: QueryWhen,
     pub finishing: RowSetFinishing,
-    pub copy_to: Option<CopyFormat>,
+    pub copy_to: Option<CopyFormatParams<'static>>,
 }
 
 #[derive(Debug)]
@@ -635,7 +643,7 @@ pub struct SubscribePlan {
     pub with_snapshot: bool,
     pub when: QueryWhen,
     pub up_to: Option<MirScalarExpr>,
-    pub copy_to: Option<CopyFormat>,
+    pub copy_to: Option<CopyFormatParams<'static>>,
     pub emit_progress: bool,
     pub output: SubscribeOutput,
 }
@@ -721,6 +729,13 @@ pub struct AlterNoopPlan {
     pub object_type: ObjectType,
 }
 
+#[derive(Debug)]
+pub struct AlterTableAddColumnPlan {
+    pub id: GlobalId,
+    /// The definition of the table, including the new column.
+    pub table: Table,
+}
+
 #[derive(Debug)]
 pub struct AlterIndexSetOptionsPlan {
     pub id: GlobalId,
@@ -890,9 +905,34 @@ pub struct Table {
     pub create_sql: String,
     pub desc: RelationDesc,
     pub defaults: Vec<Expr<Aug>>,
+    pub check_constraints: Vec<CheckConstraint>,
+    pub unique_constraints: Vec<UniqueConstraint>,
+    pub missing_values: Row,
     pub temporary: bool,
 }
 
+/// A `CHECK` constraint on a table.
+#[derive(Clone, Debug, Serialize)]
+pub struct CheckConstraint {
+    /// The name of the constraint.
+    pub name: String,
+    /// The predicate that rows written to the table must not make false,
+    /// expressed over the table's columns.
+    pub expr: MirScalarExpr,
+}
+
+/// A `PRIMARY KEY` or `UNIQUE` constraint on a table.
+#[derive(Clone, Debug, Serialize)]
+pub struct UniqueConstraint {
+    /// The name of the constraint.
+    pub name: String,
+    /// The indices of the columns that make up the key.
+    pub columns: Vec<usize>,
+    /// Whether keys that contain `NULL` are compared like any other key. If
+    /// not, such keys never conflict with each other.
+    pub nulls_not_distinct: bool,
+}
+
 #[derive(Clone, Debug)]
 pub struct Source {
     pub create_sql: String,
@@ -908,6 +948,8 @@ pub enum DataSourceDesc {
     Source,
     /// Receives data from the source's reclocking/remapping operations.
     Progress,
+    /// Receives data from requests to an HTTP endpoint.
+    Webhook(WebhookDesc),
 }
 
 #[derive(Clone, Debug)]
@@ -936,7 +978,7 @@ pub struct Sink {
     pub create_sql: String,
     pub from: GlobalId,
     pub connection_builder: StorageSinkConnectionBuilder,
-    pub envelope: SinkEnvelope,
+    pub envelope: Option<SinkEnvelope>,
 }
 
 #[derive(Clone, Debug)]
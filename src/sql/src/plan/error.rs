@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use itertools::Itertools;
 use mz_expr::EvalError;
+use mz_mysql_util::MySqlError;
 use mz_ore::error::ErrorExt;
 use mz_ore::stack::RecursionLimitError;
 use mz_ore::str::{separated, StrExt};
@@ -126,6 +127,12 @@ pub enum PlanError {
     PostgresConnectionErr {
         cause: Arc<mz_postgres_util::PostgresError>,
     },
+    UnrecognizedTypeInMySqlSource {
+        cols: Vec<(String, String)>,
+    },
+    MySqlConnectionErr {
+        cause: Arc<MySqlError>,
+    },
     InvalidProtobufSchema {
         cause: protobuf_native::OperationFailedError,
     },
@@ -178,6 +185,9 @@ pub enum PlanError {
     PostgresDatabaseMissingFilteredSchemas {
         schemas: Vec<String>,
     },
+    MySqlServerMissingFilteredSchemas {
+        schemas: Vec<String>,
+    },
     InvalidKeysInSubscribeEnvelopeUpsert,
     InvalidKeysInSubscribeEnvelopeDebezium,
     InvalidOrderByInSubscribeWithinTimestampOrderBy,
@@ -197,6 +207,7 @@ impl PlanError {
         match self {
             Self::FetchingCsrSchemaFailed { cause, .. } => Some(cause.to_string_with_causes()),
             Self::PostgresConnectionErr { cause } => Some(cause.to_string_with_causes()),
+            Self::MySqlConnectionErr { cause } => Some(cause.to_string_with_causes()),
             Self::InvalidProtobufSchema { cause } => Some(cause.to_string_with_causes()),
             Self::InvalidOptionValue { err, .. } => err.detail(),
             Self::RequiresUnsafe { .. } => {
@@ -236,6 +247,25 @@ impl PlanError {
                 as text."
                     .into(),
             ),
+            Self::UnrecognizedTypeInMySqlSource { cols: _ } => Some(
+                "Use the TEXT COLUMNS option naming the listed columns, and Materialize can ingest their values \
+                as text."
+                    .into(),
+            ),
+            Self::MySqlConnectionErr { cause } => {
+                if let Some(cause) = cause.source() {
+                    if let Some(cause) = cause.downcast_ref::<io::Error>() {
+                        if cause.kind() == io::ErrorKind::TimedOut {
+                            return Some(
+                                "Do you have a firewall or security group that is \
+                                preventing Materialize from conecting to your MySQL server?"
+                                    .into(),
+                            );
+                        }
+                    }
+                }
+                None
+            }
             Self::PostgresConnectionErr { cause } => {
                 if let Some(cause) = cause.source() {
                     if let Some(cause) = cause.downcast_ref::<io::Error>() {
@@ -416,6 +446,22 @@ impl fmt::Display for PlanError {
             Self::PostgresConnectionErr { .. } => {
                 write!(f, "failed to connect to PostgreSQL database")
             }
+            Self::UnrecognizedTypeInMySqlSource { cols } => {
+                let mut cols = cols.to_owned();
+                cols.sort();
+
+                write!(
+                    f,
+                    "the following columns contain unsupported types:\n{}",
+                    itertools::join(
+                        cols.into_iter().map(|(col, ty)| format!("{} ({})", col, ty)),
+                        "\n"
+                    )
+                )
+            }
+            Self::MySqlConnectionErr { .. } => {
+                write!(f, "failed to connect to MySQL server")
+            }
             Self::InvalidProtobufSchema { .. } => {
                 write!(f, "invalid protobuf schema")
             }
@@ -463,6 +509,9 @@ impl fmt::Display for PlanError {
             Self::PostgresDatabaseMissingFilteredSchemas { schemas} => {
                 write!(f, "FOR SCHEMAS (..) included {}, but PostgreSQL database has no schema with that name", itertools::join(schemas.iter(), ", "))
             }
+            Self::MySqlServerMissingFilteredSchemas { schemas } => {
+                write!(f, "FOR SCHEMAS (..) included {}, but MySQL server has no schema with that name", itertools::join(schemas.iter(), ", "))
+            }
             Self::InvalidKeysInSubscribeEnvelopeUpsert => {
                 write!(f, "invalid keys in SUBSCRIBE ENVELOPE UPSERT (KEY (..))")
             }
@@ -551,6 +600,12 @@ impl From<PostgresError> for PlanError {
     }
 }
 
+impl From<MySqlError> for PlanError {
+    fn from(e: MySqlError) -> PlanError {
+        PlanError::MySqlConnectionErr { cause: Arc::new(e) }
+    }
+}
+
 struct ColumnDisplay<'a> {
     table: &'a Option<PartialItemName>,
     column: &'a ColumnName,
@@ -213,6 +213,7 @@ impl HirRelationExpr {
                 offset,
                 input,
                 expected_group_size,
+                with_ties,
             } => {
                 write!(f, "{}TopK", ctx.indent)?;
                 if group_key.len() > 0 {
@@ -229,6 +230,9 @@ impl HirRelationExpr {
                 if offset > &0 {
                     write!(f, " offset={}", offset)?
                 }
+                if *with_ties {
+                    write!(f, " with_ties")?;
+                }
                 if let Some(expected_group_size) = expected_group_size {
                     write!(f, " exp_group_size={}", expected_group_size)?;
                 }
@@ -331,6 +335,12 @@ impl fmt::Display for HirScalarExpr {
                     WindowExprType::Value(scalar) => {
                         write!(f, "{}({})", scalar.clone().into_expr(), scalar.expr)?
                     }
+                    WindowExprType::Aggregate(aggregate) => write!(
+                        f,
+                        "{}({})",
+                        aggregate.clone().into_expr(),
+                        aggregate.aggregate_expr.expr
+                    )?,
                 }
                 write!(f, " over ({})", separated(", ", expr.partition.iter()))?;
 
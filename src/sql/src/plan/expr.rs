@@ -168,6 +168,8 @@ pub enum HirRelationExpr {
         offset: usize,
         /// User-supplied hint: how many rows will have the same group key.
         expected_group_size: Option<u64>,
+        /// True iff rows tying with the last retained row should be retained too.
+        with_ties: bool,
     },
     Negate {
         input: Box<HirRelationExpr>,
@@ -342,6 +344,7 @@ impl VisitChildren<HirScalarExpr> for WindowExpr {
 pub enum WindowExprType {
     Scalar(ScalarWindowExpr),
     Value(ValueWindowExpr),
+    Aggregate(AggregateWindowExpr),
 }
 
 impl WindowExprType {
@@ -354,6 +357,7 @@ impl WindowExprType {
         match self {
             Self::Scalar(expr) => expr.visit_expressions(f),
             Self::Value(expr) => expr.visit_expressions(f),
+            Self::Aggregate(expr) => expr.visit_expressions(f),
         }
     }
 
@@ -366,6 +370,7 @@ impl WindowExprType {
         match self {
             Self::Scalar(expr) => expr.visit_expressions_mut(f),
             Self::Value(expr) => expr.visit_expressions_mut(f),
+            Self::Aggregate(expr) => expr.visit_expressions_mut(f),
         }
     }
 
@@ -378,6 +383,7 @@ impl WindowExprType {
         match self {
             Self::Scalar(expr) => expr.typ(outers, inner, params),
             Self::Value(expr) => expr.typ(outers, inner, params),
+            Self::Aggregate(expr) => expr.typ(outers, inner, params),
         }
     }
 }
@@ -390,6 +396,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => (),
             Self::Value(expr) => expr.visit_children(f),
+            Self::Aggregate(expr) => expr.visit_children(f),
         }
     }
 
@@ -400,6 +407,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => (),
             Self::Value(expr) => expr.visit_mut_children(f),
+            Self::Aggregate(expr) => expr.visit_mut_children(f),
         }
     }
 
@@ -411,6 +419,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => Ok(()),
             Self::Value(expr) => expr.try_visit_children(f),
+            Self::Aggregate(expr) => expr.try_visit_children(f),
         }
     }
 
@@ -422,6 +431,7 @@ impl VisitChildren<HirScalarExpr> for WindowExprType {
         match self {
             Self::Scalar(_) => Ok(()),
             Self::Value(expr) => expr.try_visit_mut_children(f),
+            Self::Aggregate(expr) => expr.try_visit_mut_children(f),
         }
     }
 }
@@ -441,6 +451,9 @@ impl ScalarWindowExpr {
         match self.func {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::Rank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -453,6 +466,9 @@ impl ScalarWindowExpr {
         match self.func {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::Rank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -474,6 +490,15 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::DenseRank => mz_expr::AggregateFunc::DenseRank {
                 order_by: self.order_by,
             },
+            ScalarWindowFunc::Rank => mz_expr::AggregateFunc::Rank {
+                order_by: self.order_by,
+            },
+            ScalarWindowFunc::PercentRank => mz_expr::AggregateFunc::PercentRank {
+                order_by: self.order_by,
+            },
+            ScalarWindowFunc::CumeDist => mz_expr::AggregateFunc::CumeDist {
+                order_by: self.order_by,
+            },
         }
     }
 }
@@ -483,6 +508,9 @@ impl ScalarWindowExpr {
 pub enum ScalarWindowFunc {
     RowNumber,
     DenseRank,
+    Rank,
+    PercentRank,
+    CumeDist,
 }
 
 impl ScalarWindowFunc {
@@ -490,6 +518,9 @@ impl ScalarWindowFunc {
         match self {
             ScalarWindowFunc::RowNumber => ScalarType::Int64.nullable(false),
             ScalarWindowFunc::DenseRank => ScalarType::Int64.nullable(false),
+            ScalarWindowFunc::Rank => ScalarType::Int64.nullable(false),
+            ScalarWindowFunc::PercentRank => ScalarType::Float64.nullable(false),
+            ScalarWindowFunc::CumeDist => ScalarType::Float64.nullable(false),
         }
     }
 }
@@ -547,6 +578,13 @@ impl ValueWindowExpr {
                 order_by: self.order_by,
                 window_frame: self.window_frame,
             },
+            ValueWindowFunc::Ntile => mz_expr::AggregateFunc::Ntile {
+                order_by: self.order_by,
+            },
+            ValueWindowFunc::NthValue => mz_expr::AggregateFunc::NthValue {
+                order_by: self.order_by,
+                window_frame: self.window_frame,
+            },
         }
     }
 }
@@ -590,13 +628,16 @@ pub enum ValueWindowFunc {
     Lead,
     FirstValue,
     LastValue,
+    Ntile,
+    NthValue,
 }
 
 impl ValueWindowFunc {
     pub fn output_type(&self, input_type: ColumnType) -> ColumnType {
         match self {
-            ValueWindowFunc::Lag | ValueWindowFunc::Lead => {
-                // The input is a (value, offset, default) record, so extract the type of the first arg
+            ValueWindowFunc::Lag | ValueWindowFunc::Lead | ValueWindowFunc::NthValue => {
+                // The input is a (value, offset, default) or (value, n) record, so extract the type
+                // of the first arg
                 input_type.scalar_type.unwrap_record_element_type()[0]
                     .clone()
                     .nullable(true)
@@ -604,10 +645,85 @@ impl ValueWindowFunc {
             ValueWindowFunc::FirstValue | ValueWindowFunc::LastValue => {
                 input_type.scalar_type.nullable(true)
             }
+            ValueWindowFunc::Ntile => ScalarType::Int32.nullable(true),
         }
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct AggregateWindowExpr {
+    pub aggregate_expr: AggregateExpr,
+    pub order_by: Vec<ColumnOrder>,
+    pub window_frame: WindowFrame,
+}
+
+impl AggregateWindowExpr {
+    #[deprecated = "Use `VisitChildren<HirScalarExpr>::visit_children` instead."]
+    pub fn visit_expressions<'a, F, E>(&'a self, f: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&'a HirScalarExpr) -> Result<(), E>,
+    {
+        f(&self.aggregate_expr.expr)
+    }
+
+    #[deprecated = "Use `VisitChildren<HirScalarExpr>::visit_mut_children` instead."]
+    pub fn visit_expressions_mut<'a, F, E>(&'a mut self, f: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&'a mut HirScalarExpr) -> Result<(), E>,
+    {
+        f(&mut self.aggregate_expr.expr)
+    }
+
+    fn typ(
+        &self,
+        outers: &[RelationType],
+        inner: &RelationType,
+        params: &BTreeMap<usize, ScalarType>,
+    ) -> ColumnType {
+        self.aggregate_expr.typ(outers, inner, params)
+    }
+
+    pub fn into_expr(self) -> mz_expr::AggregateFunc {
+        mz_expr::AggregateFunc::WindowAggregate {
+            wrapped_aggregate: Box::new(self.aggregate_expr.func.into_expr()),
+            order_by: self.order_by,
+            window_frame: self.window_frame,
+        }
+    }
+}
+
+impl VisitChildren<HirScalarExpr> for AggregateWindowExpr {
+    fn visit_children<F>(&self, mut f: F)
+    where
+        F: FnMut(&HirScalarExpr),
+    {
+        f(&self.aggregate_expr.expr)
+    }
+
+    fn visit_mut_children<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut HirScalarExpr),
+    {
+        f(&mut self.aggregate_expr.expr)
+    }
+
+    fn try_visit_children<F, E>(&self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&HirScalarExpr) -> Result<(), E>,
+        E: From<RecursionLimitError>,
+    {
+        f(&self.aggregate_expr.expr)
+    }
+
+    fn try_visit_mut_children<F, E>(&mut self, mut f: F) -> Result<(), E>
+    where
+        F: FnMut(&mut HirScalarExpr) -> Result<(), E>,
+        E: From<RecursionLimitError>,
+    {
+        f(&mut self.aggregate_expr.expr)
+    }
+}
+
 /// A `CoercibleScalarExpr` is a [`HirScalarExpr`] whose type is not fully
 /// determined. Several SQL expressions can be freely coerced based upon where
 /// in the expression tree they appear. For example, the string literal '42'
@@ -864,6 +980,8 @@ pub enum AggregateFunc {
     Count,
     Any,
     All,
+    /// Merges `Datum::Bytes` HyperLogLog sketches into a single sketch.
+    HllMerge,
     /// Accumulates `Datum::List`s whose first element is a JSON-typed `Datum`s
     /// into a JSON list. The other elements are columns used by `order_by`.
     ///
@@ -891,6 +1009,25 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Computes the continuous percentiles at `fractions` of `Datum::List`s
+    /// whose first element is the value to aggregate. The other elements are
+    /// columns used by `order_by`.
+    PercentileCont {
+        fractions: mz_expr::PercentileFractions,
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the discrete percentiles at `fractions` of `Datum::List`s
+    /// whose first element is the value to aggregate. The other elements are
+    /// columns used by `order_by`.
+    PercentileDisc {
+        fractions: mz_expr::PercentileFractions,
+        order_by: Vec<ColumnOrder>,
+    },
+    /// Computes the most frequent first element of `Datum::List`s. The other
+    /// elements are columns used by `order_by`.
+    Mode {
+        order_by: Vec<ColumnOrder>,
+    },
     /// Accumulates any number of `Datum::Dummy`s into `Datum::Dummy`.
     ///
     /// Useful for removing an expensive aggregation while maintaining the shape
@@ -944,6 +1081,7 @@ impl AggregateFunc {
             AggregateFunc::Count => mz_expr::AggregateFunc::Count,
             AggregateFunc::Any => mz_expr::AggregateFunc::Any,
             AggregateFunc::All => mz_expr::AggregateFunc::All,
+            AggregateFunc::HllMerge => mz_expr::AggregateFunc::HllMerge,
             AggregateFunc::JsonbAgg { order_by } => mz_expr::AggregateFunc::JsonbAgg { order_by },
             AggregateFunc::JsonbObjectAgg { order_by } => {
                 mz_expr::AggregateFunc::JsonbObjectAgg { order_by }
@@ -955,6 +1093,21 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::PercentileCont {
+                fractions,
+                order_by,
+            } => mz_expr::AggregateFunc::PercentileCont {
+                fractions,
+                order_by,
+            },
+            AggregateFunc::PercentileDisc {
+                fractions,
+                order_by,
+            } => mz_expr::AggregateFunc::PercentileDisc {
+                fractions,
+                order_by,
+            },
+            AggregateFunc::Mode { order_by } => mz_expr::AggregateFunc::Mode { order_by },
             AggregateFunc::Dummy => mz_expr::AggregateFunc::Dummy,
         }
     }
@@ -1000,6 +1153,23 @@ impl AggregateFunc {
                     _ => unreachable!(),
                 }
             }
+            AggregateFunc::PercentileCont { fractions, .. }
+            | AggregateFunc::PercentileDisc { fractions, .. } => {
+                let value_type = match input_type.scalar_type {
+                    ScalarType::Record { fields, .. } => fields[0].1.scalar_type.clone(),
+                    _ => unreachable!(),
+                };
+                match fractions {
+                    mz_expr::PercentileFractions::Scalar(_) => value_type,
+                    mz_expr::PercentileFractions::Array(_) => {
+                        ScalarType::Array(Box::new(value_type))
+                    }
+                }
+            }
+            AggregateFunc::Mode { .. } => match input_type.scalar_type {
+                ScalarType::Record { fields, .. } => fields[0].1.scalar_type.clone(),
+                _ => unreachable!(),
+            },
             _ => input_type.scalar_type,
         };
         // max/min/sum return null on empty sets
@@ -1016,6 +1186,20 @@ impl AggregateFunc {
                 | ArrayConcat { .. }
                 | ListConcat { .. }
                 | StringAgg { .. }
+                | PercentileCont { .. }
+                | PercentileDisc { .. }
+                | Mode { .. }
+        )
+    }
+
+    /// Returns whether the function is an ordered-set aggregate, whose
+    /// aggregated arguments are given by a `WITHIN GROUP (ORDER BY ...)`
+    /// clause.
+    pub fn is_ordered_set(&self) -> bool {
+        use AggregateFunc::*;
+        matches!(
+            self,
+            PercentileCont { .. } | PercentileDisc { .. } | Mode { .. }
         )
     }
 }
@@ -1214,6 +1398,7 @@ impl HirRelationExpr {
         limit: Option<usize>,
         offset: usize,
         expected_group_size: Option<u64>,
+        with_ties: bool,
     ) -> Self {
         HirRelationExpr::TopK {
             input: Box::new(self),
@@ -1222,6 +1407,7 @@ impl HirRelationExpr {
             limit,
             offset,
             expected_group_size,
+            with_ties,
         }
     }
 
@@ -1645,6 +1831,7 @@ impl HirRelationExpr {
                     limit: finishing.limit,
                     offset: finishing.offset,
                     expected_group_size: None,
+                    with_ties: false,
                 }),
                 outputs: finishing.project,
             }
@@ -1722,6 +1909,7 @@ impl VisitChildren<Self> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input }
             | Threshold { input } => {
@@ -1805,6 +1993,7 @@ impl VisitChildren<Self> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input }
             | Threshold { input } => {
@@ -1888,6 +2077,7 @@ impl VisitChildren<Self> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input }
             | Threshold { input } => {
@@ -1972,6 +2162,7 @@ impl VisitChildren<Self> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input }
             | Threshold { input } => {
@@ -2053,6 +2244,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input: _ }
             | Threshold { input: _ }
@@ -2124,6 +2316,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input: _ }
             | Threshold { input: _ }
@@ -2196,6 +2389,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input: _ }
             | Threshold { input: _ }
@@ -2269,6 +2463,7 @@ impl VisitChildren<HirScalarExpr> for HirRelationExpr {
                 limit: _,
                 offset: _,
                 expected_group_size: _,
+                with_ties: _,
             }
             | Negate { input: _ }
             | Threshold { input: _ }
@@ -2695,7 +2890,11 @@ impl HirScalarExpr {
         f(depth, self)
     }
 
-    fn simplify_to_literal(self) -> Option<Row> {
+    /// Attempts to simplify this expression to a literal.
+    ///
+    /// Returns `None` if this expression cannot be simplified, e.g. because it
+    /// contains non-literal values.
+    pub fn simplify_to_literal(self) -> Option<Row> {
         let mut expr = self.lower_uncorrelated().ok()?;
         expr.reduce(&[]);
         match expr {
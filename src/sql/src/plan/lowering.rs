@@ -705,6 +705,7 @@ impl HirRelationExpr {
                     limit,
                     offset,
                     expected_group_size,
+                    with_ties,
                 } => {
                     // TopK is uncomplicated, except that we must group by the columns of `get_outer` as well.
                     let input = input.applied_to(id_gen, get_outer.clone(), col_map, cte_map)?;
@@ -725,6 +726,7 @@ impl HirRelationExpr {
                         limit,
                         offset,
                         expected_group_size,
+                        with_ties,
                     )
                 }
                 Negate { input } => {
@@ -1127,8 +1129,18 @@ impl HirScalarExpr {
                             )?;
                             SS::Column(inner.arity() - 1)
                         }
-                        WindowExprType::Value(func) => {
-                            let hir_scalar_input = func.expr.clone();
+                        func @ (WindowExprType::Value(_) | WindowExprType::Aggregate(_)) => {
+                            // Value and aggregate window functions are both computed by a reduction
+                            // that receives the input value of every row of the partition
+                            let (hir_scalar_input, func) = match func {
+                                WindowExprType::Value(func) => {
+                                    (func.expr.clone(), func.into_expr())
+                                }
+                                WindowExprType::Aggregate(func) => {
+                                    (func.aggregate_expr.expr.clone(), func.into_expr())
+                                }
+                                WindowExprType::Scalar(_) => unreachable!(),
+                            };
                             *inner = inner.take_dangerous().let_in_fallible(
                                 id_gen,
                                 |id_gen, mut get_inner| {
@@ -1267,7 +1279,6 @@ impl HirScalarExpr {
                                         }
                                         .nullable(false);
 
-                                        let func = func.into_expr();
                                         let aggregate = mz_expr::AggregateExpr {
                                             func,
                                             expr: agg_input,
@@ -48,21 +48,22 @@ use mz_ore::collections::CollectionExt;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_ore::str::StrExt;
 use mz_repr::adt::char::CharLength;
-use mz_repr::adt::numeric::{NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
+use mz_repr::adt::numeric::{self, NumericMaxScale, NUMERIC_DATUM_MAX_PRECISION};
 use mz_repr::adt::varchar::VarCharMaxLength;
 use mz_repr::{
     strconv, ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, Row, RowArena,
     ScalarType,
 };
 use mz_sql_parser::ast::display::AstDisplay;
+use mz_sql_parser::ast::visit::{self, Visit};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
-    AsOf, Assignment, AstInfo, CteBlock, DeleteStatement, Distinct, Expr, Function, FunctionArgs,
-    HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join, JoinConstraint, JoinOperator,
-    Limit, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName, SetExpr,
-    SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor, TableFunction,
-    TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
-    WindowFrameBound, WindowFrameUnits, WindowSpec,
+    AsOf, Assignment, AstInfo, Cte, CteBlock, DeleteStatement, Distinct, Expr, Function,
+    FunctionArgs, GroupByExpr, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
+    JoinConstraint, JoinOperator, Limit, OrderByExpr, Query, Select, SelectItem, SelectOption,
+    SelectOptionName, SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias,
+    TableFactor, TableFunction, TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values,
+    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
 };
 
 use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
@@ -71,10 +72,10 @@ use crate::names::{Aug, PartialItemName, ResolvedDataType, ResolvedItemName};
 use crate::normalize;
 use crate::plan::error::PlanError;
 use crate::plan::expr::{
-    AbstractColumnType, AbstractExpr, AggregateExpr, AggregateFunc, BinaryFunc,
-    CoercibleScalarExpr, ColumnOrder, ColumnRef, Hir, HirRelationExpr, HirScalarExpr, JoinKind,
-    ScalarWindowExpr, ScalarWindowFunc, UnaryFunc, ValueWindowExpr, VariadicFunc, WindowExpr,
-    WindowExprType,
+    AbstractColumnType, AbstractExpr, AggregateExpr, AggregateFunc, AggregateWindowExpr,
+    BinaryFunc, CoercibleScalarExpr, ColumnOrder, ColumnRef, Hir, HirRelationExpr, HirScalarExpr,
+    JoinKind, ScalarWindowExpr, ScalarWindowFunc, UnaryFunc, ValueWindowExpr, ValueWindowFunc,
+    VariadicFunc, WindowExpr, WindowExprType,
 };
 use crate::plan::plan_utils::{self, JoinSide};
 use crate::plan::scope::{Scope, ScopeItem};
@@ -996,6 +997,36 @@ pub fn plan_index_exprs<'a>(
     Ok(out)
 }
 
+/// Plans the predicate of a `CHECK` constraint on a table described by `desc`.
+pub fn plan_check_expr(
+    scx: &StatementContext,
+    desc: &RelationDesc,
+    expr: &Expr<Aug>,
+) -> Result<mz_expr::MirScalarExpr, PlanError> {
+    let scope = Scope::from_source(None, desc.iter_names());
+    let qcx = QueryContext::root(scx, QueryLifetime::Static);
+    let ecx = &ExprContext {
+        qcx: &qcx,
+        name: "CHECK constraint",
+        scope: &scope,
+        relation_type: desc.typ(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+    let mut expr = expr.clone();
+    transform_ast::transform(scx, &mut expr)?;
+    let expr = plan_expr(ecx, &expr)?
+        .type_as(ecx, &ScalarType::Bool)?
+        .lower_uncorrelated()?;
+    // The constraint is checked once, when a row is written, so its outcome
+    // must not depend on when that happens.
+    if expr.contains_unmaterializable() || expr.contains_temporal() {
+        sql_bail!("functions in CHECK constraints must be immutable");
+    }
+    Ok(expr)
+}
+
 fn plan_expr_or_col_index(ecx: &ExprContext, e: &Expr<Aug>) -> Result<HirScalarExpr, PlanError> {
     match check_col_index(ecx.name, e, ecx.relation_type.column_types.len())? {
         Some(column) => Ok(HirScalarExpr::column(column)),
@@ -1038,28 +1069,27 @@ fn plan_query_inner(
     // for the identifiers, so that they can be re-installed before returning.
     let cte_bindings = plan_ctes(qcx, q)?;
 
-    let limit = match &q.limit {
-        None => None,
+    let (limit, with_ties) = match &q.limit {
+        None => (None, false),
         Some(Limit {
             quantity: Expr::Value(Value::Number(x)),
-            with_ties: false,
-        }) => Some(x.parse()?),
-        Some(Limit {
-            quantity: _,
-            with_ties: true,
-        }) => bail_unsupported!("FETCH ... WITH TIES"),
+            with_ties,
+        }) => (Some(x.parse()?), *with_ties),
         Some(Limit {
             quantity: _,
             with_ties: _,
         }) => sql_bail!("LIMIT must be an integer constant"),
     };
+    if with_ties && q.order_by.is_empty() {
+        sql_bail!("WITH TIES cannot be specified without ORDER BY clause");
+    }
     let offset = match &q.offset {
         None => 0,
         Some(Expr::Value(Value::Number(x))) => x.parse()?,
         _ => sql_bail!("OFFSET must be an integer constant"),
     };
 
-    let (mut result, scope, finishing, expected_group_size) = match &q.body {
+    let (mut result, scope, mut finishing, expected_group_size) = match &q.body {
         SetExpr::Select(s) => {
             // Extract query options.
             let SelectOptionExtracted {
@@ -1099,6 +1129,21 @@ fn plan_query_inner(
         }
     }?;
 
+    // Retaining ties is not expressible as a `RowSetFinishing`, so we apply
+    // the limit and offset with a `TopK` and leave only the ordering and
+    // projection to the finishing.
+    if with_ties {
+        result = HirRelationExpr::TopK {
+            input: Box::new(result),
+            group_key: vec![],
+            order_key: finishing.order_by.clone(),
+            limit: finishing.limit.take(),
+            offset: std::mem::take(&mut finishing.offset),
+            expected_group_size,
+            with_ties,
+        };
+    }
+
     // Both introduce `Let` bindings atop `result` and re-install shadowed bindings.
     match &q.ctes {
         CteBlock::Simple(_) => {
@@ -1116,7 +1161,7 @@ fn plan_query_inner(
                 }
             }
         }
-        CteBlock::MutuallyRecursive(_) => {
+        CteBlock::Recursive(_) | CteBlock::MutuallyRecursive(_) => {
             let mut bindings = Vec::new();
             for (id, value, shadowed_val) in cte_bindings.into_iter() {
                 if let Some(cte) = qcx.ctes.remove(&id) {
@@ -1185,6 +1230,78 @@ pub fn plan_ctes(
                 result.push((cte.id, val, shadowed));
             }
         }
+        CteBlock::Recursive(ctes) => {
+            qcx.scx.require_with_recursive()?;
+
+            // Plan all CTEs in order. Unlike in a `WITH MUTUALLY RECURSIVE`
+            // block, the types of the bindings are not declared, but are
+            // instead determined by their non-recursive terms.
+            for cte in ctes.iter() {
+                let cte_name = normalize::ident(cte.alias.name.clone());
+                let (typ, scope, val) = match plan_recursive_cte_base(qcx, cte)? {
+                    Some((typ, scope)) => {
+                        // The recursive term may introduce `NULL`s, so the
+                        // binding only takes the column types of the
+                        // non-recursive term.
+                        let typ = RelationType::new(
+                            typ.column_types
+                                .into_iter()
+                                .map(|ty| ty.scalar_type.nullable(true))
+                                .collect(),
+                        );
+                        (typ, scope, None)
+                    }
+                    None => {
+                        let (val, scope) = plan_nested_query(qcx, &cte.query)?;
+                        (qcx.relation_type(&val), scope, Some(val))
+                    }
+                };
+                let mut desc = RelationDesc::new(typ, scope.column_names());
+                plan_utils::maybe_rename_columns(
+                    format!("CTE {}", cte.alias.name),
+                    &mut desc,
+                    &cte.alias.columns,
+                )?;
+                let shadowed = qcx.ctes.insert(
+                    cte.id,
+                    CteDesc {
+                        name: cte_name.clone(),
+                        desc,
+                    },
+                );
+
+                let val = match val {
+                    Some(val) => val,
+                    None => {
+                        let (val, _scope) = plan_nested_query(qcx, &cte.query)?;
+                        // Validate that the recursive term did not change the
+                        // type of the binding.
+                        let typ = qcx.relation_type(&val);
+                        let declared_typ = qcx.ctes[&cte.id].desc.typ();
+                        for (i, (declared, inferred)) in declared_typ
+                            .column_types
+                            .iter()
+                            .zip(typ.column_types.iter())
+                            .enumerate()
+                        {
+                            if declared.scalar_type != inferred.scalar_type {
+                                sql_bail!(
+                                    "recursive query {} column {} has type {} in non-recursive \
+                                     term but type {} overall",
+                                    cte_name.quoted(),
+                                    i + 1,
+                                    qcx.humanize_scalar_type(&declared.scalar_type),
+                                    qcx.humanize_scalar_type(&inferred.scalar_type),
+                                );
+                            }
+                        }
+                        val
+                    }
+                };
+
+                result.push((cte.id, val, shadowed));
+            }
+        }
         CteBlock::MutuallyRecursive(ctes) => {
             qcx.scx.require_with_mutually_recursive()?;
 
@@ -1250,6 +1367,216 @@ pub fn plan_ctes(
     Ok(result)
 }
 
+/// Plans the non-recursive term of a CTE in a `WITH RECURSIVE` block,
+/// returning its type and scope, or `None` if the CTE does not refer to
+/// itself.
+///
+/// A CTE that refers to itself must have the form `non_recursive_term UNION
+/// [ALL] recursive_term`, where only `recursive_term` refers to the CTE, and
+/// does so exactly once, outside of any subquery, `EXCEPT`, `INTERSECT`, or
+/// nullable side of an outer join, and not as the input of an aggregation.
+/// The CTE is then planned as a fixpoint of its query,
+/// which, for such a query, matches PostgreSQL's iterative evaluation: `UNION`
+/// accumulates the distinct rows reachable from the non-recursive term, while
+/// `UNION ALL` accumulates one row per derivation.
+fn plan_recursive_cte_base(
+    qcx: &mut QueryContext,
+    cte: &Cte<Aug>,
+) -> Result<Option<(RelationType, Scope)>, PlanError> {
+    let cte_name = normalize::ident(cte.alias.name.clone());
+    let mut finder = CteReferenceFinder::new(qcx.scx, cte.id);
+    finder.visit_query(&cte.query);
+    let total_references = finder.count;
+    if total_references == 0 {
+        return Ok(None);
+    }
+
+    let (left, right) = match &cte.query.body {
+        SetExpr::SetOperation {
+            op: SetOperator::Union,
+            left,
+            right,
+            ..
+        } => (left, right),
+        _ => sql_bail!(
+            "recursive query {} does not have the form non-recursive-term UNION [ALL] \
+             recursive-term",
+            cte_name.quoted()
+        ),
+    };
+    if !cte.query.order_by.is_empty() {
+        bail_unsupported!("ORDER BY in a recursive query");
+    }
+    if cte.query.limit.is_some() || cte.query.offset.is_some() {
+        bail_unsupported!("LIMIT or OFFSET in a recursive query");
+    }
+    let mut left_finder = CteReferenceFinder::new(qcx.scx, cte.id);
+    left_finder.visit_set_expr(left);
+    if left_finder.count > 0 {
+        sql_bail!(
+            "recursive reference to query {} must not appear within its non-recursive term",
+            cte_name.quoted()
+        );
+    }
+    let mut right_finder = CteReferenceFinder::new(qcx.scx, cte.id);
+    right_finder.visit_set_expr(right);
+    if right_finder.count > 1 {
+        sql_bail!(
+            "recursive reference to query {} must not appear more than once",
+            cte_name.quoted()
+        );
+    }
+    // The fixpoint of a query only matches PostgreSQL's iterative evaluation
+    // if each iteration can be computed from the rows produced by the previous
+    // iteration alone, so the recursive reference must not appear anywhere
+    // that depends on all of the rows of the binding at once.
+    if let Some(context) = right_finder.disallowed_context {
+        sql_bail!(
+            "recursive reference to query {} must not appear within {}",
+            cte_name.quoted(),
+            context
+        );
+    }
+    if right_finder.aggregates {
+        sql_bail!("aggregate functions are not allowed in a recursive query's recursive term");
+    }
+    if right_finder.count < total_references {
+        bail_unsupported!("recursive reference in a nested WITH clause");
+    }
+
+    let base = Query {
+        ctes: cte.query.ctes.clone(),
+        body: (**left).clone(),
+        order_by: vec![],
+        limit: None,
+        offset: None,
+    };
+    let (val, scope) = plan_nested_query(qcx, &base)?;
+    Ok(Some((qcx.relation_type(&val), scope)))
+}
+
+/// Finds the references to the CTE `id`, noting whether any appears where a
+/// recursive reference may not.
+struct CteReferenceFinder<'a> {
+    scx: &'a StatementContext<'a>,
+    id: LocalId,
+    /// The number of references found.
+    count: usize,
+    /// The constructs enclosing the node being visited that a recursive
+    /// reference may not appear within, innermost last.
+    context: Vec<&'static str>,
+    /// The innermost such construct enclosing the first reference found
+    /// within one.
+    disallowed_context: Option<&'static str>,
+    /// Whether a reference was found in a `SELECT` that computes aggregates.
+    aggregates: bool,
+}
+
+impl<'a> CteReferenceFinder<'a> {
+    fn new(scx: &'a StatementContext<'a>, id: LocalId) -> CteReferenceFinder<'a> {
+        CteReferenceFinder {
+            scx,
+            id,
+            count: 0,
+            context: Vec::new(),
+            disallowed_context: None,
+            aggregates: false,
+        }
+    }
+
+    fn visit_within<F>(&mut self, context: Option<&'static str>, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        if let Some(context) = context {
+            self.context.push(context);
+        }
+        f(self);
+        if context.is_some() {
+            self.context.pop();
+        }
+    }
+}
+
+impl<'a, 'ast> Visit<'ast, Aug> for CteReferenceFinder<'a> {
+    fn visit_item_name(&mut self, name: &'ast ResolvedItemName) {
+        if let ResolvedItemName::Cte { id, .. } = name {
+            if *id == self.id {
+                self.count += 1;
+                if self.disallowed_context.is_none() {
+                    self.disallowed_context = self.context.last().copied();
+                }
+            }
+        }
+    }
+
+    fn visit_set_expr(&mut self, set_expr: &'ast SetExpr<Aug>) {
+        let context = match set_expr {
+            SetExpr::SetOperation {
+                op: SetOperator::Except,
+                ..
+            } => Some("EXCEPT"),
+            SetExpr::SetOperation {
+                op: SetOperator::Intersect,
+                ..
+            } => Some("INTERSECT"),
+            _ => None,
+        };
+        self.visit_within(context, |this| visit::visit_set_expr(this, set_expr));
+    }
+
+    fn visit_select(&mut self, select: &'ast Select<Aug>) {
+        let count = self.count;
+        visit::visit_select(self, select);
+        if self.count > count {
+            // Errors are reported when the query is planned.
+            let mut visitor = AggregateTableFuncVisitor::new(self.scx);
+            visitor.visit_select_mut(&mut select.clone());
+            if let Ok((aggs, _, _)) = visitor.into_result() {
+                self.aggregates |= !aggs.is_empty();
+            }
+        }
+    }
+
+    fn visit_table_with_joins(&mut self, table_with_joins: &'ast TableWithJoins<Aug>) {
+        // Whether each relation in the join is on the nullable side of an
+        // outer join, starting with the leftmost.
+        let mut nullable = vec![false; table_with_joins.joins.len() + 1];
+        for (i, join) in table_with_joins.joins.iter().enumerate() {
+            match &join.join_operator {
+                JoinOperator::Inner(_) | JoinOperator::CrossJoin => (),
+                JoinOperator::LeftOuter(_) => nullable[i + 1] = true,
+                JoinOperator::RightOuter(_) => nullable[..=i].fill(true),
+                JoinOperator::FullOuter(_) => nullable[..=i + 1].fill(true),
+            }
+        }
+        let outer_join = |nullable: bool| nullable.then_some("an outer join");
+        self.visit_within(outer_join(nullable[0]), |this| {
+            this.visit_table_factor(&table_with_joins.relation)
+        });
+        for (join, nullable) in table_with_joins.joins.iter().zip(&nullable[1..]) {
+            self.visit_within(outer_join(*nullable), |this| {
+                this.visit_table_factor(&join.relation)
+            });
+            self.visit_join_operator(&join.join_operator);
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr<Aug>) {
+        let context = match expr {
+            Expr::Exists(_)
+            | Expr::Subquery(_)
+            | Expr::InSubquery { .. }
+            | Expr::AnySubquery { .. }
+            | Expr::AllSubquery { .. }
+            | Expr::ArraySubquery(_)
+            | Expr::ListSubquery(_) => Some("a subquery"),
+            _ => None,
+        };
+        self.visit_within(context, |this| visit::visit_expr(this, expr));
+    }
+}
+
 pub fn plan_nested_query(
     qcx: &mut QueryContext,
     q: &Query<Aug>,
@@ -1264,6 +1591,7 @@ pub fn plan_nested_query(
             limit: finishing.limit,
             offset: finishing.offset,
             expected_group_size,
+            with_ties: false,
         };
     }
     Ok((expr.project(finishing.project), scope))
@@ -1717,8 +2045,8 @@ fn plan_view_select(
         relation_expr = relation_expr.filter(vec![expr]);
     }
 
-    // Step 3. Gather aggregates and table functions.
-    let (aggregates, table_funcs) = {
+    // Step 3. Gather aggregates, grouping operations and table functions.
+    let (aggregates, groupings, table_funcs) = {
         let mut visitor = AggregateTableFuncVisitor::new(qcx.scx);
         visitor.visit_select_mut(&mut s);
         for o in order_by_exprs.iter_mut() {
@@ -1778,7 +2106,19 @@ fn plan_view_select(
         let mut group_scope = Scope::empty();
         let mut select_all_mapping = BTreeMap::new();
 
-        for group_expr in &s.group_by {
+        // Each grouping set is planned over the same columns, which are the
+        // distinct expressions of all grouping sets.
+        let grouping_sets = expand_grouping_sets(&s.group_by)?;
+        let mut distinct_group_exprs = vec![];
+        for group_expr in grouping_sets.iter().flatten() {
+            if !distinct_group_exprs.contains(group_expr) {
+                distinct_group_exprs.push(*group_expr);
+            }
+        }
+        // The index into `group_key` of each of `distinct_group_exprs`.
+        let mut group_key_positions = vec![];
+
+        for group_expr in distinct_group_exprs.iter().copied() {
             let (group_expr, expr) = plan_group_by_expr(ecx, group_expr, &projection)?;
             let new_column = group_key.len();
 
@@ -1788,9 +2128,15 @@ fn plan_view_select(
                 // next AST expression to its set
                 if let Some(existing_scope_item) = group_exprs.get_mut(&expr) {
                     existing_scope_item.exprs.insert(group_expr.clone());
+                    let position = group_hir_exprs
+                        .iter()
+                        .position(|e| *e == expr)
+                        .expect("planned grouping expressions are tracked");
+                    group_key_positions.push(position);
                     continue;
                 }
             }
+            group_key_positions.push(group_key.len());
 
             let mut scope_item = if let HirScalarExpr::Column(ColumnRef {
                 level: 0,
@@ -1825,6 +2171,44 @@ fn plan_view_select(
             }
         }
 
+        // Translate the grouping sets into sets of positions in `group_key`.
+        let grouping_sets: Vec<BTreeSet<usize>> = grouping_sets
+            .iter()
+            .map(|set| {
+                set.iter()
+                    .map(|e| {
+                        let i = distinct_group_exprs
+                            .iter()
+                            .position(|d| d == e)
+                            .expect("grouping set expressions are distinct group expressions");
+                        group_key_positions[i]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Plan the arguments of grouping operations as positions in `group_key`.
+        let mut grouping_args = vec![];
+        for grouping in &groupings {
+            let Expr::Grouping { exprs } = grouping else {
+                unreachable!("only grouping operations are collected")
+            };
+            if exprs.len() > 31 {
+                sql_bail!("GROUPING must have fewer than 32 arguments");
+            }
+            let mut positions = vec![];
+            for expr in exprs {
+                let expr = plan_expr(ecx, expr)?.type_as_any(ecx)?;
+                match group_hir_exprs.iter().position(|e| *e == expr) {
+                    Some(position) => positions.push(position),
+                    None => sql_bail!(
+                        "arguments to GROUPING must be grouping expressions of the associated query level"
+                    ),
+                }
+            }
+            grouping_args.push(positions);
+        }
+
         // Plan aggregates.
         let ecx = &ExprContext {
             qcx,
@@ -1842,13 +2226,30 @@ fn plan_view_select(
                 .items
                 .push(ScopeItem::from_expr(Expr::Function(sql_function.clone())));
         }
-        if !agg_exprs.is_empty() || !group_key.is_empty() || s.having.is_some() {
+        for grouping in groupings {
+            group_scope.items.push(ScopeItem::from_expr(grouping));
+        }
+        if !agg_exprs.is_empty()
+            || !group_key.is_empty()
+            || s.having.is_some()
+            || !s.group_by.is_empty()
+            || !grouping_args.is_empty()
+        {
             // apply GROUP BY / aggregates
-            relation_expr = relation_expr.map(group_hir_exprs).reduce(
-                group_key,
-                agg_exprs,
-                expected_group_size,
-            );
+            relation_expr = relation_expr.map(group_hir_exprs);
+            relation_expr = if grouping_sets.len() == 1 && grouping_args.is_empty() {
+                relation_expr.reduce(group_key, agg_exprs, expected_group_size)
+            } else {
+                plan_grouping_sets_reduce(
+                    qcx,
+                    relation_expr,
+                    &group_key,
+                    &grouping_sets,
+                    &agg_exprs,
+                    &grouping_args,
+                    expected_group_size,
+                )
+            };
             (group_scope, select_all_mapping)
         } else {
             // if no GROUP BY, aggregates or having then all columns remain in scope
@@ -2051,6 +2452,7 @@ fn plan_view_select(
                     limit: Some(1),
                     offset: 0,
                     expected_group_size,
+                    with_ties: false,
                 }
             }
         }
@@ -2138,6 +2540,294 @@ fn plan_scalar_table_funcs(
     Ok((expr, scope))
 }
 
+/// The maximum number of grouping sets that a `GROUP BY` clause may expand to.
+const MAX_GROUPING_SETS: usize = 4096;
+
+/// The maximum number of elements in a `CUBE` clause, which expands to all
+/// subsets of its elements.
+const MAX_CUBE_ELEMENTS: usize = 12;
+
+/// Expands the items of a `GROUP BY` clause into the grouping sets they denote.
+///
+/// Plain expressions are part of every grouping set, while `ROLLUP`, `CUBE` and
+/// `GROUPING SETS` multiply the number of grouping sets, as the grouping sets
+/// of all items are combined in every possible way. A `GROUP BY` clause without
+/// any of these expands to a single grouping set.
+fn expand_grouping_sets(group_by: &[GroupByExpr<Aug>]) -> Result<Vec<Vec<&Expr<Aug>>>, PlanError> {
+    let mut sets = vec![vec![]];
+    for group_by_expr in group_by {
+        let expr_sets = expand_group_by_expr(group_by_expr)?;
+        sets = sets
+            .iter()
+            .cartesian_product(&expr_sets)
+            .map(|(l, r)| l.iter().chain(r).copied().collect())
+            .collect();
+        if sets.len() > MAX_GROUPING_SETS {
+            sql_bail!(
+                "too many grouping sets present (maximum {})",
+                MAX_GROUPING_SETS
+            );
+        }
+    }
+    Ok(sets)
+}
+
+fn expand_group_by_expr(
+    group_by_expr: &GroupByExpr<Aug>,
+) -> Result<Vec<Vec<&Expr<Aug>>>, PlanError> {
+    let sets = match group_by_expr {
+        GroupByExpr::Expr(expr) => vec![vec![expr]],
+        GroupByExpr::List(exprs) => vec![exprs.iter().collect()],
+        GroupByExpr::Rollup(elements) => {
+            let elements = elements
+                .iter()
+                .map(expand_grouping_element)
+                .collect::<Result<Vec<_>, _>>()?;
+            (0..=elements.len())
+                .rev()
+                .map(|n| elements[..n].iter().flatten().copied().collect())
+                .collect()
+        }
+        GroupByExpr::Cube(elements) => {
+            if elements.len() > MAX_CUBE_ELEMENTS {
+                sql_bail!("CUBE is limited to {} elements", MAX_CUBE_ELEMENTS);
+            }
+            let elements = elements
+                .iter()
+                .map(expand_grouping_element)
+                .collect::<Result<Vec<_>, _>>()?;
+            elements
+                .iter()
+                .powerset()
+                .map(|subset| subset.into_iter().flatten().copied().collect())
+                .collect()
+        }
+        GroupByExpr::GroupingSets(group_by_exprs) => {
+            let mut sets = vec![];
+            for group_by_expr in group_by_exprs {
+                sets.extend(expand_group_by_expr(group_by_expr)?);
+            }
+            sets
+        }
+    };
+    if sets.len() > MAX_GROUPING_SETS {
+        sql_bail!(
+            "too many grouping sets present (maximum {})",
+            MAX_GROUPING_SETS
+        );
+    }
+    Ok(sets)
+}
+
+/// Expands an element of a `ROLLUP` or `CUBE` clause, which must be an
+/// expression or a list of expressions.
+fn expand_grouping_element(element: &GroupByExpr<Aug>) -> Result<Vec<&Expr<Aug>>, PlanError> {
+    match element {
+        GroupByExpr::Expr(expr) => Ok(vec![expr]),
+        GroupByExpr::List(exprs) => Ok(exprs.iter().collect()),
+        GroupByExpr::Rollup(_) | GroupByExpr::Cube(_) | GroupByExpr::GroupingSets(_) => {
+            sql_bail!("ROLLUP and CUBE may only contain expressions and lists of expressions")
+        }
+    }
+}
+
+/// Plans the aggregation of `input` for each of `grouping_sets`, as the union
+/// of one reduction per grouping set.
+///
+/// The grouping sets are given as positions in `group_key`. Every branch of
+/// the union produces all columns of `group_key`, where those that are not
+/// part of its grouping set are null, followed by the aggregates and the
+/// values of the grouping operations, whose arguments are also given as
+/// positions in `group_key`.
+///
+/// If the results of all aggregates can be combined (see
+/// [`combine_aggregate`]), `input` is only reduced once, by the finest
+/// grouping, i.e., the union of all grouping sets. The results of all other
+/// grouping sets are derived from that reduction, so that the input is only
+/// arranged once. Otherwise, every grouping set reduces `input` separately.
+fn plan_grouping_sets_reduce(
+    qcx: &QueryContext,
+    input: HirRelationExpr,
+    group_key: &[usize],
+    grouping_sets: &[BTreeSet<usize>],
+    aggregates: &[AggregateExpr],
+    grouping_args: &[Vec<usize>],
+    expected_group_size: Option<u64>,
+) -> HirRelationExpr {
+    let input_type = qcx.relation_type(&input);
+    let finest: BTreeSet<usize> = grouping_sets.iter().flatten().copied().collect();
+    let combined = aggregates
+        .iter()
+        .map(combine_aggregate)
+        .collect::<Option<Vec<_>>>();
+    // The reduction by the finest grouping, whose columns are the positions of
+    // `finest` in `group_key`, followed by the aggregates.
+    let finest_reduce = combined.as_ref().map(|_| {
+        let finest_key = finest.iter().map(|i| group_key[*i]).collect();
+        input
+            .clone()
+            .reduce(finest_key, aggregates.to_vec(), expected_group_size)
+    });
+
+    let mut branches = grouping_sets.iter().map(|grouping_set| {
+        let set_key = grouping_set.iter().map(|i| group_key[*i]).collect_vec();
+        let reduce_arity = set_key.len() + aggregates.len();
+
+        // Reduces the input by the grouping set, producing the columns of
+        // the grouping set followed by the aggregates.
+        let reduce = match (&finest_reduce, &combined) {
+            (Some(finest_reduce), _) if *grouping_set == finest => finest_reduce.clone(),
+            (Some(finest_reduce), Some(combined)) => {
+                let finest_set_key = grouping_set
+                    .iter()
+                    .map(|i| {
+                        finest
+                            .iter()
+                            .position(|j| j == i)
+                            .expect("subset of finest")
+                    })
+                    .collect_vec();
+                let combined_aggregates = combined
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (func, _))| AggregateExpr {
+                        func: func.clone(),
+                        expr: Box::new(HirScalarExpr::column(finest.len() + i)),
+                        distinct: false,
+                    })
+                    .collect();
+                let finish_exprs = aggregates
+                    .iter()
+                    .zip(combined)
+                    .enumerate()
+                    .map(|(i, (aggregate, (_, cast)))| {
+                        let mut expr = HirScalarExpr::column(set_key.len() + i);
+                        if let Some(cast) = cast {
+                            expr = expr.call_unary(cast.clone());
+                        }
+                        // Unlike counts, sums of no values are null.
+                        if aggregate.func == AggregateFunc::Count {
+                            expr = HirScalarExpr::CallVariadic {
+                                func: VariadicFunc::Coalesce,
+                                exprs: vec![
+                                    expr,
+                                    HirScalarExpr::literal(Datum::Int64(0), ScalarType::Int64),
+                                ],
+                            };
+                        }
+                        expr
+                    })
+                    .collect();
+                let finish_projection = (0..set_key.len())
+                    .chain(reduce_arity..reduce_arity + aggregates.len())
+                    .collect();
+                finest_reduce
+                    .clone()
+                    .reduce(finest_set_key, combined_aggregates, expected_group_size)
+                    .map(finish_exprs)
+                    .project(finish_projection)
+            }
+            _ => input
+                .clone()
+                .reduce(set_key.clone(), aggregates.to_vec(), expected_group_size),
+        };
+
+        let mut map_exprs = vec![];
+        for (i, column) in group_key.iter().enumerate() {
+            match grouping_set.iter().position(|j| *j == i) {
+                Some(set_column) => map_exprs.push(HirScalarExpr::column(set_column)),
+                None => map_exprs.push(HirScalarExpr::literal_null(
+                    input_type.column_types[*column].scalar_type.clone(),
+                )),
+            }
+        }
+        // Like in PostgreSQL, each argument of a grouping operation contributes
+        // a bit to its result, which is set if the argument is not part of the
+        // grouping set, with the last argument being the least significant bit.
+        for args in grouping_args {
+            let grouping = args.iter().fold(0, |acc, i| {
+                (acc << 1) | i32::from(!grouping_set.contains(i))
+            });
+            map_exprs.push(HirScalarExpr::literal(
+                Datum::Int32(grouping),
+                ScalarType::Int32,
+            ));
+        }
+
+        let projection = (reduce_arity..reduce_arity + group_key.len())
+            .chain(set_key.len()..reduce_arity)
+            .chain(reduce_arity + group_key.len()..reduce_arity + map_exprs.len())
+            .collect();
+        reduce.map(map_exprs).project(projection)
+    });
+    let base = branches.next().expect("at least one grouping set");
+    let inputs = branches.collect_vec();
+    if inputs.is_empty() {
+        base
+    } else {
+        HirRelationExpr::Union {
+            base: Box::new(base),
+            inputs,
+        }
+    }
+}
+
+/// Returns the function that computes the result of `aggregate` for a grouping
+/// set from its results for a finer grouping set, along with the cast that
+/// converts the combined result back to the type of `aggregate`.
+///
+/// Returns `None` if the results of `aggregate` cannot be combined, which is
+/// the case for `DISTINCT` aggregates, sums of floating-point values, whose
+/// results would depend on the order of additions, and aggregates that
+/// collect or order their inputs.
+fn combine_aggregate(aggregate: &AggregateExpr) -> Option<(AggregateFunc, Option<UnaryFunc>)> {
+    if aggregate.distinct {
+        return None;
+    }
+    let cast_to_int64 = UnaryFunc::CastNumericToInt64(expr_func::CastNumericToInt64);
+    match &aggregate.func {
+        AggregateFunc::Count | AggregateFunc::SumInt16 | AggregateFunc::SumInt32 => {
+            Some((AggregateFunc::SumInt64, Some(cast_to_int64)))
+        }
+        AggregateFunc::SumInt64 | AggregateFunc::SumNumeric => {
+            Some((AggregateFunc::SumNumeric, None))
+        }
+        func @ (AggregateFunc::MaxNumeric
+        | AggregateFunc::MaxInt16
+        | AggregateFunc::MaxInt32
+        | AggregateFunc::MaxInt64
+        | AggregateFunc::MaxUInt16
+        | AggregateFunc::MaxUInt32
+        | AggregateFunc::MaxUInt64
+        | AggregateFunc::MaxMzTimestamp
+        | AggregateFunc::MaxFloat32
+        | AggregateFunc::MaxFloat64
+        | AggregateFunc::MaxBool
+        | AggregateFunc::MaxString
+        | AggregateFunc::MaxDate
+        | AggregateFunc::MaxTimestamp
+        | AggregateFunc::MaxTimestampTz
+        | AggregateFunc::MinNumeric
+        | AggregateFunc::MinInt16
+        | AggregateFunc::MinInt32
+        | AggregateFunc::MinInt64
+        | AggregateFunc::MinUInt16
+        | AggregateFunc::MinUInt32
+        | AggregateFunc::MinUInt64
+        | AggregateFunc::MinMzTimestamp
+        | AggregateFunc::MinFloat32
+        | AggregateFunc::MinFloat64
+        | AggregateFunc::MinBool
+        | AggregateFunc::MinString
+        | AggregateFunc::MinDate
+        | AggregateFunc::MinTimestamp
+        | AggregateFunc::MinTimestampTz
+        | AggregateFunc::Any
+        | AggregateFunc::All) => Some((func.clone(), None)),
+        _ => None,
+    }
+}
+
 /// Plans an expression in a `GROUP BY` clause.
 ///
 /// For historical reasons, PostgreSQL allows `GROUP BY` expressions to refer to
@@ -2597,6 +3287,10 @@ fn plan_table_function_internal(
             }
             plan_exprs(ecx, args)?
         }
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        ),
     };
     let resolved_name = normalize::unresolved_item_name(name.clone())?;
     let table_name = match table_name {
@@ -2769,6 +3463,7 @@ fn invent_column_name(
                 NameQuality::High,
             )),
             Expr::NullIf { .. } => Some(("nullif".into(), NameQuality::High)),
+            Expr::Grouping { .. } => Some(("grouping".into(), NameQuality::High)),
             Expr::Array { .. } => Some(("array".into(), NameQuality::High)),
             Expr::List { .. } => Some(("list".into(), NameQuality::High)),
             Expr::Cast { expr, data_type } => match invent(ecx, expr, table_func_names) {
@@ -3259,6 +3954,9 @@ fn plan_expr_inner<'a>(
         Expr::HomogenizingFunction { function, exprs } => {
             plan_homogenizing_function(ecx, function, exprs)
         }
+        Expr::Grouping { .. } => {
+            sql_bail!("grouping operations are not allowed in {}", ecx.name)
+        }
         Expr::NullIf { l_expr, r_expr } => Ok(plan_case(
             ecx,
             &None,
@@ -3845,6 +4543,7 @@ where
             limit: finishing.limit,
             offset: finishing.offset,
             expected_group_size,
+            with_ties: false,
         };
     }
 
@@ -4165,7 +4864,7 @@ fn plan_aggregate(
         name,
         args,
         filter,
-        over,
+        over: _,
         distinct,
     }: &Function<Aug>,
 ) -> Result<AggregateExpr, PlanError> {
@@ -4187,10 +4886,6 @@ fn plan_aggregate(
         _ => unreachable!("plan_aggregate called on non-aggregate function,"),
     };
 
-    if over.is_some() {
-        bail_unsupported!("aggregate window functions");
-    }
-
     let name = normalize::unresolved_item_name(name.clone())?;
 
     // We follow PostgreSQL's rule here for mapping `count(*)` into the
@@ -4213,11 +4908,33 @@ fn plan_aggregate(
             let args = plan_exprs(ecx, args)?;
             (args, order_by.clone())
         }
+        // The arguments of an ordered-set aggregate are its direct arguments
+        // followed by the aggregated arguments, which are the expressions in
+        // its `WITHIN GROUP (ORDER BY ...)` clause.
+        FunctionArgs::WithinGroup { args, order_by } => {
+            let mut args = plan_exprs(ecx, args)?;
+            let aggregated_args: Vec<_> = order_by.iter().map(|obe| &obe.expr).collect();
+            args.extend(plan_exprs(ecx, &aggregated_args)?);
+            (args, order_by.clone())
+        }
     };
 
     let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &order_by)?;
 
     let (mut expr, func) = func::select_impl(ecx, FuncSpec::Func(&name), impls, args, col_orders)?;
+    match (args, func.is_ordered_set()) {
+        (FunctionArgs::WithinGroup { .. }, false) => sql_bail!(
+            "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+            name
+        ),
+        (FunctionArgs::Star | FunctionArgs::Args { .. }, true) => {
+            sql_bail!(
+                "WITHIN GROUP is required for ordered-set aggregate {}",
+                name
+            )
+        }
+        _ => (),
+    }
     if let Some(filter) = &filter {
         // If a filter is present, as in
         //
@@ -4385,6 +5102,46 @@ fn plan_function<'a>(
     let unresolved_name = normalize::unresolved_item_name(name.clone())?;
 
     let impls = match resolve_func(ecx, name, args)? {
+        Func::Aggregate(_) if over.is_some() => {
+            if !ecx.allow_windows {
+                sql_bail!(
+                    "window functions are not allowed in {} (function {})",
+                    ecx.name,
+                    unresolved_name
+                );
+            }
+            if *distinct {
+                bail_unsupported!("DISTINCT in aggregate window functions");
+            }
+            if let FunctionArgs::WithinGroup { .. } = args {
+                sql_bail!(
+                    "OVER is not supported for ordered-set aggregate {}",
+                    unresolved_name
+                );
+            }
+
+            let window_spec = over.as_ref().expect("checked above");
+            let (window_frame, partition, order_by, col_orders) =
+                plan_window_spec(ecx, window_spec)?;
+
+            // Window functions can't be nested in the arguments of the aggregate.
+            let aggregate_ecx = ExprContext {
+                name: "aggregate window function",
+                allow_windows: false,
+                ..ecx.clone()
+            };
+            let aggregate_expr = plan_aggregate(&aggregate_ecx, f)?;
+
+            return Ok(HirScalarExpr::Windowing(WindowExpr {
+                func: WindowExprType::Aggregate(AggregateWindowExpr {
+                    aggregate_expr,
+                    order_by: col_orders,
+                    window_frame,
+                }),
+                partition,
+                order_by,
+            }));
+        }
         Func::Aggregate(_) if ecx.allow_aggregates => {
             // should already have been caught by `scope.resolve_expr` in `plan_expr`
             sql_bail!(
@@ -4408,7 +5165,8 @@ fn plan_function<'a>(
         }
         Func::Scalar(impls) => impls,
         Func::ScalarWindow(impls) => {
-            let (window_spec, _, scalar_args, partition) = validate_window_function_plan(ecx, f)?;
+            let (scalar_args, (_, partition, order_by, col_orders)) =
+                validate_window_function_plan(ecx, f)?;
 
             let func = func::select_impl(
                 ecx,
@@ -4418,8 +5176,6 @@ fn plan_function<'a>(
                 vec![],
             )?;
 
-            let (order_by, col_orders) = plan_function_order_by(ecx, &window_spec.order_by)?;
-
             return Ok(HirScalarExpr::Windowing(WindowExpr {
                 func: WindowExprType::Scalar(ScalarWindowExpr {
                     func,
@@ -4430,7 +5186,7 @@ fn plan_function<'a>(
             }));
         }
         Func::ValueWindow(impls) => {
-            let (window_spec, window_frame, scalar_args, partition) =
+            let (scalar_args, (window_frame, partition, order_by, col_orders)) =
                 validate_window_function_plan(ecx, f)?;
 
             let (expr, func) = func::select_impl(
@@ -4441,9 +5197,12 @@ fn plan_function<'a>(
                 vec![],
             )?;
 
-            let (order_by, col_orders) = plan_function_order_by(ecx, &window_spec.order_by)?;
-
-            return Ok(HirScalarExpr::Windowing(WindowExpr {
+            // `ntile` only uses the number of buckets of the first row of each partition, and
+            // returns it as its result if it is not positive. Constant numbers of buckets were
+            // already checked during planning.
+            let check_result = matches!(func, ValueWindowFunc::Ntile)
+                && expr.clone().simplify_to_literal().is_none();
+            let expr = HirScalarExpr::Windowing(WindowExpr {
                 func: WindowExprType::Value(ValueWindowExpr {
                     func,
                     expr: Box::new(expr),
@@ -4452,7 +5211,12 @@ fn plan_function<'a>(
                 }),
                 partition,
                 order_by,
-            }));
+            });
+            return Ok(if check_result {
+                expr.call_unary(UnaryFunc::CheckNtileArgument(expr_func::CheckNtileArgument))
+            } else {
+                expr
+            });
         }
     };
 
@@ -4486,6 +5250,10 @@ fn plan_function<'a>(
             }
             plan_exprs(ecx, args)?
         }
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        ),
     };
 
     func::select_impl(
@@ -4524,6 +5292,12 @@ pub fn resolve_func(
             }
             plan_exprs(ecx, args)?
         }
+        mz_sql_parser::ast::FunctionArgs::WithinGroup { args, order_by } => {
+            let mut cexprs = plan_exprs(ecx, args)?;
+            let aggregated_args: Vec<_> = order_by.iter().map(|obe| &obe.expr).collect();
+            cexprs.extend(plan_exprs(ecx, &aggregated_args)?);
+            cexprs
+        }
     };
 
     let arg_types: Vec<_> = cexprs
@@ -4677,7 +5451,7 @@ fn plan_literal<'a>(l: &'a Value) -> Result<CoercibleScalarExpr, PlanError> {
     Ok(expr.into())
 }
 
-fn validate_window_function_plan<'a>(
+fn validate_window_function_plan(
     ecx: &ExprContext,
     Function {
         name,
@@ -4685,16 +5459,8 @@ fn validate_window_function_plan<'a>(
         filter,
         over,
         distinct,
-    }: &'a Function<Aug>,
-) -> Result<
-    (
-        &'a WindowSpec<Aug>,
-        mz_expr::WindowFrame,
-        Vec<CoercibleScalarExpr>,
-        Vec<HirScalarExpr>,
-    ),
-    PlanError,
-> {
+    }: &Function<Aug>,
+) -> Result<(Vec<CoercibleScalarExpr>, PlannedWindowSpec), PlanError> {
     if !ecx.allow_windows {
         sql_bail!(
             "window functions are not allowed in {} (function {})",
@@ -4720,14 +5486,7 @@ fn validate_window_function_plan<'a>(
         Some(over) => over,
         None => sql_bail!("window function {} requires an OVER clause", name),
     };
-    let window_frame = match window_spec.window_frame.as_ref() {
-        Some(frame) => plan_window_frame(frame)?,
-        None => mz_expr::WindowFrame::default(),
-    };
-    let mut partition = Vec::new();
-    for expr in &window_spec.partition_by {
-        partition.push(plan_expr(ecx, expr)?.type_as_any(ecx)?);
-    }
+    let window_spec = plan_window_spec(ecx, window_spec)?;
 
     let scalar_args = match &args {
         FunctionArgs::Star => {
@@ -4742,25 +5501,65 @@ fn validate_window_function_plan<'a>(
             }
             plan_exprs(ecx, args)?
         }
+        FunctionArgs::WithinGroup { .. } => sql_bail!(
+            "WITHIN GROUP specified, but {} is not an aggregate function",
+            name
+        ),
     };
 
-    Ok((window_spec, window_frame, scalar_args, partition))
+    Ok((scalar_args, window_spec))
+}
+
+/// The window frame, the partition, and the ORDER BY expressions and column
+/// orders of a window function's `OVER` clause.
+type PlannedWindowSpec = (
+    mz_expr::WindowFrame,
+    Vec<HirScalarExpr>,
+    Vec<HirScalarExpr>,
+    Vec<ColumnOrder>,
+);
+
+/// Plans the window frame, the partition and the ordering of a window
+/// function's `OVER` clause.
+///
+/// In `RANGE` mode, the offsets of the offset bounds of the frame are appended
+/// to the returned ORDER BY expressions, as expected by `mz_expr`.
+fn plan_window_spec(
+    ecx: &ExprContext,
+    window_spec: &WindowSpec<Aug>,
+) -> Result<PlannedWindowSpec, PlanError> {
+    let mut partition = Vec::new();
+    for expr in &window_spec.partition_by {
+        partition.push(plan_expr(ecx, expr)?.type_as_any(ecx)?);
+    }
+    let (mut order_by, col_orders) = plan_function_order_by(ecx, &window_spec.order_by)?;
+    let window_frame = match window_spec.window_frame.as_ref() {
+        Some(frame) => plan_window_frame(ecx, frame, &mut order_by, &col_orders)?,
+        None => mz_expr::WindowFrame::default(),
+    };
+    Ok((window_frame, partition, order_by, col_orders))
 }
 
 fn plan_window_frame(
+    ecx: &ExprContext,
     WindowFrame {
         units,
         start_bound,
         end_bound,
-    }: &WindowFrame,
+    }: &WindowFrame<Aug>,
+    order_by: &mut Vec<HirScalarExpr>,
+    col_orders: &[ColumnOrder],
 ) -> Result<mz_expr::WindowFrame, PlanError> {
     use mz_expr::WindowFrameBound::*;
-    let units = window_frame_unit_ast_to_expr(units)?;
-    let start_bound = window_frame_bound_ast_to_expr(start_bound);
-    let end_bound = end_bound
-        .as_ref()
-        .map(window_frame_bound_ast_to_expr)
-        .unwrap_or(CurrentRow);
+    let units = window_frame_unit_ast_to_expr(units);
+    let (start_bound, start_offset) =
+        plan_window_frame_bound(ecx, &units, start_bound, "starting", order_by, col_orders)?;
+    let (end_bound, end_offset) = match end_bound {
+        Some(end_bound) => {
+            plan_window_frame_bound(ecx, &units, end_bound, "ending", order_by, col_orders)?
+        }
+        None => (CurrentRow, None),
+    };
 
     // Validate bounds according to Postgres rules
     match (&start_bound, &end_bound) {
@@ -4783,12 +5582,8 @@ fn plan_window_frame(
         (_, _) => (),
     }
 
-    // RANGE is only supported in the default frame
-    if units == mz_expr::WindowFrameUnits::Range
-        && (start_bound != UnboundedPreceding || end_bound != CurrentRow)
-    {
-        bail_unsupported!("RANGE in non-default window frames")
-    }
+    order_by.extend(start_offset);
+    order_by.extend(end_offset);
 
     let frame = mz_expr::WindowFrame {
         units,
@@ -4798,30 +5593,182 @@ fn plan_window_frame(
     Ok(frame)
 }
 
-fn window_frame_unit_ast_to_expr(
-    unit: &WindowFrameUnits,
-) -> Result<mz_expr::WindowFrameUnits, PlanError> {
-    match unit {
-        WindowFrameUnits::Rows => Ok(mz_expr::WindowFrameUnits::Rows),
-        WindowFrameUnits::Range => Ok(mz_expr::WindowFrameUnits::Range),
-        WindowFrameUnits::Groups => bail_unsupported!("GROUPS in window frames"),
+/// Plans a bound of a window frame. `position` is either "starting" or
+/// "ending", and is only used in error messages.
+///
+/// In `RANGE` mode, offset bounds also return their offset, which `mz_expr`
+/// adds to or subtracts from the value of the first ORDER BY expression of each
+/// row, saturating like PostgreSQL's `in_range` functions. The offset and the
+/// ORDER BY expression are cast to the type of that sum if needed, e.g. when
+/// ordering by a date or by an integer with a numeric offset.
+fn plan_window_frame_bound(
+    ecx: &ExprContext,
+    units: &mz_expr::WindowFrameUnits,
+    bound: &WindowFrameBound<Aug>,
+    position: &str,
+    order_by: &mut [HirScalarExpr],
+    col_orders: &[ColumnOrder],
+) -> Result<(mz_expr::WindowFrameBound, Option<HirScalarExpr>), PlanError> {
+    use mz_expr::WindowFrameBound::*;
+    let (offset, preceding) = match bound {
+        WindowFrameBound::CurrentRow => return Ok((CurrentRow, None)),
+        WindowFrameBound::Preceding(None) => return Ok((UnboundedPreceding, None)),
+        WindowFrameBound::Following(None) => return Ok((UnboundedFollowing, None)),
+        WindowFrameBound::Preceding(Some(offset)) => (offset, true),
+        WindowFrameBound::Following(Some(offset)) => (offset, false),
+    };
+
+    // The type of the offset, if it can be derived from the frame
+    let offset_type = match units {
+        // Offsets are numeric so that they can cover the whole range of `u64`
+        mz_expr::WindowFrameUnits::Rows | mz_expr::WindowFrameUnits::Groups => {
+            Some(ScalarType::Numeric { max_scale: None })
+        }
+        mz_expr::WindowFrameUnits::Range => {
+            if order_by.len() != 1 {
+                sql_bail!(
+                    "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column"
+                );
+            }
+            match ecx.scalar_type(&order_by[0]) {
+                ScalarType::Date
+                | ScalarType::Timestamp
+                | ScalarType::TimestampTz
+                | ScalarType::Interval => Some(ScalarType::Interval),
+                _ => None,
+            }
+        }
+    };
+
+    // Offsets are constants, so they are planned without access to the input
+    let offset_ecx = ExprContext {
+        qcx: ecx.qcx,
+        name: "window frame offset",
+        scope: &Scope::empty(),
+        relation_type: &RelationType::empty(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_windows: false,
+    };
+    let offset = plan_expr(&offset_ecx, offset)?;
+    let offset = match &offset_type {
+        Some(ty) => offset.cast_to(&offset_ecx, CastContext::Implicit, ty)?,
+        None => offset.type_as_any(&offset_ecx)?,
+    };
+    let offset_row = match offset.clone().simplify_to_literal() {
+        Some(row) => row,
+        None => sql_bail!("window frame offset must be a constant"),
+    };
+    let offset_datum = offset_row.unpack_first();
+    if offset_datum.is_null() {
+        sql_bail!("frame {} offset must not be null", position);
     }
-}
 
-fn window_frame_bound_ast_to_expr(bound: &WindowFrameBound) -> mz_expr::WindowFrameBound {
-    match bound {
-        WindowFrameBound::CurrentRow => mz_expr::WindowFrameBound::CurrentRow,
-        WindowFrameBound::Preceding(None) => mz_expr::WindowFrameBound::UnboundedPreceding,
-        WindowFrameBound::Preceding(Some(offset)) => {
-            mz_expr::WindowFrameBound::OffsetPreceding(*offset)
+    match units {
+        mz_expr::WindowFrameUnits::Rows | mz_expr::WindowFrameUnits::Groups => {
+            let mut offset = offset_datum.unwrap_numeric().0;
+            if offset.is_negative() {
+                sql_bail!("frame {} offset must not be negative", position);
+            }
+            let mut cx = numeric::cx_datum();
+            cx.round(&mut offset);
+            let offset = cx
+                .try_into_u64(offset)
+                .map_err(|_| sql_err!("frame {} offset is out of range", position))?;
+            let bound = if preceding {
+                OffsetPreceding(offset)
+            } else {
+                OffsetFollowing(offset)
+            };
+            Ok((bound, None))
         }
-        WindowFrameBound::Following(None) => mz_expr::WindowFrameBound::UnboundedFollowing,
-        WindowFrameBound::Following(Some(offset)) => {
-            mz_expr::WindowFrameBound::OffsetFollowing(*offset)
+        mz_expr::WindowFrameUnits::Range => {
+            let order_by_type = ecx.scalar_type(&order_by[0]);
+            let offset_type = ecx.scalar_type(&offset);
+            let is_number = |ty: &ScalarType| {
+                matches!(
+                    ty,
+                    ScalarType::Int16
+                        | ScalarType::Int32
+                        | ScalarType::Int64
+                        | ScalarType::Float32
+                        | ScalarType::Float64
+                        | ScalarType::Numeric { .. }
+                )
+            };
+            let supported = match (&order_by_type, &offset_type) {
+                (ScalarType::Date, ScalarType::Interval)
+                | (ScalarType::Timestamp, ScalarType::Interval)
+                | (ScalarType::TimestampTz, ScalarType::Interval)
+                | (ScalarType::Interval, ScalarType::Interval) => true,
+                (order_by_type, offset_type) => is_number(order_by_type) && is_number(offset_type),
+            };
+            if !supported {
+                sql_bail!(
+                    "RANGE with offset PRECEDING/FOLLOWING is not supported for column type {} and offset type {}",
+                    ecx.humanize_scalar_type(&order_by_type),
+                    ecx.humanize_scalar_type(&offset_type),
+                );
+            }
+            let is_negative = match offset_datum {
+                Datum::Int16(n) => n < 0,
+                Datum::Int32(n) => n < 0,
+                Datum::Int64(n) => n < 0,
+                Datum::Float32(n) => n.is_nan() || *n < 0.0,
+                Datum::Float64(n) => n.is_nan() || *n < 0.0,
+                Datum::Numeric(n) => n.0.is_nan() || n.0.is_negative(),
+                Datum::Interval(i) => i.is_negative(),
+                _ => false,
+            };
+            if is_negative {
+                sql_bail!("invalid preceding or following size in window function");
+            }
+
+            // PRECEDING means smaller values in ascending order, and larger
+            // values in descending order.
+            let op = if preceding != col_orders[0].desc {
+                "-"
+            } else {
+                "+"
+            };
+            let value = func::select_impl(
+                ecx,
+                FuncSpec::Op(op),
+                func::resolve_op(op)?,
+                vec![order_by[0].clone().into(), offset.clone().into()],
+                vec![],
+            )?;
+            let value_type = ecx.scalar_type(&value);
+            if !value_type.base_eq(&order_by_type) {
+                order_by[0] =
+                    order_by[0]
+                        .clone()
+                        .cast_to(ecx, CastContext::Implicit, &value_type)?;
+            }
+            let offset = if offset_type.base_eq(&ScalarType::Interval) {
+                offset
+            } else {
+                offset.cast_to(&offset_ecx, CastContext::Implicit, &value_type)?
+            };
+            // The offset is not used in RANGE mode, see `mz_expr::WindowFrameUnits`
+            let bound = if preceding {
+                OffsetPreceding(0)
+            } else {
+                OffsetFollowing(0)
+            };
+            Ok((bound, Some(offset)))
         }
     }
 }
 
+fn window_frame_unit_ast_to_expr(unit: &WindowFrameUnits) -> mz_expr::WindowFrameUnits {
+    match unit {
+        WindowFrameUnits::Rows => mz_expr::WindowFrameUnits::Rows,
+        WindowFrameUnits::Range => mz_expr::WindowFrameUnits::Range,
+        WindowFrameUnits::Groups => mz_expr::WindowFrameUnits::Groups,
+    }
+}
+
 // Implement these as two identical enums without From/Into impls so that they
 // have no cross-package dependencies, leaving that work up to this crate.
 fn parser_datetimefield_to_adt(
@@ -5007,6 +5954,11 @@ fn scalar_type_from_catalog(
                 CatalogType::Bool => Ok(ScalarType::Bool),
                 CatalogType::Bytes => Ok(ScalarType::Bytes),
                 CatalogType::Date => Ok(ScalarType::Date),
+                CatalogType::Enum { labels } => Ok(ScalarType::Enum {
+                    labels: labels.clone(),
+                    custom_id: id,
+                    custom_oid: entry.oid(),
+                }),
                 CatalogType::Float32 => Ok(ScalarType::Float32),
                 CatalogType::Float64 => Ok(ScalarType::Float64),
                 CatalogType::Int16 => Ok(ScalarType::Int16),
@@ -5036,6 +5988,8 @@ fn scalar_type_from_catalog(
                 CatalogType::Uuid => Ok(ScalarType::Uuid),
                 CatalogType::Int2Vector => Ok(ScalarType::Int2Vector),
                 CatalogType::MzAclItem => Ok(ScalarType::MzAclItem),
+                CatalogType::Inet => Ok(ScalarType::Inet),
+                CatalogType::Cidr => Ok(ScalarType::Cidr),
                 CatalogType::Numeric => unreachable!("handled above"),
                 CatalogType::Char => unreachable!("handled above"),
                 CatalogType::VarChar => unreachable!("handled above"),
@@ -5049,6 +6003,7 @@ fn scalar_type_from_catalog(
 struct AggregateTableFuncVisitor<'a> {
     scx: &'a StatementContext<'a>,
     aggs: Vec<Function<Aug>>,
+    groupings: Vec<Expr<Aug>>,
     within_aggregate: bool,
     tables: BTreeMap<TableFunction<Aug>, String>,
     table_disallowed_context: Vec<&'static str>,
@@ -5061,6 +6016,7 @@ impl<'a> AggregateTableFuncVisitor<'a> {
         AggregateTableFuncVisitor {
             scx,
             aggs: Vec::new(),
+            groupings: Vec::new(),
             within_aggregate: false,
             tables: BTreeMap::new(),
             table_disallowed_context: Vec::new(),
@@ -5071,7 +6027,14 @@ impl<'a> AggregateTableFuncVisitor<'a> {
 
     fn into_result(
         self,
-    ) -> Result<(Vec<Function<Aug>>, BTreeMap<TableFunction<Aug>, String>), PlanError> {
+    ) -> Result<
+        (
+            Vec<Function<Aug>>,
+            Vec<Expr<Aug>>,
+            BTreeMap<TableFunction<Aug>, String>,
+        ),
+        PlanError,
+    > {
         match self.err {
             Some(err) => Err(err),
             None => {
@@ -5083,7 +6046,13 @@ impl<'a> AggregateTableFuncVisitor<'a> {
                     .into_iter()
                     .filter(move |agg| seen.insert(agg.clone()))
                     .collect();
-                Ok((aggs, self.tables))
+                let mut seen = BTreeSet::new();
+                let groupings = self
+                    .groupings
+                    .into_iter()
+                    .filter(move |grouping| seen.insert(grouping.clone()))
+                    .collect();
+                Ok((aggs, groupings, self.tables))
             }
         }
     }
@@ -5098,6 +6067,11 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
         };
 
         match item.func() {
+            // Aggregate window functions are planned along with the other
+            // window functions, but their arguments may contain aggregates.
+            Ok(Func::Aggregate { .. }) if func.over.is_some() => {
+                visit_mut::visit_function_mut(self, func)
+            }
             Ok(Func::Aggregate { .. }) => {
                 if self.within_aggregate {
                     self.err = Some(sql_err!("nested aggregate functions are not allowed",));
@@ -5138,6 +6112,12 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
     }
 
     fn visit_expr_mut(&mut self, expr: &mut Expr<Aug>) {
+        if let Expr::Grouping { .. } = expr {
+            // The arguments of grouping operations are grouping expressions,
+            // which can't contain aggregates or table functions.
+            self.groupings.push(expr.clone());
+            return;
+        }
         let (disallowed_context, func) = match expr {
             Expr::Case { .. } => (Some("CASE"), None),
             Expr::HomogenizingFunction {
@@ -43,7 +43,9 @@ mod scl;
 pub(crate) mod show;
 mod tcl;
 
-pub(crate) use ddl::PgConfigOptionExtracted;
+pub(crate) use ddl::{
+    mysql_column_type, MySqlConfigOptionExtracted, PgConfigOptionExtracted, MYSQL_DATABASE_NAME,
+};
 use mz_repr::role_id::RoleId;
 
 /// Describes the output of a SQL statement.
@@ -114,6 +116,7 @@ pub fn describe(
         // DDL statements.
         Statement::AlterConnection(stmt) => ddl::describe_alter_connection(&scx, stmt)?,
         Statement::AlterIndex(stmt) => ddl::describe_alter_index_options(&scx, stmt)?,
+        Statement::AlterTable(stmt) => ddl::describe_alter_table(&scx, stmt)?,
         Statement::AlterOwner(stmt) => ddl::describe_alter_owner(&scx, stmt)?,
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
         Statement::AlterRole(stmt) => ddl::describe_alter_role(&scx, stmt)?,
@@ -134,6 +137,7 @@ pub fn describe(
         Statement::CreateSink(stmt) => ddl::describe_create_sink(&scx, stmt)?,
         Statement::CreateSource(stmt) => ddl::describe_create_source(&scx, stmt)?,
         Statement::CreateSubsource(stmt) => ddl::describe_create_subsource(&scx, stmt)?,
+        Statement::CreateWebhookSource(stmt) => ddl::describe_create_webhook_source(&scx, stmt)?,
         Statement::CreateTable(stmt) => ddl::describe_create_table(&scx, stmt)?,
         Statement::CreateType(stmt) => ddl::describe_create_type(&scx, stmt)?,
         Statement::CreateView(stmt) => ddl::describe_create_view(&scx, stmt)?,
@@ -249,6 +253,7 @@ pub fn plan(
         // DDL statements.
         Statement::AlterConnection(stmt) => ddl::plan_alter_connection(scx, stmt),
         Statement::AlterIndex(stmt) => ddl::plan_alter_index_options(scx, stmt),
+        Statement::AlterTable(stmt) => ddl::plan_alter_table(scx, stmt),
         Statement::AlterOwner(stmt) => ddl::plan_alter_owner(scx, stmt),
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
         Statement::AlterRole(stmt) => ddl::plan_alter_role(scx, stmt),
@@ -269,6 +274,7 @@ pub fn plan(
         Statement::CreateSink(stmt) => ddl::plan_create_sink(scx, stmt),
         Statement::CreateSource(stmt) => ddl::plan_create_source(scx, stmt),
         Statement::CreateSubsource(stmt) => ddl::plan_create_subsource(scx, stmt),
+        Statement::CreateWebhookSource(stmt) => ddl::plan_create_webhook_source(scx, stmt),
         Statement::CreateTable(stmt) => ddl::plan_create_table(scx, stmt),
         Statement::CreateType(stmt) => ddl::plan_create_type(scx, stmt),
         Statement::CreateView(stmt) => ddl::plan_create_view(scx, stmt, params),
@@ -750,6 +756,36 @@ impl<'a> StatementContext<'a> {
         )
     }
 
+    /// `WITH RECURSIVE` is planned like `WITH MUTUALLY RECURSIVE`, and so
+    /// shares its feature flag.
+    pub fn require_with_recursive(&self) -> Result<(), PlanError> {
+        self.require_var_or_unsafe_mode(
+            SystemVars::enable_with_mutually_recursive,
+            "`WITH RECURSIVE` syntax (controlled by `enable_with_mutually_recursive`)",
+        )
+    }
+
+    pub fn require_table_keys(&self) -> Result<(), PlanError> {
+        self.require_var_or_unsafe_mode(
+            SystemVars::enable_table_keys,
+            "`PRIMARY KEY` and `UNIQUE` constraints",
+        )
+    }
+
+    pub fn require_file_sinks(&self) -> Result<(), PlanError> {
+        self.require_var_or_unsafe_mode(
+            SystemVars::enable_file_sinks,
+            "`CREATE SINK ... INTO FILE`",
+        )
+    }
+
+    pub fn require_unvalidated_webhook_sources(&self) -> Result<(), PlanError> {
+        self.require_var_or_unsafe_mode(
+            SystemVars::enable_unvalidated_webhook_sources,
+            "`CREATE SOURCE ... FROM WEBHOOK` without `VALIDATE USING`",
+        )
+    }
+
     pub fn require_format_json(&self) -> Result<(), PlanError> {
         self.require_var_or_unsafe_mode(SystemVars::enable_format_json, "`FORMAT JSON`")
     }
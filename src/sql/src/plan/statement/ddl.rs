@@ -21,9 +21,12 @@ use regex::Regex;
 use tracing::warn;
 
 use mz_controller::clusters::{ClusterId, ReplicaId, DEFAULT_REPLICA_LOGGING_INTERVAL_MICROS};
-use mz_expr::CollectionPlan;
+use mz_expr::{CollectionPlan, EvalError};
 use mz_interchange::avro::AvroSchemaGenerator;
+use mz_interchange::protobuf::ProtobufSchemaGenerator;
+use mz_mysql_util::MySqlColumnDesc;
 use mz_ore::cast::{self, CastFrom, TryCastFrom};
+use mz_ore::collections::CollectionExt;
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
@@ -31,7 +34,9 @@ use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::adt::system::Oid;
 use mz_repr::role_id::RoleId;
 use mz_repr::strconv;
-use mz_repr::{ColumnName, ColumnType, GlobalId, RelationDesc, RelationType, ScalarType};
+use mz_repr::{
+    ColumnName, ColumnType, Datum, GlobalId, RelationDesc, RelationType, Row, ScalarType,
+};
 use mz_sql_parser::ast::display::comma_separated;
 use mz_sql_parser::ast::{
     AlterOwnerStatement, AlterRoleStatement, AlterSinkAction, AlterSinkStatement,
@@ -45,11 +50,13 @@ use mz_sql_parser::ast::{
 use mz_storage_client::types::connections::aws::{AwsAssumeRole, AwsConfig, AwsCredentials};
 use mz_storage_client::types::connections::{
     AwsPrivatelink, AwsPrivatelinkConnection, Connection, CsrConnectionHttpAuth, KafkaConnection,
-    KafkaSecurity, KafkaTlsConfig, SaslConfig, SshTunnel, StringOrSecret, TlsIdentity, Tunnel,
+    KafkaSecurity, KafkaTlsConfig, MySqlSslMode, SaslConfig, SshTunnel, StringOrSecret,
+    TlsIdentity, Tunnel,
 };
 use mz_storage_client::types::sinks::{
-    KafkaConsistencyConfig, KafkaSinkConnectionBuilder, KafkaSinkConnectionRetention,
-    KafkaSinkFormat, SinkEnvelope, StorageSinkConnectionBuilder,
+    FileSinkConnectionBuilder, FileSinkLocation, KafkaConsistencyConfig,
+    KafkaSinkConnectionBuilder, KafkaSinkConnectionRetention, KafkaSinkFormat,
+    PostgresSinkConnectionBuilder, SinkEnvelope, StorageSinkConnectionBuilder,
 };
 use mz_storage_client::types::sources::encoding::{
     included_column_desc, AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, DataEncodingInner,
@@ -57,7 +64,8 @@ use mz_storage_client::types::sources::encoding::{
 };
 use mz_storage_client::types::sources::{
     GenericSourceConnection, IncludedColumnPos, KafkaSourceConnection, KeyEnvelope, LoadGenerator,
-    LoadGeneratorSourceConnection, PostgresSourceConnection, PostgresSourcePublicationDetails,
+    LoadGeneratorSourceConnection, MySqlSourceConnection, MySqlSourceDetails,
+    PostgresSourceConnection, PostgresSourcePublicationDetails, ProtoMySqlSourceDetails,
     ProtoPostgresSourcePublicationDetails, SourceConnection, SourceDesc, SourceEnvelope,
     TestScriptSourceConnection, Timeline, UnplannedSourceEnvelope, UpsertStyle,
 };
@@ -65,33 +73,37 @@ use mz_storage_client::types::sources::{
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     AlterConnectionStatement, AlterIndexAction, AlterIndexStatement, AlterObjectRenameStatement,
-    AlterSecretStatement, AvroSchema, AvroSchemaOption, AvroSchemaOptionName, AwsConnectionOption,
-    AwsConnectionOptionName, AwsPrivatelinkConnectionOption, AwsPrivatelinkConnectionOptionName,
-    ClusterOption, ClusterOptionName, ColumnOption, CreateClusterReplicaStatement,
-    CreateClusterStatement, CreateConnection, CreateConnectionStatement, CreateDatabaseStatement,
-    CreateIndexStatement, CreateMaterializedViewStatement, CreateRoleStatement,
-    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
-    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceFormat,
-    CreateSourceOption, CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    AlterSecretStatement, AlterTableAction, AlterTableStatement, AvroSchema, AvroSchemaOption,
+    AvroSchemaOptionName, AwsConnectionOption, AwsConnectionOptionName,
+    AwsPrivatelinkConnectionOption, AwsPrivatelinkConnectionOptionName, ClusterOption,
+    ClusterOptionName, ColumnOption, CreateClusterReplicaStatement, CreateClusterStatement,
+    CreateConnection, CreateConnectionStatement, CreateDatabaseStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreateRoleStatement, CreateSchemaStatement,
+    CreateSecretStatement, CreateSinkConnection, CreateSinkOption, CreateSinkOptionName,
+    CreateSinkStatement, CreateSourceConnection, CreateSourceFormat, CreateSourceOption,
+    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
     CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableStatement, CreateTypeAs,
-    CreateTypeStatement, CreateViewStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
-    CsrConnectionAvro, CsrConnectionOption, CsrConnectionOptionName, CsrConnectionProtobuf,
-    CsrSeedProtobuf, CsvColumns, DbzMode, DropObjectsStatement, Envelope, Expr, Format, Ident,
-    IfExistsBehavior, IndexOption, IndexOptionName, KafkaBroker, KafkaBrokerAwsPrivatelinkOption,
-    KafkaBrokerAwsPrivatelinkOptionName, KafkaBrokerTunnel, KafkaConfigOptionName,
-    KafkaConnectionOption, KafkaConnectionOptionName, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, ObjectType, PgConfigOption, PgConfigOptionName,
-    PostgresConnectionOption, PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica,
-    ReferencedSubsources, ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute,
-    SourceIncludeMetadata, SourceIncludeMetadataType, SshConnectionOptionName, Statement,
-    TableConstraint, UnresolvedDatabaseName, ViewDefinition,
+    CreateTypeStatement, CreateViewStatement, CreateWebhookSourceStatement, CsrConfigOption,
+    CsrConfigOptionName, CsrConnection, CsrConnectionAvro, CsrConnectionOption,
+    CsrConnectionOptionName, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DbzMode,
+    DropObjectsStatement, Envelope, Expr, FileSinkConfigOption, FileSinkConfigOptionName, Format,
+    Ident, IfExistsBehavior, IndexOption, IndexOptionName, KafkaBroker,
+    KafkaBrokerAwsPrivatelinkOption, KafkaBrokerAwsPrivatelinkOptionName, KafkaBrokerTunnel,
+    KafkaConfigOptionName, KafkaConnectionOption, KafkaConnectionOptionName, KeyConstraint,
+    LoadGeneratorOption, LoadGeneratorOptionName, MySqlConfigOption, MySqlConfigOptionName,
+    MySqlConnectionOption, MySqlConnectionOptionName, ObjectType, PgConfigOption,
+    PgConfigOptionName, PgSinkConfigOption, PgSinkConfigOptionName, PostgresConnectionOption,
+    PostgresConnectionOptionName, ProtobufSchema, QualifiedReplica, ReferencedSubsources,
+    ReplicaDefinition, ReplicaOption, ReplicaOptionName, RoleAttribute, SourceIncludeMetadata,
+    SourceIncludeMetadataType, SshConnectionOptionName, Statement, TableConstraint,
+    UnresolvedDatabaseName, ViewDefinition, WebhookValidationOption, WebhookValidationOptionName,
 };
 use crate::catalog::{
     CatalogCluster, CatalogDatabase, CatalogItem, CatalogItemType, CatalogType, CatalogTypeDetails,
 };
 use crate::kafka_util::{self, KafkaConfigOptionExtracted, KafkaStartOffsetType};
 use crate::names::{
-    Aug, DatabaseId, FullSchemaName, ObjectId, PartialItemName, QualifiedItemName,
+    self, Aug, DatabaseId, FullSchemaName, ObjectId, PartialItemName, QualifiedItemName,
     RawDatabaseSpecifier, ResolvedClusterName, ResolvedDataType, ResolvedDatabaseSpecifier,
     ResolvedItemName, ResolvedObjectName, ResolvedRoleName, SchemaSpecifier,
 };
@@ -107,15 +119,17 @@ use crate::plan::{
     plan_utils, query, transform_ast, AlterIndexResetOptionsPlan, AlterIndexSetOptionsPlan,
     AlterItemRenamePlan, AlterNoopPlan, AlterOptionParameter, AlterOwnerPlan, AlterRolePlan,
     AlterSecretPlan, AlterSinkPlan, AlterSourcePlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, CreateClusterPlan,
-    CreateClusterReplicaPlan, CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan,
-    CreateMaterializedViewPlan, CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan,
-    CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc,
-    DropObjectsPlan, FullItemName, GrantPrivilegePlan, GrantRolePlan, HirScalarExpr, Index,
-    Ingestion, MaterializedView, Params, Plan, QueryContext, ReplicaConfig, RevokePrivilegePlan,
-    RevokeRolePlan, RotateKeysPlan, Secret, Sink, Source, SourceSinkClusterConfig, Table, Type,
-    View,
+    AlterSystemSetPlan, AlterTableAddColumnPlan, CheckConstraint, ComputeReplicaConfig,
+    ComputeReplicaIntrospectionConfig, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateConnectionPlan, CreateDatabasePlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreateRolePlan, CreateSchemaPlan, CreateSecretPlan, CreateSinkPlan, CreateSourcePlan,
+    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan, FullItemName,
+    GrantPrivilegePlan, GrantRolePlan, HirScalarExpr, Index, Ingestion, MaterializedView, Params,
+    Plan, QueryContext, ReplicaConfig, RevokePrivilegePlan, RevokeRolePlan, RotateKeysPlan, Secret,
+    Sink, Source, SourceSinkClusterConfig, Table, Type, UniqueConstraint, View, WebhookBodyFormat,
+    WebhookDesc, WebhookHmacAlgorithm, WebhookSignatureEncoding, WebhookValidation,
 };
+use crate::session::password::ScramSecret;
 use crate::session::user::SYSTEM_USER;
 
 pub fn describe_create_database(
@@ -190,12 +204,82 @@ pub fn plan_create_table(
 ) -> Result<Plan, PlanError> {
     let CreateTableStatement {
         name,
-        columns,
-        constraints,
+        columns: _,
+        constraints: _,
         if_not_exists,
         temporary,
     } = &stmt;
 
+    let (desc, defaults, check_constraints, unique_constraints, missing_values) =
+        plan_table_desc(scx, &stmt)?;
+    if !unique_constraints.is_empty() {
+        // Tables declared with unique constraints carry them as keys of their
+        // relation type, which the optimizer relies on, so they may only be
+        // declared where they will be enforced.
+        scx.require_table_keys()?;
+    }
+
+    let temporary = *temporary;
+    let name = if temporary {
+        scx.allocate_temporary_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?
+    } else {
+        scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?
+    };
+
+    // Check for an object in the catalog with this same name
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let (false, Ok(item)) = (if_not_exists, scx.catalog.resolve_item(&partial_name)) {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt.clone()))?;
+    let table = Table {
+        create_sql,
+        desc,
+        defaults,
+        check_constraints,
+        unique_constraints,
+        missing_values,
+        temporary,
+    };
+    Ok(Plan::CreateTable(CreateTablePlan {
+        name,
+        table,
+        if_not_exists: *if_not_exists,
+    }))
+}
+
+/// Plans the columns and constraints of a `CREATE TABLE` statement, returning
+/// the table's schema, the default expression for each of its columns, the
+/// constraints to enforce when writing to it, and the value of each column in
+/// rows written before it was added to the table.
+///
+/// A column's missing value is its default if that is constant, and `NULL`
+/// otherwise.
+fn plan_table_desc(
+    scx: &StatementContext,
+    stmt: &CreateTableStatement<Aug>,
+) -> Result<
+    (
+        RelationDesc,
+        Vec<Expr<Aug>>,
+        Vec<CheckConstraint>,
+        Vec<UniqueConstraint>,
+        Row,
+    ),
+    PlanError,
+> {
+    let CreateTableStatement {
+        name,
+        columns,
+        constraints,
+        ..
+    } = stmt;
+
     let names: Vec<_> = columns
         .iter()
         .map(|c| normalize::column_name(c.name.clone()))
@@ -205,17 +289,53 @@ pub fn plan_create_table(
         sql_bail!("column {} specified more than once", dup.as_str().quoted());
     }
 
+    // Constraints that are not explicitly named are named after the table,
+    // as in PostgreSQL.
+    let table_name = normalize::unresolved_item_name(name.clone())?.item;
+    let mut constraint_names = BTreeSet::new();
+    let mut name_constraint =
+        |name: &Option<Ident>, default: String| -> Result<String, PlanError> {
+            match name {
+                Some(name) => {
+                    let name = normalize::ident(name.clone());
+                    if !constraint_names.insert(name.clone()) {
+                        sql_bail!(
+                            "constraint {} for relation {} already exists",
+                            name.quoted(),
+                            table_name.quoted()
+                        );
+                    }
+                    Ok(name)
+                }
+                None => {
+                    let mut name = default.clone();
+                    for i in 1.. {
+                        if constraint_names.insert(name.clone()) {
+                            break;
+                        }
+                        name = format!("{default}{i}");
+                    }
+                    Ok(name)
+                }
+            }
+        };
+
     // Build initial relation type that handles declared data types
     // and NOT NULL constraints.
     let mut column_types = Vec::with_capacity(columns.len());
     let mut defaults = Vec::with_capacity(columns.len());
+    let mut missing_values = Row::default();
     let mut keys = Vec::new();
+    let mut checks = Vec::new();
+    let mut unique_constraints = Vec::new();
 
     for (i, c) in columns.into_iter().enumerate() {
         let aug_data_type = &c.data_type;
         let ty = query::scalar_type_from_sql(scx, aug_data_type)?;
         let mut nullable = true;
+        let mut unique = false;
         let mut default = Expr::null();
+        let mut missing_value = None;
         for option in &c.options {
             match &option.option {
                 ColumnOption::NotNull => nullable = false,
@@ -224,29 +344,55 @@ pub fn plan_create_table(
                     // type.
                     let mut expr = expr.clone();
                     transform_ast::transform(scx, &mut expr)?;
-                    let _ = query::plan_default_expr(scx, &expr, &ty)?;
+                    missing_value = plan_constant_default(scx, &expr, &ty)?.and_then(Result::ok);
                     default = expr.clone();
                 }
                 ColumnOption::Unique { is_primary } => {
-                    keys.push(vec![i]);
+                    let default_name = if *is_primary {
+                        format!("{table_name}_pkey")
+                    } else {
+                        format!("{table_name}_{}_key", names[i].as_str())
+                    };
+                    unique_constraints.push(UniqueConstraint {
+                        name: name_constraint(&option.name, default_name)?,
+                        columns: vec![i],
+                        nulls_not_distinct: false,
+                    });
+                    unique = true;
                     if *is_primary {
                         nullable = false;
                     }
                 }
+                ColumnOption::Check(expr) => {
+                    let name = name_constraint(
+                        &option.name,
+                        format!("{table_name}_{}_check", names[i].as_str()),
+                    )?;
+                    checks.push((name, expr));
+                }
                 other => {
                     bail_unsupported!(format!("CREATE TABLE with column constraint: {}", other))
                 }
             }
         }
+        // A column whose values are unique is only a key if it cannot contain
+        // `NULL`s, which are never considered duplicates of one another.
+        if unique && !nullable {
+            keys.push(vec![i]);
+        }
         column_types.push(ty.nullable(nullable));
         defaults.push(default);
+        match missing_value {
+            Some(value) => missing_values.packer().extend_by_row(&value),
+            None => missing_values.packer().push(Datum::Null),
+        }
     }
 
     let mut seen_primary = false;
     'c: for constraint in constraints {
         match constraint {
             TableConstraint::Unique {
-                name: _,
+                name: constraint_name,
                 columns,
                 is_primary,
                 nulls_not_distinct,
@@ -264,24 +410,38 @@ pub fn plan_create_table(
                     let column = normalize::column_name(column.clone());
                     match names.iter().position(|name| *name == column) {
                         None => sql_bail!("unknown column in constraint: {}", column),
-                        Some(i) => {
-                            let nullable = &mut column_types[i].nullable;
-                            if *is_primary {
-                                if *nulls_not_distinct {
-                                    sql_bail!(
-                                        "[internal error] PRIMARY KEY does not support NULLS NOT DISTINCT"
-                                    );
-                                }
+                        Some(i) => key.push(i),
+                    }
+                }
 
-                                *nullable = false;
-                            } else if !(*nulls_not_distinct || !*nullable) {
-                                // Non-primary key unique constraints are only keys if all of their
-                                // columns are `NOT NULL` or the constraint is `NULLS NOT DISTINCT`.
-                                break 'c;
-                            }
+                let default_name = if *is_primary {
+                    format!("{table_name}_pkey")
+                } else {
+                    format!(
+                        "{table_name}_{}_key",
+                        key.iter().map(|i| names[*i].as_str()).join("_")
+                    )
+                };
+                unique_constraints.push(UniqueConstraint {
+                    name: name_constraint(constraint_name, default_name)?,
+                    columns: key.clone(),
+                    nulls_not_distinct: *nulls_not_distinct,
+                });
 
-                            key.push(i);
+                for i in &key {
+                    let nullable = &mut column_types[*i].nullable;
+                    if *is_primary {
+                        if *nulls_not_distinct {
+                            sql_bail!(
+                                "[internal error] PRIMARY KEY does not support NULLS NOT DISTINCT"
+                            );
                         }
+
+                        *nullable = false;
+                    } else if !(*nulls_not_distinct || !*nullable) {
+                        // Non-primary key unique constraints are only keys if all of their
+                        // columns are `NOT NULL` or the constraint is `NULLS NOT DISTINCT`.
+                        continue 'c;
                     }
                 }
 
@@ -296,53 +456,48 @@ pub fn plan_create_table(
                 // them in unsafe mode for sqllogictest's sake.
                 scx.require_unsafe_mode("CREATE TABLE with a foreign key")?
             }
-            TableConstraint::Check { .. } => {
-                // Check constraints are not presently enforced. We allow them
-                // in unsafe mode for sqllogictest's sake.
-                scx.require_unsafe_mode("CREATE TABLE with a check constraint")?
+            TableConstraint::Check {
+                name: constraint_name,
+                expr,
+            } => {
+                let name = name_constraint(constraint_name, format!("{table_name}_check"))?;
+                checks.push((name, &**expr));
             }
         }
     }
 
-    if !keys.is_empty() {
-        // Unique constraints are not presently enforced. We allow them in
-        // unsafe mode for sqllogictest's sake.
-        scx.require_unsafe_mode("CREATE TABLE with a primary key or unique constraint")?;
-    }
-
     let typ = RelationType::new(column_types).with_keys(keys);
-
-    let temporary = *temporary;
-    let name = if temporary {
-        scx.allocate_temporary_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?
-    } else {
-        scx.allocate_qualified_name(normalize::unresolved_item_name(name.to_owned())?)?
-    };
-
-    // Check for an object in the catalog with this same name
-    let full_name = scx.catalog.resolve_full_name(&name);
-    let partial_name = PartialItemName::from(full_name.clone());
-    if let (false, Ok(item)) = (if_not_exists, scx.catalog.resolve_item(&partial_name)) {
-        return Err(PlanError::ItemAlreadyExists {
-            name: full_name.to_string(),
-            item_type: item.item_type(),
-        });
-    }
-
     let desc = RelationDesc::new(typ, names);
 
-    let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt.clone()))?;
-    let table = Table {
-        create_sql,
+    let check_constraints = checks
+        .into_iter()
+        .map(|(name, expr)| {
+            Ok(CheckConstraint {
+                name,
+                expr: query::plan_check_expr(scx, &desc, expr)?,
+            })
+        })
+        .collect::<Result<_, PlanError>>()?;
+
+    Ok((
         desc,
         defaults,
-        temporary,
-    };
-    Ok(Plan::CreateTable(CreateTablePlan {
-        name,
-        table,
-        if_not_exists: *if_not_exists,
-    }))
+        check_constraints,
+        unique_constraints,
+        missing_values,
+    ))
+}
+
+/// Plans the default expression `default` of a column of type `ty` and
+/// evaluates it, returning `None` if its value is not constant.
+fn plan_constant_default(
+    scx: &StatementContext,
+    default: &Expr<Aug>,
+    ty: &ScalarType,
+) -> Result<Option<Result<Row, EvalError>>, PlanError> {
+    let mut expr = query::plan_default_expr(scx, default, ty)?.lower_uncorrelated()?;
+    expr.reduce(&[]);
+    Ok(expr.as_literal_owned())
 }
 
 pub fn describe_create_source(
@@ -365,16 +520,68 @@ generate_extracted_config!(
     (Size, String),
     (Timeline, String),
     (TimestampInterval, Interval),
-    (Disk, bool)
+    (Disk, bool),
+    (RetainKeysFor, Interval)
 );
 
 generate_extracted_config!(
     PgConfigOption,
     (Details, String),
     (Publication, String),
+    (TextColumns, Vec::<UnresolvedItemName>, Default(vec![])),
+    (EnumTypes, Vec::<ResolvedDataType>, Default(vec![]))
+);
+
+generate_extracted_config!(
+    MySqlConfigOption,
+    (Details, String),
     (TextColumns, Vec::<UnresolvedItemName>, Default(vec![]))
 );
 
+/// The name of the database that MySQL sources' upstream references are
+/// qualified with. MySQL does not distinguish databases from schemas, so
+/// every upstream table lives in this single, synthetic database.
+pub(crate) const MYSQL_DATABASE_NAME: &str = "mysql";
+
+/// Returns the type of the column that values of the upstream MySQL `column`
+/// are ingested into, or `None` if the column's type is not supported.
+pub(crate) fn mysql_column_type(column: &MySqlColumnDesc) -> Option<mz_pgrepr::Type> {
+    use mz_pgrepr::Type;
+
+    let unsigned = column.is_unsigned();
+    let ty = match column.data_type.as_str() {
+        "tinyint" | "year" => Type::Int2,
+        "smallint" if unsigned => Type::Int4,
+        "smallint" => Type::Int2,
+        "mediumint" => Type::Int4,
+        "int" if unsigned => Type::Int8,
+        "int" => Type::Int4,
+        "bigint" if unsigned => Type::Numeric { constraints: None },
+        "bigint" => Type::Int8,
+        "decimal" => match (column.precision, column.scale) {
+            // MySQL permits more digits of precision than we do.
+            (Some(precision), Some(scale)) if precision <= 39 => {
+                // Encode the constraints as a Postgres `numeric` typmod.
+                let typmod = i32::try_from((precision << 16) | scale).ok()? + 4;
+                Type::from_oid_and_typmod(mz_pgrepr::oid::TYPE_NUMERIC_OID, typmod).ok()?
+            }
+            _ => Type::Numeric { constraints: None },
+        },
+        "float" => Type::Float4,
+        "double" => Type::Float8,
+        "char" | "varchar" | "tinytext" | "text" | "mediumtext" | "longtext" | "enum" | "set" => {
+            Type::Text
+        }
+        "date" => Type::Date,
+        "time" => Type::Time { precision: None },
+        "datetime" => Type::Timestamp { precision: None },
+        "timestamp" => Type::TimestampTz { precision: None },
+        "json" => Type::Jsonb,
+        _ => return None,
+    };
+    Some(ty)
+}
+
 pub fn plan_create_source(
     scx: &StatementContext,
     stmt: CreateSourceStatement<Aug>,
@@ -396,7 +603,10 @@ pub fn plan_create_source(
 
     let envelope = envelope.clone().unwrap_or(Envelope::None);
 
-    const SAFE_WITH_OPTIONS: &[CreateSourceOptionName] = &[CreateSourceOptionName::Size];
+    const SAFE_WITH_OPTIONS: &[CreateSourceOptionName] = &[
+        CreateSourceOptionName::Size,
+        CreateSourceOptionName::RetainKeysFor,
+    ];
 
     if with_options
         .iter()
@@ -561,6 +771,7 @@ pub fn plan_create_source(
                 details,
                 publication,
                 text_columns,
+                enum_types,
                 seen: _,
             } = options.clone().try_into()?;
 
@@ -613,6 +824,23 @@ pub fn plan_create_source(
                     .insert(col);
             }
 
+            // Map the upstream enum types to the Materialize enum types of
+            // the same name, as recorded during purification.
+            let mut enum_cols: BTreeMap<u32, ScalarType> = BTreeMap::new();
+            for data_type in enum_types {
+                let name = match &data_type {
+                    ResolvedDataType::Named { full_name, .. } => full_name.item.clone(),
+                    _ => sql_bail!("{} is not an enum type", data_type.to_ast_string()),
+                };
+                let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
+                if !matches!(scalar_type, ScalarType::Enum { .. }) {
+                    sql_bail!("{} is not an enum type", data_type.to_ast_string());
+                }
+                for desc in details.enum_types.iter().filter(|desc| desc.name == name) {
+                    enum_cols.insert(desc.oid, scalar_type.clone());
+                }
+            }
+
             // Register the available subsources
             let mut available_subsources = BTreeMap::new();
 
@@ -653,20 +881,30 @@ pub fn plan_create_source(
                 // Then, for each column we will generate a MirRelationExpr that extracts the nth
                 // column and casts it to the appropriate target type
                 let mut column_casts = vec![];
+                let resolve_scalar_type = |ty: mz_pgrepr::Type| -> Result<ScalarType, PlanError> {
+                    let data_type = scx.resolve_type(ty)?;
+                    query::scalar_type_from_sql(scx, &data_type)
+                };
                 for (i, column) in table.columns.iter().enumerate() {
-                    let ty = match text_cols.get(&Oid(table.oid)) {
+                    let scalar_type = match text_cols.get(&Oid(table.oid)) {
                         // Treat the column as text if it was referenced in
                         // `TEXT COLUMNS`. This is the only place we need to
                         // perform this logic; even if the type is unsupported,
                         // we'll be able to ingest its values as text in
                         // storage.
-                        Some(names) if names.contains(&column.name) => mz_pgrepr::Type::Text,
+                        Some(names) if names.contains(&column.name) => {
+                            resolve_scalar_type(mz_pgrepr::Type::Text)?
+                        }
                         _ => {
                             match mz_pgrepr::Type::from_oid_and_typmod(
                                 column.type_oid,
                                 column.type_mod,
                             ) {
-                                Ok(t) => t,
+                                Ok(t) => resolve_scalar_type(t)?,
+                                // Enum values arrive as their text labels.
+                                Err(_) if enum_cols.contains_key(&column.type_oid) => {
+                                    enum_cols[&column.type_oid].clone()
+                                }
                                 // If this reference survived purification, we
                                 // do not expect it to be from a table that the
                                 // user will consume., i.e. expect this table to
@@ -698,9 +936,6 @@ pub fn plan_create_source(
                         }
                     };
 
-                    let data_type = scx.resolve_type(ty)?;
-                    let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
-
                     let col_expr = HirScalarExpr::Column(ColumnRef {
                         level: 0,
                         column: i,
@@ -775,6 +1010,181 @@ pub fn plan_create_source(
             ));
             (connection, encoding, Some(available_subsources))
         }
+        CreateSourceConnection::MySql {
+            connection,
+            options,
+        } => {
+            let connection_item = scx.get_item_by_resolved_name(connection)?;
+            let connection = match connection_item.connection()? {
+                Connection::MySql(connection) => connection.clone(),
+                _ => sql_bail!("{} is not a mysql connection", connection_item.name()),
+            };
+            let MySqlConfigOptionExtracted {
+                details,
+                text_columns,
+                seen: _,
+            } = options.clone().try_into()?;
+
+            let details = details
+                .as_ref()
+                .ok_or_else(|| sql_err!("internal error: MySQL source missing details"))?;
+            let details = hex::decode(details).map_err(|e| sql_err!("{}", e))?;
+            let details =
+                ProtoMySqlSourceDetails::decode(&*details).map_err(|e| sql_err!("{}", e))?;
+            let details = MySqlSourceDetails::from_proto(details).map_err(|e| sql_err!("{}", e))?;
+
+            // TEXT COLUMNS are fully qualified during purification.
+            let mut text_cols: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+            for name in text_columns {
+                match &name.0[..] {
+                    [_database, schema, table, column] => {
+                        text_cols
+                            .entry((schema.as_str().into(), table.as_str().into()))
+                            .or_default()
+                            .insert(column.as_str().into());
+                    }
+                    _ => sql_bail!("[internal error] TEXT COLUMNS must be fully qualified"),
+                }
+            }
+
+            // Register the available subsources
+            let mut available_subsources = BTreeMap::new();
+
+            // As with Postgres sources, the MySQL source reader receives text encoded columns
+            // and evaluates these cast expressions to convert them into the target types.
+            let mut table_casts = BTreeMap::new();
+
+            for (i, table) in details.tables.iter().enumerate() {
+                let mut cast_scx = scx.clone();
+                cast_scx.param_types = Default::default();
+                let cast_qcx = QueryContext::root(&cast_scx, QueryLifetime::Static);
+                let column_types = table
+                    .columns
+                    .iter()
+                    .map(|column| ColumnType {
+                        nullable: column.nullable,
+                        scalar_type: ScalarType::String,
+                    })
+                    .collect();
+
+                let cast_ecx = ExprContext {
+                    qcx: &cast_qcx,
+                    name: "plan_mysql_source_cast",
+                    scope: &Scope::empty(),
+                    relation_type: &RelationType {
+                        column_types,
+                        keys: vec![],
+                    },
+                    allow_aggregates: false,
+                    allow_subqueries: false,
+                    allow_windows: false,
+                };
+
+                let table_text_cols =
+                    text_cols.get(&(table.schema_name.clone(), table.name.clone()));
+
+                let mut column_casts = vec![];
+                for (i, column) in table.columns.iter().enumerate() {
+                    let ty = match table_text_cols {
+                        Some(names) if names.contains(&column.name) => mz_pgrepr::Type::Text,
+                        _ => match mysql_column_type(column) {
+                            Some(ty) => ty,
+                            // Purification rejects unsupported types in the tables the user
+                            // selected, so this table will be filtered out of table casts.
+                            None => {
+                                column_casts.push(
+                                    HirScalarExpr::CallVariadic {
+                                        func: mz_expr::VariadicFunc::ErrorIfNull,
+                                        exprs: vec![
+                                            HirScalarExpr::literal_null(ScalarType::String),
+                                            HirScalarExpr::literal(
+                                                mz_repr::Datum::from(
+                                                    format!(
+                                                        "Unsupported type {}",
+                                                        column.column_type
+                                                    )
+                                                    .as_str(),
+                                                ),
+                                                ScalarType::String,
+                                            ),
+                                        ],
+                                    }
+                                    .lower_uncorrelated()
+                                    .expect("no correlation"),
+                                );
+                                continue;
+                            }
+                        },
+                    };
+
+                    let data_type = scx.resolve_type(ty)?;
+                    let scalar_type = query::scalar_type_from_sql(scx, &data_type)?;
+
+                    let col_expr = HirScalarExpr::Column(ColumnRef {
+                        level: 0,
+                        column: i,
+                    });
+
+                    let cast_expr =
+                        plan_cast(&cast_ecx, CastContext::Explicit, col_expr, &scalar_type)?;
+
+                    let cast = if column.nullable {
+                        cast_expr
+                    } else {
+                        // The binlog does not announce constraint changes, so we must enforce
+                        // the nullability constraint ourselves.
+                        HirScalarExpr::CallVariadic {
+                            func: mz_expr::VariadicFunc::ErrorIfNull,
+                            exprs: vec![
+                                cast_expr,
+                                HirScalarExpr::literal(
+                                    mz_repr::Datum::from(
+                                        format!(
+                                            "MySQL column {}.{}.{} contained NULL data, despite having NOT NULL constraint",
+                                            table.schema_name,
+                                            table.name,
+                                            column.name)
+                                            .as_str(),
+                                    ),
+                                    ScalarType::String,
+                                ),
+                            ],
+                        }
+                    };
+
+                    let mir_cast = cast.lower_uncorrelated().expect(
+                        "lower_uncorrelated should not fail given that there is no correlation \
+                            in the input col_expr",
+                    );
+
+                    column_casts.push(mir_cast);
+                }
+                let r = table_casts.insert(i + 1, column_casts);
+                assert!(r.is_none(), "cannot have table defined multiple times");
+
+                let name = FullItemName {
+                    database: RawDatabaseSpecifier::Name(MYSQL_DATABASE_NAME.into()),
+                    schema: table.schema_name.clone(),
+                    item: table.name.clone(),
+                };
+
+                // The zero-th output is the main output
+                available_subsources.insert(name, i + 1);
+            }
+
+            let connection = GenericSourceConnection::from(MySqlSourceConnection {
+                connection,
+                connection_id: connection_item.id(),
+                table_casts,
+                details,
+            });
+            // The MySQL source only outputs data to its subsources. The catalog object
+            // representing the source itself is just an empty relation with no columns
+            let encoding = SourceDataEncoding::Single(DataEncoding::new(
+                DataEncodingInner::RowCodec(RelationDesc::empty()),
+            ));
+            (connection, encoding, Some(available_subsources))
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let (load_generator, available_subsources) =
                 load_generator_ast_to_generator(generator, options)?;
@@ -866,13 +1276,19 @@ pub fn plan_create_source(
         subsource_exports.insert(target_id, *idx);
     }
 
-    if let GenericSourceConnection::Postgres(conn) = &mut external_connection {
-        // Now that we know which subsources sources we want, we can remove all
-        // unused table casts from this connection; this represents the
-        // authoritative statement about which publication tables should be
-        // used within storage.
-        let used_pos: BTreeSet<_> = subsource_exports.values().collect();
-        conn.table_casts.retain(|pos, _| used_pos.contains(pos));
+    // Now that we know which subsources sources we want, we can remove all
+    // unused table casts from this connection; this represents the
+    // authoritative statement about which upstream tables should be used
+    // within storage.
+    let used_pos: BTreeSet<_> = subsource_exports.values().collect();
+    match &mut external_connection {
+        GenericSourceConnection::Postgres(conn) => {
+            conn.table_casts.retain(|pos, _| used_pos.contains(pos));
+        }
+        GenericSourceConnection::MySql(conn) => {
+            conn.table_casts.retain(|pos, _| used_pos.contains(pos));
+        }
+        _ => {}
     }
 
     let CreateSourceOptionExtracted {
@@ -881,9 +1297,24 @@ pub fn plan_create_source(
         timestamp_interval,
         ignore_keys,
         disk,
+        retain_keys_for,
         seen: _,
     } = CreateSourceOptionExtracted::try_from(with_options.clone())?;
 
+    let retain_keys_for = match retain_keys_for {
+        Some(retain_keys_for) => {
+            let retain_keys_for = retain_keys_for.duration()?;
+            if retain_keys_for.is_zero() {
+                sql_bail!("RETAIN KEYS FOR must be positive");
+            }
+            if envelope != mz_sql_parser::ast::Envelope::Upsert {
+                bail_unsupported!("RETAIN KEYS FOR used with non-UPSERT ENVELOPE");
+            }
+            Some(retain_keys_for)
+        }
+        None => None,
+    };
+
     let (key_desc, value_desc) = encoding.desc()?;
 
     let mut key_envelope = get_key_envelope(include_metadata, &envelope, &encoding)?;
@@ -909,6 +1340,7 @@ pub fn plan_create_source(
                 DbzMode::Plain => UnplannedSourceEnvelope::Upsert {
                     style: UpsertStyle::Debezium { after_idx },
                     disk: disk.unwrap_or(disk_default),
+                    retain_keys_for: None,
                 },
             }
         }
@@ -924,9 +1356,15 @@ pub fn plan_create_source(
             if key_envelope == KeyEnvelope::None {
                 key_envelope = get_unnamed_key_envelope(key_encoding)?;
             }
+            // The retention of keys is tracked in memory, so sources that
+            // retain keys for a limited time do not keep their state on disk.
+            if retain_keys_for.is_some() && disk == Some(true) {
+                bail_unsupported!("RETAIN KEYS FOR with DISK");
+            }
             UnplannedSourceEnvelope::Upsert {
                 style: UpsertStyle::Default(key_envelope),
-                disk: disk.unwrap_or(disk_default),
+                disk: disk.unwrap_or(disk_default && retain_keys_for.is_none()),
+                retain_keys_for,
             }
         }
         mz_sql_parser::ast::Envelope::CdcV2 => {
@@ -1199,43 +1637,160 @@ pub fn plan_create_subsource(
                                 break 'c;
                             }
 
-                            key.push(i);
-                        }
-                    }
-                }
+                            key.push(i);
+                        }
+                    }
+                }
+
+                if *is_primary {
+                    keys.insert(0, key);
+                } else {
+                    keys.push(key);
+                }
+            }
+            TableConstraint::ForeignKey { .. } => {
+                bail_unsupported!("CREATE SUBSOURCE with a foreign key")
+            }
+            TableConstraint::Check { .. } => {
+                bail_unsupported!("CREATE SUBSOURCE with a check constraint")
+            }
+        }
+    }
+
+    let if_not_exists = *if_not_exists;
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.clone())?)?;
+    let create_sql = normalize::create_statement(scx, Statement::CreateSubsource(stmt))?;
+
+    let typ = RelationType::new(column_types).with_keys(keys);
+    let desc = RelationDesc::new(typ, names);
+
+    let source = Source {
+        create_sql,
+        data_source: if progress {
+            DataSourceDesc::Progress
+        } else if references {
+            DataSourceDesc::Source
+        } else {
+            unreachable!("state prohibited above")
+        },
+        desc,
+    };
+
+    Ok(Plan::CreateSource(CreateSourcePlan {
+        name,
+        source,
+        if_not_exists,
+        timeline: Timeline::EpochMilliseconds,
+        cluster_config: SourceSinkClusterConfig::Undefined,
+    }))
+}
+
+pub fn describe_create_webhook_source(
+    _: &StatementContext,
+    _: CreateWebhookSourceStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+generate_extracted_config!(
+    WebhookValidationOption,
+    (Algorithm, String, Default("sha256".into())),
+    (Encoding, String, Default("hex".into())),
+    (Header, String),
+    (Secret, with_options::Object)
+);
+
+pub fn plan_create_webhook_source(
+    scx: &StatementContext,
+    stmt: CreateWebhookSourceStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let CreateWebhookSourceStatement {
+        name,
+        if_not_exists,
+        body_format,
+        include_headers,
+        validate_using,
+    } = &stmt;
+
+    let body_format = match body_format {
+        Format::Bytes => WebhookBodyFormat::Bytes,
+        Format::Json => WebhookBodyFormat::Json,
+        Format::Text => WebhookBodyFormat::Text,
+        f => bail_unsupported!(format!("FORMAT {} for webhook sources", f)),
+    };
+
+    let validation = match validate_using {
+        None => {
+            scx.require_unvalidated_webhook_sources()?;
+            None
+        }
+        Some(options) => {
+            let WebhookValidationOptionExtracted {
+                algorithm,
+                encoding,
+                header,
+                secret,
+                ..
+            } = options.clone().try_into()?;
 
-                if *is_primary {
-                    keys.insert(0, key);
-                } else {
-                    keys.push(key);
-                }
-            }
-            TableConstraint::ForeignKey { .. } => {
-                bail_unsupported!("CREATE SUBSOURCE with a foreign key")
-            }
-            TableConstraint::Check { .. } => {
-                bail_unsupported!("CREATE SUBSOURCE with a check constraint")
+            let Some(secret) = secret else {
+                sql_bail!("VALIDATE USING HMAC requires a SECRET");
+            };
+            let secret_id = GlobalId::from(secret);
+            let item = scx.catalog.get_item(&secret_id);
+            if item.item_type() != CatalogItemType::Secret {
+                sql_bail!(
+                    "{} is not a secret",
+                    scx.catalog
+                        .resolve_full_name(item.name())
+                        .to_string()
+                        .quoted()
+                );
             }
+            let Some(header) = header else {
+                sql_bail!("VALIDATE USING HMAC requires a HEADER");
+            };
+            let algorithm = match algorithm.to_lowercase().as_str() {
+                "sha256" => WebhookHmacAlgorithm::Sha256,
+                "sha512" => WebhookHmacAlgorithm::Sha512,
+                _ => sql_bail!(
+                    "invalid HMAC ALGORITHM {}: must be sha256 or sha512",
+                    algorithm.quoted()
+                ),
+            };
+            let encoding = match encoding.to_lowercase().as_str() {
+                "hex" => WebhookSignatureEncoding::Hex,
+                "base64" => WebhookSignatureEncoding::Base64,
+                _ => sql_bail!(
+                    "invalid HMAC ENCODING {}: must be hex or base64",
+                    encoding.quoted()
+                ),
+            };
+
+            Some(WebhookValidation {
+                secret_id,
+                // HTTP header names are case insensitive.
+                header: header.to_lowercase(),
+                algorithm,
+                encoding,
+            })
         }
-    }
+    };
+
+    let webhook = WebhookDesc {
+        body_format,
+        include_headers: *include_headers,
+        validation,
+    };
 
     let if_not_exists = *if_not_exists;
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name.clone())?)?;
-    let create_sql = normalize::create_statement(scx, Statement::CreateSubsource(stmt))?;
-
-    let typ = RelationType::new(column_types).with_keys(keys);
-    let desc = RelationDesc::new(typ, names);
+    let create_sql = normalize::create_statement(scx, Statement::CreateWebhookSource(stmt))?;
 
     let source = Source {
         create_sql,
-        data_source: if progress {
-            DataSourceDesc::Progress
-        } else if references {
-            DataSourceDesc::Source
-        } else {
-            unreachable!("state prohibited above")
-        },
-        desc,
+        desc: webhook.relation_desc(),
+        data_source: DataSourceDesc::Webhook(webhook),
     };
 
     Ok(Plan::CreateSource(CreateSourcePlan {
@@ -1966,12 +2521,27 @@ pub fn plan_create_sink(
         ))?;
     }
 
-    let envelope = match envelope {
-        None => sql_bail!("ENVELOPE clause is required"),
-        Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Plain)) => SinkEnvelope::Debezium,
-        Some(Envelope::Upsert) => SinkEnvelope::Upsert,
-        Some(Envelope::CdcV2) => bail_unsupported!("CDCv2 sinks"),
-        Some(Envelope::None) => bail_unsupported!("\"ENVELOPE NONE\" sinks"),
+    let envelope = match (&connection, envelope) {
+        // File sinks write out the raw updates, which need no envelope.
+        (CreateSinkConnection::File { .. }, None) => None,
+        (CreateSinkConnection::File { .. }, Some(_)) => {
+            sql_bail!("FILE sinks do not support the ENVELOPE clause")
+        }
+        (CreateSinkConnection::Kafka { .. }, None) => sql_bail!("ENVELOPE clause is required"),
+        // Postgres sinks maintain a table keyed by the sink key, which is an
+        // upsert by construction.
+        (CreateSinkConnection::Postgres { .. }, None | Some(Envelope::Upsert)) => {
+            Some(SinkEnvelope::Upsert)
+        }
+        (CreateSinkConnection::Postgres { .. }, Some(_)) => {
+            sql_bail!("POSTGRES sinks only support ENVELOPE UPSERT")
+        }
+        (_, Some(Envelope::Debezium(mz_sql_parser::ast::DbzMode::Plain))) => {
+            Some(SinkEnvelope::Debezium)
+        }
+        (_, Some(Envelope::Upsert)) => Some(SinkEnvelope::Upsert),
+        (_, Some(Envelope::CdcV2)) => bail_unsupported!("CDCv2 sinks"),
+        (_, Some(Envelope::None)) => bail_unsupported!("\"ENVELOPE NONE\" sinks"),
     };
     let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
 
@@ -1989,7 +2559,7 @@ pub fn plan_create_sink(
 
     let desc = from.desc(&scx.catalog.resolve_full_name(from.name()))?;
     let key_indices = match &connection {
-        CreateSinkConnection::Kafka { key, .. } => {
+        CreateSinkConnection::Kafka { key, .. } | CreateSinkConnection::Postgres { key, .. } => {
             if let Some(key) = key.clone() {
                 let key_columns = key
                     .key_columns
@@ -2019,14 +2589,14 @@ pub fn plan_create_sink(
                     desc.typ().keys.iter().any(|key_columns| {
                         key_columns.iter().all(|column| indices.contains(column))
                     });
-                if key.not_enforced && envelope == SinkEnvelope::Upsert {
+                if key.not_enforced && envelope == Some(SinkEnvelope::Upsert) {
                     // TODO: We should report a warning notice back to the user via the pgwire
                     // protocol. See https://github.com/MaterializeInc/materialize/issues/9333.
                     warn!(
                         "Verification of upsert key disabled for sink '{}' via 'NOT ENFORCED'. This is potentially dangerous and can lead to crashing materialize when the specified key is not in fact a unique key of the sinked view.",
                         name
                     );
-                } else if !is_valid_key && envelope == SinkEnvelope::Upsert {
+                } else if !is_valid_key && envelope == Some(SinkEnvelope::Upsert) {
                     return Err(invalid_upsert_key_err(&desc, &key_columns));
                 }
                 Some(indices)
@@ -2034,6 +2604,7 @@ pub fn plan_create_sink(
                 None
             }
         }
+        CreateSinkConnection::File { .. } => None,
     };
 
     // pick the first valid natural relation key, if any
@@ -2050,7 +2621,7 @@ pub fn plan_create_sink(
         (RelationDesc::new(typ, names), key_indices)
     });
 
-    if key_desc_and_indices.is_none() && envelope == SinkEnvelope::Upsert {
+    if key_desc_and_indices.is_none() && envelope == Some(SinkEnvelope::Upsert) {
         return Err(PlanError::UpsertSinkWithoutKey);
     }
 
@@ -2062,7 +2633,23 @@ pub fn plan_create_sink(
             relation_key_indices,
             key_desc_and_indices,
             desc.into_owned(),
-            envelope,
+            envelope.expect("Kafka sinks require an envelope"),
+        )?,
+        CreateSinkConnection::File {
+            connection,
+            options,
+        } => file_sink_builder(scx, connection, options, format, desc.into_owned())?,
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
+            scx,
+            connection,
+            options,
+            format,
+            key_desc_and_indices.expect("Postgres sinks require a key"),
+            desc.into_owned(),
         )?,
     };
 
@@ -2091,6 +2678,150 @@ pub fn plan_create_sink(
     }))
 }
 
+generate_extracted_config!(FileSinkConfigOption, (Url, String));
+
+fn file_sink_builder(
+    scx: &StatementContext,
+    connection: Option<ResolvedItemName>,
+    options: Vec<FileSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnectionBuilder, PlanError> {
+    scx.require_file_sinks()?;
+
+    if format.is_some() {
+        sql_bail!(
+            "FILE sinks do not support the FORMAT clause; files are always written as Parquet"
+        );
+    }
+
+    let FileSinkConfigOptionExtracted { url, .. } = options.try_into()?;
+    let url = url.ok_or_else(|| sql_err!("FILE sinks require a URL option"))?;
+    let parsed =
+        reqwest::Url::parse(&url).map_err(|e| sql_err!("invalid FILE sink URL {}: {}", url, e))?;
+    if parsed.query().is_some() || parsed.fragment().is_some() {
+        sql_bail!(
+            "invalid FILE sink URL {}: must be of the form s3://<bucket>/<prefix> or file://<path>",
+            url
+        );
+    }
+
+    let location = match (parsed.scheme(), connection) {
+        ("s3", Some(connection)) => {
+            let item = scx.get_item_by_resolved_name(&connection)?;
+            let connection = match item.connection()? {
+                Connection::Aws(connection) => connection.clone(),
+                _ => sql_bail!("{} is not an AWS connection", item.name()),
+            };
+            let bucket = match parsed.host_str() {
+                Some(bucket) if !bucket.is_empty() => bucket.to_string(),
+                _ => sql_bail!("invalid FILE sink URL {}: missing bucket", url),
+            };
+            // Files are written under the prefix as if it were a directory.
+            let prefix = match parsed.path().trim_matches('/') {
+                "" => String::new(),
+                prefix => format!("{}/", prefix),
+            };
+            FileSinkLocation::S3 {
+                connection_id: item.id(),
+                connection,
+                bucket,
+                prefix,
+            }
+        }
+        ("s3", None) => sql_bail!("FILE sinks writing to S3 require an AWS connection"),
+        ("file", None) => {
+            // Local directories are written with the permissions of the
+            // replica that runs the sink, so they're only for testing.
+            scx.require_unsafe_mode("FILE sinks writing to a local directory")?;
+            if parsed.host_str().map_or(false, |host| !host.is_empty()) {
+                sql_bail!(
+                    "invalid FILE sink URL {}: must be of the form file://<path>",
+                    url
+                );
+            }
+            FileSinkLocation::LocalDirectory {
+                path: parsed.path().to_string(),
+            }
+        }
+        ("file", Some(_)) => {
+            sql_bail!("FILE sinks writing to a local directory do not accept a connection")
+        }
+        (scheme, _) => sql_bail!(
+            "invalid FILE sink URL {}: scheme must be s3 or file, not {}",
+            url,
+            scheme
+        ),
+    };
+
+    // Every file contains the sinked columns followed by the timestamp and
+    // diff of each update.
+    for name in [
+        mz_interchange::arrow::TIMESTAMP_COLUMN,
+        mz_interchange::arrow::DIFF_COLUMN,
+    ] {
+        if value_desc.get_by_name(&ColumnName::from(name)).is_some() {
+            sql_bail!(
+                "FILE sinks cannot export a column named {}, which is reserved for update metadata",
+                name
+            );
+        }
+    }
+
+    Ok(StorageSinkConnectionBuilder::File(
+        FileSinkConnectionBuilder {
+            location,
+            value_desc,
+        },
+    ))
+}
+
+generate_extracted_config!(PgSinkConfigOption, (Table, String));
+
+fn postgres_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<PgSinkConfigOption<Aug>>,
+    format: Option<Format<Aug>>,
+    (_key_desc, key_indices): (RelationDesc, Vec<usize>),
+    value_desc: RelationDesc,
+) -> Result<StorageSinkConnectionBuilder, PlanError> {
+    if format.is_some() {
+        sql_bail!("POSTGRES sinks do not support the FORMAT clause");
+    }
+
+    let item = scx.get_item_by_resolved_name(&connection)?;
+    let connection = match item.connection()? {
+        Connection::Postgres(connection) => connection.clone(),
+        _ => sql_bail!("{} is not a postgres connection", item.name()),
+    };
+
+    let PgSinkConfigOptionExtracted { table, .. } = options.try_into()?;
+    let table = table.ok_or_else(|| sql_err!("POSTGRES sinks require a TABLE option"))?;
+    let name = mz_sql_parser::parser::parse_item_name(&table)
+        .map_err(|e| sql_err!("invalid TABLE {}: {}", table, e))?;
+    let (schema, table) = match &name.0[..] {
+        [table] => ("public".to_string(), table.as_str().to_string()),
+        [schema, table] => (schema.as_str().to_string(), table.as_str().to_string()),
+        _ => sql_bail!(
+            "invalid TABLE {}: must be of the form 'table' or 'schema.table'",
+            table
+        ),
+    };
+
+    Ok(StorageSinkConnectionBuilder::Postgres(
+        PostgresSinkConnectionBuilder {
+            connection_id: item.id(),
+            connection,
+            schema,
+            table,
+            key_indices,
+            value_desc,
+            environment_id: scx.catalog.config().environment_id.to_string(),
+        },
+    ))
+}
+
 fn invalid_upsert_key_err(desc: &RelationDesc, requested_user_key: &[ColumnName]) -> PlanError {
     let requested_user_key = requested_user_key
         .iter()
@@ -2269,6 +3000,52 @@ fn kafka_sink_builder(
                 csr_connection,
             }
         }
+        Some(Format::Protobuf(ProtobufSchema::Csr {
+            csr_connection:
+                CsrConnectionProtobuf {
+                    connection:
+                        CsrConnection {
+                            connection,
+                            options,
+                        },
+                    seed,
+                },
+        })) => {
+            if seed.is_some() {
+                sql_bail!("SEED option does not make sense with sinks");
+            }
+            if !options.is_empty() {
+                sql_bail!("Protobuf CSR connections do not support any options");
+            }
+
+            let item = scx.get_item_by_resolved_name(&connection)?;
+            let csr_connection = match item.connection()? {
+                Connection::Csr(connection) => connection.clone(),
+                _ => {
+                    sql_bail!(
+                        "{} is not a schema registry connection",
+                        scx.catalog
+                            .resolve_full_name(item.name())
+                            .to_string()
+                            .quoted()
+                    )
+                }
+            };
+
+            let schema_generator = ProtobufSchemaGenerator::new(
+                key_desc_and_indices
+                    .as_ref()
+                    .map(|(desc, _indices)| desc.clone()),
+                value_desc.clone(),
+                matches!(envelope, SinkEnvelope::Debezium),
+            );
+
+            KafkaSinkFormat::Protobuf {
+                key_schema: schema_generator.key_schema().map(String::from),
+                value_schema: schema_generator.value_schema().into(),
+                csr_connection,
+            }
+        }
         Some(Format::Json) => KafkaSinkFormat::Json,
         Some(format) => bail_unsupported!(format!("sink format {:?}", format)),
         None => bail_unsupported!("sink without format"),
@@ -2528,6 +3305,22 @@ pub fn plan_create_type(
     }
 
     let inner = match as_type {
+        CreateTypeAs::Enum { labels } => {
+            let mut seen = BTreeSet::new();
+            for label in &labels {
+                if label.len() > MAX_ENUM_LABEL_LEN {
+                    sql_bail!(
+                        "invalid enum label {}: labels must be {} bytes or less",
+                        label.quoted(),
+                        MAX_ENUM_LABEL_LEN
+                    );
+                }
+                if !seen.insert(label) {
+                    sql_bail!("enum label {} used more than once", label.quoted());
+                }
+            }
+            CatalogType::Enum { labels }
+        }
         CreateTypeAs::List { options } => {
             let CreateTypeListOptionExtracted {
                 element_type,
@@ -2582,6 +3375,10 @@ pub fn plan_create_type(
     }))
 }
 
+/// The maximum length of an enum label, in bytes, which matches PostgreSQL's
+/// limit on the length of identifiers.
+const MAX_ENUM_LABEL_LEN: usize = 63;
+
 generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
 
 generate_extracted_config!(
@@ -2596,6 +3393,8 @@ pub struct PlannedRoleAttributes {
     pub create_role: Option<bool>,
     pub create_db: Option<bool>,
     pub create_cluster: Option<bool>,
+    /// `Some(None)` indicates that the role's password should be removed.
+    pub password: Option<Option<ScramSecret>>,
 }
 
 fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttributes, PlanError> {
@@ -2604,6 +3403,7 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
         create_role: None,
         create_db: None,
         create_cluster: None,
+        password: None,
     };
 
     for option in options {
@@ -2634,6 +3434,9 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
             {
                 sql_bail!("conflicting or redundant options");
             }
+            RoleAttribute::Password(_) if planned_attributes.password.is_some() => {
+                sql_bail!("conflicting or redundant options");
+            }
 
             RoleAttribute::Inherit => planned_attributes.inherit = Some(true),
             RoleAttribute::NoInherit => planned_attributes.inherit = Some(false),
@@ -2643,6 +3446,16 @@ fn plan_role_attributes(options: Vec<RoleAttribute>) -> Result<PlannedRoleAttrib
             RoleAttribute::NoCreateDB => planned_attributes.create_db = Some(false),
             RoleAttribute::CreateRole => planned_attributes.create_role = Some(true),
             RoleAttribute::NoCreateRole => planned_attributes.create_role = Some(false),
+            RoleAttribute::Password(password) => {
+                planned_attributes.password = Some(password.map(|password| {
+                    // Like PostgreSQL, accept passwords that have already been
+                    // hashed by the client, so that the cleartext password
+                    // need not be sent to the server.
+                    password
+                        .parse()
+                        .unwrap_or_else(|_| ScramSecret::new(&password))
+                }));
+            }
         }
     }
     if planned_attributes.inherit == Some(false) {
@@ -3236,6 +4049,59 @@ impl PostgresConnectionOptionExtracted {
     }
 }
 
+generate_extracted_config!(
+    MySqlConnectionOption,
+    (AwsPrivatelink, with_options::Object),
+    (Host, String),
+    (Password, with_options::Secret),
+    (Port, u16, Default(3306_u16)),
+    (SshTunnel, with_options::Object),
+    (SslCertificate, StringOrSecret),
+    (SslCertificateAuthority, StringOrSecret),
+    (SslKey, with_options::Secret),
+    (SslMode, String),
+    (User, StringOrSecret)
+);
+
+impl MySqlConnectionOptionExtracted {
+    fn to_connection(
+        self,
+        scx: &StatementContext,
+    ) -> Result<mz_storage_client::types::connections::MySqlConnection, PlanError> {
+        let cert = self.ssl_certificate;
+        let key = self.ssl_key.map(|secret| secret.into());
+        let tls_identity = match (cert, key) {
+            (None, None) => None,
+            (Some(cert), Some(key)) => Some(TlsIdentity { cert, key }),
+            _ => sql_bail!("invalid CONNECTION: both SSL KEY and SSL CERTIFICATE are required"),
+        };
+        let tls_mode = match self.ssl_mode.as_ref().map(|m| m.as_str()) {
+            None | Some("disabled") => MySqlSslMode::Disabled,
+            Some("required") => MySqlSslMode::Required,
+            Some("verify_ca") | Some("verify-ca") => MySqlSslMode::VerifyCa,
+            Some("verify_identity") | Some("verify-identity") => MySqlSslMode::VerifyIdentity,
+            Some(m) => sql_bail!("invalid CONNECTION: unknown SSL MODE {}", m.quoted()),
+        };
+
+        let tunnel = scx.build_tunnel_definition(self.ssh_tunnel, self.aws_privatelink)?;
+
+        Ok(mz_storage_client::types::connections::MySqlConnection {
+            password: self.password.map(|password| password.into()),
+            host: self
+                .host
+                .ok_or_else(|| sql_err!("HOST option is required"))?,
+            port: self.port,
+            tunnel,
+            tls_mode,
+            tls_root_cert: self.ssl_certificate_authority,
+            tls_identity,
+            user: self
+                .user
+                .ok_or_else(|| sql_err!("USER option is required"))?,
+        })
+    }
+}
+
 generate_extracted_config!(
     SshConnectionOption,
     (Host, String),
@@ -3345,6 +4211,10 @@ pub fn plan_create_connection(
             let c = PostgresConnectionOptionExtracted::try_from(with_options)?;
             Connection::Postgres(c.to_connection(scx)?)
         }
+        CreateConnection::MySql { with_options } => {
+            let c = MySqlConnectionOptionExtracted::try_from(with_options)?;
+            Connection::MySql(c.to_connection(scx)?)
+        }
         CreateConnection::Aws { with_options } => {
             let c = AwsConnectionOptionExtracted::try_from(with_options)?;
             let connection = AwsConfig::try_from(c)?;
@@ -3697,6 +4567,129 @@ pub fn describe_alter_index_options(
     Ok(StatementDesc::new(None))
 }
 
+pub fn describe_alter_table(
+    _: &StatementContext,
+    _: AlterTableStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table(
+    scx: &StatementContext,
+    AlterTableStatement {
+        table_name,
+        if_exists,
+        action,
+    }: AlterTableStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let table_name = normalize::unresolved_item_name(table_name)?;
+    let entry = match scx.catalog.resolve_item(&table_name) {
+        Ok(table) => table,
+        Err(_) if if_exists => {
+            return Ok(Plan::AlterNoop(AlterNoopPlan {
+                object_type: ObjectType::Table,
+            }));
+        }
+        Err(e) => return Err(e.into()),
+    };
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    if entry.item_type() != CatalogItemType::Table {
+        sql_bail!("\"{}\" is a {} not a table", full_name, entry.item_type())
+    }
+    let current_desc = entry.desc(&full_name)?;
+
+    match action {
+        AlterTableAction::AddColumn {
+            if_not_exists,
+            column,
+        } => {
+            let column_name = normalize::column_name(column.name.clone());
+            if current_desc.get_by_name(&column_name).is_some() {
+                if if_not_exists {
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Table,
+                    }));
+                }
+                sql_bail!(
+                    "column {} of relation {} already exists",
+                    column_name.as_str().quoted(),
+                    full_name.item.as_str().quoted()
+                );
+            }
+            for option in &column.options {
+                match &option.option {
+                    ColumnOption::Null | ColumnOption::NotNull | ColumnOption::Default(_) => {}
+                    other => bail_unsupported!(format!(
+                        "ALTER TABLE ... ADD COLUMN with column constraint: {}",
+                        other
+                    )),
+                }
+            }
+
+            // Tables are durably recorded as their `CREATE TABLE` statement, so
+            // the altered table is described by that statement with the new
+            // column appended. Planning it the same way as `CREATE TABLE`
+            // guarantees that the table looks the same after a restart.
+            let stmt = crate::parse::parse(entry.create_sql())
+                .expect("create_sql for existing table should be valid sql")
+                .into_element();
+            let (mut stmt, _) = match stmt {
+                Statement::CreateTable(stmt) => names::resolve(scx.catalog, stmt)?,
+                _ => panic!("create_sql for existing table should parse as CREATE TABLE"),
+            };
+            stmt.columns.push(column);
+            let (desc, defaults, check_constraints, unique_constraints, missing_values) =
+                plan_table_desc(scx, &stmt)?;
+            let create_sql = normalize::create_statement(scx, Statement::CreateTable(stmt))?;
+
+            // Existing rows are not rewritten, but read back with the value of
+            // the new column's default, which must therefore be the same
+            // whenever it is read.
+            let column_type = desc
+                .typ()
+                .column_types
+                .last()
+                .expect("table has at least one column");
+            let default = defaults.last().expect("one default per column");
+            match plan_constant_default(scx, default, &column_type.scalar_type)? {
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(e.into()),
+                None => bail_unsupported!("ALTER TABLE ... ADD COLUMN with a non-constant DEFAULT"),
+            }
+
+            let table = Table {
+                create_sql,
+                desc,
+                defaults,
+                check_constraints,
+                unique_constraints,
+                missing_values,
+                temporary: entry.name().qualifiers.schema_spec == SchemaSpecifier::Temporary,
+            };
+            Ok(Plan::AlterTableAddColumn(AlterTableAddColumnPlan {
+                id: entry.id(),
+                table,
+            }))
+        }
+        AlterTableAction::DropColumn { if_exists, name } => {
+            let column_name = normalize::column_name(name);
+            if current_desc.get_by_name(&column_name).is_none() {
+                if if_exists {
+                    return Ok(Plan::AlterNoop(AlterNoopPlan {
+                        object_type: ObjectType::Table,
+                    }));
+                }
+                sql_bail!(
+                    "column {} of relation {} does not exist",
+                    column_name.as_str().quoted(),
+                    full_name.item.as_str().quoted()
+                );
+            }
+            bail_unsupported!("ALTER TABLE ... DROP COLUMN")
+        }
+    }
+}
+
 generate_extracted_config!(IndexOption, (LogicalCompactionWindow, OptionalInterval));
 
 fn plan_index_options(
@@ -4163,6 +5156,7 @@ pub fn plan_alter_source(
                 timestamp_interval: timestamp_interval_opt,
                 ignore_keys: ignore_keys_opt,
                 disk: disk_opt,
+                retain_keys_for: retain_keys_for_opt,
             } = CreateSourceOptionExtracted::try_from(options)?;
 
             if let Some(value) = size_opt {
@@ -4180,6 +5174,9 @@ pub fn plan_alter_source(
             if let Some(_) = disk_opt {
                 sql_bail!("Cannot modify the DISK property of a SOURCE.");
             }
+            if let Some(_) = retain_keys_for_opt {
+                sql_bail!("Cannot modify the RETAIN KEYS FOR property of a SOURCE.");
+            }
         }
         AlterSourceAction::ResetOptions(reset) => {
             for name in reset {
@@ -4199,6 +5196,9 @@ pub fn plan_alter_source(
                     CreateSourceOptionName::Disk => {
                         sql_bail!("Cannot modify the DISK property of a SOURCE.");
                     }
+                    CreateSourceOptionName::RetainKeysFor => {
+                        sql_bail!("Cannot modify the RETAIN KEYS FOR property of a SOURCE.");
+                    }
                 }
             }
         }
@@ -172,7 +172,7 @@ pub fn plan_select(
     scx: &StatementContext,
     SelectStatement { query, as_of }: SelectStatement<Aug>,
     params: &Params,
-    copy_to: Option<CopyFormat>,
+    copy_to: Option<CopyFormatParams<'static>>,
 ) -> Result<Plan, PlanError> {
     let query::PlannedQuery {
         expr, finishing, ..
@@ -466,7 +466,7 @@ pub fn plan_subscribe(
         up_to,
         output,
     }: SubscribeStatement<Aug>,
-    copy_to: Option<CopyFormat>,
+    copy_to: Option<CopyFormatParams<'static>>,
 ) -> Result<Plan, PlanError> {
     let (from, desc, scope) = match relation {
         SubscribeRelation::Name(name) => {
@@ -651,9 +651,20 @@ fn plan_copy_from(
     scx: &StatementContext,
     table_name: ResolvedItemName,
     columns: Vec<Ident>,
+    params: CopyFormatParams<'static>,
+) -> Result<Plan, PlanError> {
+    let (id, _, columns) = query::plan_copy_from(scx, table_name, columns)?;
+    Ok(Plan::CopyFrom(CopyFromPlan {
+        id,
+        columns,
+        params,
+    }))
+}
+
+fn plan_copy_format_params(
     format: CopyFormat,
     options: CopyOptionExtracted,
-) -> Result<Plan, PlanError> {
+) -> Result<CopyFormatParams<'static>, PlanError> {
     fn only_available_with_csv<T>(option: Option<T>, param: &str) -> Result<(), PlanError> {
         match option {
             Some(_) => sql_bail!("COPY {} available only in CSV mode", param),
@@ -673,7 +684,7 @@ fn plan_copy_from(
         }
     }
 
-    let params = match format {
+    Ok(match format {
         CopyFormat::Text => {
             only_available_with_csv(options.quote, "quote")?;
             only_available_with_csv(options.escape, "escape")?;
@@ -711,15 +722,21 @@ fn plan_copy_from(
                 header,
             })
         }
-        CopyFormat::Binary => bail_unsupported!("FORMAT BINARY"),
-    };
-
-    let (id, _, columns) = query::plan_copy_from(scx, table_name, columns)?;
-    Ok(Plan::CopyFrom(CopyFromPlan {
-        id,
-        columns,
-        params,
-    }))
+        CopyFormat::Binary => {
+            only_available_with_csv(options.quote, "quote")?;
+            only_available_with_csv(options.escape, "escape")?;
+            if options.delimiter.is_some() {
+                sql_bail!("cannot specify DELIMITER in BINARY mode");
+            }
+            if options.null.is_some() {
+                sql_bail!("cannot specify NULL in BINARY mode");
+            }
+            if options.header.is_some() {
+                sql_bail!("cannot specify HEADER in BINARY mode");
+            }
+            CopyFormatParams::Binary
+        }
+    })
 }
 
 generate_extracted_config!(
@@ -748,26 +765,17 @@ pub fn plan_copy(
         "binary" => CopyFormat::Binary,
         _ => sql_bail!("unknown FORMAT: {}", options.format),
     };
-    if let CopyDirection::To = direction {
-        if options.delimiter.is_some() {
-            sql_bail!("COPY TO does not support DELIMITER option yet");
-        }
-        if options.null.is_some() {
-            sql_bail!("COPY TO does not support NULL option yet");
-        }
-    }
+    let params = plan_copy_format_params(format, options)?;
     match (&direction, &target) {
         (CopyDirection::To, CopyTarget::Stdout) => match relation {
             CopyRelation::Table { .. } => sql_bail!("table with COPY TO unsupported"),
             CopyRelation::Select(stmt) => {
-                Ok(plan_select(scx, stmt, &Params::empty(), Some(format))?)
+                Ok(plan_select(scx, stmt, &Params::empty(), Some(params))?)
             }
-            CopyRelation::Subscribe(stmt) => Ok(plan_subscribe(scx, stmt, Some(format))?),
+            CopyRelation::Subscribe(stmt) => Ok(plan_subscribe(scx, stmt, Some(params))?),
         },
         (CopyDirection::From, CopyTarget::Stdin) => match relation {
-            CopyRelation::Table { name, columns } => {
-                plan_copy_from(scx, name, columns, format, options)
-            }
+            CopyRelation::Table { name, columns } => plan_copy_from(scx, name, columns, params),
             _ => sql_bail!("COPY FROM {} not supported", target),
         },
         _ => sql_bail!("COPY {} {} not supported", direction, target),
@@ -18,8 +18,9 @@ use uuid::Uuid;
 use mz_ore::stack::{CheckedRecursion, RecursionGuard};
 use mz_sql_parser::ast::visit_mut::{self, VisitMut, VisitMutNode};
 use mz_sql_parser::ast::{
-    Expr, Function, FunctionArgs, Ident, Op, OrderByExpr, Query, Select, SelectItem, TableAlias,
-    TableFactor, TableFunction, TableWithJoins, UnresolvedItemName, Value,
+    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, Op, OrderByExpr, Query, Select,
+    SelectItem, TableAlias, TableFactor, TableFunction, TableWithJoins, UnresolvedItemName, Value,
+    WindowSpec,
 };
 
 use crate::names::{Aug, PartialItemName, ResolvedDataType};
@@ -112,6 +113,7 @@ impl<'a> FuncRewriter<'a> {
         expr: Expr<Aug>,
         order_by: Vec<OrderByExpr<Aug>>,
         filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
         distinct: bool,
     ) -> Expr<Aug> {
         Expr::Function(Function {
@@ -121,17 +123,23 @@ impl<'a> FuncRewriter<'a> {
                 order_by,
             },
             filter,
-            over: None,
+            over,
             distinct,
         })
     }
 
-    fn plan_avg(expr: Expr<Aug>, filter: Option<Box<Expr<Aug>>>, distinct: bool) -> Expr<Aug> {
+    fn plan_avg(
+        expr: Expr<Aug>,
+        filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
+        distinct: bool,
+    ) -> Expr<Aug> {
         let sum = Self::plan_agg(
             UnresolvedItemName::qualified(&["pg_catalog", "sum"]),
             expr.clone(),
             vec![],
             filter.clone(),
+            over.clone(),
             distinct,
         )
         .call_unary(vec!["mz_internal", "mz_avg_promotion"]);
@@ -140,6 +148,7 @@ impl<'a> FuncRewriter<'a> {
             expr,
             vec![],
             filter,
+            over,
             distinct,
         );
         Self::plan_divide(sum, count)
@@ -148,6 +157,7 @@ impl<'a> FuncRewriter<'a> {
     fn plan_variance(
         expr: Expr<Aug>,
         filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
         distinct: bool,
         sample: bool,
     ) -> Expr<Aug> {
@@ -172,6 +182,7 @@ impl<'a> FuncRewriter<'a> {
             expr_squared,
             vec![],
             filter.clone(),
+            over.clone(),
             distinct,
         );
         let sum = Self::plan_agg(
@@ -179,6 +190,7 @@ impl<'a> FuncRewriter<'a> {
             expr.clone(),
             vec![],
             filter.clone(),
+            over.clone(),
             distinct,
         );
         let sum_squared = sum.clone().multiply(sum);
@@ -187,6 +199,7 @@ impl<'a> FuncRewriter<'a> {
             expr,
             vec![],
             filter,
+            over,
             distinct,
         );
         Self::plan_divide(
@@ -202,16 +215,18 @@ impl<'a> FuncRewriter<'a> {
     fn plan_stddev(
         expr: Expr<Aug>,
         filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
         distinct: bool,
         sample: bool,
     ) -> Expr<Aug> {
-        Self::plan_variance(expr, filter, distinct, sample).call_unary(vec!["sqrt"])
+        Self::plan_variance(expr, filter, over, distinct, sample).call_unary(vec!["sqrt"])
     }
 
     fn plan_bool_and(
         &self,
         expr: Expr<Aug>,
         filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
         distinct: bool,
     ) -> Expr<Aug> {
         // The code below converts `bool_and(x)` into:
@@ -230,6 +245,7 @@ impl<'a> FuncRewriter<'a> {
             expr.negate().cast(self.int32_data_type()),
             vec![],
             filter,
+            over,
             distinct,
         );
         sum.equals(Expr::Value(Value::Number(0.to_string())))
@@ -239,6 +255,7 @@ impl<'a> FuncRewriter<'a> {
         &self,
         expr: Expr<Aug>,
         filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
         distinct: bool,
     ) -> Expr<Aug> {
         // The code below converts `bool_or(x)` into:
@@ -258,11 +275,41 @@ impl<'a> FuncRewriter<'a> {
                 .cast(self.int32_data_type()),
             vec![],
             filter,
+            over,
             distinct,
         );
         sum.gt(Expr::Value(Value::Number(0.to_string())))
     }
 
+    fn plan_approx_count_distinct(
+        expr: Expr<Aug>,
+        filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
+        distinct: bool,
+    ) -> Expr<Aug> {
+        // The code below converts `approx_count_distinct(x)` into:
+        //
+        //     coalesce(hll_estimate(hll_sketch(x)), 0)
+        //
+        // `hll_sketch` returns NULL when all input values are NULL, but like
+        // `count`, `approx_count_distinct` returns zero.
+        let sketch = Self::plan_agg(
+            UnresolvedItemName::qualified(&["mz_catalog", "hll_sketch"]),
+            expr,
+            vec![],
+            filter,
+            over,
+            distinct,
+        );
+        Expr::HomogenizingFunction {
+            function: HomogenizingFunction::Coalesce,
+            exprs: vec![
+                sketch.call_unary(vec!["mz_catalog", "hll_estimate"]),
+                Expr::number("0"),
+            ],
+        }
+    }
+
     fn rewrite_expr(&mut self, expr: &Expr<Aug>) -> Option<(Ident, Expr<Aug>)> {
         match expr {
             Expr::Function(Function {
@@ -270,7 +317,7 @@ impl<'a> FuncRewriter<'a> {
                 args: FunctionArgs::Args { args, order_by: _ },
                 filter,
                 distinct,
-                over: None,
+                over,
             }) => {
                 let name = normalize::unresolved_item_name(name.clone()).ok()?;
                 if let Some(database) = &name.database {
@@ -281,24 +328,38 @@ impl<'a> FuncRewriter<'a> {
                         self.status = Err(e.into());
                     }
                 }
-                if name.schema.is_some() && name.schema.as_deref() != Some("pg_catalog") {
+                // Of the functions rewritten below, only `approx_count_distinct`
+                // lives in `mz_catalog` rather than `pg_catalog`.
+                let schema = match name.item.as_str() {
+                    "approx_count_distinct" => "mz_catalog",
+                    _ => "pg_catalog",
+                };
+                if name.schema.is_some() && name.schema.as_deref() != Some(schema) {
                     return None;
                 }
                 let filter = filter.clone();
+                let over = over.clone();
                 let distinct = *distinct;
                 let expr = if args.len() == 1 {
                     let arg = args[0].clone();
                     match name.item.as_str() {
-                        "avg" => Self::plan_avg(arg, filter, distinct),
-                        "variance" | "var_samp" => Self::plan_variance(arg, filter, distinct, true),
-                        "var_pop" => Self::plan_variance(arg, filter, distinct, false),
-                        "stddev" | "stddev_samp" => Self::plan_stddev(arg, filter, distinct, true),
-                        "stddev_pop" => Self::plan_stddev(arg, filter, distinct, false),
-                        "bool_and" => self.plan_bool_and(arg, filter, distinct),
-                        "bool_or" => self.plan_bool_or(arg, filter, distinct),
+                        "avg" => Self::plan_avg(arg, filter, over, distinct),
+                        "variance" | "var_samp" => {
+                            Self::plan_variance(arg, filter, over, distinct, true)
+                        }
+                        "var_pop" => Self::plan_variance(arg, filter, over, distinct, false),
+                        "stddev" | "stddev_samp" => {
+                            Self::plan_stddev(arg, filter, over, distinct, true)
+                        }
+                        "stddev_pop" => Self::plan_stddev(arg, filter, over, distinct, false),
+                        "bool_and" => self.plan_bool_and(arg, filter, over, distinct),
+                        "bool_or" => self.plan_bool_or(arg, filter, over, distinct),
+                        "approx_count_distinct" => {
+                            Self::plan_approx_count_distinct(arg, filter, over, distinct)
+                        }
                         _ => return None,
                     }
-                } else if args.len() == 2 {
+                } else if args.len() == 2 && over.is_none() {
                     let (lhs, rhs) = (args[0].clone(), args[1].clone());
                     match name.item.as_str() {
                         "mod" => lhs.modulo(rhs),
@@ -455,6 +455,8 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
         (String, Bytes) => Explicit: CastStringToBytes(func::CastStringToBytes),
         (String, Jsonb) => Explicit: CastStringToJsonb(func::CastStringToJsonb),
         (String, Uuid) => Explicit: CastStringToUuid(func::CastStringToUuid),
+        (String, Inet) => Explicit: CastStringToInet(func::CastStringToInet),
+        (String, Cidr) => Explicit: CastStringToCidr(func::CastStringToCidr),
         (String, Array) => Explicit: CastTemplate::new(|ecx, ccx, from_type, to_type| {
             let return_ty = to_type.clone();
             let to_el_type = to_type.unwrap_array_element_type();
@@ -492,6 +494,10 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
             })))
         }),
         (String, Int2Vector) => Explicit: CastStringToInt2Vector(func::CastStringToInt2Vector),
+        (String, Enum) => Explicit: CastTemplate::new(|_ecx, _ccx, _from_type, to_type| {
+            let return_ty = to_type.clone();
+            Some(|e: HirScalarExpr| e.call_unary(UnaryFunc::CastStringToEnum(func::CastStringToEnum { return_ty })))
+        }),
         (String, Char) => Implicit: CastTemplate::new(|_ecx, ccx, _from_type, to_type| {
             let length = to_type.unwrap_char_length();
             Some(move |e: HirScalarExpr| e.call_unary(CastStringToChar(func::CastStringToChar {length, fail_on_len: ccx != CastContext::Explicit})))
@@ -648,6 +654,20 @@ static VALID_CASTS: Lazy<BTreeMap<(ScalarBaseType, ScalarBaseType), CastImpl>> =
             Some(|e: HirScalarExpr| e.call_unary(CastRangeToString(func::CastRangeToString { ty })))
         }),
 
+        // Enum
+        (Enum, String) => Assignment: CastTemplate::new(|_ecx, _ccx, from_type, _to_type| {
+            let ty = from_type.clone();
+            Some(|e: HirScalarExpr| e.call_unary(CastEnumToString(func::CastEnumToString { ty })))
+        }),
+
+        // Inet
+        (Inet, Cidr) => Assignment: CastInetToCidr(func::CastInetToCidr),
+        (Inet, String) => Assignment: CastInetToString(func::CastInetToString),
+
+        // Cidr
+        (Cidr, Inet) => Implicit: CastCidrToInet(func::CastCidrToInet),
+        (Cidr, String) => Assignment: CastCidrToString(func::CastCidrToString),
+
         // MzAclItem
         (MzAclItem, String) => Explicit: sql_impl_cast("(
                 SELECT
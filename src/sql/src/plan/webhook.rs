@@ -0,0 +1,264 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Webhook sources.
+//!
+//! A webhook source receives data pushed to an HTTP endpoint of `environmentd`,
+//! rather than pulling it from an external system. The types in this module
+//! describe how the requests to that endpoint are authenticated and decoded.
+
+use std::collections::BTreeMap;
+
+use hmac::{Hmac, Mac};
+use mz_repr::{ColumnType, Datum, GlobalId, RelationDesc, Row, ScalarType};
+use serde::Serialize;
+use sha2::{Sha256, Sha512};
+
+/// The name of the column that holds the body of each request.
+pub const WEBHOOK_BODY_COLUMN: &str = "body";
+
+/// The name of the column that holds the headers of each request, if the
+/// source includes them.
+pub const WEBHOOK_HEADERS_COLUMN: &str = "headers";
+
+/// The headers that carry credentials, which are never stored, even by sources
+/// that include headers.
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "cookie", "proxy-authorization"];
+
+/// The format that the body of each request to a webhook source is decoded
+/// with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WebhookBodyFormat {
+    Bytes,
+    Json,
+    Text,
+}
+
+impl WebhookBodyFormat {
+    /// The type of the column that decoded bodies are stored in.
+    pub fn scalar_type(&self) -> ScalarType {
+        match self {
+            WebhookBodyFormat::Bytes => ScalarType::Bytes,
+            WebhookBodyFormat::Json => ScalarType::Jsonb,
+            WebhookBodyFormat::Text => ScalarType::String,
+        }
+    }
+}
+
+/// Describes the data that a webhook source receives.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WebhookDesc {
+    pub body_format: WebhookBodyFormat,
+    /// Whether the headers of each request are stored alongside its body.
+    pub include_headers: bool,
+    /// How requests are authenticated, if at all.
+    pub validation: Option<WebhookValidation>,
+}
+
+impl WebhookDesc {
+    /// The relation that the source's data conforms to.
+    pub fn relation_desc(&self) -> RelationDesc {
+        let mut desc = RelationDesc::empty().with_column(
+            WEBHOOK_BODY_COLUMN,
+            self.body_format.scalar_type().nullable(false),
+        );
+        if self.include_headers {
+            desc = desc.with_column(
+                WEBHOOK_HEADERS_COLUMN,
+                ColumnType {
+                    scalar_type: ScalarType::Map {
+                        value_type: Box::new(ScalarType::String),
+                        custom_id: None,
+                    },
+                    nullable: false,
+                },
+            );
+        }
+        desc
+    }
+
+    /// Decodes a request into a row of the source.
+    ///
+    /// The body is decoded by the same decoders that other sources use for
+    /// the format.
+    ///
+    /// `headers` must map lowercase header names to their values. Headers that
+    /// carry credentials, like `authorization`, are omitted.
+    pub fn decode(
+        &self,
+        headers: &BTreeMap<String, String>,
+        body: &[u8],
+    ) -> Result<Row, anyhow::Error> {
+        let body = match self.body_format {
+            WebhookBodyFormat::Bytes => mz_interchange::text::decode_bytes(body),
+            WebhookBodyFormat::Json => mz_interchange::json::decode_json(body)?,
+            WebhookBodyFormat::Text => mz_interchange::text::decode_text(body)?,
+        };
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        packer.extend(body.iter());
+        if self.include_headers {
+            packer.push_dict(
+                headers
+                    .iter()
+                    .filter(|(name, _)| !CREDENTIAL_HEADERS.contains(&name.as_str()))
+                    .map(|(name, value)| (name.as_str(), Datum::String(value))),
+            );
+        }
+        Ok(row)
+    }
+}
+
+/// Describes how requests to a webhook source are authenticated.
+///
+/// The sender signs the body of each request with an HMAC keyed by a shared
+/// secret, and sends the signature in a request header.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WebhookValidation {
+    /// The secret that holds the HMAC key.
+    pub secret_id: GlobalId,
+    /// The lowercase name of the header that carries the signature.
+    pub header: String,
+    pub algorithm: WebhookHmacAlgorithm,
+    pub encoding: WebhookSignatureEncoding,
+}
+
+impl WebhookValidation {
+    /// Reports whether the signature in `headers` is a valid signature of
+    /// `body` under `key`.
+    ///
+    /// The signature may be prefixed with the name of the algorithm and an
+    /// equals sign, e.g. `sha256=...`, as some senders do.
+    pub fn validate(&self, key: &[u8], headers: &BTreeMap<String, String>, body: &[u8]) -> bool {
+        let Some(signature) = headers.get(&self.header) else {
+            return false;
+        };
+        let signature = signature.trim();
+        let signature = signature
+            .strip_prefix(self.algorithm.name())
+            .and_then(|s| s.strip_prefix('='))
+            .unwrap_or(signature);
+        let signature = match self.encoding {
+            WebhookSignatureEncoding::Base64 => base64::decode(signature).ok(),
+            WebhookSignatureEncoding::Hex => hex::decode(signature).ok(),
+        };
+        let Some(signature) = signature else {
+            return false;
+        };
+        // `verify_slice` compares the signatures in constant time.
+        match self.algorithm {
+            WebhookHmacAlgorithm::Sha256 => {
+                let mut mac =
+                    Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts all key sizes");
+                mac.update(body);
+                mac.verify_slice(&signature).is_ok()
+            }
+            WebhookHmacAlgorithm::Sha512 => {
+                let mut mac =
+                    Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts all key sizes");
+                mac.update(body);
+                mac.verify_slice(&signature).is_ok()
+            }
+        }
+    }
+}
+
+/// The hash function of the HMAC that signs requests to a webhook source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WebhookHmacAlgorithm {
+    Sha256,
+    Sha512,
+}
+
+impl WebhookHmacAlgorithm {
+    /// The name of the algorithm, as it is spelled in SQL.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebhookHmacAlgorithm::Sha256 => "sha256",
+            WebhookHmacAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+/// How the signature of a request to a webhook source is encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum WebhookSignatureEncoding {
+    Base64,
+    Hex,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validation(encoding: WebhookSignatureEncoding) -> WebhookValidation {
+        WebhookValidation {
+            secret_id: GlobalId::User(1),
+            header: "x-signature".into(),
+            algorithm: WebhookHmacAlgorithm::Sha256,
+            encoding,
+        }
+    }
+
+    fn headers(signature: &str) -> BTreeMap<String, String> {
+        BTreeMap::from([("x-signature".to_string(), signature.to_string())])
+    }
+
+    #[test]
+    fn test_webhook_validation() {
+        let key = b"key";
+        let body = b"The quick brown fox jumps over the lazy dog";
+        let signature = "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8";
+
+        let hex = validation(WebhookSignatureEncoding::Hex);
+        assert!(hex.validate(key, &headers(signature), body));
+        assert!(hex.validate(key, &headers(&format!("sha256={signature}")), body));
+        assert!(!hex.validate(b"other key", &headers(signature), body));
+        assert!(!hex.validate(key, &headers(signature), b"tampered"));
+        assert!(!hex.validate(key, &headers("not hex"), body));
+        assert!(!hex.validate(key, &BTreeMap::new(), body));
+
+        let base64 = validation(WebhookSignatureEncoding::Base64);
+        let signature = base64::encode(hex::decode(signature).unwrap());
+        assert!(base64.validate(key, &headers(&signature), body));
+    }
+
+    #[test]
+    fn test_webhook_decode() {
+        let desc = WebhookDesc {
+            body_format: WebhookBodyFormat::Json,
+            include_headers: true,
+            validation: None,
+        };
+        let row = desc
+            .decode(&headers("abc"), br#"{"a": 1}"#)
+            .expect("valid JSON");
+        assert_eq!(row.iter().count(), 2);
+        assert!(desc.decode(&headers("abc"), b"{").is_err());
+
+        let mut credentials = headers("abc");
+        for name in CREDENTIAL_HEADERS {
+            credentials.insert(name.to_string(), "secret".into());
+        }
+        let row = desc.decode(&credentials, b"{}").expect("valid JSON");
+        let stored: Vec<_> = row.iter().nth(1).unwrap().unwrap_map().iter().collect();
+        assert_eq!(stored, vec![("x-signature", Datum::String("abc"))]);
+
+        let desc = WebhookDesc {
+            body_format: WebhookBodyFormat::Text,
+            include_headers: false,
+            validation: None,
+        };
+        let row = desc
+            .decode(&BTreeMap::new(), b"hello")
+            .expect("valid UTF-8");
+        assert_eq!(row.unpack_first(), Datum::String("hello"));
+        assert!(desc.decode(&BTreeMap::new(), &[0xff]).is_err());
+    }
+}
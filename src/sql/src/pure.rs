@@ -29,16 +29,17 @@ use mz_ccsr::Schema as CcsrSchema;
 use mz_ccsr::{Client, GetByIdError, GetBySubjectError};
 use mz_ore::str::StrExt;
 use mz_proto::RustType;
-use mz_repr::{strconv, GlobalId};
+use mz_repr::{strconv, GlobalId, ScalarType};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::{
     ColumnDef, CreateSubsourceOption, CreateSubsourceOptionName, CsrConnection, CsrSeedAvro,
     CsrSeedProtobuf, CsrSeedProtobufSchema, DbzMode, DeferredItemName, Envelope, Ident,
     KafkaConfigOption, KafkaConfigOptionName, KafkaConnection, KafkaSourceConnection,
-    PgConfigOption, PgConfigOptionName, ReaderSchemaSelectionStrategy, UnresolvedItemName,
+    MySqlConfigOption, MySqlConfigOptionName, PgConfigOption, PgConfigOptionName, RawDataType,
+    RawItemName, ReaderSchemaSelectionStrategy, UnresolvedItemName,
 };
 use mz_storage_client::types::connections::{Connection, ConnectionContext};
-use mz_storage_client::types::sources::PostgresSourcePublicationDetails;
+use mz_storage_client::types::sources::{MySqlSourceDetails, PostgresSourcePublicationDetails};
 
 use crate::ast::{
     AvroSchema, CreateSourceConnection, CreateSourceFormat, CreateSourceStatement,
@@ -48,9 +49,10 @@ use crate::ast::{
 use crate::catalog::{ErsatzCatalog, SessionCatalog};
 use crate::kafka_util;
 use crate::kafka_util::KafkaConfigOptionExtracted;
-use crate::names::{Aug, RawDatabaseSpecifier};
+use crate::names::{self, Aug, RawDatabaseSpecifier};
 use crate::normalize;
 use crate::plan::error::PlanError;
+use crate::plan::query;
 use crate::plan::statement::ddl::load_generator_ast_to_generator;
 use crate::plan::StatementContext;
 
@@ -171,6 +173,9 @@ pub async fn purify_create_source(
         CreateSourceConnection::Postgres { .. } => {
             &mz_storage_client::types::sources::PG_PROGRESS_DESC
         }
+        CreateSourceConnection::MySql { .. } => {
+            &mz_storage_client::types::sources::MYSQL_PROGRESS_DESC
+        }
         CreateSourceConnection::LoadGenerator { .. } => {
             &mz_storage_client::types::sources::LOAD_GEN_PROGRESS_DESC
         }
@@ -188,7 +193,9 @@ pub async fn purify_create_source(
                 );
             }
         }
-        CreateSourceConnection::Postgres { .. } | CreateSourceConnection::LoadGenerator { .. } => {}
+        CreateSourceConnection::Postgres { .. }
+        | CreateSourceConnection::MySql { .. }
+        | CreateSourceConnection::LoadGenerator { .. } => {}
     }
 
     match connection {
@@ -486,6 +493,54 @@ pub async fn purify_create_source(
                 text_cols_option.value = Some(WithOptionValue::Sequence(seq));
             }
 
+            // Upstream columns of types we do not otherwise support may be of
+            // enum types, which we map to the Materialize enum type of the same
+            // name, if one exists.
+            let unrecognized_type_oids: BTreeSet<_> = validated_requested_subsources
+                .iter()
+                .flat_map(|(_, _, table)| {
+                    let text_cols = text_cols_dict.get(&table.oid);
+                    table.columns.iter().filter_map(move |c| {
+                        let is_text = text_cols.map_or(false, |names| names.contains(&c.name));
+                        let is_recognized =
+                            mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod).is_ok();
+                        (!is_text && !is_recognized).then_some(c.type_oid)
+                    })
+                })
+                .collect();
+
+            let mut enum_types = BTreeMap::new();
+            if !unrecognized_type_oids.is_empty() {
+                let oids: Vec<_> = unrecognized_type_oids.into_iter().collect();
+                for desc in mz_postgres_util::enum_types(&config, &oids).await? {
+                    let data_type = RawDataType::Other {
+                        name: RawItemName::Name(UnresolvedItemName::unqualified(&desc.name)),
+                        typ_mod: vec![],
+                    };
+                    // Upstream enum types without a Materialize counterpart
+                    // are reported as unsupported below.
+                    let data_type = match names::resolve(scx.catalog, data_type) {
+                        Ok((data_type, _)) => data_type,
+                        Err(_) => continue,
+                    };
+                    match query::scalar_type_from_sql(&scx, &data_type)? {
+                        ScalarType::Enum { labels, .. } if labels == desc.labels => (),
+                        ScalarType::Enum { labels, .. } => {
+                            sql_bail!(
+                                "enum type {} has labels ({}), but upstream type {}.{} has labels ({})",
+                                data_type.to_ast_string(),
+                                labels.iter().map(|l| l.quoted()).join(", "),
+                                desc.namespace,
+                                desc.name,
+                                desc.labels.iter().map(|l| l.quoted()).join(", "),
+                            )
+                        }
+                        _ => continue,
+                    }
+                    enum_types.insert(desc.oid, (desc, data_type));
+                }
+            }
+
             // Aggregate all unrecognized types.
             let mut unsupported_cols = vec![];
 
@@ -496,23 +551,27 @@ pub async fn purify_create_source(
                 let mut columns = vec![];
                 for c in table.columns.iter() {
                     let name = Ident::new(c.name.clone());
-                    let ty = match text_cols_dict.get(&table.oid) {
-                        Some(names) if names.contains(&c.name) => mz_pgrepr::Type::Text,
+                    let data_type = match text_cols_dict.get(&table.oid) {
+                        Some(names) if names.contains(&c.name) => {
+                            scx.resolve_type(mz_pgrepr::Type::Text)?
+                        }
                         _ => match mz_pgrepr::Type::from_oid_and_typmod(c.type_oid, c.type_mod) {
-                            Ok(t) => t,
-                            Err(_) => {
-                                let mut full_name = upstream_name.0.clone();
-                                full_name.push(name);
-                                unsupported_cols.push((
-                                    UnresolvedItemName(full_name).to_ast_string(),
-                                    Oid(c.type_oid),
-                                ));
-                                continue;
-                            }
+                            Ok(t) => scx.resolve_type(t)?,
+                            Err(_) => match enum_types.get(&c.type_oid) {
+                                Some((_, data_type)) => data_type.clone(),
+                                None => {
+                                    let mut full_name = upstream_name.0.clone();
+                                    full_name.push(name);
+                                    unsupported_cols.push((
+                                        UnresolvedItemName(full_name).to_ast_string(),
+                                        Oid(c.type_oid),
+                                    ));
+                                    continue;
+                                }
+                            },
                         },
                     };
 
-                    let data_type = scx.resolve_type(ty)?;
                     let mut options = vec![];
 
                     if !c.nullable {
@@ -603,13 +662,28 @@ pub async fn purify_create_source(
             *referenced_subsources = Some(ReferencedSubsources::SubsetTables(targeted_subsources));
 
             // Remove any old detail references
-            options.retain(|PgConfigOption { name, .. }| name != &PgConfigOptionName::Details);
+            options.retain(|PgConfigOption { name, .. }| {
+                name != &PgConfigOptionName::Details && name != &PgConfigOptionName::EnumTypes
+            });
+            let (enum_types, enum_data_types): (Vec<_>, Vec<_>) = enum_types.into_values().unzip();
+            if !enum_data_types.is_empty() {
+                options.push(PgConfigOption {
+                    name: PgConfigOptionName::EnumTypes,
+                    value: Some(WithOptionValue::Sequence(
+                        enum_data_types
+                            .into_iter()
+                            .map(WithOptionValue::DataType)
+                            .collect(),
+                    )),
+                });
+            }
             let details = PostgresSourcePublicationDetails {
                 tables: publication_tables,
                 slot: format!(
                     "materialize_{}",
                     Uuid::new_v4().to_string().replace('-', "")
                 ),
+                enum_types,
             };
             options.push(PgConfigOption {
                 name: PgConfigOptionName::Details,
@@ -618,6 +692,324 @@ pub async fn purify_create_source(
                 )))),
             })
         }
+        CreateSourceConnection::MySql {
+            connection,
+            options,
+        } => {
+            let scx = StatementContext::new(None, &*catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                match item.connection()? {
+                    Connection::MySql(connection) => connection.clone(),
+                    _ => sql_bail!("{} is not a mysql connection", item.name()),
+                }
+            };
+            let crate::plan::statement::MySqlConfigOptionExtracted {
+                mut text_columns, ..
+            } = options.clone().try_into()?;
+
+            // Verify that we can connect upstream, that the server is configured for
+            // GTID-based row replication, and snapshot the table metadata.
+            let config = connection
+                .config(&*connection_context.secrets_reader)
+                .await?;
+            let mut conn = config.connect().await?;
+            mz_mysql_util::ensure_replication_settings(&mut conn).await?;
+            let server_uuid = mz_mysql_util::query_sys_var(&mut conn, "server_uuid").await?;
+            let tables = mz_mysql_util::schema_info(&mut conn, None).await?;
+
+            if tables.is_empty() {
+                sql_bail!("MySQL server contains no tables to replicate");
+            }
+
+            let database = crate::plan::statement::MYSQL_DATABASE_NAME.to_string();
+
+            // An index from table name -> schema name -> database name -> MySqlTableDesc
+            let mut tables_by_name = BTreeMap::new();
+            for table in &tables {
+                tables_by_name
+                    .entry(table.name.clone())
+                    .or_insert_with(BTreeMap::new)
+                    .entry(table.schema_name.clone())
+                    .or_insert_with(BTreeMap::new)
+                    .entry(database.clone())
+                    .or_insert(table);
+            }
+
+            let server_catalog = ErsatzCatalog(tables_by_name);
+
+            let mut targeted_subsources = vec![];
+
+            let mut validated_requested_subsources = vec![];
+            match referenced_subsources {
+                Some(ReferencedSubsources::All) => {
+                    for table in &tables {
+                        let upstream_name = UnresolvedItemName::qualified(&[
+                            &database,
+                            &table.schema_name,
+                            &table.name,
+                        ]);
+                        let subsource_name = subsource_name_gen(source_name, &table.name)?;
+                        validated_requested_subsources.push((upstream_name, subsource_name, table));
+                    }
+                }
+                Some(ReferencedSubsources::SubsetSchemas(schemas)) => {
+                    let available_schemas = mz_mysql_util::schema_names(&mut conn).await?;
+
+                    let requested_schemas: BTreeSet<_> =
+                        schemas.iter().map(|s| s.as_str().to_string()).collect();
+
+                    let missing_schemas: Vec<_> = requested_schemas
+                        .difference(&available_schemas)
+                        .map(|s| s.to_string())
+                        .collect();
+
+                    if !missing_schemas.is_empty() {
+                        return Err(PlanError::MySqlServerMissingFilteredSchemas {
+                            schemas: missing_schemas,
+                        });
+                    }
+
+                    for table in &tables {
+                        if !requested_schemas.contains(table.schema_name.as_str()) {
+                            continue;
+                        }
+
+                        let upstream_name = UnresolvedItemName::qualified(&[
+                            &database,
+                            &table.schema_name,
+                            &table.name,
+                        ]);
+                        let subsource_name = UnresolvedItemName::unqualified(&table.name);
+                        validated_requested_subsources.push((upstream_name, subsource_name, table));
+                    }
+                }
+                Some(ReferencedSubsources::SubsetTables(subsources)) => {
+                    // The user manually selected a subset of upstream tables so we need to
+                    // validate that the names actually exist and are not ambiguous
+                    validated_requested_subsources.extend(subsource_gen(
+                        subsources,
+                        &server_catalog,
+                        source_name,
+                    )?);
+                }
+                None => {
+                    sql_bail!("multi-output sources require a FOR TABLES (..), FOR SCHEMAS (..), or FOR ALL TABLES clause");
+                }
+            };
+
+            if validated_requested_subsources.is_empty() {
+                sql_bail!(
+                    "[internal error]: MySQL source must ingest at least one table, but {} matched none",
+                    referenced_subsources.as_ref().unwrap().to_ast_string()
+                );
+            }
+
+            if let Some(name) = validated_requested_subsources
+                .iter()
+                .map(|(_, subsource_name, _)| subsource_name)
+                .duplicates()
+                .next()
+                .cloned()
+            {
+                let mut upstream_references: Vec<_> = validated_requested_subsources
+                    .into_iter()
+                    .filter_map(|(u, t, _)| if t == name { Some(u) } else { None })
+                    .collect();
+
+                upstream_references.sort();
+
+                return Err(PlanError::DuplicateSubsourceReference {
+                    name,
+                    upstream_references,
+                });
+            }
+
+            let mut text_cols_dict: BTreeMap<(String, String), BTreeSet<String>> = BTreeMap::new();
+
+            for name in text_columns.iter_mut() {
+                let (qual, col) = match name.0.split_last().expect("must have at least one element")
+                {
+                    (col, qual) if qual.is_empty() => {
+                        return Err(PlanError::InvalidOptionValue {
+                            option_name: MySqlConfigOptionName::TextColumns.to_ast_string(),
+                            err: Box::new(PlanError::UnderqualifiedColumnName(
+                                col.as_str().to_string(),
+                            )),
+                        });
+                    }
+                    (col, qual) => (qual.to_vec(), col.as_str().to_string()),
+                };
+
+                let qual_name = UnresolvedItemName(qual);
+
+                let (mut fully_qualified_name, desc) =
+                    server_catalog.resolve(qual_name).map_err(|e| {
+                        PlanError::InvalidOptionValue {
+                            option_name: MySqlConfigOptionName::TextColumns.to_ast_string(),
+                            err: Box::new(e),
+                        }
+                    })?;
+
+                if !desc.columns.iter().any(|column| column.name == col) {
+                    return Err(PlanError::InvalidOptionValue {
+                        option_name: MySqlConfigOptionName::TextColumns.to_ast_string(),
+                        err: Box::new(PlanError::UnknownColumn {
+                            table: Some(
+                                normalize::unresolved_item_name(fully_qualified_name)
+                                    .expect("known to be of valid len"),
+                            ),
+                            column: mz_repr::ColumnName::from(col),
+                        }),
+                    });
+                }
+
+                // Rewrite fully qualified name.
+                fully_qualified_name.0.push(col.as_str().to_string().into());
+                *name = fully_qualified_name;
+
+                let new = text_cols_dict
+                    .entry((desc.schema_name.clone(), desc.name.clone()))
+                    .or_default()
+                    .insert(col.as_str().to_string());
+
+                if !new {
+                    return Err(PlanError::InvalidOptionValue {
+                        option_name: MySqlConfigOptionName::TextColumns.to_ast_string(),
+                        err: Box::new(PlanError::UnexpectedDuplicateReference {
+                            name: name.clone(),
+                        }),
+                    });
+                }
+            }
+
+            // Normalize options to contain full qualified values.
+            if let Some(text_cols_option) = options
+                .iter_mut()
+                .find(|option| option.name == MySqlConfigOptionName::TextColumns)
+            {
+                let seq = text_columns
+                    .into_iter()
+                    .map(WithOptionValue::UnresolvedItemName)
+                    .collect();
+                text_cols_option.value = Some(WithOptionValue::Sequence(seq));
+            }
+
+            // Aggregate all unrecognized types.
+            let mut unsupported_cols = vec![];
+
+            // Now that we have an explicit list of validated requested subsources we can create them
+            for (upstream_name, subsource_name, table) in validated_requested_subsources.into_iter()
+            {
+                let table_text_cols =
+                    text_cols_dict.get(&(table.schema_name.clone(), table.name.clone()));
+
+                // Figure out the schema of the subsource
+                let mut columns = vec![];
+                for c in table.columns.iter() {
+                    let name = Ident::new(c.name.clone());
+                    let ty = match table_text_cols {
+                        Some(names) if names.contains(&c.name) => mz_pgrepr::Type::Text,
+                        _ => match crate::plan::statement::mysql_column_type(c) {
+                            Some(t) => t,
+                            None => {
+                                let mut full_name = upstream_name.0.clone();
+                                full_name.push(name);
+                                unsupported_cols.push((
+                                    UnresolvedItemName(full_name).to_ast_string(),
+                                    c.column_type.clone(),
+                                ));
+                                continue;
+                            }
+                        },
+                    };
+
+                    let data_type = scx.resolve_type(ty)?;
+                    let mut options = vec![];
+
+                    if !c.nullable {
+                        options.push(mz_sql_parser::ast::ColumnOptionDef {
+                            name: None,
+                            option: mz_sql_parser::ast::ColumnOption::NotNull,
+                        });
+                    }
+
+                    columns.push(ColumnDef {
+                        name,
+                        data_type,
+                        collation: None,
+                        options,
+                    });
+                }
+
+                let mut constraints = vec![];
+                for key in table.keys.iter() {
+                    let constraint = mz_sql_parser::ast::TableConstraint::Unique {
+                        name: Some(Ident::new(key.name.clone())),
+                        columns: key.columns.iter().cloned().map(Ident::new).collect(),
+                        is_primary: key.is_primary,
+                        // MySQL unique indexes never consider NULLs equal.
+                        nulls_not_distinct: false,
+                    };
+
+                    // We take the first constraint available to be the primary key.
+                    if key.is_primary {
+                        constraints.insert(0, constraint);
+                    } else {
+                        constraints.push(constraint);
+                    }
+                }
+
+                // Create the targeted AST node for the original CREATE SOURCE statement
+                let transient_id = GlobalId::Transient(get_transient_subsource_id());
+
+                let subsource =
+                    scx.allocate_resolved_item_name(transient_id, subsource_name.clone())?;
+
+                targeted_subsources.push(CreateSourceSubsource {
+                    reference: upstream_name,
+                    subsource: Some(DeferredItemName::Named(subsource)),
+                });
+
+                // Create the subsource statement
+                let subsource = CreateSubsourceStatement {
+                    name: subsource_name,
+                    columns,
+                    constraints,
+                    if_not_exists: false,
+                    with_options: vec![CreateSubsourceOption {
+                        name: CreateSubsourceOptionName::References,
+                        value: Some(WithOptionValue::Value(Value::Boolean(true))),
+                    }],
+                };
+                subsources.push((transient_id, subsource));
+            }
+
+            if !unsupported_cols.is_empty() {
+                return Err(PlanError::UnrecognizedTypeInMySqlSource {
+                    cols: unsupported_cols,
+                });
+            }
+
+            *referenced_subsources = Some(ReferencedSubsources::SubsetTables(targeted_subsources));
+
+            // Remove any old detail references
+            options
+                .retain(|MySqlConfigOption { name, .. }| name != &MySqlConfigOptionName::Details);
+            let details = MySqlSourceDetails {
+                tables,
+                server_uuid,
+                // The source connects to the server as a replica, which requires a
+                // server ID that is unique among all of the server's replicas.
+                server_id: rand::random::<u32>().max(1),
+            };
+            options.push(MySqlConfigOption {
+                name: MySqlConfigOptionName::Details,
+                value: Some(WithOptionValue::Value(Value::String(hex::encode(
+                    details.into_proto().encode_to_vec(),
+                )))),
+            })
+        }
         CreateSourceConnection::LoadGenerator { generator, options } => {
             let scx = StatementContext::new(None, &*catalog);
 
@@ -0,0 +1,283 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Storage of role passwords.
+//!
+//! Passwords are never stored directly. Instead, we store the SCRAM-SHA-256
+//! verifier described in [RFC 5802] and [RFC 7677], in the same textual format
+//! that PostgreSQL uses in `pg_authid.rolpassword`. The verifier is sufficient
+//! to authenticate a client, via either a SCRAM exchange or a cleartext
+//! password, but not to impersonate one.
+//!
+//! [RFC 5802]: https://www.rfc-editor.org/rfc/rfc5802
+//! [RFC 7677]: https://www.rfc-editor.org/rfc/rfc7677
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use hmac::{Hmac, Mac};
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+
+/// The prefix that identifies a SCRAM-SHA-256 secret.
+const SCRAM_SHA_256: &str = "SCRAM-SHA-256";
+/// The number of PBKDF2 iterations used for new secrets. This matches
+/// PostgreSQL's default for `scram_iterations`.
+const DEFAULT_ITERATIONS: u32 = 4096;
+/// The number of random bytes in a new secret's salt.
+const SALT_LENGTH: usize = 16;
+
+/// The verifier that is stored in place of a role's password.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ScramSecret {
+    iterations: u32,
+    salt: Vec<u8>,
+    stored_key: [u8; 32],
+    server_key: [u8; 32],
+}
+
+impl ScramSecret {
+    /// Derives a secret for `password` with a random salt.
+    pub fn new(password: &str) -> ScramSecret {
+        let mut salt = vec![0; SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        ScramSecret::with_salt(password, salt, DEFAULT_ITERATIONS)
+    }
+
+    /// Derives a secret for `password` with the specified salt and iteration
+    /// count.
+    pub fn with_salt(password: &str, salt: Vec<u8>, iterations: u32) -> ScramSecret {
+        let salted_password = hi(&normalize(password), &salt, iterations);
+        let client_key = hmac(&salted_password, b"Client Key");
+        ScramSecret {
+            iterations,
+            salt,
+            stored_key: Sha256::digest(client_key).into(),
+            server_key: hmac(&salted_password, b"Server Key"),
+        }
+    }
+
+    /// Generates a secret for the unknown role `user` that no password
+    /// matches.
+    ///
+    /// Authentication attempts for unknown roles are run against a mock secret
+    /// so that clients cannot determine whether a role exists. Like
+    /// PostgreSQL, the mock secret is derived from a server-wide nonce and the
+    /// role name, so that repeated attempts for the same role observe the same
+    /// salt, just as they would for a role that exists.
+    pub fn mock(user: &str) -> ScramSecret {
+        static MOCK_AUTH_NONCE: Lazy<[u8; 32]> = Lazy::new(|| {
+            let mut nonce = [0; 32];
+            rand::thread_rng().fill_bytes(&mut nonce);
+            nonce
+        });
+        let derive = |label: &[u8]| {
+            let mut mac = Hmac::<Sha256>::new_from_slice(&*MOCK_AUTH_NONCE)
+                .expect("HMAC accepts all key sizes");
+            mac.update(label);
+            mac.update(user.as_bytes());
+            <[u8; 32]>::from(mac.finalize().into_bytes())
+        };
+        ScramSecret {
+            iterations: DEFAULT_ITERATIONS,
+            salt: derive(b"salt")[..SALT_LENGTH].to_vec(),
+            stored_key: derive(b"stored key"),
+            server_key: derive(b"server key"),
+        }
+    }
+
+    /// Reports whether `password` is the password from which this secret
+    /// was derived.
+    pub fn verify_password(&self, password: &str) -> bool {
+        let candidate = ScramSecret::with_salt(password, self.salt.clone(), self.iterations);
+        constant_time_eq(&candidate.stored_key, &self.stored_key)
+            && constant_time_eq(&candidate.server_key, &self.server_key)
+    }
+
+    /// Returns the number of PBKDF2 iterations used to derive the secret.
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Returns the salt used to derive the secret.
+    pub fn salt(&self) -> &[u8] {
+        &self.salt
+    }
+
+    /// Returns the `StoredKey`, which verifies a client's proof.
+    pub fn stored_key(&self) -> &[u8; 32] {
+        &self.stored_key
+    }
+
+    /// Returns the `ServerKey`, which proves the server's identity to a client.
+    pub fn server_key(&self) -> &[u8; 32] {
+        &self.server_key
+    }
+}
+
+impl fmt::Debug for ScramSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Avoid leaking the keys into logs.
+        f.debug_struct("ScramSecret")
+            .field("iterations", &self.iterations)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Formats the secret in the same textual format that PostgreSQL uses in
+/// `pg_authid.rolpassword`.
+impl fmt::Display for ScramSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}${}:{}${}:{}",
+            SCRAM_SHA_256,
+            self.iterations,
+            base64::encode(&self.salt),
+            base64::encode(self.stored_key),
+            base64::encode(self.server_key),
+        )
+    }
+}
+
+impl FromStr for ScramSecret {
+    type Err = MalformedScramSecret;
+
+    fn from_str(s: &str) -> Result<ScramSecret, MalformedScramSecret> {
+        fn parse(s: &str) -> Option<ScramSecret> {
+            let s = s.strip_prefix(SCRAM_SHA_256)?.strip_prefix('$')?;
+            let (params, keys) = s.split_once('$')?;
+            let (iterations, salt) = params.split_once(':')?;
+            let (stored_key, server_key) = keys.split_once(':')?;
+            Some(ScramSecret {
+                iterations: iterations.parse().ok()?,
+                salt: base64::decode(salt).ok()?,
+                stored_key: base64::decode(stored_key).ok()?.try_into().ok()?,
+                server_key: base64::decode(server_key).ok()?.try_into().ok()?,
+            })
+        }
+        parse(s).ok_or(MalformedScramSecret)
+    }
+}
+
+impl Serialize for ScramSecret {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScramSecret {
+    fn deserialize<D>(deserializer: D) -> Result<ScramSecret, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}
+
+/// An error indicating that a string is not a valid [`ScramSecret`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MalformedScramSecret;
+
+impl fmt::Display for MalformedScramSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("malformed SCRAM secret")
+    }
+}
+
+impl Error for MalformedScramSecret {}
+
+/// Compares two byte slices in constant time with respect to their contents.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && openssl::memcmp::eq(a, b)
+}
+
+/// Normalizes a password with SASLprep, as RFC 5802 requires.
+///
+/// Like PostgreSQL, passwords that cannot be normalized are used as is.
+fn normalize(password: &str) -> Vec<u8> {
+    match stringprep::saslprep(password) {
+        Ok(password) => password.into_owned().into_bytes(),
+        Err(_) => password.as_bytes().to_vec(),
+    }
+}
+
+/// The PBKDF2 function with HMAC-SHA-256 as the pseudorandom function,
+/// producing a single block of output.
+fn hi(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(password).expect("HMAC accepts all key sizes");
+    mac.update(salt);
+    mac.update(&[0, 0, 0, 1]);
+    let mut prev: [u8; 32] = mac.finalize().into_bytes().into();
+    let mut result = prev;
+    for _ in 1..iterations {
+        prev = hmac(password, &prev);
+        for (r, p) in result.iter_mut().zip(prev) {
+            *r ^= p;
+        }
+    }
+    result
+}
+
+fn hmac(key: &[u8], message: &[u8]) -> [u8; 32] {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts all key sizes");
+    mac.update(message);
+    mac.finalize().into_bytes().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // too slow
+    fn test_scram_secret() {
+        let secret = ScramSecret::new("hunter2");
+        assert!(secret.verify_password("hunter2"));
+        assert!(!secret.verify_password("hunter3"));
+        assert!(!ScramSecret::mock("alice").verify_password(""));
+
+        // Mock secrets for the same role are indistinguishable from one
+        // another, as a real role's secret would be.
+        assert_eq!(ScramSecret::mock("alice"), ScramSecret::mock("alice"));
+        assert_ne!(
+            ScramSecret::mock("alice").salt(),
+            ScramSecret::mock("bob").salt()
+        );
+
+        // Secrets survive a round trip through their textual representation.
+        let parsed: ScramSecret = secret.to_string().parse().unwrap();
+        assert_eq!(parsed, secret);
+        let json = serde_json::to_string(&secret).unwrap();
+        assert_eq!(serde_json::from_str::<ScramSecret>(&json).unwrap(), secret);
+
+        // Secrets are compatible with those PostgreSQL generates.
+        let parsed: ScramSecret = postgres_protocol::password::scram_sha_256(b"hunter2")
+            .parse()
+            .unwrap();
+        assert!(parsed.verify_password("hunter2"));
+
+        for malformed in [
+            "",
+            "md5abcdef",
+            "SCRAM-SHA-256$4096:c2FsdA==",
+            "SCRAM-SHA-256$x:c2FsdA==$AAAA:AAAA",
+            "SCRAM-SHA-256$4096:c2FsdA==$AAAA:AAAA",
+        ] {
+            assert_eq!(malformed.parse::<ScramSecret>(), Err(MalformedScramSecret));
+        }
+    }
+}
@@ -713,6 +713,36 @@ static ENABLE_FORMAT_JSON: ServerVar<bool> = ServerVar {
     safe: true,
 };
 
+/// Feature flag indicating whether `FILE` sinks are enabled.
+static ENABLE_FILE_SINKS: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("enable_file_sinks"),
+    value: &false,
+    description: "Feature flag indicating whether `FILE` sinks are enabled (Materialize).",
+    internal: true,
+    safe: true,
+};
+
+/// Feature flag indicating whether webhook sources that do not validate
+/// requests may be created.
+static ENABLE_UNVALIDATED_WEBHOOK_SOURCES: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("enable_unvalidated_webhook_sources"),
+    value: &false,
+    description: "Feature flag indicating whether webhook sources without a VALIDATE USING clause are enabled (Materialize).",
+    internal: true,
+    safe: true,
+};
+
+/// Feature flag indicating whether tables may be declared with `PRIMARY KEY`
+/// and `UNIQUE` constraints. Declared constraints are always enforced.
+static ENABLE_TABLE_KEYS: ServerVar<bool> = ServerVar {
+    name: UncasedStr::new("enable_table_keys"),
+    value: &false,
+    description: "Feature flag indicating whether tables may be declared with PRIMARY KEY \
+                  and UNIQUE constraints (Materialize).",
+    internal: true,
+    safe: true,
+};
+
 /// Feature flag indicating whether real time recency is enabled.
 static REAL_TIME_RECENCY: ServerVar<bool> = ServerVar {
     name: UncasedStr::new("real_time_recency"),
@@ -1561,6 +1591,9 @@ impl Default for SystemVars {
             .with_var(&ENABLE_WITH_MUTUALLY_RECURSIVE)
             .with_var(&ENABLE_MONOTONIC_ONESHOT_SELECTS)
             .with_var(&ENABLE_FORMAT_JSON)
+            .with_var(&ENABLE_FILE_SINKS)
+            .with_var(&ENABLE_UNVALIDATED_WEBHOOK_SOURCES)
+            .with_var(&ENABLE_TABLE_KEYS)
             .with_var(&ENABLE_LD_RBAC_CHECKS)
             .with_var(&ENABLE_RBAC_CHECKS)
             .with_var(&PG_REPLICATION_CONNECT_TIMEOUT)
@@ -1931,6 +1964,48 @@ impl SystemVars {
             .expect("valid parameter value")
     }
 
+    /// Returns the `enable_file_sinks` configuration parameter.
+    pub fn enable_file_sinks(&self) -> bool {
+        *self.expect_value(&ENABLE_FILE_SINKS)
+    }
+
+    /// Sets the `enable_file_sinks` configuration parameter.
+    pub fn set_enable_file_sinks(&mut self, value: bool) -> bool {
+        self.vars
+            .get_mut(ENABLE_FILE_SINKS.name)
+            .expect("var known to exist")
+            .set(VarInput::Flat(value.format().as_str()))
+            .expect("valid parameter value")
+    }
+
+    /// Returns the `enable_unvalidated_webhook_sources` configuration parameter.
+    pub fn enable_unvalidated_webhook_sources(&self) -> bool {
+        *self.expect_value(&ENABLE_UNVALIDATED_WEBHOOK_SOURCES)
+    }
+
+    /// Sets the `enable_unvalidated_webhook_sources` configuration parameter.
+    pub fn set_enable_unvalidated_webhook_sources(&mut self, value: bool) -> bool {
+        self.vars
+            .get_mut(ENABLE_UNVALIDATED_WEBHOOK_SOURCES.name)
+            .expect("var known to exist")
+            .set(VarInput::Flat(value.format().as_str()))
+            .expect("valid parameter value")
+    }
+
+    /// Returns the `enable_table_keys` configuration parameter.
+    pub fn enable_table_keys(&self) -> bool {
+        *self.expect_value(&ENABLE_TABLE_KEYS)
+    }
+
+    /// Sets the `enable_table_keys` configuration parameter.
+    pub fn set_enable_table_keys(&mut self, value: bool) -> bool {
+        self.vars
+            .get_mut(ENABLE_TABLE_KEYS.name)
+            .expect("var known to exist")
+            .set(VarInput::Flat(value.format().as_str()))
+            .expect("valid parameter value")
+    }
+
     /// Returns the `enable_ld_rbac_checks` configuration parameter.
     pub fn enable_ld_rbac_checks(&self) -> bool {
         *self.expect_value(&ENABLE_LD_RBAC_CHECKS)
@@ -72,7 +72,7 @@ use mz_ore::tracing::TracingHandle;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::PersistConfig;
 use mz_persist_client::PersistLocation;
-use mz_pgrepr::{oid, Interval, Jsonb, Numeric, UInt2, UInt4, UInt8, Value};
+use mz_pgrepr::{oid, Inet, Interval, Jsonb, Numeric, UInt2, UInt4, UInt8, Value};
 use mz_repr::adt::date::Date;
 use mz_repr::adt::mz_acl_item::MzAclItem;
 use mz_repr::adt::numeric;
@@ -403,6 +403,8 @@ impl<'a> FromSql<'a> for Slt {
                 DateTime::<Utc>::from_sql(ty, raw)?.try_into()?,
             )),
             PgType::UUID => Self(Value::Uuid(Uuid::from_sql(ty, raw)?)),
+            PgType::INET => Self(Value::Inet(Inet::from_sql(ty, raw)?)),
+            PgType::CIDR => Self(Value::Cidr(Inet::from_sql(ty, raw)?)),
             PgType::RECORD => {
                 let num_fields = read_be_i32(&mut raw)?;
                 let mut tuple = vec![];
@@ -429,6 +431,7 @@ impl<'a> FromSql<'a> for Slt {
             }
 
             _ => match ty.kind() {
+                PgKind::Enum(_) => Self(Value::Text(types::text_from_sql(raw)?.to_string())),
                 PgKind::Array(arr_type) => {
                     let arr = types::array_from_sql(raw)?;
                     let elements: Vec<Option<Value>> = arr
@@ -476,7 +479,7 @@ impl<'a> FromSql<'a> for Slt {
     }
     fn accepts(ty: &PgType) -> bool {
         match ty.kind() {
-            PgKind::Array(_) | PgKind::Composite(_) => return true,
+            PgKind::Array(_) | PgKind::Composite(_) | PgKind::Enum(_) => return true,
             _ => {}
         }
         match ty.oid() {
@@ -513,6 +516,8 @@ impl<'a> FromSql<'a> for Slt {
                 | PgType::TIMESTAMP
                 | PgType::TIMESTAMPTZ
                 | PgType::UUID
+                | PgType::INET
+                | PgType::CIDR
                 | PgType::INT4_RANGE
                 | PgType::INT4_RANGE_ARRAY
                 | PgType::INT8_RANGE
@@ -64,12 +64,13 @@ use crate::client::{
 use crate::controller::rehydration::RehydratingStorageClient;
 use crate::healthcheck;
 use crate::metrics::StorageControllerMetrics;
+use crate::source::persist_source::adjust_to_arity;
 use crate::types::errors::DataflowError;
 use crate::types::instances::StorageInstanceContext;
 use crate::types::instances::StorageInstanceId;
 use crate::types::parameters::StorageParameters;
 use crate::types::sinks::{
-    MetadataUnfilled, ProtoDurableExportMetadata, SinkAsOf, StorageSinkDesc,
+    MetadataUnfilled, ProtoDurableExportMetadata, SinkAsOf, StorageSinkConnection, StorageSinkDesc,
 };
 use crate::types::sources::{IngestionDescription, SourceData, SourceEnvelope, SourceExport};
 
@@ -153,6 +154,10 @@ pub struct CollectionDescription<T> {
     /// A GlobalId to use for this collection to use for the status collection.
     /// Used to keep track of source status/error information.
     pub status_collection_id: Option<GlobalId>,
+    /// The values of trailing columns that rows written under an earlier,
+    /// narrower `desc` are missing. Columns past the end of this row are
+    /// `NULL`.
+    pub missing_values: Row,
 }
 
 impl<T> CollectionDescription<T> {
@@ -201,6 +206,7 @@ impl<T> From<RelationDesc> for CollectionDescription<T> {
             data_source: DataSource::Other,
             since: None,
             status_collection_id: None,
+            missing_values: Row::default(),
         }
     }
 }
@@ -311,6 +317,20 @@ pub trait StorageController: Debug + Send {
         collections: Vec<(GlobalId, CollectionDescription<Self::Timestamp>)>,
     ) -> Result<(), StorageError>;
 
+    /// Changes the schema of the table `id` to `new_desc`.
+    ///
+    /// Subsequent appends to `id` are written with the new schema and
+    /// dataflows that import `id` after this call observe rows of the new
+    /// shape. Data already written to the collection is not rewritten;
+    /// instead, readers pad rows written under an earlier schema with
+    /// `missing_values`, see [`CollectionDescription::missing_values`].
+    async fn alter_table_desc(
+        &mut self,
+        id: GlobalId,
+        new_desc: RelationDesc,
+        missing_values: Row,
+    ) -> Result<(), StorageError>;
+
     /// Acquire an immutable reference to the export state, should it exist.
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError>;
 
@@ -564,6 +584,9 @@ pub struct CollectionMetadata {
     pub status_shard: Option<ShardId>,
     /// The `RelationDesc` that describes the contents of the `data_shard`.
     pub relation_desc: RelationDesc,
+    /// The values used to pad rows in the `data_shard` that are narrower than
+    /// `relation_desc`.
+    pub missing_values: Row,
 }
 
 impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
@@ -575,6 +598,7 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
             remap_shard: self.remap_shard.map(|s| s.to_string()),
             status_shard: self.status_shard.map(|s| s.to_string()),
             relation_desc: Some(self.relation_desc.into_proto()),
+            missing_values: Some(self.missing_values.into_proto()),
         }
     }
 
@@ -599,6 +623,9 @@ impl RustType<ProtoCollectionMetadata> for CollectionMetadata {
             relation_desc: value
                 .relation_desc
                 .into_rust_if_some("ProtoCollectionMetadata::relation_desc")?,
+            missing_values: value
+                .missing_values
+                .into_rust_if_some("ProtoCollectionMetadata::missing_values")?,
         })
     }
 }
@@ -664,6 +691,9 @@ impl RustType<ProtoDurableCollectionMetadata> for DurableCollectionMetadata {
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DurableExportMetadata<T> {
     pub initial_as_of: SinkAsOf<T>,
+    /// The shard in which the sink records its progress, for sinks that
+    /// record their progress in persist.
+    pub progress_shard: Option<ShardId>,
 }
 
 impl PartialOrd for DurableExportMetadata<mz_repr::Timestamp> {
@@ -686,6 +716,7 @@ impl RustType<ProtoDurableExportMetadata> for DurableExportMetadata<mz_repr::Tim
     fn into_proto(&self) -> ProtoDurableExportMetadata {
         ProtoDurableExportMetadata {
             initial_as_of: Some(self.initial_as_of.into_proto()),
+            progress_shard: self.progress_shard.into_proto(),
         }
     }
 
@@ -694,6 +725,7 @@ impl RustType<ProtoDurableExportMetadata> for DurableExportMetadata<mz_repr::Tim
             initial_as_of: proto
                 .initial_as_of
                 .into_rust_if_some("ProtoDurableExportMetadata::initial_as_of")?,
+            progress_shard: proto.progress_shard.into_rust()?,
         })
     }
 }
@@ -716,8 +748,14 @@ impl Arbitrary for DurableExportMetadata<mz_repr::Timestamp> {
     type Parameters = ();
 
     fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
-        (any::<SinkAsOf<mz_repr::Timestamp>>(),)
-            .prop_map(|(initial_as_of,)| Self { initial_as_of })
+        (
+            any::<SinkAsOf<mz_repr::Timestamp>>(),
+            any::<Option<ShardId>>(),
+        )
+            .prop_map(|(initial_as_of, progress_shard)| Self {
+                initial_as_of,
+                progress_shard,
+            })
             .boxed()
     }
 }
@@ -1235,6 +1273,7 @@ where
                     data_shard: collection_shards.data_shard,
                     status_shard,
                     relation_desc: description.desc.clone(),
+                    missing_values: description.missing_values.clone(),
                 };
 
                 Ok((id, description, metadata))
@@ -1556,6 +1595,48 @@ where
         Ok(())
     }
 
+    async fn alter_table_desc(
+        &mut self,
+        id: GlobalId,
+        new_desc: RelationDesc,
+        missing_values: Row,
+    ) -> Result<(), StorageError> {
+        let collection = self.collection(id)?;
+        if !matches!(collection.description.data_source, DataSource::Other) {
+            return Err(StorageError::InvalidUsage(format!(
+                "cannot alter the schema of non-table collection {id}"
+            )));
+        }
+        let metadata = collection.collection_metadata.clone();
+
+        let persist_client = self
+            .persist
+            .open(metadata.persist_location.clone())
+            .await
+            .unwrap();
+        let write = persist_client
+            .open_writer(
+                metadata.data_shard,
+                format!("controller data for {id}").as_str(),
+                Arc::new(new_desc.clone()),
+                Arc::new(UnitSchema),
+            )
+            .await
+            .expect("invalid persist usage");
+
+        // Appends are processed by the write worker in the order they are
+        // sent, so all appends issued after this point use the new handle.
+        self.state.persist_write_handles.update(id, write);
+
+        let collection = self.collection_mut(id)?;
+        collection.description.desc = new_desc.clone();
+        collection.description.missing_values = missing_values.clone();
+        collection.collection_metadata.relation_desc = new_desc;
+        collection.collection_metadata.missing_values = missing_values;
+
+        Ok(())
+    }
+
     fn export(&self, id: GlobalId) -> Result<&ExportState<Self::Timestamp>, StorageError> {
         self.state
             .exports
@@ -1690,12 +1771,19 @@ where
 
             let storage_dependencies = vec![from_id];
 
+            // File sinks record their progress in a shard of their own, which
+            // must survive restarts of the controller.
+            let progress_shard = match &description.sink.connection {
+                StorageSinkConnection::File(_) => Some(ShardId::new()),
+                StorageSinkConnection::Kafka(_) | StorageSinkConnection::Postgres(_) => None,
+            };
             let mut durable_export_data = MetadataExportFetcher::get_stash_collection()
                 .insert_key_without_overwrite(
                     &mut self.state.stash,
                     id,
                     DurableExportMetadata {
                         initial_as_of: description.sink.as_of.clone(),
+                        progress_shard,
                     },
                 )
                 .await?;
@@ -1740,6 +1828,7 @@ where
                     as_of: durable_export_data.initial_as_of,
                     status_id,
                     from_storage_metadata,
+                    progress_shard: durable_export_data.progress_shard,
                 },
             };
 
@@ -1850,13 +1939,22 @@ where
             .await
             .expect("invalid persist usage");
 
+        // Rows written before a table was altered to add columns are narrower
+        // than its current shape and are padded with its missing values,
+        // matching how they are read by dataflows.
+        let arity = metadata.relation_desc.arity();
         match read_handle.snapshot_and_fetch(as_of).await {
             Ok(contents) => {
                 let mut snapshot = Vec::with_capacity(contents.len());
                 for ((data, _), _, diff) in contents {
                     // TODO(petrosagg): We should accumulate the errors too and let the user
                     // interprret the result
-                    let row = data.expect("invalid protobuf data").0?;
+                    let mut row = data.expect("invalid protobuf data").0?;
+                    if row.iter().count() != arity {
+                        let mut datums = row.iter().collect_vec();
+                        adjust_to_arity(&mut datums, arity, &metadata.missing_values);
+                        row = Row::pack(datums);
+                    }
                     snapshot.push((row, diff));
                 }
                 Ok(snapshot)
@@ -7,7 +7,7 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::time::Duration;
 
 use anyhow::{anyhow, bail, Context};
@@ -20,8 +20,9 @@ use mz_ore::collections::CollectionExt;
 
 use crate::types::connections::ConnectionContext;
 use crate::types::sinks::{
-    KafkaConsistencyConfig, KafkaSinkConnection, KafkaSinkConnectionBuilder,
+    FileSinkConnection, KafkaConsistencyConfig, KafkaSinkConnection, KafkaSinkConnectionBuilder,
     KafkaSinkConnectionRetention, KafkaSinkFormat, KafkaSinkProgressConnection,
+    PostgresSinkConnection, PostgresSinkConnectionBuilder, PublishedSchemaFormat,
     PublishedSchemaInfo, StorageSinkConnection, StorageSinkConnectionBuilder,
 };
 
@@ -34,6 +35,13 @@ pub async fn build_sink_connection(
 ) -> Result<StorageSinkConnection, anyhow::Error> {
     match builder {
         StorageSinkConnectionBuilder::Kafka(k) => build_kafka(k, connection_context).await,
+        StorageSinkConnectionBuilder::File(f) => {
+            Ok(StorageSinkConnection::File(FileSinkConnection {
+                location: f.location,
+                value_desc: f.value_desc,
+            }))
+        }
+        StorageSinkConnectionBuilder::Postgres(p) => build_postgres(p, connection_context).await,
     }
 }
 
@@ -262,6 +270,29 @@ async fn build_kafka(
             Some(PublishedSchemaInfo {
                 key_schema_id,
                 value_schema_id,
+                format: PublishedSchemaFormat::Avro,
+            })
+        }
+        KafkaSinkFormat::Protobuf {
+            key_schema,
+            value_schema,
+            csr_connection,
+        } => {
+            let ccsr = csr_connection.connect(&connection_context).await?;
+            let (key_schema_id, value_schema_id) = publish_kafka_schemas(
+                &ccsr,
+                &builder.topic_name,
+                key_schema.as_deref(),
+                Some(mz_ccsr::SchemaType::Protobuf),
+                &value_schema,
+                mz_ccsr::SchemaType::Protobuf,
+            )
+            .await
+            .context("error publishing kafka schemas for sink")?;
+            Some(PublishedSchemaInfo {
+                key_schema_id,
+                value_schema_id,
+                format: PublishedSchemaFormat::Protobuf,
             })
         }
         KafkaSinkFormat::Json => None,
@@ -295,3 +326,88 @@ async fn build_kafka(
         fuel: builder.fuel,
     }))
 }
+
+async fn build_postgres(
+    builder: PostgresSinkConnectionBuilder,
+    connection_context: ConnectionContext,
+) -> Result<StorageSinkConnection, anyhow::Error> {
+    let client = builder
+        .connection
+        .config(&*connection_context.secrets_reader)
+        .await?
+        .connect("postgres_sink_purification")
+        .await?;
+
+    // Verify that the target table exists and has a column for every column
+    // of the sinked relation.
+    let rows = client
+        .query(
+            "SELECT column_name::text FROM information_schema.columns
+            WHERE table_schema = $1 AND table_name = $2",
+            &[&builder.schema, &builder.table],
+        )
+        .await
+        .context("error describing postgres table for sink")?;
+    if rows.is_empty() {
+        bail!(
+            "table {}.{} does not exist or is not accessible",
+            builder.schema,
+            builder.table
+        );
+    }
+    let columns: BTreeSet<String> = rows.iter().map(|row| row.get(0)).collect();
+    for name in builder.value_desc.iter_names() {
+        if !columns.contains(name.as_str()) {
+            bail!(
+                "table {}.{} does not have a column named {}",
+                builder.schema,
+                builder.table,
+                name
+            );
+        }
+    }
+
+    // `INSERT ... ON CONFLICT` requires a unique index covering exactly the
+    // key columns.
+    let mut key_columns: Vec<String> = builder
+        .key_indices
+        .iter()
+        .map(|i| builder.value_desc.get_name(*i).to_string())
+        .collect();
+    key_columns.sort();
+    let row = client
+        .query_opt(
+            "SELECT 1 FROM pg_index i
+            JOIN pg_class c ON c.oid = i.indrelid
+            JOIN pg_namespace n ON n.oid = c.relnamespace
+            WHERE n.nspname = $1 AND c.relname = $2
+            AND i.indisunique AND i.indpred IS NULL AND i.indexprs IS NULL
+            AND (
+                SELECT array_agg(a.attname::text ORDER BY a.attname::text)
+                FROM pg_attribute a
+                WHERE a.attrelid = c.oid AND a.attnum = ANY(i.indkey)
+            ) = $3::text[]
+            LIMIT 1",
+            &[&builder.schema, &builder.table, &key_columns],
+        )
+        .await
+        .context("error inspecting indexes of postgres table for sink")?;
+    if row.is_none() {
+        bail!(
+            "table {}.{} does not have a unique constraint on the sink key ({})",
+            builder.schema,
+            builder.table,
+            key_columns.join(", ")
+        );
+    }
+
+    Ok(StorageSinkConnection::Postgres(PostgresSinkConnection {
+        connection_id: builder.connection_id,
+        connection: builder.connection,
+        schema: builder.schema,
+        table: builder.table,
+        key_indices: builder.key_indices,
+        value_desc: builder.value_desc,
+        environment_id: builder.environment_id,
+    }))
+}
@@ -131,6 +131,8 @@ where
     let name = source_id.to_string();
     let mfp_pushdown = map_filter_project.as_ref().map(|x| MfpPushdown::new(*x));
     let desc = RelationDescHack::new(&metadata.relation_desc);
+    let arity = metadata.relation_desc.arity();
+    let missing_values = metadata.missing_values;
     let (fetched, token) = shard_source(
         &mut scope.clone(),
         &name,
@@ -148,13 +150,27 @@ where
             })
         },
     );
-    let rows = decode_and_mfp(&fetched, &name, until, map_filter_project, yield_fn);
+    let rows = decode_and_mfp(
+        &fetched,
+        &name,
+        arity,
+        missing_values,
+        until,
+        map_filter_project,
+        yield_fn,
+    );
     (rows, token)
 }
 
+/// Decodes the rows of `fetched` and applies `map_filter_project` to them.
+///
+/// Every decoded row is adjusted to have `arity` columns using
+/// `missing_values`, see [`adjust_to_arity`].
 pub fn decode_and_mfp<G, YFn>(
     fetched: &Stream<G, FetchedPart<SourceData, (), Timestamp, Diff>>,
     name: &str,
+    arity: usize,
+    missing_values: Row,
     until: Antichain<Timestamp>,
     mut map_filter_project: Option<&mut MfpPlan>,
     yield_fn: YFn,
@@ -209,6 +225,8 @@ where
                 let done = pending_work.front_mut().unwrap().do_work(
                     &mut work,
                     &name,
+                    arity,
+                    &missing_values,
                     start_time,
                     &yield_fn,
                     &until,
@@ -230,6 +248,28 @@ where
     updates_stream
 }
 
+/// Adjusts `datums` to have exactly `arity` columns.
+///
+/// A table that has been altered by `ALTER TABLE ... ADD COLUMN` can contain
+/// rows written under an earlier, narrower shape. These are padded with the
+/// corresponding trailing values of `missing_values`, which holds one datum
+/// per column of the current shape, or `NULL` past its end. Conversely, a
+/// reader built before the alteration truncates newer, wider rows back to the
+/// shape it expects.
+pub fn adjust_to_arity<'a>(datums: &mut Vec<Datum<'a>>, arity: usize, missing_values: &'a Row) {
+    let len = datums.len();
+    if len < arity {
+        let missing = missing_values
+            .iter()
+            .chain(std::iter::repeat(Datum::Null))
+            .skip(len)
+            .take(arity - len);
+        datums.extend(missing);
+    } else {
+        datums.truncate(arity);
+    }
+}
+
 /// Pending work to read from fetched parts
 struct PendingWork {
     /// The time at which the work should happen.
@@ -245,6 +285,8 @@ impl PendingWork {
         &mut self,
         work: &mut usize,
         name: &str,
+        arity: usize,
+        missing_values: &Row,
         start_time: Instant,
         yield_fn: YFn,
         until: &Antichain<Timestamp>,
@@ -267,6 +309,7 @@ impl PendingWork {
                     if let Some(mfp) = map_filter_project {
                         let arena = mz_repr::RowArena::new();
                         let mut datums_local = datum_vec.borrow_with(&row);
+                        adjust_to_arity(&mut datums_local, arity, missing_values);
                         for result in mfp.evaluate(
                             &mut datums_local,
                             &arena,
@@ -299,6 +342,14 @@ impl PendingWork {
                             }
                         }
                     } else {
+                        let mut datums = datum_vec.borrow_with(&row);
+                        let resized = (datums.len() != arity).then(|| {
+                            adjust_to_arity(&mut datums, arity, missing_values);
+                            row_builder.packer().extend(datums.iter());
+                            row_builder.clone()
+                        });
+                        drop(datums);
+                        let row = resized.unwrap_or(row);
                         output.give_at(&self.capability, (Ok(row), time, diff));
                         *work += 1;
                     }
@@ -161,6 +161,7 @@ pub enum Connection {
     Kafka(KafkaConnection),
     Csr(CsrConnection),
     Postgres(PostgresConnection),
+    MySql(MySqlConnection),
     Ssh(SshConnection),
     Aws(AwsConfig),
     AwsPrivatelink(AwsPrivatelinkConnection),
@@ -891,6 +892,173 @@ impl Arbitrary for PostgresConnection {
     }
 }
 
+/// The TLS mode to use when connecting to a MySQL server.
+#[derive(Arbitrary, Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum MySqlSslMode {
+    /// Do not use TLS.
+    Disabled,
+    /// Require TLS, but do not verify the server's certificate.
+    Required,
+    /// Require TLS and verify the server's certificate against the
+    /// configured certificate authority.
+    VerifyCa,
+    /// Like [`MySqlSslMode::VerifyCa`], but additionally verify that the
+    /// server's certificate matches its hostname.
+    VerifyIdentity,
+}
+
+impl RustType<ProtoMySqlSslMode> for MySqlSslMode {
+    fn into_proto(&self) -> ProtoMySqlSslMode {
+        use proto_my_sql_ssl_mode::Kind::*;
+        ProtoMySqlSslMode {
+            kind: Some(match self {
+                MySqlSslMode::Disabled => Disabled(()),
+                MySqlSslMode::Required => Required(()),
+                MySqlSslMode::VerifyCa => VerifyCa(()),
+                MySqlSslMode::VerifyIdentity => VerifyIdentity(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlSslMode) -> Result<Self, TryFromProtoError> {
+        use proto_my_sql_ssl_mode::Kind::*;
+        match proto.kind {
+            Some(Disabled(())) => Ok(MySqlSslMode::Disabled),
+            Some(Required(())) => Ok(MySqlSslMode::Required),
+            Some(VerifyCa(())) => Ok(MySqlSslMode::VerifyCa),
+            Some(VerifyIdentity(())) => Ok(MySqlSslMode::VerifyIdentity),
+            None => Err(TryFromProtoError::missing_field("ProtoMySqlSslMode::kind")),
+        }
+    }
+}
+
+/// A connection to a MySQL server.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct MySqlConnection {
+    /// The hostname of the server.
+    pub host: String,
+    /// The port of the server.
+    pub port: u16,
+    /// The username to authenticate as.
+    pub user: StringOrSecret,
+    /// An optional password for authentication.
+    pub password: Option<GlobalId>,
+    /// A tunnel through which to route traffic.
+    pub tunnel: Tunnel,
+    /// Whether to use TLS for encryption, authentication, or both.
+    pub tls_mode: MySqlSslMode,
+    /// An optional root TLS certificate in PEM format, to verify the server's
+    /// identity.
+    pub tls_root_cert: Option<StringOrSecret>,
+    /// An optional TLS client certificate for authentication.
+    pub tls_identity: Option<TlsIdentity>,
+}
+
+impl MySqlConnection {
+    pub async fn config(
+        &self,
+        secrets_reader: &dyn mz_secrets::SecretsReader,
+    ) -> Result<mz_mysql_util::Config, anyhow::Error> {
+        use mysql_async::{ClientIdentity, OptsBuilder, PathOrBuf, SslOpts};
+
+        let mut opts = OptsBuilder::default()
+            .ip_or_hostname(&self.host)
+            .tcp_port(self.port)
+            .user(Some(self.user.get_string(secrets_reader).await?));
+        if let Some(password) = self.password {
+            let password = secrets_reader.read_string(password).await?;
+            opts = opts.pass(Some(password));
+        }
+
+        let ssl_opts = match self.tls_mode {
+            MySqlSslMode::Disabled => None,
+            MySqlSslMode::Required => Some(
+                SslOpts::default()
+                    .with_danger_accept_invalid_certs(true)
+                    .with_danger_skip_domain_validation(true),
+            ),
+            MySqlSslMode::VerifyCa => {
+                Some(SslOpts::default().with_danger_skip_domain_validation(true))
+            }
+            MySqlSslMode::VerifyIdentity => Some(SslOpts::default()),
+        };
+        if let Some(mut ssl_opts) = ssl_opts {
+            if let Some(tls_root_cert) = &self.tls_root_cert {
+                let tls_root_cert = tls_root_cert.get_string(secrets_reader).await?;
+                ssl_opts =
+                    ssl_opts.with_root_certs(vec![PathOrBuf::from(tls_root_cert.into_bytes())]);
+            }
+            if let Some(tls_identity) = &self.tls_identity {
+                let cert = tls_identity.cert.get_string(secrets_reader).await?;
+                let key = secrets_reader.read_string(tls_identity.key).await?;
+                ssl_opts = ssl_opts.with_client_identity(Some(ClientIdentity::new(
+                    PathOrBuf::from(cert.into_bytes()),
+                    PathOrBuf::from(key.into_bytes()),
+                )));
+            }
+            opts = opts.ssl_opts(Some(ssl_opts));
+        }
+
+        let tunnel = match &self.tunnel {
+            Tunnel::Direct => mz_mysql_util::TunnelConfig::Direct,
+            Tunnel::Ssh(SshTunnel {
+                connection_id,
+                connection,
+            }) => {
+                let secret = secrets_reader.read(*connection_id).await?;
+                let key_set = SshKeyPairSet::from_bytes(&secret)?;
+                let key_pair = key_set.primary().clone();
+                mz_mysql_util::TunnelConfig::Ssh(SshTunnelConfig {
+                    host: connection.host.clone(),
+                    port: connection.port,
+                    user: connection.user.clone(),
+                    key_pair,
+                })
+            }
+            Tunnel::AwsPrivatelink(connection) => {
+                assert!(connection.port.is_none());
+                mz_mysql_util::TunnelConfig::AwsPrivatelink {
+                    connection_id: connection.connection_id,
+                }
+            }
+        };
+
+        Ok(mz_mysql_util::Config::new(opts.into(), tunnel))
+    }
+}
+
+impl RustType<ProtoMySqlConnection> for MySqlConnection {
+    fn into_proto(&self) -> ProtoMySqlConnection {
+        ProtoMySqlConnection {
+            host: self.host.into_proto(),
+            port: self.port.into_proto(),
+            user: Some(self.user.into_proto()),
+            password: self.password.into_proto(),
+            tls_mode: Some(self.tls_mode.into_proto()),
+            tls_root_cert: self.tls_root_cert.into_proto(),
+            tls_identity: self.tls_identity.into_proto(),
+            tunnel: Some(self.tunnel.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlConnection) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlConnection {
+            host: proto.host,
+            port: proto.port.into_rust()?,
+            user: proto.user.into_rust_if_some("ProtoMySqlConnection::user")?,
+            password: proto.password.into_rust()?,
+            tunnel: proto
+                .tunnel
+                .into_rust_if_some("ProtoMySqlConnection::tunnel")?,
+            tls_mode: proto
+                .tls_mode
+                .into_rust_if_some("ProtoMySqlConnection::tls_mode")?,
+            tls_root_cert: proto.tls_root_cert.into_rust()?,
+            tls_identity: proto.tls_identity.into_rust()?,
+        })
+    }
+}
+
 /// Specifies how to tunnel a connection.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum Tunnel {
@@ -22,7 +22,8 @@ use mz_proto::{IntoRustIfSome, ProtoType, RustType, TryFromProtoError};
 use mz_repr::{GlobalId, RelationDesc};
 
 use crate::controller::CollectionMetadata;
-use crate::types::connections::{CsrConnection, KafkaConnection};
+use crate::types::connections::aws::AwsConfig;
+use crate::types::connections::{CsrConnection, KafkaConnection, PostgresConnection};
 
 include!(concat!(
     env!("OUT_DIR"),
@@ -39,11 +40,15 @@ pub struct StorageSinkDesc<S: StorageSinkDescFillState, T = mz_repr::Timestamp>
     pub as_of: SinkAsOf<T>,
     pub status_id: Option<<S as StorageSinkDescFillState>::StatusId>,
     pub from_storage_metadata: <S as StorageSinkDescFillState>::StorageMetadata,
+    /// The shard in which the sink records its progress, for sinks that
+    /// record their progress in persist.
+    pub progress_shard: <S as StorageSinkDescFillState>::ProgressShard,
 }
 
 pub trait StorageSinkDescFillState {
     type StatusId: Debug + Clone + Serialize + for<'a> Deserialize<'a> + Eq + PartialEq;
     type StorageMetadata: Debug + Clone + Serialize + for<'a> Deserialize<'a> + Eq + PartialEq;
+    type ProgressShard: Debug + Clone + Serialize + for<'a> Deserialize<'a> + Eq + PartialEq;
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -51,6 +56,7 @@ pub struct MetadataUnfilled;
 impl StorageSinkDescFillState for MetadataUnfilled {
     type StatusId = GlobalId;
     type StorageMetadata = ();
+    type ProgressShard = ();
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -58,6 +64,7 @@ pub struct MetadataFilled;
 impl StorageSinkDescFillState for MetadataFilled {
     type StatusId = ShardId;
     type StorageMetadata = CollectionMetadata;
+    type ProgressShard = Option<ShardId>;
 }
 
 impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
@@ -73,6 +80,7 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
             any::<SinkAsOf<mz_repr::Timestamp>>(),
             any::<Option<ShardId>>(),
             any::<CollectionMetadata>(),
+            any::<Option<ShardId>>(),
         )
             .prop_map(
                 |(
@@ -83,6 +91,7 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
                     as_of,
                     status_id,
                     from_storage_metadata,
+                    progress_shard,
                 )| {
                     StorageSinkDesc {
                         from,
@@ -92,6 +101,7 @@ impl Arbitrary for StorageSinkDesc<MetadataFilled, mz_repr::Timestamp> {
                         as_of,
                         status_id,
                         from_storage_metadata,
+                        progress_shard,
                     }
                 },
             )
@@ -109,6 +119,7 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
             as_of: Some(self.as_of.into_proto()),
             status_id: self.status_id.into_proto(),
             from_storage_metadata: Some(self.from_storage_metadata.into_proto()),
+            progress_shard: self.progress_shard.into_proto(),
         }
     }
 
@@ -129,6 +140,7 @@ impl RustType<ProtoStorageSinkDesc> for StorageSinkDesc<MetadataFilled, mz_repr:
             from_storage_metadata: proto
                 .from_storage_metadata
                 .into_rust_if_some("ProtoStorageSinkDesc::from_storage_metadata")?,
+            progress_shard: proto.progress_shard.into_rust()?,
         })
     }
 }
@@ -219,6 +231,8 @@ impl RustType<ProtoSinkAsOf> for SinkAsOf<mz_repr::Timestamp> {
 #[derive(Arbitrary, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub enum StorageSinkConnection {
     Kafka(KafkaSinkConnection),
+    File(FileSinkConnection),
+    Postgres(PostgresSinkConnection),
 }
 
 impl StorageSinkConnection {
@@ -227,6 +241,8 @@ impl StorageSinkConnection {
         use StorageSinkConnection::*;
         match self {
             Kafka(KafkaSinkConnection { connection_id, .. }) => Some(*connection_id),
+            File(FileSinkConnection { location, .. }) => location.connection_id(),
+            Postgres(PostgresSinkConnection { connection_id, .. }) => Some(*connection_id),
         }
     }
 
@@ -234,6 +250,8 @@ impl StorageSinkConnection {
     pub fn name(&self) -> &'static str {
         match self {
             StorageSinkConnection::Kafka(_) => "kafka",
+            StorageSinkConnection::File(_) => "file",
+            StorageSinkConnection::Postgres(_) => "postgres",
         }
     }
 }
@@ -244,6 +262,8 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
         ProtoStorageSinkConnection {
             kind: Some(match self {
                 StorageSinkConnection::Kafka(kafka) => Kind::Kafka(kafka.into_proto()),
+                StorageSinkConnection::File(file) => Kind::File(file.into_proto()),
+                StorageSinkConnection::Postgres(postgres) => Kind::Postgres(postgres.into_proto()),
             }),
         }
     }
@@ -255,6 +275,8 @@ impl RustType<ProtoStorageSinkConnection> for StorageSinkConnection {
             .ok_or_else(|| TryFromProtoError::missing_field("ProtoStorageSinkConnection::kind"))?;
         Ok(match kind {
             Kind::Kafka(kafka) => StorageSinkConnection::Kafka(kafka.into_rust()?),
+            Kind::File(file) => StorageSinkConnection::File(file.into_rust()?),
+            Kind::Postgres(postgres) => StorageSinkConnection::Postgres(postgres.into_rust()?),
         })
     }
 }
@@ -398,11 +420,156 @@ impl RustType<ProtoKafkaSinkConnection> for KafkaSinkConnection {
     }
 }
 
+/// A sink that writes Parquet files to an S3 bucket or a local directory.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FileSinkConnection {
+    pub location: FileSinkLocation,
+    pub value_desc: RelationDesc,
+}
+
+impl RustType<ProtoFileSinkConnection> for FileSinkConnection {
+    fn into_proto(&self) -> ProtoFileSinkConnection {
+        ProtoFileSinkConnection {
+            location: Some(self.location.into_proto()),
+            value_desc: Some(self.value_desc.into_proto()),
+        }
+    }
+
+    fn from_proto(proto: ProtoFileSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(FileSinkConnection {
+            location: proto
+                .location
+                .into_rust_if_some("ProtoFileSinkConnection::location")?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoFileSinkConnection::value_desc")?,
+        })
+    }
+}
+
+/// The location to which a file sink writes its files.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum FileSinkLocation {
+    /// A prefix of an S3 bucket, written with the credentials of an AWS
+    /// connection.
+    S3 {
+        connection_id: GlobalId,
+        connection: AwsConfig,
+        /// The bucket to write files to.
+        bucket: String,
+        /// The prefix of the keys of all files, either empty or ending in `/`.
+        prefix: String,
+    },
+    /// A directory on the local filesystem of the replica running the sink.
+    /// Only available in unsafe mode, for testing.
+    LocalDirectory { path: String },
+}
+
+impl FileSinkLocation {
+    /// Returns the ID of the connection used to write to the location, if any.
+    pub fn connection_id(&self) -> Option<GlobalId> {
+        match self {
+            FileSinkLocation::S3 { connection_id, .. } => Some(*connection_id),
+            FileSinkLocation::LocalDirectory { .. } => None,
+        }
+    }
+}
+
+impl RustType<proto_file_sink_connection::Location> for FileSinkLocation {
+    fn into_proto(&self) -> proto_file_sink_connection::Location {
+        use proto_file_sink_connection::Location;
+        match self {
+            FileSinkLocation::S3 {
+                connection_id,
+                connection,
+                bucket,
+                prefix,
+            } => Location::S3(ProtoFileSinkS3Location {
+                connection_id: Some(connection_id.into_proto()),
+                connection: Some(connection.into_proto()),
+                bucket: bucket.clone(),
+                prefix: prefix.clone(),
+            }),
+            FileSinkLocation::LocalDirectory { path } => Location::LocalDirectory(path.clone()),
+        }
+    }
+
+    fn from_proto(proto: proto_file_sink_connection::Location) -> Result<Self, TryFromProtoError> {
+        use proto_file_sink_connection::Location;
+        Ok(match proto {
+            Location::S3(s3) => FileSinkLocation::S3 {
+                connection_id: s3
+                    .connection_id
+                    .into_rust_if_some("ProtoFileSinkS3Location::connection_id")?,
+                connection: s3
+                    .connection
+                    .into_rust_if_some("ProtoFileSinkS3Location::connection")?,
+                bucket: s3.bucket,
+                prefix: s3.prefix,
+            },
+            Location::LocalDirectory(path) => FileSinkLocation::LocalDirectory { path },
+        })
+    }
+}
+
+/// A sink that upserts into a table in a PostgreSQL database.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection {
+    pub connection_id: GlobalId,
+    pub connection: PostgresConnection,
+    /// The schema containing the target table. The sink's progress table is
+    /// created in the same schema.
+    pub schema: String,
+    /// The name of the target table.
+    pub table: String,
+    /// The indices of the columns of `value_desc` that make up the key of the
+    /// target table.
+    pub key_indices: Vec<usize>,
+    pub value_desc: RelationDesc,
+    /// The ID of the environment that owns the sink, used to namespace the
+    /// sink's entry in the progress table.
+    pub environment_id: String,
+}
+
+impl RustType<ProtoPostgresSinkConnection> for PostgresSinkConnection {
+    fn into_proto(&self) -> ProtoPostgresSinkConnection {
+        ProtoPostgresSinkConnection {
+            connection_id: Some(self.connection_id.into_proto()),
+            connection: Some(self.connection.into_proto()),
+            schema: self.schema.clone(),
+            table: self.table.clone(),
+            key_indices: self.key_indices.into_proto(),
+            value_desc: Some(self.value_desc.into_proto()),
+            environment_id: self.environment_id.clone(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPostgresSinkConnection) -> Result<Self, TryFromProtoError> {
+        Ok(PostgresSinkConnection {
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection_id")?,
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoPostgresSinkConnection::connection")?,
+            schema: proto.schema,
+            table: proto.table,
+            key_indices: proto.key_indices.into_rust()?,
+            value_desc: proto
+                .value_desc
+                .into_rust_if_some("ProtoPostgresSinkConnection::value_desc")?,
+            environment_id: proto.environment_id,
+        })
+    }
+}
+
 /// TODO(JLDLaughlin): Documentation.
 #[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PublishedSchemaInfo {
     pub key_schema_id: Option<i32>,
     pub value_schema_id: i32,
+    #[serde(default = "PublishedSchemaFormat::legacy")]
+    pub format: PublishedSchemaFormat,
 }
 
 impl RustType<ProtoPublishedSchemaInfo> for PublishedSchemaInfo {
@@ -410,6 +577,7 @@ impl RustType<ProtoPublishedSchemaInfo> for PublishedSchemaInfo {
         ProtoPublishedSchemaInfo {
             key_schema_id: self.key_schema_id.clone(),
             value_schema_id: self.value_schema_id,
+            format: Some(self.format.into_proto()),
         }
     }
 
@@ -417,6 +585,49 @@ impl RustType<ProtoPublishedSchemaInfo> for PublishedSchemaInfo {
         Ok(PublishedSchemaInfo {
             key_schema_id: proto.key_schema_id,
             value_schema_id: proto.value_schema_id,
+            format: match proto.format {
+                Some(format) => format.into_rust()?,
+                None => PublishedSchemaFormat::legacy(),
+            },
+        })
+    }
+}
+
+/// The format of the schemas that a Kafka sink published to the schema
+/// registry, and so the format in which the sink encodes its messages.
+#[derive(Arbitrary, Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum PublishedSchemaFormat {
+    Avro,
+    Protobuf,
+}
+
+impl PublishedSchemaFormat {
+    /// The format of sinks that were created before the format was recorded,
+    /// all of which published Avro schemas.
+    fn legacy() -> PublishedSchemaFormat {
+        PublishedSchemaFormat::Avro
+    }
+}
+
+impl RustType<ProtoPublishedSchemaFormat> for PublishedSchemaFormat {
+    fn into_proto(&self) -> ProtoPublishedSchemaFormat {
+        use proto_published_schema_format::Kind;
+        ProtoPublishedSchemaFormat {
+            kind: Some(match self {
+                PublishedSchemaFormat::Avro => Kind::Avro(()),
+                PublishedSchemaFormat::Protobuf => Kind::Protobuf(()),
+            }),
+        }
+    }
+
+    fn from_proto(proto: ProtoPublishedSchemaFormat) -> Result<Self, TryFromProtoError> {
+        use proto_published_schema_format::Kind;
+        let kind = proto
+            .kind
+            .ok_or_else(|| TryFromProtoError::missing_field("ProtoPublishedSchemaFormat::kind"))?;
+        Ok(match kind {
+            Kind::Avro(()) => PublishedSchemaFormat::Avro,
+            Kind::Protobuf(()) => PublishedSchemaFormat::Protobuf,
         })
     }
 }
@@ -424,6 +635,8 @@ impl RustType<ProtoPublishedSchemaInfo> for PublishedSchemaInfo {
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StorageSinkConnectionBuilder {
     Kafka(KafkaSinkConnectionBuilder),
+    File(FileSinkConnectionBuilder),
+    Postgres(PostgresSinkConnectionBuilder),
 }
 
 impl StorageSinkConnectionBuilder {
@@ -432,6 +645,8 @@ impl StorageSinkConnectionBuilder {
         use StorageSinkConnectionBuilder::*;
         match self {
             Kafka(KafkaSinkConnectionBuilder { connection_id, .. }) => Some(*connection_id),
+            File(FileSinkConnectionBuilder { location, .. }) => location.connection_id(),
+            Postgres(PostgresSinkConnectionBuilder { connection_id, .. }) => Some(*connection_id),
         }
     }
 
@@ -440,6 +655,8 @@ impl StorageSinkConnectionBuilder {
         use StorageSinkConnectionBuilder::*;
         match self {
             Kafka(_) => "kafka",
+            File(_) => "file",
+            Postgres(_) => "postgres",
         }
     }
 }
@@ -480,5 +697,27 @@ pub enum KafkaSinkFormat {
         value_schema: String,
         csr_connection: CsrConnection,
     },
+    Protobuf {
+        key_schema: Option<String>,
+        value_schema: String,
+        csr_connection: CsrConnection,
+    },
     Json,
 }
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FileSinkConnectionBuilder {
+    pub location: FileSinkLocation,
+    pub value_desc: RelationDesc,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnectionBuilder {
+    pub connection_id: GlobalId,
+    pub connection: PostgresConnection,
+    pub schema: String,
+    pub table: String,
+    pub key_indices: Vec<usize>,
+    pub value_desc: RelationDesc,
+    pub environment_id: String,
+}
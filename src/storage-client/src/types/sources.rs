@@ -51,7 +51,7 @@ use mz_repr::{
 use mz_timely_util::order::{Interval, Partitioned, RangeBound};
 
 use crate::controller::{CollectionMetadata, ResumptionFrontierCalculator};
-use crate::types::connections::{KafkaConnection, PostgresConnection};
+use crate::types::connections::{KafkaConnection, MySqlConnection, PostgresConnection};
 use crate::types::errors::{DataflowError, ProtoDataflowError};
 use crate::types::instances::{StorageInstanceContext, StorageInstanceId};
 
@@ -131,6 +131,7 @@ impl<T: Timestamp + Lattice + Codec64> ResumptionFrontierCalculator<T>
                 // The status shard only contains non-definite status updates
                 status_shard: _,
                 relation_desc,
+                missing_values: _,
             } = &export.storage_metadata;
             let handle = client_cache
                 .open(persist_location.clone())
@@ -156,6 +157,7 @@ impl<T: Timestamp + Lattice + Codec64> ResumptionFrontierCalculator<T>
             // The status shard only contains non-definite status updates
             status_shard: _,
             relation_desc: _,
+            missing_values: _,
         } = &self.ingestion_metadata
         {
             let remap_handle = client_cache
@@ -873,7 +875,11 @@ impl RustType<ProtoSourceEnvelope> for SourceEnvelope {
 pub enum UnplannedSourceEnvelope {
     None(KeyEnvelope),
     Debezium(DebeziumEnvelope),
-    Upsert { style: UpsertStyle, disk: bool },
+    Upsert {
+        style: UpsertStyle,
+        disk: bool,
+        retain_keys_for: Option<Duration>,
+    },
     CdcV2,
 }
 
@@ -901,6 +907,10 @@ impl RustType<ProtoNoneEnvelope> for NoneEnvelope {
     }
 }
 
+/// The name of the column that holds the time at which each key was last
+/// updated, in upsert sources that only retain keys for a limited time.
+pub const UPSERT_REFRESHED_AT_COLUMN: &str = "mz_refreshed_at";
+
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct UpsertEnvelope {
     /// Full arity, including the key columns
@@ -913,6 +923,13 @@ pub struct UpsertEnvelope {
     /// Whether or not to store the upsert state on disk, as opposed
     /// to in-memory.
     pub disk: bool,
+    /// If set, keys whose last update is older than this duration (in source
+    /// time) are retracted from the output and evicted from the upsert state.
+    ///
+    /// The time of the last update of each key is stored in a trailing
+    /// [`UPSERT_REFRESHED_AT_COLUMN`] column, so that the retention of every
+    /// key survives restarts.
+    pub retain_keys_for: Option<Duration>,
 }
 
 impl Arbitrary for UpsertEnvelope {
@@ -925,13 +942,17 @@ impl Arbitrary for UpsertEnvelope {
             any::<UpsertStyle>(),
             proptest::collection::vec(any::<usize>(), 1..4),
             any::<bool>(),
+            any::<Option<Duration>>(),
         )
-            .prop_map(|(source_arity, style, key_indices, disk)| Self {
-                source_arity,
-                style,
-                key_indices,
-                disk,
-            })
+            .prop_map(
+                |(source_arity, style, key_indices, disk, retain_keys_for)| Self {
+                    source_arity,
+                    style,
+                    key_indices,
+                    disk,
+                    retain_keys_for,
+                },
+            )
             .boxed()
     }
 }
@@ -943,6 +964,7 @@ impl RustType<ProtoUpsertEnvelope> for UpsertEnvelope {
             style: Some(self.style.into_proto()),
             key_indices: self.key_indices.into_proto(),
             disk: self.disk.into_proto(),
+            retain_keys_for: self.retain_keys_for.into_proto(),
         }
     }
 
@@ -954,6 +976,7 @@ impl RustType<ProtoUpsertEnvelope> for UpsertEnvelope {
                 .into_rust_if_some("ProtoUpsertEnvelope::style")?,
             key_indices: proto.key_indices.into_rust()?,
             disk: proto.disk.into_rust()?,
+            retain_keys_for: proto.retain_keys_for.into_rust()?,
         })
     }
 }
@@ -1234,6 +1257,7 @@ impl UnplannedSourceEnvelope {
             UnplannedSourceEnvelope::Upsert {
                 style: upsert_style,
                 disk,
+                retain_keys_for,
             } => SourceEnvelope::Upsert(UpsertEnvelope {
                 style: upsert_style,
                 key_indices: key.expect(
@@ -1245,6 +1269,7 @@ impl UnplannedSourceEnvelope {
                     correct parameters for UnplannedSourceEnvelope::Upsert",
                 ),
                 disk,
+                retain_keys_for,
             }),
             UnplannedSourceEnvelope::Debezium(inner) => SourceEnvelope::Debezium(inner),
             UnplannedSourceEnvelope::None(key_envelope) => SourceEnvelope::None(NoneEnvelope {
@@ -1314,7 +1339,26 @@ impl UnplannedSourceEnvelope {
                         (key_desc.with_key(vec![0]).concat(value_desc), Some(vec![0]))
                     }
                 };
-                let desc = keyed.concat(metadata_desc);
+                let mut desc = keyed.concat(metadata_desc);
+                if let UnplannedSourceEnvelope::Upsert {
+                    retain_keys_for: Some(_),
+                    ..
+                } = &self
+                {
+                    if desc
+                        .iter_names()
+                        .any(|name| name.as_str() == UPSERT_REFRESHED_AT_COLUMN)
+                    {
+                        bail!(
+                            "RETAIN KEYS FOR cannot be used with a column named {}",
+                            UPSERT_REFRESHED_AT_COLUMN
+                        );
+                    }
+                    desc = desc.with_column(
+                        UPSERT_REFRESHED_AT_COLUMN,
+                        ScalarType::MzTimestamp.nullable(false),
+                    );
+                }
                 (
                     self.into_source_envelope(key, Some(key_arity), Some(desc.arity())),
                     desc,
@@ -1716,6 +1760,11 @@ impl SourceDesc<GenericSourceConnection> {
                 connection: GenericSourceConnection::Postgres(_),
                 ..
             } => false,
+            // MySQL can produce retractions (deletes)
+            SourceDesc {
+                connection: GenericSourceConnection::MySql(_),
+                ..
+            } => false,
             // Loadgen can produce retractions (deletes)
             SourceDesc {
                 connection: GenericSourceConnection::LoadGenerator(g),
@@ -1765,6 +1814,7 @@ impl<C> SourceDesc<C> {
 pub enum GenericSourceConnection {
     Kafka(KafkaSourceConnection),
     Postgres(PostgresSourceConnection),
+    MySql(MySqlSourceConnection),
     LoadGenerator(LoadGeneratorSourceConnection),
     TestScript(TestScriptSourceConnection),
 }
@@ -1781,6 +1831,12 @@ impl From<PostgresSourceConnection> for GenericSourceConnection {
     }
 }
 
+impl From<MySqlSourceConnection> for GenericSourceConnection {
+    fn from(conn: MySqlSourceConnection) -> Self {
+        Self::MySql(conn)
+    }
+}
+
 impl From<LoadGeneratorSourceConnection> for GenericSourceConnection {
     fn from(conn: LoadGeneratorSourceConnection) -> Self {
         Self::LoadGenerator(conn)
@@ -1798,6 +1854,7 @@ impl SourceConnection for GenericSourceConnection {
         match self {
             Self::Kafka(conn) => conn.name(),
             Self::Postgres(conn) => conn.name(),
+            Self::MySql(conn) => conn.name(),
             Self::LoadGenerator(conn) => conn.name(),
             Self::TestScript(conn) => conn.name(),
         }
@@ -1807,6 +1864,7 @@ impl SourceConnection for GenericSourceConnection {
         match self {
             Self::Kafka(conn) => conn.upstream_name(),
             Self::Postgres(conn) => conn.upstream_name(),
+            Self::MySql(conn) => conn.upstream_name(),
             Self::LoadGenerator(conn) => conn.upstream_name(),
             Self::TestScript(conn) => conn.upstream_name(),
         }
@@ -1816,6 +1874,7 @@ impl SourceConnection for GenericSourceConnection {
         match self {
             Self::Kafka(conn) => conn.timestamp_desc(),
             Self::Postgres(conn) => conn.timestamp_desc(),
+            Self::MySql(conn) => conn.timestamp_desc(),
             Self::LoadGenerator(conn) => conn.timestamp_desc(),
             Self::TestScript(conn) => conn.timestamp_desc(),
         }
@@ -1825,6 +1884,7 @@ impl SourceConnection for GenericSourceConnection {
         match self {
             Self::Kafka(conn) => conn.connection_id(),
             Self::Postgres(conn) => conn.connection_id(),
+            Self::MySql(conn) => conn.connection_id(),
             Self::LoadGenerator(conn) => conn.connection_id(),
             Self::TestScript(conn) => conn.connection_id(),
         }
@@ -1834,6 +1894,7 @@ impl SourceConnection for GenericSourceConnection {
         match self {
             Self::Kafka(conn) => conn.metadata_columns(),
             Self::Postgres(conn) => conn.metadata_columns(),
+            Self::MySql(conn) => conn.metadata_columns(),
             Self::LoadGenerator(conn) => conn.metadata_columns(),
             Self::TestScript(conn) => conn.metadata_columns(),
         }
@@ -1843,6 +1904,7 @@ impl SourceConnection for GenericSourceConnection {
         match self {
             Self::Kafka(conn) => conn.metadata_column_types(),
             Self::Postgres(conn) => conn.metadata_column_types(),
+            Self::MySql(conn) => conn.metadata_column_types(),
             Self::LoadGenerator(conn) => conn.metadata_column_types(),
             Self::TestScript(conn) => conn.metadata_column_types(),
         }
@@ -1858,6 +1920,7 @@ impl RustType<ProtoSourceConnection> for GenericSourceConnection {
                 GenericSourceConnection::Postgres(postgres) => {
                     Kind::Postgres(postgres.into_proto())
                 }
+                GenericSourceConnection::MySql(mysql) => Kind::Mysql(mysql.into_proto()),
                 GenericSourceConnection::LoadGenerator(loadgen) => {
                     Kind::Loadgen(loadgen.into_proto())
                 }
@@ -1876,6 +1939,7 @@ impl RustType<ProtoSourceConnection> for GenericSourceConnection {
         Ok(match kind {
             Kind::Kafka(kafka) => GenericSourceConnection::Kafka(kafka.into_rust()?),
             Kind::Postgres(postgres) => GenericSourceConnection::Postgres(postgres.into_rust()?),
+            Kind::Mysql(mysql) => GenericSourceConnection::MySql(mysql.into_rust()?),
             Kind::Loadgen(loadgen) => GenericSourceConnection::LoadGenerator(loadgen.into_rust()?),
             Kind::Testscript(testscript) => {
                 GenericSourceConnection::TestScript(testscript.into_rust()?)
@@ -2025,6 +2089,9 @@ impl RustType<ProtoPostgresSourceConnection> for PostgresSourceConnection {
 pub struct PostgresSourcePublicationDetails {
     pub tables: Vec<mz_postgres_util::desc::PostgresTableDesc>,
     pub slot: String,
+    /// The upstream enum types used by columns of `tables` that are ingested as
+    /// Materialize enum types.
+    pub enum_types: Vec<mz_postgres_util::desc::PostgresEnumDesc>,
 }
 
 impl RustType<ProtoPostgresSourcePublicationDetails> for PostgresSourcePublicationDetails {
@@ -2032,6 +2099,7 @@ impl RustType<ProtoPostgresSourcePublicationDetails> for PostgresSourcePublicati
         ProtoPostgresSourcePublicationDetails {
             tables: self.tables.iter().map(|t| t.into_proto()).collect(),
             slot: self.slot.clone(),
+            enum_types: self.enum_types.iter().map(|t| t.into_proto()).collect(),
         }
     }
 
@@ -2043,6 +2111,171 @@ impl RustType<ProtoPostgresSourcePublicationDetails> for PostgresSourcePublicati
                 .map(mz_postgres_util::desc::PostgresTableDesc::from_proto)
                 .collect::<Result<_, _>>()?,
             slot: proto.slot,
+            enum_types: proto
+                .enum_types
+                .into_iter()
+                .map(mz_postgres_util::desc::PostgresEnumDesc::from_proto)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlSourceConnection {
+    pub connection_id: GlobalId,
+    pub connection: MySqlConnection,
+    /// The cast expressions to convert the incoming string encoded rows to
+    /// their target types, keyed by their position in the source.
+    pub table_casts: BTreeMap<usize, Vec<MirScalarExpr>>,
+    pub details: MySqlSourceDetails,
+}
+
+impl Arbitrary for MySqlSourceConnection {
+    type Strategy = BoxedStrategy<Self>;
+    type Parameters = ();
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<MySqlConnection>(),
+            any::<GlobalId>(),
+            proptest::collection::btree_map(
+                any::<usize>(),
+                proptest::collection::vec(any::<MirScalarExpr>(), 1..4),
+                1..4,
+            ),
+            any::<MySqlSourceDetails>(),
+        )
+            .prop_map(|(connection, connection_id, table_casts, details)| Self {
+                connection,
+                connection_id,
+                table_casts,
+                details,
+            })
+            .boxed()
+    }
+}
+
+pub static MYSQL_PROGRESS_DESC: Lazy<RelationDesc> = Lazy::new(|| {
+    RelationDesc::empty().with_column("transaction_id", ScalarType::UInt64.nullable(true))
+});
+
+impl SourceConnection for MySqlSourceConnection {
+    fn name(&self) -> &'static str {
+        "mysql"
+    }
+
+    fn upstream_name(&self) -> Option<&str> {
+        None
+    }
+
+    fn timestamp_desc(&self) -> RelationDesc {
+        MYSQL_PROGRESS_DESC.clone()
+    }
+
+    fn connection_id(&self) -> Option<GlobalId> {
+        Some(self.connection_id)
+    }
+
+    fn metadata_columns(&self) -> Vec<(&str, ColumnType)> {
+        vec![]
+    }
+
+    fn metadata_column_types(&self) -> Vec<IncludedColumnSource> {
+        vec![]
+    }
+}
+
+impl RustType<ProtoMySqlSourceConnection> for MySqlSourceConnection {
+    fn into_proto(&self) -> ProtoMySqlSourceConnection {
+        use proto_my_sql_source_connection::ProtoMySqlTableCast;
+        let mut table_casts = Vec::with_capacity(self.table_casts.len());
+        let mut table_cast_pos = Vec::with_capacity(self.table_casts.len());
+        for (pos, table_cast_cols) in self.table_casts.iter() {
+            table_casts.push(ProtoMySqlTableCast {
+                column_casts: table_cast_cols
+                    .iter()
+                    .cloned()
+                    .map(|cast| cast.into_proto())
+                    .collect(),
+            });
+            table_cast_pos.push(mz_ore::cast::usize_to_u64(*pos));
+        }
+
+        ProtoMySqlSourceConnection {
+            connection: Some(self.connection.into_proto()),
+            connection_id: Some(self.connection_id.into_proto()),
+            details: Some(self.details.into_proto()),
+            table_casts,
+            table_cast_pos,
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlSourceConnection) -> Result<Self, TryFromProtoError> {
+        if proto.table_casts.len() != proto.table_cast_pos.len() {
+            return Err(TryFromProtoError::missing_field(
+                "ProtoMySqlSourceConnection::table_cast_pos",
+            ));
+        }
+
+        let mut table_casts = BTreeMap::new();
+        for (pos, cast) in proto
+            .table_cast_pos
+            .into_iter()
+            .zip_eq(proto.table_casts.into_iter())
+        {
+            let mut column_casts = vec![];
+            for cast in cast.column_casts {
+                column_casts.push(cast.into_rust()?);
+            }
+            table_casts.insert(mz_ore::cast::u64_to_usize(pos), column_casts);
+        }
+
+        Ok(MySqlSourceConnection {
+            connection: proto
+                .connection
+                .into_rust_if_some("ProtoMySqlSourceConnection::connection")?,
+            connection_id: proto
+                .connection_id
+                .into_rust_if_some("ProtoMySqlSourceConnection::connection_id")?,
+            details: proto
+                .details
+                .into_rust_if_some("ProtoMySqlSourceConnection::details")?,
+            table_casts,
+        })
+    }
+}
+
+/// The upstream state a MySQL source was created against.
+#[derive(Arbitrary, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlSourceDetails {
+    /// The upstream tables ingested by the source, in output order.
+    pub tables: Vec<mz_mysql_util::desc::MySqlTableDesc>,
+    /// The `@@server_uuid` of the upstream server. Only transactions
+    /// originating on this server are reflected in the source's offsets.
+    pub server_uuid: String,
+    /// The server ID the source identifies itself with when reading the
+    /// binlog, which must be unique among the upstream server's replicas.
+    pub server_id: u32,
+}
+
+impl RustType<ProtoMySqlSourceDetails> for MySqlSourceDetails {
+    fn into_proto(&self) -> ProtoMySqlSourceDetails {
+        ProtoMySqlSourceDetails {
+            tables: self.tables.iter().map(|t| t.into_proto()).collect(),
+            server_uuid: self.server_uuid.clone(),
+            server_id: self.server_id,
+        }
+    }
+
+    fn from_proto(proto: ProtoMySqlSourceDetails) -> Result<Self, TryFromProtoError> {
+        Ok(MySqlSourceDetails {
+            tables: proto
+                .tables
+                .into_iter()
+                .map(mz_mysql_util::desc::MySqlTableDesc::from_proto)
+                .collect::<Result<_, _>>()?,
+            server_uuid: proto.server_uuid,
+            server_id: proto.server_id,
         })
     }
 }
@@ -23,7 +23,6 @@ use chrono::NaiveDateTime;
 use differential_dataflow::capture::YieldingIter;
 use differential_dataflow::Hashable;
 use differential_dataflow::{AsCollection, Collection};
-use mz_ore::error::ErrorExt;
 use regex::Regex;
 use timely::dataflow::channels::pact::Exchange;
 use timely::dataflow::Scope;
@@ -157,26 +156,13 @@ pub(crate) enum PreDelimitedFormat {
 impl PreDelimitedFormat {
     pub fn decode(&mut self, bytes: &[u8]) -> Result<Option<Row>, DecodeErrorKind> {
         match self {
-            PreDelimitedFormat::Bytes => Ok(Some(Row::pack(Some(Datum::Bytes(bytes))))),
-            PreDelimitedFormat::Json => {
-                let j = mz_repr::adt::jsonb::Jsonb::from_slice(bytes).map_err(|e| {
-                    DecodeErrorKind::Bytes(format!(
-                        "Failed to decode JSON: {}",
-                        // See if we can output the string that failed to be converted to JSON.
-                        match std::str::from_utf8(bytes) {
-                            Ok(str) => str.to_string(),
-                            // Otherwise produce the nominally helpful error.
-                            Err(_) => e.display_with_causes().to_string(),
-                        }
-                    ))
-                })?;
-                Ok(Some(j.into_row()))
-            }
-            PreDelimitedFormat::Text => {
-                let s = std::str::from_utf8(bytes)
-                    .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".to_string()))?;
-                Ok(Some(Row::pack(Some(Datum::String(s)))))
-            }
+            PreDelimitedFormat::Bytes => Ok(Some(mz_interchange::text::decode_bytes(bytes))),
+            PreDelimitedFormat::Json => mz_interchange::json::decode_json(bytes)
+                .map(Some)
+                .map_err(|e| DecodeErrorKind::Bytes(e.to_string())),
+            PreDelimitedFormat::Text => mz_interchange::text::decode_text(bytes)
+                .map(Some)
+                .map_err(|e| DecodeErrorKind::Text(e.to_string())),
             PreDelimitedFormat::Regex(regex, row_buf) => {
                 let s = std::str::from_utf8(bytes)
                     .map_err(|_| DecodeErrorKind::Text("Failed to decode UTF-8".to_string()))?;
@@ -10,6 +10,7 @@
 use std::any::Any;
 use std::cell::RefCell;
 use std::cmp::Reverse;
+use std::collections::BTreeSet;
 use std::convert::AsRef;
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
@@ -26,11 +27,11 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use timely::dataflow::channels::pact::Exchange;
 use timely::dataflow::Scope;
-use timely::order::{PartialOrder, TotalOrder};
+use timely::order::PartialOrder;
 use timely::progress::{Antichain, Timestamp};
 
 use crate::source::types::UpsertMetrics;
-use mz_repr::{Datum, DatumVec, Diff, Row};
+use mz_repr::{Datum, DatumVec, Diff, Row, RowPacker, TimestampManipulation};
 use mz_storage_client::types::errors::{DataflowError, EnvelopeError, UpsertError};
 use mz_storage_client::types::instances::StorageInstanceContext;
 use mz_storage_client::types::sources::UpsertEnvelope;
@@ -126,7 +127,7 @@ impl<H: Digest> Hasher for DigestHasher<H> {
 
 /// Resumes an upsert computation at `resume_upper` given as inputs a collection of upsert commands
 /// and the collection of the previous output of this operator.
-pub(crate) fn upsert<G: Scope, O: timely::ExchangeData + Ord>(
+pub(crate) fn upsert<G: Scope<Timestamp = mz_repr::Timestamp>, O: timely::ExchangeData + Ord>(
     input: &Collection<G, (UpsertKey, Option<UpsertValue>, O), Diff>,
     upsert_envelope: UpsertEnvelope,
     resume_upper: Antichain<G::Timestamp>,
@@ -134,16 +135,17 @@ pub(crate) fn upsert<G: Scope, O: timely::ExchangeData + Ord>(
     previous_token: Option<Rc<dyn Any>>,
     source_config: crate::source::RawSourceCreationConfig,
     instance_context: &StorageInstanceContext,
-) -> Collection<G, Result<Row, DataflowError>, Diff>
-where
-    G::Timestamp: TotalOrder,
-{
+) -> Collection<G, Result<Row, DataflowError>, Diff> {
     let upsert_metrics = UpsertMetrics::new(
         &source_config.base_metrics,
         source_config.id,
         source_config.worker_id,
     );
 
+    let retain_keys_for = upsert_envelope.retain_keys_for.map(|retain_keys_for| {
+        mz_repr::Timestamp::try_from(retain_keys_for).expect("retention window fits in timestamp")
+    });
+
     if upsert_envelope.disk {
         tracing::info!(
             "timely-{} rendering {} with rocksdb-backed upsert state",
@@ -160,6 +162,7 @@ where
         upsert_inner(
             input,
             upsert_envelope.key_indices,
+            retain_keys_for,
             resume_upper,
             previous,
             previous_token,
@@ -185,6 +188,7 @@ where
         upsert_inner(
             input,
             upsert_envelope.key_indices,
+            retain_keys_for,
             resume_upper,
             previous,
             previous_token,
@@ -194,9 +198,10 @@ where
     }
 }
 
-fn upsert_inner<G: Scope, O: timely::ExchangeData + Ord, F, Fut, US>(
+fn upsert_inner<G, O: timely::ExchangeData + Ord, F, Fut, US>(
     input: &Collection<G, (UpsertKey, Option<UpsertValue>, O), Diff>,
     mut key_indices: Vec<usize>,
+    retain_keys_for: Option<mz_repr::Timestamp>,
     resume_upper: Antichain<G::Timestamp>,
     previous: Collection<G, Result<Row, DataflowError>, Diff>,
     previous_token: Option<Rc<dyn Any>>,
@@ -204,7 +209,7 @@ fn upsert_inner<G: Scope, O: timely::ExchangeData + Ord, F, Fut, US>(
     state: F,
 ) -> Collection<G, Result<Row, DataflowError>, Diff>
 where
-    G::Timestamp: TotalOrder,
+    G: Scope<Timestamp = mz_repr::Timestamp>,
     F: FnOnce() -> Fut + 'static,
     Fut: std::future::Future<Output = US>,
     US: UpsertState,
@@ -272,6 +277,11 @@ where
         // The main key->value used to store previous values.
         let mut state = StatsState::new(state().await, upsert_shared_metrics);
 
+        // When keys are only retained for a limited time, the time at which each key expires,
+        // ordered by time. The time at which a key expires is derived from the time of its last
+        // update, which is stored in the last column of its value.
+        let mut expirations = BTreeSet::new();
+
         // A re-usable buffer of changes, per key. This is
         // an `IndexMap` because it has to be `drain`-able
         // and have a consistent iteration order.
@@ -279,13 +289,16 @@ where
         let mut multi_get_scratch = Vec::new();
 
         // Rehydrate the upsert state (and bump some stats), even if the snapshot is empty.
+        let snapshot_size = snapshot.len();
         let snapshot = snapshot.into_iter().map(|((key, value), diff)| {
             assert_eq!(diff, 1, "invalid upsert state");
+            if let Some(expires_at) = expiration(&value, retain_keys_for) {
+                expirations.insert((expires_at, key));
+            }
             (key, Some(value))
         });
 
         let now = Instant::now();
-        let snapshot_size = snapshot.len();
         state
             .multi_put(snapshot)
             .await
@@ -325,6 +338,14 @@ where
                     for (_, key, _, _) in stash.iter().take(idx) {
                         commands_state.entry(*key).or_insert(None);
                     }
+                    // Keys that expire before `upper` are retracted in this round too. Keys that
+                    // are scheduled to expire by the commands below are already included above.
+                    for (_, key) in expirations
+                        .iter()
+                        .take_while(|(expires_at, _)| !upper.less_equal(expires_at))
+                    {
+                        commands_state.entry(*key).or_insert(None);
+                    }
 
                     // These iterators iterate in the same order because `commands_state`
                     // is an `IndexMap`.
@@ -349,11 +370,38 @@ where
                     // Upsert the values into `commands_state`, by recording the latest
                     // value (or deletion). These will be synced at the end to the `state`.
                     while let Some((ts, key, _, value)) = commands.next() {
+                        // Expirations are interleaved with the commands in time order. A key that
+                        // expires at the time of a command is retracted before the command applies.
+                        expire_keys(
+                            &mut expirations,
+                            &mut commands_state,
+                            &mut output_updates,
+                            |expires_at| expires_at <= &ts,
+                        );
+
                         let command_state = commands_state
                             .get_mut(&key)
                             .expect("key missing from commands_state");
+                        let old_expiration = command_state
+                            .as_ref()
+                            .and_then(|old_value| expiration(old_value, retain_keys_for));
+                        if let Some(expires_at) = old_expiration {
+                            expirations.remove(&(expires_at, key));
+                        }
                         match value {
-                            Some(value) => {
+                            Some(mut value) => {
+                                // Every update refreshes the retention of its key, including
+                                // one that rewrites the key with its current value, by recording
+                                // the time of the update in the value.
+                                if retain_keys_for.is_some() {
+                                    if let Ok(row) = &mut value {
+                                        RowPacker::for_existing_row(row)
+                                            .push(Datum::MzTimestamp(ts));
+                                    }
+                                }
+                                if let Some(expires_at) = expiration(&value, retain_keys_for) {
+                                    expirations.insert((expires_at, key));
+                                }
                                 if let Some(old_value) = command_state.replace(value.clone()) {
                                     output_updates.push((old_value, ts.clone(), -1));
                                 }
@@ -366,6 +414,12 @@ where
                             }
                         }
                     }
+                    expire_keys(
+                        &mut expirations,
+                        &mut commands_state,
+                        &mut output_updates,
+                        |expires_at| !upper.less_equal(expires_at),
+                    );
 
                     // Record the changes in `state`.
                     state
@@ -391,3 +445,44 @@ where
         Err(err) => Err(DataflowError::from(EnvelopeError::Upsert(err))),
     })
 }
+
+/// Returns the time at which a key with the given value expires, if keys are only retained for
+/// `retain_keys_for`.
+///
+/// The time of the last update of the key is stored in the last column of its value. Keys whose
+/// value is an error carry no such time, and are retained until they are next updated.
+fn expiration(
+    value: &UpsertValue,
+    retain_keys_for: Option<mz_repr::Timestamp>,
+) -> Option<mz_repr::Timestamp> {
+    let retain_keys_for = retain_keys_for?;
+    let row = value.as_ref().ok()?;
+    let refreshed_at = row
+        .iter()
+        .last()
+        .expect("value missing refresh time")
+        .unwrap_mz_timestamp();
+    Some(refreshed_at.step_forward_by(&retain_keys_for))
+}
+
+/// Retracts the values of the keys in `expirations` for which `expired` returns true, in time
+/// order. The values of all such keys must be present in `commands_state`.
+fn expire_keys<T: Ord>(
+    expirations: &mut BTreeSet<(T, UpsertKey)>,
+    commands_state: &mut indexmap::IndexMap<UpsertKey, Option<UpsertValue>>,
+    output_updates: &mut Vec<(UpsertValue, T, Diff)>,
+    expired: impl Fn(&T) -> bool,
+) {
+    while let Some((expires_at, _)) = expirations.first() {
+        if !expired(expires_at) {
+            break;
+        }
+        let (expires_at, key) = expirations.pop_first().expect("known to exist");
+        let command_state = commands_state
+            .get_mut(&key)
+            .expect("key missing from commands_state");
+        if let Some(old_value) = command_state.take() {
+            output_updates.push((old_value, expires_at, -1));
+        }
+    }
+}
@@ -0,0 +1,1118 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that writes Parquet files to S3 or to a local directory.
+//!
+//! The sink writes its files under the bucket and prefix of its
+//! [`FileSinkLocation`], using the credentials of the sink's AWS connection, or
+//! into a local directory, which is only available in unsafe mode for testing.
+//! Each file contains the updates at the times in `[<lower>, <upper>)`, where
+//! `<lower>` and `<upper>` are consecutive frontiers of the sinked collection.
+//! The first file written by a sink with a snapshot is named
+//! `snapshot-<lower>.parquet` and contains the snapshot of the collection as of
+//! the sink's `as_of`. Every later file is named `updates-<lower>.parquet`.
+//! Timestamps in file names are zero-padded, so that the files sort in
+//! timestamp order, and the upper of each file is stored in its `mz_upper`
+//! metadata key.
+//!
+//! Files are streamed out while the updates at their lower are received, so
+//! that the sink never holds a whole snapshot in memory: S3 files are written
+//! with multipart uploads, and local files are written under a temporary name
+//! and renamed once complete. Only updates beyond the lower of the file being
+//! written are buffered, until the file they belong to is known.
+//!
+//! The sink records its progress in persist, as the upper of its progress
+//! shard: after each file, the sink advances the upper of the progress shard
+//! to the upper of the file. A restarted sink resumes from that upper, first
+//! removing any files that were written but never recorded, along with any
+//! incomplete files. Because the upper is advanced with a compare-and-append,
+//! a sink that has been superseded by another instance of itself fails to
+//! record its progress and halts. Readers that want exactly-once results should
+//! only read files whose lower is not beyond the recorded upper.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::future;
+use std::io::{self, Write};
+use std::iter;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use anyhow::{bail, Context};
+use arrow2::io::parquet::write::{
+    CompressionOptions, Encoding, FileWriter, KeyValue, RowGroupIterator, Version, WriteOptions,
+};
+use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::types::{CompletedMultipartUpload, CompletedPart};
+use aws_sdk_s3::Client as S3Client;
+use differential_dataflow::{Collection, Hashable};
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::Scope;
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use tokio::io::AsyncWriteExt;
+use tracing::info;
+
+use mz_interchange::arrow::ArrowEncoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_client::{PersistClient, ShardId};
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_client::types::connections::ConnectionContext;
+use mz_storage_client::types::errors::DataflowError;
+use mz_storage_client::types::sinks::{
+    FileSinkConnection, FileSinkLocation, MetadataFilled, SinkAsOf, StorageSinkDesc,
+};
+use mz_storage_client::types::sources::SourceData;
+use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
+
+use crate::internal_control::{InternalCommandSender, InternalStorageCommand};
+use crate::render::sinks::{HealthcheckerArgs, SinkRender};
+use crate::sink::{Healthchecker, SinkStatus};
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+/// The key prefix of the file containing the initial snapshot.
+const SNAPSHOT_PREFIX: &str = "snapshot-";
+/// The key prefix of files containing updates.
+const UPDATES_PREFIX: &str = "updates-";
+/// The key suffix of data files.
+const PARQUET_SUFFIX: &str = ".parquet";
+/// The suffix of local files that are still being written.
+const TEMPORARY_SUFFIX: &str = ".tmp";
+/// The Parquet metadata key under which the upper of a file is stored.
+const UPPER_METADATA_KEY: &str = "mz_upper";
+
+/// The number of updates in each Parquet row group.
+const ROW_GROUP_UPDATES: usize = 16_384;
+/// The number of bytes at which a file's pending bytes are written out as a
+/// part.
+///
+/// S3 requires every part but the last to be at least 5 MiB.
+const PART_BYTES: usize = 8 << 20;
+
+const WRITE_OPTIONS: WriteOptions = WriteOptions {
+    write_statistics: false,
+    compression: CompressionOptions::Uncompressed,
+    version: Version::V2,
+    data_pagesize_limit: None,
+};
+
+impl<G> SinkRender<G> for FileSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        false
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        // TODO: errors should stream out through the sink, if we figure out a
+        // protocol for that.
+        _err_collection: Collection<G, DataflowError, Diff>,
+        healthchecker_args: HealthcheckerArgs,
+    ) -> Option<Rc<dyn Any>>
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        // Only the active_write_worker will ever produce data so all other
+        // workers have an empty frontier.
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let token = write_files(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            storage_state.connection_context.clone(),
+            sink.as_of.clone(),
+            sink.progress_shard,
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            healthchecker_args,
+            Rc::clone(&storage_state.internal_cmd_tx),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        Some(token)
+    }
+}
+
+struct FileSinkState {
+    name: String,
+    sink_id: GlobalId,
+    healthchecker: Option<Healthchecker>,
+    internal_cmd_tx: Rc<RefCell<dyn InternalCommandSender>>,
+}
+
+impl FileSinkState {
+    async fn update_status(&mut self, status: SinkStatus) {
+        if let Some(hc) = &mut self.healthchecker {
+            hc.update_status(status).await;
+        }
+    }
+
+    /// Report a SinkStatus::Stalled and then halt with the same message.
+    async fn halt_on_err<T>(&mut self, result: Result<T, anyhow::Error>) -> T {
+        match result {
+            Ok(t) => t,
+            Err(error) => {
+                self.update_status(SinkStatus::Stalled {
+                    error: format!("{}", error.display_with_causes()),
+                    hint: None,
+                })
+                .await;
+                self.internal_cmd_tx.borrow_mut().broadcast(
+                    InternalStorageCommand::SuspendAndRestart {
+                        id: self.sink_id,
+                        reason: error.to_string(),
+                    },
+                );
+
+                // Make sure to never return, preventing the sink from writing
+                // out anything it might regret in the future.
+                future::pending().await
+            }
+        }
+    }
+}
+
+/// Writes the updates of `collection` as Parquet files to the location
+/// described by `connection`, recording its progress in `progress_shard`.
+///
+/// Exactly one worker writes all of the files. Updates that are not beyond the
+/// given [`SinkAsOf`], or that were written before a restart, are discarded.
+fn write_files<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    sink_id: GlobalId,
+    connection: FileSinkConnection,
+    connection_context: ConnectionContext,
+    as_of: SinkAsOf,
+    progress_shard: Option<ShardId>,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    healthchecker_args: HealthcheckerArgs,
+    internal_cmd_tx: Rc<RefCell<dyn InternalCommandSender>>,
+) -> Rc<dyn Any>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("file-{}", sink_id);
+    let scope = collection.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to write all the files.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+
+    let button = builder.build(move |_capabilities| async move {
+        if !is_active_worker {
+            return;
+        }
+
+        let healthchecker = match healthchecker_args.status_shard_id {
+            Some(status_shard_id) => Some(
+                Healthchecker::new(
+                    sink_id,
+                    &healthchecker_args.persist_clients,
+                    healthchecker_args.persist_location.clone(),
+                    status_shard_id,
+                    healthchecker_args.now_fn.clone(),
+                )
+                .await
+                .expect("error initializing healthchecker"),
+            ),
+            None => None,
+        };
+        let mut s = FileSinkState {
+            name,
+            sink_id,
+            healthchecker,
+            internal_cmd_tx,
+        };
+
+        s.update_status(SinkStatus::Starting).await;
+
+        let progress = async {
+            let progress_shard = progress_shard.context("file sink has no progress shard")?;
+            let persist_client = healthchecker_args
+                .persist_clients
+                .open(healthchecker_args.persist_location.clone())
+                .await
+                .context("error creating persist client for file sink")?;
+            let progress = open_progress(&persist_client, progress_shard, sink_id).await?;
+            Ok::<_, anyhow::Error>(progress)
+        };
+        let progress = s.halt_on_err(progress.await).await;
+
+        let destination = match connection.location {
+            FileSinkLocation::S3 {
+                connection_id,
+                connection: aws_connection,
+                bucket,
+                prefix,
+            } => {
+                let sdk_config = aws_connection
+                    .load(
+                        connection_context.aws_external_id_prefix.as_ref(),
+                        Some(&connection_id),
+                        &*connection_context.secrets_reader,
+                    )
+                    .await;
+                Destination::S3 {
+                    client: mz_aws_s3_util::new_client(&sdk_config),
+                    bucket,
+                    prefix,
+                }
+            }
+            FileSinkLocation::LocalDirectory { path } => {
+                Destination::LocalDirectory { path: path.into() }
+            }
+        };
+
+        let writer = FileSinkWriter::new(destination, progress, connection.value_desc, as_of);
+        let mut writer = s.halt_on_err(writer.await).await;
+        info!(
+            "{}: initial as_of: {:?}, recorded upper: {:?}",
+            s.name,
+            writer.as_of.frontier,
+            writer.upper()
+        );
+        write_frontier.borrow_mut().clone_from(writer.upper());
+
+        s.update_status(SinkStatus::Running).await;
+
+        while let Some(event) = input.next_mut().await {
+            match event {
+                Event::Data(_, rows) => {
+                    for ((_key, value), time, diff) in rows.drain(..) {
+                        let value = value.expect("file sinks have no envelope");
+                        let pushed = writer.push(value, time, diff).await;
+                        s.halt_on_err(pushed).await;
+                    }
+                }
+                Event::Progress(frontier) => {
+                    let advanced = writer.advance(frontier).await;
+                    if let Some((key, count, size)) = s.halt_on_err(advanced).await {
+                        info!("{}: wrote {} updates to {}", s.name, count, key);
+                        sink_statistics.inc_messages_staged_by(count);
+                        sink_statistics.inc_bytes_staged_by(size);
+                        sink_statistics.inc_messages_committed_by(count);
+                        sink_statistics.inc_bytes_committed_by(size);
+                    }
+                    write_frontier.borrow_mut().clone_from(writer.upper());
+                }
+            }
+        }
+    });
+
+    Rc::new(button.press_on_drop())
+}
+
+/// Opens a write handle to the shard in which the sink `sink_id` records its
+/// progress.
+async fn open_progress(
+    persist_client: &PersistClient,
+    progress_shard: ShardId,
+    sink_id: GlobalId,
+) -> Result<WriteHandle<SourceData, (), Timestamp, Diff>, anyhow::Error> {
+    persist_client
+        .open_writer(
+            progress_shard,
+            &format!("file sink progress {}", sink_id),
+            Arc::new(RelationDesc::empty()),
+            Arc::new(UnitSchema),
+        )
+        .await
+        .context("error opening file sink progress shard")
+}
+
+/// Writes the updates of a collection to files, and records the upper of the
+/// written files as the upper of the sink's progress shard.
+struct FileSinkWriter {
+    destination: Destination,
+    progress: WriteHandle<SourceData, (), Timestamp, Diff>,
+    encoder: ArrowEncoder,
+    as_of: SinkAsOf,
+    /// The upper of all files written so far, which is also the lower of the
+    /// file being written.
+    upper: Antichain<Timestamp>,
+    /// Whether the next file contains the snapshot. The file containing the
+    /// snapshot has an upper beyond the `as_of`, so it has not yet been
+    /// recorded as long as the recorded upper is not beyond the `as_of`.
+    snapshot_pending: bool,
+    /// The file being written, if any updates at `upper` have been received.
+    file: Option<ParquetFile>,
+    /// The updates at times beyond `upper`.
+    pending: Vec<(Row, Timestamp, Diff)>,
+}
+
+impl FileSinkWriter {
+    /// Resumes writing at the upper recorded in `progress`, removing any files
+    /// that were written beyond it.
+    async fn new(
+        destination: Destination,
+        progress: WriteHandle<SourceData, (), Timestamp, Diff>,
+        value_desc: RelationDesc,
+        as_of: SinkAsOf,
+    ) -> Result<FileSinkWriter, anyhow::Error> {
+        let upper = progress.upper().clone();
+        destination.remove_unrecorded_files(&upper).await?;
+        let snapshot_pending = !as_of.strict && PartialOrder::less_equal(&upper, &as_of.frontier);
+        Ok(FileSinkWriter {
+            destination,
+            progress,
+            encoder: ArrowEncoder::new(value_desc),
+            as_of,
+            upper,
+            snapshot_pending,
+            file: None,
+            pending: Vec::new(),
+        })
+    }
+
+    /// Returns the upper of all files written so far.
+    fn upper(&self) -> &Antichain<Timestamp> {
+        &self.upper
+    }
+
+    /// Writes an update, unless it is not beyond the `as_of` or was written
+    /// before a restart.
+    async fn push(&mut self, value: Row, time: Timestamp, diff: Diff) -> Result<(), anyhow::Error> {
+        let should_emit = if self.as_of.strict {
+            self.as_of.frontier.less_than(&time)
+        } else {
+            self.as_of.frontier.less_equal(&time)
+        };
+        let previously_written = !self.upper.less_equal(&time);
+        if !should_emit || previously_written || diff == 0 {
+            return Ok(());
+        }
+
+        // Updates at `upper` belong to the file being written, so they're
+        // streamed out right away. Later updates wait until the file they
+        // belong to is known.
+        if self.upper.as_option() != Some(&time) {
+            self.pending.push((value, time, diff));
+            return Ok(());
+        }
+        if self.file.is_none() {
+            let key = file_key(self.snapshot_pending, time);
+            self.file = Some(ParquetFile::start(&self.destination, &self.encoder, key).await?);
+        }
+        let file = self.file.as_mut().expect("file started");
+        file.push(&self.destination, &self.encoder, (value, time, diff))
+            .await
+    }
+
+    /// Finishes the file containing the updates before `frontier`, if there
+    /// are any, and records `frontier` as the new upper.
+    ///
+    /// Returns the key of the finished file and the number of updates and
+    /// bytes in it.
+    async fn advance(
+        &mut self,
+        frontier: Antichain<Timestamp>,
+    ) -> Result<Option<(String, u64, u64)>, anyhow::Error> {
+        if !PartialOrder::less_than(&self.upper, &frontier) {
+            return Ok(None);
+        }
+
+        let (ready, not_ready) = self
+            .pending
+            .drain(..)
+            .partition::<Vec<_>, _>(|(_, time, _)| !frontier.less_equal(time));
+        self.pending = not_ready;
+
+        if self.file.is_none() && !ready.is_empty() {
+            let lower = *self.upper.as_option().expect("updates beyond upper");
+            let key = file_key(self.snapshot_pending, lower);
+            self.file = Some(ParquetFile::start(&self.destination, &self.encoder, key).await?);
+        }
+        let mut written = None;
+        if let Some(mut file) = self.file.take() {
+            for update in ready {
+                file.push(&self.destination, &self.encoder, update).await?;
+            }
+            let key = file.key.clone();
+            let file_upper = frontier.as_option().map(|t| u64::from(*t));
+            let (count, size) = file
+                .finish(&self.destination, &self.encoder, file_upper)
+                .await?;
+            written = Some((key, count, size));
+        }
+
+        let updates: Vec<((SourceData, ()), Timestamp, Diff)> = Vec::new();
+        let recorded = self
+            .progress
+            .compare_and_append(updates, self.upper.clone(), frontier.clone())
+            .await
+            .context("error recording file sink progress")?;
+        if let Err(mismatch) = recorded {
+            bail!(
+                "file sink progress was recorded concurrently: expected upper {:?}, found {:?}",
+                mismatch.expected,
+                mismatch.current
+            );
+        }
+
+        if PartialOrder::less_than(&self.as_of.frontier, &frontier) {
+            self.snapshot_pending = false;
+        }
+        self.upper = frontier;
+
+        // Stream out the buffered updates at the new `upper`, which belong to
+        // the next file.
+        if let Some(lower) = self.upper.as_option().copied() {
+            let (next, later) = self
+                .pending
+                .drain(..)
+                .partition::<Vec<_>, _>(|(_, time, _)| *time == lower);
+            self.pending = later;
+            if !next.is_empty() {
+                let key = file_key(self.snapshot_pending, lower);
+                let mut file = ParquetFile::start(&self.destination, &self.encoder, key).await?;
+                for update in next {
+                    file.push(&self.destination, &self.encoder, update).await?;
+                }
+                self.file = Some(file);
+            }
+        }
+        Ok(written)
+    }
+}
+
+/// Returns the key, relative to the sink's prefix, of the file whose lower is
+/// `lower`.
+fn file_key(snapshot: bool, lower: Timestamp) -> String {
+    let prefix = if snapshot {
+        SNAPSHOT_PREFIX
+    } else {
+        UPDATES_PREFIX
+    };
+    format!("{}{:020}{}", prefix, u64::from(lower), PARQUET_SUFFIX)
+}
+
+/// Returns the lower of the file with the given key, relative to the sink's
+/// prefix, or `None` if the key does not name a file written by the sink.
+fn parse_file_key(key: &str) -> Option<u64> {
+    let lower = key
+        .strip_prefix(SNAPSHOT_PREFIX)
+        .or_else(|| key.strip_prefix(UPDATES_PREFIX))?
+        .strip_suffix(PARQUET_SUFFIX)?;
+    lower.parse().ok()
+}
+
+/// Reports whether the file whose lower is `lower` is covered by the recorded
+/// `upper`.
+fn is_recorded(upper: &Antichain<Timestamp>, lower: u64) -> bool {
+    !upper.less_equal(&Timestamp::from(lower))
+}
+
+/// The location to which a file sink writes.
+enum Destination {
+    S3 {
+        client: S3Client,
+        bucket: String,
+        /// The prefix of all keys written by the sink. Either empty or ends in
+        /// `/`.
+        prefix: String,
+    },
+    LocalDirectory {
+        path: PathBuf,
+    },
+}
+
+impl Destination {
+    /// Removes the files that were written beyond the recorded `upper`, e.g.
+    /// because the sink crashed before it could record writing them, and any
+    /// files that were never completed.
+    async fn remove_unrecorded_files(
+        &self,
+        upper: &Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            Destination::S3 {
+                client,
+                bucket,
+                prefix,
+            } => remove_unrecorded_objects(client, bucket, prefix, upper).await,
+            Destination::LocalDirectory { path } => {
+                tokio::fs::create_dir_all(path)
+                    .await
+                    .with_context(|| format!("error creating directory {}", path.display()))?;
+                let mut entries = tokio::fs::read_dir(path)
+                    .await
+                    .with_context(|| format!("error listing directory {}", path.display()))?;
+                while let Some(entry) = entries
+                    .next_entry()
+                    .await
+                    .with_context(|| format!("error listing directory {}", path.display()))?
+                {
+                    let Ok(name) = entry.file_name().into_string() else {
+                        continue;
+                    };
+                    let remove = match name.strip_suffix(TEMPORARY_SUFFIX) {
+                        Some(name) => parse_file_key(name).is_some(),
+                        None => parse_file_key(&name).map_or(false, |l| !is_recorded(upper, l)),
+                    };
+                    if remove {
+                        info!("removing unrecorded file {}", entry.path().display());
+                        tokio::fs::remove_file(entry.path())
+                            .await
+                            .with_context(|| format!("error removing file {}", name))?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Removes the S3 objects that were written beyond the recorded `upper`, and
+/// aborts the uploads of objects that were never completed.
+async fn remove_unrecorded_objects(
+    client: &S3Client,
+    bucket: &str,
+    prefix: &str,
+    upper: &Antichain<Timestamp>,
+) -> Result<(), anyhow::Error> {
+    let mut keys = Vec::new();
+    let mut continuation_token = None;
+    loop {
+        let resp = client
+            .list_objects_v2()
+            .bucket(bucket)
+            .prefix(prefix)
+            .set_continuation_token(continuation_token)
+            .send()
+            .await
+            .context("error listing files")?;
+        for object in resp.contents().unwrap_or_default() {
+            if let Some(key) = object.key() {
+                keys.push(key.to_owned());
+            }
+        }
+        continuation_token = resp.next_continuation_token().map(String::from);
+        if continuation_token.is_none() {
+            break;
+        }
+    }
+
+    for key in keys {
+        let Some(lower) = key.strip_prefix(prefix).and_then(parse_file_key) else {
+            continue;
+        };
+        if !is_recorded(upper, lower) {
+            info!("removing unrecorded file {}", key);
+            client
+                .delete_object()
+                .bucket(bucket)
+                .key(&key)
+                .send()
+                .await
+                .with_context(|| format!("error removing file {}", key))?;
+        }
+    }
+
+    let mut key_marker = None;
+    let mut upload_id_marker = None;
+    loop {
+        let resp = client
+            .list_multipart_uploads()
+            .bucket(bucket)
+            .prefix(prefix)
+            .set_key_marker(key_marker)
+            .set_upload_id_marker(upload_id_marker)
+            .send()
+            .await
+            .context("error listing incomplete uploads")?;
+        for upload in resp.uploads().unwrap_or_default() {
+            let (Some(key), Some(upload_id)) = (upload.key(), upload.upload_id()) else {
+                continue;
+            };
+            if key.strip_prefix(prefix).and_then(parse_file_key).is_none() {
+                continue;
+            }
+            info!("aborting incomplete upload of {}", key);
+            client
+                .abort_multipart_upload()
+                .bucket(bucket)
+                .key(key)
+                .upload_id(upload_id)
+                .send()
+                .await
+                .with_context(|| format!("error aborting upload of {}", key))?;
+        }
+        if !resp.is_truncated() {
+            break;
+        }
+        key_marker = resp.next_key_marker().map(String::from);
+        upload_id_marker = resp.next_upload_id_marker().map(String::from);
+    }
+    Ok(())
+}
+
+/// A [`Write`] that appends to a buffer shared with its creator, so that the
+/// creator can write out the bytes written so far.
+#[derive(Clone, Default)]
+struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The destination-specific state of a file that is being written.
+enum PartialFile {
+    /// A multipart upload to S3.
+    S3 {
+        upload_id: String,
+        parts: Vec<CompletedPart>,
+    },
+    /// A local file that is renamed to its final path once complete.
+    Local {
+        file: tokio::fs::File,
+        temporary_path: PathBuf,
+        path: PathBuf,
+    },
+}
+
+/// A Parquet file that is written out in parts while it is encoded.
+struct ParquetFile {
+    /// The key of the file, relative to the sink's prefix or directory.
+    key: String,
+    partial: PartialFile,
+    writer: FileWriter<SharedBuffer>,
+    /// The bytes encoded by `writer` that have not yet been written out.
+    buffer: SharedBuffer,
+    /// The updates that have not yet been encoded as a row group.
+    row_group: Vec<(Row, Timestamp, Diff)>,
+    /// The number of updates written to the file so far.
+    count: u64,
+    /// The number of bytes written out so far.
+    size: u64,
+}
+
+impl ParquetFile {
+    /// Starts writing the file with the given key.
+    async fn start(
+        destination: &Destination,
+        encoder: &ArrowEncoder,
+        key: String,
+    ) -> Result<ParquetFile, anyhow::Error> {
+        let partial = match destination {
+            Destination::S3 {
+                client,
+                bucket,
+                prefix,
+            } => {
+                let upload = client
+                    .create_multipart_upload()
+                    .bucket(bucket)
+                    .key(format!("{}{}", prefix, key))
+                    .send()
+                    .await
+                    .with_context(|| format!("error starting upload of file {}", key))?;
+                let upload_id = upload
+                    .upload_id()
+                    .with_context(|| format!("missing upload id for file {}", key))?
+                    .to_owned();
+                PartialFile::S3 {
+                    upload_id,
+                    parts: Vec::new(),
+                }
+            }
+            Destination::LocalDirectory { path } => {
+                let temporary_path = path.join(format!("{}{}", key, TEMPORARY_SUFFIX));
+                let path = path.join(&key);
+                let file = tokio::fs::File::create(&temporary_path)
+                    .await
+                    .with_context(|| format!("error creating file {}", key))?;
+                PartialFile::Local {
+                    file,
+                    temporary_path,
+                    path,
+                }
+            }
+        };
+        let buffer = SharedBuffer::default();
+        let writer = FileWriter::try_new(buffer.clone(), encoder.schema().clone(), WRITE_OPTIONS)
+            .context("error encoding parquet file")?;
+        Ok(ParquetFile {
+            key,
+            partial,
+            writer,
+            buffer,
+            row_group: Vec::new(),
+            count: 0,
+            size: 0,
+        })
+    }
+
+    /// Appends an update to the file, writing out the bytes encoded so far
+    /// once they make up a part.
+    async fn push(
+        &mut self,
+        destination: &Destination,
+        encoder: &ArrowEncoder,
+        update: (Row, Timestamp, Diff),
+    ) -> Result<(), anyhow::Error> {
+        self.row_group.push(update);
+        if self.row_group.len() >= ROW_GROUP_UPDATES {
+            self.write_row_group(encoder)?;
+            if self.buffer.0.borrow().len() >= PART_BYTES {
+                self.write_part(destination).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finishes the file, whose upper is `upper`, and makes it visible.
+    ///
+    /// Returns the number of updates and bytes in the file.
+    async fn finish(
+        mut self,
+        destination: &Destination,
+        encoder: &ArrowEncoder,
+        upper: Option<u64>,
+    ) -> Result<(u64, u64), anyhow::Error> {
+        self.write_row_group(encoder)?;
+        let metadata = vec![KeyValue {
+            key: UPPER_METADATA_KEY.into(),
+            value: upper.map(|upper| upper.to_string()),
+        }];
+        self.writer
+            .end(Some(metadata))
+            .context("error encoding parquet file")?;
+        self.write_part(destination).await?;
+
+        match (destination, self.partial) {
+            (
+                Destination::S3 {
+                    client,
+                    bucket,
+                    prefix,
+                },
+                PartialFile::S3 { upload_id, parts },
+            ) => {
+                client
+                    .complete_multipart_upload()
+                    .bucket(bucket)
+                    .key(format!("{}{}", prefix, self.key))
+                    .upload_id(upload_id)
+                    .multipart_upload(
+                        CompletedMultipartUpload::builder()
+                            .set_parts(Some(parts))
+                            .build(),
+                    )
+                    .send()
+                    .await
+                    .with_context(|| format!("error completing upload of file {}", self.key))?;
+            }
+            (
+                Destination::LocalDirectory { .. },
+                PartialFile::Local {
+                    mut file,
+                    temporary_path,
+                    path,
+                },
+            ) => {
+                file.flush()
+                    .await
+                    .with_context(|| format!("error writing file {}", self.key))?;
+                file.sync_all()
+                    .await
+                    .with_context(|| format!("error writing file {}", self.key))?;
+                tokio::fs::rename(&temporary_path, &path)
+                    .await
+                    .with_context(|| format!("error renaming file {}", self.key))?;
+            }
+            _ => unreachable!("file started for a different destination"),
+        }
+        Ok((self.count, self.size))
+    }
+
+    /// Encodes the buffered updates as a row group.
+    fn write_row_group(&mut self, encoder: &ArrowEncoder) -> Result<(), anyhow::Error> {
+        if self.row_group.is_empty() {
+            return Ok(());
+        }
+        let chunk = encoder.encode(self.row_group.iter().map(|(row, t, d)| (row, *t, *d)));
+        let encodings = encoder
+            .schema()
+            .fields
+            .iter()
+            .map(|_| vec![Encoding::Plain])
+            .collect();
+        let row_groups = RowGroupIterator::try_new(
+            iter::once(Ok(chunk)),
+            encoder.schema(),
+            WRITE_OPTIONS,
+            encodings,
+        )
+        .context("error encoding parquet file")?;
+        for row_group in row_groups {
+            let row_group = row_group.context("error encoding parquet file")?;
+            self.writer
+                .write(row_group)
+                .context("error encoding parquet file")?;
+        }
+        self.count += u64::cast_from(self.row_group.len());
+        self.row_group.clear();
+        Ok(())
+    }
+
+    /// Writes out the bytes encoded so far as the next part of the file.
+    async fn write_part(&mut self, destination: &Destination) -> Result<(), anyhow::Error> {
+        let data = std::mem::take(&mut *self.buffer.0.borrow_mut());
+        self.size += u64::cast_from(data.len());
+        match (destination, &mut self.partial) {
+            (
+                Destination::S3 {
+                    client,
+                    bucket,
+                    prefix,
+                },
+                PartialFile::S3 { upload_id, parts },
+            ) => {
+                // S3 allows at most 10,000 parts, so this cannot overflow.
+                let part_number = i32::try_from(parts.len() + 1).expect("part number fits in i32");
+                let part = client
+                    .upload_part()
+                    .bucket(bucket)
+                    .key(format!("{}{}", prefix, self.key))
+                    .upload_id(upload_id.as_str())
+                    .part_number(part_number)
+                    .body(ByteStream::from(data))
+                    .send()
+                    .await
+                    .with_context(|| format!("error uploading part of file {}", self.key))?;
+                let e_tag = part
+                    .e_tag()
+                    .with_context(|| format!("missing e_tag for part of file {}", self.key))?;
+                parts.push(
+                    CompletedPart::builder()
+                        .e_tag(e_tag)
+                        .part_number(part_number)
+                        .build(),
+                );
+            }
+            (Destination::LocalDirectory { .. }, PartialFile::Local { file, .. }) => {
+                file.write_all(&data)
+                    .await
+                    .with_context(|| format!("error writing file {}", self.key))?;
+            }
+            _ => unreachable!("file started for a different destination"),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use arrow2::array::PrimitiveArray;
+    use arrow2::io::parquet::read::{infer_schema, read_metadata, FileReader};
+    use mz_build_info::DUMMY_BUILD_INFO;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_ore::now::SYSTEM_TIME;
+    use mz_persist_client::cache::PersistClientCache;
+    use mz_persist_client::cfg::PersistConfig;
+    use mz_persist_client::PersistLocation;
+    use mz_repr::{Datum, ScalarType};
+
+    use super::*;
+
+    async fn persist_client() -> PersistClient {
+        let cache = PersistClientCache::new(
+            PersistConfig::new(&DUMMY_BUILD_INFO, SYSTEM_TIME.clone()),
+            &MetricsRegistry::new(),
+        );
+        cache
+            .open(PersistLocation {
+                blob_uri: "mem://".to_owned(),
+                consensus_uri: "mem://".to_owned(),
+            })
+            .await
+            .expect("error opening persist client")
+    }
+
+    async fn writer(
+        client: &PersistClient,
+        progress_shard: ShardId,
+        path: &Path,
+    ) -> FileSinkWriter {
+        let progress = open_progress(client, progress_shard, GlobalId::User(1))
+            .await
+            .unwrap();
+        let as_of = SinkAsOf {
+            frontier: Antichain::from_elem(Timestamp::from(2)),
+            strict: false,
+        };
+        let value_desc = RelationDesc::empty().with_column("a", ScalarType::Int64.nullable(false));
+        FileSinkWriter::new(
+            Destination::LocalDirectory { path: path.into() },
+            progress,
+            value_desc,
+            as_of,
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn push(writer: &mut FileSinkWriter, a: i64, time: u64, diff: Diff) {
+        let row = Row::pack_slice(&[Datum::Int64(a)]);
+        writer.push(row, time.into(), diff).await.unwrap();
+    }
+
+    async fn advance(writer: &mut FileSinkWriter, upper: Option<u64>) {
+        let frontier = Antichain::from_iter(upper.map(Timestamp::from));
+        writer.advance(frontier).await.unwrap();
+    }
+
+    /// Returns the name of each file in `path` and the `(a, mz_timestamp,
+    /// mz_diff)` updates it contains, in name order.
+    fn read_files(path: &Path) -> Vec<(String, Vec<(i64, u64, i64)>)> {
+        let mut names: Vec<_> = std::fs::read_dir(path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        names
+            .into_iter()
+            .map(|name| {
+                let mut file = std::fs::File::open(path.join(&name)).unwrap();
+                let metadata = read_metadata(&mut file).unwrap();
+                let schema = infer_schema(&metadata).unwrap();
+                let reader = FileReader::new(file, metadata.row_groups, schema, None, None, None);
+                let mut updates = Vec::new();
+                for chunk in reader {
+                    let chunk = chunk.unwrap();
+                    let column = |i: usize| chunk.arrays()[i].as_any();
+                    let a = column(0).downcast_ref::<PrimitiveArray<i64>>().unwrap();
+                    let time = column(1).downcast_ref::<PrimitiveArray<u64>>().unwrap();
+                    let diff = column(2).downcast_ref::<PrimitiveArray<i64>>().unwrap();
+                    for i in 0..chunk.len() {
+                        updates.push((a.value(i), time.value(i), diff.value(i)));
+                    }
+                }
+                (name, updates)
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `epoll_wait` on OS `linux`
+    async fn test_write_and_resume() {
+        let client = persist_client().await;
+        let progress_shard = ShardId::new();
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut w = writer(&client, progress_shard, dir.path()).await;
+        push(&mut w, 1, 2, 1).await;
+        push(&mut w, 2, 2, 1).await;
+        push(&mut w, 3, 4, 1).await;
+        advance(&mut w, Some(3)).await;
+        push(&mut w, 2, 5, -1).await;
+        advance(&mut w, Some(6)).await;
+        // Crash while the file at 6 is being written, after another instance
+        // of the sink has written a file that it never recorded.
+        push(&mut w, 4, 6, 1).await;
+        drop(w);
+        std::fs::write(
+            dir.path().join(file_key(false, Timestamp::from(6))),
+            b"not a parquet file",
+        )
+        .unwrap();
+
+        // The restarted sink resumes at the recorded upper and removes the
+        // unrecorded and incomplete files. The restarted dataflow replays all
+        // updates beyond the `as_of`, of which only the unwritten ones are
+        // written.
+        let mut w = writer(&client, progress_shard, dir.path()).await;
+        assert_eq!(w.upper(), &Antichain::from_elem(Timestamp::from(6)));
+        assert_eq!(read_files(dir.path()).len(), 2);
+        push(&mut w, 1, 2, 1).await;
+        push(&mut w, 2, 2, 1).await;
+        push(&mut w, 3, 4, 1).await;
+        push(&mut w, 2, 5, -1).await;
+        push(&mut w, 4, 6, 1).await;
+        push(&mut w, 5, 7, 1).await;
+        advance(&mut w, None).await;
+        assert_eq!(w.upper(), &Antichain::new());
+
+        let key = |snapshot, lower: u64| file_key(snapshot, Timestamp::from(lower));
+        assert_eq!(
+            read_files(dir.path()),
+            vec![
+                (key(true, 0), vec![(1, 2, 1), (2, 2, 1)]),
+                (key(false, 3), vec![(3, 4, 1), (2, 5, -1)]),
+                (key(false, 6), vec![(4, 6, 1), (5, 7, 1)]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `epoll_wait` on OS `linux`
+    async fn test_fencing() {
+        let client = persist_client().await;
+        let progress_shard = ShardId::new();
+        let dir = tempfile::tempdir().unwrap();
+
+        let mut stale = writer(&client, progress_shard, dir.path()).await;
+        let mut current = writer(&client, progress_shard, dir.path()).await;
+        advance(&mut current, Some(3)).await;
+
+        let frontier = Antichain::from_elem(Timestamp::from(3));
+        assert!(stale.advance(frontier).await.is_err());
+    }
+}
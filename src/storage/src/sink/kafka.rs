@@ -42,6 +42,7 @@ use tracing::{debug, error, info, warn};
 use mz_interchange::avro::{AvroEncoder, AvroSchemaGenerator};
 use mz_interchange::encode::Encode;
 use mz_interchange::json::JsonEncoder;
+use mz_interchange::protobuf::{ProtobufEncoder, ProtobufSchemaGenerator};
 use mz_kafka_util::client::{
     BrokerRewritingClientContext, MzClientContext, DEFAULT_FETCH_METADATA_TIMEOUT,
 };
@@ -56,8 +57,8 @@ use mz_storage_client::client::SinkStatisticsUpdate;
 use mz_storage_client::types::connections::ConnectionContext;
 use mz_storage_client::types::errors::DataflowError;
 use mz_storage_client::types::sinks::{
-    KafkaSinkConnection, MetadataFilled, PublishedSchemaInfo, SinkAsOf, SinkEnvelope,
-    StorageSinkDesc,
+    KafkaSinkConnection, MetadataFilled, PublishedSchemaFormat, PublishedSchemaInfo, SinkAsOf,
+    SinkEnvelope, StorageSinkDesc,
 };
 use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
 
@@ -963,6 +964,7 @@ where
         Some(PublishedSchemaInfo {
             key_schema_id,
             value_schema_id,
+            format: PublishedSchemaFormat::Avro,
         }) => {
             let schema_generator = AvroSchemaGenerator::new(
                 None,
@@ -981,6 +983,25 @@ where
                 &name,
             )
         }
+        Some(PublishedSchemaInfo {
+            key_schema_id,
+            value_schema_id,
+            format: PublishedSchemaFormat::Protobuf,
+        }) => {
+            let schema_generator = ProtobufSchemaGenerator::new(
+                key_desc,
+                value_desc,
+                matches!(envelope, Some(SinkEnvelope::Debezium)),
+            );
+            let encoder = ProtobufEncoder::new(schema_generator, key_schema_id, value_schema_id);
+            encode_stream(
+                stream,
+                as_of.clone(),
+                Rc::clone(&shared_gate_ts),
+                encoder,
+                &name,
+            )
+        }
         None => {
             let encoder = JsonEncoder::new(
                 key_desc,
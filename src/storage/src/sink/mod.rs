@@ -9,9 +9,11 @@
 
 //! Moving data to external systems
 
+mod file;
 mod healthcheck;
 mod kafka;
 pub mod metrics;
+mod postgres;
 
 pub use healthcheck::{Healthchecker, SinkStatus};
 pub(crate) use metrics::KafkaBaseMetrics;
@@ -0,0 +1,719 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! A sink that upserts into a table in a PostgreSQL database.
+//!
+//! Whenever the frontier of its input advances, the sink applies all updates
+//! that became ready in a single transaction, in timestamp order: keys whose
+//! row was retracted are removed with `DELETE`, and all other rows are written
+//! with `INSERT ... ON CONFLICT DO UPDATE`. The same transaction records the
+//! new upper of the sink in the [`PROGRESS_TABLE`], which lives in the schema
+//! of the target table and is keyed by environment and sink ID. A restarted
+//! sink resumes from the recorded upper, so each timestamp is applied exactly
+//! once.
+//!
+//! Rows are bound to prepared statements as parameters in the PostgreSQL text
+//! format, which the upstream database parses according to the types of the
+//! columns they are written to.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::future;
+use std::rc::Rc;
+
+use anyhow::Context;
+use bytes::{BufMut, BytesMut};
+use differential_dataflow::{Collection, Hashable};
+use itertools::Itertools;
+use postgres_protocol::escape::escape_identifier;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::Scope;
+use timely::progress::{Antichain, Timestamp as _};
+use timely::PartialOrder;
+use tokio_postgres::types::{to_sql_checked, Format, IsNull, ToSql, Type};
+use tokio_postgres::{Client, Statement};
+use tracing::info;
+
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_repr::{Datum, Diff, GlobalId, Row, ScalarType, Timestamp};
+use mz_storage_client::client::SinkStatisticsUpdate;
+use mz_storage_client::types::connections::ConnectionContext;
+use mz_storage_client::types::errors::DataflowError;
+use mz_storage_client::types::sinks::{
+    MetadataFilled, PostgresSinkConnection, SinkAsOf, StorageSinkDesc,
+};
+use mz_timely_util::builder_async::{Event, OperatorBuilder as AsyncOperatorBuilder};
+
+use crate::internal_control::{InternalCommandSender, InternalStorageCommand};
+use crate::render::sinks::{HealthcheckerArgs, SinkRender};
+use crate::sink::{Healthchecker, SinkStatus};
+use crate::statistics::{SinkStatisticsMetrics, StorageStatistics};
+use crate::storage_state::StorageState;
+
+/// The name of the table in which sinks record their progress.
+const PROGRESS_TABLE: &str = "_materialize_sink_progress";
+/// The maximum number of rows written by a single statement.
+const ROWS_PER_STATEMENT: usize = 1024;
+/// The maximum number of parameters that PostgreSQL accepts in a statement.
+const MAX_PARAMETERS: usize = 65535;
+
+impl<G> SinkRender<G> for PostgresSinkConnection
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    fn uses_keys(&self) -> bool {
+        true
+    }
+
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        Some(&self.key_indices)
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn render_continuous_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<MetadataFilled, Timestamp>,
+        sink_id: GlobalId,
+        sinked_collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+        // TODO: errors should stream out through the sink, if we figure out a
+        // protocol for that.
+        _err_collection: Collection<G, DataflowError, Diff>,
+        healthchecker_args: HealthcheckerArgs,
+    ) -> Option<Rc<dyn Any>>
+    where
+        G: Scope<Timestamp = Timestamp>,
+    {
+        let peers = sinked_collection.inner.scope().peers();
+        let worker_index = sinked_collection.inner.scope().index();
+        let active_write_worker = (usize::cast_from(sink_id.hashed()) % peers) == worker_index;
+
+        // Only the active_write_worker will ever produce data so all other
+        // workers have an empty frontier.
+        let shared_frontier = Rc::new(RefCell::new(if active_write_worker {
+            Antichain::from_elem(Timestamp::minimum())
+        } else {
+            Antichain::new()
+        }));
+
+        let token = write_rows(
+            sinked_collection,
+            sink_id,
+            self.clone(),
+            sink.as_of.clone(),
+            Rc::clone(&shared_frontier),
+            storage_state
+                .sink_statistics
+                .get(&sink_id)
+                .expect("statistics initialized")
+                .clone(),
+            storage_state.connection_context.clone(),
+            healthchecker_args,
+            Rc::clone(&storage_state.internal_cmd_tx),
+        );
+
+        storage_state
+            .sink_write_frontiers
+            .insert(sink_id, shared_frontier);
+
+        Some(token)
+    }
+}
+
+struct PostgresSinkState {
+    name: String,
+    sink_id: GlobalId,
+    healthchecker: Option<Healthchecker>,
+    internal_cmd_tx: Rc<RefCell<dyn InternalCommandSender>>,
+}
+
+impl PostgresSinkState {
+    async fn update_status(&mut self, status: SinkStatus) {
+        if let Some(hc) = &mut self.healthchecker {
+            hc.update_status(status).await;
+        }
+    }
+
+    /// Report a SinkStatus::Stalled and then halt with the same message.
+    async fn halt_on_err<T>(&mut self, result: Result<T, anyhow::Error>) -> T {
+        match result {
+            Ok(t) => t,
+            Err(error) => {
+                self.update_status(SinkStatus::Stalled {
+                    error: format!("{}", error.display_with_causes()),
+                    hint: None,
+                })
+                .await;
+                self.internal_cmd_tx.borrow_mut().broadcast(
+                    InternalStorageCommand::SuspendAndRestart {
+                        id: self.sink_id,
+                        reason: error.to_string(),
+                    },
+                );
+
+                // Make sure to never return, preventing the sink from writing
+                // out anything it might regret in the future.
+                future::pending().await
+            }
+        }
+    }
+}
+
+/// Generates the SQL statements that the sink runs against the upstream
+/// database, and caches the prepared statements that write rows.
+struct StatementBuilder {
+    /// The quoted, schema-qualified name of the target table.
+    table: String,
+    /// The quoted, schema-qualified name of the progress table.
+    progress_table: String,
+    /// The quoted names of all columns.
+    columns: Vec<String>,
+    /// The quoted names of the key columns.
+    key_columns: Vec<String>,
+    /// Whether each key column is nullable.
+    key_nullable: Vec<bool>,
+    /// The quoted names of all non-key columns.
+    value_columns: Vec<String>,
+    column_types: Vec<ScalarType>,
+    key_types: Vec<ScalarType>,
+    /// Prepared statements that write the given number of keys or rows.
+    prepared: BTreeMap<(KeyOrValue, usize), Statement>,
+}
+
+impl StatementBuilder {
+    fn new(connection: &PostgresSinkConnection) -> StatementBuilder {
+        let schema = escape_identifier(&connection.schema);
+        let desc = &connection.value_desc;
+        let columns = desc
+            .iter_names()
+            .map(|name| escape_identifier(name.as_str()))
+            .collect::<Vec<_>>();
+        let column_types = desc
+            .typ()
+            .column_types
+            .iter()
+            .map(|typ| typ.scalar_type.clone())
+            .collect::<Vec<_>>();
+        let key_columns = connection
+            .key_indices
+            .iter()
+            .map(|i| columns[*i].clone())
+            .collect();
+        let key_types = connection
+            .key_indices
+            .iter()
+            .map(|i| column_types[*i].clone())
+            .collect();
+        let key_nullable = connection
+            .key_indices
+            .iter()
+            .map(|i| desc.typ().column_types[*i].nullable)
+            .collect();
+        let value_columns = (0..columns.len())
+            .filter(|i| !connection.key_indices.contains(i))
+            .map(|i| columns[i].clone())
+            .collect();
+        StatementBuilder {
+            table: format!("{}.{}", schema, escape_identifier(&connection.table)),
+            progress_table: format!("{}.{}", schema, escape_identifier(PROGRESS_TABLE)),
+            columns,
+            key_columns,
+            key_nullable,
+            value_columns,
+            column_types,
+            key_types,
+            prepared: BTreeMap::new(),
+        }
+    }
+
+    fn create_progress_table(&self) -> String {
+        format!(
+            "CREATE TABLE IF NOT EXISTS {} (
+                environment_id text NOT NULL,
+                sink_id text NOT NULL,
+                upper bigint,
+                PRIMARY KEY (environment_id, sink_id)
+            )",
+            self.progress_table
+        )
+    }
+
+    fn read_progress(&self) -> String {
+        format!(
+            "SELECT upper FROM {} WHERE environment_id = $1 AND sink_id = $2",
+            self.progress_table
+        )
+    }
+
+    fn write_progress(&self) -> String {
+        format!(
+            "INSERT INTO {} (environment_id, sink_id, upper) VALUES ($1, $2, $3)
+            ON CONFLICT (environment_id, sink_id) DO UPDATE SET upper = EXCLUDED.upper",
+            self.progress_table
+        )
+    }
+
+    /// The maximum number of rows written by a single statement, which keeps
+    /// the number of parameters within the limit of PostgreSQL.
+    fn rows_per_statement(&self) -> usize {
+        ROWS_PER_STATEMENT.min(MAX_PARAMETERS / self.columns.len().max(1))
+    }
+
+    /// Returns a statement that deletes the rows with `count` keys.
+    ///
+    /// Nullable key columns are compared with `IS NOT DISTINCT FROM`, so that
+    /// keys containing `NULL` match their row. All other key columns are
+    /// compared with `=`, which allows PostgreSQL to use the unique index.
+    fn delete(&self, count: usize) -> String {
+        let width = self.key_columns.len();
+        let conditions = (0..count)
+            .map(|i| {
+                let condition = self
+                    .key_columns
+                    .iter()
+                    .zip(&self.key_nullable)
+                    .enumerate()
+                    .map(|(j, (column, nullable))| {
+                        let op = if *nullable {
+                            "IS NOT DISTINCT FROM"
+                        } else {
+                            "="
+                        };
+                        format!("{} {} ${}", column, op, i * width + j + 1)
+                    })
+                    .join(" AND ");
+                format!("({})", condition)
+            })
+            .join(" OR ");
+        format!("DELETE FROM {} WHERE {}", self.table, conditions)
+    }
+
+    /// Returns a statement that inserts `count` rows, replacing any existing
+    /// rows with the same key.
+    fn upsert(&self, count: usize) -> String {
+        let on_conflict = if self.value_columns.is_empty() {
+            "DO NOTHING".to_string()
+        } else {
+            let assignments = self
+                .value_columns
+                .iter()
+                .map(|col| format!("{} = EXCLUDED.{}", col, col))
+                .join(", ");
+            format!("DO UPDATE SET {}", assignments)
+        };
+        format!(
+            "INSERT INTO {} ({}) VALUES {} ON CONFLICT ({}) {}",
+            self.table,
+            self.columns.join(", "),
+            placeholders(count, self.columns.len()),
+            self.key_columns.join(", "),
+            on_conflict
+        )
+    }
+
+    /// Returns the statement that deletes `count` keys or upserts `count`
+    /// rows, preparing it on `client` if it was not prepared before.
+    async fn prepare(
+        &mut self,
+        client: &Client,
+        kind: KeyOrValue,
+        count: usize,
+    ) -> Result<Statement, anyhow::Error> {
+        if let Some(statement) = self.prepared.get(&(kind, count)) {
+            return Ok(statement.clone());
+        }
+        let sql = match kind {
+            KeyOrValue::Key => self.delete(count),
+            KeyOrValue::Value => self.upsert(count),
+        };
+        let statement = client
+            .prepare(&sql)
+            .await
+            .context("error preparing statement")?;
+        self.prepared.insert((kind, count), statement.clone());
+        Ok(statement)
+    }
+
+    /// Encodes the datums of `row` as parameters of a statement.
+    fn params(&self, row: &Row, kind: KeyOrValue) -> impl Iterator<Item = TextParam> + '_ {
+        let types = match kind {
+            KeyOrValue::Key => &self.key_types,
+            KeyOrValue::Value => &self.column_types,
+        };
+        row.iter()
+            .zip(types)
+            .map(|(datum, typ)| TextParam::new(datum, typ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum KeyOrValue {
+    Key,
+    Value,
+}
+
+/// Renders `count` parenthesized lists of `width` consecutive parameters.
+fn placeholders(count: usize, width: usize) -> String {
+    (0..count)
+        .map(|i| {
+            let params = (1..=width)
+                .map(|j| format!("${}", i * width + j))
+                .join(", ");
+            format!("({})", params)
+        })
+        .join(", ")
+}
+
+/// A statement parameter in the PostgreSQL text format.
+///
+/// Sending every parameter as text lets the upstream database parse it
+/// according to the type it infers for the parameter, which is the type of the
+/// column it is written to.
+#[derive(Debug)]
+struct TextParam(Option<BytesMut>);
+
+impl TextParam {
+    fn new(datum: Datum, typ: &ScalarType) -> TextParam {
+        TextParam(mz_pgrepr::Value::from_datum(datum, typ).map(|value| {
+            let mut buf = BytesMut::new();
+            value.encode_text(&mut buf);
+            buf
+        }))
+    }
+
+    fn len(&self) -> usize {
+        self.0.as_ref().map_or(0, |buf| buf.len())
+    }
+}
+
+impl ToSql for TextParam {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match &self.0 {
+            None => Ok(IsNull::Yes),
+            Some(buf) => {
+                out.put_slice(buf);
+                Ok(IsNull::No)
+            }
+        }
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+
+    fn encode_format(&self, _: &Type) -> Format {
+        Format::Text
+    }
+
+    to_sql_checked!();
+}
+
+/// Converts a sink upper to its representation in the progress table, where
+/// `NULL` marks a sink whose input is closed.
+fn progress_upper(upper: &Antichain<Timestamp>) -> Result<Option<i64>, anyhow::Error> {
+    upper
+        .as_option()
+        .map(|t| i64::try_from(u64::from(*t)).context("sink upper out of range"))
+        .transpose()
+}
+
+/// Upserts the rows of `collection` into the table described by `connection`.
+///
+/// Exactly one worker writes all of the rows. Updates that are not beyond the
+/// given [`SinkAsOf`], or that were written before a restart, are discarded.
+fn write_rows<G>(
+    collection: Collection<G, (Option<Row>, Option<Row>), Diff>,
+    sink_id: GlobalId,
+    connection: PostgresSinkConnection,
+    as_of: SinkAsOf,
+    write_frontier: Rc<RefCell<Antichain<Timestamp>>>,
+    sink_statistics: StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+    connection_context: ConnectionContext,
+    healthchecker_args: HealthcheckerArgs,
+    internal_cmd_tx: Rc<RefCell<dyn InternalCommandSender>>,
+) -> Rc<dyn Any>
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let name = format!("postgres-{}", sink_id);
+    let scope = collection.scope();
+    let worker_id = scope.index();
+    let worker_count = scope.peers();
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), scope);
+
+    // We want exactly one worker to write all the rows.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % worker_count == worker_id;
+
+    let mut input = builder.new_input(&collection.inner, Exchange::new(move |_| hashed_id));
+
+    let button = builder.build(move |_capabilities| async move {
+        if !is_active_worker {
+            return;
+        }
+
+        let healthchecker = match healthchecker_args.status_shard_id {
+            Some(status_shard_id) => Some(
+                Healthchecker::new(
+                    sink_id,
+                    &healthchecker_args.persist_clients,
+                    healthchecker_args.persist_location.clone(),
+                    status_shard_id,
+                    healthchecker_args.now_fn.clone(),
+                )
+                .await
+                .expect("error initializing healthchecker"),
+            ),
+            None => None,
+        };
+        let mut s = PostgresSinkState {
+            name,
+            sink_id,
+            healthchecker,
+            internal_cmd_tx,
+        };
+
+        s.update_status(SinkStatus::Starting).await;
+
+        let mut statements = StatementBuilder::new(&connection);
+        let environment_id = connection.environment_id.clone();
+        let sink_id_str = sink_id.to_string();
+
+        let client = connect(&connection, &connection_context, &statements).await;
+        let mut client = s.halt_on_err(client).await;
+
+        let progress = client
+            .query_opt(
+                statements.read_progress().as_str(),
+                &[&environment_id, &sink_id_str],
+            )
+            .await
+            .context("error reading postgres sink progress")
+            .map(|row| row.map(|row| row.get::<_, Option<i64>>(0)));
+        let progress = s.halt_on_err(progress).await;
+        info!(
+            "{}: initial as_of: {:?}, latest progress record: {:?}",
+            s.name, as_of.frontier, progress
+        );
+
+        // The upper of all updates written so far.
+        let mut upper = match progress {
+            Some(Some(upper)) => {
+                let upper = u64::try_from(upper).context("invalid postgres sink progress");
+                Antichain::from_elem(Timestamp::from(s.halt_on_err(upper).await))
+            }
+            Some(None) => Antichain::new(),
+            None => Antichain::from_elem(Timestamp::minimum()),
+        };
+        write_frontier.borrow_mut().clone_from(&upper);
+
+        s.update_status(SinkStatus::Running).await;
+
+        let mut pending: Vec<(Timestamp, Row, Option<Row>)> = Vec::new();
+        while let Some(event) = input.next_mut().await {
+            match event {
+                Event::Data(_, rows) => {
+                    for ((key, value), time, _diff) in rows.drain(..) {
+                        let should_emit = if as_of.strict {
+                            as_of.frontier.less_than(&time)
+                        } else {
+                            as_of.frontier.less_equal(&time)
+                        };
+                        let previously_written = !upper.less_equal(&time);
+                        if !should_emit || previously_written {
+                            continue;
+                        }
+                        let key = key.expect("postgres sinks have a key");
+                        pending.push((time, key, value));
+                    }
+                }
+                Event::Progress(frontier) => {
+                    if !PartialOrder::less_than(&upper, &frontier) {
+                        continue;
+                    }
+
+                    let (ready, not_ready) = pending
+                        .drain(..)
+                        .partition::<Vec<_>, _>(|(time, _, _)| !frontier.less_equal(time));
+                    pending = not_ready;
+
+                    // The latest row for each key, per timestamp. Keys are
+                    // only repeated within a timestamp if the sink key is
+                    // `NOT ENFORCED`, in which case an arbitrary row wins.
+                    let mut by_time: BTreeMap<Timestamp, BTreeMap<Row, Option<Row>>> =
+                        BTreeMap::new();
+                    for (time, key, value) in ready {
+                        by_time.entry(time).or_default().insert(key, value);
+                    }
+
+                    let written = write_updates(
+                        &mut client,
+                        &mut statements,
+                        &environment_id,
+                        &sink_id_str,
+                        &by_time,
+                        &frontier,
+                        &sink_statistics,
+                    )
+                    .await
+                    .with_context(|| format!("error writing updates up to {:?}", frontier));
+                    s.halt_on_err(written).await;
+
+                    upper.clone_from(&frontier);
+                    write_frontier.borrow_mut().clone_from(&frontier);
+                }
+            }
+        }
+    });
+
+    Rc::new(button.press_on_drop())
+}
+
+/// Connects to the upstream database and ensures that the progress table
+/// exists.
+async fn connect(
+    connection: &PostgresSinkConnection,
+    connection_context: &ConnectionContext,
+    statements: &StatementBuilder,
+) -> Result<Client, anyhow::Error> {
+    let client = connection
+        .connection
+        .config(&*connection_context.secrets_reader)
+        .await
+        .context("error creating postgres config")?
+        .connect("postgres_sink")
+        .await
+        .context("error connecting to postgres")?;
+    client
+        .batch_execute(&statements.create_progress_table())
+        .await
+        .context("error creating postgres sink progress table")?;
+    Ok(client)
+}
+
+/// Applies the updates at each timestamp in time order, and records `upper` as
+/// the new upper of the sink, in one transaction.
+///
+/// Nothing is written if there are no updates: a restarted sink resumes from
+/// the last recorded upper and finds no updates to apply before `upper`.
+async fn write_updates(
+    client: &mut Client,
+    statements: &mut StatementBuilder,
+    environment_id: &str,
+    sink_id: &str,
+    by_time: &BTreeMap<Timestamp, BTreeMap<Row, Option<Row>>>,
+    upper: &Antichain<Timestamp>,
+    sink_statistics: &StorageStatistics<SinkStatisticsUpdate, SinkStatisticsMetrics>,
+) -> Result<(), anyhow::Error> {
+    if by_time.is_empty() {
+        return Ok(());
+    }
+
+    let rows_per_statement = statements.rows_per_statement();
+    let mut batches = Vec::new();
+    for updates in by_time.values() {
+        let deletes = updates
+            .iter()
+            .filter(|(_, value)| value.is_none())
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        let upserts = updates.values().flatten().collect::<Vec<_>>();
+        for (kind, rows) in [(KeyOrValue::Key, deletes), (KeyOrValue::Value, upserts)] {
+            for rows in rows.chunks(rows_per_statement) {
+                let params = rows
+                    .iter()
+                    .flat_map(|row| statements.params(row, kind))
+                    .collect::<Vec<_>>();
+                let statement = statements.prepare(client, kind, rows.len()).await?;
+                batches.push((statement, params));
+            }
+        }
+    }
+
+    let count = u64::cast_from(by_time.values().map(|updates| updates.len()).sum::<usize>());
+    let size = u64::cast_from(
+        batches
+            .iter()
+            .flat_map(|(_, params)| params)
+            .map(|param| param.len())
+            .sum::<usize>(),
+    );
+    sink_statistics.inc_messages_staged_by(count);
+    sink_statistics.inc_bytes_staged_by(size);
+
+    let progress = progress_upper(upper)?;
+    let txn = client
+        .transaction()
+        .await
+        .context("error starting transaction")?;
+    for (statement, params) in &batches {
+        let params = params
+            .iter()
+            .map(|param| param as &(dyn ToSql + Sync))
+            .collect::<Vec<_>>();
+        txn.execute(statement, &params).await?;
+    }
+    txn.execute(
+        statements.write_progress().as_str(),
+        &[&environment_id, &sink_id, &progress],
+    )
+    .await
+    .context("error writing postgres sink progress")?;
+    txn.commit().await.context("error committing transaction")?;
+
+    sink_statistics.inc_messages_committed_by(count);
+    sink_statistics.inc_bytes_committed_by(size);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statement_builder(key_nullable: Vec<bool>) -> StatementBuilder {
+        let key_columns = (0..key_nullable.len())
+            .map(|i| escape_identifier(&format!("k{}", i)))
+            .collect::<Vec<_>>();
+        StatementBuilder {
+            table: format!("{}.{}", escape_identifier("s"), escape_identifier("t")),
+            progress_table: escape_identifier(PROGRESS_TABLE),
+            columns: key_columns.clone(),
+            key_types: vec![ScalarType::Int32; key_columns.len()],
+            column_types: vec![ScalarType::Int32; key_columns.len()],
+            key_columns,
+            key_nullable,
+            value_columns: vec![],
+            prepared: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_delete_null_keys() {
+        let statements = statement_builder(vec![false, true]);
+        assert_eq!(
+            statements.delete(2),
+            r#"DELETE FROM "s"."t" WHERE ("k0" = $1 AND "k1" IS NOT DISTINCT FROM $2) OR ("k0" = $3 AND "k1" IS NOT DISTINCT FROM $4)"#
+        );
+
+        // A `NULL` key column is bound as a `NULL` parameter, which only
+        // matches the row through `IS NOT DISTINCT FROM`.
+        let key = Row::pack_slice(&[Datum::Int32(1), Datum::Null]);
+        let params = statements
+            .params(&key, KeyOrValue::Key)
+            .map(|param| param.0)
+            .collect::<Vec<_>>();
+        assert_eq!(params, vec![Some(BytesMut::from("1")), None]);
+    }
+}
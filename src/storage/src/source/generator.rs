@@ -105,7 +105,7 @@ impl SourceRender for LoadGeneratorSourceConnection {
                 Antichain::from_iter(config.source_resume_upper.iter().map(MzOffset::decode_row));
 
             let Some(mut offset) = resume_upper.into_option() else {
-                return
+                return;
             };
             cap.downgrade(&offset);
 
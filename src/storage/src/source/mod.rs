@@ -31,6 +31,7 @@ use crate::source::types::SourceReaderError;
 pub mod generator;
 mod kafka;
 pub mod metrics;
+mod mysql;
 mod postgres;
 pub(crate) mod reclock;
 mod resumption;
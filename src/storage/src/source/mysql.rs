@@ -0,0 +1,1152 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the ingestion dataflow of a [`MySqlSourceConnection`].
+//!
+//! The source's offsets are the transaction numbers of the GTIDs that
+//! originate on the upstream server identified by
+//! [`MySqlSourceDetails::server_uuid`]. A transaction with GTID `uuid:N` is
+//! emitted at offset `N`, so a resume upper of `N` means that every
+//! transaction up to and including `uuid:N-1` has been ingested.
+//!
+//! [`MySqlSourceDetails::server_uuid`]: mz_storage_client::types::sources::MySqlSourceDetails::server_uuid
+
+use std::any::Any;
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt::Write;
+use std::future;
+use std::rc::Rc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use differential_dataflow::{AsCollection, Collection};
+use futures::StreamExt;
+use mysql_async::binlog::events::{EventData, RowsEventData};
+use mysql_async::binlog::value::BinlogValue;
+use mysql_async::prelude::Queryable;
+use mysql_async::{BinlogRequest, Value};
+use timely::dataflow::{Scope, Stream};
+use timely::progress::Antichain;
+use tokio::sync::mpsc::Sender;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use mz_expr::MirScalarExpr;
+use mz_mysql_util::desc::{MySqlColumnDesc, MySqlTableDesc};
+use mz_mysql_util::{quote_identifier, GtidSet, MySqlError};
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::task;
+use mz_repr::{Datum, Diff, GlobalId, Row};
+use mz_storage_client::types::connections::ConnectionContext;
+use mz_storage_client::types::errors::SourceErrorDetails;
+use mz_storage_client::types::sources::{MySqlSourceConnection, MzOffset};
+use mz_timely_util::builder_async::OperatorBuilder as AsyncOperatorBuilder;
+
+use crate::source::types::{HealthStatus, HealthStatusUpdate, SourceRender};
+use crate::source::{RawSourceCreationConfig, SourceMessage, SourceReaderError};
+
+enum ReplicationError {
+    /// This error is definite: this source is permanently wedged.
+    /// Returning a definite error will cause the collection to become un-queryable.
+    Definite(anyhow::Error),
+    /// This error may or may not resolve itself in the future, and
+    /// should be retried instead of being added to the output.
+    Indefinite(anyhow::Error),
+    /// When this error happens we must halt
+    Irrecoverable(anyhow::Error),
+}
+
+impl From<MySqlError> for ReplicationError {
+    fn from(err: MySqlError) -> Self {
+        match err.server_code() {
+            // The upstream tables have been dropped or we have lost access to them, or the
+            // binlog we need to resume from has been purged. None of these resolve themselves.
+            Some(
+                mz_mysql_util::ER_NO_SUCH_TABLE
+                | mz_mysql_util::ER_TABLEACCESS_DENIED_ERROR
+                | mz_mysql_util::ER_MASTER_FATAL_ERROR_READING_BINLOG,
+            ) => Self::Definite(err.into()),
+            // We have no information about what happened, so we adopt a "indefinite unless
+            // proven otherwise" policy and keep retrying.
+            _ => Self::Indefinite(err.into()),
+        }
+    }
+}
+
+impl From<mysql_async::Error> for ReplicationError {
+    fn from(err: mysql_async::Error) -> Self {
+        MySqlError::from(err).into()
+    }
+}
+
+trait ResultExt<T, E> {
+    fn err_definite(self) -> Result<T, ReplicationError>;
+    fn err_indefinite(self) -> Result<T, ReplicationError>;
+}
+
+impl<T, E: Into<anyhow::Error>> ResultExt<T, E> for Result<T, E> {
+    fn err_definite(self) -> Result<T, ReplicationError> {
+        match self {
+            Ok(val) => Ok(val),
+            Err(err) => Err(ReplicationError::Definite(err.into())),
+        }
+    }
+    fn err_indefinite(self) -> Result<T, ReplicationError> {
+        match self {
+            Ok(val) => Ok(val),
+            Err(err) => Err(ReplicationError::Indefinite(err.into())),
+        }
+    }
+}
+
+/// Message used to communicate between the source operator and the tokio task
+#[derive(Debug)]
+enum InternalMessage {
+    /// A definite error for the source, i.e. not a subsource error.
+    Err(SourceReaderError),
+    Status(HealthStatusUpdate),
+    /// A value meant for a subsource.
+    Value {
+        transaction_id: u64,
+        /// Errors sent here are meant to express a subsource error that occurred in the process
+        /// of decoding values, i.e. it is in lieu of a value.
+        value: Result<(Row, Diff), anyhow::Error>,
+        end: bool,
+    },
+    /// All transactions before `upper` have been emitted.
+    Progress {
+        upper: u64,
+    },
+}
+
+/// Information about an ingested upstream table
+#[derive(Debug)]
+struct SourceTable {
+    /// The source output index of this table
+    output_index: usize,
+    /// The description of this table at the time the source was created
+    desc: MySqlTableDesc,
+    /// The scalar expressions required to cast the text encoded columns received from MySQL
+    /// into the target relational types
+    casts: Vec<MirScalarExpr>,
+}
+
+/// An internal struct held by the spawned tokio task
+struct MySqlTaskInfo {
+    source_id: GlobalId,
+    connection_config: mz_mysql_util::Config,
+    /// The UUID of the upstream server whose transactions we ingest.
+    server_uuid: Uuid,
+    /// The server ID we identify ourselves with when reading the binlog.
+    server_id: u32,
+    /// Our cursor into the binlog: every transaction before this one has been emitted. Zero
+    /// means that the initial snapshot has yet to be taken.
+    upper: u64,
+    /// A map of the table's `(schema, name)` to its information.
+    ///
+    /// As with Postgres sources, tables are only removed from this map when they encounter
+    /// errors, so they may reappear when the source restarts.
+    source_tables: BTreeMap<(String, String), SourceTable>,
+    row_sender: RowSender,
+    sender: Sender<(usize, InternalMessage)>,
+}
+
+impl SourceRender for MySqlSourceConnection {
+    type Key = ();
+    type Value = Row;
+    type Time = MzOffset;
+
+    fn render<G: Scope<Timestamp = MzOffset>>(
+        self,
+        scope: &mut G,
+        config: RawSourceCreationConfig,
+        connection_context: ConnectionContext,
+        _resume_uppers: impl futures::Stream<Item = Antichain<MzOffset>> + 'static,
+    ) -> (
+        Collection<
+            G,
+            (
+                usize,
+                Result<SourceMessage<Self::Key, Self::Value>, SourceReaderError>,
+            ),
+            Diff,
+        >,
+        Option<Stream<G, Infallible>>,
+        Stream<G, (usize, HealthStatusUpdate)>,
+        Rc<dyn Any>,
+    ) {
+        let mut builder = AsyncOperatorBuilder::new(config.name.clone(), scope.clone());
+
+        let (mut data_output, stream) = builder.new_output();
+        let (mut _upper_output, progress) = builder.new_output();
+        let (mut health_output, health_stream) = builder.new_output();
+
+        let button = builder.build(move |mut capabilities| async move {
+            let health_capability = capabilities.pop().unwrap();
+            let mut upper_capability = capabilities.pop().unwrap();
+            let mut data_capability = capabilities.pop().unwrap();
+            assert!(capabilities.is_empty());
+
+            if !config.responsible_for(()) {
+                return;
+            }
+
+            let (dataflow_tx, mut dataflow_rx) = tokio::sync::mpsc::channel(50_000);
+
+            let resume_upper =
+                Antichain::from_iter(config.source_resume_upper.iter().map(MzOffset::decode_row));
+            let Some(start_offset) = resume_upper.into_option() else {
+                return;
+            };
+            data_capability.downgrade(&start_offset);
+            upper_capability.downgrade(&start_offset);
+
+            let setup = async {
+                let connection_config = self
+                    .connection
+                    .config(&*connection_context.secrets_reader)
+                    .await
+                    .map_err(|e| {
+                        format!("failed creating mysql config: {}", e.display_with_causes())
+                    })?;
+                let server_uuid = Uuid::parse_str(&self.details.server_uuid)
+                    .map_err(|e| format!("invalid server UUID: {}", e.display_with_causes()))?;
+                Ok::<_, String>((connection_config, server_uuid))
+            };
+            let (connection_config, server_uuid) = match setup.await {
+                Ok(setup) => setup,
+                Err(error) => {
+                    let update = HealthStatusUpdate {
+                        update: HealthStatus::StalledWithError { error, hint: None },
+                        should_halt: true,
+                    };
+                    let primary_output_index = 0;
+                    health_output
+                        .give(&health_capability, (primary_output_index, update))
+                        .await;
+                    // IMPORTANT: wedge forever until the `SuspendAndRestart` is processed.
+                    // Returning would incorrectly present to the remap operator as progress to the
+                    // empty frontier which would be incorrectly recorded to the remap shard.
+                    std::future::pending::<()>().await;
+                    unreachable!("pending future never returns");
+                }
+            };
+
+            let mut source_tables = BTreeMap::new();
+            for (i, desc) in self.details.tables.into_iter().enumerate() {
+                let output_index = i + 1;
+                // As with Postgres sources, we only ingest the tables we have casts for.
+                if let Some(casts) = self.table_casts.get(&output_index) {
+                    let source_table = SourceTable {
+                        output_index,
+                        desc: desc.clone(),
+                        casts: casts.to_vec(),
+                    };
+                    source_tables.insert((desc.schema_name, desc.name), source_table);
+                }
+            }
+
+            let task_info = MySqlTaskInfo {
+                source_id: config.id,
+                connection_config,
+                server_uuid,
+                server_id: self.details.server_id,
+                upper: start_offset.offset,
+                source_tables,
+                row_sender: RowSender::new(dataflow_tx.clone()),
+                sender: dataflow_tx,
+            };
+
+            task::spawn(|| format!("mysql_source:{}", config.id), {
+                mysql_replication_loop(task_info)
+            });
+
+            // The transaction we last emitted data at. Used to fabricate timestamps for errors.
+            let mut last_transaction_id = start_offset.offset;
+
+            while let Some(message) = dataflow_rx.recv().await {
+                match message {
+                    (
+                        output_index,
+                        InternalMessage::Value {
+                            transaction_id,
+                            value,
+                            end,
+                        },
+                    ) => {
+                        mz_ore::soft_assert!(
+                            output_index != 0,
+                            "InternalMessage::Value is meant only for subsources"
+                        );
+
+                        last_transaction_id = transaction_id;
+                        let (msg, diff) = match value {
+                            Ok((row, diff)) => (
+                                Ok(SourceMessage {
+                                    upstream_time_millis: None,
+                                    key: (),
+                                    value: row,
+                                    headers: None,
+                                }),
+                                diff,
+                            ),
+                            Err(err) => (Err(SourceReaderError::other_definite(err)), 1),
+                        };
+
+                        let ts = MzOffset::from(transaction_id);
+                        let cap = data_capability.delayed(&ts);
+                        let next_ts = ts + 1;
+                        upper_capability.downgrade(&next_ts);
+                        if end {
+                            data_capability.downgrade(&next_ts);
+                        }
+                        data_output
+                            .give(&cap, ((output_index, msg), *cap.time(), diff))
+                            .await;
+                    }
+                    (_, InternalMessage::Progress { upper }) => {
+                        let upper = MzOffset::from(upper);
+                        data_capability.downgrade(&upper);
+                        upper_capability.downgrade(&upper);
+                    }
+                    (output_index, InternalMessage::Status(update)) => {
+                        health_output
+                            .give(&health_capability, (output_index, update))
+                            .await;
+                    }
+                    (output_index, InternalMessage::Err(err)) => {
+                        mz_ore::soft_assert!(
+                            output_index == 0,
+                            "InternalMessage::Err is meant only for the primary source"
+                        );
+
+                        // We are fabricating a timestamp here, as Postgres sources do.
+                        let non_definite_ts = MzOffset::from(last_transaction_id) + 1;
+
+                        let cap = data_capability.delayed(&non_definite_ts);
+                        let next_ts = non_definite_ts + 1;
+                        data_capability.downgrade(&next_ts);
+                        upper_capability.downgrade(&next_ts);
+                        data_output
+                            .give(&cap, ((output_index, Err(err)), *cap.time(), 1))
+                            .await;
+                    }
+                }
+            }
+        });
+
+        (
+            stream.as_collection(),
+            Some(progress),
+            health_stream,
+            Rc::new(button.press_on_drop()),
+        )
+    }
+}
+
+/// Defers to `mysql_replication_loop_inner` and sends errors through the channel if they occur
+async fn mysql_replication_loop(mut task_info: MySqlTaskInfo) {
+    loop {
+        // Signal that the primary source is running.
+        let _ = task_info
+            .sender
+            .send((
+                0,
+                InternalMessage::Status(HealthStatusUpdate {
+                    update: HealthStatus::Running,
+                    should_halt: false,
+                }),
+            ))
+            .await;
+
+        match mysql_replication_loop_inner(&mut task_info).await {
+            Ok(()) => {}
+            Err(ReplicationError::Indefinite(e)) => {
+                warn!(
+                    "replication for source {} interrupted, retrying: {e}",
+                    task_info.source_id
+                );
+                // Indefinite errors affect all subsources.
+                for output_index in std::iter::once(0)
+                    .chain(task_info.source_tables.values().map(|t| t.output_index))
+                {
+                    // If the channel is shutting down, so is the source.
+                    let _ = task_info
+                        .sender
+                        .send((
+                            output_index,
+                            InternalMessage::Status(HealthStatusUpdate {
+                                update: HealthStatus::StalledWithError {
+                                    error: e.to_string_with_causes(),
+                                    hint: None,
+                                },
+                                should_halt: false,
+                            }),
+                        ))
+                        .await;
+                }
+            }
+            Err(ReplicationError::Irrecoverable(e)) => {
+                warn!(
+                    "irrecoverable error for source {}: {}",
+                    &task_info.source_id,
+                    e.display_with_causes()
+                );
+                // Irrecoverable errors affect all subsources.
+                for output_index in std::iter::once(0)
+                    .chain(task_info.source_tables.values().map(|t| t.output_index))
+                {
+                    // If the channel is shutting down, so is the source.
+                    let _ = task_info
+                        .sender
+                        .send((
+                            output_index,
+                            InternalMessage::Status(HealthStatusUpdate {
+                                update: HealthStatus::StalledWithError {
+                                    error: e.to_string_with_causes(),
+                                    hint: None,
+                                },
+                                // Halting discards any partially emitted snapshot; the restarted
+                                // clusterd instance will take the snapshot afresh. Only the
+                                // primary source halts, though all of them error.
+                                should_halt: output_index == 0,
+                            }),
+                        ))
+                        .await;
+                }
+
+                future::pending().await
+            }
+            Err(ReplicationError::Definite(e)) => {
+                warn!(
+                    "definite error for source {}: {}",
+                    &task_info.source_id,
+                    e.display_with_causes()
+                );
+
+                // Invent a transaction to send these new definite errors at.
+                let transaction_id = task_info.upper;
+
+                // Definite errors affect all subsources in a way that they should be errored out.
+                for output_index in task_info.source_tables.values().map(|t| t.output_index) {
+                    task_info
+                        .row_sender
+                        .send_row(transaction_id, output_index, Err(anyhow!(e.to_string())))
+                        .await;
+                }
+
+                // Close the transaction to "commit" the messages we just sent.
+                task_info.row_sender.close(transaction_id).await;
+
+                // Drop the send error, as we have no way of communicating back to the
+                // source operator if the channel is gone.
+                let _ = task_info
+                    .sender
+                    .send((
+                        0,
+                        InternalMessage::Err(SourceReaderError {
+                            inner: SourceErrorDetails::Initialization(e.to_string()),
+                        }),
+                    ))
+                    .await;
+                return;
+            }
+        }
+        // TODO: implement exponential back-off
+        tokio::time::sleep(Duration::from_secs(3)).await;
+    }
+}
+
+/// Takes the initial snapshot of the source's tables and emits it at the transaction number of
+/// the last transaction it reflects.
+///
+/// Snapshots are read in a consistent-snapshot transaction that is started while all of the
+/// source's tables are locked against writes, which lets us learn exactly which transactions the
+/// snapshot reflects.
+async fn mysql_replication_loop_inner_snapshot(
+    task_info: &mut MySqlTaskInfo,
+) -> Result<(), ReplicationError> {
+    let mut conn = task_info
+        .connection_config
+        .connect()
+        .await
+        .err_indefinite()?;
+    let errors = validate_tables(&mut conn, &mut task_info.source_tables).await?;
+
+    let mut lock_conn = task_info
+        .connection_config
+        .connect()
+        .await
+        .err_indefinite()?;
+    if !task_info.source_tables.is_empty() {
+        let tables = task_info
+            .source_tables
+            .keys()
+            .map(|(schema, name)| {
+                format!(
+                    "{}.{} READ",
+                    quote_identifier(schema),
+                    quote_identifier(name)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        lock_conn
+            .query_drop(format!("LOCK TABLES {tables}"))
+            .await?;
+    }
+
+    // Render `TIMESTAMP` columns in UTC, to match what we receive from the binlog.
+    conn.query_drop("SET SESSION time_zone = '+00:00'").await?;
+    conn.query_drop("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ")
+        .await?;
+    conn.query_drop("START TRANSACTION READ ONLY WITH CONSISTENT SNAPSHOT")
+        .await?;
+    let gtid_executed: GtidSet = mz_mysql_util::query_sys_var(&mut conn, "gtid_executed")
+        .await?
+        .parse()
+        .err_indefinite()?;
+    lock_conn.query_drop("UNLOCK TABLES").await?;
+    drop(lock_conn);
+
+    let snapshot_id = gtid_executed
+        .max_transaction_id(&task_info.server_uuid)
+        .err_definite()?;
+
+    for (output_index, err) in errors {
+        task_info
+            .row_sender
+            .send_row(snapshot_id, output_index, Err(err))
+            .await;
+    }
+
+    let mut tables_to_remove = vec![];
+    'tables: for (key, info) in task_info.source_tables.iter() {
+        let columns = info
+            .desc
+            .columns
+            .iter()
+            .map(|c| quote_identifier(&c.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let query = format!(
+            "SELECT {columns} FROM {}.{}",
+            quote_identifier(&info.desc.schema_name),
+            quote_identifier(&info.desc.name)
+        );
+        let mut result = conn.query_iter(query).await?;
+        while let Some(row) = result.next().await? {
+            let value = row
+                .unwrap()
+                .into_iter()
+                .zip(info.desc.columns.iter())
+                .map(|(value, column)| snapshot_value_to_text(value, column))
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|values| pack_row(&info.casts, values));
+            let is_err = value.is_err();
+            task_info
+                .row_sender
+                .send_row(snapshot_id, info.output_index, value)
+                .await;
+            if is_err {
+                tables_to_remove.push(key.clone());
+                // Drain the rest of the result set so the connection is usable again.
+                result.drop_result().await?;
+                continue 'tables;
+            }
+        }
+    }
+    for key in tables_to_remove {
+        task_info.source_tables.remove(&key);
+    }
+
+    conn.query_drop("COMMIT").await?;
+
+    task_info.row_sender.close(snapshot_id).await;
+    task_info.upper = snapshot_id + 1;
+
+    info!(
+        "replication snapshot for source {} succeeded",
+        &task_info.source_id
+    );
+
+    Ok(())
+}
+
+/// Core logic
+async fn mysql_replication_loop_inner(
+    task_info: &mut MySqlTaskInfo,
+) -> Result<(), ReplicationError> {
+    if task_info.upper == 0 {
+        match mysql_replication_loop_inner_snapshot(task_info).await {
+            // Snapshotting cannot, under any circmstance, return indefinite errors; everything must
+            // be restarted.
+            Err(ReplicationError::Indefinite(err)) => {
+                return Err(ReplicationError::Irrecoverable(err))
+            }
+            o => o?,
+        }
+    }
+
+    let mut conn = task_info
+        .connection_config
+        .connect()
+        .await
+        .err_indefinite()?;
+    let errors = validate_tables(&mut conn, &mut task_info.source_tables).await?;
+    if !errors.is_empty() {
+        for (output_index, err) in errors {
+            task_info
+                .row_sender
+                .send_row(task_info.upper, output_index, Err(err))
+                .await;
+        }
+        task_info.row_sender.close(task_info.upper).await;
+        task_info.upper += 1;
+    }
+
+    // Request every transaction that has not originated on the upstream server, as well as the
+    // ones from the upstream server we have already ingested, so that the binlog stream starts
+    // at exactly our upper.
+    let gtid_executed: GtidSet = mz_mysql_util::query_sys_var(&mut conn, "gtid_executed")
+        .await?
+        .parse()
+        .err_indefinite()?;
+    let gtid_set = gtid_executed
+        .with_transaction_id(task_info.server_uuid, task_info.upper - 1)
+        .err_definite()?;
+    let request = BinlogRequest::new(task_info.server_id)
+        .with_gtid()
+        .with_sids(gtid_set.to_sids());
+    let mut stream = conn.get_binlog_stream(request).await?;
+
+    // The transaction whose events we are currently receiving.
+    let mut current_transaction = None;
+    let mut tables_to_remove = BTreeSet::new();
+
+    while let Some(event) = stream.next().await {
+        let event = event?;
+        let Some(data) = event.read_data().err_indefinite()? else {
+            continue;
+        };
+        match data {
+            EventData::GtidEvent(gtid) => {
+                // DDL statements are not terminated by a commit event, so a new transaction
+                // also ends the previous one.
+                if let Some(transaction_id) = current_transaction.take() {
+                    commit(task_info, transaction_id, &mut tables_to_remove).await;
+                }
+                let uuid = Uuid::from_bytes(gtid.sid());
+                if uuid != task_info.server_uuid {
+                    return Err(ReplicationError::Definite(anyhow!(
+                        "received transaction {}:{} that did not originate on server {}; \
+                        MySQL sources must connect to the primary server",
+                        uuid,
+                        gtid.gno(),
+                        task_info.server_uuid
+                    )));
+                }
+                // Transactions are timestamped by their transaction number, so a transaction
+                // numbered below our upper, e.g. one that was assigned an explicit number with
+                // `gtid_next`, cannot be ingested without losing data.
+                if gtid.gno() < task_info.upper {
+                    return Err(ReplicationError::Definite(anyhow!(
+                        "received transaction {}:{} out of order after transaction {}; \
+                        MySQL sources require transaction numbers to be assigned in order",
+                        uuid,
+                        gtid.gno(),
+                        task_info.upper - 1
+                    )));
+                }
+                current_transaction = Some(gtid.gno());
+            }
+            EventData::RowsEvent(rows) => {
+                let transaction_id = current_transaction
+                    .ok_or_else(|| anyhow!("received rows event outside of a transaction"))
+                    .err_indefinite()?;
+                let tme = stream
+                    .get_tme(rows.table_id())
+                    .ok_or_else(|| anyhow!("received rows event for unknown table map"))
+                    .err_indefinite()?;
+                let key = (
+                    tme.database_name().into_owned(),
+                    tme.table_name().into_owned(),
+                );
+                if tables_to_remove.contains(&key) {
+                    continue;
+                }
+                let Some(info) = task_info.source_tables.get(&key) else {
+                    continue;
+                };
+
+                if tme.columns_count() != u64::cast_from(info.desc.columns.len()) {
+                    warn!(
+                        "alter table error: name {}.{}, expected {} columns, found {}",
+                        info.desc.schema_name,
+                        info.desc.name,
+                        info.desc.columns.len(),
+                        tme.columns_count()
+                    );
+                    let err = anyhow!(
+                        "source table {}.{} has been altered",
+                        info.desc.schema_name,
+                        info.desc.name
+                    );
+                    task_info
+                        .row_sender
+                        .send_row(transaction_id, info.output_index, Err(err))
+                        .await;
+                    tables_to_remove.insert(key);
+                    continue;
+                }
+
+                if let RowsEventData::PartialUpdateRowsEvent(_) = rows {
+                    let err = anyhow!(
+                        "received partial JSON update for table {}.{}; \
+                        set binlog_row_value_options to '' upstream",
+                        info.desc.schema_name,
+                        info.desc.name
+                    );
+                    task_info
+                        .row_sender
+                        .send_row(transaction_id, info.output_index, Err(err))
+                        .await;
+                    tables_to_remove.insert(key);
+                    continue;
+                }
+
+                for row in rows.rows(tme) {
+                    let (before, after) = row.err_indefinite()?;
+                    let updates = before
+                        .map(|before| (before, -1))
+                        .into_iter()
+                        .chain(after.map(|after| (after, 1)));
+                    for (row, diff) in updates {
+                        let value = row
+                            .unwrap()
+                            .into_iter()
+                            .zip(info.desc.columns.iter())
+                            .map(|(value, column)| binlog_value_to_text(value, column))
+                            .collect::<Result<Vec<_>, _>>()
+                            .and_then(|values| pack_row(&info.casts, values))
+                            .map(|row| (row, diff));
+                        let is_err = value.is_err();
+                        task_info
+                            .row_sender
+                            .send_row(transaction_id, info.output_index, value)
+                            .await;
+                        if is_err {
+                            tables_to_remove.insert(key.clone());
+                            break;
+                        }
+                    }
+                    if tables_to_remove.contains(&key) {
+                        break;
+                    }
+                }
+            }
+            EventData::XidEvent(_) => {
+                if let Some(transaction_id) = current_transaction.take() {
+                    commit(task_info, transaction_id, &mut tables_to_remove).await;
+                }
+            }
+            EventData::QueryEvent(query) if query.query() == "COMMIT" => {
+                if let Some(transaction_id) = current_transaction.take() {
+                    commit(task_info, transaction_id, &mut tables_to_remove).await;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(ReplicationError::Indefinite(anyhow!(
+        "binlog stream ended unexpectedly"
+    )))
+}
+
+/// Marks the transaction `transaction_id` as fully emitted.
+async fn commit(
+    task_info: &mut MySqlTaskInfo,
+    transaction_id: u64,
+    tables_to_remove: &mut BTreeSet<(String, String)>,
+) {
+    task_info.row_sender.close(transaction_id).await;
+    task_info.upper = transaction_id + 1;
+    for key in std::mem::take(tables_to_remove) {
+        task_info.source_tables.remove(&key);
+    }
+}
+
+/// Checks the source's tables against their current upstream definitions, removing those that
+/// have become incompatible.
+///
+/// Returns the output index of each removed table along with the subsource error to propagate.
+async fn validate_tables(
+    conn: &mut mz_mysql_util::MySqlConn,
+    source_tables: &mut BTreeMap<(String, String), SourceTable>,
+) -> Result<Vec<(usize, anyhow::Error)>, ReplicationError> {
+    let schemas: BTreeSet<_> = source_tables
+        .values()
+        .map(|t| t.desc.schema_name.clone())
+        .collect();
+    let upstream: BTreeMap<_, _> = mz_mysql_util::schema_info(conn, Some(&schemas))
+        .await?
+        .into_iter()
+        .map(|desc| ((desc.schema_name.clone(), desc.name.clone()), desc))
+        .collect();
+
+    let mut errors = vec![];
+    source_tables.retain(|key, info| {
+        let result = match upstream.get(key) {
+            Some(desc) => info.desc.determine_compatibility(desc),
+            None => Err(anyhow!(
+                "source table {}.{} has been dropped",
+                info.desc.schema_name,
+                info.desc.name
+            )),
+        };
+        match result {
+            Ok(()) => true,
+            Err(err) => {
+                warn!(
+                    "error validating table {}.{}: {}",
+                    info.desc.schema_name, info.desc.name, err
+                );
+                errors.push((info.output_index, err));
+                false
+            }
+        }
+    });
+    Ok(errors)
+}
+
+/// Packs the text encoded values of a row and casts them into the target types.
+fn pack_row(casts: &[MirScalarExpr], values: Vec<Option<String>>) -> Result<Row, anyhow::Error> {
+    let text_row = Row::pack(
+        values
+            .iter()
+            .map(|v| v.as_deref().map_or(Datum::Null, Datum::String)),
+    );
+    let datums: Vec<_> = text_row.iter().collect();
+    let arena = mz_repr::RowArena::new();
+    let mut row = Row::default();
+    let mut packer = row.packer();
+    for column_cast in casts {
+        let datum = column_cast.eval(&datums, &arena)?;
+        packer.push(datum);
+    }
+    Ok(row)
+}
+
+/// Converts a value read with the text protocol during the snapshot into text that the
+/// column's cast accepts.
+fn snapshot_value_to_text(
+    value: Value,
+    column: &MySqlColumnDesc,
+) -> Result<Option<String>, anyhow::Error> {
+    match value {
+        Value::NULL => Ok(None),
+        Value::Bytes(bytes) => match column.data_type.as_str() {
+            // The snapshot session runs in UTC.
+            "timestamp" => Ok(Some(format!("{}+00", std::str::from_utf8(&bytes)?))),
+            _ if is_binary(column) => Ok(Some(encode_hex(&bytes))),
+            _ => Ok(Some(String::from_utf8(bytes)?)),
+        },
+        value => binlog_value_to_text(BinlogValue::Value(value), column),
+    }
+}
+
+/// Converts a value decoded from the binlog into text that the column's cast accepts.
+fn binlog_value_to_text(
+    value: BinlogValue<'_>,
+    column: &MySqlColumnDesc,
+) -> Result<Option<String>, anyhow::Error> {
+    let value = match value {
+        BinlogValue::Value(value) => value,
+        BinlogValue::Jsonb(value) => {
+            let json = serde_json::Value::try_from(value)?;
+            return Ok(Some(json.to_string()));
+        }
+        BinlogValue::JsonDiff(_) => bail!("partial JSON updates are not supported"),
+    };
+    let text = match value {
+        Value::NULL => return Ok(None),
+        Value::Int(i) => match column.data_type.as_str() {
+            "enum" => enum_label(column, i)?,
+            "set" => set_labels(column, i)?,
+            _ if column.is_unsigned() => {
+                // Unsigned integers narrower than 64 bits are sign extended when decoded.
+                let bits = match column.data_type.as_str() {
+                    "tinyint" => 8,
+                    "smallint" => 16,
+                    "mediumint" => 24,
+                    "int" => 32,
+                    _ => 64,
+                };
+                let u = u64::from_le_bytes(i.to_le_bytes());
+                let u = if bits == 64 { u } else { u & ((1 << bits) - 1) };
+                u.to_string()
+            }
+            _ => i.to_string(),
+        },
+        Value::UInt(u) => u.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Double(d) => d.to_string(),
+        Value::Bytes(bytes) => match column.data_type.as_str() {
+            // `TIMESTAMP` columns are decoded as `seconds[.fraction]` since the epoch.
+            "timestamp" => {
+                let text = std::str::from_utf8(&bytes)?;
+                let (secs, frac) = text.split_once('.').unwrap_or((text, ""));
+                let micros: u32 = format!("{:0<6}", frac).parse()?;
+                let ts = chrono::NaiveDateTime::from_timestamp_opt(secs.parse()?, micros * 1000)
+                    .ok_or_else(|| anyhow!("invalid timestamp: {}", text))?;
+                format!("{}+00", ts.format("%Y-%m-%d %H:%M:%S%.6f"))
+            }
+            _ if is_binary(column) => encode_hex(&bytes),
+            _ => String::from_utf8(bytes)?,
+        },
+        Value::Date(year, month, day, hour, minute, second, micros) => {
+            match column.data_type.as_str() {
+                "date" => format!("{:04}-{:02}-{:02}", year, month, day),
+                "timestamp" => format!(
+                    "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}+00",
+                    year, month, day, hour, minute, second, micros
+                ),
+                _ => format!(
+                    "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}",
+                    year, month, day, hour, minute, second, micros
+                ),
+            }
+        }
+        Value::Time(negative, days, hours, minutes, seconds, micros) => format!(
+            "{}{:02}:{:02}:{:02}.{:06}",
+            if negative { "-" } else { "" },
+            u64::from(days) * 24 + u64::from(hours),
+            minutes,
+            seconds,
+            micros
+        ),
+    };
+    Ok(Some(text))
+}
+
+/// Reports whether the column holds binary strings, which we ingest hex encoded.
+fn is_binary(column: &MySqlColumnDesc) -> bool {
+    matches!(
+        column.data_type.as_str(),
+        "binary" | "varbinary" | "tinyblob" | "blob" | "mediumblob" | "longblob" | "bit"
+    )
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    let mut s = String::with_capacity(2 + bytes.len() * 2);
+    s.push_str("\\x");
+    for b in bytes {
+        write!(s, "{:02x}", b).expect("writing to a string cannot fail");
+    }
+    s
+}
+
+/// Returns the labels declared by an `enum(...)` or `set(...)` column type.
+fn type_labels(column: &MySqlColumnDesc) -> Result<Vec<String>, anyhow::Error> {
+    let inner = column
+        .column_type
+        .split_once('(')
+        .and_then(|(_, rest)| rest.rsplit_once(')'))
+        .map(|(inner, _)| inner)
+        .ok_or_else(|| anyhow!("invalid column type: {}", column.column_type))?;
+    let mut labels = vec![];
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\'' {
+            continue;
+        }
+        let mut label = String::new();
+        loop {
+            match chars.next() {
+                Some('\'') if chars.peek() == Some(&'\'') => {
+                    chars.next();
+                    label.push('\'');
+                }
+                Some('\'') => break,
+                Some('\\') => label.extend(chars.next()),
+                Some(c) => label.push(c),
+                None => bail!("invalid column type: {}", column.column_type),
+            }
+        }
+        labels.push(label);
+    }
+    Ok(labels)
+}
+
+/// Decodes the 1-based index of an `ENUM` value into its label.
+fn enum_label(column: &MySqlColumnDesc, index: i64) -> Result<String, anyhow::Error> {
+    // The zero index is the error value MySQL stores for invalid values.
+    if index == 0 {
+        return Ok(String::new());
+    }
+    let labels = type_labels(column)?;
+    usize::try_from(index - 1)
+        .ok()
+        .and_then(|i| labels.get(i).cloned())
+        .ok_or_else(|| anyhow!("invalid enum index {} for column {}", index, column.name))
+}
+
+/// Decodes the bitmask of a `SET` value into its comma separated labels.
+fn set_labels(column: &MySqlColumnDesc, mask: i64) -> Result<String, anyhow::Error> {
+    let mask = u64::from_le_bytes(mask.to_le_bytes());
+    let labels = type_labels(column)?;
+    Ok(labels
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i < 64 && mask & (1 << i) != 0)
+        .map(|(_, label)| label.as_str())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// A type that makes it easy to correctly send the rows of a transaction.
+///
+/// Note: `RowSender::send_row` should be called with the same transaction id until `close` is
+/// called, which should be called and awaited before dropping the `RowSender` or moving onto a
+/// new transaction. Internally, this type uses asserts to uphold the first requirement.
+struct RowSender {
+    sender: Sender<(usize, InternalMessage)>,
+    buffered_message: Option<(u64, usize, Result<(Row, Diff), anyhow::Error>)>,
+}
+
+impl RowSender {
+    /// Create a new `RowSender`.
+    pub fn new(sender: Sender<(usize, InternalMessage)>) -> Self {
+        Self {
+            sender,
+            buffered_message: None,
+        }
+    }
+
+    /// Send a row for the specific output
+    pub async fn send_row(
+        &mut self,
+        transaction_id: u64,
+        output_index: usize,
+        value: Result<(Row, Diff), anyhow::Error>,
+    ) {
+        if let Some((buffered_id, output, value)) = self.buffered_message.take() {
+            assert_eq!(buffered_id, transaction_id);
+            self.send_row_inner(buffered_id, output, value, false).await;
+        }
+
+        self.buffered_message = Some((transaction_id, output_index, value));
+    }
+
+    /// Finalize a transaction, making sure any buffered message is flushed and marked as closing
+    /// the transaction, or that progress is reported if the transaction produced no messages.
+    pub async fn close(&mut self, transaction_id: u64) {
+        match self.buffered_message.take() {
+            Some((buffered_id, output, value)) => {
+                assert_eq!(buffered_id, transaction_id);
+                self.send_row_inner(buffered_id, output, value, true).await;
+            }
+            None => {
+                // If the channel is shutting down, so is the source.
+                let _ = self
+                    .sender
+                    .send((
+                        0,
+                        InternalMessage::Progress {
+                            upper: transaction_id + 1,
+                        },
+                    ))
+                    .await;
+            }
+        }
+    }
+
+    async fn send_row_inner(
+        &self,
+        transaction_id: u64,
+        output: usize,
+        value: Result<(Row, Diff), anyhow::Error>,
+        end: bool,
+    ) {
+        let message = InternalMessage::Value {
+            transaction_id,
+            value,
+            end,
+        };
+        // a closed receiver means the source has been shutdown (dropped or the process is
+        // dying), so just continue on without activation
+        let _ = self.sender.send((output, message)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(data_type: &str, column_type: &str) -> MySqlColumnDesc {
+        MySqlColumnDesc {
+            name: "c".into(),
+            data_type: data_type.into(),
+            column_type: column_type.into(),
+            precision: None,
+            scale: None,
+            nullable: true,
+        }
+    }
+
+    #[test]
+    fn test_binlog_value_to_text() {
+        let cases = [
+            (column("int", "int"), Value::Int(-1), "-1"),
+            (column("int", "int unsigned"), Value::Int(-1), "4294967295"),
+            (column("tinyint", "tinyint unsigned"), Value::Int(-1), "255"),
+            (
+                column("bigint", "bigint unsigned"),
+                Value::Int(-1),
+                "18446744073709551615",
+            ),
+            (column("enum", "enum('a','b''c')"), Value::Int(2), "b'c"),
+            (column("set", "set('a','b','c')"), Value::Int(5), "a,c"),
+            (
+                column("timestamp", "timestamp(3)"),
+                Value::Bytes(b"1.5".to_vec()),
+                "1970-01-01 00:00:01.500000+00",
+            ),
+            (
+                column("datetime", "datetime"),
+                Value::Date(2023, 1, 2, 3, 4, 5, 6),
+                "2023-01-02 03:04:05.000006",
+            ),
+            (
+                column("date", "date"),
+                Value::Date(2023, 1, 2, 0, 0, 0, 0),
+                "2023-01-02",
+            ),
+            (
+                column("time", "time"),
+                Value::Time(true, 1, 2, 3, 4, 0),
+                "-26:03:04.000000",
+            ),
+            (
+                column("varbinary", "varbinary(2)"),
+                Value::Bytes(vec![1, 171]),
+                "\\x01ab",
+            ),
+        ];
+        for (column, value, expected) in cases {
+            let text = binlog_value_to_text(BinlogValue::Value(value), &column).unwrap();
+            assert_eq!(text.as_deref(), Some(expected), "{:?}", column);
+        }
+        let null = binlog_value_to_text(BinlogValue::Value(Value::NULL), &column("int", "int"));
+        assert_eq!(null.unwrap(), None);
+    }
+}
@@ -41,7 +41,7 @@ use mz_expr::MirScalarExpr;
 use mz_ore::error::ErrorExt;
 use mz_ore::future::TimeoutError;
 use mz_ore::task;
-use mz_postgres_util::desc::PostgresTableDesc;
+use mz_postgres_util::desc::{PostgresEnumDesc, PostgresTableDesc};
 use mz_repr::{Datum, DatumVec, Diff, GlobalId, Row};
 use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::Ident;
@@ -256,6 +256,8 @@ struct PostgresTaskInfo {
     /// table's frontier independently; in that world, we can close the source table's frontier so
     /// we have a durable signal that it should never produce any data.
     source_tables: BTreeMap<u32, SourceTable>,
+    /// The upstream enum types used by `source_tables`, as they were when the source was created.
+    enum_types: Vec<PostgresEnumDesc>,
     row_sender: RowSender,
     sender: Sender<(usize, InternalMessage)>,
     resume_lsn: Arc<AtomicU64>,
@@ -376,6 +378,7 @@ impl SourceRender for PostgresSourceConnection {
                 replication_lsn: start_offset.offset.into(),
                 metrics: PgSourceMetrics::new(&config.base_metrics, config.id),
                 source_tables,
+                enum_types: self.publication_details.enum_types,
                 row_sender: RowSender::new(dataflow_tx.clone()),
                 sender: dataflow_tx,
                 resume_lsn: Arc::clone(&resume_lsn),
@@ -661,8 +664,19 @@ async fn postgres_replication_loop_inner_snapshot(
     .err_indefinite()?;
 
     // Validate publication tables against the state snapshot
-    let incompatible_tables =
+    let mut incompatible_tables =
         determine_table_compatibility(task_info.source_tables.iter(), publication_tables);
+    let current_enum_types =
+        fetch_enum_types(&task_info.connection_config, &task_info.enum_types).await?;
+    let incompatible_enum_types = determine_enum_compatibility(
+        task_info
+            .source_tables
+            .iter()
+            .filter(|(id, _)| !incompatible_tables.iter().any(|(i, _, _)| i == *id)),
+        &task_info.enum_types,
+        current_enum_types,
+    );
+    incompatible_tables.extend(incompatible_enum_types);
     for (id, output, err) in incompatible_tables {
         task_info.source_tables.remove(&id);
         task_info
@@ -807,6 +821,7 @@ async fn postgres_replication_loop_inner_snapshot(
             Arc::clone(&task_info.resume_lsn),
             &task_info.metrics,
             &mut task_info.source_tables,
+            &task_info.enum_types,
             task_info.source_id,
         )
         .await;
@@ -873,6 +888,7 @@ async fn postgres_replication_loop_inner(
         Arc::clone(&task_info.resume_lsn),
         &task_info.metrics,
         &mut task_info.source_tables,
+        &task_info.enum_types,
         task_info.source_id,
     )
     .await;
@@ -1037,6 +1053,105 @@ where
     errors
 }
 
+/// Determines if the upstream enum types used by a set of [`SourceTable`]s still have the labels
+/// that they had when the source was created, `enum_types`, given their current definitions.
+///
+/// Such columns are ingested as Materialize enum types with the original labels, so rows using a
+/// label added upstream could not be decoded.
+///
+/// The returned tuples are as for [`determine_table_compatibility`].
+fn determine_enum_compatibility<'a, I>(
+    source_tables: I,
+    enum_types: &[PostgresEnumDesc],
+    current_enum_types: Vec<PostgresEnumDesc>,
+) -> Vec<(u32, usize, anyhow::Error)>
+where
+    I: Iterator<Item = (&'a u32, &'a SourceTable)>,
+{
+    let current_enum_types: BTreeMap<u32, PostgresEnumDesc> =
+        current_enum_types.into_iter().map(|t| (t.oid, t)).collect();
+    let mut errors = vec![];
+    for (id, info) in source_tables {
+        let changed = info
+            .desc
+            .columns
+            .iter()
+            .filter_map(|c| enum_types.iter().find(|t| t.oid == c.type_oid))
+            .find_map(|expected| match current_enum_types.get(&expected.oid) {
+                Some(current) if current.labels == expected.labels => None,
+                current => Some((expected, current)),
+            });
+        let Some((expected, current)) = changed else {
+            continue;
+        };
+        warn!(
+            "alter type error, enum type changed upstream: table {}, oid {}, expected {:?}, actual {:?}",
+            info.desc.name, info.desc.oid, expected, current,
+        );
+        let err = match current {
+            Some(current) => anyhow!(
+                "labels of enum type {}.{} used by source table {} have changed from {:?} to {:?}",
+                expected.namespace,
+                expected.name,
+                info.desc.name,
+                expected.labels,
+                current.labels
+            ),
+            None => anyhow!(
+                "enum type {}.{} used by source table {} has been dropped",
+                expected.namespace,
+                expected.name,
+                info.desc.name
+            ),
+        };
+        errors.push((*id, info.output_index, err));
+    }
+    errors
+}
+
+/// Fetches the current upstream definitions of `enum_types`.
+async fn fetch_enum_types(
+    config: &mz_postgres_util::Config,
+    enum_types: &[PostgresEnumDesc],
+) -> Result<Vec<PostgresEnumDesc>, ReplicationError> {
+    if enum_types.is_empty() {
+        return Ok(vec![]);
+    }
+    let oids: Vec<_> = enum_types.iter().map(|t| t.oid).collect();
+    mz_postgres_util::enum_types(config, &oids)
+        .await
+        .err_indefinite()
+}
+
+/// Returns the error to report for a row of the table `rel_id` that could not be decoded: `err`,
+/// unless an enum type that the table uses has changed upstream, which is then the cause.
+async fn explain_decoding_error(
+    config: &mz_postgres_util::Config,
+    enum_types: &[PostgresEnumDesc],
+    rel_id: u32,
+    info: &SourceTable,
+    err: anyhow::Error,
+) -> Result<anyhow::Error, ReplicationError> {
+    let uses_enum_types = info
+        .desc
+        .columns
+        .iter()
+        .any(|c| enum_types.iter().any(|t| t.oid == c.type_oid));
+    if !uses_enum_types {
+        return Ok(err);
+    }
+    let current_enum_types = fetch_enum_types(config, enum_types).await?;
+    let incompatible = determine_enum_compatibility(
+        std::iter::once((&rel_id, info)),
+        enum_types,
+        current_enum_types,
+    );
+    Ok(incompatible
+        .into_iter()
+        .next()
+        .map_or(err, |(_, _, err)| err))
+}
+
 /// Parses SQL results that are expected to be a single row into a Rust type
 fn parse_single_row<T: FromStr>(
     result: &[SimpleQueryMessage],
@@ -1207,6 +1322,7 @@ async fn produce_replication<'a>(
     committed_lsn: Arc<AtomicU64>,
     metrics: &'a PgSourceMetrics,
     source_tables: &'a mut BTreeMap<u32, SourceTable>,
+    enum_types: &'a [PostgresEnumDesc],
     source_id: GlobalId,
 ) -> impl futures::Stream<
     Item = Result<Event<[PgLsn; 1], (usize, Result<(Row, Diff), anyhow::Error>)>, ReplicationError>,
@@ -1333,13 +1449,23 @@ async fn produce_replication<'a>(
 
                             match gen_row() {
                                 Ok(row) => inserts.push((info.output_index, row)),
-                                Err(err) => handle_subsource_err!(
-                                    errors,
-                                    source_tables,
-                                    info.output_index,
-                                    err,
-                                    rel_id
-                                ),
+                                Err(err) => {
+                                    let err = explain_decoding_error(
+                                        &client_config,
+                                        enum_types,
+                                        rel_id,
+                                        info,
+                                        err,
+                                    )
+                                    .await?;
+                                    handle_subsource_err!(
+                                        errors,
+                                        source_tables,
+                                        info.output_index,
+                                        err,
+                                        rel_id
+                                    )
+                                }
                             }
                         }
                         Update(update) if source_tables.contains_key(&update.rel_id()) => {
@@ -1395,13 +1521,23 @@ async fn produce_replication<'a>(
                                     deletes.push((info.output_index, old_row));
                                     inserts.push((info.output_index, new_row));
                                 }
-                                Err(err) => handle_subsource_err!(
-                                    errors,
-                                    source_tables,
-                                    info.output_index,
-                                    err,
-                                    rel_id
-                                ),
+                                Err(err) => {
+                                    let err = explain_decoding_error(
+                                        &client_config,
+                                        enum_types,
+                                        rel_id,
+                                        info,
+                                        err,
+                                    )
+                                    .await?;
+                                    handle_subsource_err!(
+                                        errors,
+                                        source_tables,
+                                        info.output_index,
+                                        err,
+                                        rel_id
+                                    )
+                                }
                             }
                         }
                         Delete(delete) if source_tables.contains_key(&delete.rel_id()) => {
@@ -1430,13 +1566,23 @@ async fn produce_replication<'a>(
 
                             match gen_row() {
                                 Ok(row) => deletes.push((info.output_index, row)),
-                                Err(err) => handle_subsource_err!(
-                                    errors,
-                                    source_tables,
-                                    info.output_index,
-                                    err,
-                                    rel_id
-                                ),
+                                Err(err) => {
+                                    let err = explain_decoding_error(
+                                        &client_config,
+                                        enum_types,
+                                        rel_id,
+                                        info,
+                                        err,
+                                    )
+                                    .await?;
+                                    handle_subsource_err!(
+                                        errors,
+                                        source_tables,
+                                        info.output_index,
+                                        err,
+                                        rel_id
+                                    )
+                                }
                             };
                         }
                         Commit(commit) => {
@@ -1474,10 +1620,19 @@ async fn produce_replication<'a>(
                                 .err_indefinite()?;
 
                                 // Validate publication tables against the state snapshot
-                                let incompatible_tables = determine_table_compatibility(
+                                let mut incompatible_tables = determine_table_compatibility(
                                     std::iter::once((&rel_id, info)),
                                     current_publication_info,
                                 );
+                                if incompatible_tables.is_empty() {
+                                    let current_enum_types =
+                                        fetch_enum_types(&client_config, enum_types).await?;
+                                    incompatible_tables = determine_enum_compatibility(
+                                        std::iter::once((&rel_id, info)),
+                                        enum_types,
+                                        current_enum_types,
+                                    );
+                                }
                                 for (rel_id, output_index, err) in incompatible_tables {
                                     handle_subsource_err!(
                                         errors,
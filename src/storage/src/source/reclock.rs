@@ -625,7 +625,7 @@ mod tests {
     use mz_persist_client::cfg::PersistConfig;
     use mz_persist_client::{PersistLocation, ShardId};
     use mz_persist_types::codec_impls::UnitSchema;
-    use mz_repr::{GlobalId, RelationDesc, ScalarType, Timestamp};
+    use mz_repr::{GlobalId, RelationDesc, Row, ScalarType, Timestamp};
     use mz_storage_client::controller::CollectionMetadata;
     use mz_storage_client::types::sources::{MzOffset, SourceData};
     use mz_storage_client::util::remap_handle::RemapHandle;
@@ -673,6 +673,7 @@ mod tests {
             data_shard: ShardId::new(),
             status_shard: None,
             relation_desc: RelationDesc::empty(),
+            missing_values: Row::default(),
         };
 
         let clock_stream = futures::stream::iter((0..).map(|seconds| {
@@ -33,8 +33,8 @@ use mz_storage_client::controller::CollectionMetadata;
 use mz_storage_client::controller::ResumptionFrontierCalculator;
 use mz_storage_client::types::sources::{
     GenericSourceConnection, IngestionDescription, KafkaSourceConnection,
-    LoadGeneratorSourceConnection, PostgresSourceConnection, SourceConnection, SourceData,
-    SourceTimestamp, TestScriptSourceConnection,
+    LoadGeneratorSourceConnection, MySqlSourceConnection, PostgresSourceConnection,
+    SourceConnection, SourceData, SourceTimestamp, TestScriptSourceConnection,
 };
 
 use crate::source::reclock::{ReclockBatch, ReclockFollower};
@@ -193,6 +193,15 @@ impl<T: Timestamp + Lattice + Codec64 + Display> AsyncStorageWorker<T> {
                                 .await;
                                 upper.into_iter().map(|ts| ts.encode_row()).collect()
                             }
+                            GenericSourceConnection::MySql(_) => {
+                                let upper = reclock_resume_frontier::<MySqlSourceConnection, _>(
+                                    &persist_clients,
+                                    &ingestion_description,
+                                    &resume_upper,
+                                )
+                                .await;
+                                upper.into_iter().map(|ts| ts.encode_row()).collect()
+                            }
                             GenericSourceConnection::LoadGenerator(_) => {
                                 let upper =
                                     reclock_resume_frontier::<LoadGeneratorSourceConnection, _>(
@@ -245,6 +245,7 @@ where
                 // TODO(guswynn|danhhz): replace this with a real desc when persist requires a
                 // schema.
                 relation_desc: RelationDesc::empty(),
+                missing_values: Row::default(),
             };
             let data_shard = collection_metadata.data_shard.clone();
             let id = GlobalId::User(1);
@@ -265,6 +266,7 @@ where
                 let source_resumption_frontier = match &desc.connection {
                     GenericSourceConnection::Kafka(c) => minimum_frontier(c),
                     GenericSourceConnection::Postgres(c) => minimum_frontier(c),
+                    GenericSourceConnection::MySql(c) => minimum_frontier(c),
                     GenericSourceConnection::TestScript(c) => minimum_frontier(c),
                     GenericSourceConnection::LoadGenerator(c) => minimum_frontier(c),
                 };
@@ -24,7 +24,8 @@ use tokio_postgres::types::{FromSql, Type};
 use mz_ore::collections::CollectionExt;
 use mz_ore::retry::Retry;
 use mz_ore::str::StrExt;
-use mz_pgrepr::{Interval, Jsonb, Numeric, UInt2, UInt4, UInt8};
+use mz_pgrepr::{Inet, Interval, Jsonb, Numeric, UInt2, UInt4, UInt8};
+use mz_repr::adt::inet::Cidr;
 use mz_repr::adt::range::Range;
 use mz_sql::ast::ExplainStage;
 use mz_sql_parser::ast::{Raw, Statement};
@@ -67,6 +68,7 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &mut State) -> Result<ControlFl
         | CreateRole(_)
         | AlterObjectRename(_)
         | AlterIndex(_)
+        | AlterTable(_)
         | Discard(_)
         | DropObjects(_)
         | SetVariable(_) => false,
@@ -126,6 +128,7 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &mut State) -> Result<ControlFl
         | Statement::CreateIndex { .. }
         | Statement::CreateSchema { .. }
         | Statement::CreateSource { .. }
+        | Statement::CreateWebhookSource { .. }
         | Statement::CreateTable { .. }
         | Statement::CreateView { .. }
         | Statement::DropObjects { .. } => {
@@ -613,6 +616,8 @@ pub fn decode_row(
             Type::INTERVAL => row.get::<_, Option<Interval>>(i).map(|x| x.to_string()),
             Type::JSONB => row.get::<_, Option<Jsonb>>(i).map(|v| v.0.to_string()),
             Type::UUID => row.get::<_, Option<uuid::Uuid>>(i).map(|v| v.to_string()),
+            Type::INET => row.get::<_, Option<Inet>>(i).map(|v| v.to_string()),
+            Type::CIDR => row.get::<_, Option<Inet>>(i).map(|v| Cidr(v.0).to_string()),
             Type::BOOL_ARRAY => row
                 .get::<_, Option<Array<ArrayElement<bool>>>>(i)
                 .map(|a| a.to_string()),
@@ -50,6 +50,7 @@ impl TopKElision {
             offset,
             monotonic: _,
             expected_group_size: _,
+            with_ties: _,
         } = relation
         {
             if limit.is_none() && *offset == 0 {
@@ -139,11 +139,14 @@ impl FoldConstants {
                 order_key,
                 limit,
                 offset,
+                with_ties,
                 ..
             } => {
                 if let Some((rows, ..)) = (**input).as_const_mut() {
                     if let Ok(rows) = rows {
-                        Self::fold_topk_constant(group_key, order_key, limit, offset, rows);
+                        Self::fold_topk_constant(
+                            group_key, order_key, limit, offset, *with_ties, rows,
+                        );
                     }
                     *relation = input.take_dangerous();
                 }
@@ -500,10 +503,12 @@ impl FoldConstants {
                 let mut row_buf = Row::default();
                 move |(key, vals)| {
                     let temp_storage = RowArena::new();
-                    row_buf.packer().extend(key.into_iter().chain(
-                        aggregates.iter().enumerate().map(|(i, agg)| {
+                    let aggregates = aggregates
+                        .iter()
+                        .enumerate()
+                        .map(|(i, agg)| {
                             if agg.distinct {
-                                agg.func.eval(
+                                agg.func.try_eval(
                                     vals.iter()
                                         .map(|val| val[i].unpack_first())
                                         .collect::<BTreeSet<_>>()
@@ -511,18 +516,19 @@ impl FoldConstants {
                                     &temp_storage,
                                 )
                             } else {
-                                agg.func.eval(
+                                agg.func.try_eval(
                                     vals.iter().map(|val| val[i].unpack_first()),
                                     &temp_storage,
                                 )
                             }
-                        }),
-                    ));
-                    (row_buf.clone(), 1)
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                    row_buf.packer().extend(key.into_iter().chain(aggregates));
+                    Ok((row_buf.clone(), 1))
                 }
             })
-            .collect();
-        Some(Ok(new_rows))
+            .collect::<Result<_, EvalError>>();
+        Some(new_rows)
     }
 
     fn fold_topk_constant<'a>(
@@ -530,6 +536,7 @@ impl FoldConstants {
         order_key: &[ColumnOrder],
         limit: &Option<usize>,
         offset: &usize,
+        with_ties: bool,
         rows: &'a mut [(Row, Diff)],
     ) {
         // helper functions for comparing elements by order_key and group_key
@@ -571,12 +578,24 @@ impl FoldConstants {
 
         let mut same_group_key =
             |lhs: &(Row, Diff), rhs: &(Row, Diff)| cmp_group_key(lhs, rhs) == Ordering::Equal;
+        let mut same_order_key = {
+            let mut lhs_datum_vec = mz_repr::DatumVec::new();
+            let mut rhs_datum_vec = mz_repr::DatumVec::new();
+            move |lhs: &(Row, Diff), rhs: &(Row, Diff)| {
+                let lhs_datums = &lhs_datum_vec.borrow_with(&lhs.0);
+                let rhs_datums = &rhs_datum_vec.borrow_with(&rhs.0);
+                mz_expr::compare_columns(order_key, lhs_datums, rhs_datums, || Ordering::Equal)
+                    == Ordering::Equal
+            }
+        };
 
         let mut cursor = 0;
         while cursor < rows.len() {
             // first, reset the remaining limit and offset for the current group
             let mut offset_rem: Diff = offset.clone().try_into().unwrap();
             let mut limit_rem: Option<Diff> = limit.clone().map(|x| x.try_into().unwrap());
+            // the last row retained for the current group, if any
+            let mut last_retained: Option<usize> = None;
 
             let mut finger = cursor;
             while finger < rows.len() && same_group_key(&rows[cursor], &rows[finger]) {
@@ -593,8 +612,19 @@ impl FoldConstants {
                     // then update the diff and decrement the remaining limit by that number
                     if let Some(limit_rem) = &mut limit_rem {
                         let rows_to_retain = std::cmp::min(*limit_rem, rows[finger].1);
-                        rows[finger].1 = rows_to_retain;
                         *limit_rem -= rows_to_retain;
+                        // with ties, rows that are in the limit or that tie with the last
+                        // retained row are retained in their entirety
+                        let is_tie = with_ties
+                            && (rows_to_retain > 0
+                                || last_retained
+                                    .map_or(false, |l| same_order_key(&rows[l], &rows[finger])));
+                        if !is_tie {
+                            rows[finger].1 = rows_to_retain;
+                        }
+                    }
+                    if rows[finger].1 > 0 {
+                        last_retained = Some(finger);
                     }
                 }
                 finger += 1;
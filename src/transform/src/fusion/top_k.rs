@@ -51,6 +51,7 @@ impl TopK {
             offset,
             monotonic,
             expected_group_size,
+            with_ties,
         } = relation
         {
             while let MirRelationExpr::TopK {
@@ -61,11 +62,16 @@ impl TopK {
                 offset: inner_offset,
                 monotonic: inner_monotonic,
                 expected_group_size: inner_expected_group_size,
+                with_ties: inner_with_ties,
             } = &mut **input
             {
                 // We can fuse two chained TopK operators as long as they share the
-                // same grouping and ordering key.
-                if *group_key == *inner_group_key && *order_key == *inner_order_key {
+                // same grouping and ordering key, and neither retains ties.
+                if *group_key == *inner_group_key
+                    && *order_key == *inner_order_key
+                    && !*with_ties
+                    && !*inner_with_ties
+                {
                     // Given the following limit/offset pairs:
                     //
                     // inner_offset          inner_limit
@@ -593,7 +593,7 @@ impl LiteralLifting {
                         let temp = mz_repr::RowArena::new();
                         let mut eval = aggr.expr.eval(&[], &temp);
                         if let Ok(param) = eval {
-                            eval = Ok(aggr.func.eval(Some(param), &temp));
+                            eval = aggr.func.try_eval(Some(param), &temp);
                         }
                         MirScalarExpr::literal(
                             eval,
@@ -670,6 +670,7 @@ impl LiteralLifting {
                     offset: _,
                     monotonic: _,
                     expected_group_size: _,
+                    with_ties: _,
                 } => {
                     let literals = self.action(input, gets)?;
                     if !literals.is_empty() {
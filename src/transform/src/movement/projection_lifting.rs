@@ -275,6 +275,7 @@ impl ProjectionLifting {
                     offset,
                     monotonic: _,
                     expected_group_size,
+                    with_ties,
                 } => {
                     self.action(input, gets)?;
                     if let MirRelationExpr::Project {
@@ -296,6 +297,7 @@ impl ProjectionLifting {
                                 limit.clone(),
                                 offset.clone(),
                                 expected_group_size.clone(),
+                                *with_ties,
                             )
                             .project(outputs.clone());
                     }
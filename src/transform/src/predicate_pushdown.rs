@@ -350,6 +350,7 @@ impl PredicatePushdown {
                             offset: _,
                             monotonic: _,
                             expected_group_size: _,
+                            with_ties: _,
                         } => {
                             let mut retain = Vec::new();
                             let mut push_down = Vec::new();
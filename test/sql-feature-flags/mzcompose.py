@@ -233,6 +233,91 @@ class FormatJson(FeatureTestScenario):
         return "SELECT true;"
 
 
+class FileSinks(FeatureTestScenario):
+    @classmethod
+    def feature_name(cls) -> str:
+        return "enable_file_sinks"
+
+    @classmethod
+    def feature_error(cls) -> str:
+        return "`CREATE SINK ... INTO FILE` is not enabled"
+
+    @classmethod
+    def initialize(cls) -> str:
+        return dedent(
+            """
+            > CREATE SECRET IF NOT EXISTS aws_secret_for_file_sinks AS 'secret'
+            > CREATE CONNECTION IF NOT EXISTS aws_conn_for_file_sinks TO AWS (REGION 'us-east-1', ACCESS KEY ID 'key', SECRET ACCESS KEY SECRET aws_secret_for_file_sinks)
+            > CREATE MATERIALIZED VIEW IF NOT EXISTS mv_for_file_sinks AS SELECT 1 AS a
+            """
+        )
+
+    @classmethod
+    def create_item(cls, ordinal: int) -> str:
+        return dedent(
+            f"""
+            CREATE SINK file_sink_{ordinal:02d}
+                FROM mv_for_file_sinks
+                INTO FILE CONNECTION aws_conn_for_file_sinks (URL 's3://bucket/file-sink-{ordinal:02d}')
+                WITH (SIZE '1');
+            """
+        )
+
+    @classmethod
+    def drop_item(cls, ordinal: int) -> str:
+        return f"DROP SINK file_sink_{ordinal:02d};"
+
+    @classmethod
+    def query_item(cls, ordinal: int) -> str:
+        # Test cannot spin up infra for this feature to be tested, but we just want to verify it
+        # plans successfully.
+        return "SELECT true;"
+
+
+class UnvalidatedWebhookSources(FeatureTestScenario):
+    @classmethod
+    def feature_name(cls) -> str:
+        return "enable_unvalidated_webhook_sources"
+
+    @classmethod
+    def feature_error(cls) -> str:
+        return "`CREATE SOURCE ... FROM WEBHOOK` without `VALIDATE USING` is not enabled"
+
+    @classmethod
+    def create_item(cls, ordinal: int) -> str:
+        return f"CREATE SOURCE webhook_{ordinal:02d} FROM WEBHOOK BODY FORMAT JSON;"
+
+    @classmethod
+    def drop_item(cls, ordinal: int) -> str:
+        return f"DROP SOURCE webhook_{ordinal:02d};"
+
+    @classmethod
+    def query_item(cls, ordinal: int) -> str:
+        return f"SELECT * FROM webhook_{ordinal:02d}"
+
+
+class TableKeys(FeatureTestScenario):
+    @classmethod
+    def feature_name(cls) -> str:
+        return "enable_table_keys"
+
+    @classmethod
+    def feature_error(cls) -> str:
+        return "`PRIMARY KEY` and `UNIQUE` constraints is not enabled"
+
+    @classmethod
+    def create_item(cls, ordinal: int) -> str:
+        return f"CREATE TABLE table_keys_{ordinal:02d} (a int PRIMARY KEY);"
+
+    @classmethod
+    def drop_item(cls, ordinal: int) -> str:
+        return f"DROP TABLE table_keys_{ordinal:02d};"
+
+    @classmethod
+    def query_item(cls, ordinal: int) -> str:
+        return f"SELECT * FROM table_keys_{ordinal:02d}"
+
+
 def run_test(c: Composition, args: argparse.Namespace) -> None:
     c.up("redpanda", "materialized")
     c.up("testdrive", persistent=True)